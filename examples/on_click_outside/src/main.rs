@@ -1,14 +1,20 @@
 use leptos::html::Div;
 use leptos::prelude::*;
 use leptos_use::docs::demo_or_body;
-use leptos_use::on_click_outside;
+use leptos_use::{on_click_outside_with_options, OnClickOutsideOptions};
 
 #[component]
 fn Demo() -> impl IntoView {
     let (show_modal, set_show_modal) = signal(false);
     let modal_ref = NodeRef::<Div>::new();
 
-    let _ = on_click_outside(modal_ref, move |_| set_show_modal.set(false));
+    let _ = on_click_outside_with_options(
+        modal_ref,
+        move |_| set_show_modal.set(false),
+        OnClickOutsideOptions::default()
+            .detect_focus_outside(true)
+            .detect_escape(true),
+    );
 
     view! {
         <button on:click=move |_| set_show_modal.set(true)>"Open Modal"</button>
@@ -24,7 +30,7 @@ fn Demo() -> impl IntoView {
                         "𝖷"
                     </button>
                     <p class="heading">"Demo Modal"</p>
-                    <p>"Click outside this modal to close it."</p>
+                    <p>"Click outside this modal or press Escape to close it."</p>
                 </div>
             </div>
         </Show>
@@ -1,6 +1,10 @@
 use leptos::prelude::*;
 use leptos_use::docs::demo_or_body;
-use leptos_use::sync_signal;
+use leptos_use::{
+    sync_signal, sync_signal_many_with_options, sync_signal_with_options, SyncSignalManyOptions,
+    SyncSignalOptions,
+};
+use std::str::FromStr;
 
 #[component]
 fn Demo() -> impl IntoView {
@@ -9,9 +13,62 @@ fn Demo() -> impl IntoView {
 
     let _ = sync_signal((a, set_a), (b, set_b));
 
+    let (text, set_text) = signal("1".to_string());
+    let (number, set_number) = signal(1_i32);
+    let (error, set_error) = signal(None);
+
+    let _ = sync_signal_with_options(
+        (text, set_text),
+        (number, set_number),
+        SyncSignalOptions::with_fallible_transforms(
+            |text: &String| i32::from_str(text.trim()),
+            |number: &i32| Some(number.to_string()),
+        )
+        .error(set_error),
+    );
+
+    let first_name = RwSignal::new("Jane".to_string());
+    let last_name = RwSignal::new("Doe".to_string());
+    let full_name = RwSignal::new(String::new());
+    let (split_back, set_split_back) = signal(true);
+
+    let _ = sync_signal_many_with_options(
+        [first_name, last_name],
+        full_name,
+        |names: &[String]| names.join(" "),
+        SyncSignalManyOptions::default()
+            .split(|full_name: &String| {
+                let (first, last) = full_name.split_once(' ').unwrap_or((full_name, ""));
+                vec![first.to_string(), last.to_string()]
+            })
+            .target_to_sources(split_back),
+    );
+
     view! {
         <input prop:value=a on:input=move |e| set_a(event_target_value(&e)) placeholder="A" type="text" />
         <input prop:value=b on:input=move |e| set_b(event_target_value(&e)) placeholder="B" type="text" />
+
+        <h3 class="mt-6">"Fallible transforms"</h3>
+        <input prop:value=text on:input=move |e| set_text.set(event_target_value(&e)) placeholder="Number" type="text" />
+        <button on:click=move |_| set_number.update(|number| *number += 1)>"Increment"</button>
+        <p>"Number: " {number}</p>
+        <p>
+            "Error: "
+            {move || error.get().and_then(|error| error.message).unwrap_or_else(|| "-".to_string())}
+        </p>
+
+        <h3 class="mt-6">"Many to one"</h3>
+        <input prop:value=first_name on:input=move |e| first_name.set(event_target_value(&e)) placeholder="First name" type="text" />
+        <input prop:value=last_name on:input=move |e| last_name.set(event_target_value(&e)) placeholder="Last name" type="text" />
+        <input prop:value=full_name on:input=move |e| full_name.set(event_target_value(&e)) placeholder="Full name" type="text" />
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=split_back
+                on:change=move |e| set_split_back.set(event_target_checked(&e))
+            />
+            " Sync full name back"
+        </label>
     }
 }
 
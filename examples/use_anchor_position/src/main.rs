@@ -0,0 +1,86 @@
+use leptos::html::{Button, Div};
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_anchor_position_with_options, Placement, UseAnchorPositionOptions, UseAnchorPositionReturn,
+};
+
+const PLACEMENTS: [Placement; 12] = [
+    Placement::Top,
+    Placement::TopStart,
+    Placement::TopEnd,
+    Placement::Right,
+    Placement::RightStart,
+    Placement::RightEnd,
+    Placement::Bottom,
+    Placement::BottomStart,
+    Placement::BottomEnd,
+    Placement::Left,
+    Placement::LeftStart,
+    Placement::LeftEnd,
+];
+
+#[component]
+fn Demo() -> impl IntoView {
+    let anchor = NodeRef::<Button>::new();
+    let floating = NodeRef::<Div>::new();
+
+    let (requested_placement, set_requested_placement) = signal(Placement::Bottom);
+
+    let UseAnchorPositionReturn {
+        is_supported,
+        floating_styles,
+        placement,
+        ..
+    } = use_anchor_position_with_options(
+        anchor,
+        floating,
+        UseAnchorPositionOptions::default()
+            .placement(requested_placement)
+            .offset(8.0)
+            .flip(true),
+    );
+
+    view! {
+        <Note class="mb-4">"Scroll the page or resize the window. The floating element stays attached."</Note>
+        <p>"Native anchor positioning: " <BooleanDisplay value=is_supported/></p>
+        <select on:change=move |e| {
+            if let Ok(index) = event_target_value(&e).parse::<usize>() {
+                set_requested_placement.set(PLACEMENTS[index]);
+            }
+        }>
+            {PLACEMENTS
+                .iter()
+                .enumerate()
+                .map(|(index, placement)| {
+                    view! {
+                        <option value=index selected=*placement == Placement::Bottom>
+                            {format!("{placement:?}")}
+                        </option>
+                    }
+                })
+                .collect_view()}
+        </select>
+        <div class="flex justify-center py-24">
+            <button node_ref=anchor>"Anchor"</button>
+        </div>
+        <div
+            node_ref=floating
+            class="px-3 py-2 rounded bg-[--brand-color] text-white"
+            style=floating_styles
+        >
+            {move || format!("{:?}", placement.get())}
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,40 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_app_badge, UseAppBadgeReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseAppBadgeReturn {
+        is_supported,
+        count,
+        set_badge,
+        clear_badge,
+    } = use_app_badge();
+
+    let increment = {
+        let set_badge = set_badge.clone();
+        move |_| set_badge(count.get_untracked() + 1)
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Count: " {count}</p>
+        <button on:click=increment>"Increment"</button>
+        <button on:click=move |_| set_badge(42)>"Set to 42"</button>
+        <button on:click=move |_| clear_badge()>"Clear"</button>
+        <Note>
+            "The app badge is only visible if the app is installed. In browsers without support the count is shown in the document title instead."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,59 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_background_sync, use_service_worker_with_options, UseBackgroundSyncReturn,
+    UseServiceWorkerOptions,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let _ = use_service_worker_with_options(
+        UseServiceWorkerOptions::default()
+            .script_url("sync-worker.js")
+            .on_controller_change(|| {}),
+    );
+
+    let UseBackgroundSyncReturn {
+        is_supported,
+        is_periodic_supported,
+        periodic_permission,
+        pending_tags,
+        periodic_tags,
+        error,
+        register,
+        register_periodic,
+        unregister_periodic,
+        refresh,
+    } = use_background_sync();
+
+    let (tag, set_tag) = signal("outbox".to_string());
+
+    view! {
+        <p>"Sync supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Periodic sync supported: " <BooleanDisplay value=is_periodic_supported/></p>
+        <p>"Periodic sync permission: " {move || periodic_permission.get().to_string()}</p>
+        <input
+            type="text"
+            prop:value=tag
+            on:input=move |ev| set_tag.set(event_target_value(&ev))
+        />
+        <button on:click=move |_| register(&tag.get())>"Register sync"</button>
+        <button on:click=move |_| register_periodic(&tag.get(), 60_000)>"Register periodic sync"</button>
+        <button on:click=move |_| unregister_periodic(&tag.get())>"Unregister periodic sync"</button>
+        <button on:click=move |_| refresh()>"Refresh"</button>
+        <p>"Pending tags: " {move || pending_tags.get().join(", ")}</p>
+        <p>"Periodic tags: " {move || periodic_tags.get().join(", ")}</p>
+        <p>"Error: " {move || error.get().map(|error| format!("{error:?}"))}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
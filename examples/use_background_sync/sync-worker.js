@@ -0,0 +1,10 @@
+self.addEventListener('install', () => self.skipWaiting());
+self.addEventListener('activate', (event) => event.waitUntil(self.clients.claim()));
+
+self.addEventListener('sync', (event) => {
+    console.log('sync event', event.tag);
+});
+
+self.addEventListener('periodicsync', (event) => {
+    console.log('periodicsync event', event.tag);
+});
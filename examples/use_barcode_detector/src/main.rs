@@ -0,0 +1,63 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_barcode_detector, use_user_media, UseBarcodeDetectorReturn, UseUserMediaReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video_ref = NodeRef::<leptos::html::Video>::new();
+
+    let UseUserMediaReturn {
+        stream,
+        enabled,
+        set_enabled,
+        ..
+    } = use_user_media();
+
+    Effect::new(move || {
+        let stream = stream.get().and_then(Result::ok);
+
+        if let Some(video) = video_ref.get() {
+            video.set_src_object(stream.as_ref());
+        }
+    });
+
+    let UseBarcodeDetectorReturn {
+        is_supported,
+        barcodes,
+        ..
+    } = use_barcode_detector(video_ref);
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <button on:click=move |_| set_enabled.set(!enabled.get())>
+            {move || if enabled.get() { "Stop camera" } else { "Start camera" }}
+        </button>
+        <div>
+            <video node_ref=video_ref autoplay=true muted=true class="h-72 w-auto"></video>
+        </div>
+        <p>"Detected barcodes:"</p>
+        <ul>
+            <For each=move || barcodes.get() key=|barcode| barcode.raw_value.clone() let:barcode>
+                <li>
+                    <code>{barcode.format}</code>
+                    ": "
+                    {barcode.raw_value}
+                </li>
+            </For>
+        </ul>
+        <Note>"Hold a QR code or barcode in front of the camera."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
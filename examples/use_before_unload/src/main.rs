@@ -0,0 +1,45 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_before_unload, UseBeforeUnloadReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (text, set_text) = signal(String::new());
+    let (saved_text, set_saved_text) = signal(String::new());
+
+    let dirty = Signal::derive(move || text.get() != saved_text.get());
+
+    let UseBeforeUnloadReturn { confirm_leave } = use_before_unload(dirty);
+
+    let (left, set_left) = signal(false);
+
+    view! {
+        <textarea
+            class="block w-full"
+            prop:value=text
+            on:input=move |ev| set_text.set(event_target_value(&ev))
+            placeholder="Type something and then try to leave the page"
+        ></textarea>
+        <button on:click=move |_| set_saved_text.set(text.get())>"Save"</button>
+        <p>"Unsaved changes: " <BooleanDisplay value=dirty/></p>
+        <p>
+            <a href="?page=other">"Link to another page"</a>
+        </p>
+        <button on:click=move |_| set_left.set(confirm_leave("?page=other"))>"Leave programmatically"</button>
+        <Show when=move || left.get()>
+            <p>"You would have left the page now."</p>
+        </Show>
+        <Note>"Reloading or closing the tab shows the browser's own prompt."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,96 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_bluetooth_with_options, UseBluetoothOptions, UseBluetoothReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseBluetoothReturn {
+        is_supported,
+        device,
+        is_connected,
+        error,
+        bluetooth,
+    } = use_bluetooth_with_options(
+        UseBluetoothOptions::default()
+            .accept_all_devices(true)
+            .optional_services(vec![
+                "battery_service".to_string(),
+                "heart_rate".to_string(),
+            ]),
+    );
+
+    let (battery_level, set_battery_level) = signal(None::<u8>);
+
+    let heart_rate = bluetooth.subscribe("heart_rate", "heart_rate_measurement");
+
+    let read_battery = move || {
+        leptos::task::spawn_local(async move {
+            if let Ok(value) = bluetooth.read("battery_service", "battery_level").await {
+                set_battery_level.set(value.first().copied());
+            }
+        });
+    };
+
+    let request = move |_| {
+        leptos::task::spawn_local(async move {
+            if bluetooth.request_device().await.is_ok() {
+                read_battery();
+            }
+        });
+    };
+
+    let reconnect = move |_| {
+        leptos::task::spawn_local(async move {
+            let _ = bluetooth.connect().await;
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <button on:click=request>"Request device"</button>
+        <p>"Device: " {move || device.get().map(|device| device.name().unwrap_or_else(|| device.id()))}</p>
+        <p>"Connected: " <BooleanDisplay value=is_connected/></p>
+        <button on:click=reconnect disabled=move || device.get().is_none() || is_connected.get()>
+            "Reconnect"
+        </button>
+        <button on:click=move |_| bluetooth.disconnect() disabled=move || !is_connected.get()>
+            "Disconnect"
+        </button>
+        <button on:click=move |_| read_battery() disabled=move || !is_connected.get()>
+            "Read battery level"
+        </button>
+        <p>"Battery level: " {move || battery_level.get().map(|level| format!("{level}%"))}</p>
+        <p>
+            "Heart rate: "
+            {move || {
+                heart_rate
+                    .get()
+                    .and_then(|value| {
+                        // the first byte contains flags, bit 0 selects between u8 and u16 values
+                        if value.first()? & 1 == 0 {
+                            value.get(1).map(|bpm| *bpm as u16)
+                        } else {
+                            Some(u16::from_le_bytes([*value.get(1)?, *value.get(2)?]))
+                        }
+                    })
+                    .map(|bpm| format!("{bpm} bpm"))
+            }}
+        </p>
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+        <Note>
+            "Battery level and heart rate are only shown for devices that provide these services. "
+            "The heart rate subscription is renewed after reconnecting."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
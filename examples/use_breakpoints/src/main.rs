@@ -13,10 +13,11 @@ fn Demo() -> impl IntoView {
     let sm_width = *breakpoints.get(&Sm).expect("It's there!");
 
     let current = screen_size.current();
+    let active = screen_size.active();
     let xs = screen_size.lt(Sm);
     let xse = screen_size.le(Sm);
-    let sm = screen_size.between(Sm, Md);
-    let md = screen_size.between(Md, Lg);
+    let sm = screen_size.only(Sm);
+    let md = screen_size.only(Md);
     let lg = screen_size.between(Lg, Xl);
     let xl = screen_size.between(Xl, Xxl);
     let xxl = screen_size.ge(Xxl);
@@ -29,6 +30,9 @@ fn Demo() -> impl IntoView {
             <div class=label_classes.clone()>"Current breakpoints :"</div>
             <code>{move || format!("{:?}", current.get())}</code>
 
+            <div class=label_classes.clone()>"Active breakpoint :"</div>
+            <code>{move || format!("{:?}", active.get())}</code>
+
             <div class=label_classes.clone()>
                 <code class="font-bold">"xs"</code>
                 <small>" (< " {move || sm_width.to_string()} "px)"</small>
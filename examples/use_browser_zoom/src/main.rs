@@ -0,0 +1,36 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_browser_zoom, UseBrowserZoomReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseBrowserZoomReturn {
+        zoom,
+        pinch_zoom,
+        is_pinch_zoomed,
+        pixel_ratio,
+    } = use_browser_zoom();
+
+    view! {
+        <pre>
+            {move || format!("zoom: {:.0}%", zoom.get() * 100.0)} <br/>
+            {move || format!("pinch zoom: {:.2}", pinch_zoom.get())} <br/>
+            {move || format!("pixel ratio: {}", pixel_ratio.get())}
+        </pre>
+        <div>"Pinch zoomed: " <BooleanDisplay value=is_pinch_zoomed/></div>
+        <Note>
+            "Zoom in and out with Ctrl + / Ctrl - or pinch zoom on a touch device or trackpad to see the values change."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
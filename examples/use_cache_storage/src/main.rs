@@ -0,0 +1,93 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_cache_storage_with_options, UseCacheStorageOptions, UseCacheStorageReturn};
+use web_sys::Response;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseCacheStorageReturn {
+        is_supported,
+        keys,
+        size,
+        cache,
+    } = use_cache_storage_with_options(
+        "leptos-use-demo",
+        UseCacheStorageOptions::default().measure_size(true),
+    );
+
+    let (path, set_path) = signal("/notes/1".to_string());
+    let (content, set_content) = signal("Hello from the cache!".to_string());
+    let (matched, set_matched) = signal(None::<String>);
+
+    let store = move |_| {
+        leptos::task::spawn_local(async move {
+            if let Ok(response) = Response::new_with_opt_str(Some(&content.get_untracked())) {
+                let _ = cache.put(path.get_untracked(), &response).await;
+            }
+        });
+    };
+
+    let read = move |_| {
+        leptos::task::spawn_local(async move {
+            let text = match cache.match_request(path.get_untracked()).await {
+                Ok(Some(response)) => match response.text() {
+                    Ok(promise) => wasm_bindgen_futures::JsFuture::from(promise)
+                        .await
+                        .ok()
+                        .and_then(|text| text.as_string()),
+                    Err(_) => None,
+                },
+                _ => None,
+            };
+
+            set_matched.set(Some(text.unwrap_or_else(|| "Not cached".to_string())));
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <input
+            type="text"
+            prop:value=path
+            on:input=move |ev| set_path.set(event_target_value(&ev))
+        />
+        <textarea
+            class="block w-full"
+            prop:value=content
+            on:input=move |ev| set_content.set(event_target_value(&ev))
+        ></textarea>
+        <button on:click=store>"Put"</button>
+        <button on:click=read>"Match"</button>
+        <button on:click=move |_| {
+            leptos::task::spawn_local(async move {
+                let _ = cache.clear().await;
+            })
+        }>"Clear"</button>
+        <p>"Matched: " {move || matched.get()}</p>
+        <p>"Size: " {move || size.get().map(|size| format!("{size} bytes"))}</p>
+        <ul>
+            <For each=move || keys.get() key=|key| key.clone() let:key>
+                <li>
+                    {key.clone()}
+                    <button on:click=move |_| {
+                        let key = key.clone();
+                        leptos::task::spawn_local(async move {
+                            let _ = cache.delete(key).await;
+                        })
+                    }>"Delete"</button>
+                </li>
+            </For>
+        </ul>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
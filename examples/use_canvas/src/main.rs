@@ -0,0 +1,74 @@
+use leptos::html::Canvas;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_canvas_with_options, UseCanvasOptions, UseCanvasReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let canvas = NodeRef::<Canvas>::new();
+
+    let (angle, set_angle) = signal(0.0_f64);
+    let (fps, set_fps) = signal(0.0_f64);
+
+    let UseCanvasReturn {
+        width,
+        height,
+        pixel_ratio,
+        is_active,
+        pause,
+        resume,
+        ..
+    } = use_canvas_with_options(
+        canvas,
+        UseCanvasOptions::default()
+            .max_fps(Some(60.0))
+            .on_draw(move |args| {
+                let Some(context) = args.context.as_2d() else {
+                    return;
+                };
+
+                if args.delta > 0.0 {
+                    set_fps.set(1000.0 / args.delta);
+                }
+
+                let angle = angle.get_untracked() + args.delta / 1000.0;
+                set_angle.set(angle);
+
+                let width = args.width as f64;
+                let height = args.height as f64;
+                let size = width.min(height) * 0.3;
+
+                context.clear_rect(0.0, 0.0, width, height);
+                context.save();
+                let _ = context.translate(width / 2.0, height / 2.0);
+                let _ = context.rotate(angle);
+                context.set_fill_style_str("#3b82f6");
+                context.fill_rect(-size / 2.0, -size / 2.0, size, size);
+                context.restore();
+            }),
+    );
+
+    view! {
+        <canvas node_ref=canvas style="width: 100%; height: 300px;"></canvas>
+        <p>
+            "Backing store: " {width} " x " {height} " (pixel ratio "
+            {pixel_ratio} ")"
+        </p>
+        <p>"Drawing at " {move || format!("{:.0}", fps.get())} " fps"</p>
+        <p>"Running: " <BooleanDisplay value=is_active/></p>
+        <button on:click=move |_| pause()>"Pause"</button>
+        <button on:click=move |_| resume()>"Resume"</button>
+        <Note>"Resize the window or zoom in to see the backing store follow the element size."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
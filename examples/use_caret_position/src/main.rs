@@ -0,0 +1,75 @@
+use leptos::html::{Div, Textarea};
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_caret_position, UseCaretPositionReturn};
+
+#[component]
+fn CaretMarker(
+    x: Signal<f64>,
+    y: Signal<f64>,
+    height: Signal<f64>,
+    is_active: Signal<bool>,
+) -> impl IntoView {
+    view! {
+        <Show when=move || is_active.get()>
+            <div
+                class="fixed px-2 py-1 text-xs rounded bg-[--brand-color] text-white pointer-events-none"
+                style:left=move || format!("{}px", x.get())
+                style:top=move || format!("{}px", y.get() + height.get())
+            >
+                "@mention"
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let textarea = NodeRef::<Textarea>::new();
+    let editable = NodeRef::<Div>::new();
+
+    let UseCaretPositionReturn {
+        x,
+        y,
+        height,
+        is_active,
+        ..
+    } = use_caret_position(textarea);
+
+    let UseCaretPositionReturn {
+        x: editable_x,
+        y: editable_y,
+        height: editable_height,
+        is_active: editable_is_active,
+        ..
+    } = use_caret_position(editable);
+
+    view! {
+        <textarea node_ref=textarea class="w-full h-24" placeholder="Type here..."></textarea>
+        <CaretMarker x y height is_active/>
+
+        <div node_ref=editable contenteditable="true" class="p-2 border rounded">
+            "Or edit this content"
+        </div>
+        <CaretMarker
+            x=editable_x
+            y=editable_y
+            height=editable_height
+            is_active=editable_is_active
+        />
+
+        <p>{move || format!("x: {:.0}, y: {:.0}, height: {:.0}", x.get(), y.get(), height.get())}</p>
+        <Note>"The marker follows the caret."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,125 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_chunked_task_with_options, ChunkScheduler, UseChunkedTaskOptions, UseChunkedTaskReturn,
+};
+
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (use_idle, set_use_idle) = signal(false);
+    let (primes, set_primes) = signal(0_usize);
+
+    let task = move |scheduler| {
+        use_chunked_task_with_options(
+            move || 0..2_000_000_u64,
+            move |n| {
+                if is_prime(n) {
+                    set_primes.update_untracked(|primes| *primes += 1);
+                }
+            },
+            UseChunkedTaskOptions::default().scheduler(scheduler),
+        )
+    };
+
+    let UseChunkedTaskReturn {
+        is_running,
+        processed,
+        progress,
+        start,
+        abort,
+        ..
+    } = task(ChunkScheduler::AnimationFrame);
+
+    let UseChunkedTaskReturn {
+        is_running: is_running_idle,
+        processed: processed_idle,
+        progress: progress_idle,
+        start: start_idle,
+        abort: abort_idle,
+        ..
+    } = task(ChunkScheduler::Idle);
+
+    let start = move |_| {
+        set_primes.set(0);
+
+        if use_idle.get_untracked() {
+            start_idle();
+        } else {
+            start();
+        }
+    };
+
+    let abort = move |_| {
+        abort();
+        abort_idle();
+    };
+
+    let running = Signal::derive(move || is_running.get() || is_running_idle.get());
+
+    let current_progress = move || {
+        let progress = if use_idle.get() {
+            progress_idle.get()
+        } else {
+            progress.get()
+        };
+
+        progress.unwrap_or_default()
+    };
+
+    let count = move || {
+        if use_idle.get() {
+            processed_idle.get()
+        } else {
+            processed.get()
+        }
+    };
+
+    view! {
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=use_idle
+                on:change=move |e| set_use_idle.set(event_target_checked(&e))
+            />
+            " Use idle callbacks instead of animation frames"
+        </label>
+
+        <div class="my-2">
+            <button on:click=start disabled=running>"Count primes"</button>
+            <button on:click=abort disabled=move || !running.get()>"Abort"</button>
+        </div>
+
+        <progress class="w-full" max="1" value=current_progress></progress>
+
+        <p>"Progress: " {move || format!("{:.1}%", current_progress() * 100.0)}</p>
+        <p>"Checked numbers: " {count}</p>
+        // `primes` is updated untracked so it is rendered whenever the count changes
+        <p>"Primes found: " {move || {
+            count();
+            primes.get_untracked()
+        }}</p>
+        <p>"Running: " <BooleanDisplay value=running /></p>
+
+        <Note>"The page stays responsive while two million numbers are checked"</Note>
+        <div class="animate-spin w-6 h-6 border-4 border-gray-400/30 border-t-[--brand-color] rounded-full"></div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo /> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,88 @@
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_collator, use_sorted_by, CollatorSensitivity, UseCollatorOptions};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (locale, set_locale) = signal("en".to_string());
+    let (numeric, set_numeric) = signal(true);
+    let (query, set_query) = signal(String::new());
+
+    let names = vec![
+        "Zoë", "Åsa", "anna", "Anna", "Émile", "ezra", "Ölaf", "Oskar", "item 10", "item 2",
+        "item 1",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect::<Vec<_>>();
+
+    let collator = use_collator(Signal::derive(move || {
+        UseCollatorOptions::default()
+            .locale(&locale.get())
+            .numeric(numeric.get())
+    }));
+
+    let sorted: Signal<Vec<String>> = use_sorted_by(names.clone(), collator.comparator());
+
+    let search_collator = use_collator(Signal::derive(move || {
+        UseCollatorOptions::default()
+            .locale(&locale.get())
+            .sensitivity(CollatorSensitivity::Base)
+    }));
+
+    let matches = Signal::derive(move || {
+        let query = query.get();
+
+        names
+            .iter()
+            .filter(|name| {
+                name.chars().count() >= query.chars().count()
+                    && search_collator.compare(
+                        &name.chars().take(query.chars().count()).collect::<String>(),
+                        &query,
+                    ) == std::cmp::Ordering::Equal
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(", ")
+    });
+
+    view! {
+        <p>
+            <select on:change=move |e| set_locale.set(event_target_value(&e))>
+                <option value="en">"English"</option>
+                <option value="de">"German"</option>
+                <option value="sv">"Swedish"</option>
+            </select>
+            " "
+            <label>
+                <input
+                    type="checkbox"
+                    prop:checked=numeric
+                    on:change=move |e| set_numeric.set(event_target_checked(&e))
+                />
+                " Numeric"
+            </label>
+        </p>
+        <p>"Sorted: " {move || sorted.get().join(", ")}</p>
+        <p>
+            <input
+                type="text"
+                placeholder="Search ignoring accents and case"
+                on:input=move |e| set_query.set(event_target_value(&e))
+            />
+        </p>
+        <p>"Matches: " {matches}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -7,8 +7,14 @@ use leptos_use::{
 
 #[component]
 fn Demo() -> impl IntoView {
-    let UseColorModeReturn { mode, set_mode, .. } = use_color_mode_with_options(
+    let UseColorModeReturn {
+        mode,
+        set_mode,
+        is_auto,
+        ..
+    } = use_color_mode_with_options(
         UseColorModeOptions::default()
+            .view_transition(true)
             .custom_modes(vec![
                 "rust".into(),
                 "coal".into(),
@@ -37,6 +43,9 @@ fn Demo() -> impl IntoView {
 
     view! {
         <button on:click=move |_| next()>{move || format!("{}", state.get())}</button>
+        <button disabled=is_auto on:click=move |_| set_mode.set(ColorMode::Auto)>
+            "Follow system"
+        </button>
         <Note>"Click to change the color mode"</Note>
     }
 }
@@ -0,0 +1,68 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_confirm, UseConfirmResult, UseConfirmReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseConfirmReturn {
+        is_revealed,
+        payload,
+        confirm,
+        accept,
+        cancel,
+    } = use_confirm::<String>();
+
+    let (message, set_message) = signal("Nothing deleted yet".to_string());
+
+    let delete = move |item: &'static str| {
+        let confirm = confirm.clone();
+
+        spawn_local(async move {
+            let result = confirm(format!("Do you really want to delete {item}?")).await;
+
+            set_message.set(match result {
+                UseConfirmResult::Confirmed => format!("Deleted {item}"),
+                UseConfirmResult::Canceled => format!("Kept {item}"),
+            });
+        });
+    };
+
+    view! {
+        <div class="flex gap-2">
+            <button on:click={
+                let delete = delete.clone();
+                move |_| delete("the first item")
+            }>"Delete first item"</button>
+            <button on:click=move |_| delete("the second item")>"Delete second item"</button>
+        </div>
+        <p>{message}</p>
+
+        <Show when=move || is_revealed.get()>
+            <div class="p-4 border rounded">
+                <p>{payload}</p>
+                <div class="flex gap-2">
+                    <button on:click={
+                        let accept = accept.clone();
+                        move |_| accept()
+                    }>"Yes"</button>
+                    <button on:click={
+                        let cancel = cancel.clone();
+                        move |_| cancel()
+                    }>"No"</button>
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,56 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{
+    use_content_editable_with_options, ContentEditableMode, UseContentEditableOptions,
+    UseContentEditableReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let text_el = NodeRef::<Div>::new();
+    let html_el = NodeRef::<Div>::new();
+
+    let UseContentEditableReturn {
+        content: text,
+        set_content: set_text,
+    } = use_content_editable_with_options(
+        text_el,
+        UseContentEditableOptions::default().content("Edit this text".to_string()),
+    );
+
+    let UseContentEditableReturn { content: html, .. } = use_content_editable_with_options(
+        html_el,
+        UseContentEditableOptions::default()
+            .content("Some <b>bold</b> text".to_string())
+            .mode(ContentEditableMode::Html)
+            .debounce(500.0)
+            .sanitize_paste(|html| html.replace("<img", "<img hidden")),
+    );
+
+    view! {
+        <h3>"Text"</h3>
+        <div node_ref=text_el contenteditable="true" class="p-2 border rounded"></div>
+        <pre>{text}</pre>
+        <button on:click=move |_| {
+            set_text.update(|text| text.push_str(" (appended)"))
+        }>"Append text"</button>
+
+        <h3>"HTML (debounced)"</h3>
+        <div node_ref=html_el contenteditable="true" class="p-2 border rounded"></div>
+        <pre>{html}</pre>
+
+        <Note>"Appending text keeps the caret where it is. Images pasted into the HTML editor are hidden."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,115 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_credentials, CredentialMediation, GetCredentialOptions, UseCredentialsReturn,
+    UserCredential,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseCredentialsReturn {
+        is_supported,
+        credential,
+        is_pending,
+        client,
+    } = use_credentials();
+
+    let (username, set_username) = signal(String::new());
+    let (password, set_password) = signal(String::new());
+    let (signed_in_as, set_signed_in_as) = signal(None::<String>);
+    let (error, set_error) = signal(None::<String>);
+
+    leptos::task::spawn_local(async move {
+        if let Ok(Some(credential)) = client.silent_sign_in().await {
+            set_signed_in_as.set(Some(credential.id().to_string()));
+        }
+    });
+
+    let sign_in = move |_| {
+        let id = username.get_untracked();
+        if id.is_empty() {
+            return;
+        }
+
+        // a real app would verify the password with its server first
+        set_signed_in_as.set(Some(id.clone()));
+
+        leptos::task::spawn_local(async move {
+            if let Err(err) = client
+                .store(UserCredential::password(id, password.get_untracked()))
+                .await
+            {
+                set_error.set(Some(err.to_string()));
+            }
+        });
+    };
+
+    let choose_account = move |_| {
+        leptos::task::spawn_local(async move {
+            match client
+                .get(GetCredentialOptions::default().mediation(CredentialMediation::Required))
+                .await
+            {
+                Ok(Some(credential)) => set_signed_in_as.set(Some(credential.id().to_string())),
+                Ok(None) => {}
+                Err(err) => set_error.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    let sign_out = move |_| {
+        set_signed_in_as.set(None);
+
+        leptos::task::spawn_local(async move {
+            let _ = client.prevent_silent_access().await;
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Pending: " <BooleanDisplay value=is_pending/></p>
+        <Show
+            when=move || signed_in_as.get().is_some()
+            fallback=move || {
+                view! {
+                    <form on:submit=|e| e.prevent_default()>
+                        <input
+                            type="text"
+                            placeholder="Username"
+                            autocomplete="username"
+                            on:input=move |e| set_username.set(event_target_value(&e))
+                        />
+                        <input
+                            type="password"
+                            placeholder="Password"
+                            autocomplete="current-password"
+                            on:input=move |e| set_password.set(event_target_value(&e))
+                        />
+                        <button on:click=sign_in>"Sign in"</button>
+                        <button on:click=choose_account>"Choose saved account"</button>
+                    </form>
+                }
+            }
+        >
+            <p>"Signed in as " {signed_in_as}</p>
+            <button on:click=sign_out>"Sign out"</button>
+        </Show>
+        <p>
+            "Last credential: "
+            {move || credential.get().map(|credential| credential.id().to_string())}
+        </p>
+        <p>{error}</p>
+        <Note>"Reload the page after signing in to be signed in silently."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
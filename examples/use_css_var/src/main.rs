@@ -33,7 +33,21 @@ fn Demo() -> impl IntoView {
         )
     };
 
+    let el_external = NodeRef::<Div>::new();
+    let (highlighted, set_highlighted) = signal(false);
+    let (external_color, _) = use_css_var_with_options(
+        "--external-color",
+        UseCssVarOptions::default()
+            .target(el_external)
+            .detect_transitions(true)
+            .poll(Some(2000)),
+    );
+
     view! {
+        <style>
+            ".external { --external-color: #7fa998; color: var(--external-color); }
+            .external.highlighted { --external-color: #df8543; }"
+        </style>
         <div>
             <div node_ref=el style="--color: #7fa998; color: var(--color)">
                 "Sample text, "
@@ -51,6 +65,16 @@ fn Demo() -> impl IntoView {
             </div>
             <button on:click=change_var>"Change color variable"</button>
         </div>
+
+        <div>
+            <div node_ref=el_external class="external mt-4" class:highlighted=highlighted>
+                "Changed by a stylesheet: "
+                {external_color}
+            </div>
+            <button on:click=move |_| {
+                set_highlighted.update(|h| *h = !*h)
+            }>"Toggle class"</button>
+        </div>
     }
 }
 
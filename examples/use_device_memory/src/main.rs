@@ -0,0 +1,32 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::use_device_memory;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let device_memory = use_device_memory();
+
+    view! {
+        <p>
+            "Device memory: "
+            {move || {
+                device_memory
+                    .get()
+                    .map(|memory| format!("{memory} GB"))
+                    .unwrap_or_else(|| "not supported".to_string())
+            }}
+        </p>
+        <Note>"Browsers round the value down and cap it to prevent fingerprinting."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
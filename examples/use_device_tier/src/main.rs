@@ -0,0 +1,40 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_device_memory, use_device_tier, use_hardware_concurrency, DeviceTier};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let device_tier = use_device_tier();
+    let device_memory = use_device_memory();
+    let hardware_concurrency = use_hardware_concurrency();
+
+    view! {
+        <p>"Device tier: " {move || format!("{:?}", device_tier.get())}</p>
+        <p>"Device memory: " {move || device_memory.get().map(|memory| format!("{memory} GB"))}</p>
+        <p>"Logical processors: " {hardware_concurrency}</p>
+        <div
+            class="p-4 rounded"
+            style:background=move || {
+                if device_tier.get() > DeviceTier::Low {
+                    "linear-gradient(135deg, #6366f1, #ec4899)"
+                } else {
+                    "#6366f1"
+                }
+            }
+        >
+            "Expensive gradient for medium and high end devices, plain color for low end devices"
+        </div>
+        <Note>"The tier is a heuristic based on the device memory and the number of logical processors."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
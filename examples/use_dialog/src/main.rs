@@ -0,0 +1,51 @@
+use leptos::html::Dialog;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_dialog_with_options, UseDialogOptions, UseDialogReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Dialog>::new();
+
+    let UseDialogReturn {
+        is_open,
+        is_modal,
+        return_value,
+        open,
+        open_modal,
+        close,
+    } = use_dialog_with_options(
+        el,
+        UseDialogOptions::default().close_on_backdrop_click(true),
+    );
+
+    let close_yes = close.clone();
+
+    view! {
+        <div class="flex gap-2">
+            <button on:click=move |_| open_modal()>"Open Modal"</button>
+            <button on:click=move |_| open()>"Open"</button>
+        </div>
+        <p>"Open: " <BooleanDisplay value=is_open/></p>
+        <p>"Modal: " <BooleanDisplay value=is_modal/></p>
+        <p>"Return value: " {return_value}</p>
+        <dialog node_ref=el class="p-4 border rounded">
+            <p>"Do you like this demo?"</p>
+            <div class="flex gap-2">
+                <button on:click=move |_| close_yes(Some("yes".to_string()))>"Yes"</button>
+                <button on:click=move |_| close(Some("no".to_string()))>"No"</button>
+            </div>
+        </dialog>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,54 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_document_title_with_options, UseDocumentTitleOptions, UseDocumentTitleReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (count, set_count) = signal(0_usize);
+
+    let UseDocumentTitleReturn {
+        title,
+        set_title,
+        document_title,
+        is_blinking,
+        blink,
+        stop_blink,
+    } = use_document_title_with_options(
+        UseDocumentTitleOptions::default()
+            .title(Some("Demo".to_string()))
+            .template("{} – Leptos-Use")
+            .count(count),
+    );
+
+    view! {
+        <p>"Document title: " <b>{document_title}</b></p>
+        <input
+            type="text"
+            prop:value=move || title.get().unwrap_or_default()
+            on:input=move |e| set_title.set(Some(event_target_value(&e)))
+            placeholder="Title"
+        />
+        <p>
+            <button on:click=move |_| set_count.update(|count| *count += 1)>"Increment counter"</button>
+            <button on:click=move |_| set_count.set(0)>"Reset counter"</button>
+        </p>
+        <p>
+            <button on:click=move |_| blink("👋 Come back!".to_string())>"Blink"</button>
+            <button on:click=move |_| stop_blink()>"Stop blinking"</button>
+        </p>
+        <p>"Blinking: " <BooleanDisplay value=is_blinking/></p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
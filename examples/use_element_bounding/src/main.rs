@@ -1,7 +1,9 @@
 use leptos::html::Textarea;
 use leptos::prelude::*;
 use leptos_use::docs::{demo_or_body, Note};
-use leptos_use::{use_element_bounding, UseElementBoundingReturn};
+use leptos_use::{
+    use_element_bounding_with_options, UseElementBoundingOptions, UseElementBoundingReturn,
+};
 
 #[component]
 fn Demo() -> impl IntoView {
@@ -16,12 +18,13 @@ fn Demo() -> impl IntoView {
         bottom,
         x,
         y,
+        ready,
         ..
-    } = use_element_bounding(el);
+    } = use_element_bounding_with_options(el, UseElementBoundingOptions::default().poll(true));
 
     let text = move || {
         format!(
-            "width: {}\nheight: {}\nleft: {}\nright: {}\ntop: {}\nbottom: {}\nx: {}\ny: {}",
+            "width: {}\nheight: {}\nleft: {}\nright: {}\ntop: {}\nbottom: {}\nx: {}\ny: {}\nready: {}",
             width.get(),
             height.get(),
             left.get(),
@@ -29,7 +32,8 @@ fn Demo() -> impl IntoView {
             top.get(),
             bottom.get(),
             x.get(),
-            y.get()
+            y.get(),
+            ready.get(),
         )
     };
 
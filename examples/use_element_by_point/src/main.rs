@@ -0,0 +1,62 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_element_by_point_with_options, use_mouse, UseElementByPointOptions,
+    UseElementByPointReturn, UseMouseReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseMouseReturn { x, y, .. } = use_mouse();
+
+    let UseElementByPointReturn {
+        element,
+        elements,
+        is_active,
+        pause,
+        resume,
+        ..
+    } = use_element_by_point_with_options(x, y, UseElementByPointOptions::default().multiple(true));
+
+    let describe = |el: &web_sys::Element| {
+        let id = el.id();
+        if id.is_empty() {
+            el.tag_name().to_lowercase()
+        } else {
+            format!("{}#{}", el.tag_name().to_lowercase(), id)
+        }
+    };
+
+    view! {
+        <div id="outer" class="p-8 border rounded">
+            <div id="inner" class="p-8 border rounded">
+                <button id="button">"Hover me"</button>
+            </div>
+        </div>
+        <pre lang="yaml">
+            {move || {
+                format!(
+                    "element: {}\nelements: [{}]",
+                    element.get().as_ref().map(describe).unwrap_or_default(),
+                    elements.get().iter().map(describe).collect::<Vec<_>>().join(", "),
+                )
+            }}
+        </pre>
+        <p>
+            "Active: " <BooleanDisplay value=is_active/>
+        </p>
+        <button on:click=move |_| pause()>"Pause"</button>
+        <button on:click=move |_| resume()>"Resume"</button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -1,7 +1,7 @@
-use leptos::html::Button;
+use leptos::html::{Button, Div};
 use leptos::prelude::*;
 use leptos_use::docs::demo_or_body;
-use leptos_use::{use_element_hover_with_options, UseElementHoverOptions};
+use leptos_use::{use_element_hover_with_options, UseElementHoverIntent, UseElementHoverOptions};
 
 #[component]
 fn Demo() -> impl IntoView {
@@ -14,10 +14,25 @@ fn Demo() -> impl IntoView {
             .delay_leave(600),
     );
 
+    let intent_el = NodeRef::<Div>::new();
+
+    let is_intent_hovered = use_element_hover_with_options(
+        intent_el,
+        UseElementHoverOptions::default()
+            .intent(Some(UseElementHoverIntent::default()))
+            .delay_leave(300),
+    );
+
     view! {
         <button node_ref=el>
             {move || if is_hovered.get() { "Thank you!" } else { "Hover me" }}
         </button>
+
+        <h3>"Hover intent"</h3>
+        <p>"Quickly move the mouse across the box. It only reacts when you slow down."</p>
+        <div node_ref=intent_el class="p-4 border rounded">
+            {move || if is_intent_hovered.get() { "Menu open" } else { "Menu closed" }}
+        </div>
     }
 }
 
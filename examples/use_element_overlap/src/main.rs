@@ -0,0 +1,54 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::core::Position;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_draggable_with_options, use_element_overlap_with_options, UseDraggableOptions,
+    UseDraggableReturn, UseElementOverlapOptions, UseElementOverlapReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let target = NodeRef::<Div>::new();
+    let other = NodeRef::<Div>::new();
+
+    let UseDraggableReturn { style, .. } = use_draggable_with_options(
+        other,
+        UseDraggableOptions::default().initial_value(Position { x: 40.0, y: 40.0 }),
+    );
+
+    let UseElementOverlapReturn {
+        is_overlapping,
+        intersection,
+        ratio,
+    } = use_element_overlap_with_options(
+        target,
+        other,
+        UseElementOverlapOptions::default().poll(true),
+    );
+
+    view! {
+        <Note class="mb-4">"Drag the small box over the big one"</Note>
+        <div node_ref=target class="w-64 h-40 border-2 border-dashed rounded"></div>
+        <div
+            node_ref=other
+            class="fixed w-24 h-24 rounded bg-[--brand-color] cursor-move select-none"
+            style=move || format!("touch-action: none; {}", style.get())
+        ></div>
+        <p>"Overlapping: " <BooleanDisplay value=is_overlapping/></p>
+        <pre lang="yaml">
+            {move || format!("ratio: {:.2}\nintersection: {:?}", ratio.get(), intersection.get())}
+        </pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -1,13 +1,17 @@
 use leptos::html::Div;
 use leptos::prelude::*;
 use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
-use leptos_use::use_element_visibility;
+use leptos_use::{use_element_visibility_with_details, UseElementVisibilityReturn};
 
 #[component]
 fn Demo() -> impl IntoView {
     let el = NodeRef::<Div>::new();
 
-    let is_visible = use_element_visibility(el);
+    let UseElementVisibilityReturn {
+        is_visible,
+        offscreen_direction,
+        offscreen_distance,
+    } = use_element_visibility_with_details(el);
 
     view! {
         <div>
@@ -24,6 +28,17 @@ fn Demo() -> impl IntoView {
                 false_str="outside"
                 class="font-bold"
             /> " the viewport"
+            <Show when=move || offscreen_direction.get().is_some()>
+                <div>
+                    {move || {
+                        format!(
+                            "{:?} by {:.0}px",
+                            offscreen_direction.get().unwrap(),
+                            offscreen_distance.get(),
+                        )
+                    }}
+                </div>
+            </Show>
         </div>
     }
 }
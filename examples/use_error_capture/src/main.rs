@@ -0,0 +1,69 @@
+use leptos::prelude::*;
+use leptos::wasm_bindgen::JsValue;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{
+    use_error_capture_with_options, CapturedErrorKind, UseErrorCaptureOptions,
+    UseErrorCaptureReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseErrorCaptureReturn { errors, clear, .. } = use_error_capture_with_options(
+        UseErrorCaptureOptions::default()
+            .prevent_default(true)
+            .on_error(|error| leptos::logging::log!("captured: {}", error.message)),
+    );
+
+    let throw_error = move |_| {
+        let _ = leptos::web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback(&leptos::web_sys::js_sys::Function::new_no_args(
+                "throw new Error('Something broke')",
+            ));
+    };
+
+    let reject_promise = move |_| {
+        let _ = leptos::web_sys::js_sys::Promise::reject(&JsValue::from_str("Request failed"));
+    };
+
+    view! {
+        <button on:click=throw_error>"Throw error"</button>
+        <button on:click=reject_promise>"Reject promise"</button>
+        <button on:click=move |_| clear()>"Clear"</button>
+        <ul>
+            <For each=move || errors.get() key=|error| error.timestamp.to_bits() let:error>
+                <li>
+                    <strong>
+                        {match error.kind {
+                            CapturedErrorKind::Error => "Error",
+                            CapturedErrorKind::UnhandledRejection => "Unhandled rejection",
+                        }}
+                    </strong>
+                    ": "
+                    {error.message}
+                    {error
+                        .source
+                        .map(|source| {
+                            format!(
+                                " ({source}:{}:{})",
+                                error.line.unwrap_or_default(),
+                                error.column.unwrap_or_default(),
+                            )
+                        })}
+                </li>
+            </For>
+        </ul>
+        <Note>"The errors are not logged to the console because of the option prevent_default."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -1,8 +1,8 @@
 use leptos::ev::{click, keydown};
-use leptos::html::A;
+use leptos::html::{Ul, A};
 use leptos::logging::log;
 use leptos::prelude::*;
-use leptos_use::{use_event_listener, use_window};
+use leptos_use::{use_event_listener, use_event_listener_delegated, use_window};
 
 #[component]
 fn Demo() -> impl IntoView {
@@ -23,6 +23,14 @@ fn Demo() -> impl IntoView {
 
     let (cond, set_cond) = signal(true);
 
+    let list = NodeRef::<Ul>::new();
+    let (clicked_item, set_clicked_item) = signal(None::<String>);
+
+    // a single listener for all the items of the list
+    let _ = use_event_listener_delegated(list, click, "li", move |_, item| {
+        set_clicked_item.set(item.get_attribute("data-index"));
+    });
+
     view! {
         <p>"Check in the dev tools console"</p>
         <p>
@@ -54,6 +62,12 @@ fn Demo() -> impl IntoView {
                 " [click me]"
             </a>
         </Show>
+        <p>"Clicked item: " {move || clicked_item.get().unwrap_or_default()}</p>
+        <ul node_ref=list class="h-32 overflow-auto">
+            {(0..100)
+                .map(|i| view! { <li data-index=i class="cursor-pointer">"Item " <b>{i}</b></li> })
+                .collect_view()}
+        </ul>
     }
 }
 
@@ -0,0 +1,40 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_eye_dropper, UseEyeDropperReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseEyeDropperReturn {
+        is_supported,
+        color,
+        is_open,
+        open,
+        ..
+    } = use_eye_dropper();
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <button on:click=move |_| open() disabled=move || !is_supported.get() || is_open.get()>
+            "Open Eye Dropper"
+        </button>
+        <p class="flex items-center gap-2">
+            "Color: "
+            <span
+                class="inline-block w-6 h-6 rounded border border-gray-500/50"
+                style:background-color=move || color.get().unwrap_or_default()
+            ></span>
+            <code>{move || color.get().unwrap_or_else(|| "-".to_string())}</code>
+        </p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -1,11 +1,36 @@
 use leptos::prelude::*;
-use leptos_use::docs::demo_or_body;
-use leptos_use::{use_favicon_with_options, UseFaviconOptions};
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_favicon_with_options, FaviconBadge, UseFaviconOptions};
 
 #[component]
 fn Demo() -> impl IntoView {
-    let (_, set_icon) =
-        use_favicon_with_options(UseFaviconOptions::default().base_url("use_favicon/demo/img/"));
+    let (badge, set_badge) = signal(None::<FaviconBadge>);
+    let (is_animated, set_animated) = signal(false);
+
+    let (_, set_icon) = use_favicon_with_options(
+        UseFaviconOptions::default()
+            .base_url("use_favicon/demo/img/")
+            .badge(badge)
+            .animation(Signal::derive(move || {
+                if is_animated.get() {
+                    ["red", "green", "blue", "orange"]
+                        .map(|color| format!("favicon-{color}.svg"))
+                        .to_vec()
+                } else {
+                    vec![]
+                }
+            }))
+            .animation_interval(400),
+    );
+
+    let increment = move |_| {
+        set_badge.update(|badge| {
+            *badge = match badge {
+                Some(FaviconBadge::Count(count)) => Some(FaviconBadge::Count(*count + 1)),
+                _ => Some(FaviconBadge::Count(1)),
+            }
+        })
+    };
 
     let classes = "border border-solid border-b-4 rounded p-2 block border-gray-500/50 bg-[--bg] active:translate-y-1 active:border-b".to_string();
     let img_classes = "block".to_string();
@@ -98,6 +123,19 @@ fn Demo() -> impl IntoView {
                 />
             </a>
         </p>
+        <p>"Badge"</p>
+        <p class="flex gap-2">
+            <button on:click=increment>"+1"</button>
+            <button on:click=move |_| set_badge.set(Some(FaviconBadge::Dot))>"Dot"</button>
+            <button on:click=move |_| set_badge.set(None)>"Clear"</button>
+        </p>
+        <p>
+            <button on:click=move |_| set_animated.update(|animated| *animated = !*animated)>
+                "Toggle animation"
+            </button>
+            " Animated: "
+            <BooleanDisplay value=is_animated/>
+        </p>
     }
 }
 
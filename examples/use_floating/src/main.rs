@@ -0,0 +1,104 @@
+use leptos::html::{Button, Div};
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_floating_with_options, Placement, UseFloatingOptions, UseFloatingReturn};
+
+const PLACEMENTS: [Placement; 12] = [
+    Placement::Top,
+    Placement::TopStart,
+    Placement::TopEnd,
+    Placement::Right,
+    Placement::RightStart,
+    Placement::RightEnd,
+    Placement::Bottom,
+    Placement::BottomStart,
+    Placement::BottomEnd,
+    Placement::Left,
+    Placement::LeftStart,
+    Placement::LeftEnd,
+];
+
+#[component]
+fn Demo() -> impl IntoView {
+    let anchor = NodeRef::<Button>::new();
+    let floating = NodeRef::<Div>::new();
+    let arrow = NodeRef::<Div>::new();
+
+    let (requested_placement, set_requested_placement) = signal(Placement::Top);
+
+    let UseFloatingReturn {
+        floating_styles,
+        placement,
+        arrow_x,
+        arrow_y,
+        ..
+    } = use_floating_with_options(
+        anchor,
+        floating,
+        UseFloatingOptions::default()
+            .placement(requested_placement)
+            .offset(10.0)
+            .flip(true)
+            .shift(true)
+            .padding(8.0)
+            .arrow(Some(arrow)),
+    );
+
+    let arrow_styles = move || {
+        let side = match placement.get().side() {
+            leptos_use::core::Direction::Top => "bottom",
+            leptos_use::core::Direction::Bottom => "top",
+            leptos_use::core::Direction::Left => "right",
+            leptos_use::core::Direction::Right => "left",
+        };
+
+        format!(
+            "position: absolute; width: 8px; height: 8px; transform: rotate(45deg); background: inherit; {side}: -4px; left: {}; top: {};",
+            arrow_x.get().map(|x| format!("{x}px")).unwrap_or_default(),
+            arrow_y.get().map(|y| format!("{y}px")).unwrap_or_default(),
+        )
+    };
+
+    view! {
+        <Note class="mb-4">"Scroll the page or resize the window to see the tooltip flip and shift"</Note>
+        <select on:change=move |e| {
+            if let Ok(index) = event_target_value(&e).parse::<usize>() {
+                set_requested_placement.set(PLACEMENTS[index]);
+            }
+        }>
+            {PLACEMENTS
+                .iter()
+                .enumerate()
+                .map(|(index, placement)| {
+                    view! {
+                        <option value=index selected=*placement == Placement::Top>
+                            {format!("{placement:?}")}
+                        </option>
+                    }
+                })
+                .collect_view()}
+        </select>
+        <div class="flex justify-center py-24">
+            <button node_ref=anchor>"Anchor"</button>
+        </div>
+        <div
+            node_ref=floating
+            class="px-3 py-2 rounded bg-[--brand-color] text-white"
+            style=floating_styles
+        >
+            {move || format!("{:?}", placement.get())}
+            <div node_ref=arrow style=arrow_styles></div>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
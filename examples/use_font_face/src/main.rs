@@ -0,0 +1,55 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_font_face, FontFaceSource, UseFontFaceReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseFontFaceReturn {
+        status,
+        is_loaded,
+        fonts,
+        ready,
+        ..
+    } = use_font_face(vec![
+        FontFaceSource::url(
+            "Pacifico",
+            "https://fonts.gstatic.com/s/pacifico/v22/FwZY7-Qmy14u9lezJ-6H6Mk.woff2",
+        ),
+        FontFaceSource::url("Missing", "https://example.invalid/missing.woff2"),
+    ]);
+
+    let (ready_message, set_ready_message) = signal("Waiting for fonts...".to_string());
+
+    spawn_local(async move {
+        let status = ready().await;
+        set_ready_message.set(format!("All fonts finished loading with status {status:?}"));
+    });
+
+    view! {
+        <p>"Overall status: " {move || format!("{:?}", status.get())}</p>
+        <p>"All loaded: " <BooleanDisplay value=is_loaded/></p>
+        <ul>
+            {move || {
+                fonts
+                    .get()
+                    .into_iter()
+                    .map(|(family, status)| view! { <li>{family} ": " {format!("{status:?}")}</li> })
+                    .collect_view()
+            }}
+        </ul>
+        <p>{ready_message}</p>
+        <p style="font-family: Pacifico, sans-serif; font-size: 2rem">"Hello Fonts"</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
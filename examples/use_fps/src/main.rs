@@ -0,0 +1,50 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_fps, use_interval_fn, UseFpsReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseFpsReturn {
+        fps,
+        is_janky,
+        is_active,
+        pause,
+        resume,
+    } = use_fps();
+
+    let (blocking, set_blocking) = signal(false);
+
+    let _ = use_interval_fn(
+        move || {
+            if blocking.get_untracked() {
+                // busy wait to simulate expensive work
+                let start = leptos::web_sys::js_sys::Date::now();
+                while leptos::web_sys::js_sys::Date::now() - start < 50.0 {}
+            }
+        },
+        60,
+    );
+
+    view! {
+        <p>"FPS: " {move || format!("{:.0}", fps.get())}</p>
+        <p>"Janky: " <BooleanDisplay value=is_janky/></p>
+        <p>"Measuring: " <BooleanDisplay value=is_active/></p>
+        <button on:click=move |_| pause()>"Pause"</button>
+        <button on:click=move |_| resume()>"Resume"</button>
+        <button on:click=move |_| set_blocking.set(!blocking.get())>
+            {move || if blocking.get() { "Stop blocking" } else { "Block main thread" }}
+        </button>
+        <Note>"Blocking the main thread for 50ms every 60ms makes the page janky."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,45 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_fullscreen_with_options, OrientationLock, UseFullscreenOptions, UseFullscreenReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+
+    let UseFullscreenReturn {
+        is_supported,
+        is_fullscreen,
+        error,
+        toggle,
+        ..
+    } = use_fullscreen_with_options(
+        el,
+        UseFullscreenOptions::default().orientation_lock(OrientationLock::Landscape),
+    );
+
+    view! {
+        <div>"Supported: " <BooleanDisplay value=is_supported/></div>
+        <div node_ref=el class="p-4 bg-[--c-bg-soft]">
+            <div>"Fullscreen: " <BooleanDisplay value=is_fullscreen/></div>
+            <button on:click=move |_| toggle()>
+                {move || if is_fullscreen.get() { "Exit fullscreen" } else { "Go fullscreen" }}
+            </button>
+            <p>{move || error.get().map(|err| err.to_string())}</p>
+        </div>
+        <Note>"On mobile devices the screen is locked to landscape while fullscreen."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
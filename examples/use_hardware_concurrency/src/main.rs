@@ -0,0 +1,32 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::use_hardware_concurrency;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let hardware_concurrency = use_hardware_concurrency();
+
+    view! {
+        <p>
+            "Logical processors: "
+            {move || {
+                hardware_concurrency
+                    .get()
+                    .map(|concurrency| concurrency.to_string())
+                    .unwrap_or_else(|| "unknown".to_string())
+            }}
+        </p>
+        <Note>"Browsers may report fewer processors than the device actually has."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,128 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_hid, HidDeviceHandle, UseHidReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseHidReturn {
+        is_supported,
+        devices,
+        device,
+        input_report,
+        hid,
+    } = use_hid();
+
+    let (error, set_error) = signal(None::<String>);
+    let (report, set_report) = signal("01 ff 00 00".to_string());
+
+    let pair = move |_| {
+        leptos::task::spawn_local(async move {
+            match hid.request_device().await {
+                Ok(Some(device)) => {
+                    if let Err(err) = hid.open(&device).await {
+                        set_error.set(Some(err.to_string()));
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => set_error.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    let open = move |device: HidDeviceHandle| {
+        leptos::task::spawn_local(async move {
+            if let Err(err) = hid.open(&device).await {
+                set_error.set(Some(err.to_string()));
+            }
+        });
+    };
+
+    let send = move |_| {
+        let Some(device) = device.get_untracked() else {
+            return;
+        };
+
+        let bytes = report
+            .get_untracked()
+            .split_whitespace()
+            .filter_map(|byte| u8::from_str_radix(byte, 16).ok())
+            .collect::<Vec<_>>();
+
+        let Some((report_id, data)) = bytes.split_first() else {
+            return;
+        };
+        let (report_id, data) = (*report_id, data.to_vec());
+
+        leptos::task::spawn_local(async move {
+            if let Err(err) = device.send_report(report_id, &data).await {
+                set_error.set(Some(err.to_string()));
+            }
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <button on:click=pair>"Pair device"</button>
+        <ul>
+            {move || {
+                devices
+                    .get()
+                    .into_iter()
+                    .map(|device| {
+                        let label = format!(
+                            "{} ({:04x}:{:04x})",
+                            device.product_name(),
+                            device.vendor_id(),
+                            device.product_id(),
+                        );
+
+                        view! {
+                            <li>
+                                {label} " "
+                                <button on:click=move |_| open(device.clone())>"Open"</button>
+                            </li>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+        <p>"Opened: " {move || device.get().map(|device| device.product_name())}</p>
+        <p>
+            "Last input report: "
+            {move || {
+                input_report
+                    .get()
+                    .map(|report| {
+                        let data = report
+                            .data
+                            .iter()
+                            .map(|byte| format!("{byte:02x}"))
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        format!("#{} {}", report.report_id, data)
+                    })
+            }}
+        </p>
+        <input
+            type="text"
+            prop:value=report
+            on:input=move |e| set_report.set(event_target_value(&e))
+        />
+        <button on:click=send disabled=move || device.get().is_none()>
+            "Send report"
+        </button>
+        <p>{error}</p>
+        <Note>"Enter the report as hex bytes. The first byte is the report id."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,53 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_idle_callback_with_options, UseIdleCallbackOptions, UseIdleCallbackReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (log, set_log) = signal(Vec::<String>::new());
+
+    let UseIdleCallbackReturn {
+        is_supported,
+        is_pending,
+        schedule,
+        cancel,
+        flush,
+    } = use_idle_callback_with_options(
+        move |deadline| {
+            let message = if deadline.is_urgent() {
+                "Flushed urgently".to_string()
+            } else if deadline.did_timeout() {
+                "Timed out".to_string()
+            } else {
+                format!("Idle with {:.1}ms left", deadline.time_remaining())
+            };
+
+            set_log.update(|log| log.push(message));
+        },
+        UseIdleCallbackOptions::default().timeout(Some(3000)),
+    );
+
+    view! {
+        <p>"requestIdleCallback supported: " <BooleanDisplay value=is_supported /></p>
+        <p>"Pending: " <BooleanDisplay value=is_pending /></p>
+
+        <button on:click=move |_| schedule()>"Schedule"</button>
+        <button on:click=move |_| cancel()>"Cancel"</button>
+        <button on:click=move |_| flush()>"Flush"</button>
+
+        <Note>"Schedule and flush quickly to run the callback urgently"</Note>
+
+        <pre>{move || log.get().join("\n")}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo /> }
+    });
+
+    unmount_handle.forget();
+}
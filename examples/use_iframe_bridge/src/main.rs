@@ -0,0 +1,111 @@
+use codee::string::JsonSerdeCodec;
+use leptos::html::Iframe;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_iframe_bridge, UseIframeBridgeReturn};
+
+// A tiny page without Rust that answers the handshake, exposes `add` and calls `get_name`
+const IFRAME_DOC: &str = r#"
+<p id="greeting">I'm the iframe</p>
+<button id="ask">Ask parent for its name</button>
+<script>
+    const functions = {
+        __bridge_handshake: () => null,
+        add: ([a, b]) => a + b,
+    };
+
+    const pending = {};
+    let nextId = 1000;
+
+    addEventListener("message", (event) => {
+        const msg = event.data;
+        if (!msg || msg.channel !== "leptos-use") return;
+
+        if (msg.reply_to != null) {
+            pending[msg.reply_to]?.(JSON.parse(msg.data));
+            delete pending[msg.reply_to];
+        } else if (msg.id != null && functions[msg.type]) {
+            const result = functions[msg.type](JSON.parse(msg.data));
+            event.source.postMessage(
+                { channel: "leptos-use", type: msg.type, reply_to: msg.id, data: JSON.stringify(result) },
+                event.origin,
+            );
+        }
+    });
+
+    document.getElementById("ask").onclick = () => {
+        const id = nextId++;
+        pending[id] = (name) => document.getElementById("greeting").textContent = `Hello, ${name}!`;
+        parent.postMessage({ channel: "leptos-use", type: "get_name", id, data: "null" }, "*");
+    };
+</script>
+"#;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let iframe = NodeRef::<Iframe>::new();
+
+    let UseIframeBridgeReturn {
+        bridge,
+        state,
+        is_connected,
+        ..
+    } = use_iframe_bridge::<JsonSerdeCodec>(iframe);
+
+    let (name, set_name) = signal("Leptos".to_string());
+
+    bridge.expose("get_name", move |_: ()| name.get_untracked());
+
+    let (a, set_a) = signal(1);
+    let (b, set_b) = signal(2);
+    let (sum, set_sum) = signal(String::new());
+
+    let add = move |_| {
+        leptos::task::spawn_local(async move {
+            let result = bridge
+                .call::<(i32, i32), i32>("add", &(a.get_untracked(), b.get_untracked()))
+                .await;
+
+            set_sum.set(match result {
+                Ok(sum) => sum.to_string(),
+                Err(err) => err.to_string(),
+            });
+        });
+    };
+
+    view! {
+        <iframe node_ref=iframe srcdoc=IFRAME_DOC class="h-24"></iframe>
+        <div>"State: " {move || format!("{:?}", state.get())}</div>
+        <div>"Connected: " <BooleanDisplay value=is_connected/></div>
+        <div>
+            "My name: " <input bind:value=(name, set_name) />
+        </div>
+        <div>
+            <input
+                type="number"
+                prop:value=a
+                on:input=move |e| set_a.set(event_target_value(&e).parse().unwrap_or_default())
+            />
+            " + "
+            <input
+                type="number"
+                prop:value=b
+                on:input=move |e| set_b.set(event_target_value(&e).parse().unwrap_or_default())
+            />
+            <button on:click=add disabled=move || !is_connected.get()>"Add in iframe"</button>
+            " = " {sum}
+        </div>
+        <Note>"The iframe exposes `add` and calls `get_name` of this page."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,64 @@
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_image, UseImageReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (src, set_src) = signal("https://place.dog/300/200".to_string());
+
+    let UseImageReturn {
+        status,
+        is_loading,
+        is_error,
+        natural_width,
+        natural_height,
+        retry,
+        ..
+    } = use_image(src);
+
+    view! {
+        <input
+            class="block w-full"
+            prop:value=src
+            on:change=move |ev| set_src.set(event_target_value(&ev))
+        />
+        <button on:click=move |_| set_src.set("https://place.dog/does-not-exist.png".to_string())>
+            "Load broken image"
+        </button>
+
+        <p>"Status: " {move || format!("{:?}", status.get())}</p>
+        <p>"Natural size: " {move || format!("{} x {}", natural_width.get(), natural_height.get())}</p>
+
+        <Show
+            when=move || !is_loading.get()
+            fallback=|| view! { <div class="w-[300px] h-[200px] bg-gray-500/20 animate-pulse"></div> }
+        >
+            <Show
+                when=move || !is_error.get()
+                fallback={
+                    let retry = retry.clone();
+                    move || {
+                        let retry = retry.clone();
+                        view! {
+                            <p>"Failed to load the image."</p>
+                            <button on:click=move |_| retry()>"Retry"</button>
+                        }
+                    }
+                }
+            >
+                <img src=src />
+            </Show>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,103 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_image_capture, use_user_media, UseImageCaptureReturn, UseUserMediaReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video_ref = NodeRef::<leptos::html::Video>::new();
+
+    let UseUserMediaReturn {
+        stream,
+        enabled,
+        set_enabled,
+        ..
+    } = use_user_media();
+
+    Effect::new(move || {
+        let stream = stream.get().and_then(Result::ok);
+
+        if let Some(video) = video_ref.get() {
+            video.set_src_object(stream.as_ref());
+        }
+    });
+
+    let UseImageCaptureReturn {
+        is_supported,
+        photo,
+        frame,
+        capabilities,
+        zoom,
+        torch,
+        take_photo,
+        grab_frame,
+        set_zoom,
+        set_torch,
+    } = use_image_capture(stream);
+
+    let photo_url = Signal::derive_local(move || {
+        photo
+            .get()
+            .and_then(|blob| web_sys::Url::create_object_url_with_blob(&blob).ok())
+    });
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <button on:click=move |_| set_enabled.set(!enabled.get())>
+            {move || if enabled.get() { "Stop camera" } else { "Start camera" }}
+        </button>
+        <button on:click=move |_| take_photo() disabled=move || !enabled.get()>
+            "Take photo"
+        </button>
+        <button on:click=move |_| grab_frame() disabled=move || !enabled.get()>
+            "Grab frame"
+        </button>
+        <div>
+            <video node_ref=video_ref autoplay=true muted=true class="h-72 w-auto"></video>
+        </div>
+        <Show when=move || capabilities.get().zoom.is_some()>
+            <p>
+                "Zoom: "
+                <input
+                    type="range"
+                    min=move || capabilities.get().zoom.map(|zoom| zoom.min)
+                    max=move || capabilities.get().zoom.map(|zoom| zoom.max)
+                    step=move || capabilities.get().zoom.map(|zoom| zoom.step)
+                    prop:value=move || zoom.get().unwrap_or_default()
+                    on:input={
+                        let set_zoom = set_zoom.clone();
+                        move |e| set_zoom(event_target_value(&e).parse().unwrap_or_default())
+                    }
+                />
+            </p>
+        </Show>
+        <Show when=move || capabilities.get().torch>
+            <button on:click={
+                let set_torch = set_torch.clone();
+                move |_| set_torch(!torch.get())
+            }>{move || if torch.get() { "Torch off" } else { "Torch on" }}</button>
+        </Show>
+        <p>
+            "Last frame: "
+            {move || {
+                frame
+                    .get()
+                    .map(|frame| format!("{} x {}", frame.width(), frame.height()))
+                    .unwrap_or_else(|| "-".to_string())
+            }}
+        </p>
+        <Show when=move || photo_url.get().is_some()>
+            <img src=move || photo_url.get() class="h-72 w-auto"/>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
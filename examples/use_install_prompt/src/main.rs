@@ -0,0 +1,46 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_install_prompt, UseInstallPromptReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseInstallPromptReturn {
+        can_install,
+        is_installed,
+        prompt,
+    } = use_install_prompt();
+
+    let (outcome, set_outcome) = signal(None::<String>);
+
+    let install = move |_| {
+        let choice = prompt();
+
+        spawn_local(async move {
+            set_outcome.set(Some(format!("{:?}", choice.await)));
+        });
+    };
+
+    view! {
+        <p>"Can install: " <BooleanDisplay value=can_install/></p>
+        <p>"Is installed: " <BooleanDisplay value=is_installed/></p>
+        <button on:click=install disabled=move || !can_install.get()>
+            "Install"
+        </button>
+        <p>"Outcome: " {move || outcome.get().unwrap_or_else(|| "-".to_string())}</p>
+        <Note>
+            "The install prompt is only available in supporting browsers for sites with a web app manifest."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -16,13 +16,16 @@ fn Demo() -> impl IntoView {
         is_active,
         pause,
         resume,
+        ratio,
         ..
     } = use_intersection_observer_with_options(
         target,
         move |entries, _| {
             set_visible.set(entries[0].is_intersecting());
         },
-        UseIntersectionObserverOptions::default().root(Some(root)),
+        UseIntersectionObserverOptions::default()
+            .root(Some(root))
+            .thresholds(vec![0.0, 0.25, 0.5, 0.75, 1.0]),
     );
 
     view! {
@@ -62,6 +65,10 @@ fn Demo() -> impl IntoView {
             /> " the viewport"
         </div>
 
+        <div class="text-center">
+            {move || format!("Intersection ratio: {:.2}", ratio.get())}
+        </div>
+
         <style>
             "
             .root {
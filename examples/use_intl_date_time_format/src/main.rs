@@ -0,0 +1,88 @@
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{
+    use_intl_date_time_format, use_timestamp, DateTimeNumericWidth, DateTimeStyle, TimeZoneName,
+    UseIntlDateTimeFormatOptions,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let now = use_timestamp();
+
+    let (locale, set_locale) = signal("en-US".to_string());
+    let system_time_zone =
+        use_intl_date_time_format(UseIntlDateTimeFormatOptions::default()).resolved_time_zone();
+    let (selected_time_zone, set_time_zone) = signal(None::<String>);
+    let time_zone = Signal::derive(move || {
+        selected_time_zone
+            .get()
+            .unwrap_or_else(|| system_time_zone.get())
+    });
+
+    let date_time_format = use_intl_date_time_format(Signal::derive(move || {
+        UseIntlDateTimeFormatOptions::default()
+            .locale(&locale.get())
+            .date_style(DateTimeStyle::Full)
+            .time_style(DateTimeStyle::Long)
+            .time_zone(time_zone.get())
+    }));
+    let formatted = date_time_format.format(now);
+
+    let clock_format = use_intl_date_time_format(Signal::derive(move || {
+        UseIntlDateTimeFormatOptions::default()
+            .locale(&locale.get())
+            .hour(DateTimeNumericWidth::TwoDigit)
+            .minute(DateTimeNumericWidth::TwoDigit)
+            .second(DateTimeNumericWidth::TwoDigit)
+            .fractional_second_digits(1)
+            .time_zone_name(TimeZoneName::ShortOffset)
+            .time_zone(time_zone.get())
+    }));
+    let clock = clock_format.format(now);
+
+    let week_format = use_intl_date_time_format(Signal::derive(move || {
+        UseIntlDateTimeFormatOptions::default()
+            .locale(&locale.get())
+            .date_style(DateTimeStyle::Medium)
+            .time_zone(time_zone.get())
+    }));
+    let next_week =
+        week_format.format_range(now, Signal::derive(move || now.get() + 7.0 * 86_400_000.0));
+
+    view! {
+        <p>
+            <select on:change=move |e| set_locale.set(event_target_value(&e))>
+                <option value="en-US">"en-US"</option>
+                <option value="de-DE">"de-DE"</option>
+                <option value="ja-JP">"ja-JP"</option>
+                <option value="ar-EG">"ar-EG"</option>
+            </select>
+            " "
+            <select on:change=move |e| {
+                let value = event_target_value(&e);
+                set_time_zone.set((!value.is_empty()).then_some(value));
+            }>
+                <option value="">"System time zone"</option>
+                <option value="UTC">"UTC"</option>
+                <option value="America/New_York">"America/New_York"</option>
+                <option value="Asia/Kolkata">"Asia/Kolkata"</option>
+                <option value="Australia/Sydney">"Australia/Sydney"</option>
+            </select>
+        </p>
+        <p>"System time zone: " {system_time_zone}</p>
+        <p>"Now: " {formatted}</p>
+        <p>"Clock: " {clock}</p>
+        <p>"Next seven days: " {next_week}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 use leptos_use::docs::demo_or_body;
-use leptos_use::{use_intl_number_format, NumberStyle, UseIntlNumberFormatOptions};
+use leptos_use::{use_intl_number_format, Notation, NumberStyle, UseIntlNumberFormatOptions};
 
 #[component]
 fn Demo() -> impl IntoView {
@@ -29,6 +29,15 @@ fn Demo() -> impl IntoView {
     );
     let in_num = in_nf.format::<f64>(number);
 
+    let (locale, set_locale) = signal("en-US".to_string());
+
+    let compact_nf = use_intl_number_format(Signal::derive(move || {
+        UseIntlNumberFormatOptions::default()
+            .locale(&locale.get())
+            .notation(Notation::Compact)
+    }));
+    let compact_num = compact_nf.format::<f64>(number);
+
     view! {
         <input
             class="block"
@@ -43,6 +52,15 @@ fn Demo() -> impl IntoView {
         <p>"German currency (EUR): " {de_num}</p>
         <p>"Japanese currency (JPY): " {ja_num}</p>
         <p>"Indian 3 max significant digits: " {in_num}</p>
+        <p>
+            "Compact in "
+            <select on:change=move |e| set_locale.set(event_target_value(&e))>
+                <option value="en-US">"en-US"</option>
+                <option value="de-DE">"de-DE"</option>
+                <option value="ja-JP">"ja-JP"</option>
+                <option value="hi-IN">"hi-IN"</option>
+            </select> ": " {compact_num}
+        </p>
     }
 }
 
@@ -0,0 +1,84 @@
+use codee::string::JsonSerdeCodec;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_jwt_with_options, UseJwtOptions, UseJwtReturn};
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Debug)]
+struct Claims {
+    name: String,
+    admin: bool,
+}
+
+/// Creates an unsigned token that expires in `seconds`. Only for demonstration purposes,
+/// real tokens are issued by your server.
+fn create_token(seconds: f64) -> String {
+    let exp = (web_sys::js_sys::Date::now() / 1000.0 + seconds).floor();
+
+    let encode = |json: &str| {
+        window()
+            .btoa(json)
+            .unwrap_or_default()
+            .replace('+', "-")
+            .replace('/', "_")
+            .trim_end_matches('=')
+            .to_string()
+    };
+
+    format!(
+        "{}.{}.signature",
+        encode(r#"{"alg":"HS256","typ":"JWT"}"#),
+        encode(&format!(
+            r#"{{"sub":"1234567890","name":"Jane Doe","admin":true,"exp":{exp}}}"#
+        )),
+    )
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (token, set_token) = signal(Some(create_token(15.0)));
+    let (refresh_count, set_refresh_count) = signal(0);
+
+    let UseJwtReturn {
+        header,
+        claims,
+        registered_claims,
+        is_expired,
+        ..
+    } = use_jwt_with_options::<Claims, JsonSerdeCodec>(
+        token,
+        UseJwtOptions::default()
+            .refresh_ahead(5000)
+            .on_refresh(move || set_refresh_count.update(|count| *count += 1)),
+    );
+
+    view! {
+        <textarea
+            class="w-full"
+            rows="4"
+            prop:value=move || token.get().unwrap_or_default()
+            on:input=move |e| set_token.set(Some(event_target_value(&e)))
+        />
+        <button on:click=move |_| set_token.set(Some(create_token(15.0)))>
+            "Create token expiring in 15 s"
+        </button>
+        <p>"Algorithm: " {move || header.get().map(|header| header.alg)}</p>
+        <p>"Subject: " {move || registered_claims.get().and_then(|claims| claims.sub)}</p>
+        <p>"Name: " {move || claims.get().map(|claims| claims.name)}</p>
+        <p>"Admin: " <BooleanDisplay value=Signal::derive(move || claims.get().is_some_and(|claims| claims.admin))/></p>
+        <p>"Expired: " <BooleanDisplay value=is_expired/></p>
+        <p>"Refresh requested " {refresh_count} " times"</p>
+        <Note>"Refresh is requested 5 seconds before the token expires. The signature isn't verified."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,49 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_keyboard_avoiding_with_options, KeyboardAvoidingMode, UseKeyboardAvoidingOptions,
+    UseKeyboardAvoidingReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+
+    let UseKeyboardAvoidingReturn {
+        is_supported,
+        occluded_height,
+        is_keyboard_open,
+    } = use_keyboard_avoiding_with_options(
+        el,
+        UseKeyboardAvoidingOptions::default()
+            .mode(KeyboardAvoidingMode::Translate)
+            .offset(8.0),
+    );
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported /></p>
+        <p>"Keyboard open: " <BooleanDisplay value=is_keyboard_open /></p>
+        <p>"Occluded height: " {move || format!("{:.0}px", occluded_height.get())}</p>
+
+        <Note>"Open this demo on a phone and tap the input at the bottom of the screen"</Note>
+
+        <div
+            node_ref=el
+            class="fixed bottom-0 left-0 right-0 p-4 bg-[--bg] border-t border-gray-400/30 transition-transform"
+        >
+            <input class="w-full" placeholder="Type a message..." />
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo /> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,133 @@
+use leptos::html::Video;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_media_controls_with_options, MediaSource, MediaTextTrackSource, UseMediaControlsOptions,
+    UseMediaControlsReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video = NodeRef::<Video>::new();
+
+    let UseMediaControlsReturn {
+        playing,
+        current_time,
+        duration,
+        volume,
+        muted,
+        rate,
+        buffered,
+        waiting,
+        ended,
+        tracks,
+        selected_track,
+        enable_track,
+        disable_tracks,
+        ..
+    } = use_media_controls_with_options(
+        video,
+        UseMediaControlsOptions::default()
+            .src(vec![MediaSource::new(
+                "https://commondatastorage.googleapis.com/gtv-videos-bucket/sample/ElephantsDream.mp4",
+            )
+            .mime_type("video/mp4".to_string())])
+            .tracks(vec![MediaTextTrackSource::new(
+                "https://gist.githubusercontent.com/wheatjs/a85a65a82d87d7c098e1a0972ef1f726/raw",
+            )
+            .label("English")
+            .srclang("en")]),
+    );
+
+    let format_time =
+        |seconds: f64| format!("{}:{:02}", (seconds / 60.0) as u32, seconds as u32 % 60);
+
+    view! {
+        <video node_ref=video class="w-full max-w-xl" crossorigin="anonymous"></video>
+
+        <div class="flex items-center gap-2">
+            <button on:click=move |_| playing.update(|playing| *playing = !*playing)>
+                {move || if playing.get() { "Pause" } else { "Play" }}
+            </button>
+            <input
+                type="range"
+                min="0"
+                step="0.1"
+                max=duration
+                prop:value=current_time
+                on:input=move |ev| {
+                    current_time.set(event_target_value(&ev).parse().unwrap_or_default())
+                }
+            />
+            <span>
+                {move || format!("{} / {}", format_time(current_time.get()), format_time(duration.get()))}
+            </span>
+        </div>
+
+        <div class="flex items-center gap-2">
+            <button on:click=move |_| muted.update(|muted| *muted = !*muted)>
+                {move || if muted.get() { "Unmute" } else { "Mute" }}
+            </button>
+            <input
+                type="range"
+                min="0"
+                max="1"
+                step="0.01"
+                prop:value=volume
+                on:input=move |ev| volume.set(event_target_value(&ev).parse().unwrap_or_default())
+            />
+            <select on:change=move |ev| rate.set(event_target_value(&ev).parse().unwrap_or(1.0))>
+                <option value="0.5">"0.5x"</option>
+                <option value="1" selected>"1x"</option>
+                <option value="1.5">"1.5x"</option>
+                <option value="2">"2x"</option>
+            </select>
+        </div>
+
+        <div class="flex items-center gap-2">
+            "Subtitles: "
+            <button
+                on:click=move |_| disable_tracks()
+                class:font-bold=move || selected_track.get().is_none()
+            >
+                "Off"
+            </button>
+            <For each=move || tracks.get() key=|track| track.index let:track>
+                <button
+                    on:click={
+                        let enable_track = enable_track.clone();
+                        move |_| enable_track(track.index)
+                    }
+                    class:font-bold=move || selected_track.get() == Some(track.index)
+                >
+                    {track.label}
+                </button>
+            </For>
+        </div>
+
+        <p>"Waiting: " <BooleanDisplay value=waiting/></p>
+        <p>"Ended: " <BooleanDisplay value=ended/></p>
+        <p>
+            "Buffered: "
+            {move || {
+                buffered
+                    .get()
+                    .into_iter()
+                    .map(|(start, end)| format!("{} - {}", format_time(start), format_time(end)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }}
+        </p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
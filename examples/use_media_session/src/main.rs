@@ -0,0 +1,117 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_interval_fn, use_media_session_with_options, MediaSessionAction, MediaSessionArtwork,
+    MediaSessionMetadata, MediaSessionPlaybackState, MediaSessionPosition, UseMediaSessionOptions,
+};
+
+const TRACKS: [(&str, &str); 3] = [
+    ("Morning Walk", "The Leptonians"),
+    ("Reactive Dreams", "Signal & Effect"),
+    ("Fine-Grained Blues", "The Memos"),
+];
+
+const DURATION: f64 = 180.0;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (track, set_track) = signal(0_usize);
+    let (playing, set_playing) = signal(false);
+    let (position, set_position) = signal(0.0_f64);
+
+    let _ = use_interval_fn(
+        move || {
+            if playing.get_untracked() {
+                set_position.update(|position| *position = (*position + 1.0).min(DURATION));
+            }
+        },
+        1000,
+    );
+
+    let change_track = move |offset: isize| {
+        set_track.update(|track| {
+            *track = (*track as isize + offset).rem_euclid(TRACKS.len() as isize) as usize
+        });
+        set_position.set(0.0);
+    };
+
+    let is_supported = use_media_session_with_options(
+        UseMediaSessionOptions::default()
+            .metadata(Signal::derive(move || {
+                let (title, artist) = TRACKS[track.get()];
+
+                Some(
+                    MediaSessionMetadata::default()
+                        .title(title)
+                        .artist(artist)
+                        .album("Leptos-Use Demo")
+                        .artwork(vec![MediaSessionArtwork::new(
+                            "https://leptos-use.rs/logo.svg",
+                        )
+                        .mime_type("image/svg+xml".to_string())]),
+                )
+            }))
+            .playback_state(Signal::derive(move || {
+                if playing.get() {
+                    MediaSessionPlaybackState::Playing
+                } else {
+                    MediaSessionPlaybackState::Paused
+                }
+            }))
+            .position_state(Signal::derive(move || {
+                Some(MediaSessionPosition {
+                    duration: DURATION,
+                    position: position.get(),
+                    playback_rate: 1.0,
+                })
+            }))
+            .on_action(MediaSessionAction::Play, move |_| set_playing.set(true))
+            .on_action(MediaSessionAction::Pause, move |_| set_playing.set(false))
+            .on_action(MediaSessionAction::SeekTo, move |details| {
+                if let Some(time) = details.seek_time {
+                    set_position.set(time.clamp(0.0, DURATION));
+                }
+            })
+            .on_action(MediaSessionAction::SeekBackward, move |details| {
+                set_position.update(|position| {
+                    *position = (*position - details.seek_offset.unwrap_or(10.0)).max(0.0)
+                });
+            })
+            .on_action(MediaSessionAction::SeekForward, move |details| {
+                set_position.update(|position| {
+                    *position = (*position + details.seek_offset.unwrap_or(10.0)).min(DURATION)
+                });
+            })
+            .on_action(MediaSessionAction::PreviousTrack, move |_| change_track(-1))
+            .on_action(MediaSessionAction::NextTrack, move |_| change_track(1)),
+    );
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>
+            <strong>{move || TRACKS[track.get()].0}</strong>
+            " by "
+            {move || TRACKS[track.get()].1}
+        </p>
+        <p>{move || format!("{:.0}s / {:.0}s", position.get(), DURATION)}</p>
+        <button on:click=move |_| change_track(-1)>"Previous"</button>
+        <button on:click=move |_| set_playing.update(|playing| *playing = !*playing)>
+            {move || if playing.get() { "Pause" } else { "Play" }}
+        </button>
+        <button on:click=move |_| change_track(1)>"Next"</button>
+        <Note>
+            "Most browsers only show the media controls of the operating system while some media is playing on the page."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
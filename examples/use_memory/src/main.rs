@@ -0,0 +1,53 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_memory_with_options, UseMemoryOptions, UseMemoryReturn};
+
+fn format_bytes(bytes: f64) -> String {
+    format!("{:.1} MB", bytes / 1024.0 / 1024.0)
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseMemoryReturn {
+        is_supported,
+        memory,
+        is_measure_supported,
+        measured_bytes,
+        is_pressure_supported,
+        pressure,
+    } = use_memory_with_options(UseMemoryOptions::default().measure_user_agent_specific(true));
+
+    view! {
+        <p>"Heap statistics supported: " <BooleanDisplay value=is_supported/></p>
+        {move || {
+            memory
+                .get()
+                .map(|memory| {
+                    view! {
+                        <p>"Used: " {format_bytes(memory.used_js_heap_size)}</p>
+                        <p>"Allocated: " {format_bytes(memory.total_js_heap_size)}</p>
+                        <p>"Limit: " {format_bytes(memory.js_heap_size_limit)}</p>
+                    }
+                })
+        }}
+        <p>"Page memory supported: " <BooleanDisplay value=is_measure_supported/></p>
+        <p>
+            "Page memory: "
+            {move || measured_bytes.get().map_or_else(|| "-".to_string(), format_bytes)}
+        </p>
+        <p>"Pressure supported: " <BooleanDisplay value=is_pressure_supported/></p>
+        <p>"CPU pressure: " {move || format!("{:?}", pressure.get())}</p>
+        <Note>"Heap statistics are only available in Chromium based browsers."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
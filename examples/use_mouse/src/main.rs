@@ -33,6 +33,10 @@ fn Demo() -> impl IntoView {
             .coord_type(UseMouseCoordType::Custom(Extractor)),
     );
 
+    let mouse_in_percent = use_mouse_with_options(
+        UseMouseOptions::default().coord_type(UseMouseCoordType::element_percentage(el)),
+    );
+
     view! {
         <div node_ref=el>
             <p class="font-semibold">"Basic Usage"</p>
@@ -66,6 +70,21 @@ fn Demo() -> impl IntoView {
                 }}
 
             </pre>
+            <p class="font-semibold">"Element Percentage Usage"</p>
+            <pre lang="yaml">
+                {move || {
+                    format!(
+                        r#"    x: {:.1}%
+    y: {:.1}%
+    source_type: {:?}
+"#,
+                        mouse_in_percent.x.get(),
+                        mouse_in_percent.y.get(),
+                        mouse_in_percent.source_type.get(),
+                    )
+                }}
+
+            </pre>
         </div>
     }
 }
@@ -1,7 +1,7 @@
 use leptos::html::Div;
 use leptos::prelude::*;
 use leptos_use::docs::demo_or_body;
-use leptos_use::{use_mutation_observer_with_options, UseMutationObserverOptions};
+use leptos_use::{use_mutation_observer_typed_with_options, Mutation, UseMutationObserverOptions};
 use std::time::Duration;
 
 #[component]
@@ -11,16 +11,23 @@ fn Demo() -> impl IntoView {
     let (class_name, set_class_name) = signal(String::new());
     let (style, set_style) = signal(String::new());
 
-    use_mutation_observer_with_options(
+    use_mutation_observer_typed_with_options(
         el,
-        move |mutations, _| {
-            if let Some(mutation) = mutations.first() {
-                set_messages.update(move |messages| {
-                    messages.push(format!("{:?}", mutation.attribute_name()));
-                });
+        move |mutations| {
+            for mutation in mutations {
+                if let Mutation::AttributeChanged {
+                    name, old_value, ..
+                } = mutation
+                {
+                    set_messages.update(move |messages| {
+                        messages.push(format!("{name} (old value: {old_value:?})"));
+                    });
+                }
             }
         },
-        UseMutationObserverOptions::default().attributes(true),
+        UseMutationObserverOptions::default()
+            .attributes(true)
+            .attribute_old_value(true),
     );
 
     let _ = set_timeout_with_handle(
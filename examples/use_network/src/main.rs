@@ -0,0 +1,43 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_network, UseNetworkReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseNetworkReturn {
+        is_supported,
+        is_online,
+        online_at,
+        offline_at,
+        downlink,
+        downlink_max,
+        rtt,
+        save_data,
+        effective_type,
+        connection_type,
+    } = use_network();
+
+    view! {
+        <div>"Supported: " <BooleanDisplay value=is_supported/></div>
+        <div>"Online: " <BooleanDisplay value=is_online/></div>
+        <div>"Online at: " {move || format!("{:?}", online_at.get())}</div>
+        <div>"Offline at: " {move || format!("{:?}", offline_at.get())}</div>
+        <div>"Downlink: " {move || format!("{:?} Mbit/s", downlink.get())}</div>
+        <div>"Downlink max: " {move || format!("{:?} Mbit/s", downlink_max.get())}</div>
+        <div>"RTT: " {move || format!("{:?} ms", rtt.get())}</div>
+        <div>"Save data: " <BooleanDisplay value=save_data/></div>
+        <div>"Effective type: " {move || format!("{:?}", effective_type.get())}</div>
+        <div>"Connection type: " {move || format!("{:?}", connection_type.get())}</div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
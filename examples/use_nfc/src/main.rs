@@ -0,0 +1,95 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_nfc, NfcRecord, UseNfcReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseNfcReturn {
+        is_supported,
+        permission,
+        is_scanning,
+        message,
+        error,
+        nfc,
+    } = use_nfc();
+
+    let (text, set_text) = signal("Hello from Leptos".to_string());
+    let (status, set_status) = signal(None::<String>);
+
+    let scan = move |_| {
+        leptos::task::spawn_local(async move {
+            let _ = nfc.scan().await;
+        });
+    };
+
+    let write = move |_| {
+        set_status.set(Some("Tap a tag to write...".to_string()));
+
+        leptos::task::spawn_local(async move {
+            let result = nfc.write(vec![NfcRecord::text(text.get_untracked())]).await;
+
+            set_status.set(Some(match result {
+                Ok(()) => "Written".to_string(),
+                Err(err) => err.to_string(),
+            }));
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Permission: " {move || permission.get().to_string()}</p>
+        <p>"Scanning: " <BooleanDisplay value=is_scanning/></p>
+        <button on:click=scan disabled=is_scanning>"Scan"</button>
+        <button on:click=move |_| nfc.abort()>"Abort"</button>
+        <p>"Serial number: " {move || message.get().map(|message| message.serial_number)}</p>
+        <ul>
+            {move || {
+                message
+                    .get()
+                    .map(|message| {
+                        message
+                            .records
+                            .into_iter()
+                            .map(|record| {
+                                let label = match record {
+                                    NfcRecord::Empty => "Empty".to_string(),
+                                    NfcRecord::Text { text, lang } => {
+                                        format!("Text ({}): {text}", lang.unwrap_or_default())
+                                    }
+                                    NfcRecord::Url(url) => format!("URL: {url}"),
+                                    NfcRecord::Mime { media_type, data } => {
+                                        format!("{media_type}: {} bytes", data.len())
+                                    }
+                                    NfcRecord::Unknown { record_type, data, .. } => {
+                                        format!("{record_type}: {} bytes", data.len())
+                                    }
+                                };
+
+                                view! { <li>{label}</li> }
+                            })
+                            .collect_view()
+                    })
+            }}
+        </ul>
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+        <input
+            type="text"
+            prop:value=text
+            on:input=move |e| set_text.set(event_target_value(&e))
+        />
+        <button on:click=write>"Write text"</button>
+        <p>{status}</p>
+        <Note>"Web NFC is only available in Chrome on Android."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
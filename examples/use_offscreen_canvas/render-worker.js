@@ -0,0 +1,25 @@
+let ctx;
+let frames = 0;
+
+onmessage = (event) => {
+  if (event.data.canvas) {
+    ctx = event.data.canvas.getContext("2d");
+    return;
+  }
+
+  const command = JSON.parse(event.data);
+
+  if (command === "Clear") {
+    ctx.clearRect(0, 0, ctx.canvas.width, ctx.canvas.height);
+  } else if (command.Circle) {
+    const { x, y, radius, color } = command.Circle;
+
+    ctx.beginPath();
+    ctx.arc(x, y, radius, 0, 2 * Math.PI);
+    ctx.fillStyle = color;
+    ctx.fill();
+  }
+
+  frames += 1;
+  postMessage(JSON.stringify({ Rendered: { frames } }));
+};
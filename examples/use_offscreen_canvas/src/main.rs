@@ -0,0 +1,82 @@
+use codee::string::JsonSerdeCodec;
+use leptos::html::Canvas;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_offscreen_canvas, UseOffscreenCanvasReturn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+enum Message {
+    Clear,
+    Circle {
+        x: f64,
+        y: f64,
+        radius: f64,
+        color: String,
+    },
+    Rendered {
+        frames: usize,
+    },
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let canvas = NodeRef::<Canvas>::new();
+
+    let UseOffscreenCanvasReturn {
+        is_supported,
+        message,
+        post,
+        error,
+        ..
+    } = use_offscreen_canvas::<Message, JsonSerdeCodec, _, _>(canvas, "render-worker.js");
+
+    let rendered_frames = move || match message.get() {
+        Some(Message::Rendered { frames }) => frames,
+        _ => 0,
+    };
+
+    let on_click = {
+        let post = post.clone();
+
+        move |ev: leptos::ev::MouseEvent| {
+            let x: f64 = ev.offset_x().into();
+            let y: f64 = ev.offset_y().into();
+
+            post(&Message::Circle {
+                x,
+                y,
+                radius: 20.0,
+                color: format!("hsl({}, 80%, 60%)", (x + y) % 360.0),
+            })
+        }
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <canvas
+            node_ref=canvas
+            width="400"
+            height="300"
+            class="border border-current"
+            on:click=on_click
+        ></canvas>
+        <button on:click=move |_| post(&Message::Clear)>"Clear"</button>
+        <p>"Commands rendered by the worker: " {rendered_frames}</p>
+        <Show when=move || error.with(Option::is_some)>
+            <p>"Error: " {move || error.with(|error| error.as_ref().map(ToString::to_string))}</p>
+        </Show>
+        <Note>"Click on the canvas to let the worker draw a circle."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
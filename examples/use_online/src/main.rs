@@ -0,0 +1,50 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_online, use_online_with_options, UseOnlineOptions, UseOnlineReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseOnlineReturn { is_online, .. } = use_online();
+
+    let UseOnlineReturn {
+        is_online: is_verified_online,
+        last_verified_at,
+        latency,
+        verify,
+    } = use_online_with_options(
+        UseOnlineOptions::default()
+            .verify_url(Some("/index.html".to_string()))
+            .verify_interval(5000),
+    );
+
+    view! {
+        <p>"navigator.onLine: " <BooleanDisplay value=is_online/></p>
+        <p>"Verified online: " <BooleanDisplay value=is_verified_online/></p>
+        <p>
+            "Last verified at: "
+            {move || {
+                last_verified_at
+                    .get()
+                    .map(|at| {
+                        leptos::web_sys::js_sys::Date::new(&at.into())
+                            .to_locale_time_string("en-US")
+                            .as_string()
+                            .unwrap_or_default()
+                    })
+            }}
+        </p>
+        <p>"Latency: " {move || latency.get().map(|latency| format!("{latency:.0} ms"))}</p>
+        <button on:click=move |_| verify()>"Verify now"</button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,53 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_parallax_with_options, UseParallaxOptions, UseParallaxReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+
+    let UseParallaxReturn { tilt, roll, source } =
+        use_parallax_with_options(el, UseParallaxOptions::default().easing(0.15));
+
+    let card_style = move || {
+        format!(
+            "transform: perspective(400px) rotateX({}deg) rotateY({}deg); transition: box-shadow 0.3s",
+            roll.get() * 20.0,
+            tilt.get() * 20.0,
+        )
+    };
+
+    view! {
+        <Note class="mb-4">"Move the mouse over the area or tilt your phone"</Note>
+        <div node_ref=el class="flex items-center justify-center h-[300px] border rounded">
+            <div
+                class="w-48 h-64 rounded-lg shadow-lg bg-gradient-to-br from-[--brand-color] to-[--theme-popup-bg] flex items-center justify-center"
+                style=card_style
+            >
+                "Parallax Card"
+            </div>
+        </div>
+        <pre lang="yaml">
+            {move || {
+                format!(
+                    "tilt: {:.3}\nroll: {:.3}\nsource: {:?}",
+                    tilt.get(),
+                    roll.get(),
+                    source.get(),
+                )
+            }}
+        </pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
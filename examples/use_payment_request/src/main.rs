@@ -0,0 +1,123 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_payment_request_with_options, PaymentAmount, PaymentComplete, PaymentDetails, PaymentItem,
+    PaymentMethod, PaymentShippingOption, UsePaymentRequestOptions, UsePaymentRequestReturn,
+};
+
+const PRICE: f64 = 9.99;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (quantity, set_quantity) = signal(1_u32);
+    let (express, set_express) = signal(false);
+    let (result, set_result) = signal(None::<String>);
+
+    let details = Signal::derive(move || {
+        let subtotal = PRICE * quantity.get() as f64;
+        let shipping = if express.get() { 10.0 } else { 0.0 };
+
+        PaymentDetails::new(PaymentItem::new(
+            "Total",
+            PaymentAmount::new("USD", format!("{:.2}", subtotal + shipping)),
+        ))
+        .display_items(vec![
+            PaymentItem::new(
+                format!("{} × T-shirt", quantity.get()),
+                PaymentAmount::new("USD", format!("{subtotal:.2}")),
+            ),
+            PaymentItem::new(
+                "Shipping",
+                PaymentAmount::new("USD", format!("{shipping:.2}")),
+            ),
+        ])
+        .shipping_options(vec![
+            PaymentShippingOption::new("standard", "Standard", PaymentAmount::new("USD", "0.00"))
+                .selected(!express.get()),
+            PaymentShippingOption::new("express", "Express", PaymentAmount::new("USD", "10.00"))
+                .selected(express.get()),
+        ])
+    });
+
+    let UsePaymentRequestReturn {
+        is_supported,
+        can_make_payment,
+        is_showing,
+        shipping_address,
+        shipping_option,
+        payment_request,
+    } = use_payment_request_with_options(
+        vec![PaymentMethod::new("https://bobbucks.dev/pay")],
+        details,
+        UsePaymentRequestOptions::default()
+            .request_payer_email(true)
+            .request_shipping(true),
+    );
+
+    Effect::new(move || {
+        if let Some(id) = shipping_option.get() {
+            set_express.set(id == "express");
+        }
+    });
+
+    let pay = move |_| {
+        leptos::task::spawn_local(async move {
+            match payment_request.show().await {
+                Ok(response) => {
+                    let _ = response.complete(PaymentComplete::Success).await;
+                    set_result.set(Some(format!(
+                        "Paid with {} by {}",
+                        response.method_name(),
+                        response.payer_email().unwrap_or_default()
+                    )));
+                }
+                Err(err) => set_result.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Can make payment: " <BooleanDisplay value=can_make_payment/></p>
+        <p>
+            "T-shirts: "
+            <button on:click=move |_| set_quantity.update(|q| *q = q.saturating_sub(1).max(1))>
+                "-"
+            </button> " " {quantity} " "
+            <button on:click=move |_| set_quantity.update(|q| *q += 1)>"+"</button>
+        </p>
+        <p>
+            <label>
+                <input
+                    type="checkbox"
+                    prop:checked=express
+                    on:change=move |e| set_express.set(event_target_checked(&e))
+                />
+                " Express shipping"
+            </label>
+        </p>
+        <p>"Total: $" {move || details.get().total.amount.value}</p>
+        <button on:click=pay disabled=is_showing>"Checkout"</button>
+        <p>
+            "Ship to: "
+            {move || {
+                shipping_address
+                    .get()
+                    .map(|address| format!("{} {}, {}", address.postal_code, address.city, address.country))
+            }}
+        </p>
+        <p>{result}</p>
+        <Note>"Uses the Bob Bucks demo payment app, no real money is involved."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,75 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_performance_observer, PerformanceEntryType, UsePerformanceObserverReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UsePerformanceObserverReturn {
+        is_supported,
+        entries,
+        largest_contentful_paint,
+        cumulative_layout_shift,
+        interaction_to_next_paint,
+        ..
+    } = use_performance_observer([
+        PerformanceEntryType::Paint,
+        PerformanceEntryType::LongTask,
+        PerformanceEntryType::LargestContentfulPaint,
+        PerformanceEntryType::LayoutShift,
+        PerformanceEntryType::Event,
+    ]);
+
+    let format_ms =
+        |value: Option<f64>| value.map_or_else(|| "-".to_string(), |v| format!("{v:.0} ms"));
+
+    let block_main_thread = move |_| {
+        // busy wait to create a long task
+        let start = leptos::web_sys::js_sys::Date::now();
+        while leptos::web_sys::js_sys::Date::now() - start < 200.0 {}
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"LCP: " {move || format_ms(largest_contentful_paint.get())}</p>
+        <p>
+            "CLS: "
+            {move || {
+                cumulative_layout_shift
+                    .get()
+                    .map_or_else(|| "-".to_string(), |v| format!("{v:.3}"))
+            }}
+        </p>
+        <p>"INP: " {move || format_ms(interaction_to_next_paint.get())}</p>
+        <button on:click=block_main_thread>"Run a long task"</button>
+        <Note>"Clicking the button blocks the main thread for 200ms which is reported as long task and slow interaction."</Note>
+        <ul>
+            {move || {
+                entries
+                    .get()
+                    .into_iter()
+                    .rev()
+                    .take(20)
+                    .map(|entry| {
+                        view! {
+                            <li>
+                                {entry.entry_type()} " " {entry.name()} ": "
+                                {format!("{:.0} ms", entry.duration())}
+                            </li>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
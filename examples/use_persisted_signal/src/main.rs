@@ -0,0 +1,80 @@
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_persisted_signal, use_persisted_signal_with_options, CookiePersistence,
+    IndexedDbPersistence, StoragePersistence, UsePersistedSignalOptions, UsePersistedSignalReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let count = RwSignal::new(0);
+    let UsePersistedSignalReturn {
+        is_loaded: count_loaded,
+        remove: remove_count,
+    } = use_persisted_signal::<_, FromToStringCodec, _>(
+        count,
+        "persisted-signal-count",
+        StoragePersistence::local(),
+    );
+
+    let text = RwSignal::new("Hello".to_string());
+    let UsePersistedSignalReturn {
+        is_loaded: text_loaded,
+        remove: remove_text,
+    } = use_persisted_signal_with_options::<_, FromToStringCodec, _>(
+        text,
+        "persisted-signal-text",
+        IndexedDbPersistence::new("leptos-use-demo", "persisted-signal"),
+        UsePersistedSignalOptions::default().debounce(500.0),
+    );
+
+    let visits = RwSignal::new(0);
+    let UsePersistedSignalReturn {
+        is_loaded: visits_loaded,
+        ..
+    } = use_persisted_signal::<_, FromToStringCodec, _>(
+        visits,
+        "persisted_signal_visits",
+        CookiePersistence::default().max_age(24 * 60 * 60 * 1000),
+    );
+
+    Effect::new(move |done: Option<bool>| {
+        if done != Some(true) && visits_loaded.get() {
+            visits.update(|visits| *visits += 1);
+            return true;
+        }
+        done.unwrap_or_default()
+    });
+
+    view! {
+        <h3>"Local storage"</h3>
+        <p>"Count: " {count}</p>
+        <p>"Loaded: " <BooleanDisplay value=count_loaded /></p>
+        <button on:click=move |_| count.update(|count| *count += 1)>"Increment"</button>
+        <button on:click=move |_| remove_count()>"Remove"</button>
+        <Note>"Open this page in a second tab to see the count being synced"</Note>
+
+        <h3 class="mt-6">"IndexedDB"</h3>
+        <input prop:value=text on:input=move |e| text.set(event_target_value(&e)) type="text" />
+        <p>"Loaded: " <BooleanDisplay value=text_loaded /></p>
+        <button on:click=move |_| remove_text()>"Remove"</button>
+        <Note>"Writes are debounced by 500ms"</Note>
+
+        <h3 class="mt-6">"Cookie"</h3>
+        <p>"Visits today: " {visits}</p>
+
+        <Note>"Reload the page to see that the values are persisted"</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo /> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,70 @@
+use leptos::html::{Div, Video};
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_picture_in_picture, use_picture_in_picture_with_options, UsePictureInPictureOptions,
+    UsePictureInPictureReturn,
+};
+
+const VIDEO_SRC: &str =
+    "https://commondatastorage.googleapis.com/gtv-videos-bucket/sample/BigBuckBunny.mp4";
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video = NodeRef::<Video>::new();
+    let player = NodeRef::<Div>::new();
+
+    let UsePictureInPictureReturn {
+        is_supported,
+        is_active,
+        width,
+        height,
+        toggle,
+        ..
+    } = use_picture_in_picture(video);
+
+    let UsePictureInPictureReturn {
+        is_document_pip_supported,
+        is_active: is_player_active,
+        toggle: toggle_player,
+        ..
+    } = use_picture_in_picture_with_options(
+        player,
+        UsePictureInPictureOptions::default().document_pip(true),
+    );
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <video node_ref=video src=VIDEO_SRC controls muted class="w-96"></video>
+        <button on:click=move |_| toggle() disabled=move || !is_supported.get()>
+            {move || if is_active.get() { "Exit Picture-in-Picture" } else { "Enter Picture-in-Picture" }}
+        </button>
+        <p>"Active: " <BooleanDisplay value=is_active/></p>
+        <p>"Window size: " {move || format!("{} x {}", width.get(), height.get())}</p>
+
+        <hr/>
+
+        <p>"Document Picture-in-Picture supported: " <BooleanDisplay value=is_document_pip_supported/></p>
+        <div node_ref=player class="p-4 rounded bg-gray-500/10">
+            <p>"This whole box can be moved into a Picture-in-Picture window."</p>
+            <input type="range" />
+        </div>
+        <button
+            on:click=move |_| toggle_player()
+            disabled=move || !is_document_pip_supported.get()
+        >
+            {move || if is_player_active.get() { "Move back" } else { "Pop out" }}
+        </button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,58 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_pointer_lock, UsePointerLockReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+
+    let UsePointerLockReturn {
+        is_supported,
+        is_locked,
+        movement_x,
+        movement_y,
+        lock,
+        ..
+    } = use_pointer_lock(el);
+
+    let (x, set_x) = signal(0.0);
+    let (y, set_y) = signal(0.0);
+
+    Effect::new(move |_| {
+        set_x.update(|x| *x += movement_x.get());
+    });
+    Effect::new(move |_| {
+        set_y.update(|y| *y += movement_y.get());
+    });
+
+    view! {
+        <div>"Supported: " <BooleanDisplay value=is_supported/></div>
+        <div>"Locked: " <BooleanDisplay value=is_locked/></div>
+        <div
+            node_ref=el
+            on:click=move |_| lock()
+            class="relative h-48 overflow-hidden bg-[--c-bg-soft] cursor-pointer"
+        >
+            <div
+                class="absolute w-4 h-4 rounded-full bg-[--c-text]"
+                style:transform=move || {
+                    format!("translate({}px, {}px)", x.get().rem_euclid(400.0), y.get().rem_euclid(192.0))
+                }
+            ></div>
+        </div>
+        <pre>{move || format!("movement: {}, {}", movement_x.get(), movement_y.get())}</pre>
+        <Note>"Click the box to lock the pointer and press Esc to unlock it."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,63 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_pointers_with_options, UsePointersOptions, UsePointersReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+
+    let UsePointersReturn { pointers, count } =
+        use_pointers_with_options(UsePointersOptions::default().target(el));
+
+    view! {
+        <Note class="mb-2">"Touch the area below with multiple fingers or press the mouse button"</Note>
+        <p>"Active pointers: " {count}</p>
+        <div
+            node_ref=el
+            class="relative h-[300px] border rounded overflow-hidden"
+            style="touch-action: none"
+        >
+            <For
+                each=move || pointers.get().into_values()
+                key=|pointer| pointer.id
+                let:pointer
+            >
+                <div
+                    class="fixed w-12 h-12 -ml-6 -mt-6 rounded-full bg-[--brand-color] opacity-50 pointer-events-none"
+                    style=move || {
+                        let pointer = pointers.get().get(&pointer.id).cloned();
+                        pointer
+                            .map(|p| {
+                                format!(
+                                    "left: {}px; top: {}px; transform: scale({})",
+                                    p.x,
+                                    p.y,
+                                    0.5 + p.pressure,
+                                )
+                            })
+                            .unwrap_or_default()
+                    }
+                ></div>
+            </For>
+        </div>
+        <pre>
+            {move || {
+                let mut pointers = pointers.get().into_values().collect::<Vec<_>>();
+                pointers.sort_by_key(|p| p.id);
+                format!("{pointers:#?}")
+            }}
+        </pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,46 @@
+use leptos::html::{Button, Div};
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_popover_with_options, UsePopoverOptions, UsePopoverReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Div>::new();
+    let button = NodeRef::<Button>::new();
+
+    let UsePopoverReturn {
+        is_supported,
+        is_open,
+        show,
+        hide,
+        toggle,
+    } = use_popover_with_options(el, UsePopoverOptions::default().ignore([button]));
+
+    view! {
+        <div class="flex gap-2">
+            <button node_ref=button on:click=move |_| toggle()>
+                "Toggle"
+            </button>
+            <button on:click=move |_| show()>"Show"</button>
+        </div>
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Open: " <BooleanDisplay value=is_open/></p>
+        <div node_ref=el class="p-4 border rounded">
+            <p>"Click outside or press Escape to close me."</p>
+            <button autofocus on:click=move |_| hide()>
+                "Close"
+            </button>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,78 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_preferred_languages, UsePreferredLanguagesReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UsePreferredLanguagesReturn {
+        languages,
+        primary,
+        override_language,
+        set_override_language,
+    } = use_preferred_languages();
+
+    view! {
+        <p>
+            "Override: "
+            <select on:change=move |e| {
+                let value = event_target_value(&e);
+                set_override_language.set((!value.is_empty()).then_some(value));
+            }>
+                <option value="" selected=move || override_language.get().is_none()>
+                    "Browser default"
+                </option>
+                <For each=move || ["en-GB", "de-CH", "fr-CA", "sr-Latn-RS", "zh-Hant-TW"] key=|tag| *tag let:tag>
+                    <option
+                        value=tag
+                        selected=move || override_language.get().as_deref() == Some(tag)
+                    >
+                        {tag}
+                    </option>
+                </For>
+            </select>
+        </p>
+        <p>"Primary: " {move || primary.get().map(|language| language.tag)}</p>
+        <table>
+            <thead>
+                <tr>
+                    <th>"Rank"</th>
+                    <th>"Tag"</th>
+                    <th>"Language"</th>
+                    <th>"Script"</th>
+                    <th>"Region"</th>
+                </tr>
+            </thead>
+            <tbody>
+                {move || {
+                    languages
+                        .get()
+                        .into_iter()
+                        .map(|language| {
+                            view! {
+                                <tr>
+                                    <td>{language.rank}</td>
+                                    <td>{language.tag}</td>
+                                    <td>{language.language}</td>
+                                    <td>{language.script}</td>
+                                    <td>{language.region}</td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </tbody>
+        </table>
+        <Note>"Change the languages in your browser settings to see the list update."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
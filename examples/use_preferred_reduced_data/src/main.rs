@@ -0,0 +1,30 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_preferred_contrast, use_preferred_reduced_data};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let is_reduced_data_preferred = use_preferred_reduced_data();
+    let preferred_contrast = use_preferred_contrast();
+
+    view! {
+        <p>"Prefers reduced data: " <BooleanDisplay value=is_reduced_data_preferred/></p>
+        <p>"Preferred contrast: " {move || preferred_contrast.get().to_string()}</p>
+        <Note>
+            "Enable the data saver mode of your browser or operating system to see the value change. "
+            "It's only picked up after reloading the page if the browser lacks support for the "
+            "prefers-reduced-data media query."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,63 @@
+use gloo_timers::future::sleep;
+use leptos::html::Button;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_prefetch_with_options, UsePrefetchOptions, UsePrefetchReturn};
+use std::time::Duration;
+
+#[component]
+fn PrefetchButton(label: &'static str, #[prop(optional)] on_visible: bool) -> impl IntoView {
+    let el = NodeRef::<Button>::new();
+    let (log, set_log) = signal(Vec::<String>::new());
+
+    let UsePrefetchReturn {
+        is_prefetching,
+        is_prefetched,
+        ..
+    } = use_prefetch_with_options(
+        el,
+        move || async move {
+            set_log.update(|log| log.push(format!("{label}: loading...")));
+            // simulate a slow request
+            sleep(Duration::from_millis(1500)).await;
+            set_log.update(|log| log.push(format!("{label}: done")));
+        },
+        UsePrefetchOptions::default().on_visible(on_visible),
+    );
+
+    view! {
+        <div class="my-4">
+            <button node_ref=el>{label}</button>
+            <span class="ml-2">"Prefetching: " <BooleanDisplay value=is_prefetching /></span>
+            <span class="ml-2">"Prefetched: " <BooleanDisplay value=is_prefetched /></span>
+            <pre>{move || log.get().join("\n")}</pre>
+        </div>
+    }
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    view! {
+        <Note>"Hover or focus a button to start prefetching its data"</Note>
+
+        <PrefetchButton label="Profile" />
+        <PrefetchButton label="Settings" />
+        <PrefetchButton label="Inbox" />
+
+        <div class="h-[80vh]"></div>
+
+        <Note>"This one is prefetched as soon as it scrolls into view"</Note>
+        <PrefetchButton label="Archive" on_visible=true />
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo /> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,36 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_print, use_print_with_options, UsePrintOptions, UsePrintReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let card = NodeRef::<Div>::new();
+
+    let UsePrintReturn { is_printing, print } = use_print();
+
+    let UsePrintReturn {
+        print: print_card, ..
+    } = use_print_with_options(UsePrintOptions::default().target(card));
+
+    view! {
+        <p>"Printing: " <BooleanDisplay value=is_printing/></p>
+        <div node_ref=card class="p-4 my-4 border rounded">
+            <h3>"Invoice #42"</h3>
+            <p>"1 x Leptos sticker ........ 2.00 €"</p>
+        </div>
+        <button on:click=move |_| print()>"Print page"</button>
+        <button on:click=move |_| print_card()>"Print card only"</button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -1,21 +1,28 @@
 use leptos::prelude::*;
 use leptos_use::docs::demo_or_body;
-use leptos_use::{use_raf_fn, utils::Pausable};
+use leptos_use::{use_raf_fn_with_options, utils::Pausable, UseRafFnCallbackArgs, UseRafFnOptions};
 
 #[component]
 fn Demo() -> impl IntoView {
     let (count, set_count) = signal(0);
+    let (elapsed, set_elapsed) = signal(0.0);
 
     let Pausable {
         pause,
         resume,
         is_active,
-    } = use_raf_fn(move |_| {
-        set_count.update(|count| *count += 1);
-    });
+    } = use_raf_fn_with_options(
+        move |UseRafFnCallbackArgs { elapsed, .. }| {
+            set_count.update(|count| *count += 1);
+            set_elapsed.set(elapsed);
+        },
+        UseRafFnOptions::default().fps_limit(30.0),
+    );
 
     view! {
         <div>Count: {count}</div>
+        <div>Elapsed: {move || format!("{:.1}s", elapsed.get() / 1000.0)}</div>
+        <div>Limited to 30 frames per second</div>
         <button on:click=move |_| pause() disabled=move || !is_active()>
             Pause
         </button>
@@ -0,0 +1,76 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_reporting_observer, UseReportingObserverReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseReportingObserverReturn {
+        is_supported,
+        reports,
+        csp_violations,
+        clear,
+        ..
+    } = use_reporting_observer();
+
+    let trigger_csp_violation = move |_| {
+        // blocked by the CSP meta tag in index.html
+        let document = document();
+        if let Ok(img) = document.create_element("img") {
+            let _ = img.set_attribute("src", "https://example.com/blocked.png");
+            let _ = document.body().unwrap().append_child(&img);
+        }
+    };
+
+    view! {
+        <p>"ReportingObserver supported: " <BooleanDisplay value=is_supported/></p>
+        <button on:click=trigger_csp_violation>"Trigger CSP violation"</button>
+        <button on:click=move |_| clear()>"Clear"</button>
+
+        <h3>"Reports"</h3>
+        <ul>
+            {move || {
+                reports
+                    .get()
+                    .into_iter()
+                    .map(|report| {
+                        view! {
+                            <li>
+                                {format!("{:?}", report.report_type)} ": "
+                                {report.message.unwrap_or_default()}
+                            </li>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+
+        <h3>"CSP violations"</h3>
+        <ul>
+            {move || {
+                csp_violations
+                    .get()
+                    .into_iter()
+                    .map(|violation| {
+                        view! {
+                            <li>
+                                {violation.effective_directive} " blocked " {violation.blocked_uri}
+                            </li>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+        <Note>"The page only allows images from its own origin."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
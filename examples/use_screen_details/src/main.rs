@@ -0,0 +1,73 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_screen_details, UseScreenDetailsReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseScreenDetailsReturn {
+        is_supported,
+        permission,
+        screens,
+        current_screen,
+        error,
+        screen_details,
+    } = use_screen_details();
+
+    let request = move |_| {
+        leptos::task::spawn_local(async move {
+            let _ = screen_details.request().await;
+        });
+    };
+
+    view! {
+        <div>"Supported: " <BooleanDisplay value=is_supported/></div>
+        <div>"Permission: " {move || permission.get().to_string()}</div>
+        <button on:click=request>"Detect screens"</button>
+        <Show when=move || error.with(Option::is_some)>
+            <p>{move || error.get().map(|err| err.to_string())}</p>
+        </Show>
+        <p>
+            "Current screen: "
+            {move || current_screen.get().map(|screen| screen.label).unwrap_or_default()}
+        </p>
+        <ul>
+            {move || {
+                screens
+                    .get()
+                    .into_iter()
+                    .map(|screen| {
+                        view! {
+                            <li>
+                                {format!(
+                                    "{} {}x{} at ({}, {}) {}",
+                                    screen.label,
+                                    screen.width,
+                                    screen.height,
+                                    screen.left,
+                                    screen.top,
+                                    if screen.is_primary { "primary" } else { "" },
+                                )}
+                                <button on:click=move |_| {
+                                    let _ = screen_details
+                                        .open_window("https://leptos-use.rs", screen.index);
+                                }>"Open window here"</button>
+                            </li>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+        <Note>"Connect a second screen to see the list change."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,43 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_script_tag, use_script_tag_with_options, UseScriptTagOptions, UseScriptTagReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseScriptTagReturn {
+        status,
+        is_loaded,
+        load,
+        unload,
+        ..
+    } = use_script_tag_with_options(
+        "https://cdn.jsdelivr.net/npm/canvas-confetti@1.9.3/dist/confetti.browser.min.js",
+        UseScriptTagOptions::default().immediate(false),
+    );
+
+    let UseScriptTagReturn { is_error, .. } =
+        use_script_tag("https://example.invalid/does-not-exist.js");
+
+    view! {
+        <div class="flex gap-2">
+            <button on:click=move |_| load()>"Load script"</button>
+            <button on:click=move |_| unload()>"Unload script"</button>
+        </div>
+        <p>"Status: " {move || format!("{:?}", status.get())}</p>
+        <p>"Loaded: " <BooleanDisplay value=is_loaded/></p>
+        <p>"Invalid script failed: " <BooleanDisplay value=is_error/></p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,73 @@
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+use leptos_use::core::ConnectionReadyState;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_serial_with_options, UseSerialOptions, UseSerialReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (lines, set_lines) = signal(Vec::<String>::new());
+    let (input, set_input) = signal(String::new());
+
+    let UseSerialReturn {
+        is_supported,
+        ready_state,
+        message,
+        error,
+        open,
+        close,
+        write,
+        ..
+    } = use_serial_with_options::<String, FromToStringCodec>(
+        UseSerialOptions::default()
+            .baud_rate(115_200)
+            .on_disconnect(move || {
+                set_lines.update(|lines| lines.push("-- disconnected --".to_string()))
+            }),
+    );
+
+    Effect::new(move |_| {
+        if let Some(message) = message.get() {
+            set_lines.update(|lines| lines.push(message));
+        }
+    });
+
+    let is_open = Signal::derive(move || ready_state.get() == ConnectionReadyState::Open);
+
+    let send = move |_| {
+        write(format!("{}\n", input.get_untracked()).as_bytes());
+        set_input.set(String::new());
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"State: " {move || ready_state.get().to_string()}</p>
+        <button on:click=move |_| open() disabled=is_open>"Connect"</button>
+        <button on:click=move |_| close() disabled=move || !is_open.get()>"Disconnect"</button>
+        <div>
+            <input
+                type="text"
+                placeholder="Command"
+                prop:value=input
+                on:input=move |e| set_input.set(event_target_value(&e))
+            />
+            <button on:click=send disabled=move || !is_open.get()>"Send"</button>
+        </div>
+        <pre>{move || lines.get().join("\n")}</pre>
+        <Show when=move || error.with(Option::is_some)>
+            <p>"Error: " {move || format!("{:?}", error.get())}</p>
+        </Show>
+        <Note>"Connect a device like an Arduino that sends text lines at 115200 baud."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
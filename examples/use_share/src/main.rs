@@ -0,0 +1,55 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_share_with_options, UseShareData, UseShareOptions, UseShareReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseShareReturn {
+        is_supported,
+        share,
+        ..
+    } = use_share_with_options(UseShareOptions::default().clipboard_fallback(true));
+
+    let (text, set_text) = signal("Collection of essential Leptos utilities".to_string());
+    let (outcome, set_outcome) = signal(None::<String>);
+
+    let on_share = move |_| {
+        let result = share(
+            UseShareData::default()
+                .title("Leptos-Use")
+                .text(text.get_untracked())
+                .url(window().location().href().unwrap_or_default()),
+        );
+
+        spawn_local(async move {
+            set_outcome.set(Some(format!("{:?}", result.await)));
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <textarea
+            prop:value=text
+            on:input=move |e| set_text.set(event_target_value(&e))
+            rows="3"
+        />
+        <div>
+            <button on:click=on_share>
+                {move || if is_supported.get() { "Share" } else { "Copy to clipboard" }}
+            </button>
+        </div>
+        <p>"Outcome: " {move || outcome.get().unwrap_or_else(|| "-".to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,47 @@
+use leptos::html::Div;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_sticky_with_options, UseStickyOptions, UseStickyReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let container = NodeRef::<Div>::new();
+    let header = NodeRef::<Div>::new();
+
+    let UseStickyReturn {
+        is_stuck,
+        stuck_edge,
+    } = use_sticky_with_options(
+        header,
+        UseStickyOptions::default().viewport(Some(container)),
+    );
+
+    view! {
+        <p>
+            "Header is " <BooleanDisplay value=is_stuck true_str="stuck" false_str="not stuck"/>
+            " " {move || stuck_edge.get().map(|edge| format!("({edge:?})")).unwrap_or_default()}
+        </p>
+        <div node_ref=container class="h-[300px] overflow-auto border rounded">
+            <p class="p-4">"Scroll down"</p>
+            <div
+                node_ref=header
+                class="sticky top-0 p-4 bg-white dark:bg-gray-800 transition-shadow"
+                class:shadow-lg=is_stuck
+            >
+                "Sticky Header"
+            </div>
+            <div class="p-4 h-[800px]">"Content"</div>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,42 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_storage_quota_with_options, UseStorageQuotaOptions, UseStorageQuotaReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseStorageQuotaReturn {
+        is_supported,
+        usage,
+        quota,
+        usage_ratio,
+        is_running_low,
+        is_persisted,
+        request_persistence,
+        refresh,
+    } = use_storage_quota_with_options(UseStorageQuotaOptions::default().interval(Some(5000)));
+
+    let format_bytes =
+        |bytes: Option<f64>| bytes.map(|bytes| format!("{:.2} MB", bytes / 1_000_000.0));
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Usage: " {move || format_bytes(usage.get())}</p>
+        <p>"Quota: " {move || format_bytes(quota.get())}</p>
+        <p>"Used: " {move || usage_ratio.get().map(|ratio| format!("{:.4} %", ratio * 100.0))}</p>
+        <p>"Running low: " <BooleanDisplay value=is_running_low/></p>
+        <p>"Persisted: " <BooleanDisplay value=is_persisted/></p>
+        <button on:click=move |_| request_persistence()>"Request persistence"</button>
+        <button on:click=move |_| refresh()>"Refresh"</button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
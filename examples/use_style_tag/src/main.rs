@@ -0,0 +1,53 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_style_tag, use_style_tag_with_options, UseStyleTagOptions, UseStyleTagReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (color, set_color) = signal("#4f46e5".to_string());
+
+    let UseStyleTagReturn {
+        id,
+        is_loaded,
+        load,
+        unload,
+        ..
+    } = use_style_tag(Signal::derive(move || {
+        format!(".demo-text {{ color: {}; }}", color.get())
+    }));
+
+    let UseStyleTagReturn { scope_id, .. } = use_style_tag_with_options(
+        ".demo-text { font-weight: bold; text-decoration: underline; }",
+        UseStyleTagOptions::default().scoped(true),
+    );
+
+    view! {
+        <input
+            type="color"
+            prop:value=color
+            on:input=move |e| set_color.set(event_target_value(&e))
+        />
+        <div class="flex gap-2">
+            <button on:click=move |_| load()>"Load"</button>
+            <button on:click=move |_| unload()>"Unload"</button>
+        </div>
+        <p>"Style element " <code>{id}</code> " loaded: " <BooleanDisplay value=is_loaded/></p>
+        <p class="demo-text">"This text is colored."</p>
+        <div data-leptos-use-style=scope_id>
+            <p class="demo-text">"This text is colored and, because it's scoped, also bold and underlined."</p>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
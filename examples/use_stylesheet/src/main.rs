@@ -0,0 +1,40 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_stylesheet_with_options, UseStylesheetOptions, UseStylesheetReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseStylesheetReturn {
+        status,
+        is_loaded,
+        load,
+        unload,
+        ..
+    } = use_stylesheet_with_options(
+        "https://cdn.jsdelivr.net/npm/animate.css@4.1.1/animate.min.css",
+        UseStylesheetOptions::default().immediate(false),
+    );
+
+    view! {
+        <div class="flex gap-2">
+            <button on:click=move |_| load()>"Load stylesheet"</button>
+            <button on:click=move |_| unload()>"Unload stylesheet"</button>
+        </div>
+        <p>"Status: " {move || format!("{:?}", status.get())}</p>
+        <p>"Loaded: " <BooleanDisplay value=is_loaded/></p>
+        <p class="animate__animated animate__bounce animate__infinite">
+            "I bounce when the stylesheet is loaded"
+        </p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,61 @@
+use leptos::html::Textarea;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_text_direction_with_options, TextDirection, UseTextDirectionOptions, UseTextDirectionReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = NodeRef::<Textarea>::new();
+    let (text, set_text) = signal("Type here... / اكتب هنا ...".to_string());
+
+    let UseTextDirectionReturn {
+        dir,
+        set_dir,
+        resolved,
+        document_dir,
+    } = use_text_direction_with_options(
+        UseTextDirectionOptions::default()
+            .target(el)
+            .initial_value(TextDirection::Auto)
+            .value(text)
+            .observe(true),
+    );
+
+    let is_rtl = Signal::derive(move || resolved.get() == TextDirection::Rtl);
+
+    view! {
+        <textarea
+            node_ref=el
+            class="block w-full"
+            rows="3"
+            prop:value=text
+            on:input=move |e| set_text.set(event_target_value(&e))
+        ></textarea>
+
+        <div class="my-2">
+            <button on:click=move |_| set_dir.set(TextDirection::Ltr)>"LTR"</button>
+            <button on:click=move |_| set_dir.set(TextDirection::Rtl)>"RTL"</button>
+            <button on:click=move |_| set_dir.set(TextDirection::Auto)>"Auto"</button>
+        </div>
+
+        <p>"Mode: " <code>{move || dir.get().to_string()}</code></p>
+        <p>"Resolved: " <code>{move || resolved.get().to_string()}</code></p>
+        <p>"Right to left: " <BooleanDisplay value=is_rtl /></p>
+        <p>"Document: " <code>{move || document_dir.get().to_string()}</code></p>
+
+        <Note>"In auto mode the direction follows the first strong character you type"</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo /> }
+    });
+
+    unmount_handle.forget();
+}
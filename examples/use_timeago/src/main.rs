@@ -0,0 +1,67 @@
+use leptos::prelude::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_timeago, use_timeago_with_options, TimeAgoIntlOptions, UseTimeAgoOptions};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (offset, set_offset) = signal(-5.0);
+    let (locale, set_locale) = signal("en-US".to_string());
+
+    let time = Signal::derive(move || web_sys::js_sys::Date::now() + offset.get() * 60_000.0);
+
+    let english = use_timeago(time);
+
+    let localized = use_timeago_with_options(
+        time,
+        UseTimeAgoOptions::default()
+            .show_second(true)
+            .intl(Signal::derive(move || {
+                TimeAgoIntlOptions::default().locale(&locale.get())
+            })),
+    );
+
+    let with_cutoff = use_timeago_with_options(
+        time,
+        UseTimeAgoOptions::default()
+            .intl(Signal::derive(move || {
+                TimeAgoIntlOptions::default().locale(&locale.get())
+            }))
+            .full_date_after_days(7),
+    );
+
+    view! {
+        <p>
+            <input
+                class="block"
+                type="range"
+                min="-525600"
+                max="525600"
+                prop:value=offset
+                on:input=move |e| set_offset.set(event_target_value(&e).parse().unwrap())
+            />
+            "Offset: " {offset} " minutes"
+        </p>
+        <p>
+            <select on:change=move |e| set_locale.set(event_target_value(&e))>
+                <option value="en-US">"en-US"</option>
+                <option value="de-DE">"de-DE"</option>
+                <option value="fr-FR">"fr-FR"</option>
+                <option value="ja-JP">"ja-JP"</option>
+            </select>
+        </p>
+        <p>"English: " {english}</p>
+        <p>"Localized: " {localized}</p>
+        <p>"Full date after 7 days: " {with_cutoff}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
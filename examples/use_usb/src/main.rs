@@ -0,0 +1,72 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_usb, UsbDeviceHandle, UseUsbReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseUsbReturn {
+        is_supported,
+        devices,
+        usb,
+    } = use_usb();
+
+    let (error, set_error) = signal(None::<String>);
+
+    let pair = move |_| {
+        leptos::task::spawn_local(async move {
+            if let Err(err) = usb.request_device().await {
+                set_error.set(Some(err.to_string()));
+            }
+        });
+    };
+
+    let forget = move |device: UsbDeviceHandle| {
+        leptos::task::spawn_local(async move {
+            if let Err(err) = device.forget().await {
+                set_error.set(Some(err.to_string()));
+            }
+            let _ = usb.refresh().await;
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <button on:click=pair>"Pair device"</button>
+        <ul>
+            {move || {
+                devices
+                    .get()
+                    .into_iter()
+                    .map(|device| {
+                        let label = format!(
+                            "{} ({:04x}:{:04x})",
+                            device.product_name().unwrap_or_else(|| "Unknown device".to_string()),
+                            device.vendor_id(),
+                            device.product_id(),
+                        );
+
+                        view! {
+                            <li>
+                                {label} " "
+                                <button on:click=move |_| forget(device.clone())>"Forget"</button>
+                            </li>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+        <p>{error}</p>
+        <Note>"Paired devices are listed while they are plugged in."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
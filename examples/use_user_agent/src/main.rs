@@ -0,0 +1,49 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_user_agent, UseUserAgentReturn, UserAgentBrand};
+
+fn format_brands(brands: Vec<UserAgentBrand>) -> String {
+    brands
+        .into_iter()
+        .map(|brand| format!("{} {}", brand.brand, brand.version))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseUserAgentReturn {
+        is_supported,
+        user_agent,
+        brands,
+        platform,
+        mobile,
+        architecture,
+        bitness,
+        model,
+        platform_version,
+        full_version_list,
+    } = use_user_agent();
+
+    view! {
+        <p>"Client hints supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Brands: " {move || format_brands(brands.get())}</p>
+        <p>"Platform: " {platform} " " {platform_version}</p>
+        <p>"Mobile: " <BooleanDisplay value=mobile/></p>
+        <p>"Architecture: " {architecture} " " {move || bitness.get().map(|bitness| format!("({bitness} bit)"))}</p>
+        <p>"Model: " {model}</p>
+        <p>"Full version list: " {move || format_brands(full_version_list.get())}</p>
+        <p>"User agent: " <code>{user_agent}</code></p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
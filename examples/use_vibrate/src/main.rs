@@ -0,0 +1,64 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_vibrate_with_options, UseVibrateOptions, UseVibrateReturn, VibratePreset};
+
+const PRESETS: [VibratePreset; 7] = [
+    VibratePreset::Tap,
+    VibratePreset::DoubleTap,
+    VibratePreset::Success,
+    VibratePreset::Warning,
+    VibratePreset::Error,
+    VibratePreset::Heartbeat,
+    VibratePreset::Sos,
+];
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (preset, set_preset) = signal(VibratePreset::Tap);
+    let pattern = Signal::derive(move || preset.get().pattern());
+
+    let UseVibrateReturn {
+        is_supported,
+        is_vibrating,
+        error,
+        vibrate,
+        stop,
+    } = use_vibrate_with_options(UseVibrateOptions::default().pattern(pattern).interval(2000));
+
+    view! {
+        <div>"Supported: " <BooleanDisplay value=is_supported/></div>
+        <div>"Vibrating: " <BooleanDisplay value=is_vibrating/></div>
+        <div>
+            {PRESETS
+                .into_iter()
+                .map(|p| {
+                    view! {
+                        <button
+                            class:active=move || preset.get() == p
+                            on:click=move |_| set_preset.set(p)
+                        >
+                            {format!("{p:?}")}
+                        </button>
+                    }
+                })
+                .collect_view()}
+        </div>
+        <pre>{move || format!("pattern: {:?}", pattern.get())}</pre>
+        <button on:click=move |_| {
+            if is_vibrating.get() { stop() } else { vibrate() }
+        }>{move || if is_vibrating.get() { "Stop" } else { "Vibrate every 2s" }}</button>
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+        <Note>"Open this page on a mobile device to feel the vibration."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,139 @@
+use leptos::html::{Canvas, Video};
+use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_raf_fn, use_user_media, use_video_decoder, use_video_encoder_with_options,
+    UseUserMediaReturn, UseVideoDecoderReturn, UseVideoEncoderOptions, UseVideoEncoderReturn,
+};
+
+const WIDTH: u32 = 640;
+const HEIGHT: u32 = 480;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video_ref = NodeRef::<Video>::new();
+    let canvas_ref = NodeRef::<Canvas>::new();
+
+    let UseUserMediaReturn {
+        stream,
+        enabled,
+        set_enabled,
+        ..
+    } = use_user_media();
+
+    Effect::new(move || {
+        let stream = stream.get().and_then(Result::ok);
+
+        if let Some(video) = video_ref.get() {
+            video.set_src_object(stream.as_ref());
+        }
+    });
+
+    let UseVideoDecoderReturn {
+        is_supported,
+        frame,
+        decode_queue_size,
+        configure: configure_decoder,
+        decode,
+        ..
+    } = use_video_decoder();
+
+    // the encoded chunks are fed directly into the decoder. A real app would send them over the network.
+    let UseVideoEncoderReturn {
+        encode,
+        encode_queue_size,
+        is_saturated,
+        configure: configure_encoder,
+        ..
+    } = use_video_encoder_with_options(UseVideoEncoderOptions::default().on_chunk(
+        move |chunk: web_sys::EncodedVideoChunk, metadata| {
+            if let Some(config) = metadata.and_then(|metadata| metadata.get_decoder_config()) {
+                configure_decoder(&config);
+            }
+            decode(&chunk);
+        },
+    ));
+
+    Effect::new(move || {
+        if enabled.get() && is_supported.get() {
+            let config = web_sys::VideoEncoderConfig::new("vp8", HEIGHT, WIDTH);
+            config.set_bitrate(1_000_000);
+            configure_encoder(&config);
+        }
+    });
+
+    let frame_count = StoredValue::new(0_usize);
+
+    let _ = use_raf_fn(move |_| {
+        if !enabled.get_untracked() || is_saturated.get_untracked() {
+            return;
+        }
+
+        let Some(video) = video_ref.get_untracked() else {
+            return;
+        };
+
+        if video.ready_state() < 2 {
+            return;
+        }
+
+        if let Ok(frame) = web_sys::VideoFrame::new_with_html_video_element(&video) {
+            let count = frame_count.get_value();
+            frame_count.set_value(count + 1);
+
+            encode(&frame, count % 60 == 0);
+            frame.close();
+        }
+    });
+
+    Effect::new(move || {
+        let Some(canvas) = canvas_ref.get() else {
+            return;
+        };
+
+        frame.with(|frame| {
+            if let Some(frame) = frame {
+                let context = canvas.get_context("2d").ok().flatten().and_then(|context| {
+                    context.dyn_into::<web_sys::CanvasRenderingContext2d>().ok()
+                });
+
+                if let Some(context) = context {
+                    let _ = context.draw_image_with_video_frame(frame, 0.0, 0.0);
+                }
+            }
+        });
+    });
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <button on:click=move |_| set_enabled.set(!enabled.get())>
+            {move || if enabled.get() { "Stop" } else { "Start" }}
+        </button>
+        <p>
+            "Encode queue: " {encode_queue_size} " | Decode queue: " {decode_queue_size}
+        </p>
+        <div class="flex gap-4">
+            <div>
+                <p>"Camera"</p>
+                <video node_ref=video_ref autoplay muted playsinline class="w-80"></video>
+            </div>
+            <div>
+                <p>"Encoded and decoded again"</p>
+                <canvas node_ref=canvas_ref width=WIDTH height=HEIGHT class="w-80"></canvas>
+            </div>
+        </div>
+        <Note>"The decoded video is drawn to the canvas frame by frame."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
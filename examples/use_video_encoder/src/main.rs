@@ -0,0 +1,111 @@
+use leptos::html::Video;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_raf_fn, use_user_media, use_video_encoder_with_options, UseUserMediaReturn,
+    UseVideoEncoderOptions, UseVideoEncoderReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video_ref = NodeRef::<Video>::new();
+
+    let UseUserMediaReturn {
+        stream,
+        enabled,
+        set_enabled,
+        ..
+    } = use_user_media();
+
+    Effect::new(move || {
+        let stream = stream.get().and_then(Result::ok);
+
+        if let Some(video) = video_ref.get() {
+            video.set_src_object(stream.as_ref());
+        }
+    });
+
+    let (chunk_count, set_chunk_count) = signal(0_usize);
+    let (key_frame_count, set_key_frame_count) = signal(0_usize);
+    let (total_bytes, set_total_bytes) = signal(0_u64);
+    let (codec, set_codec) = signal(None::<String>);
+
+    let UseVideoEncoderReturn {
+        is_supported,
+        state,
+        encode_queue_size,
+        is_saturated,
+        configure,
+        encode,
+        ..
+    } = use_video_encoder_with_options(UseVideoEncoderOptions::default().on_chunk(
+        move |chunk: web_sys::EncodedVideoChunk, metadata| {
+            set_chunk_count.update(|count| *count += 1);
+            set_total_bytes.update(|bytes| *bytes += chunk.byte_length() as u64);
+
+            if chunk.type_() == web_sys::EncodedVideoChunkType::Key {
+                set_key_frame_count.update(|count| *count += 1);
+            }
+
+            if let Some(config) = metadata.and_then(|metadata| metadata.get_decoder_config()) {
+                set_codec.set(Some(config.get_codec()));
+            }
+        },
+    ));
+
+    Effect::new(move || {
+        if enabled.get() && is_supported.get() {
+            let config = web_sys::VideoEncoderConfig::new("vp8", 480, 640);
+            config.set_bitrate(1_000_000);
+            configure(&config);
+        }
+    });
+
+    let frame_count = StoredValue::new(0_usize);
+
+    let _ = use_raf_fn(move |_| {
+        if !enabled.get_untracked() || is_saturated.get_untracked() {
+            return;
+        }
+
+        let Some(video) = video_ref.get_untracked() else {
+            return;
+        };
+
+        if video.ready_state() < 2 {
+            return;
+        }
+
+        if let Ok(frame) = web_sys::VideoFrame::new_with_html_video_element(&video) {
+            let count = frame_count.get_value();
+            frame_count.set_value(count + 1);
+
+            encode(&frame, count % 60 == 0);
+            frame.close();
+        }
+    });
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <button on:click=move |_| set_enabled.set(!enabled.get())>
+            {move || if enabled.get() { "Stop" } else { "Start" }}
+        </button>
+        <video node_ref=video_ref autoplay muted playsinline class="w-80"></video>
+        <p>"State: " {move || format!("{:?}", state.get())}</p>
+        <p>"Codec: " {move || codec.get().unwrap_or_else(|| "-".to_string())}</p>
+        <p>"Encode queue: " {encode_queue_size}</p>
+        <p>"Chunks: " {chunk_count} " (" {key_frame_count} " key frames)"</p>
+        <p>"Total size: " {move || format!("{:.1} KB", total_bytes.get() as f64 / 1024.0)}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,37 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_wake_lock, UseWakeLockReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseWakeLockReturn {
+        is_supported,
+        is_active,
+        release_reason,
+        request,
+        release,
+    } = use_wake_lock();
+
+    view! {
+        <div>"Supported: " <BooleanDisplay value=is_supported/></div>
+        <div>"Active: " <BooleanDisplay value=is_active/></div>
+        <div>"Release reason: " {move || format!("{:?}", release_reason.get())}</div>
+        <button on:click=move |_| {
+            if is_active.get() { release() } else { request() }
+        }>{move || if is_active.get() { "Release" } else { "Request" }}</button>
+        <Note>
+            "Switch to another tab and back to see the lock being released and reacquired."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,97 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_web_crypto, AesGcmCiphertext, AesGcmKey, AesKeyLength, HashAlgorithm, HmacKey,
+    UseWebCryptoReturn,
+};
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (text, set_text) = signal("Hello, Leptos!".to_string());
+
+    let UseWebCryptoReturn {
+        is_supported, hex, ..
+    } = use_web_crypto(text);
+
+    let (signature, set_signature) = signal(None::<String>);
+    let (is_valid, set_is_valid) = signal(false);
+
+    let sign = move |_| {
+        leptos::task::spawn_local(async move {
+            let Ok(key) = HmacKey::generate(HashAlgorithm::Sha256, false).await else {
+                return;
+            };
+
+            let message = text.get_untracked();
+
+            if let Ok(signed) = key.sign(message.as_bytes()).await {
+                set_signature.set(Some(to_hex(&signed)));
+                set_is_valid.set(
+                    key.verify(&signed, message.as_bytes())
+                        .await
+                        .unwrap_or_default(),
+                );
+            }
+        });
+    };
+
+    let (ciphertext, set_ciphertext) = signal(None::<String>);
+    let (decrypted, set_decrypted) = signal(None::<String>);
+
+    let encrypt = move |_| {
+        leptos::task::spawn_local(async move {
+            let Ok(key) = AesGcmKey::generate(AesKeyLength::Aes256, false).await else {
+                return;
+            };
+
+            let Ok(encrypted) = key.encrypt(text.get_untracked().as_bytes()).await else {
+                return;
+            };
+
+            let bytes = encrypted.to_bytes();
+            set_ciphertext.set(Some(to_hex(&bytes)));
+
+            if let Some(encrypted) = AesGcmCiphertext::from_bytes(&bytes) {
+                if let Ok(plaintext) = key.decrypt(&encrypted).await {
+                    set_decrypted.set(String::from_utf8(plaintext).ok());
+                }
+            }
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <input
+            type="text"
+            prop:value=text
+            on:input=move |e| set_text.set(event_target_value(&e))
+        />
+        <p>"SHA-256: " <code>{hex}</code></p>
+
+        <h3>"HMAC"</h3>
+        <button on:click=sign>"Sign with a new key"</button>
+        <p>"Signature: " <code>{signature}</code></p>
+        <p>"Valid: " <BooleanDisplay value=is_valid/></p>
+
+        <h3>"AES-GCM"</h3>
+        <button on:click=encrypt>"Encrypt with a new key"</button>
+        <p>"Ciphertext: " <code class="break-all">{ciphertext}</code></p>
+        <p>"Decrypted: " {decrypted}</p>
+        <Note>"The Web Crypto API is only available on HTTPS pages or localhost."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
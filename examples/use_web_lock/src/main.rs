@@ -2,7 +2,7 @@ use gloo_timers::future::sleep;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use leptos_use::docs::demo_or_body;
-use leptos_use::use_web_lock;
+use leptos_use::{use_web_lock, use_web_lock_snapshot, UseWebLockSnapshotReturn};
 use std::time::Duration;
 
 async fn my_process(_lock: web_sys::Lock) -> i32 {
@@ -14,12 +14,22 @@ async fn my_process(_lock: web_sys::Lock) -> i32 {
 #[component]
 fn Demo() -> impl IntoView {
     let (res, set_res) = signal("Not started yet".to_string());
+    let (state, set_state) = signal(None::<(Signal<bool>, Signal<bool>)>);
+
+    let UseWebLockSnapshotReturn {
+        held,
+        pending,
+        refresh,
+    } = use_web_lock_snapshot();
 
     let on_click = move |_| {
         set_res.set("Running...".to_string());
 
+        let lock = use_web_lock("my_lock", my_process);
+        set_state.set(Some((lock.is_pending, lock.is_held)));
+
         spawn_local(async move {
-            let res = use_web_lock("my_lock", my_process).await;
+            let res = lock.await;
 
             match res {
                 Ok(res) => {
@@ -35,6 +45,20 @@ fn Demo() -> impl IntoView {
     view! {
         <button on:click=on_click>Run locked task</button>
         <p>{res}</p>
+        <p>
+            "This request: "
+            {move || match state.get() {
+                Some((is_pending, _)) if is_pending.get() => "waiting for the lock",
+                Some((_, is_held)) if is_held.get() => "holding the lock",
+                _ => "idle",
+            }}
+        </p>
+        <button on:click={
+            let refresh = refresh.clone();
+            move |_| refresh()
+        }>"Refresh locks of all tabs"</button>
+        <p>"Held locks: " {move || format!("{:?}", held.get())}</p>
+        <p>"Pending locks: " {move || format!("{:?}", pending.get())}</p>
     }
 }
 
@@ -0,0 +1,79 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_webauthn, UseWebauthnReturn};
+
+// In a real app these options are generated by the server which also verifies the results.
+const REGISTRATION_OPTIONS: &str = r#"{
+    "challenge": "bGVwdG9zLXVzZS1kZW1vLWNoYWxsZW5nZQ",
+    "rp": { "name": "Leptos-Use Demo" },
+    "user": { "id": "ZGVtby11c2Vy", "name": "demo@example.com", "displayName": "Demo User" },
+    "pubKeyCredParams": [{ "type": "public-key", "alg": -7 }, { "type": "public-key", "alg": -257 }],
+    "authenticatorSelection": { "residentKey": "preferred", "userVerification": "preferred" },
+    "timeout": 60000
+}"#;
+
+const AUTHENTICATION_OPTIONS: &str = r#"{
+    "challenge": "bGVwdG9zLXVzZS1kZW1vLWNoYWxsZW5nZQ",
+    "userVerification": "preferred",
+    "timeout": 60000
+}"#;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseWebauthnReturn {
+        is_supported,
+        is_conditional_mediation_available,
+        is_platform_authenticator_available,
+        is_pending,
+        client,
+    } = use_webauthn();
+
+    let (result, set_result) = signal(String::new());
+
+    let register = move |_| {
+        leptos::task::spawn_local(async move {
+            set_result.set(match client.register(REGISTRATION_OPTIONS).await {
+                Ok(credential) => credential,
+                Err(err) => format!("Error: {err}"),
+            });
+        });
+    };
+
+    let authenticate = move |_| {
+        leptos::task::spawn_local(async move {
+            set_result.set(match client.authenticate(AUTHENTICATION_OPTIONS).await {
+                Ok(credential) => credential,
+                Err(err) => format!("Error: {err}"),
+            });
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>
+            "Conditional mediation available: "
+            <BooleanDisplay value=is_conditional_mediation_available/>
+        </p>
+        <p>
+            "Platform authenticator available: "
+            <BooleanDisplay value=is_platform_authenticator_available/>
+        </p>
+        <p>"Pending: " <BooleanDisplay value=is_pending/></p>
+        <button on:click=register disabled=is_pending>"Create passkey"</button>
+        <button on:click=authenticate disabled=is_pending>"Sign in with passkey"</button>
+        <button on:click=move |_| client.abort()>"Abort"</button>
+        <pre class="whitespace-pre-wrap break-all">{result}</pre>
+        <Note>"This demo doesn't verify the results. That's the job of your server."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,65 @@
+use leptos::html::Canvas;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_webgl_context_with_options, UseWebGlContextOptions, UseWebGlContextReturn};
+use web_sys::WebGl2RenderingContext as Gl;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let canvas = NodeRef::<Canvas>::new();
+
+    let (init_count, set_init_count) = signal(0_usize);
+    let (red, set_red) = signal(0.2_f32);
+
+    let UseWebGlContextReturn {
+        context,
+        is_context_lost,
+        restore_count,
+        lose_context,
+        restore_context,
+    } = use_webgl_context_with_options(
+        canvas,
+        UseWebGlContextOptions::default()
+            .preserve_drawing_buffer(true)
+            .on_init(move |_| set_init_count.update(|count| *count += 1)),
+    );
+
+    Effect::new(move || {
+        let red = red.get();
+
+        context.with(|context| {
+            if let Some(gl) = context.as_ref().and_then(|context| context.as_webgl2()) {
+                gl.clear_color(red, 0.4, 0.8, 1.0);
+                gl.clear(Gl::COLOR_BUFFER_BIT);
+            }
+        });
+    });
+
+    view! {
+        <canvas node_ref=canvas width="300" height="150"></canvas>
+        <input
+            type="range"
+            min="0"
+            max="1"
+            step="0.01"
+            prop:value=move || red.get().to_string()
+            on:input=move |ev| set_red.set(event_target_value(&ev).parse().unwrap_or_default())
+        />
+        <p>"Context lost: " <BooleanDisplay value=is_context_lost/></p>
+        <p>"Initialized " {init_count} " times, restored " {restore_count} " times"</p>
+        <button on:click=move |_| lose_context()>"Lose context"</button>
+        <button on:click=move |_| restore_context()>"Restore context"</button>
+        <Note>"After restoring, the canvas is cleared again with the current color."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,45 @@
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_window_controls_overlay, UseWindowControlsOverlayReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseWindowControlsOverlayReturn {
+        is_supported,
+        visible,
+        x,
+        y,
+        width,
+        height,
+    } = use_window_controls_overlay();
+
+    view! {
+        <div>"Supported: " <BooleanDisplay value=is_supported/></div>
+        <div>"Visible: " <BooleanDisplay value=visible/></div>
+        <pre>
+            {move || {
+                format!(
+                    "title bar area: x: {}, y: {}, width: {}, height: {}",
+                    x.get(),
+                    y.get(),
+                    width.get(),
+                    height.get(),
+                )
+            }}
+        </pre>
+        <Note>
+            "Install this page as a PWA with window controls overlay enabled in the manifest to see the geometry."
+        </Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
@@ -0,0 +1,72 @@
+use codee::string::JsonSerdeCodec;
+use leptos::html::Iframe;
+use leptos::prelude::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_window_message, UseWindowMessageReturn};
+
+// A tiny page without Rust that speaks the wire format of `use_window_message`
+const IFRAME_DOC: &str = r#"
+<p>I'm the iframe</p>
+<script>
+    let count = 0;
+    setInterval(() => {
+        parent.postMessage({ channel: "leptos-use", type: "tick", data: JSON.stringify(count++) }, "*");
+    }, 1000);
+
+    addEventListener("message", (event) => {
+        const msg = event.data;
+        if (msg && msg.channel === "leptos-use" && msg.type === "greet" && msg.id != null) {
+            const name = JSON.parse(msg.data);
+            event.source.postMessage(
+                { channel: "leptos-use", type: "greet", reply_to: msg.id, data: JSON.stringify(`Hello, ${name}!`) },
+                event.origin,
+            );
+        }
+    });
+</script>
+"#;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let iframe = NodeRef::<Iframe>::new();
+
+    let UseWindowMessageReturn { messenger, .. } = use_window_message::<JsonSerdeCodec>(iframe);
+
+    let tick = messenger.message::<u32>("tick");
+
+    let (name, set_name) = signal("Leptos".to_string());
+    let (reply, set_reply) = signal(String::new());
+
+    let greet = move |_| {
+        leptos::task::spawn_local(async move {
+            let result = messenger
+                .request::<String, String>("greet", &name.get_untracked())
+                .await;
+
+            set_reply.set(match result {
+                Ok(greeting) => greeting,
+                Err(err) => err.to_string(),
+            });
+        });
+    };
+
+    view! {
+        <iframe node_ref=iframe srcdoc=IFRAME_DOC class="h-16"></iframe>
+        <p>"Latest tick: " {move || format!("{:?}", tick.get())}</p>
+        <input bind:value=(name, set_name) />
+        <button on:click=greet>"Request greeting"</button>
+        <p>"Reply: " {reply}</p>
+        <Note>"The iframe sends a tick every second and answers greeting requests."</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    let unmount_handle = leptos::mount::mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    });
+
+    unmount_handle.forget();
+}
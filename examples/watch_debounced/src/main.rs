@@ -1,13 +1,18 @@
 use leptos::prelude::*;
-use leptos_use::docs::{demo_or_body, Note};
-use leptos_use::{watch_debounced_with_options, WatchDebouncedOptions};
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{watch_debounced_with_options, WatchDebouncedOptions, WatchFilteredReturn};
 
 #[component]
 fn Demo() -> impl IntoView {
     let (input, set_input) = signal("".to_string());
     let (updated, set_updated) = signal(0);
 
-    let _ = watch_debounced_with_options(
+    let WatchFilteredReturn {
+        flush,
+        cancel,
+        is_pending,
+        ..
+    } = watch_debounced_with_options(
         move || input.get(),
         move |_, _, _| {
             set_updated.update(|x| *x += 1);
@@ -32,6 +37,9 @@ fn Demo() -> impl IntoView {
         </Note>
         <p>"Input: " {input}</p>
         <p>"Times Updated: " {updated}</p>
+        <p>"Pending: " <BooleanDisplay value=is_pending /></p>
+        <button on:click=move |_| flush()>"Flush"</button>
+        <button on:click=move |_| cancel()>"Cancel"</button>
     }
 }
 
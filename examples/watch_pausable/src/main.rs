@@ -12,7 +12,9 @@ fn Demo() -> impl IntoView {
     let WatchPausableReturn {
         pause,
         resume,
+        resume_and_run,
         is_active,
+        missed_updates,
         ..
     } = watch_pausable(
         move || source.get(),
@@ -33,6 +35,11 @@ fn Demo() -> impl IntoView {
         resume();
     };
 
+    let resume_and_run = move |_| {
+        set_log.update(|log| *log = format!("{log}Resumed and run\n"));
+        resume_and_run();
+    };
+
     view! {
         <Note class="mb-2">"Type something below to trigger the watch"</Note>
         <input
@@ -43,12 +50,16 @@ fn Demo() -> impl IntoView {
             type="text"
         />
         <p>"Value: " {source}</p>
+        <p>"Missed updates: " {missed_updates}</p>
         <button prop:disabled=move || !is_active.get() class="orange" on:click=pause>
             "Pause"
         </button>
         <button prop:disabled=move || is_active.get() on:click=resume>
             "Resume"
         </button>
+        <button prop:disabled=move || is_active.get() on:click=resume_and_run>
+            "Resume and run"
+        </button>
         <button on:click=clear>"Clear Log"</button>
         <br/>
         <br/>
@@ -0,0 +1,13 @@
+/// Loading status of an external resource like a script, a stylesheet or a font.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub enum LoadStatus {
+    /// The resource hasn't been requested (yet)
+    #[default]
+    Idle,
+    /// The resource is loading
+    Loading,
+    /// The resource has loaded successfully
+    Loaded,
+    /// The resource failed to load
+    Error,
+}
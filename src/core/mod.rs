@@ -5,10 +5,12 @@ mod direction;
 mod element_maybe_signal;
 #[cfg(feature = "element")]
 mod elements_maybe_signal;
+mod load_status;
 mod maybe_rw_signal;
 mod pointer_type;
 mod position;
 mod reconnect_limit;
+mod rect;
 mod size;
 mod ssr_safe_method;
 #[cfg(feature = "use_color_mode")]
@@ -22,10 +24,12 @@ pub use direction::*;
 pub use element_maybe_signal::*;
 #[cfg(feature = "element")]
 pub use elements_maybe_signal::*;
+pub use load_status::*;
 pub use maybe_rw_signal::*;
 pub use pointer_type::*;
 pub use position::*;
 pub use reconnect_limit::*;
+pub use rect::*;
 pub use size::*;
 #[allow(unused_imports)]
 pub(crate) use ssr_safe_method::*;
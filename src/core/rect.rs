@@ -0,0 +1,34 @@
+/// An axis aligned rectangle in px
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    /// The area of the rectangle
+    pub fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    /// The intersection of two rectangles or `None` if they don't overlap
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+
+        if right > left && bottom > top {
+            Some(Rect {
+                x: left,
+                y: top,
+                width: right - left,
+                height: bottom - top,
+            })
+        } else {
+            None
+        }
+    }
+}
@@ -36,30 +36,69 @@ mod signal_throttled;
 mod sync_signal;
 #[cfg(feature = "use_active_element")]
 mod use_active_element;
+#[cfg(feature = "use_anchor_position")]
+mod use_anchor_position;
+#[cfg(feature = "use_app_badge")]
+mod use_app_badge;
+#[cfg(feature = "use_background_sync")]
+mod use_background_sync;
+#[cfg(feature = "use_barcode_detector")]
+mod use_barcode_detector;
+#[cfg(feature = "use_before_unload")]
+mod use_before_unload;
+#[cfg(feature = "use_bluetooth")]
+#[cfg(web_sys_unstable_apis)]
+mod use_bluetooth;
 #[cfg(feature = "use_breakpoints")]
 mod use_breakpoints;
 #[cfg(feature = "use_broadcast_channel")]
 mod use_broadcast_channel;
+#[cfg(feature = "use_browser_zoom")]
+mod use_browser_zoom;
+#[cfg(feature = "use_cache_storage")]
+mod use_cache_storage;
+#[cfg(feature = "use_canvas")]
+mod use_canvas;
+#[cfg(feature = "use_caret_position")]
+mod use_caret_position;
+#[cfg(feature = "use_chunked_task")]
+mod use_chunked_task;
 #[cfg(feature = "use_clipboard")]
 mod use_clipboard;
+#[cfg(feature = "use_collator")]
+mod use_collator;
 #[cfg(feature = "use_color_mode")]
 mod use_color_mode;
+#[cfg(feature = "use_confirm")]
+mod use_confirm;
+#[cfg(feature = "use_content_editable")]
+mod use_content_editable;
 #[cfg(feature = "use_cookie")]
 mod use_cookie;
+#[cfg(feature = "use_credentials")]
+mod use_credentials;
 #[cfg(feature = "use_css_var")]
 mod use_css_var;
 #[cfg(feature = "use_cycle_list")]
 mod use_cycle_list;
 #[cfg(feature = "use_debounce_fn")]
 mod use_debounce_fn;
+#[cfg(feature = "use_device_memory")]
+mod use_device_memory;
 #[cfg(feature = "use_device_orientation")]
 mod use_device_orientation;
 #[cfg(feature = "use_device_pixel_ratio")]
 mod use_device_pixel_ratio;
+#[cfg(feature = "use_device_tier")]
+mod use_device_tier;
+#[cfg(feature = "use_dialog")]
+mod use_dialog;
 #[cfg(feature = "use_display_media")]
 mod use_display_media;
 #[cfg(feature = "use_document")]
 mod use_document;
+#[cfg(feature = "use_document_title")]
+mod use_document_title;
 #[cfg(feature = "use_document_visibility")]
 mod use_document_visibility;
 #[cfg(feature = "use_draggable")]
@@ -68,68 +107,167 @@ mod use_draggable;
 mod use_drop_zone;
 #[cfg(feature = "use_element_bounding")]
 mod use_element_bounding;
+#[cfg(feature = "use_element_by_point")]
+mod use_element_by_point;
 #[cfg(feature = "use_element_hover")]
 mod use_element_hover;
+#[cfg(feature = "use_element_overlap")]
+mod use_element_overlap;
 #[cfg(feature = "use_element_size")]
 mod use_element_size;
 #[cfg(feature = "use_element_visibility")]
 mod use_element_visibility;
+#[cfg(feature = "use_error_capture")]
+mod use_error_capture;
 #[cfg(feature = "use_event_listener")]
 mod use_event_listener;
 #[cfg(feature = "use_event_source")]
 mod use_event_source;
+#[cfg(feature = "use_eye_dropper")]
+mod use_eye_dropper;
 #[cfg(feature = "use_favicon")]
 mod use_favicon;
+#[cfg(feature = "use_floating")]
+mod use_floating;
+#[cfg(feature = "use_font_face")]
+mod use_font_face;
+#[cfg(feature = "use_fps")]
+mod use_fps;
+#[cfg(feature = "use_fullscreen")]
+mod use_fullscreen;
 #[cfg(feature = "use_geolocation")]
 mod use_geolocation;
+#[cfg(feature = "use_hardware_concurrency")]
+mod use_hardware_concurrency;
+#[cfg(feature = "use_hid")]
+#[cfg(web_sys_unstable_apis)]
+mod use_hid;
 #[cfg(feature = "use_idle")]
 mod use_idle;
+#[cfg(feature = "use_idle_callback")]
+mod use_idle_callback;
+#[cfg(feature = "use_iframe_bridge")]
+mod use_iframe_bridge;
+#[cfg(feature = "use_image")]
+mod use_image;
+#[cfg(feature = "use_image_capture")]
+#[cfg(web_sys_unstable_apis)]
+mod use_image_capture;
 #[cfg(feature = "use_infinite_scroll")]
 mod use_infinite_scroll;
+#[cfg(feature = "use_install_prompt")]
+mod use_install_prompt;
 #[cfg(feature = "use_intersection_observer")]
 mod use_intersection_observer;
 #[cfg(feature = "use_interval")]
 mod use_interval;
 #[cfg(feature = "use_interval_fn")]
 mod use_interval_fn;
+#[cfg(feature = "use_intl_date_time_format")]
+mod use_intl_date_time_format;
 #[cfg(feature = "use_intl_number_format")]
 mod use_intl_number_format;
+#[cfg(feature = "use_jwt")]
+mod use_jwt;
+#[cfg(feature = "use_keyboard_avoiding")]
+mod use_keyboard_avoiding;
 #[cfg(feature = "use_locale")]
 mod use_locale;
 #[cfg(feature = "use_locales")]
 mod use_locales;
+#[cfg(feature = "use_media_controls")]
+mod use_media_controls;
 #[cfg(feature = "use_media_query")]
 mod use_media_query;
+#[cfg(feature = "use_media_session")]
+mod use_media_session;
+#[cfg(feature = "use_memory")]
+mod use_memory;
 #[cfg(feature = "use_mouse")]
 mod use_mouse;
 #[cfg(feature = "use_mouse_in_element")]
 mod use_mouse_in_element;
 #[cfg(feature = "use_mutation_observer")]
 mod use_mutation_observer;
+#[cfg(feature = "use_network")]
+mod use_network;
+#[cfg(feature = "use_nfc")]
+mod use_nfc;
+#[cfg(feature = "use_offscreen_canvas")]
+mod use_offscreen_canvas;
+#[cfg(feature = "use_online")]
+mod use_online;
+#[cfg(feature = "use_parallax")]
+mod use_parallax;
+#[cfg(feature = "use_payment_request")]
+mod use_payment_request;
+#[cfg(feature = "use_performance_observer")]
+mod use_performance_observer;
 #[cfg(feature = "use_permission")]
 mod use_permission;
+#[cfg(feature = "use_persisted_signal")]
+mod use_persisted_signal;
+#[cfg(feature = "use_picture_in_picture")]
+mod use_picture_in_picture;
+#[cfg(feature = "use_pointer_lock")]
+mod use_pointer_lock;
+#[cfg(feature = "use_pointers")]
+mod use_pointers;
+#[cfg(feature = "use_popover")]
+mod use_popover;
 #[cfg(feature = "use_preferred_contrast")]
 mod use_preferred_contrast;
 #[cfg(feature = "use_preferred_dark")]
 mod use_preferred_dark;
+#[cfg(feature = "use_preferred_languages")]
+mod use_preferred_languages;
+#[cfg(feature = "use_preferred_reduced_data")]
+mod use_preferred_reduced_data;
 #[cfg(feature = "use_prefers_reduced_motion")]
 mod use_prefers_reduced_motion;
+#[cfg(feature = "use_prefetch")]
+mod use_prefetch;
+#[cfg(feature = "use_print")]
+mod use_print;
 #[cfg(feature = "use_raf_fn")]
 mod use_raf_fn;
+#[cfg(feature = "use_reporting_observer")]
+mod use_reporting_observer;
 #[cfg(feature = "use_resize_observer")]
 mod use_resize_observer;
+#[cfg(feature = "use_screen_details")]
+mod use_screen_details;
+#[cfg(feature = "use_script_tag")]
+mod use_script_tag;
 #[cfg(feature = "use_scroll")]
 mod use_scroll;
+#[cfg(feature = "use_serial")]
+#[cfg(web_sys_unstable_apis)]
+mod use_serial;
 #[cfg(feature = "use_service_worker")]
 mod use_service_worker;
+#[cfg(feature = "use_share")]
+mod use_share;
 #[cfg(feature = "use_sorted")]
 mod use_sorted;
+#[cfg(feature = "use_sticky")]
+mod use_sticky;
+#[cfg(feature = "use_storage_quota")]
+mod use_storage_quota;
+#[cfg(feature = "use_style_tag")]
+mod use_style_tag;
+#[cfg(feature = "use_stylesheet")]
+mod use_stylesheet;
 #[cfg(feature = "use_supported")]
 mod use_supported;
+#[cfg(feature = "use_text_direction")]
+mod use_text_direction;
 #[cfg(feature = "use_textarea_autosize")]
 mod use_textarea_autosize;
 #[cfg(feature = "use_throttle_fn")]
 mod use_throttle_fn;
+#[cfg(feature = "use_timeago")]
+mod use_timeago;
 #[cfg(feature = "use_timeout_fn")]
 mod use_timeout_fn;
 #[cfg(feature = "use_timestamp")]
@@ -138,29 +276,58 @@ mod use_timestamp;
 mod use_to_string;
 #[cfg(feature = "use_toggle")]
 mod use_toggle;
+#[cfg(feature = "use_usb")]
+#[cfg(web_sys_unstable_apis)]
+mod use_usb;
+#[cfg(feature = "use_user_agent")]
+mod use_user_agent;
 #[cfg(feature = "use_user_media")]
 mod use_user_media;
+#[cfg(feature = "use_vibrate")]
+mod use_vibrate;
+#[cfg(feature = "use_video_decoder")]
+#[cfg(web_sys_unstable_apis)]
+mod use_video_decoder;
+#[cfg(feature = "use_video_encoder")]
+#[cfg(web_sys_unstable_apis)]
+mod use_video_encoder;
+#[cfg(feature = "use_wake_lock")]
+mod use_wake_lock;
+#[cfg(feature = "use_web_crypto")]
+mod use_web_crypto;
 #[cfg(feature = "use_web_lock")]
 #[cfg(web_sys_unstable_apis)]
 mod use_web_lock;
 #[cfg(feature = "use_web_notification")]
 mod use_web_notification;
+#[cfg(feature = "use_webauthn")]
+mod use_webauthn;
+#[cfg(feature = "use_webgl_context")]
+mod use_webgl_context;
 #[cfg(feature = "use_websocket")]
 mod use_websocket;
 #[cfg(feature = "use_window")]
 mod use_window;
+#[cfg(feature = "use_window_controls_overlay")]
+mod use_window_controls_overlay;
 #[cfg(feature = "use_window_focus")]
 mod use_window_focus;
+#[cfg(feature = "use_window_message")]
+mod use_window_message;
 #[cfg(feature = "use_window_scroll")]
 mod use_window_scroll;
 #[cfg(feature = "use_window_size")]
 mod use_window_size;
 #[cfg(feature = "watch_debounced")]
 mod watch_debounced;
+#[cfg(feature = "watch_once")]
+mod watch_once;
 #[cfg(feature = "watch_pausable")]
 mod watch_pausable;
 #[cfg(feature = "watch_throttled")]
 mod watch_throttled;
+#[cfg(feature = "watch_until")]
+mod watch_until;
 #[cfg(feature = "watch_with_options")]
 mod watch_with_options;
 #[cfg(feature = "whenever")]
@@ -184,30 +351,69 @@ pub use signal_throttled::*;
 pub use sync_signal::*;
 #[cfg(feature = "use_active_element")]
 pub use use_active_element::*;
+#[cfg(feature = "use_anchor_position")]
+pub use use_anchor_position::*;
+#[cfg(feature = "use_app_badge")]
+pub use use_app_badge::*;
+#[cfg(feature = "use_background_sync")]
+pub use use_background_sync::*;
+#[cfg(feature = "use_barcode_detector")]
+pub use use_barcode_detector::*;
+#[cfg(feature = "use_before_unload")]
+pub use use_before_unload::*;
+#[cfg(feature = "use_bluetooth")]
+#[cfg(web_sys_unstable_apis)]
+pub use use_bluetooth::*;
 #[cfg(feature = "use_breakpoints")]
 pub use use_breakpoints::*;
 #[cfg(feature = "use_broadcast_channel")]
 pub use use_broadcast_channel::*;
+#[cfg(feature = "use_browser_zoom")]
+pub use use_browser_zoom::*;
+#[cfg(feature = "use_cache_storage")]
+pub use use_cache_storage::*;
+#[cfg(feature = "use_canvas")]
+pub use use_canvas::*;
+#[cfg(feature = "use_caret_position")]
+pub use use_caret_position::*;
+#[cfg(feature = "use_chunked_task")]
+pub use use_chunked_task::*;
 #[cfg(feature = "use_clipboard")]
 pub use use_clipboard::*;
+#[cfg(feature = "use_collator")]
+pub use use_collator::*;
 #[cfg(feature = "use_color_mode")]
 pub use use_color_mode::*;
+#[cfg(feature = "use_confirm")]
+pub use use_confirm::*;
+#[cfg(feature = "use_content_editable")]
+pub use use_content_editable::*;
 #[cfg(feature = "use_cookie")]
 pub use use_cookie::*;
+#[cfg(feature = "use_credentials")]
+pub use use_credentials::*;
 #[cfg(feature = "use_css_var")]
 pub use use_css_var::*;
 #[cfg(feature = "use_cycle_list")]
 pub use use_cycle_list::*;
 #[cfg(feature = "use_debounce_fn")]
 pub use use_debounce_fn::*;
+#[cfg(feature = "use_device_memory")]
+pub use use_device_memory::*;
 #[cfg(feature = "use_device_orientation")]
 pub use use_device_orientation::*;
 #[cfg(feature = "use_device_pixel_ratio")]
 pub use use_device_pixel_ratio::*;
+#[cfg(feature = "use_device_tier")]
+pub use use_device_tier::*;
+#[cfg(feature = "use_dialog")]
+pub use use_dialog::*;
 #[cfg(feature = "use_display_media")]
 pub use use_display_media::*;
 #[cfg(feature = "use_document")]
 pub use use_document::*;
+#[cfg(feature = "use_document_title")]
+pub use use_document_title::*;
 #[cfg(feature = "use_document_visibility")]
 pub use use_document_visibility::*;
 #[cfg(feature = "use_draggable")]
@@ -216,68 +422,167 @@ pub use use_draggable::*;
 pub use use_drop_zone::*;
 #[cfg(feature = "use_element_bounding")]
 pub use use_element_bounding::*;
+#[cfg(feature = "use_element_by_point")]
+pub use use_element_by_point::*;
 #[cfg(feature = "use_element_hover")]
 pub use use_element_hover::*;
+#[cfg(feature = "use_element_overlap")]
+pub use use_element_overlap::*;
 #[cfg(feature = "use_element_size")]
 pub use use_element_size::*;
 #[cfg(feature = "use_element_visibility")]
 pub use use_element_visibility::*;
+#[cfg(feature = "use_error_capture")]
+pub use use_error_capture::*;
 #[cfg(feature = "use_event_listener")]
 pub use use_event_listener::*;
 #[cfg(feature = "use_event_source")]
 pub use use_event_source::*;
+#[cfg(feature = "use_eye_dropper")]
+pub use use_eye_dropper::*;
 #[cfg(feature = "use_favicon")]
 pub use use_favicon::*;
+#[cfg(feature = "use_floating")]
+pub use use_floating::*;
+#[cfg(feature = "use_font_face")]
+pub use use_font_face::*;
+#[cfg(feature = "use_fps")]
+pub use use_fps::*;
+#[cfg(feature = "use_fullscreen")]
+pub use use_fullscreen::*;
 #[cfg(feature = "use_geolocation")]
 pub use use_geolocation::*;
+#[cfg(feature = "use_hardware_concurrency")]
+pub use use_hardware_concurrency::*;
+#[cfg(feature = "use_hid")]
+#[cfg(web_sys_unstable_apis)]
+pub use use_hid::*;
 #[cfg(feature = "use_idle")]
 pub use use_idle::*;
+#[cfg(feature = "use_idle_callback")]
+pub use use_idle_callback::*;
+#[cfg(feature = "use_iframe_bridge")]
+pub use use_iframe_bridge::*;
+#[cfg(feature = "use_image")]
+pub use use_image::*;
+#[cfg(feature = "use_image_capture")]
+#[cfg(web_sys_unstable_apis)]
+pub use use_image_capture::*;
 #[cfg(feature = "use_infinite_scroll")]
 pub use use_infinite_scroll::*;
+#[cfg(feature = "use_install_prompt")]
+pub use use_install_prompt::*;
 #[cfg(feature = "use_intersection_observer")]
 pub use use_intersection_observer::*;
 #[cfg(feature = "use_interval")]
 pub use use_interval::*;
 #[cfg(feature = "use_interval_fn")]
 pub use use_interval_fn::*;
+#[cfg(feature = "use_intl_date_time_format")]
+pub use use_intl_date_time_format::*;
 #[cfg(feature = "use_intl_number_format")]
 pub use use_intl_number_format::*;
+#[cfg(feature = "use_jwt")]
+pub use use_jwt::*;
+#[cfg(feature = "use_keyboard_avoiding")]
+pub use use_keyboard_avoiding::*;
 #[cfg(feature = "use_locale")]
 pub use use_locale::*;
 #[cfg(feature = "use_locales")]
 pub use use_locales::*;
+#[cfg(feature = "use_media_controls")]
+pub use use_media_controls::*;
 #[cfg(feature = "use_media_query")]
 pub use use_media_query::*;
+#[cfg(feature = "use_media_session")]
+pub use use_media_session::*;
+#[cfg(feature = "use_memory")]
+pub use use_memory::*;
 #[cfg(feature = "use_mouse")]
 pub use use_mouse::*;
 #[cfg(feature = "use_mouse_in_element")]
 pub use use_mouse_in_element::*;
 #[cfg(feature = "use_mutation_observer")]
 pub use use_mutation_observer::*;
+#[cfg(feature = "use_network")]
+pub use use_network::*;
+#[cfg(feature = "use_nfc")]
+pub use use_nfc::*;
+#[cfg(feature = "use_offscreen_canvas")]
+pub use use_offscreen_canvas::*;
+#[cfg(feature = "use_online")]
+pub use use_online::*;
+#[cfg(feature = "use_parallax")]
+pub use use_parallax::*;
+#[cfg(feature = "use_payment_request")]
+pub use use_payment_request::*;
+#[cfg(feature = "use_performance_observer")]
+pub use use_performance_observer::*;
 #[cfg(feature = "use_permission")]
 pub use use_permission::*;
+#[cfg(feature = "use_persisted_signal")]
+pub use use_persisted_signal::*;
+#[cfg(feature = "use_picture_in_picture")]
+pub use use_picture_in_picture::*;
+#[cfg(feature = "use_pointer_lock")]
+pub use use_pointer_lock::*;
+#[cfg(feature = "use_pointers")]
+pub use use_pointers::*;
+#[cfg(feature = "use_popover")]
+pub use use_popover::*;
 #[cfg(feature = "use_preferred_contrast")]
 pub use use_preferred_contrast::*;
 #[cfg(feature = "use_preferred_dark")]
 pub use use_preferred_dark::*;
+#[cfg(feature = "use_preferred_languages")]
+pub use use_preferred_languages::*;
+#[cfg(feature = "use_preferred_reduced_data")]
+pub use use_preferred_reduced_data::*;
 #[cfg(feature = "use_prefers_reduced_motion")]
 pub use use_prefers_reduced_motion::*;
+#[cfg(feature = "use_prefetch")]
+pub use use_prefetch::*;
+#[cfg(feature = "use_print")]
+pub use use_print::*;
 #[cfg(feature = "use_raf_fn")]
 pub use use_raf_fn::*;
+#[cfg(feature = "use_reporting_observer")]
+pub use use_reporting_observer::*;
 #[cfg(feature = "use_resize_observer")]
 pub use use_resize_observer::*;
+#[cfg(feature = "use_screen_details")]
+pub use use_screen_details::*;
+#[cfg(feature = "use_script_tag")]
+pub use use_script_tag::*;
 #[cfg(feature = "use_scroll")]
 pub use use_scroll::*;
+#[cfg(feature = "use_serial")]
+#[cfg(web_sys_unstable_apis)]
+pub use use_serial::*;
 #[cfg(feature = "use_service_worker")]
 pub use use_service_worker::*;
+#[cfg(feature = "use_share")]
+pub use use_share::*;
 #[cfg(feature = "use_sorted")]
 pub use use_sorted::*;
+#[cfg(feature = "use_sticky")]
+pub use use_sticky::*;
+#[cfg(feature = "use_storage_quota")]
+pub use use_storage_quota::*;
+#[cfg(feature = "use_style_tag")]
+pub use use_style_tag::*;
+#[cfg(feature = "use_stylesheet")]
+pub use use_stylesheet::*;
 #[cfg(feature = "use_supported")]
 pub use use_supported::*;
+#[cfg(feature = "use_text_direction")]
+pub use use_text_direction::*;
 #[cfg(feature = "use_textarea_autosize")]
 pub use use_textarea_autosize::*;
 #[cfg(feature = "use_throttle_fn")]
 pub use use_throttle_fn::*;
+#[cfg(feature = "use_timeago")]
+pub use use_timeago::*;
 #[cfg(feature = "use_timeout_fn")]
 pub use use_timeout_fn::*;
 #[cfg(feature = "use_timestamp")]
@@ -286,29 +591,58 @@ pub use use_timestamp::*;
 pub use use_to_string::*;
 #[cfg(feature = "use_toggle")]
 pub use use_toggle::*;
+#[cfg(feature = "use_usb")]
+#[cfg(web_sys_unstable_apis)]
+pub use use_usb::*;
+#[cfg(feature = "use_user_agent")]
+pub use use_user_agent::*;
 #[cfg(feature = "use_user_media")]
 pub use use_user_media::*;
+#[cfg(feature = "use_vibrate")]
+pub use use_vibrate::*;
+#[cfg(feature = "use_video_decoder")]
+#[cfg(web_sys_unstable_apis)]
+pub use use_video_decoder::*;
+#[cfg(feature = "use_video_encoder")]
+#[cfg(web_sys_unstable_apis)]
+pub use use_video_encoder::*;
+#[cfg(feature = "use_wake_lock")]
+pub use use_wake_lock::*;
+#[cfg(feature = "use_web_crypto")]
+pub use use_web_crypto::*;
 #[cfg(feature = "use_web_lock")]
 #[cfg(web_sys_unstable_apis)]
 pub use use_web_lock::*;
 #[cfg(feature = "use_web_notification")]
 pub use use_web_notification::*;
+#[cfg(feature = "use_webauthn")]
+pub use use_webauthn::*;
+#[cfg(feature = "use_webgl_context")]
+pub use use_webgl_context::*;
 #[cfg(feature = "use_websocket")]
 pub use use_websocket::*;
 #[cfg(feature = "use_window")]
 pub use use_window::*;
+#[cfg(feature = "use_window_controls_overlay")]
+pub use use_window_controls_overlay::*;
 #[cfg(feature = "use_window_focus")]
 pub use use_window_focus::*;
+#[cfg(feature = "use_window_message")]
+pub use use_window_message::*;
 #[cfg(feature = "use_window_scroll")]
 pub use use_window_scroll::*;
 #[cfg(feature = "use_window_size")]
 pub use use_window_size::*;
 #[cfg(feature = "watch_debounced")]
 pub use watch_debounced::*;
+#[cfg(feature = "watch_once")]
+pub use watch_once::*;
 #[cfg(feature = "watch_pausable")]
 pub use watch_pausable::*;
 #[cfg(feature = "watch_throttled")]
 pub use watch_throttled::*;
+#[cfg(feature = "watch_until")]
+pub use watch_until::*;
 #[cfg(feature = "watch_with_options")]
 pub use watch_with_options::*;
 #[cfg(feature = "whenever")]
@@ -1,12 +1,13 @@
 use crate::core::{ElementsMaybeSignal, IntoElementMaybeSignal, IntoElementsMaybeSignal};
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
-    use leptos::prelude::*;
     use crate::utils::IS_IOS;
     use crate::{use_event_listener, use_event_listener_with_options, UseEventListenerOptions, sendwrap_fn};
-    use leptos::ev::{blur, click, pointerdown};
+    use leptos::ev::{blur, click, focusin, keydown, pointerdown};
     use std::cell::Cell;
     use std::rc::Rc;
     use std::sync::RwLock;
@@ -49,10 +50,39 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// > If you are targeting these browsers, we recommend you to include
 /// > [this code snippet](https://gist.github.com/sibbng/13e83b1dd1b733317ce0130ef07d4efd) on your project.
 ///
+/// Clicks are tracked with pointer events, so this covers mouse clicks as well as touch taps and pens.
+///
+/// ## Enabling and Disabling
+///
+/// The returned `enable` and `disable` functions switch the handling on and off without
+/// removing the event listeners. `stop` removes all event listeners for good.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{on_click_outside, OnClickOutsideReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let target = NodeRef::<Div>::new();
+///
+/// let OnClickOutsideReturn { is_enabled, enable, disable, .. } =
+///     on_click_outside(target, move |_| { /* close */ });
+///
+/// view! {
+///     <div node_ref=target>"Hello World"</div>
+///     <button on:click=move |_| if is_enabled.get() { disable() } else { enable() }>
+///         "Toggle"
+///     </button>
+/// }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
-/// The return value of this function is a sendwrapped function to remove all event listeners. It can
-/// only be called from the same thread that called `on_click_outside`.
+/// The returned closure `stop` is a sendwrapped function. It can only be called from the same
+/// thread that called `on_click_outside`. `enable` and `disable` only set a signal and can be
+/// called from any thread.
 ///
 /// ## Excluding Elements
 ///
@@ -81,10 +111,58 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 ///
 /// ```
 ///
+/// ## Focus and Escape Key
+///
+/// For popovers and dropdowns it's usually not enough to only react to clicks. With the options
+/// `detect_focus_outside` the handler is also called when the focus moves to an element outside of the target
+/// (e.g. by pressing `Tab`), and with `detect_escape` when the `Escape` key is pressed.
+/// The handler receives the `focusin` or `keydown` event respectively.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{on_click_outside_with_options, OnClickOutsideOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let target = NodeRef::<Div>::new();
+/// # let (open, set_open) = signal(true);
+/// #
+/// on_click_outside_with_options(
+///     target,
+///     move |_| set_open.set(false),
+///     OnClickOutsideOptions::default()
+///         .detect_focus_outside(true)
+///         .detect_escape(true),
+/// );
+/// #
+/// # view! {
+/// #     <div node_ref=target>"Hello World"</div>
+/// # }
+/// # }
+/// ```
+///
+/// ## Shadow DOM
+///
+/// By default the [composed path](https://developer.mozilla.org/en-US/docs/Web/API/Event/composedPath) of the event
+/// is checked. This way clicks inside the open shadow roots of custom elements count as inside of the target if they
+/// are rendered inside of it, and the target can live inside a shadow root itself.
+///
+/// Set the option `include_shadow` to `false` to respect shadow boundaries. Then only the (retargeted) event target
+/// is checked against the light DOM of the target. Note that elements in `ignore` that are given as CSS selectors are
+/// always looked up in the document and never inside shadow roots.
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this amounts to a no-op.
-pub fn on_click_outside<El, M, F>(target: El, handler: F) -> impl FnOnce() + Clone + Send + Sync
+pub fn on_click_outside<El, M, F>(
+    target: El,
+    handler: F,
+) -> OnClickOutsideReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl FnOnce() + Clone + Send + Sync,
+>
 where
     El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
     F: FnMut(web_sys::Event) + Clone + 'static,
@@ -98,14 +176,25 @@ pub fn on_click_outside_with_options<El, M, F>(
     target: El,
     handler: F,
     options: OnClickOutsideOptions,
-) -> impl FnOnce() + Clone + Send + Sync
+) -> OnClickOutsideReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl FnOnce() + Clone + Send + Sync,
+>
 where
     El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
     F: FnMut(web_sys::Event) + Clone + 'static,
 {
+    let (is_enabled, set_enabled) = signal(true);
+
+    let enable = move || set_enabled.set(true);
+    let disable = move || set_enabled.set(false);
+
+    let stop;
+
     #[cfg(feature = "ssr")]
     {
-        || {}
+        stop = || {};
     }
 
     #[cfg(not(feature = "ssr"))]
@@ -114,6 +203,9 @@ where
             ignore,
             capture,
             detect_iframes,
+            detect_focus_outside,
+            detect_escape,
+            include_shadow,
         } = options;
 
         // Fixes: https://github.com/vueuse/vueuse/issues/1520
@@ -140,13 +232,28 @@ where
 
         let should_listen = Rc::new(Cell::new(true));
 
+        // whether the event happened on or inside of `el`
+        let is_inside = move |event: &web_sys::Event, el: &web_sys::EventTarget| {
+            let Some(event_target) = event.target() else {
+                return false;
+            };
+
+            if include_shadow {
+                *el == event_target || event.composed_path().includes(el.as_ref(), 0)
+            } else {
+                el.dyn_ref::<web_sys::Node>()
+                    .is_some_and(|node| node.contains(event_target.dyn_ref::<web_sys::Node>()))
+                    || *el == event_target
+            }
+        };
+
         let should_ignore = move |event: &web_sys::UiEvent| {
             let ignore = ignore.get_untracked();
 
-            ignore.into_iter().flatten().any(|element| {
-                event_target::<web_sys::EventTarget>(event) == element
-                    || event.composed_path().includes(element.as_ref(), 0)
-            })
+            ignore
+                .into_iter()
+                .flatten()
+                .any(|element| is_inside(event, &element))
         };
 
         let target = target.into_element_maybe_signal();
@@ -158,9 +265,12 @@ where
             let target = target.clone();
 
             move |event: web_sys::UiEvent| {
+                if !is_enabled.get_untracked() {
+                    return;
+                }
+
                 if let Some(el) = target.get_untracked() {
-                    if el == event_target(&event) || event.composed_path().includes(el.as_ref(), 0)
-                    {
+                    if is_inside(&event, &el) {
                         return;
                     }
 
@@ -198,31 +308,90 @@ where
             let target = target.clone();
             let should_listen = Rc::clone(&should_listen);
 
+            let should_ignore = should_ignore.clone();
+
             use_event_listener_with_options::<_, web_sys::Window, _, _>(
                 window(),
                 pointerdown,
                 move |event| {
                     if let Some(el) = target.get_untracked() {
-                        should_listen
-                            .set(!event.composed_path().includes(&el, 0) && !should_ignore(&event));
+                        should_listen.set(!is_inside(&event, &el) && !should_ignore(&event));
                     }
                 },
                 UseEventListenerOptions::default().passive(true),
             )
         };
 
+        let remove_focus_listener = if detect_focus_outside {
+            let target = target.clone();
+            let mut handler = handler.clone();
+
+            Some(use_event_listener_with_options::<_, web_sys::Window, _, _>(
+                window(),
+                focusin,
+                move |event| {
+                    if !is_enabled.get_untracked() {
+                        return;
+                    }
+
+                    if let Some(el) = target.get_untracked() {
+                        if is_inside(&event, &el) || should_ignore(&event) {
+                            return;
+                        }
+
+                        #[cfg(debug_assertions)]
+                        let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                        handler(event.into());
+                    }
+                },
+                UseEventListenerOptions::default()
+                    .passive(true)
+                    .capture(capture),
+            ))
+        } else {
+            None
+        };
+
+        let remove_escape_listener = if detect_escape {
+            let mut handler = handler.clone();
+
+            Some(use_event_listener::<_, web_sys::Window, _, _>(
+                window(),
+                keydown,
+                move |event| {
+                    if !is_enabled.get_untracked() || event.key() != "Escape" {
+                        return;
+                    }
+
+                    #[cfg(debug_assertions)]
+                    let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                    handler(event.into());
+                },
+            ))
+        } else {
+            None
+        };
+
         let remove_blur_listener = if detect_iframes {
             Some(use_event_listener::<_, web_sys::Window, _, _>(
                 window(),
                 blur,
                 move |event| {
+                    if !is_enabled.get_untracked() {
+                        return;
+                    }
+
                     let target = target.clone();
                     let mut handler = handler.clone();
 
                     let _ = set_timeout_with_handle(
                         move || {
                             if let Some(el) = target.get_untracked() {
-                                if let Some(active_element) = document().active_element() {
+                                if let Some(active_element) =
+                                    crate::utils::active_element(include_shadow)
+                                {
                                     if active_element.tag_name() == "IFRAME"
                                         && !el
                                             .unchecked_into::<web_sys::Node>()
@@ -241,13 +410,26 @@ where
             None
         };
 
-        sendwrap_fn!(once move || {
+        stop = sendwrap_fn!(once move || {
             remove_click_listener();
             remove_pointer_listener();
+            if let Some(f) = remove_focus_listener {
+                f();
+            }
+            if let Some(f) = remove_escape_listener {
+                f();
+            }
             if let Some(f) = remove_blur_listener {
                 f();
             }
-        })
+        });
+    }
+
+    OnClickOutsideReturn {
+        is_enabled: is_enabled.into(),
+        enable,
+        disable,
+        stop,
     }
 }
 
@@ -264,6 +446,16 @@ pub struct OnClickOutsideOptions {
 
     /// Run callback if focus moves to an iframe. Defaults to `false`.
     detect_iframes: bool,
+
+    /// Run callback if focus moves to an element outside of the target. Defaults to `false`.
+    detect_focus_outside: bool,
+
+    /// Run callback if the `Escape` key is pressed. Defaults to `false`.
+    detect_escape: bool,
+
+    /// Check the composed path of events to look into open shadow roots. If `false` shadow boundaries are
+    /// respected and only the retargeted event target is checked. Defaults to `true`.
+    include_shadow: bool,
 }
 
 impl Default for OnClickOutsideOptions {
@@ -272,6 +464,9 @@ impl Default for OnClickOutsideOptions {
             ignore: Vec::<web_sys::EventTarget>::new().into_elements_maybe_signal(),
             capture: true,
             detect_iframes: false,
+            detect_focus_outside: false,
+            detect_escape: false,
+            include_shadow: true,
         }
     }
 }
@@ -289,3 +484,23 @@ impl OnClickOutsideOptions {
         }
     }
 }
+
+/// Return type of [`on_click_outside`].
+pub struct OnClickOutsideReturn<EnableFn, DisableFn, StopFn>
+where
+    EnableFn: Fn() + Clone + Send + Sync,
+    DisableFn: Fn() + Clone + Send + Sync,
+    StopFn: FnOnce() + Clone + Send + Sync,
+{
+    /// Whether the handler is currently called on outside interactions
+    pub is_enabled: Signal<bool>,
+
+    /// Start calling the handler again after `disable` has been called
+    pub enable: EnableFn,
+
+    /// Stop calling the handler without removing the event listeners
+    pub disable: DisableFn,
+
+    /// Remove all event listeners
+    pub stop: StopFn,
+}
@@ -10,7 +10,7 @@ use leptos::prelude::window;
 use wasm_bindgen::JsValue;
 
 /// Local or session storage or a custom store that is a `web_sys::Storage`.
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub enum StorageType {
     #[default]
     Local,
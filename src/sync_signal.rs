@@ -187,6 +187,82 @@ use std::rc::Rc;
 /// # view! { }
 /// # }
 /// ```
+///
+/// #### Fallible Transforms
+///
+/// If a transform can fail, like parsing the text of an input into a number, use
+/// `with_fallible_transforms`. The transforms return an `Option` or a `Result`. When they fail,
+/// the other signal keeps its value. Provide a signal with the option `error` to find out what
+/// went wrong.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos::task::Executor;
+/// # use leptos_use::{sync_signal_with_options, SyncSignalOptions};
+/// # use std::str::FromStr;
+/// #
+/// # let _ = Executor::init_futures_executor();
+/// # let owner = Owner::new();
+/// # owner.set();
+/// #
+/// let (text, set_text) = signal("10".to_string());
+/// let (number, set_number) = signal(0);
+/// let (error, set_error) = signal(None);
+///
+/// let stop = sync_signal_with_options(
+///     (text, set_text),
+///     (number, set_number),
+///     SyncSignalOptions::with_fallible_transforms(
+///         |text: &String| i32::from_str(text),
+///         |number: &i32| Some(number.to_string()),
+///     )
+///     .error(set_error),
+/// );
+/// # Executor::poll_local();
+///
+/// set_text.set("abc".to_string());
+/// # Executor::poll_local();
+///
+/// log!("number: {}", number.get()); // number: 10
+/// log!("error: {:?}", error.get().and_then(|e| e.message)); // error: Some("invalid digit found in string")
+/// # assert_eq!(number.get(), 10);
+/// # assert_eq!(error.get().and_then(|e| e.message).as_deref(), Some("invalid digit found in string"));
+/// ```
+///
+/// If the immediate sync from left to right fails, the immediate sync from right to left is skipped.
+/// This way the left signal keeps the value that couldn't be synced instead of being overwritten.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::Executor;
+/// # use leptos_use::{sync_signal_with_options, SyncSignalOptions};
+/// # use std::str::FromStr;
+/// #
+/// # let _ = Executor::init_futures_executor();
+/// # let owner = Owner::new();
+/// # owner.set();
+/// #
+/// let text = RwSignal::new("abc".to_string());
+/// let number = RwSignal::new(5);
+///
+/// sync_signal_with_options(
+///     text,
+///     number,
+///     SyncSignalOptions::with_fallible_transforms(
+///         |text: &String| i32::from_str(text),
+///         |number: &i32| Some(number.to_string()),
+///     ),
+/// );
+/// # Executor::poll_local();
+///
+/// assert_eq!(text.get(), "abc");
+/// assert_eq!(number.get(), 5);
+/// ```
+///
+/// ### Many to One
+///
+/// To sync several signals into one use [`sync_signal_many`].
 pub fn sync_signal<T>(
     left: impl Into<UseRwSignal<T>>,
     right: impl Into<UseRwSignal<T>>,
@@ -211,9 +287,10 @@ where
         immediate,
         direction,
         transforms,
+        error,
     } = options;
 
-    let (assign_ltr, assign_rtl) = transforms.assigns();
+    let (assign_ltr, assign_rtl) = transforms.fallible_assigns();
 
     let left = left.into();
     let right = right.into();
@@ -222,16 +299,50 @@ where
     let mut stop_watch_right = None;
 
     let is_sync_update = StoredValue::new(false);
+    // `true` if the immediate sync from left to right has failed. The immediate sync back from right to
+    // left is skipped then. It would overwrite the left value that couldn't be synced.
+    let is_ltr_immediate_failed = StoredValue::new(false);
+
+    let report = move |result: Result<(), SyncSignalError>| {
+        if let Some(error) = error {
+            let new_error = result.err();
+            error.try_maybe_update(|error| {
+                if *error != new_error {
+                    *error = new_error;
+                    (true, ())
+                } else {
+                    (false, ())
+                }
+            });
+        }
+    };
 
     if matches!(direction, SyncDirection::Both | SyncDirection::LeftToRight) {
         stop_watch_left = Some(Effect::watch(
             move || left.get(),
-            move |new_value, _, _| {
+            move |new_value, prev_value, _| {
                 if !is_sync_update.get_value() {
                     is_sync_update.set_value(true);
-                    right.try_update(|right| {
-                        assign_ltr(right, new_value);
-                    });
+                    let result = right
+                        .try_maybe_update(|right| match assign_ltr(right, new_value) {
+                            Ok(()) => (true, Ok(())),
+                            Err(message) => (false, Err(message)),
+                        })
+                        .unwrap_or(Ok(()));
+
+                    if result.is_err() {
+                        // the right signal isn't notified so its watch won't reset the flag
+                        is_sync_update.set_value(false);
+
+                        if prev_value.is_none() {
+                            is_ltr_immediate_failed.set_value(true);
+                        }
+                    }
+
+                    report(result.map_err(|message| SyncSignalError {
+                        direction: SyncDirection::LeftToRight,
+                        message,
+                    }));
                 } else {
                     is_sync_update.set_value(false);
                 }
@@ -243,10 +354,29 @@ where
     if matches!(direction, SyncDirection::Both | SyncDirection::RightToLeft) {
         stop_watch_right = Some(Effect::watch(
             move || right.get(),
-            move |new_value, _, _| {
+            move |new_value, prev_value, _| {
+                if prev_value.is_none() && is_ltr_immediate_failed.get_value() {
+                    is_ltr_immediate_failed.set_value(false);
+                    return;
+                }
+
                 if !is_sync_update.get_value() {
                     is_sync_update.set_value(true);
-                    left.try_update(|left| assign_rtl(left, new_value));
+                    let result = left
+                        .try_maybe_update(|left| match assign_rtl(left, new_value) {
+                            Ok(()) => (true, Ok(())),
+                            Err(message) => (false, Err(message)),
+                        })
+                        .unwrap_or(Ok(()));
+
+                    if result.is_err() {
+                        is_sync_update.set_value(false);
+                    }
+
+                    report(result.map_err(|message| SyncSignalError {
+                        direction: SyncDirection::RightToLeft,
+                        message,
+                    }));
                 } else {
                     is_sync_update.set_value(false);
                 }
@@ -265,6 +395,217 @@ where
     }
 }
 
+/// Syncs several source signals into one target signal.
+///
+/// Whenever one of the `sources` changes, `aggregate` is called with the values of all sources
+/// and the result is assigned to `target`. If a splitter is provided with
+/// [`SyncSignalManyOptions::split`], changes of `target` are propagated back to the sources as well.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::sync_signal_many;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let first_name = RwSignal::new("Jane".to_string());
+/// let last_name = RwSignal::new("Doe".to_string());
+/// let full_name = RwSignal::new(String::new());
+///
+/// let stop = sync_signal_many(
+///     [first_name, last_name],
+///     full_name,
+///     |names: &[String]| names.join(" "),
+/// );
+///
+/// log!("{}", full_name.get()); // Jane Doe
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// All sources have to be of the same type. Wrap them in an enum if they are not.
+///
+/// Like all effects the syncing happens asynchronously after a source has changed.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::Executor;
+/// # use leptos_use::sync_signal_many;
+/// #
+/// # let _ = Executor::init_futures_executor();
+/// # let owner = Owner::new();
+/// # owner.set();
+/// #
+/// let a = RwSignal::new(1);
+/// let b = RwSignal::new(2);
+/// let sum = RwSignal::new(0);
+///
+/// sync_signal_many([a, b], sum, |values: &[i32]| values.iter().sum());
+/// # Executor::poll_local();
+/// # assert_eq!(sum.get_untracked(), 3);
+///
+/// a.set(5);
+/// # Executor::poll_local();
+/// # assert_eq!(sum.get_untracked(), 7);
+/// ```
+///
+/// ## Splitting
+///
+/// To sync the target back into the sources provide a splitter. It returns the new values of
+/// the sources in the same order as they were passed in.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{sync_signal_many_with_options, SyncSignalManyOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let first_name = RwSignal::new("Jane".to_string());
+/// # let last_name = RwSignal::new("Doe".to_string());
+/// # let full_name = RwSignal::new(String::new());
+/// #
+/// let stop = sync_signal_many_with_options(
+///     [first_name, last_name],
+///     full_name,
+///     |names: &[String]| names.join(" "),
+///     SyncSignalManyOptions::default().split(|full_name: &String| {
+///         let (first, last) = full_name.split_once(' ').unwrap_or((full_name, ""));
+///         vec![first.to_string(), last.to_string()]
+///     }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Selective Sync
+///
+/// Each direction can be switched on and off with the options `sources_to_target` and
+/// `target_to_sources`. Changes that happen while a direction is disabled are not synced
+/// afterwards.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{sync_signal_many_with_options, SyncSignalManyOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let a = RwSignal::new(1);
+/// # let b = RwSignal::new(2);
+/// # let sum = RwSignal::new(0);
+/// let (live_update, set_live_update) = signal(true);
+///
+/// let stop = sync_signal_many_with_options(
+///     [a, b],
+///     sum,
+///     |values: &[i32]| values.iter().sum(),
+///     SyncSignalManyOptions::default().sources_to_target(live_update),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+pub fn sync_signal_many<S, T>(
+    sources: impl IntoIterator<Item = impl Into<UseRwSignal<S>>>,
+    target: impl Into<UseRwSignal<T>>,
+    aggregate: impl Fn(&[S]) -> T + 'static,
+) -> impl Fn() + Clone
+where
+    S: Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
+{
+    sync_signal_many_with_options(sources, target, aggregate, SyncSignalManyOptions::default())
+}
+
+/// Version of [`sync_signal_many`] that takes a `SyncSignalManyOptions`. See [`sync_signal_many`] for how to use.
+pub fn sync_signal_many_with_options<S, T>(
+    sources: impl IntoIterator<Item = impl Into<UseRwSignal<S>>>,
+    target: impl Into<UseRwSignal<T>>,
+    aggregate: impl Fn(&[S]) -> T + 'static,
+    options: SyncSignalManyOptions<S, T>,
+) -> impl Fn() + Clone
+where
+    S: Clone + Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
+{
+    let SyncSignalManyOptions {
+        immediate,
+        sources_to_target,
+        target_to_sources,
+        split,
+    } = options;
+
+    let sources = Rc::new(sources.into_iter().map(Into::into).collect::<Vec<_>>());
+    let target = target.into();
+
+    let is_sync_update = StoredValue::new(false);
+
+    let stop_watch_sources = Effect::watch(
+        {
+            let sources = Rc::clone(&sources);
+            move || {
+                sources
+                    .iter()
+                    .map(|source| source.get())
+                    .collect::<Vec<_>>()
+            }
+        },
+        move |values, _, _| {
+            if is_sync_update.get_value() {
+                is_sync_update.set_value(false);
+                return;
+            }
+
+            if sources_to_target.get_untracked() {
+                is_sync_update.set_value(true);
+                if target.try_set(aggregate(values)).is_some() {
+                    is_sync_update.set_value(false);
+                }
+            }
+        },
+        immediate,
+    );
+
+    // The target is always watched to reset `is_sync_update` after it has been synced.
+    let stop_watch_target = Effect::watch(
+        move || target.get(),
+        move |value, prev_value, _| {
+            // The first run only subscribes to the target. It might run after the immediate sync of the
+            // sources in which case it isn't notified about that change, so reset the flag here.
+            if prev_value.is_none() || is_sync_update.get_value() {
+                is_sync_update.set_value(false);
+                return;
+            }
+
+            let Some(split) = &split else {
+                return;
+            };
+
+            if target_to_sources.get_untracked() {
+                is_sync_update.set_value(true);
+
+                let mut is_any_set = false;
+                for (source, value) in sources.iter().zip(split(value)) {
+                    is_any_set |= source.try_set(value).is_none();
+                }
+
+                if !is_any_set {
+                    is_sync_update.set_value(false);
+                }
+            }
+        },
+        true,
+    );
+
+    move || {
+        stop_watch_sources.stop();
+        stop_watch_target.stop();
+    }
+}
+
 /// Direction of syncing.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum SyncDirection {
@@ -276,6 +617,10 @@ pub enum SyncDirection {
 
 pub type AssignFn<T, S> = Rc<dyn Fn(&mut T, &S)>;
 
+pub type FallibleTransformFn<S, T> = Rc<dyn Fn(&S) -> Result<T, Option<String>>>;
+
+type FallibleAssignFn<T, S> = Rc<dyn Fn(&mut T, &S) -> Result<(), Option<String>>>;
+
 /// Transforms or assigns for syncing.
 pub enum SyncTransforms<L, R> {
     /// Transform the signal into each other by calling the transform functions.
@@ -295,6 +640,15 @@ pub enum SyncTransforms<L, R> {
         /// Assigns the right signal to the left signal.
         rtl: AssignFn<L, R>,
     },
+
+    /// Like `Transforms` but the transforms can fail. If they do, the value is not propagated
+    /// to the other signal. Create this with [`SyncSignalOptions::with_fallible_transforms`].
+    FallibleTransforms {
+        /// Tries to transform the left signal into the right signal.
+        ltr: FallibleTransformFn<L, R>,
+        /// Tries to transform the right signal into the left signal.
+        rtl: FallibleTransformFn<R, L>,
+    },
 }
 
 impl<T> Default for SyncTransforms<T, T>
@@ -326,10 +680,87 @@ where
                 )
             }
             SyncTransforms::Assigns { ltr, rtl } => (Rc::clone(ltr), Rc::clone(rtl)),
+            SyncTransforms::FallibleTransforms { ltr, rtl } => {
+                let ltr = Rc::clone(ltr);
+                let rtl = Rc::clone(rtl);
+                (
+                    Rc::new(move |right, left| {
+                        if let Ok(value) = ltr(left) {
+                            *right = value;
+                        }
+                    }),
+                    Rc::new(move |left, right| {
+                        if let Ok(value) = rtl(right) {
+                            *left = value;
+                        }
+                    }),
+                )
+            }
+        }
+    }
+
+    /// Like [`SyncTransforms::assigns`] but the returned functions report failed transforms.
+    fn fallible_assigns(&self) -> (FallibleAssignFn<R, L>, FallibleAssignFn<L, R>) {
+        match self {
+            SyncTransforms::FallibleTransforms { ltr, rtl } => {
+                let ltr = Rc::clone(ltr);
+                let rtl = Rc::clone(rtl);
+                (
+                    Rc::new(move |right, left| ltr(left).map(|value| *right = value)),
+                    Rc::new(move |left, right| rtl(right).map(|value| *left = value)),
+                )
+            }
+            _ => {
+                let (ltr, rtl) = self.assigns();
+                (
+                    Rc::new(move |right, left| {
+                        ltr(right, left);
+                        Ok(())
+                    }),
+                    Rc::new(move |left, right| {
+                        rtl(left, right);
+                        Ok(())
+                    }),
+                )
+            }
         }
     }
 }
 
+/// Output of the transforms of [`SyncSignalOptions::with_fallible_transforms`].
+///
+/// Implemented for `Option` and for `Result`s whose error can be converted to a string.
+pub trait IntoSyncResult<T> {
+    /// Converts into the transformed value or the error message if there is one.
+    fn into_sync_result(self) -> Result<T, Option<String>>;
+}
+
+impl<T> IntoSyncResult<T> for Option<T> {
+    fn into_sync_result(self) -> Result<T, Option<String>> {
+        self.ok_or(None)
+    }
+}
+
+impl<T, E> IntoSyncResult<T> for Result<T, E>
+where
+    E: ToString,
+{
+    fn into_sync_result(self) -> Result<T, Option<String>> {
+        self.map_err(|err| Some(err.to_string()))
+    }
+}
+
+/// Error that is written to the option `error` of [`sync_signal_with_options`] when a fallible
+/// transform fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyncSignalError {
+    /// Direction of the sync that failed. Either `LeftToRight` or `RightToLeft`.
+    pub direction: SyncDirection,
+
+    /// The message of the error if the transform returned an `Err`. `None` if it returned `None`.
+    pub message: Option<String>,
+}
+
 /// Options for [`sync_signal_with_options`].
 #[derive(DefaultBuilder)]
 pub struct SyncSignalOptions<L, R> {
@@ -347,6 +778,13 @@ pub struct SyncSignalOptions<L, R> {
     /// or [`SyncSignalOptions::with_assigns`].
     #[builder(skip)]
     transforms: SyncTransforms<L, R>,
+
+    /// Signal that is set to the error of a failed fallible transform and reset to `None` when
+    /// the next sync succeeds. Defaults to `None`.
+    ///
+    /// See [`SyncSignalOptions::with_fallible_transforms`].
+    #[builder(into)]
+    error: Option<WriteSignal<Option<SyncSignalError>>>,
 }
 
 impl<L, R> SyncSignalOptions<L, R> {
@@ -362,6 +800,7 @@ impl<L, R> SyncSignalOptions<L, R> {
                 ltr: Rc::new(transform_ltr),
                 rtl: Rc::new(transform_rtl),
             },
+            error: None,
         }
     }
 
@@ -377,6 +816,29 @@ impl<L, R> SyncSignalOptions<L, R> {
                 ltr: Rc::new(assign_ltr),
                 rtl: Rc::new(assign_rtl),
             },
+            error: None,
+        }
+    }
+
+    /// Initializes options with transform functions that can fail. They can return either an
+    /// `Option` or a `Result`. If a transform fails, the other signal is left untouched and the
+    /// error is written to the option `error`.
+    pub fn with_fallible_transforms<OL, OR>(
+        transform_ltr: impl Fn(&L) -> OR + 'static,
+        transform_rtl: impl Fn(&R) -> OL + 'static,
+    ) -> Self
+    where
+        OL: IntoSyncResult<L>,
+        OR: IntoSyncResult<R>,
+    {
+        Self {
+            immediate: true,
+            direction: SyncDirection::Both,
+            transforms: SyncTransforms::FallibleTransforms {
+                ltr: Rc::new(move |left| transform_ltr(left).into_sync_result()),
+                rtl: Rc::new(move |right| transform_rtl(right).into_sync_result()),
+            },
+            error: None,
         }
     }
 }
@@ -390,6 +852,53 @@ where
             immediate: true,
             direction: Default::default(),
             transforms: Default::default(),
+            error: None,
+        }
+    }
+}
+
+pub type SplitFn<T, S> = Rc<dyn Fn(&T) -> Vec<S>>;
+
+/// Options for [`sync_signal_many_with_options`].
+#[derive(DefaultBuilder)]
+pub struct SyncSignalManyOptions<S, T> {
+    /// If `true`, the target is immediately set to the aggregated sources when this function is called.
+    /// Defaults to `true`.
+    immediate: bool,
+
+    /// Whether changes of the sources are synced into the target. Defaults to `true`.
+    #[builder(into)]
+    sources_to_target: Signal<bool>,
+
+    /// Whether changes of the target are synced back into the sources. Only has an effect if
+    /// a splitter is provided with [`SyncSignalManyOptions::split`]. Defaults to `true`.
+    #[builder(into)]
+    target_to_sources: Signal<bool>,
+
+    /// Splits the target into the values of the sources. Defaults to `None` which means that
+    /// the target is not synced back.
+    #[builder(skip)]
+    split: Option<SplitFn<T, S>>,
+}
+
+impl<S, T> SyncSignalManyOptions<S, T> {
+    /// Splits the target into the values of the sources so changes of the target are synced
+    /// back. The returned values have to be in the same order as the sources.
+    pub fn split(self, split: impl Fn(&T) -> Vec<S> + 'static) -> Self {
+        Self {
+            split: Some(Rc::new(split)),
+            ..self
+        }
+    }
+}
+
+impl<S, T> Default for SyncSignalManyOptions<S, T> {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            sources_to_target: Signal::stored(true),
+            target_to_sources: Signal::stored(true),
+            split: None,
         }
     }
 }
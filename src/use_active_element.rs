@@ -1,6 +1,7 @@
 #![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
 
-use crate::{use_document, use_event_listener_with_options, use_window, UseEventListenerOptions};
+use crate::{use_event_listener_with_options, use_window, UseEventListenerOptions};
+use default_struct_builder::DefaultBuilder;
 use leptos::ev::{blur, focus};
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
@@ -30,11 +31,50 @@ use leptos::reactive::wrappers::read::Signal;
 /// # }
 /// ```
 ///
+/// ## Shadow DOM
+///
+/// If the focus is inside the open shadow root of a custom element, the focused element inside the shadow root
+/// is returned instead of the custom element itself. Set the option `include_shadow` to `false` to get the
+/// shadow host like `document.activeElement` does.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_active_element_with_options, UseActiveElementOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let active_host = use_active_element_with_options(
+///     UseActiveElementOptions::default().include_shadow(false),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns a `Signal` that always contains the value `None`.
 pub fn use_active_element() -> Signal<Option<web_sys::Element>, LocalStorage> {
-    let get_active_element = move || use_document().active_element();
+    use_active_element_with_options(UseActiveElementOptions::default())
+}
+
+/// Version of [`use_active_element`] that takes a `UseActiveElementOptions`. See [`use_active_element`] for how to use.
+pub fn use_active_element_with_options(
+    options: UseActiveElementOptions,
+) -> Signal<Option<web_sys::Element>, LocalStorage> {
+    let UseActiveElementOptions { include_shadow } = options;
+
+    let get_active_element = move || {
+        #[cfg(feature = "ssr")]
+        {
+            None
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::utils::active_element(include_shadow)
+        }
+    };
 
     let (active_element, set_active_element) = signal_local(get_active_element());
 
@@ -64,3 +104,20 @@ pub fn use_active_element() -> Signal<Option<web_sys::Element>, LocalStorage> {
 
     active_element.into()
 }
+
+/// Options for [`use_active_element_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseActiveElementOptions {
+    /// Return the focused element inside open shadow roots instead of their host element.
+    /// Defaults to `true`.
+    include_shadow: bool,
+}
+
+impl Default for UseActiveElementOptions {
+    fn default() -> Self {
+        Self {
+            include_shadow: true,
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use crate::core::{Direction, IntoElementMaybeSignal};
+use crate::use_floating::Alignment;
+use crate::{
+    use_floating_with_options, use_supported, FloatingStrategy, Placement, UseFloatingOptions,
+    UseFloatingReturn,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Keeps a floating element attached to an anchor element using
+/// [CSS Anchor Positioning](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_anchor_positioning)
+/// if the browser supports it and falls back to positioning it with JS otherwise.
+///
+/// With native support the browser takes care of keeping the floating element in place while
+/// scrolling and resizing. This function only assigns a unique `anchor-name` to the anchor element and returns
+/// the `floating_styles` that attach the floating element to it. Without support the position is computed with
+/// [`fn@crate::use_floating`] and updated on scroll and resize.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_anchor_position)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::{Button, Div};
+/// # use leptos_use::{use_anchor_position_with_options, Placement, UseAnchorPositionOptions, UseAnchorPositionReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let anchor = NodeRef::<Button>::new();
+/// let floating = NodeRef::<Div>::new();
+///
+/// let UseAnchorPositionReturn { floating_styles, .. } = use_anchor_position_with_options(
+///     anchor,
+///     floating,
+///     UseAnchorPositionOptions::default()
+///         .placement(Placement::BottomStart)
+///         .offset(4.0)
+///         .flip(true),
+/// );
+///
+/// view! {
+///     <button node_ref=anchor>"Anchor"</button>
+///     <div node_ref=floating style=floating_styles>"Floating"</div>
+/// }
+/// # }
+/// ```
+///
+/// The floating element is always positioned with `position: fixed`.
+///
+/// Please note that with native support the returned `placement` is always the requested one, because the
+/// browser doesn't report if a fallback position (due to `flip`) has been applied.
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `update` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_anchor_position`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this behaves like [`fn@crate::use_floating`] which means the returned `floating_styles`
+/// position the element at `0px` / `0px`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_floating`]
+pub fn use_anchor_position<AnchorEl, AnchorM, FloatingEl, FloatingM>(
+    anchor: AnchorEl,
+    floating: FloatingEl,
+) -> UseAnchorPositionReturn<impl Fn() + Clone + Send + Sync>
+where
+    AnchorEl: IntoElementMaybeSignal<web_sys::Element, AnchorM>,
+    FloatingEl: IntoElementMaybeSignal<web_sys::Element, FloatingM>,
+{
+    use_anchor_position_with_options(anchor, floating, UseAnchorPositionOptions::default())
+}
+
+/// Version of [`use_anchor_position`] that takes a `UseAnchorPositionOptions`. See [`use_anchor_position`] for how to use.
+pub fn use_anchor_position_with_options<AnchorEl, AnchorM, FloatingEl, FloatingM>(
+    anchor: AnchorEl,
+    floating: FloatingEl,
+    options: UseAnchorPositionOptions,
+) -> UseAnchorPositionReturn<impl Fn() + Clone + Send + Sync>
+where
+    AnchorEl: IntoElementMaybeSignal<web_sys::Element, AnchorM>,
+    FloatingEl: IntoElementMaybeSignal<web_sys::Element, FloatingM>,
+{
+    let UseAnchorPositionOptions {
+        placement,
+        offset,
+        flip,
+    } = options;
+
+    let is_supported = use_supported(is_anchor_positioning_supported);
+
+    let anchor = anchor.into_element_maybe_signal();
+
+    // the fallback only sees the anchor if there is no native support which keeps it idle otherwise
+    let fallback_anchor = {
+        let anchor = anchor.clone();
+
+        Signal::derive_local(move || {
+            if is_supported.get() {
+                None
+            } else {
+                anchor.get()
+            }
+        })
+    };
+
+    let UseFloatingReturn {
+        placement: fallback_placement,
+        floating_styles: fallback_styles,
+        update,
+        ..
+    } = use_floating_with_options::<_, _, FloatingEl, FloatingM, web_sys::Element, _>(
+        fallback_anchor,
+        floating,
+        UseFloatingOptions::default()
+            .placement(placement)
+            .strategy(FloatingStrategy::Fixed)
+            .offset(offset)
+            .flip(flip),
+    );
+
+    let anchor_name = next_anchor_name();
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use wasm_bindgen::JsCast;
+
+        let anchor_name = anchor_name.clone();
+
+        let set_anchor_name = move |el: &Option<web_sys::Element>, name: Option<&str>| {
+            if let Some(el) = el
+                .as_ref()
+                .and_then(|el| el.dyn_ref::<web_sys::HtmlElement>())
+            {
+                let _ = match name {
+                    Some(name) => el.style().set_property("anchor-name", name),
+                    None => el.style().remove_property("anchor-name").map(|_| ()),
+                };
+            }
+        };
+
+        Effect::watch(
+            move || (anchor.get(), is_supported.get()),
+            move |(el, supported), prev, _| {
+                if let Some((prev_el, _)) = prev {
+                    set_anchor_name(prev_el, None);
+                }
+
+                if *supported {
+                    set_anchor_name(el, Some(&anchor_name));
+                }
+            },
+            true,
+        );
+    }
+
+    UseAnchorPositionReturn {
+        is_supported,
+        floating_styles: Signal::derive(move || {
+            if is_supported.get() {
+                native_styles(placement.get(), &anchor_name, offset, flip)
+            } else {
+                fallback_styles.get()
+            }
+        }),
+        placement: Signal::derive(move || {
+            if is_supported.get() {
+                placement.get()
+            } else {
+                fallback_placement.get()
+            }
+        }),
+        update,
+    }
+}
+
+fn is_anchor_positioning_supported() -> bool {
+    #[cfg(feature = "ssr")]
+    {
+        false
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        web_sys::css::supports("anchor-name: --a").unwrap_or_default()
+    }
+}
+
+fn next_anchor_name() -> String {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    format!(
+        "--leptos-use-anchor-{}",
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+fn native_styles(placement: Placement, anchor_name: &str, offset: f64, flip: bool) -> String {
+    let side = placement.side();
+
+    let (inset, margin) = match side {
+        Direction::Top => ("bottom: anchor(top)", "margin-bottom"),
+        Direction::Bottom => ("top: anchor(bottom)", "margin-top"),
+        Direction::Left => ("right: anchor(left)", "margin-right"),
+        Direction::Right => ("left: anchor(right)", "margin-left"),
+    };
+
+    let vertical = matches!(side, Direction::Top | Direction::Bottom);
+
+    let alignment = match (placement.alignment(), vertical) {
+        (Alignment::Center, true) => "justify-self: anchor-center",
+        (Alignment::Start, true) => "left: anchor(left)",
+        (Alignment::End, true) => "right: anchor(right)",
+        (Alignment::Center, false) => "align-self: anchor-center",
+        (Alignment::Start, false) => "top: anchor(top)",
+        (Alignment::End, false) => "bottom: anchor(bottom)",
+    };
+
+    let mut styles = format!(
+        "position: fixed; position-anchor: {anchor_name}; {inset}; {alignment}; {margin}: {offset}px;"
+    );
+
+    if flip {
+        styles.push_str(if vertical {
+            " position-try-fallbacks: flip-block;"
+        } else {
+            " position-try-fallbacks: flip-inline;"
+        });
+    }
+
+    styles
+}
+
+/// Options for [`use_anchor_position_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseAnchorPositionOptions {
+    /// Where to place the floating element relative to the anchor. Defaults to `Placement::Bottom`.
+    #[builder(into)]
+    placement: Signal<Placement>,
+
+    /// Distance in px between the anchor and the floating element. Defaults to `0.0`.
+    offset: f64,
+
+    /// Place the floating element on the opposite side if it doesn't fit on the chosen side. Defaults to `false`.
+    flip: bool,
+}
+
+impl Default for UseAnchorPositionOptions {
+    fn default() -> Self {
+        Self {
+            placement: Placement::Bottom.into(),
+            offset: 0.0,
+            flip: false,
+        }
+    }
+}
+
+/// Return type of [`use_anchor_position`].
+pub struct UseAnchorPositionReturn<F>
+where
+    F: Fn() + Clone + Send + Sync,
+{
+    /// Whether CSS Anchor Positioning is supported. If not, the JS fallback is used.
+    pub is_supported: Signal<bool>,
+    /// CSS styles to apply to the floating element
+    pub floating_styles: Signal<String>,
+    /// The placement of the floating element. Only differs from the requested one in the fallback if `flip` has been applied.
+    pub placement: Signal<Placement>,
+    /// Recompute the position manually. Only has an effect in the fallback.
+    pub update: F,
+}
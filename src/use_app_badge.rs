@@ -0,0 +1,203 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{sendwrap_fn, use_media_query, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive [Badging API](https://developer.mozilla.org/en-US/docs/Web/API/Badging_API).
+///
+/// Shows a counter on the icon of an installed web app. If the badge can't be shown, because the API isn't
+/// supported, the browser refuses to set it or the app runs in a regular browser tab, the counter is shown
+/// in front of the document title like `(3) MyApp` instead.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_app_badge)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_app_badge, UseAppBadgeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseAppBadgeReturn { is_supported, set_badge, clear_badge, .. } = use_app_badge();
+///
+/// view! {
+///     <button on:click=move |_| set_badge(3)>"Three unread messages"</button>
+///     <button on:click=move |_| clear_badge()>"Mark all as read"</button>
+/// }
+/// # }
+/// ```
+///
+/// Setting the badge to `0` is the same as clearing it.
+///
+/// The title fallback only touches the counter prefix. The rest of the current document title is kept, even if
+/// it's changed later, and the prefix is removed when the component is cleaned up. To disable it set the option
+/// `title_fallback` to `false`.
+/// The app badge itself is kept after cleanup as it belongs to the app and not to the component.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false` and no badge is shown.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_document_title`]
+/// * [`fn@crate::use_favicon`]
+pub fn use_app_badge(
+) -> UseAppBadgeReturn<impl Fn(usize) + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_app_badge_with_options(UseAppBadgeOptions::default())
+}
+
+/// Version of [`use_app_badge`] that takes a `UseAppBadgeOptions`. See [`use_app_badge`] for how to use.
+pub fn use_app_badge_with_options(
+    options: UseAppBadgeOptions,
+) -> UseAppBadgeReturn<impl Fn(usize) + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseAppBadgeOptions { title_fallback } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("setAppBadge" in &window().navigator())
+        }
+    });
+
+    let (count, set_count) = signal(0_usize);
+    // `true` if the browser has refused to set the badge, e.g. because the app isn't installed
+    let (is_rejected, set_rejected) = signal(false);
+
+    // the badge is only visible for installed apps that don't run in a browser tab
+    let is_browser_tab = use_media_query("(display-mode: browser)");
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use wasm_bindgen::{JsCast, JsValue};
+
+        let _ = Effect::watch(
+            move || (count.get(), is_supported.get()),
+            move |(count, supported), prev, _| {
+                // don't clear a badge of the app that hasn't been set by this component
+                let was_set = matches!(prev, Some((prev_count, _)) if *prev_count > 0);
+
+                if !supported || (*count == 0 && !was_set) {
+                    return;
+                }
+
+                let navigator = window().navigator();
+
+                let result = if *count == 0 {
+                    crate::js!(navigator["clearAppBadge"]).and_then(|clear| {
+                        clear.unchecked_into::<js_sys::Function>().call0(&navigator)
+                    })
+                } else {
+                    crate::js!(navigator["setAppBadge"]).and_then(|set| {
+                        set.unchecked_into::<js_sys::Function>()
+                            .call1(&navigator, &JsValue::from(*count as f64))
+                    })
+                };
+
+                match result.and_then(|promise| promise.dyn_into::<js_sys::Promise>()) {
+                    Ok(promise) => {
+                        leptos::task::spawn_local(async move {
+                            let is_err = crate::js_fut!(promise).await.is_err();
+                            let _ = set_rejected.try_set(is_err);
+                        });
+                    }
+                    Err(_) => set_rejected.set(true),
+                }
+            },
+            false,
+        );
+
+        if title_fallback {
+            // the prefix like `"(3) "` that has been put in front of the document title
+            let title_prefix = StoredValue::new(None::<String>);
+
+            let replace_prefix = move |prefix: Option<String>| {
+                let title = document().title();
+
+                // the title might have been changed in the meantime so the current one is used
+                let title = match title_prefix.get_value() {
+                    Some(old_prefix) => title
+                        .strip_prefix(&old_prefix)
+                        .map(str::to_string)
+                        .unwrap_or(title),
+                    None => title,
+                };
+
+                document().set_title(&format!("{}{title}", prefix.as_deref().unwrap_or_default()));
+                title_prefix.set_value(prefix);
+            };
+
+            Effect::new(move || {
+                let count = count.get();
+                let show_in_title =
+                    !is_supported.get() || is_browser_tab.get() || is_rejected.get();
+
+                let prefix = (show_in_title && count > 0).then(|| format!("({count}) "));
+
+                if prefix != title_prefix.get_value() {
+                    replace_prefix(prefix);
+                }
+            });
+
+            on_cleanup(sendwrap_fn!(move || {
+                if title_prefix
+                    .try_with_value(Option::is_some)
+                    .unwrap_or_default()
+                {
+                    replace_prefix(None);
+                }
+            }));
+        }
+    }
+
+    UseAppBadgeReturn {
+        is_supported,
+        count: count.into(),
+        set_badge: move |count: usize| set_count.set(count),
+        clear_badge: move || set_count.set(0),
+    }
+}
+
+/// Options for [`use_app_badge_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseAppBadgeOptions {
+    /// Show the counter in front of the document title if the badge can't be shown. Defaults to `true`.
+    title_fallback: bool,
+}
+
+impl Default for UseAppBadgeOptions {
+    fn default() -> Self {
+        Self {
+            title_fallback: true,
+        }
+    }
+}
+
+/// Return type of [`use_app_badge`].
+pub struct UseAppBadgeReturn<SetBadgeFn, ClearBadgeFn>
+where
+    SetBadgeFn: Fn(usize) + Clone + Send + Sync,
+    ClearBadgeFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the Badging API is supported. If not, the title fallback is used.
+    pub is_supported: Signal<bool>,
+
+    /// The current count of the badge
+    pub count: Signal<usize>,
+
+    /// Set the badge to the given count. `0` clears the badge.
+    pub set_badge: SetBadgeFn,
+
+    /// Clear the badge
+    pub clear_badge: ClearBadgeFn,
+}
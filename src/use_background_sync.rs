@@ -0,0 +1,316 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, sendwrap_fn, use_permission, use_supported, PermissionState};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::JsValue;
+
+/// Register tags for the [Background Synchronization API](https://developer.mozilla.org/en-US/docs/Web/API/Background_Synchronization_API)
+/// and the [Periodic Background Synchronization API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Periodic_Background_Synchronization_API).
+///
+/// A one-shot sync tag makes the browser fire a `sync` event in your service worker as soon as the device
+/// has connectivity. A periodic sync tag fires `periodicsync` events with at least the given interval.
+/// Both use the registration of the active service worker. You can register it with
+/// [`fn@crate::use_service_worker`].
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_background_sync)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_background_sync, UseBackgroundSyncReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseBackgroundSyncReturn {
+///     is_supported,
+///     pending_tags,
+///     register,
+///     ..
+/// } = use_background_sync();
+///
+/// let send_message = move |_| {
+///     // store the message in IndexedDB, then let the service worker send it once online
+///     register("send-messages");
+/// };
+///
+/// view! {
+///     <button on:click=send_message disabled=move || !is_supported.get()>"Send"</button>
+///     <p>{move || pending_tags.get().len()} " syncs pending"</p>
+/// }
+/// # }
+/// ```
+///
+/// ## Periodic Sync
+///
+/// Periodic sync is only available for installed web apps and requires the permission
+/// `periodic-background-sync`. Its state is available as `periodic_permission`. If it's denied
+/// `register_periodic` sets `error` to [`UseBackgroundSyncError::PermissionDenied`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_background_sync, UseBackgroundSyncReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseBackgroundSyncReturn {
+///     periodic_tags,
+///     register_periodic,
+///     unregister_periodic,
+///     ..
+/// } = use_background_sync();
+///
+/// // refresh the news at most once a day
+/// register_periodic("news", 24 * 60 * 60 * 1000);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `register`, `register_periodic`, `unregister_periodic` and `refresh` are
+/// sendwrapped functions. They can only be called from the same thread that called `use_background_sync`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the support signals are `false`, the tag lists are empty and the returned
+/// closures do nothing.
+#[allow(clippy::type_complexity)]
+pub fn use_background_sync() -> UseBackgroundSyncReturn<
+    impl Fn(&str) + Clone + Send + Sync,
+    impl Fn(&str, u64) + Clone + Send + Sync,
+    impl Fn(&str) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+> {
+    let is_supported = use_supported(|| {
+        js!("serviceWorker" in &window().navigator()) && js!("SyncManager" in &window())
+    });
+    let is_periodic_supported = use_supported(|| {
+        js!("serviceWorker" in &window().navigator()) && js!("PeriodicSyncManager" in &window())
+    });
+
+    let periodic_permission = use_permission("periodic-background-sync");
+
+    let (pending_tags, set_pending_tags) = signal(Vec::<String>::new());
+    let (periodic_tags, set_periodic_tags) = signal(Vec::<String>::new());
+    let (error, set_error) = signal(None::<UseBackgroundSyncError>);
+
+    let refresh = move || {
+        #[cfg(not(feature = "ssr"))]
+        leptos::task::spawn_local(async move {
+            if is_supported.get_untracked() {
+                match get_tags(SYNC_MANAGER).await {
+                    Ok(tags) => {
+                        set_pending_tags.try_set(tags);
+                    }
+                    Err(err) => {
+                        set_error.try_set(Some(err));
+                    }
+                }
+            }
+
+            if is_periodic_supported.get_untracked() {
+                match get_tags(PERIODIC_SYNC_MANAGER).await {
+                    Ok(tags) => {
+                        set_periodic_tags.try_set(tags);
+                    }
+                    Err(err) => {
+                        set_error.try_set(Some(err));
+                    }
+                }
+            }
+        });
+    };
+
+    refresh();
+
+    let register = sendwrap_fn!(move |tag: &str| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() {
+                set_error.set(Some(UseBackgroundSyncError::NotSupported));
+                return;
+            }
+
+            let args = js_sys::Array::of1(&JsValue::from_str(tag));
+
+            leptos::task::spawn_local(async move {
+                match call_manager(SYNC_MANAGER, "register", &args).await {
+                    Ok(_) => {
+                        set_error.try_set(None);
+                        refresh();
+                    }
+                    Err(err) => {
+                        set_error.try_set(Some(err));
+                    }
+                }
+            });
+        }
+    });
+
+    let register_periodic = sendwrap_fn!(move |tag: &str, min_interval: u64| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_periodic_supported.get_untracked() {
+                set_error.set(Some(UseBackgroundSyncError::NotSupported));
+                return;
+            }
+
+            if periodic_permission.get_untracked() == PermissionState::Denied {
+                set_error.set(Some(UseBackgroundSyncError::PermissionDenied));
+                return;
+            }
+
+            let options = js_sys::Object::new();
+            js!(options["minInterval"] = min_interval as f64);
+
+            let args = js_sys::Array::of2(&JsValue::from_str(tag), &options);
+
+            leptos::task::spawn_local(async move {
+                match call_manager(PERIODIC_SYNC_MANAGER, "register", &args).await {
+                    Ok(_) => {
+                        set_error.try_set(None);
+                        refresh();
+                    }
+                    Err(err) => {
+                        set_error.try_set(Some(err));
+                    }
+                }
+            });
+        }
+    });
+
+    let unregister_periodic = sendwrap_fn!(move |tag: &str| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_periodic_supported.get_untracked() {
+                return;
+            }
+
+            let args = js_sys::Array::of1(&JsValue::from_str(tag));
+
+            leptos::task::spawn_local(async move {
+                match call_manager(PERIODIC_SYNC_MANAGER, "unregister", &args).await {
+                    Ok(_) => refresh(),
+                    Err(err) => {
+                        set_error.try_set(Some(err));
+                    }
+                }
+            });
+        }
+    });
+
+    UseBackgroundSyncReturn {
+        is_supported,
+        is_periodic_supported,
+        periodic_permission,
+        pending_tags: pending_tags.into(),
+        periodic_tags: periodic_tags.into(),
+        error: error.into(),
+        register,
+        register_periodic,
+        unregister_periodic,
+        refresh: sendwrap_fn!(move || refresh()),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+const SYNC_MANAGER: &str = "sync";
+#[cfg(not(feature = "ssr"))]
+const PERIODIC_SYNC_MANAGER: &str = "periodicSync";
+
+/// Calls `method` of the sync manager `manager` of the ready service worker registration.
+#[cfg(not(feature = "ssr"))]
+async fn call_manager(
+    manager: &str,
+    method: &str,
+    args: &js_sys::Array,
+) -> Result<JsValue, UseBackgroundSyncError> {
+    use wasm_bindgen::JsCast;
+
+    let js_error = |err: JsValue| UseBackgroundSyncError::Js(SendWrapper::new(err));
+
+    let ready = window()
+        .navigator()
+        .service_worker()
+        .ready()
+        .map_err(js_error)?;
+    let registration = crate::js_fut!(ready).await.map_err(js_error)?;
+
+    let manager = js_sys::Reflect::get(&registration, &manager.into()).map_err(js_error)?;
+    let method: js_sys::Function = js_sys::Reflect::get(&manager, &method.into())
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(js_error)?;
+
+    let promise: js_sys::Promise = js_sys::Reflect::apply(&method, &manager, args)
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(js_error)?;
+
+    crate::js_fut!(promise).await.map_err(js_error)
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn get_tags(manager: &str) -> Result<Vec<String>, UseBackgroundSyncError> {
+    let tags = call_manager(manager, "getTags", &js_sys::Array::new()).await?;
+
+    Ok(js_sys::Array::from(&tags)
+        .iter()
+        .filter_map(|tag| tag.as_string())
+        .collect())
+}
+
+#[derive(Debug, Clone)]
+pub enum UseBackgroundSyncError {
+    /// The browser doesn't support the requested kind of background sync
+    NotSupported,
+    /// The permission `periodic-background-sync` has been denied
+    PermissionDenied,
+    /// A call of the browser API failed
+    Js(SendWrapper<JsValue>),
+}
+
+/// Return type of [`use_background_sync`].
+pub struct UseBackgroundSyncReturn<RegisterFn, RegisterPeriodicFn, UnregisterPeriodicFn, RefreshFn>
+where
+    RegisterFn: Fn(&str) + Clone + Send + Sync,
+    RegisterPeriodicFn: Fn(&str, u64) + Clone + Send + Sync,
+    UnregisterPeriodicFn: Fn(&str) + Clone + Send + Sync,
+    RefreshFn: Fn() + Clone + Send + Sync,
+{
+    /// `true` if the browser supports one-shot background sync
+    pub is_supported: Signal<bool>,
+
+    /// `true` if the browser supports periodic background sync
+    pub is_periodic_supported: Signal<bool>,
+
+    /// State of the permission `periodic-background-sync`
+    pub periodic_permission: Signal<PermissionState>,
+
+    /// One-shot sync tags that haven't fired yet
+    pub pending_tags: Signal<Vec<String>>,
+
+    /// Registered periodic sync tags
+    pub periodic_tags: Signal<Vec<String>>,
+
+    /// The error of the last failed operation. Reset by the next successful registration.
+    pub error: Signal<Option<UseBackgroundSyncError>>,
+
+    /// Registers a one-shot sync with the given tag
+    pub register: RegisterFn,
+
+    /// Registers a periodic sync with the given tag and minimum interval in milliseconds
+    pub register_periodic: RegisterPeriodicFn,
+
+    /// Unregisters the periodic sync with the given tag
+    pub unregister_periodic: UnregisterPeriodicFn,
+
+    /// Reads `pending_tags` and `periodic_tags` again, for example after the service worker handled a sync
+    pub refresh: RefreshFn,
+}
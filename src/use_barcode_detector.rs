@@ -0,0 +1,500 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::utils::{LocalBoxFuture, Pausable};
+use crate::{
+    use_interval_fn_with_options, use_raf_fn_with_options, use_supported, UseIntervalFnOptions,
+    UseRafFnOptions,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Detect barcodes and QR codes in images and videos with the
+/// [Barcode Detection API](https://developer.mozilla.org/en-US/docs/Web/API/Barcode_Detection_API).
+///
+/// The source can be an `<img>`, `<video>` or `<canvas>` element. While scanning, the detected barcodes
+/// are updated continuously.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_barcode_detector)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_barcode_detector, UseBarcodeDetectorReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video = NodeRef::<Video>::new();
+///
+/// let UseBarcodeDetectorReturn { barcodes, .. } = use_barcode_detector(video);
+///
+/// view! {
+///     <video node_ref=video autoplay muted />
+///     <ul>
+///         <For each=move || barcodes.get() key=|barcode| barcode.raw_value.clone() let:barcode>
+///             <li>{barcode.format} ": " {barcode.raw_value}</li>
+///         </For>
+///     </ul>
+/// }
+/// # }
+/// ```
+///
+/// ## Scanning
+///
+/// By default the source is scanned every 500 milliseconds. With the option `mode` you can scan on every
+/// animation frame instead or only when calling `detect`. Scanning can be paused and resumed with
+/// `pause` and `resume`. Only the given `formats` are detected if you provide any.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Img;
+/// # use leptos_use::{use_barcode_detector_with_options, BarcodeScanMode, UseBarcodeDetectorOptions, UseBarcodeDetectorReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let img = NodeRef::<Img>::new();
+///
+/// let UseBarcodeDetectorReturn { barcodes, detect, .. } = use_barcode_detector_with_options(
+///     img,
+///     UseBarcodeDetectorOptions::default()
+///         .mode(BarcodeScanMode::Manual)
+///         .formats(vec!["qr_code".to_string()]),
+/// );
+///
+/// view! { <img node_ref=img src="qr.png" on:load=move |_| detect() /> }
+/// # }
+/// ```
+///
+/// ## Fallback
+///
+/// The Barcode Detection API is not available in all browsers. You can provide a `fallback` which is used
+/// for detection instead, for example one based on a barcode reading library compiled to wasm.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_barcode_detector_with_options, UseBarcodeDetectorOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let video = NodeRef::<Video>::new();
+/// use_barcode_detector_with_options(
+///     video,
+///     UseBarcodeDetectorOptions::default().fallback(|source: web_sys::Element| {
+///         Box::pin(async move {
+///             // draw `source` onto a canvas, read the pixels and decode them
+///             vec![]
+///         })
+///     }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `detect`, `pause` and `resume` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_barcode_detector`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false` and no barcodes are detected.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_user_media`]
+pub fn use_barcode_detector<El, M>(
+    source: El,
+) -> UseBarcodeDetectorReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_barcode_detector_with_options(source, UseBarcodeDetectorOptions::default())
+}
+
+/// Version of [`use_barcode_detector`] that takes a `UseBarcodeDetectorOptions`. See [`use_barcode_detector`] for how to use.
+pub fn use_barcode_detector_with_options<El, M>(
+    source: El,
+    options: UseBarcodeDetectorOptions,
+) -> UseBarcodeDetectorReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseBarcodeDetectorOptions {
+        formats,
+        mode,
+        immediate,
+        fallback,
+    } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("BarcodeDetector" in &window())
+        }
+    });
+
+    let source = source.into_element_maybe_signal();
+
+    let (barcodes, set_barcodes) = signal(Vec::<DetectedBarcode>::new());
+
+    let detect;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_barcodes;
+
+        detect = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js_fut, sendwrap_fn};
+        use std::cell::{Cell, RefCell};
+        use std::rc::Rc;
+
+        let detector = Rc::new(RefCell::new(None::<wasm_bindgen::JsValue>));
+
+        // skip scans while the previous one is still running
+        let is_detecting = Rc::new(Cell::new(false));
+
+        detect = sendwrap_fn!(move || {
+            if is_detecting.get() {
+                return;
+            }
+
+            let Some(el) = source.get_untracked() else {
+                return;
+            };
+
+            let future: BarcodeDetectFuture = if is_supported.get_untracked() {
+                let detector = detector
+                    .borrow_mut()
+                    .get_or_insert_with(|| create_detector(&formats))
+                    .clone();
+
+                let Some(promise) = detect_native(&detector, &el) else {
+                    return;
+                };
+
+                Box::pin(async move {
+                    // rejects e.g. if the video has no data yet
+                    js_fut!(promise)
+                        .await
+                        .map(|result| {
+                            js_sys::Array::from(&result)
+                                .iter()
+                                .map(parse_barcode)
+                                .collect()
+                        })
+                        .unwrap_or_default()
+                })
+            } else if let Some(fallback) = &fallback {
+                fallback(el)
+            } else {
+                return;
+            };
+
+            is_detecting.set(true);
+
+            let is_detecting = Rc::clone(&is_detecting);
+
+            leptos::task::spawn_local(async move {
+                let detected = future.await;
+
+                if barcodes
+                    .try_with_untracked(|barcodes| *barcodes != detected)
+                    .unwrap_or_default()
+                {
+                    let _ = set_barcodes.try_set(detected);
+                }
+
+                is_detecting.set(false);
+            });
+        });
+    }
+
+    #[allow(clippy::clone_on_copy)]
+    let Pausable {
+        pause: pause_interval,
+        resume: resume_interval,
+        is_active: is_interval_active,
+    } = use_interval_fn_with_options(
+        detect.clone(),
+        match mode {
+            BarcodeScanMode::Interval(interval) => interval,
+            _ => 500,
+        },
+        UseIntervalFnOptions::default().immediate(false),
+    );
+
+    #[allow(clippy::clone_on_copy)]
+    let Pausable {
+        pause: pause_raf,
+        resume: resume_raf,
+        is_active: is_raf_active,
+    } = use_raf_fn_with_options(
+        {
+            let detect = detect.clone();
+            move |_| detect()
+        },
+        UseRafFnOptions::default().immediate(false),
+    );
+
+    let pause = move || match mode {
+        BarcodeScanMode::Interval(_) => pause_interval(),
+        BarcodeScanMode::AnimationFrame => pause_raf(),
+        BarcodeScanMode::Manual => {}
+    };
+
+    let resume = move || match mode {
+        BarcodeScanMode::Interval(_) => resume_interval(),
+        BarcodeScanMode::AnimationFrame => resume_raf(),
+        BarcodeScanMode::Manual => {}
+    };
+
+    if immediate {
+        resume();
+    }
+
+    UseBarcodeDetectorReturn {
+        is_supported,
+        barcodes: barcodes.into(),
+        is_active: Signal::derive(move || match mode {
+            BarcodeScanMode::Interval(_) => is_interval_active.get(),
+            BarcodeScanMode::AnimationFrame => is_raf_active.get(),
+            BarcodeScanMode::Manual => false,
+        }),
+        detect,
+        pause,
+        resume,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn create_detector(formats: &[String]) -> wasm_bindgen::JsValue {
+    use wasm_bindgen::{JsCast, JsValue};
+
+    let window = window();
+
+    let args = js_sys::Array::new();
+
+    if !formats.is_empty() {
+        let options = js_sys::Object::new();
+        crate::js!(
+            options["formats"] = formats
+                .iter()
+                .map(|format| JsValue::from_str(format))
+                .collect::<js_sys::Array>()
+        );
+        args.push(&options);
+    }
+
+    crate::js!(window["BarcodeDetector"])
+        .and_then(|constructor| {
+            js_sys::Reflect::construct(&constructor.unchecked_into::<js_sys::Function>(), &args)
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn detect_native(
+    detector: &wasm_bindgen::JsValue,
+    el: &web_sys::Element,
+) -> Option<js_sys::Promise> {
+    use wasm_bindgen::JsCast;
+
+    js_sys::Reflect::get(detector, &"detect".into())
+        .and_then(|detect| {
+            detect
+                .unchecked_into::<js_sys::Function>()
+                .call1(detector, el)
+        })
+        .ok()?
+        .dyn_into::<js_sys::Promise>()
+        .ok()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn parse_barcode(barcode: wasm_bindgen::JsValue) -> DetectedBarcode {
+    use crate::js;
+
+    let number = |obj: &wasm_bindgen::JsValue, key: &str| {
+        js_sys::Reflect::get(obj, &key.into())
+            .ok()
+            .and_then(|value| value.as_f64())
+            .unwrap_or_default()
+    };
+
+    let string = |key: &str| {
+        js_sys::Reflect::get(&barcode, &key.into())
+            .ok()
+            .and_then(|value| value.as_string())
+            .unwrap_or_default()
+    };
+
+    let bounding_box = js!(barcode["boundingBox"])
+        .map(|rect| BarcodeBoundingBox {
+            x: number(&rect, "x"),
+            y: number(&rect, "y"),
+            width: number(&rect, "width"),
+            height: number(&rect, "height"),
+        })
+        .unwrap_or_default();
+
+    let corner_points = js!(barcode["cornerPoints"])
+        .map(|points| {
+            js_sys::Array::from(&points)
+                .iter()
+                .map(|point| (number(&point, "x"), number(&point, "y")))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    DetectedBarcode {
+        raw_value: string("rawValue"),
+        format: string("format"),
+        bounding_box,
+        corner_points,
+    }
+}
+
+/// A barcode detected by [`use_barcode_detector`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DetectedBarcode {
+    /// The decoded value of the barcode
+    pub raw_value: String,
+
+    /// The [format](https://developer.mozilla.org/en-US/docs/Web/API/Barcode_Detection_API#supported_barcode_formats)
+    /// of the barcode like `"qr_code"` or `"ean_13"`
+    pub format: String,
+
+    /// The bounding box of the barcode in the coordinates of the source
+    pub bounding_box: BarcodeBoundingBox,
+
+    /// The `(x, y)` coordinates of the corners of the barcode, clockwise starting at the top left
+    pub corner_points: Vec<(f64, f64)>,
+}
+
+/// Bounding box of a [`DetectedBarcode`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct BarcodeBoundingBox {
+    /// Left edge in px
+    pub x: f64,
+    /// Top edge in px
+    pub y: f64,
+    /// Width in px
+    pub width: f64,
+    /// Height in px
+    pub height: f64,
+}
+
+/// Future returned by the `fallback` of [`UseBarcodeDetectorOptions`].
+pub type BarcodeDetectFuture = LocalBoxFuture<Vec<DetectedBarcode>>;
+
+/// When [`use_barcode_detector`] scans the source.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BarcodeScanMode {
+    /// Scan every given number of milliseconds
+    Interval(u64),
+    /// Scan on every animation frame
+    AnimationFrame,
+    /// Only scan when `detect` is called
+    Manual,
+}
+
+impl Default for BarcodeScanMode {
+    fn default() -> Self {
+        Self::Interval(500)
+    }
+}
+
+/// Options for [`use_barcode_detector_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseBarcodeDetectorOptions {
+    /// The formats to detect like `"qr_code"`. If empty, all formats supported by the platform are detected.
+    /// Defaults to `[]`.
+    formats: Vec<String>,
+
+    /// When to scan the source. Defaults to `BarcodeScanMode::Interval(500)`.
+    mode: BarcodeScanMode,
+
+    /// Start scanning immediately. Defaults to `true`.
+    immediate: bool,
+
+    /// Used for detection if the Barcode Detection API isn't supported. Defaults to `None`.
+    #[builder(skip)]
+    fallback: Option<Arc<dyn Fn(web_sys::Element) -> BarcodeDetectFuture + Send + Sync>>,
+}
+
+impl Default for UseBarcodeDetectorOptions {
+    fn default() -> Self {
+        Self {
+            formats: vec![],
+            mode: BarcodeScanMode::default(),
+            immediate: true,
+            fallback: None,
+        }
+    }
+}
+
+impl UseBarcodeDetectorOptions {
+    /// Detect barcodes with the given function if the Barcode Detection API isn't supported.
+    /// It receives the source element and returns the detected barcodes.
+    pub fn fallback(
+        self,
+        fallback: impl Fn(web_sys::Element) -> BarcodeDetectFuture + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            fallback: Some(Arc::new(fallback)),
+            ..self
+        }
+    }
+}
+
+/// Return type of [`use_barcode_detector`].
+pub struct UseBarcodeDetectorReturn<DetectFn, PauseFn, ResumeFn>
+where
+    DetectFn: Fn() + Clone + Send + Sync,
+    PauseFn: Fn() + Clone + Send + Sync,
+    ResumeFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the Barcode Detection API is supported. If not, the `fallback` is used if provided.
+    pub is_supported: Signal<bool>,
+
+    /// The barcodes detected in the last scan
+    pub barcodes: Signal<Vec<DetectedBarcode>>,
+
+    /// `true` while scanning continuously
+    pub is_active: Signal<bool>,
+
+    /// Scan the source once
+    pub detect: DetectFn,
+
+    /// Pause scanning
+    pub pause: PauseFn,
+
+    /// Resume scanning
+    pub resume: ResumeFn,
+}
@@ -0,0 +1,276 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Ask the user for confirmation before leaving a page with unsaved changes.
+///
+/// While `dirty` is `true` the browser shows its
+/// [`beforeunload`](https://developer.mozilla.org/en-US/docs/Web/API/Window/beforeunload_event) prompt when
+/// the page is closed or reloaded. Clicks on links to other pages of the same origin, which is how
+/// `leptos_router` navigates, are intercepted as well and only followed if the `confirm` hook returns `true`.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_before_unload)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_before_unload;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (text, set_text) = signal(String::new());
+/// let (saved_text, set_saved_text) = signal(String::new());
+///
+/// use_before_unload(Signal::derive(move || text.get() != saved_text.get()));
+///
+/// view! {
+///     <textarea on:input=move |ev| set_text.set(event_target_value(&ev))></textarea>
+///     <button on:click=move |_| set_saved_text.set(text.get())>"Save"</button>
+///     <a href="/other-page">"Leave"</a>
+/// }
+/// # }
+/// ```
+///
+/// ## Custom Confirmation
+///
+/// By default in-app navigation is confirmed with `window.confirm()` and the option `message`. Provide your own
+/// `confirm` hook which receives the URL that is about to be opened. It has to decide synchronously.
+/// For programmatic navigation, like with `use_navigate`, call the returned `confirm_leave` with the
+/// destination first. Relative URLs are resolved against the current location before they're passed to the hook.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_before_unload_with_options, UseBeforeUnloadOptions, UseBeforeUnloadReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (dirty, set_dirty) = signal(false);
+/// let UseBeforeUnloadReturn { confirm_leave } = use_before_unload_with_options(
+///     dirty,
+///     UseBeforeUnloadOptions::default().confirm(|url| {
+///         window()
+///             .confirm_with_message(&format!("Discard your changes and open {url}?"))
+///             .unwrap_or(true)
+///     }),
+/// );
+///
+/// let go_home = move |_| {
+///     if confirm_leave("/") {
+///         // navigate("/", Default::default());
+///     }
+/// };
+///
+/// view! { <button on:click=go_home>"Home"</button> }
+/// # }
+/// ```
+///
+/// > Navigating with the back and forward buttons of the browser can't be intercepted.
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `confirm_leave` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_before_unload`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server nothing is intercepted and `confirm_leave` always returns `true`.
+pub fn use_before_unload(
+    dirty: impl Into<Signal<bool>>,
+) -> UseBeforeUnloadReturn<impl Fn(&str) -> bool + Clone + Send + Sync> {
+    use_before_unload_with_options(dirty, UseBeforeUnloadOptions::default())
+}
+
+/// Version of [`use_before_unload`] that takes a `UseBeforeUnloadOptions`. See [`use_before_unload`] for how to use.
+pub fn use_before_unload_with_options(
+    dirty: impl Into<Signal<bool>>,
+    options: UseBeforeUnloadOptions,
+) -> UseBeforeUnloadReturn<impl Fn(&str) -> bool + Clone + Send + Sync> {
+    let UseBeforeUnloadOptions {
+        message,
+        block_in_app_navigation,
+        confirm,
+    } = options;
+
+    let dirty = dirty.into();
+
+    let confirm: ConfirmFn = confirm.unwrap_or_else(|| {
+        Arc::new(move |_: &str| {
+            #[cfg(not(feature = "ssr"))]
+            {
+                window().confirm_with_message(&message).unwrap_or(true)
+            }
+
+            #[cfg(feature = "ssr")]
+            true
+        })
+    });
+
+    let confirm_leave = sendwrap_fn!(move |url: &str| {
+        if !dirty.get_untracked() {
+            return true;
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let url = window()
+                .location()
+                .href()
+                .ok()
+                .and_then(|current| web_sys::Url::new_with_base(url, &current).ok())
+                .map(|url| url.href())
+                .unwrap_or_else(|| url.to_string());
+
+            confirm(&url)
+        }
+
+        #[cfg(feature = "ssr")]
+        true
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{use_event_listener, use_event_listener_with_options, UseEventListenerOptions};
+        use leptos::ev::{beforeunload, click};
+
+        let _ = use_event_listener(window(), beforeunload, move |event| {
+            if dirty.get_untracked() {
+                event.prevent_default();
+                // needed by older browsers to show the prompt
+                event.set_return_value("");
+            }
+        });
+
+        if block_in_app_navigation {
+            let confirm_leave = confirm_leave.clone();
+
+            let _ = use_event_listener_with_options(
+                window(),
+                click,
+                move |event| {
+                    if !dirty.get_untracked() {
+                        return;
+                    }
+
+                    let Some(url) = in_app_navigation_url(&event) else {
+                        return;
+                    };
+
+                    if !confirm_leave(&url) {
+                        // capturing on the window runs before the router's listener
+                        event.prevent_default();
+                        event.stop_propagation();
+                    }
+                },
+                UseEventListenerOptions::default().capture(true),
+            );
+        }
+    }
+
+    UseBeforeUnloadReturn { confirm_leave }
+}
+
+/// Returns the URL that the click is about to navigate to if it's a plain click on a link to another
+/// page of the same origin. Mirrors the conditions under which `leptos_router` handles a click.
+#[cfg(not(feature = "ssr"))]
+fn in_app_navigation_url(event: &web_sys::MouseEvent) -> Option<String> {
+    use wasm_bindgen::JsCast;
+
+    if event.default_prevented()
+        || event.button() != 0
+        || event.meta_key()
+        || event.alt_key()
+        || event.ctrl_key()
+        || event.shift_key()
+    {
+        return None;
+    }
+
+    let anchor = event
+        .target()?
+        .dyn_into::<web_sys::Element>()
+        .ok()?
+        .closest("a[href]")
+        .ok()??;
+
+    if anchor.has_attribute("download")
+        || anchor.get_attribute("rel").as_deref() == Some("external")
+        || anchor
+            .get_attribute("target")
+            .is_some_and(|target| !target.is_empty() && target != "_self")
+    {
+        return None;
+    }
+
+    let location = window().location();
+    let current = location.href().ok()?;
+    let url = web_sys::Url::new_with_base(&anchor.get_attribute("href")?, &current).ok()?;
+
+    if url.origin() != location.origin().ok()? {
+        return None;
+    }
+
+    // only the hash changes, the page isn't left
+    if url.pathname() == location.pathname().ok()? && url.search() == location.search().ok()? {
+        return None;
+    }
+
+    Some(url.href())
+}
+
+type ConfirmFn = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+/// Options for [`use_before_unload_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseBeforeUnloadOptions {
+    /// The message of the default `confirm` hook. Browsers always show their own message in the
+    /// `beforeunload` prompt. Defaults to `"You have unsaved changes. Do you really want to leave?"`.
+    #[builder(into)]
+    message: String,
+
+    /// Intercept clicks on links to other pages of the same origin. Defaults to `true`.
+    block_in_app_navigation: bool,
+
+    /// Decides if an in-app navigation to the given URL may proceed while `dirty` is `true`.
+    /// Defaults to asking with `window.confirm()`.
+    #[builder(skip)]
+    confirm: Option<ConfirmFn>,
+}
+
+impl UseBeforeUnloadOptions {
+    /// Decides if an in-app navigation to the given URL may proceed while `dirty` is `true`.
+    /// Has to return synchronously. Defaults to asking with `window.confirm()`.
+    pub fn confirm(self, confirm: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            confirm: Some(Arc::new(confirm)),
+            ..self
+        }
+    }
+}
+
+impl Default for UseBeforeUnloadOptions {
+    fn default() -> Self {
+        Self {
+            message: "You have unsaved changes. Do you really want to leave?".to_string(),
+            block_in_app_navigation: true,
+            confirm: None,
+        }
+    }
+}
+
+/// Return type of [`use_before_unload`].
+pub struct UseBeforeUnloadReturn<ConfirmLeaveFn>
+where
+    ConfirmLeaveFn: Fn(&str) -> bool + Clone + Send + Sync,
+{
+    /// Returns `true` if the page may be left for the given URL, i.e. it's not dirty or the `confirm` hook
+    /// allowed it. Call this with the destination before navigating programmatically.
+    pub confirm_leave: ConfirmLeaveFn,
+}
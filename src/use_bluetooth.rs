@@ -0,0 +1,615 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use std::rc::Rc;
+use thiserror::Error;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsValue;
+pub use web_sys::BluetoothLeScanFilterInit;
+
+/// Reactive [Web Bluetooth API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Bluetooth_API).
+///
+/// Requests a Bluetooth Low Energy device and connects to its GATT server. Characteristics can be
+/// read and written, and their notifications are available as signals.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_bluetooth)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_bluetooth_with_options, UseBluetoothOptions, UseBluetoothReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseBluetoothReturn {
+///     device,
+///     is_connected,
+///     bluetooth,
+///     ..
+/// } = use_bluetooth_with_options(
+///     UseBluetoothOptions::default()
+///         .accept_all_devices(true)
+///         .optional_services(vec!["battery_service".to_string()]),
+/// );
+///
+/// let (battery_level, set_battery_level) = signal(None::<u8>);
+///
+/// let connect = move |_| {
+///     leptos::task::spawn_local(async move {
+///         // shows the device picker and connects to the selected device
+///         let _ = bluetooth.request_device().await;
+///
+///         if let Ok(value) = bluetooth.read("battery_service", "battery_level").await {
+///             set_battery_level.set(value.first().copied());
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=connect>"Connect"</button>
+///     <p>{move || device.get().and_then(|device| device.name())}</p>
+///     <p>"Battery: " {battery_level} "%"</p>
+/// }
+/// # }
+/// ```
+///
+/// `request_device` has to be called in reaction to a user gesture. The services you want to access
+/// have to be listed in `filters` or `optional_services`.
+///
+/// ## Notifications
+///
+/// With `subscribe` notifications of a characteristic are received as a signal. Subscriptions are
+/// renewed automatically whenever `connect` succeeds again, for example after the device has been
+/// out of range. They end when the calling component is unmounted.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_bluetooth_with_options, BluetoothLeScanFilterInit, UseBluetoothOptions, UseBluetoothReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let filter = BluetoothLeScanFilterInit::new();
+/// filter.set_name_prefix("Polar");
+///
+/// let UseBluetoothReturn {
+///     is_connected,
+///     bluetooth,
+///     ..
+/// } = use_bluetooth_with_options(UseBluetoothOptions::default().filters(vec![filter]).optional_services(vec!["heart_rate".to_string()]));
+///
+/// let heart_rate = bluetooth.subscribe("heart_rate", "heart_rate_measurement");
+///
+/// let reconnect = move |_| {
+///     leptos::task::spawn_local(async move {
+///         let _ = bluetooth.connect().await;
+///     });
+/// };
+///
+/// view! {
+///     <p>{move || heart_rate.get().and_then(|value| value.get(1).copied())} " bpm"</p>
+///     <Show when=move || !is_connected.get()>
+///         <button on:click=reconnect>"Reconnect"</button>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_connected` are `false`, `device` is `None`, the signals returned
+/// by `subscribe` stay `None` and the async methods of [`BluetoothClient`] return
+/// `Err(UseBluetoothError::Server)`.
+pub fn use_bluetooth() -> UseBluetoothReturn {
+    use_bluetooth_with_options(UseBluetoothOptions::default())
+}
+
+/// Version of [`use_bluetooth`] that takes a `UseBluetoothOptions`. See [`use_bluetooth`] for how to use.
+pub fn use_bluetooth_with_options(options: UseBluetoothOptions) -> UseBluetoothReturn {
+    let is_supported = use_supported(|| js!("bluetooth" in &window().navigator()));
+
+    let (device, set_device) = signal(None::<BluetoothDeviceHandle>);
+    let (is_connected, set_connected) = signal(false);
+    let (error, set_error) = signal(None::<UseBluetoothError>);
+
+    let bluetooth = BluetoothClient {
+        options: StoredValue::new_local(options),
+        device: device.into(),
+        set_device,
+        is_connected: is_connected.into(),
+        set_connected,
+        set_error,
+        subscriptions: StoredValue::new_local(vec![]),
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::Custom;
+
+        let _ = use_event_listener(
+            Signal::derive_local(move || {
+                device
+                    .get()
+                    .map(|device| web_sys::BluetoothDevice::clone(&device.device))
+            }),
+            Custom::<web_sys::Event>::new("gattserverdisconnected"),
+            move |_| set_connected.set(false),
+        );
+    }
+
+    UseBluetoothReturn {
+        is_supported,
+        device: device.into(),
+        is_connected: is_connected.into(),
+        error: error.into(),
+        bluetooth,
+    }
+}
+
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+fn data_view_to_vec(view: &js_sys::DataView) -> Vec<u8> {
+    js_sys::Uint8Array::new_with_byte_offset_and_length(
+        &view.buffer(),
+        view.byte_offset() as u32,
+        view.byte_length() as u32,
+    )
+    .to_vec()
+}
+
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+struct Subscription {
+    service: String,
+    characteristic: String,
+    listener: Closure<dyn FnMut(web_sys::Event)>,
+    target: std::cell::RefCell<Option<web_sys::BluetoothRemoteGattCharacteristic>>,
+}
+
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+impl Subscription {
+    fn detach(&self) -> Option<web_sys::BluetoothRemoteGattCharacteristic> {
+        use wasm_bindgen::JsCast;
+
+        let target = self.target.take()?;
+        let _ = target.remove_event_listener_with_callback(
+            "characteristicvaluechanged",
+            self.listener.as_ref().unchecked_ref(),
+        );
+
+        Some(target)
+    }
+}
+
+/// Requests, connects and talks to a device. Returned by [`use_bluetooth`].
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct BluetoothClient {
+    options: StoredValue<UseBluetoothOptions, LocalStorage>,
+    device: Signal<Option<BluetoothDeviceHandle>>,
+    set_device: WriteSignal<Option<BluetoothDeviceHandle>>,
+    is_connected: Signal<bool>,
+    set_connected: WriteSignal<bool>,
+    set_error: WriteSignal<Option<UseBluetoothError>>,
+    subscriptions: StoredValue<Vec<Rc<Subscription>>, LocalStorage>,
+}
+
+impl BluetoothClient {
+    /// Shows the device picker and connects to the selected device.
+    /// Has to be called in reaction to a user gesture.
+    pub async fn request_device(&self) -> Result<(), UseBluetoothError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseBluetoothError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let result = self.request_and_connect().await;
+
+            if let Err(err) = &result {
+                self.set_error.try_set(Some(err.clone()));
+            }
+
+            result
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    async fn request_and_connect(&self) -> Result<(), UseBluetoothError> {
+        use wasm_bindgen::JsCast;
+
+        let bluetooth = window()
+            .navigator()
+            .bluetooth()
+            .ok_or(UseBluetoothError::NotSupported)?;
+
+        let request_options = self
+            .options
+            .try_with_value(|options| {
+                let request_options = web_sys::RequestDeviceOptions::new();
+
+                if options.accept_all_devices {
+                    request_options.set_accept_all_devices(true);
+                } else {
+                    request_options.set_filters(&options.filters);
+                }
+
+                request_options.set_optional_services(
+                    &options
+                        .optional_services
+                        .iter()
+                        .map(|service| js_sys::JsString::from(service.as_str()))
+                        .collect::<Vec<_>>(),
+                );
+
+                request_options
+            })
+            .ok_or(UseBluetoothError::NotSupported)?;
+
+        let device: web_sys::BluetoothDevice =
+            crate::js_fut!(bluetooth.request_device(&request_options))
+                .await
+                .map_err(UseBluetoothError::from)?
+                .unchecked_into();
+
+        if let Some(previous) = self.device.get_untracked() {
+            if let Some(server) = previous.device.gatt() {
+                server.disconnect();
+            }
+        }
+
+        self.set_connected.try_set(false);
+        self.set_device
+            .try_set(Some(BluetoothDeviceHandle::new(device)));
+
+        self.connect().await
+    }
+
+    /// Connects to the GATT server of the current device and renews all subscriptions.
+    /// Use this to reconnect after the connection has been lost.
+    pub async fn connect(&self) -> Result<(), UseBluetoothError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseBluetoothError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let result = self.connect_and_subscribe().await;
+
+            match &result {
+                Ok(()) => self.set_error.try_set(None),
+                Err(err) => self.set_error.try_set(Some(err.clone())),
+            };
+
+            result
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    async fn connect_and_subscribe(&self) -> Result<(), UseBluetoothError> {
+        let server = self.server()?;
+
+        crate::js_fut!(server.connect())
+            .await
+            .map_err(UseBluetoothError::from)?;
+
+        self.set_connected.try_set(true);
+
+        for subscription in self.subscriptions.try_get_value().unwrap_or_default() {
+            self.start_notifications(&subscription).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Disconnects from the GATT server. Subscriptions are kept and renewed on the next `connect`.
+    pub fn disconnect(&self) {
+        #[cfg(not(feature = "ssr"))]
+        if let Ok(server) = self.server() {
+            server.disconnect();
+        }
+
+        self.set_connected.try_set(false);
+    }
+
+    /// Reads the value of a characteristic. Services and characteristics are given by their
+    /// full UUID or their name like `"battery_service"` and `"battery_level"`.
+    pub async fn read(
+        &self,
+        service: &str,
+        characteristic: &str,
+    ) -> Result<Vec<u8>, UseBluetoothError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseBluetoothError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            let characteristic = self.characteristic(service, characteristic).await?;
+
+            let view: js_sys::DataView = crate::js_fut!(characteristic.read_value())
+                .await
+                .map_err(UseBluetoothError::from)?
+                .unchecked_into();
+
+            Ok(data_view_to_vec(&view))
+        }
+    }
+
+    /// Writes the value of a characteristic and waits for the device to acknowledge it.
+    pub async fn write(
+        &self,
+        service: &str,
+        characteristic: &str,
+        value: &[u8],
+    ) -> Result<(), UseBluetoothError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseBluetoothError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let characteristic = self.characteristic(service, characteristic).await?;
+
+            let promise = characteristic
+                .write_value_with_response_with_u8_slice(value)
+                .map_err(UseBluetoothError::from)?;
+
+            crate::js_fut!(promise)
+                .await
+                .map(|_| ())
+                .map_err(UseBluetoothError::from)
+        }
+    }
+
+    /// Writes the value of a characteristic without waiting for an acknowledgement.
+    pub async fn write_without_response(
+        &self,
+        service: &str,
+        characteristic: &str,
+        value: &[u8],
+    ) -> Result<(), UseBluetoothError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseBluetoothError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let characteristic = self.characteristic(service, characteristic).await?;
+
+            let promise = characteristic
+                .write_value_without_response_with_u8_slice(value)
+                .map_err(UseBluetoothError::from)?;
+
+            crate::js_fut!(promise)
+                .await
+                .map(|_| ())
+                .map_err(UseBluetoothError::from)
+        }
+    }
+
+    /// Subscribes to the notifications of a characteristic. The returned signal contains the last
+    /// received value. Notifications are started now if connected and again after every
+    /// successful `connect`. They're stopped when the calling component is unmounted.
+    pub fn subscribe(
+        &self,
+        service: impl Into<String>,
+        characteristic: impl Into<String>,
+    ) -> Signal<Option<Vec<u8>>> {
+        let (value, set_value) = signal(None::<Vec<u8>>);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            let listener =
+                Closure::<dyn FnMut(web_sys::Event)>::new(move |event: web_sys::Event| {
+                    let characteristic = event.target().map(|target| {
+                        target.unchecked_into::<web_sys::BluetoothRemoteGattCharacteristic>()
+                    });
+
+                    if let Some(view) =
+                        characteristic.and_then(|characteristic| characteristic.value())
+                    {
+                        set_value.try_set(Some(data_view_to_vec(&view)));
+                    }
+                });
+
+            let subscription = Rc::new(Subscription {
+                service: service.into(),
+                characteristic: characteristic.into(),
+                listener,
+                target: Default::default(),
+            });
+
+            self.subscriptions
+                .update_value(|subscriptions| subscriptions.push(Rc::clone(&subscription)));
+
+            if self.is_connected.get_untracked() {
+                let client = *self;
+                let subscription = Rc::clone(&subscription);
+
+                leptos::task::spawn_local(async move {
+                    if let Err(err) = client.start_notifications(&subscription).await {
+                        client.set_error.try_set(Some(err));
+                    }
+                });
+            }
+
+            let subscriptions = self.subscriptions;
+            let subscription = SendWrapper::new(subscription);
+
+            on_cleanup(move || {
+                subscriptions.try_update_value(|subscriptions| {
+                    subscriptions.retain(|other| !Rc::ptr_eq(other, &subscription))
+                });
+
+                if let Some(target) = subscription.detach() {
+                    let _ = target.stop_notifications();
+                }
+            });
+        }
+
+        value.into()
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    async fn start_notifications(
+        &self,
+        subscription: &Subscription,
+    ) -> Result<(), UseBluetoothError> {
+        use wasm_bindgen::JsCast;
+
+        let characteristic = self
+            .characteristic(&subscription.service, &subscription.characteristic)
+            .await?;
+
+        // after a reconnect the browser may hand out a new characteristic object
+        subscription.detach();
+
+        characteristic
+            .add_event_listener_with_callback(
+                "characteristicvaluechanged",
+                subscription.listener.as_ref().unchecked_ref(),
+            )
+            .map_err(UseBluetoothError::from)?;
+
+        subscription.target.replace(Some(characteristic.clone()));
+
+        crate::js_fut!(characteristic.start_notifications())
+            .await
+            .map(|_| ())
+            .map_err(UseBluetoothError::from)
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn server(&self) -> Result<web_sys::BluetoothRemoteGattServer, UseBluetoothError> {
+        self.device
+            .try_get_untracked()
+            .flatten()
+            .ok_or(UseBluetoothError::NoDevice)?
+            .device
+            .gatt()
+            .ok_or(UseBluetoothError::NotSupported)
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    async fn characteristic(
+        &self,
+        service: &str,
+        characteristic: &str,
+    ) -> Result<web_sys::BluetoothRemoteGattCharacteristic, UseBluetoothError> {
+        use wasm_bindgen::JsCast;
+
+        let server = self.server()?;
+
+        let service: web_sys::BluetoothRemoteGattService =
+            crate::js_fut!(server.get_primary_service_with_str(service))
+                .await
+                .map_err(UseBluetoothError::from)?
+                .unchecked_into();
+
+        Ok(
+            crate::js_fut!(service.get_characteristic_with_str(characteristic))
+                .await
+                .map_err(UseBluetoothError::from)?
+                .unchecked_into(),
+        )
+    }
+}
+
+/// The device selected with [`BluetoothClient::request_device`].
+#[derive(Clone, Debug)]
+pub struct BluetoothDeviceHandle {
+    device: SendWrapper<web_sys::BluetoothDevice>,
+}
+
+impl PartialEq for BluetoothDeviceHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl BluetoothDeviceHandle {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn new(device: web_sys::BluetoothDevice) -> Self {
+        Self {
+            device: SendWrapper::new(device),
+        }
+    }
+
+    /// An opaque id that identifies the device across page loads
+    pub fn id(&self) -> String {
+        self.device.id()
+    }
+
+    /// The name of the device if available
+    pub fn name(&self) -> Option<String> {
+        self.device.name()
+    }
+
+    /// The underlying [`web_sys::BluetoothDevice`]
+    pub fn bluetooth_device(&self) -> &web_sys::BluetoothDevice {
+        &self.device
+    }
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum UseBluetoothError {
+    #[error("Web Bluetooth is not available on the server")]
+    Server,
+
+    #[error("Web Bluetooth is not supported by this browser")]
+    NotSupported,
+
+    #[error("no device has been requested yet")]
+    NoDevice,
+
+    #[error("Bluetooth operation failed")]
+    Js(SendWrapper<JsValue>),
+}
+
+impl From<JsValue> for UseBluetoothError {
+    fn from(err: JsValue) -> Self {
+        Self::Js(SendWrapper::new(err))
+    }
+}
+
+/// Options for [`use_bluetooth_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseBluetoothOptions {
+    /// Show all devices in the picker and ignore `filters`. Defaults to `false`.
+    accept_all_devices: bool,
+
+    /// Only devices matching one of these filters are shown in the picker.
+    /// At least one filter is required unless `accept_all_devices` is `true`.
+    filters: Vec<BluetoothLeScanFilterInit>,
+
+    /// Services that can be accessed in addition to the ones listed in `filters`,
+    /// given by their full UUID or their name like `"battery_service"`. Defaults to none.
+    optional_services: Vec<String>,
+}
+
+/// Return type of [`use_bluetooth`].
+pub struct UseBluetoothReturn {
+    /// `true` if the browser supports the Web Bluetooth API
+    pub is_supported: Signal<bool>,
+
+    /// The selected device
+    pub device: Signal<Option<BluetoothDeviceHandle>>,
+
+    /// `true` while connected to the GATT server of the device
+    pub is_connected: Signal<bool>,
+
+    /// The last error of requesting, connecting or subscribing
+    pub error: Signal<Option<UseBluetoothError>>,
+
+    /// Requests, connects and talks to the device
+    pub bluetooth: BluetoothClient,
+}
@@ -1,10 +1,11 @@
-use crate::{use_media_query, use_window};
+use crate::{use_media_query_with_options, use_window, UseMediaQueryOptions};
+use default_struct_builder::DefaultBuilder;
 use leptos::logging::error;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
 use paste::paste;
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::hash::Hash;
 
 /// Reactive viewport breakpoints.
@@ -83,6 +84,53 @@ use std::hash::Hash;
 /// }
 /// ```
 ///
+/// The keys can be of any type that implements `Eq + Hash + Debug + Clone + Send + Sync`, e.g. string slices.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_breakpoints;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let screen_width = use_breakpoints(HashMap::from([
+///     ("sm", 640),
+///     ("md", 768),
+///     ("lg", 1024),
+///     ("xl", 1280),
+/// ]));
+///
+/// let md_to_xl = screen_width.between("md", "xl");
+/// let only_md = screen_width.only("md"); // `md` <= `[screen size]` < `lg`
+/// let active = screen_width.active(); // Signal<Option<&str>>, e.g. Some("lg")
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Units
+///
+/// By default the breakpoints are in `px`. If your CSS uses `em` or `rem` breakpoints you can
+/// specify the unit with the option `unit`. This way the media queries respect the user's font size settings.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_breakpoints_with_options, BreakpointUnit, UseBreakpointsOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let screen_width = use_breakpoints_with_options(
+///     HashMap::from([("tablet", 40), ("desktop", 64)]),
+///     UseBreakpointsOptions::default().unit(BreakpointUnit::Em),
+/// );
+///
+/// let is_desktop = screen_width.ge("desktop"); // (min-width: 64em)
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Non-reactive methods
 ///
 /// For every reactive method there is also a non-reactive variant that is prefixed with `is_`
@@ -111,26 +159,116 @@ use std::hash::Hash;
 ///
 /// Since internally this uses [`fn@crate::use_media_query`], which returns always `false` on the server,
 /// the returned methods also will return `false`.
+///
+/// To render the correct layout on the server you can provide the viewport width as a hint with the
+/// option `ssr_width`. All methods are then evaluated against this width on the server and on the
+/// client until the media queries have been evaluated after hydration. You can get the width for example from the
+/// [`Sec-CH-Viewport-Width`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Sec-CH-Viewport-Width)
+/// client hint or from a cookie. Make sure that the client uses the same value as the server, otherwise
+/// the first render on the client differs nonetheless.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_breakpoints_with_options, breakpoints_tailwind, BreakpointsTailwind, UseBreakpointsOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let viewport_width_hint = Some(1280);
+/// let screen_width = use_breakpoints_with_options(
+///     breakpoints_tailwind(),
+///     UseBreakpointsOptions::default().ssr_width(viewport_width_hint),
+/// );
+///
+/// let is_desktop = screen_width.ge(BreakpointsTailwind::Lg); // `true` on the server
+/// #
+/// # view! { }
+/// # }
+/// ```
 pub fn use_breakpoints<K: Eq + Hash + Debug + Clone + Send + Sync>(
     breakpoints: HashMap<K, u32>,
 ) -> UseBreakpointsReturn<K> {
-    UseBreakpointsReturn { breakpoints }
+    use_breakpoints_with_options(breakpoints, UseBreakpointsOptions::default())
+}
+
+/// Version of [`use_breakpoints`] that takes a `UseBreakpointsOptions`. See [`use_breakpoints`] for how to use.
+pub fn use_breakpoints_with_options<K: Eq + Hash + Debug + Clone + Send + Sync>(
+    breakpoints: HashMap<K, u32>,
+    options: UseBreakpointsOptions,
+) -> UseBreakpointsReturn<K> {
+    let UseBreakpointsOptions { unit, ssr_width } = options;
+
+    UseBreakpointsReturn {
+        breakpoints,
+        unit,
+        ssr_width,
+    }
+}
+
+/// Options for [`use_breakpoints_with_options`].
+#[derive(DefaultBuilder, Default, Clone, Copy, Debug)]
+pub struct UseBreakpointsOptions {
+    /// The unit of the breakpoint values. Defaults to `BreakpointUnit::Px`.
+    unit: BreakpointUnit,
+
+    /// The viewport width in the unit of the breakpoints that is assumed before the media queries are evaluated, i.e. on the
+    /// server and on the client before hydration has finished. If `None` all methods return `false` there.
+    /// Defaults to `None`.
+    ssr_width: Option<u32>,
+}
+
+/// Unit of the breakpoint values used in the media queries.
+#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum BreakpointUnit {
+    #[default]
+    Px,
+    Em,
+    Rem,
+}
+
+impl BreakpointUnit {
+    /// Decimal places appended to a value to make a `min-width` query exclusive.
+    fn above_fraction(&self) -> &'static str {
+        match self {
+            Self::Px => "1",
+            Self::Em | Self::Rem => "01",
+        }
+    }
+
+    /// Decimal places appended to `value - 1` to make a `max-width` query exclusive.
+    fn below_fraction(&self) -> &'static str {
+        match self {
+            Self::Px => "9",
+            Self::Em | Self::Rem => "99",
+        }
+    }
+}
+
+impl Display for BreakpointUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Px => write!(f, "px"),
+            Self::Em => write!(f, "em"),
+            Self::Rem => write!(f, "rem"),
+        }
+    }
 }
 
 /// Return type of [`use_breakpoints`]
 #[derive(Clone)]
 pub struct UseBreakpointsReturn<K: Eq + Hash + Debug + Clone + Send + Sync> {
     breakpoints: HashMap<K, u32>,
+    unit: BreakpointUnit,
+    ssr_width: Option<u32>,
 }
 
 macro_rules! query_suffix {
-    (>) => {
-        ".1"
+    (>, $unit:expr) => {
+        format!(".{}", $unit.above_fraction())
     };
-    (<) => {
-        ".9"
+    (<, $unit:expr) => {
+        format!(".{}", $unit.below_fraction())
     };
-    (=) => {
+    (=, $unit:expr) => {
         ""
     };
 }
@@ -148,25 +286,29 @@ macro_rules! value_expr {
 }
 
 macro_rules! format_media_query {
-    ($cmp:tt, $suffix:tt, $v:ident) => {
+    ($cmp:tt, $suffix:tt, $v:ident, $unit:expr) => {
         format!(
-            "({}-width: {}{}px)",
+            "({}-width: {}{}{})",
             $cmp,
             value_expr!($v, $suffix),
-            query_suffix!($suffix)
+            query_suffix!($suffix, $unit),
+            $unit
         )
     };
 }
 
 macro_rules! impl_cmp_reactively {
     (   #[$attr:meta]
-        $fn:ident, $cmp:tt, $suffix:tt) => {
+        $fn:ident, $cmp:tt, $suffix:tt, $op:tt) => {
         paste! {
             // Reactive check if
             #[$attr]
             pub fn $fn(&self, key: K) -> Signal<bool> {
                 if let Some(value) = self.breakpoints.get(&key) {
-                    use_media_query(format_media_query!($cmp, $suffix, value))
+                    self.media_query(
+                        format_media_query!($cmp, $suffix, value, self.unit),
+                        |width| width $op *value,
+                    )
                 } else {
                     self.not_found_signal(key)
                 }
@@ -176,7 +318,10 @@ macro_rules! impl_cmp_reactively {
             #[$attr]
             pub fn [<is_ $fn>](&self, key: K) -> bool {
                 if let Some(value) = self.breakpoints.get(&key) {
-                    Self::match_(&format_media_query!($cmp, $suffix, value))
+                    self.match_(
+                        &format_media_query!($cmp, $suffix, value, self.unit),
+                        |width| width $op *value,
+                    )
                 } else {
                     self.not_found(key)
                 }
@@ -189,7 +334,18 @@ impl<K> UseBreakpointsReturn<K>
 where
     K: Eq + Hash + Debug + Clone + Send + Sync + 'static,
 {
-    fn match_(query: &str) -> bool {
+    fn media_query(&self, query: String, ssr_matches: impl FnOnce(u32) -> bool) -> Signal<bool> {
+        use_media_query_with_options(
+            query,
+            UseMediaQueryOptions::default().initial_value(self.ssr_width.is_some_and(ssr_matches)),
+        )
+    }
+
+    fn match_(&self, query: &str, ssr_matches: impl FnOnce(u32) -> bool) -> bool {
+        if cfg!(feature = "ssr") {
+            return self.ssr_width.is_some_and(ssr_matches);
+        }
+
         if let Ok(Some(query_list)) = use_window().match_media(query) {
             return query_list.matches();
         }
@@ -209,30 +365,36 @@ where
 
     impl_cmp_reactively!(
         /// `[screen size]` > `key`
-        gt, "min", >
+        gt, "min", >, >
     );
     impl_cmp_reactively!(
         /// `[screen size]` >= `key`
-        ge, "min", =
+        ge, "min", =, >=
     );
     impl_cmp_reactively!(
         /// `[screen size]` < `key`
-        lt, "max", <
+        lt, "max", <, <
     );
     impl_cmp_reactively!(
         /// `[screen size]` <= `key`
-        le, "max", =
+        le, "max", =, <=
     );
 
-    fn between_media_query(min: &u32, max: &u32) -> String {
-        format!("(min-width: {min}px) and (max-width: {}.9px)", max - 1)
+    fn between_media_query(&self, min: &u32, max: &u32) -> String {
+        format!(
+            "{} and {}",
+            format_media_query!("min", =, min, self.unit),
+            format_media_query!("max", <, max, self.unit)
+        )
     }
 
     /// Reactive check if `min_key` <= `[screen size]` <= `max_key`
     pub fn between(&self, min_key: K, max_key: K) -> Signal<bool> {
         if let Some(min) = self.breakpoints.get(&min_key) {
             if let Some(max) = self.breakpoints.get(&max_key) {
-                use_media_query(Self::between_media_query(min, max))
+                self.media_query(self.between_media_query(min, max), |width| {
+                    *min <= width && width < *max
+                })
             } else {
                 self.not_found_signal(max_key)
             }
@@ -245,7 +407,9 @@ where
     pub fn is_between(&self, min_key: K, max_key: K) -> bool {
         if let Some(min) = self.breakpoints.get(&min_key) {
             if let Some(max) = self.breakpoints.get(&max_key) {
-                Self::match_(&Self::between_media_query(min, max))
+                self.match_(&self.between_media_query(min, max), |width| {
+                    *min <= width && width < *max
+                })
             } else {
                 self.not_found(max_key)
             }
@@ -256,15 +420,18 @@ where
 
     /// Reactive Vec of all breakpoints that fulfill `[screen size]` >= `key`
     pub fn current(&self) -> Signal<Vec<K>> {
-        let breakpoints = self.breakpoints.clone();
-        let keys: Vec<_> = breakpoints.keys().cloned().collect();
+        let this = self.clone();
+        let keys: Vec<_> = self.breakpoints.keys().cloned().collect();
 
         let ge = move |key: &K| {
-            let value = breakpoints
+            let value = this
+                .breakpoints
                 .get(key)
                 .expect("only used with keys() from the HashMap");
 
-            use_media_query(format_media_query!("min", =, value))
+            this.media_query(format_media_query!("min", =, value, this.unit), |width| {
+                width >= *value
+            })
         };
 
         let signals: Vec<_> = keys.iter().map(ge.clone()).collect();
@@ -277,6 +444,59 @@ where
                 .collect::<Vec<_>>()
         })
     }
+
+    /// Reactive name of the largest breakpoint that fulfills `[screen size]` >= `key`.
+    /// `None` if the screen is smaller than all breakpoints.
+    pub fn active(&self) -> Signal<Option<K>> {
+        let breakpoints = self.breakpoints.clone();
+        let current = self.current();
+
+        Signal::derive(move || {
+            current
+                .get()
+                .into_iter()
+                .max_by_key(|key| breakpoints.get(key).copied())
+        })
+    }
+
+    /// The value of the next larger breakpoint after `value` if there is one.
+    fn next_value(&self, value: &u32) -> Option<u32> {
+        self.breakpoints
+            .values()
+            .filter(|next| *next > value)
+            .min()
+            .copied()
+    }
+
+    /// Reactive check if `key` <= `[screen size]` < `[next larger key]`.
+    /// For the largest breakpoint this is the same as [`Self::ge`].
+    pub fn only(&self, key: K) -> Signal<bool> {
+        if let Some(value) = self.breakpoints.get(&key) {
+            match self.next_value(value) {
+                Some(next) => self.media_query(self.between_media_query(value, &next), |width| {
+                    *value <= width && width < next
+                }),
+                None => self.ge(key),
+            }
+        } else {
+            self.not_found_signal(key)
+        }
+    }
+
+    /// Static check if `key` <= `[screen size]` < `[next larger key]`.
+    /// For the largest breakpoint this is the same as [`Self::is_ge`].
+    pub fn is_only(&self, key: K) -> bool {
+        if let Some(value) = self.breakpoints.get(&key) {
+            match self.next_value(value) {
+                Some(next) => self.match_(&self.between_media_query(value, &next), |width| {
+                    *value <= width && width < next
+                }),
+                None => self.is_ge(key),
+            }
+        } else {
+            self.not_found(key)
+        }
+    }
 }
 
 /// Breakpoint keys for Tailwind V2
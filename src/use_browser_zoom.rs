@@ -0,0 +1,139 @@
+use crate::use_device_pixel_ratio;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Reactive estimate of the browser zoom level and the pinch zoom scale.
+///
+/// Browsers don't expose the zoom level directly. When the user zooms with `Ctrl +` / `Ctrl -`
+/// the [`devicePixelRatio`](https://developer.mozilla.org/en-US/docs/Web/API/Window/devicePixelRatio)
+/// changes proportionally. So the browser zoom is estimated by comparing the current pixel ratio with the
+/// pixel ratio at 100% zoom.
+///
+/// Pinch zoom on the other hand doesn't change the pixel ratio but only scales the
+/// [visual viewport](https://developer.mozilla.org/en-US/docs/Web/API/VisualViewport/scale).
+/// This way both kinds of zoom can be distinguished.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_browser_zoom)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_browser_zoom, UseBrowserZoomReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseBrowserZoomReturn {
+///     zoom,
+///     pinch_zoom,
+///     is_pinch_zoomed,
+///     pixel_ratio,
+/// } = use_browser_zoom();
+///
+/// view! {
+///     <p>"Browser zoom: " {move || format!("{:.0}%", zoom.get() * 100.0)}</p>
+/// }
+/// # }
+/// ```
+///
+/// ## Pixel ratio at 100% zoom
+///
+/// By default the pixel ratio at the time this function is called is assumed to be the one at 100% zoom.
+/// So if the page is loaded while it is already zoomed, `zoom` is relative to that level.
+/// If you know the pixel ratio of the device, e.g. because you've stored it earlier, you can provide it
+/// with the option `base_pixel_ratio`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_browser_zoom_with_options, UseBrowserZoomOptions, UseBrowserZoomReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseBrowserZoomReturn { zoom, .. } =
+///     use_browser_zoom_with_options(UseBrowserZoomOptions::default().base_pixel_ratio(2.0));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Canvas
+///
+/// For crisp rendering a canvas has to be sized with the physical pixels. Use `pixel_ratio`, which includes the
+/// browser zoom, to scale the canvas and ignore `pinch_zoom` as the browser simply magnifies the page in that case.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `zoom`, `pinch_zoom` and `pixel_ratio` are always `1.0` and `is_pinch_zoomed` is `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_device_pixel_ratio`]
+pub fn use_browser_zoom() -> UseBrowserZoomReturn {
+    use_browser_zoom_with_options(UseBrowserZoomOptions::default())
+}
+
+/// Version of [`use_browser_zoom`] that takes a `UseBrowserZoomOptions`. See [`use_browser_zoom`] for how to use.
+pub fn use_browser_zoom_with_options(options: UseBrowserZoomOptions) -> UseBrowserZoomReturn {
+    let UseBrowserZoomOptions { base_pixel_ratio } = options;
+
+    let pixel_ratio = use_device_pixel_ratio();
+
+    #[cfg_attr(feature = "ssr", allow(unused_variables))]
+    let (pinch_zoom, set_pinch_zoom) = signal(1.0);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::resize;
+
+        if let Some(visual_viewport) = window().visual_viewport() {
+            set_pinch_zoom.set(visual_viewport.scale());
+
+            let _ = use_event_listener(visual_viewport.clone(), resize, move |_| {
+                set_pinch_zoom.set(visual_viewport.scale());
+            });
+        }
+    }
+
+    let base_pixel_ratio = base_pixel_ratio.unwrap_or_else(|| pixel_ratio.get_untracked());
+
+    // rounded to whole percents to get rid of floating point noise like 1.1000000000000001
+    let zoom =
+        Signal::derive(move || (pixel_ratio.get() / base_pixel_ratio * 100.0).round() / 100.0);
+
+    let is_pinch_zoomed = Signal::derive(move || pinch_zoom.get() > 1.0);
+
+    UseBrowserZoomReturn {
+        zoom,
+        pinch_zoom: pinch_zoom.into(),
+        is_pinch_zoomed,
+        pixel_ratio,
+    }
+}
+
+/// Options for [`use_browser_zoom_with_options`].
+#[derive(DefaultBuilder, Default, Clone, Copy, Debug)]
+pub struct UseBrowserZoomOptions {
+    /// The device pixel ratio at 100% browser zoom. Defaults to the pixel ratio at the time
+    /// [`use_browser_zoom`] is called.
+    #[builder(into)]
+    base_pixel_ratio: Option<f64>,
+}
+
+/// Return type of [`use_browser_zoom`].
+#[derive(Clone, Copy)]
+pub struct UseBrowserZoomReturn {
+    /// The estimated browser zoom level, e.g. `1.25` for 125%.
+    pub zoom: Signal<f64>,
+
+    /// The scale of the visual viewport caused by pinch zoom. `1.0` if not pinch zoomed.
+    pub pinch_zoom: Signal<f64>,
+
+    /// `true` if the page is currently magnified by pinch zoom.
+    pub is_pinch_zoomed: Signal<bool>,
+
+    /// The current device pixel ratio which includes the browser zoom but not the pinch zoom.
+    pub pixel_ratio: Signal<f64>,
+}
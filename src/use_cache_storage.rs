@@ -0,0 +1,383 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive [Cache Storage API](https://developer.mozilla.org/en-US/docs/Web/API/Cache).
+///
+/// Opens the cache with the given name and keeps a reactive list of the URLs it contains. Responses can
+/// be looked up, stored and deleted with the methods of the returned [`CacheHandle`]. This allows to build
+/// offline caches from the page itself without writing a service worker.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_cache_storage)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_cache_storage, UseCacheStorageReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseCacheStorageReturn { keys, cache, .. } = use_cache_storage("articles-v1");
+///
+/// let save_for_offline = move |_| {
+///     leptos::task::spawn_local(async move {
+///         let response = web_sys::Response::new_with_opt_str(Some("Article text")).unwrap();
+///         let _ = cache.put("/articles/1", &response).await;
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=save_for_offline>"Save for offline"</button>
+///     <p>{move || keys.get().len()} " articles available offline"</p>
+/// }
+/// # }
+/// ```
+///
+/// Later the response can be read from the cache, for example when the browser is offline.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_cache_storage, UseCacheStorageReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let UseCacheStorageReturn { cache, .. } = use_cache_storage("articles-v1");
+/// leptos::task::spawn_local(async move {
+///     if let Ok(Some(response)) = cache.match_request("/articles/1").await {
+///         // read response.text()
+///     }
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Size Estimation
+///
+/// With the option `measure_size` the bodies of all cached responses are read to compute the total size
+/// of the cache after every change. This can take a while for large caches.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_cache_storage_with_options, UseCacheStorageOptions, UseCacheStorageReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseCacheStorageReturn { size, .. } = use_cache_storage_with_options(
+///     "articles-v1",
+///     UseCacheStorageOptions::default().measure_size(true),
+/// );
+///
+/// view! { <p>{move || size.get().map(|size| format!("{:.1} kB", size / 1000.0))}</p> }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `keys` is empty, `size` is `None` and all methods of the [`CacheHandle`] return
+/// `Err(UseCacheStorageError::Server)`.
+pub fn use_cache_storage(cache_name: impl Into<String>) -> UseCacheStorageReturn {
+    use_cache_storage_with_options(cache_name, UseCacheStorageOptions::default())
+}
+
+/// Version of [`use_cache_storage`] that takes a `UseCacheStorageOptions`. See [`use_cache_storage`] for how to use.
+pub fn use_cache_storage_with_options(
+    cache_name: impl Into<String>,
+    options: UseCacheStorageOptions,
+) -> UseCacheStorageReturn {
+    let UseCacheStorageOptions { measure_size } = options;
+
+    let is_supported = use_supported(|| js!("caches" in &window()));
+
+    let (keys, set_keys) = signal(Vec::<String>::new());
+    let (size, set_size) = signal(None::<f64>);
+
+    let cache = CacheHandle {
+        cache_name: StoredValue::new(cache_name.into()),
+        is_supported,
+        set_keys,
+        set_size,
+        measure_size,
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        leptos::task::spawn_local(async move {
+            let _ = cache.refresh().await;
+        });
+    }
+
+    UseCacheStorageReturn {
+        is_supported,
+        keys: keys.into(),
+        size: size.into(),
+        cache,
+    }
+}
+
+/// Gives access to the cache opened by [`use_cache_storage`]. Every method that modifies the cache
+/// updates `keys` and `size` afterwards.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct CacheHandle {
+    cache_name: StoredValue<String>,
+    is_supported: Signal<bool>,
+    set_keys: WriteSignal<Vec<String>>,
+    set_size: WriteSignal<Option<f64>>,
+    measure_size: bool,
+}
+
+impl CacheHandle {
+    /// The name of the cache.
+    pub fn name(&self) -> String {
+        self.cache_name.get_value()
+    }
+
+    /// Returns the cached response for the request or `None` if there is none.
+    pub async fn match_request(
+        &self,
+        request: impl Into<CacheRequest>,
+    ) -> Result<Option<web_sys::Response>, UseCacheStorageError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseCacheStorageError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            let cache = self.open().await?;
+
+            let promise = match request.into() {
+                CacheRequest::Url(url) => cache.match_with_str(&url),
+                CacheRequest::Request(request) => cache.match_with_request(&request),
+            };
+
+            let response = crate::js_fut!(promise)
+                .await
+                .map_err(UseCacheStorageError::Failed)?;
+
+            Ok(response.dyn_into::<web_sys::Response>().ok())
+        }
+    }
+
+    /// Stores the response for the request. Replaces a previously cached response for the same request.
+    pub async fn put(
+        &self,
+        request: impl Into<CacheRequest>,
+        response: &web_sys::Response,
+    ) -> Result<(), UseCacheStorageError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseCacheStorageError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let cache = self.open().await?;
+
+            let promise = match request.into() {
+                CacheRequest::Url(url) => cache.put_with_str(&url, response),
+                CacheRequest::Request(request) => cache.put_with_request(&request, response),
+            };
+
+            crate::js_fut!(promise)
+                .await
+                .map_err(UseCacheStorageError::Failed)?;
+
+            self.refresh().await
+        }
+    }
+
+    /// Deletes the cached response for the request. Returns `true` if there was one.
+    pub async fn delete(
+        &self,
+        request: impl Into<CacheRequest>,
+    ) -> Result<bool, UseCacheStorageError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseCacheStorageError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let cache = self.open().await?;
+
+            let promise = match request.into() {
+                CacheRequest::Url(url) => cache.delete_with_str(&url),
+                CacheRequest::Request(request) => cache.delete_with_request(&request),
+            };
+
+            let deleted = crate::js_fut!(promise)
+                .await
+                .map_err(UseCacheStorageError::Failed)?
+                .as_bool()
+                .unwrap_or_default();
+
+            self.refresh().await?;
+
+            Ok(deleted)
+        }
+    }
+
+    /// Deletes the whole cache.
+    pub async fn clear(&self) -> Result<(), UseCacheStorageError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseCacheStorageError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let caches = self.caches()?;
+
+            crate::js_fut!(caches.delete(&self.name()))
+                .await
+                .map_err(UseCacheStorageError::Failed)?;
+
+            self.set_keys.try_set(vec![]);
+            self.set_size.try_set(self.measure_size.then_some(0.0));
+
+            Ok(())
+        }
+    }
+
+    /// Reads the keys and, if enabled, the size of the cache again. Only needed if the cache is modified
+    /// from somewhere else like a service worker or another tab.
+    pub async fn refresh(&self) -> Result<(), UseCacheStorageError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseCacheStorageError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            let cache = self.open().await?;
+
+            let requests: js_sys::Array = crate::js_fut!(cache.keys())
+                .await
+                .map_err(UseCacheStorageError::Failed)?
+                .unchecked_into();
+
+            let requests = requests
+                .iter()
+                .map(|request| request.unchecked_into::<web_sys::Request>())
+                .collect::<Vec<_>>();
+
+            self.set_keys
+                .try_set(requests.iter().map(|request| request.url()).collect());
+
+            if self.measure_size {
+                let mut size = 0.0;
+
+                for request in &requests {
+                    size += response_size(&cache, request).await.unwrap_or_default();
+                }
+
+                self.set_size.try_set(Some(size));
+            }
+
+            Ok(())
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn caches(&self) -> Result<web_sys::CacheStorage, UseCacheStorageError> {
+        if !self.is_supported.get_untracked() {
+            return Err(UseCacheStorageError::NotSupported);
+        }
+
+        window().caches().map_err(UseCacheStorageError::Failed)
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    async fn open(&self) -> Result<web_sys::Cache, UseCacheStorageError> {
+        use wasm_bindgen::JsCast;
+
+        let caches = self.caches()?;
+
+        crate::js_fut!(caches.open(&self.name()))
+            .await
+            .map(|cache| cache.unchecked_into())
+            .map_err(UseCacheStorageError::Failed)
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn response_size(cache: &web_sys::Cache, request: &web_sys::Request) -> Option<f64> {
+    use wasm_bindgen::JsCast;
+
+    let response: web_sys::Response = crate::js_fut!(cache.match_with_request(request))
+        .await
+        .ok()?
+        .dyn_into()
+        .ok()?;
+
+    let blob: web_sys::Blob = crate::js_fut!(response.blob().ok()?)
+        .await
+        .ok()?
+        .dyn_into()
+        .ok()?;
+
+    Some(blob.size())
+}
+
+/// A request to look up in or store into the cache. Either a URL or a [`web_sys::Request`].
+pub enum CacheRequest {
+    Url(String),
+    Request(web_sys::Request),
+}
+
+impl From<&str> for CacheRequest {
+    fn from(url: &str) -> Self {
+        Self::Url(url.to_string())
+    }
+}
+
+impl From<String> for CacheRequest {
+    fn from(url: String) -> Self {
+        Self::Url(url)
+    }
+}
+
+impl From<web_sys::Request> for CacheRequest {
+    fn from(request: web_sys::Request) -> Self {
+        Self::Request(request)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum UseCacheStorageError {
+    #[error("Cache storage is not available on the server")]
+    Server,
+
+    #[error("Cache storage is not supported by this browser")]
+    NotSupported,
+
+    #[error("Cache operation failed")]
+    Failed(JsValue),
+}
+
+/// Options for [`use_cache_storage_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseCacheStorageOptions {
+    /// Compute the total size of the cached responses. Defaults to `false`.
+    measure_size: bool,
+}
+
+/// Return type of [`use_cache_storage`].
+pub struct UseCacheStorageReturn {
+    /// `true` if the browser supports the Cache Storage API
+    pub is_supported: Signal<bool>,
+
+    /// The URLs of the cached requests
+    pub keys: Signal<Vec<String>>,
+
+    /// Total size of the cached responses in bytes. Requires the option `measure_size`.
+    pub size: Signal<Option<f64>>,
+
+    /// Methods to read and modify the cache
+    pub cache: CacheHandle,
+}
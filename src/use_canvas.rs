@@ -0,0 +1,402 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::utils::Pausable;
+use crate::{
+    use_device_pixel_ratio, use_element_size, use_raf_fn_with_options, UseElementSizeReturn,
+    UseRafFnCallbackArgs, UseRafFnOptions,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+use wasm_bindgen::JsCast;
+
+/// Frames that arrive up to this many milliseconds early still count when limiting the frame rate.
+/// Otherwise the jitter of `requestAnimationFrame` would skip frames that should be drawn.
+const FPS_LIMIT_TOLERANCE: f64 = 1.0;
+
+/// Reactive canvas with a rendering context and a draw loop.
+///
+/// Binds to a `<canvas>` element and keeps its backing store sized to the element's CSS size
+/// multiplied by the [device pixel ratio](fn@crate::use_device_pixel_ratio) so drawings stay crisp on
+/// high density screens. The rendering context is requested once the element is mounted and a
+/// `requestAnimationFrame` draw loop can be registered that is optionally capped to a maximum frame rate.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_canvas)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::{html::Canvas, prelude::*};
+/// # use leptos_use::{use_canvas_with_options, UseCanvasOptions, UseCanvasReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let canvas = NodeRef::<Canvas>::new();
+///
+/// let UseCanvasReturn { pause, resume, .. } = use_canvas_with_options(
+///     canvas,
+///     UseCanvasOptions::default()
+///         .max_fps(Some(30.0))
+///         .on_draw(|args| {
+///             if let Some(context) = args.context.as_2d() {
+///                 context.clear_rect(0.0, 0.0, args.width as f64, args.height as f64);
+///                 context.fill_rect(0.0, 0.0, args.timestamp % args.width as f64, 10.0);
+///             }
+///         }),
+/// );
+///
+/// view! { <canvas node_ref=canvas style="width: 100%; height: 200px;"></canvas> }
+/// # }
+/// ```
+///
+/// The returned `width` and `height` are the size of the backing store in device pixels. To draw in
+/// CSS pixels with a 2d context, scale it by `pixel_ratio`. Please note that changing the size of a canvas
+/// resets the state of its context, including any transformations.
+///
+/// ## Context
+///
+/// With the option `context_type` you can choose between a `2d` (the default), `webgl` or `webgl2` context.
+/// The `context` signal contains `None` until the canvas is mounted or if the browser doesn't support the
+/// requested context type.
+///
+/// ```
+/// # use leptos::{html::Canvas, prelude::*};
+/// # use leptos_use::{use_canvas_with_options, CanvasContextType, UseCanvasOptions, UseCanvasReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let canvas = NodeRef::<Canvas>::new();
+///
+/// let UseCanvasReturn { context, .. } = use_canvas_with_options(
+///     canvas,
+///     UseCanvasOptions::default().context_type(CanvasContextType::WebGl2),
+/// );
+///
+/// Effect::new(move || {
+///     if let Some(gl) = context.get().and_then(|context| context.as_webgl2().cloned()) {
+///         gl.clear_color(0.0, 0.0, 0.0, 1.0);
+///         gl.clear(web_sys::WebGl2RenderingContext::COLOR_BUFFER_BIT);
+///     }
+/// });
+/// #
+/// # view! { <canvas node_ref=canvas></canvas> }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `pause` and `resume` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_canvas`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no context is created, `width` and `height` are always `0` and the draw loop never runs.
+///
+/// ## See also
+///
+/// - [`fn@crate::use_element_size`]
+/// - [`fn@crate::use_raf_fn`]
+pub fn use_canvas<El, M>(
+    target: El,
+) -> UseCanvasReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::HtmlCanvasElement, M>,
+{
+    use_canvas_with_options(target, UseCanvasOptions::default())
+}
+
+/// Version of [`use_canvas`] that takes a `UseCanvasOptions`. See [`use_canvas`] for how to use.
+pub fn use_canvas_with_options<El, M>(
+    target: El,
+    options: UseCanvasOptions,
+) -> UseCanvasReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::HtmlCanvasElement, M>,
+{
+    let UseCanvasOptions {
+        context_type,
+        auto_resize,
+        max_fps,
+        immediate,
+        on_draw,
+    } = options;
+
+    let target = target.into_element_maybe_signal();
+
+    let (context, set_context) = signal_local(None::<CanvasContext>);
+    let (width, set_width) = signal(0_u32);
+    let (height, set_height) = signal(0_u32);
+
+    let pixel_ratio = use_device_pixel_ratio();
+    let UseElementSizeReturn {
+        width: css_width,
+        height: css_height,
+    } = use_element_size(target.clone());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        Effect::new({
+            let target = target.clone();
+
+            move || {
+                let context = target.get().and_then(|canvas| {
+                    canvas
+                        .get_context(context_type.as_str())
+                        .ok()
+                        .flatten()
+                        .and_then(|context| CanvasContext::new(context_type, context))
+                });
+
+                set_context.set(context);
+            }
+        });
+
+        Effect::new({
+            let target = target.clone();
+
+            move || {
+                let Some(canvas) = target.get() else {
+                    set_width.set(0);
+                    set_height.set(0);
+                    return;
+                };
+
+                if auto_resize {
+                    let pixel_ratio = pixel_ratio.get();
+                    let backing_width = (css_width.get() * pixel_ratio).round() as u32;
+                    let backing_height = (css_height.get() * pixel_ratio).round() as u32;
+
+                    if backing_width > 0 && canvas.width() != backing_width {
+                        canvas.set_width(backing_width);
+                    }
+                    if backing_height > 0 && canvas.height() != backing_height {
+                        canvas.set_height(backing_height);
+                    }
+                }
+
+                set_width.set(canvas.width());
+                set_height.set(canvas.height());
+            }
+        });
+    }
+
+    let last_draw = Rc::new(Cell::new(None::<f64>));
+    let has_draw_callback = on_draw.is_some();
+
+    let Pausable {
+        pause,
+        resume,
+        is_active,
+    } = use_raf_fn_with_options(
+        move |UseRafFnCallbackArgs { timestamp, .. }| {
+            let Some(on_draw) = &on_draw else {
+                return;
+            };
+
+            let Some(context) = context.get_untracked() else {
+                return;
+            };
+
+            let last = last_draw.get();
+
+            if let (Some(max_fps), Some(last)) = (max_fps, last) {
+                if max_fps > 0.0 && timestamp - last < 1000.0 / max_fps - FPS_LIMIT_TOLERANCE {
+                    return;
+                }
+            }
+
+            last_draw.set(Some(timestamp));
+
+            on_draw(UseCanvasDrawArgs {
+                context,
+                width: width.get_untracked(),
+                height: height.get_untracked(),
+                pixel_ratio: pixel_ratio.get_untracked(),
+                delta: last.map(|last| timestamp - last).unwrap_or_default(),
+                timestamp,
+            });
+        },
+        UseRafFnOptions::default().immediate(immediate && has_draw_callback),
+    );
+
+    UseCanvasReturn {
+        context: context.into(),
+        width: width.into(),
+        height: height.into(),
+        pixel_ratio,
+        is_active,
+        pause,
+        resume,
+    }
+}
+
+/// Type of rendering context requested by [`use_canvas_with_options`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CanvasContextType {
+    /// `CanvasRenderingContext2D`
+    #[default]
+    TwoD,
+    /// `WebGLRenderingContext`
+    WebGl,
+    /// `WebGL2RenderingContext`
+    WebGl2,
+}
+
+impl CanvasContextType {
+    /// The context id that is passed to `canvas.getContext()`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TwoD => "2d",
+            Self::WebGl => "webgl",
+            Self::WebGl2 => "webgl2",
+        }
+    }
+}
+
+/// Rendering context of a canvas returned by [`use_canvas`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum CanvasContext {
+    /// Context of type `CanvasContextType::TwoD`
+    TwoD(web_sys::CanvasRenderingContext2d),
+    /// Context of type `CanvasContextType::WebGl`
+    WebGl(web_sys::WebGlRenderingContext),
+    /// Context of type `CanvasContextType::WebGl2`
+    WebGl2(web_sys::WebGl2RenderingContext),
+}
+
+impl CanvasContext {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn new(context_type: CanvasContextType, context: js_sys::Object) -> Option<Self> {
+        match context_type {
+            CanvasContextType::TwoD => context.dyn_into().ok().map(Self::TwoD),
+            CanvasContextType::WebGl => context.dyn_into().ok().map(Self::WebGl),
+            CanvasContextType::WebGl2 => context.dyn_into().ok().map(Self::WebGl2),
+        }
+    }
+
+    /// Returns the 2d context if this is one.
+    pub fn as_2d(&self) -> Option<&web_sys::CanvasRenderingContext2d> {
+        match self {
+            Self::TwoD(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns the WebGL context if this is one.
+    pub fn as_webgl(&self) -> Option<&web_sys::WebGlRenderingContext> {
+        match self {
+            Self::WebGl(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns the WebGL2 context if this is one.
+    pub fn as_webgl2(&self) -> Option<&web_sys::WebGl2RenderingContext> {
+        match self {
+            Self::WebGl2(context) => Some(context),
+            _ => None,
+        }
+    }
+}
+
+/// Options for [`use_canvas_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseCanvasOptions {
+    /// The type of rendering context to request. Defaults to `CanvasContextType::TwoD`.
+    context_type: CanvasContextType,
+
+    /// Keep the backing store of the canvas sized to its CSS size times the device pixel ratio.
+    /// Defaults to `true`. If `false` the `width` and `height` attributes of the canvas are left as they are.
+    auto_resize: bool,
+
+    /// Maximum number of frames per second the draw callback is called. Defaults to `None` which means
+    /// it's called on every animation frame.
+    max_fps: Option<f64>,
+
+    /// Start the draw loop immediately. Defaults to `true`. If `false` the loop only starts
+    /// when you call `resume()`. Has no effect if no draw callback is set.
+    immediate: bool,
+
+    /// Called on every frame of the draw loop once the context is available.
+    #[builder(skip)]
+    on_draw: Option<Arc<dyn Fn(UseCanvasDrawArgs) + Send + Sync>>,
+}
+
+impl UseCanvasOptions {
+    /// Called on every frame of the draw loop once the context is available.
+    /// The frame rate can be limited with `max_fps`.
+    pub fn on_draw(self, on_draw: impl Fn(UseCanvasDrawArgs) + Send + Sync + 'static) -> Self {
+        Self {
+            on_draw: Some(Arc::new(on_draw)),
+            ..self
+        }
+    }
+}
+
+impl Default for UseCanvasOptions {
+    fn default() -> Self {
+        Self {
+            context_type: CanvasContextType::default(),
+            auto_resize: true,
+            max_fps: None,
+            immediate: true,
+            on_draw: None,
+        }
+    }
+}
+
+/// Argument of the draw callback of [`UseCanvasOptions::on_draw`].
+pub struct UseCanvasDrawArgs {
+    /// The rendering context of the canvas.
+    pub context: CanvasContext,
+
+    /// Width of the backing store in device pixels.
+    pub width: u32,
+
+    /// Height of the backing store in device pixels.
+    pub height: u32,
+
+    /// The current device pixel ratio.
+    pub pixel_ratio: f64,
+
+    /// Time elapsed since the last call of the draw callback in milliseconds, `0.0` on the first call.
+    pub delta: f64,
+
+    /// Time elapsed since the time origin of the page in milliseconds.
+    pub timestamp: f64,
+}
+
+/// Return type of [`use_canvas`].
+pub struct UseCanvasReturn<PauseFn, ResumeFn>
+where
+    PauseFn: Fn() + Clone + Send + Sync,
+    ResumeFn: Fn() + Clone + Send + Sync,
+{
+    /// The rendering context. `None` until the canvas is mounted or if the context type isn't supported.
+    pub context: Signal<Option<CanvasContext>, LocalStorage>,
+
+    /// Width of the backing store of the canvas in device pixels.
+    pub width: Signal<u32>,
+
+    /// Height of the backing store of the canvas in device pixels.
+    pub height: Signal<u32>,
+
+    /// The current device pixel ratio
+    pub pixel_ratio: Signal<f64>,
+
+    /// Whether the draw loop is running
+    pub is_active: Signal<bool>,
+
+    /// Pause the draw loop
+    pub pause: PauseFn,
+
+    /// Resume the draw loop
+    pub resume: ResumeFn,
+}
@@ -0,0 +1,379 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive viewport coordinates of the caret inside an `<input>`, a `<textarea>` or a `contenteditable` element.
+///
+/// Useful to position popups like an @-mention autocomplete next to the caret.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_caret_position)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Textarea;
+/// # use leptos_use::{use_caret_position, UseCaretPositionReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let textarea = NodeRef::<Textarea>::new();
+///
+/// let UseCaretPositionReturn {
+///     x,
+///     y,
+///     height,
+///     is_active,
+///     ..
+/// } = use_caret_position(textarea);
+///
+/// view! {
+///     <textarea node_ref=textarea></textarea>
+///     <Show when=move || is_active.get()>
+///         <div
+///             style="position: fixed"
+///             style:left=move || format!("{}px", x.get())
+///             style:top=move || format!("{}px", y.get() + height.get())
+///         >
+///             "Suggestions"
+///         </div>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// The position is updated when the user types, the selection changes or the element is scrolled. If the element
+/// moves for other reasons you can call `update` to measure it again.
+///
+/// ## How it works
+///
+/// Inputs and textareas don't expose the caret position. Their text is copied into a hidden "mirror" element that has the same
+/// styles. The position of a marker at the caret index inside the mirror is the position of the caret.
+/// For `contenteditable` elements the bounding rect of the selection is used.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the position is always `0.0`, `is_active` is `false` and `update` is a no-op.
+pub fn use_caret_position<El, M>(
+    target: El,
+) -> UseCaretPositionReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let (x, set_x) = signal(0.0);
+    let (y, set_y) = signal(0.0);
+    let (height, set_height) = signal(0.0);
+    let (is_active, set_active) = signal(false);
+
+    let update;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = target;
+        let _ = set_x;
+        let _ = set_y;
+        let _ = set_height;
+        let _ = set_active;
+
+        update = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{sendwrap_fn, use_event_listener};
+        use leptos::ev::{blur, focus, input, scroll, Custom};
+
+        let target = target.into_element_maybe_signal();
+        let element = Signal::derive_local({
+            let target = target.clone();
+            move || target.get()
+        });
+
+        update = sendwrap_fn!(move || {
+            let Some(el) = element.get_untracked() else {
+                return;
+            };
+
+            let is_focused = document()
+                .active_element()
+                .is_some_and(|active| el == active || el.contains(Some(&active)));
+
+            let rect = if is_focused { caret_rect(&el) } else { None };
+
+            match rect {
+                Some(CaretRect {
+                    x: left,
+                    y: top,
+                    height: line_height,
+                }) => {
+                    set_x.set(left);
+                    set_y.set(top);
+                    set_height.set(line_height);
+                    set_active.set(true);
+                }
+                None => set_active.set(false),
+            }
+        });
+
+        let _ = use_event_listener(target.clone(), input, {
+            let update = update.clone();
+            move |_| update()
+        });
+        let _ = use_event_listener(target.clone(), scroll, {
+            let update = update.clone();
+            move |_| update()
+        });
+        let _ = use_event_listener(target.clone(), focus, {
+            let update = update.clone();
+            move |_| update()
+        });
+        let _ = use_event_listener(target, blur, move |_| set_active.set(false));
+        let _ = use_event_listener(
+            document(),
+            Custom::<web_sys::Event>::new("selectionchange"),
+            {
+                let update = update.clone();
+                move |_| update()
+            },
+        );
+    }
+
+    UseCaretPositionReturn {
+        x: x.into(),
+        y: y.into(),
+        height: height.into(),
+        is_active: is_active.into(),
+        update,
+    }
+}
+
+/// Return type of [`use_caret_position`].
+pub struct UseCaretPositionReturn<UpdateFn>
+where
+    UpdateFn: Fn() + Clone + Send + Sync,
+{
+    /// Horizontal viewport coordinate of the caret in pixels
+    pub x: Signal<f64>,
+
+    /// Vertical viewport coordinate of the top of the caret in pixels
+    pub y: Signal<f64>,
+
+    /// Height of the caret (the line height) in pixels
+    pub height: Signal<f64>,
+
+    /// `true` while the element has the focus and the caret position is known
+    pub is_active: Signal<bool>,
+
+    /// Measures the caret position again
+    pub update: UpdateFn,
+}
+
+#[cfg(not(feature = "ssr"))]
+struct CaretRect {
+    x: f64,
+    y: f64,
+    height: f64,
+}
+
+/// Styles that affect the layout of the text and are copied to the mirror element
+#[cfg(not(feature = "ssr"))]
+const MIRRORED_PROPERTIES: [&str; 33] = [
+    "direction",
+    "box-sizing",
+    "width",
+    "height",
+    "overflow-x",
+    "overflow-y",
+    "border-top-width",
+    "border-right-width",
+    "border-bottom-width",
+    "border-left-width",
+    "border-style",
+    "padding-top",
+    "padding-right",
+    "padding-bottom",
+    "padding-left",
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "font-stretch",
+    "font-size",
+    "font-size-adjust",
+    "line-height",
+    "font-family",
+    "font-feature-settings",
+    "font-kerning",
+    "text-align",
+    "text-transform",
+    "text-indent",
+    "text-decoration",
+    "letter-spacing",
+    "word-spacing",
+    "tab-size",
+    "white-space",
+];
+
+#[cfg(not(feature = "ssr"))]
+fn caret_rect(el: &web_sys::Element) -> Option<CaretRect> {
+    use wasm_bindgen::JsCast;
+
+    if let Some(input) = el.dyn_ref::<web_sys::HtmlInputElement>() {
+        let caret = input.selection_end().ok().flatten()?;
+        return mirror_caret_rect(el, &input.value(), caret, false);
+    }
+
+    if let Some(textarea) = el.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+        let caret = textarea.selection_end().ok().flatten()?;
+        return mirror_caret_rect(el, &textarea.value(), caret, true);
+    }
+
+    selection_caret_rect(el)
+}
+
+/// Caret position in an input or textarea measured with a mirror element
+#[cfg(not(feature = "ssr"))]
+fn mirror_caret_rect(
+    el: &web_sys::Element,
+    value: &str,
+    caret: u32,
+    is_multiline: bool,
+) -> Option<CaretRect> {
+    use wasm_bindgen::JsCast;
+
+    let style = window().get_computed_style(el).ok().flatten()?;
+    let body = document().body()?;
+
+    let mirror = document()
+        .create_element("div")
+        .ok()?
+        .unchecked_into::<web_sys::HtmlElement>();
+    let mirror_style = mirror.style();
+
+    for property in MIRRORED_PROPERTIES {
+        let _ = mirror_style.set_property(
+            property,
+            &style.get_property_value(property).unwrap_or_default(),
+        );
+    }
+
+    let _ = mirror_style.set_property("position", "absolute");
+    let _ = mirror_style.set_property("top", "0");
+    let _ = mirror_style.set_property("left", "0");
+    let _ = mirror_style.set_property("visibility", "hidden");
+    let _ = mirror_style.set_property("overflow", "hidden");
+
+    if is_multiline {
+        let _ = mirror_style.set_property("white-space", "pre-wrap");
+        let _ = mirror_style.set_property("overflow-wrap", "break-word");
+    } else {
+        let _ = mirror_style.set_property("white-space", "pre");
+    }
+
+    // the caret index is in UTF-16 code units
+    let value = js_sys::JsString::from(value);
+    let before: String = value.slice(0, caret).into();
+    let after: String = value.slice(caret, value.length()).into();
+
+    mirror.set_text_content(Some(&before));
+
+    let marker = document()
+        .create_element("span")
+        .ok()?
+        .unchecked_into::<web_sys::HtmlElement>();
+    // the marker needs some content to have a height
+    marker.set_text_content(Some(if after.is_empty() { "." } else { &after }));
+
+    let _ = mirror.append_child(&marker);
+    let _ = body.append_child(&mirror);
+
+    let border_top = parse_px(
+        &style
+            .get_property_value("border-top-width")
+            .unwrap_or_default(),
+    );
+    let border_left = parse_px(
+        &style
+            .get_property_value("border-left-width")
+            .unwrap_or_default(),
+    );
+    let line_height = match parse_px(&style.get_property_value("line-height").unwrap_or_default()) {
+        height if height > 0.0 => height,
+        _ => marker.offset_height() as f64,
+    };
+
+    let top = marker.offset_top() as f64 + border_top;
+    let left = marker.offset_left() as f64 + border_left;
+
+    mirror.remove();
+
+    let rect = el.get_bounding_client_rect();
+
+    Some(CaretRect {
+        x: rect.left() + left - el.scroll_left() as f64,
+        y: rect.top() + top - el.scroll_top() as f64,
+        height: line_height,
+    })
+}
+
+/// Caret position in a contenteditable element from the current selection
+#[cfg(not(feature = "ssr"))]
+fn selection_caret_rect(el: &web_sys::Element) -> Option<CaretRect> {
+    let selection = window().get_selection().ok().flatten()?;
+
+    if selection.range_count() == 0 {
+        return None;
+    }
+
+    let range = selection.get_range_at(0).ok()?;
+
+    if !el.contains(Some(&range.end_container().ok()?)) {
+        return None;
+    }
+
+    let range = range.clone_range();
+    range.collapse_with_to_start(false);
+
+    let rect = range
+        .get_client_rects()
+        .and_then(|rects| rects.get(0))
+        .unwrap_or_else(|| range.get_bounding_client_rect());
+
+    if rect.height() > 0.0 {
+        return Some(CaretRect {
+            x: rect.left(),
+            y: rect.top(),
+            height: rect.height(),
+        });
+    }
+
+    // empty lines have no rects. Fall back to the start of the element
+    let style = window().get_computed_style(el).ok().flatten()?;
+    let rect = el.get_bounding_client_rect();
+
+    Some(CaretRect {
+        x: rect.left()
+            + parse_px(&style.get_property_value("padding-left").unwrap_or_default())
+            + parse_px(
+                &style
+                    .get_property_value("border-left-width")
+                    .unwrap_or_default(),
+            ),
+        y: rect.top()
+            + parse_px(&style.get_property_value("padding-top").unwrap_or_default())
+            + parse_px(
+                &style
+                    .get_property_value("border-top-width")
+                    .unwrap_or_default(),
+            ),
+        height: parse_px(&style.get_property_value("line-height").unwrap_or_default()),
+    })
+}
+
+#[cfg(not(feature = "ssr"))]
+fn parse_px(value: &str) -> f64 {
+    value.trim_end_matches("px").parse().unwrap_or_default()
+}
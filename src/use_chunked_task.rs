@@ -0,0 +1,394 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Process a long iterator in small slices so that the UI stays responsive.
+///
+/// Every item that the iterator returned by `iter` yields is passed to `process`. Instead of doing all the
+/// work at once, items are processed only until the time `budget` of the current slice is used up.
+/// The rest is continued in the next animation frame or idle period. This way huge client-side
+/// computations like parsing, filtering or indexing large data sets don't freeze the page.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_chunked_task)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_chunked_task, UseChunkedTaskReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (sum, set_sum) = signal(0_u64);
+///
+/// let UseChunkedTaskReturn {
+///     start,
+///     abort,
+///     progress,
+///     is_running,
+///     ..
+/// } = use_chunked_task(
+///     move || 0..10_000_000_u64,
+///     move |n| set_sum.update_untracked(|sum| *sum += n),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| start()>"Start"</button>
+///     <button on:click=move |_| abort()>"Abort"</button>
+///     <p>{move || format!("{:.0}%", progress.get().unwrap_or_default() * 100.0)}</p>
+/// }
+/// # }
+/// ```
+///
+/// `iter` is called every time `start` is called so the task can be run again. Calling `start` while the
+/// task is running starts it over. `abort` stops it and drops the remaining items.
+///
+/// For work that doesn't come as a collection use [`std::iter::from_fn`]. Return `None` from it when you're
+/// done.
+///
+/// ## Progress
+///
+/// `processed` counts the items done so far. If the size of the iterator is known (i.e. its
+/// [`Iterator::size_hint`] is exact) `total` and `progress` (between `0.0` and `1.0`) are available as well.
+/// Otherwise they are `None`. These signals are only updated at the end of each slice.
+///
+/// ## Scheduler
+///
+/// By default the slices are run in animation frames with a budget of 8ms each, which leaves enough of
+/// a 60fps frame for rendering. With [`ChunkScheduler::Idle`] the slices are run by
+/// [`fn@crate::use_idle_callback`] instead and use up whatever time the browser has left in the idle period.
+/// That's slower but interferes even less with animations and user input.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_chunked_task_with_options, ChunkScheduler, UseChunkedTaskOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let rows: Vec<String> = vec![];
+/// let task = use_chunked_task_with_options(
+///     move || rows.clone(),
+///     move |row| { /* index the row */ },
+///     UseChunkedTaskOptions::default()
+///         .scheduler(ChunkScheduler::Idle)
+///         .immediate(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `start` and `abort` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_chunked_task`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this does basically nothing. Neither `iter` nor `process` are ever called.
+pub fn use_chunked_task<IFn, I, F>(
+    iter: IFn,
+    process: F,
+) -> UseChunkedTaskReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    IFn: Fn() -> I + 'static,
+    I: IntoIterator + 'static,
+    F: FnMut(I::Item) + 'static,
+{
+    use_chunked_task_with_options(iter, process, UseChunkedTaskOptions::default())
+}
+
+/// Version of [`use_chunked_task`] that takes a `UseChunkedTaskOptions`. See [`use_chunked_task`] for how to use.
+pub fn use_chunked_task_with_options<IFn, I, F>(
+    iter: IFn,
+    process: F,
+    options: UseChunkedTaskOptions,
+) -> UseChunkedTaskReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    IFn: Fn() -> I + 'static,
+    I: IntoIterator + 'static,
+    F: FnMut(I::Item) + 'static,
+{
+    let UseChunkedTaskOptions {
+        scheduler,
+        budget,
+        immediate,
+    } = options;
+
+    let (is_running, set_running) = signal(false);
+    let (is_finished, set_finished) = signal(false);
+    let (processed, set_processed) = signal(0_usize);
+    let (total, set_total) = signal(None::<usize>);
+
+    let start;
+    let abort;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = iter;
+        let _ = process;
+        let _ = set_running;
+        let _ = set_finished;
+        let _ = set_processed;
+        let _ = set_total;
+
+        start = || {};
+        abort = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::utils::Pausable;
+        use crate::{
+            use_idle_callback_with_options, use_raf_fn_with_options, UseIdleCallbackOptions,
+            UseIdleCallbackReturn, UseRafFnOptions,
+        };
+        use std::cell::{Cell, RefCell};
+        use std::rc::Rc;
+
+        let remaining = Rc::new(RefCell::new(None::<I::IntoIter>));
+        // incremented whenever the task is started or aborted to drop the slice that is running
+        let generation = Rc::new(Cell::new(0_u64));
+        let process = Rc::new(RefCell::new(process));
+
+        // Processes items until `budget` milliseconds have passed. Returns `true` if there is more work.
+        let run_slice = {
+            let remaining = Rc::clone(&remaining);
+            let generation = Rc::clone(&generation);
+
+            move |budget: f64| -> bool {
+                let Some(mut items) = remaining.take() else {
+                    return false;
+                };
+
+                let slice_generation = generation.get();
+                let started_at = now();
+                let mut count = 0;
+                let mut is_done = false;
+
+                while generation.get() == slice_generation {
+                    let Some(item) = items.next() else {
+                        is_done = true;
+                        break;
+                    };
+
+                    (process.borrow_mut())(item);
+                    count += 1;
+
+                    if now() - started_at >= budget {
+                        break;
+                    }
+                }
+
+                // `start` or `abort` has been called from within `process`
+                if generation.get() != slice_generation {
+                    return remaining.borrow().is_some();
+                }
+
+                set_processed.update(|processed| *processed += count);
+
+                if is_done {
+                    set_running.set(false);
+                    set_finished.set(true);
+                    false
+                } else {
+                    remaining.replace(Some(items));
+                    true
+                }
+            }
+        };
+
+        let (resume_slices, pause_slices) = match scheduler {
+            ChunkScheduler::AnimationFrame => {
+                let pause_ref = Rc::new(RefCell::new(None::<Rc<dyn Fn()>>));
+
+                let Pausable { pause, resume, .. } = use_raf_fn_with_options(
+                    {
+                        let pause_ref = Rc::clone(&pause_ref);
+
+                        move |_| {
+                            if !run_slice(budget) {
+                                if let Some(pause) = pause_ref.borrow().as_ref() {
+                                    pause();
+                                }
+                            }
+                        }
+                    },
+                    UseRafFnOptions::default().immediate(false),
+                );
+
+                let pause: Rc<dyn Fn()> = Rc::new(pause);
+                pause_ref.replace(Some(Rc::clone(&pause)));
+
+                (Rc::new(resume) as Rc<dyn Fn()>, pause)
+            }
+            ChunkScheduler::Idle => {
+                let schedule_ref = Rc::new(RefCell::new(None::<Rc<dyn Fn()>>));
+
+                let UseIdleCallbackReturn {
+                    schedule, cancel, ..
+                } = use_idle_callback_with_options(
+                    {
+                        let schedule_ref = Rc::clone(&schedule_ref);
+
+                        move |deadline| {
+                            if run_slice(deadline.time_remaining().max(1.0)) {
+                                if let Some(schedule) = schedule_ref.borrow().as_ref() {
+                                    schedule();
+                                }
+                            }
+                        }
+                    },
+                    UseIdleCallbackOptions::default(),
+                );
+
+                let schedule: Rc<dyn Fn()> = Rc::new(schedule);
+                schedule_ref.replace(Some(Rc::clone(&schedule)));
+
+                (schedule, Rc::new(cancel) as Rc<dyn Fn()>)
+            }
+        };
+
+        abort = {
+            let remaining = Rc::clone(&remaining);
+            let generation = Rc::clone(&generation);
+            let pause_slices = Rc::clone(&pause_slices);
+
+            sendwrap_fn!(move || {
+                generation.set(generation.get() + 1);
+                remaining.replace(None);
+                pause_slices();
+                set_running.set(false);
+            })
+        };
+
+        start = {
+            let abort = abort.clone();
+            let iter = Rc::new(iter);
+
+            sendwrap_fn!(move || {
+                abort();
+
+                let items = iter().into_iter();
+                let total = match items.size_hint() {
+                    (lower, Some(upper)) if lower == upper => Some(lower),
+                    _ => None,
+                };
+
+                remaining.replace(Some(items));
+
+                set_total.set(total);
+                set_processed.set(0);
+                set_finished.set(false);
+                set_running.set(true);
+
+                resume_slices();
+            })
+        };
+
+        if immediate {
+            start();
+        }
+
+        on_cleanup({
+            let abort = abort.clone();
+            #[allow(clippy::redundant_closure)]
+            move || abort()
+        });
+    }
+
+    let progress = Signal::derive(move || {
+        total.get().map(|total| {
+            if total == 0 {
+                1.0
+            } else {
+                processed.get() as f64 / total as f64
+            }
+        })
+    });
+
+    UseChunkedTaskReturn {
+        is_running: is_running.into(),
+        is_finished: is_finished.into(),
+        processed: processed.into(),
+        total: total.into(),
+        progress,
+        start,
+        abort,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn now() -> f64 {
+    window()
+        .performance()
+        .map(|performance| performance.now())
+        .unwrap_or_else(js_sys::Date::now)
+}
+
+/// When the slices of [`use_chunked_task`] are run.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub enum ChunkScheduler {
+    /// In every animation frame with the `budget` of the options
+    #[default]
+    AnimationFrame,
+
+    /// In idle periods of the browser with the time that is left in the period
+    Idle,
+}
+
+/// Options for [`use_chunked_task_with_options`].
+#[derive(DefaultBuilder, Clone, Copy, Debug)]
+pub struct UseChunkedTaskOptions {
+    /// How the slices are scheduled. Defaults to [`ChunkScheduler::AnimationFrame`].
+    scheduler: ChunkScheduler,
+
+    /// Milliseconds a slice may take with [`ChunkScheduler::AnimationFrame`]. Defaults to `8.0`.
+    budget: f64,
+
+    /// Start the task immediately on creation. Defaults to `false`.
+    immediate: bool,
+}
+
+impl Default for UseChunkedTaskOptions {
+    fn default() -> Self {
+        Self {
+            scheduler: ChunkScheduler::default(),
+            budget: 8.0,
+            immediate: false,
+        }
+    }
+}
+
+/// Return type of [`use_chunked_task`].
+pub struct UseChunkedTaskReturn<StartFn, AbortFn>
+where
+    StartFn: Fn() + Clone + Send + Sync,
+    AbortFn: Fn() + Clone + Send + Sync,
+{
+    /// `true` while the task has items left to process
+    pub is_running: Signal<bool>,
+
+    /// `true` after all items have been processed. Reset by `start`.
+    pub is_finished: Signal<bool>,
+
+    /// Number of items processed so far
+    pub processed: Signal<usize>,
+
+    /// Number of items in total if the iterator knows its exact size
+    pub total: Signal<Option<usize>>,
+
+    /// Ratio of processed items between `0.0` and `1.0` if `total` is known
+    pub progress: Signal<Option<f64>>,
+
+    /// Starts the task from the beginning
+    pub start: StartFn,
+
+    /// Stops the task and drops the remaining items
+    pub abort: AbortFn,
+}
@@ -0,0 +1,300 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::js;
+use crate::utils::js_value_from_to_string;
+use cfg_if::cfg_if;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use wasm_bindgen::JsValue;
+
+/// Reactive [`Intl.Collator`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator).
+///
+/// Compares strings in a language sensitive way. This is what you want to use for sorting lists that
+/// are displayed to the user as Rust's `Ord` for strings simply compares the bytes.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_collator)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_collator, UseCollatorOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let collator = use_collator(UseCollatorOptions::default().locale("de"));
+///
+/// let mut words = vec!["Z", "a", "z", "ä"];
+/// words.sort_by(|a, b| collator.compare(a, b)); // ["a", "ä", "z", "Z"]
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Sorting reactively
+///
+/// The `comparator` method returns a compare function that can be passed to [`fn@crate::use_sorted_by`].
+/// The options can be a signal. When they change the collator is recreated and the sorted list
+/// is updated accordingly.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_collator, use_sorted_by, UseCollatorOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (locale, set_locale) = signal("sv".to_string());
+///
+/// let collator = use_collator(Signal::derive(move || {
+///     UseCollatorOptions::default().locale(&locale.get())
+/// }));
+///
+/// let (names, set_names) = signal(vec!["Zoë".to_string(), "Åsa".to_string(), "Anna".to_string()]);
+///
+/// // ["Anna", "Zoë", "Åsa"] in Swedish, ["Anna", "Åsa", "Zoë"] after set_locale("de")
+/// let sorted: Signal<Vec<String>> = use_sorted_by(names, collator.comparator());
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Using options
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_collator, CollatorSensitivity, UseCollatorOptions};
+/// # use std::cmp::Ordering;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// // natural sort order of numbers
+/// let collator = use_collator(UseCollatorOptions::default().numeric(true));
+/// let mut files = vec!["file10.txt", "file2.txt", "file1.txt"];
+/// files.sort_by(|a, b| collator.compare(a, b)); // ["file1.txt", "file2.txt", "file10.txt"]
+///
+/// // ignore accents and case, e.g. for searching
+/// let collator = use_collator(
+///     UseCollatorOptions::default()
+///         .locale("fr")
+///         .sensitivity(CollatorSensitivity::Base),
+/// );
+/// let equal = collator.compare("Élan", "elan") == Ordering::Equal; // true
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// For an exhaustive list of options see [`UseCollatorOptions`](https://docs.rs/leptos_use/latest/leptos_use/struct.UseCollatorOptions.html).
+///
+/// ## Server-Side Rendering
+///
+/// Since `Intl.Collator` is a JavaScript API it is not available on the server. That's why
+/// it falls back to comparing the strings with `Ord` on the server.
+pub fn use_collator(options: impl Into<Signal<UseCollatorOptions>>) -> UseCollatorReturn {
+    cfg_if! { if #[cfg(feature = "ssr")] {
+        UseCollatorReturn
+    } else {
+        let options = options.into();
+
+        let collator = Memo::new_with_compare(
+            move |_| {
+                options.with(|options| {
+                    SendWrapper::new(js_sys::Intl::Collator::new(
+                        &js_sys::Array::from_iter(options.locales.iter().map(JsValue::from)),
+                        &js_sys::Object::from(options.clone()),
+                    ))
+                })
+            },
+            // a new instance is only created when the options have changed
+            |_, _| true,
+        );
+
+        UseCollatorReturn {
+            js_intl_collator: collator.into(),
+        }
+    }}
+}
+
+/// Whether the comparison is for sorting or for searching for matching strings.
+#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CollatorUsage {
+    #[default]
+    Sort,
+    Search,
+}
+
+impl Display for CollatorUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sort => write!(f, "sort"),
+            Self::Search => write!(f, "search"),
+        }
+    }
+}
+
+js_value_from_to_string!(CollatorUsage);
+
+/// Which differences in the strings should lead to non-zero results.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CollatorSensitivity {
+    /// Only strings that differ in base letters compare as unequal. Examples: `a ≠ b`, `a = á`, `a = A`.
+    Base,
+    /// Only strings that differ in base letters or accents and other diacritic marks compare as unequal.
+    /// Examples: `a ≠ b`, `a ≠ á`, `a = A`.
+    Accent,
+    /// Only strings that differ in base letters or case compare as unequal. Examples: `a ≠ b`, `a = á`, `a ≠ A`.
+    Case,
+    /// Strings that differ in base letters, accents and other diacritic marks, or case compare as unequal.
+    /// Other differences may also be taken into consideration. Examples: `a ≠ b`, `a ≠ á`, `a ≠ A`.
+    Variant,
+}
+
+impl Display for CollatorSensitivity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Base => write!(f, "base"),
+            Self::Accent => write!(f, "accent"),
+            Self::Case => write!(f, "case"),
+            Self::Variant => write!(f, "variant"),
+        }
+    }
+}
+
+js_value_from_to_string!(CollatorSensitivity);
+
+/// Whether upper case or lower case should sort first.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CollatorCaseFirst {
+    Upper,
+    Lower,
+    /// Use the locale's default.
+    False,
+}
+
+impl Display for CollatorCaseFirst {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Upper => write!(f, "upper"),
+            Self::Lower => write!(f, "lower"),
+            Self::False => write!(f, "false"),
+        }
+    }
+}
+
+js_value_from_to_string!(CollatorCaseFirst);
+
+/// Options for [`use_collator`].
+#[derive(DefaultBuilder, Default, Clone, PartialEq, Debug)]
+pub struct UseCollatorOptions {
+    /// A vec of strings, each with a BCP 47 language tag. Please refer to the
+    /// [MDN Docs](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/Collator#parameters)
+    /// for more info.
+    locales: Vec<String>,
+
+    /// Whether the comparison is for sorting or for searching for matching strings. Defaults to `Sort`.
+    usage: CollatorUsage,
+
+    /// Which differences in the strings should lead to non-zero results.
+    /// The default is `Variant` for usage `Sort`; it's locale dependent for usage `Search`.
+    #[builder(into)]
+    sensitivity: Option<CollatorSensitivity>,
+
+    /// Whether punctuation should be ignored. Defaults to `false`.
+    ignore_punctuation: bool,
+
+    /// Whether numeric collation should be used, such that `"1" < "2" < "10"`. Defaults to `false`.
+    numeric: bool,
+
+    /// Whether upper case or lower case should sort first. The default is locale dependent.
+    #[builder(into)]
+    case_first: Option<CollatorCaseFirst>,
+
+    /// Variant collations for certain locales, such as `"pinyin"`, `"phonebk"` or `"emoji"`.
+    /// For a list of supported collation types, see [`Intl.Locale.prototype.getCollations()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCollations#supported_collation_types).
+    #[builder(into)]
+    collation: Option<String>,
+}
+
+impl UseCollatorOptions {
+    pub fn locale(self, locale: &str) -> Self {
+        Self {
+            locales: vec![locale.to_string()],
+            ..self
+        }
+    }
+}
+
+impl From<UseCollatorOptions> for js_sys::Object {
+    fn from(options: UseCollatorOptions) -> Self {
+        let obj = Self::new();
+
+        js!(obj["usage"] = options.usage);
+
+        if let Some(sensitivity) = options.sensitivity {
+            js!(obj["sensitivity"] = sensitivity);
+        }
+
+        js!(obj["ignorePunctuation"] = options.ignore_punctuation);
+        js!(obj["numeric"] = options.numeric);
+
+        if let Some(case_first) = options.case_first {
+            js!(obj["caseFirst"] = case_first);
+        }
+
+        if let Some(collation) = options.collation {
+            js!(obj["collation"] = collation);
+        }
+
+        obj
+    }
+}
+
+cfg_if! { if #[cfg(feature = "ssr")] {
+    #[derive(Copy, Clone)]
+    pub struct UseCollatorReturn;
+} else {
+    #[derive(Copy, Clone)]
+    pub struct UseCollatorReturn {
+        /// The instance of [`Intl.Collator`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator).
+        /// It's recreated when the options change.
+        pub js_intl_collator: Signal<SendWrapper<js_sys::Intl::Collator>>,
+    }
+}}
+
+impl UseCollatorReturn {
+    /// Compares two strings according to the locales and options of the collator.
+    ///
+    /// When called inside a reactive context, like the derived signal of [`fn@crate::use_sorted_by`],
+    /// it is rerun when the options change.
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        cfg_if! { if #[cfg(feature = "ssr")] {
+            a.cmp(b)
+        } else {
+            self.js_intl_collator.with(|collator| {
+                collator
+                    .compare()
+                    .call2(collator, &JsValue::from(a), &JsValue::from(b))
+                    .ok()
+                    .and_then(|result| result.as_f64())
+                    .map(|result| result.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+                    .unwrap_or(Ordering::Equal)
+            })
+        }}
+    }
+
+    /// Returns a compare function that can be passed to [`fn@crate::use_sorted_by`] or `sort_by`.
+    pub fn comparator<T>(&self) -> impl Fn(&T, &T) -> Ordering + Clone + Send + Sync + 'static
+    where
+        T: AsRef<str>,
+    {
+        let this = *self;
+
+        move |a: &T, b: &T| this.compare(a.as_ref(), b.as_ref())
+    }
+}
@@ -112,8 +112,94 @@ use wasm_bindgen::JsCast;
 /// # }
 /// ```
 ///
+/// On the server the storage can't be read. So when the cookie is enabled it is used to determine the
+/// color mode that is rendered, which avoids a flash of the wrong color mode on hydration.
+///
+/// While the color mode is `ColorMode::Auto` the server falls back to the `Sec-CH-Prefers-Color-Scheme` header
+/// (see below). Since not every browser sends this header, the system preference detected in the
+/// browser is additionally stored in the cookie named by `system_cookie_name` and used by the server
+/// if the header is missing.
+///
 /// For a working example please check out the [ssr example](https://github.com/Synphonyte/leptos-use/blob/main/examples/ssr/src/app.rs).
 ///
+/// ### Fallback chain
+///
+/// When the color mode is initialized, the sources in `fallback_chain` are tried in order and the
+/// first one that holds an explicit mode (not `ColorMode::Auto`) wins. Empty storage or a missing cookie
+/// don't hold a mode. If no source holds one, `initial_value` is used. The chosen mode is written back to
+/// the other enabled sources.
+///
+/// If `cookie_enabled` is `true` it defaults to cookie → storage → system. The cookie is the only source that the
+/// server can read as well, so this way the server and the browser render the same mode. Otherwise it defaults to
+/// storage → system.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_color_mode_with_options, ColorModeSource, UseColorModeOptions, UseColorModeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseColorModeReturn { mode, .. } = use_color_mode_with_options(
+///     UseColorModeOptions::default()
+///         .cookie_enabled(true)
+///         // prefer the mode stored in this browser, e.g. when the cookie expires quicker
+///         .fallback_chain(vec![
+///             ColorModeSource::Storage,
+///             ColorModeSource::Cookie,
+///             ColorModeSource::System,
+///         ]),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Auto mode
+///
+/// As long as the mode is `ColorMode::Auto` the system preference is followed, also when it changes while the page
+/// is open. As soon as the user sets an explicit mode this overrides the system preference until
+/// `ColorMode::Auto` is set again. `is_auto` tells you if the system preference is currently followed.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{ColorMode, use_color_mode, UseColorModeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseColorModeReturn { set_mode, is_auto, .. } = use_color_mode();
+///
+/// view! {
+///     <button disabled=is_auto on:click=move |_| set_mode.set(ColorMode::Auto)>
+///         "Follow system"
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// ### Transitions
+///
+/// By default CSS transitions are disabled while the color mode is switched to avoid elements flashing
+/// with different speeds. Set `transition_enabled` to `true` to keep them.
+///
+/// With `view_transition` enabled the switch is wrapped in a
+/// [View Transition](https://developer.mozilla.org/en-US/docs/Web/API/Document/startViewTransition)
+/// so the whole page cross-fades into the new color mode. In browsers that don't support View Transitions
+/// the color mode is switched immediately. The initial color mode is always applied without a transition.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_color_mode_with_options, UseColorModeOptions, UseColorModeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseColorModeReturn { mode, set_mode, .. } = use_color_mode_with_options(
+///     UseColorModeOptions::default().view_transition(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this will try to read the
@@ -166,8 +252,11 @@ where
         storage_enabled,
         cookie_name,
         cookie_enabled,
+        system_cookie_name,
         emit_auto,
         transition_enabled,
+        view_transition,
+        fallback_chain,
         listen_to_storage_changes,
         ssr_color_header_getter,
         _marker,
@@ -181,8 +270,13 @@ where
         ])
         .collect();
 
+    let (system_cookie, set_system_cookie) = get_cookie_signal(&system_cookie_name, cookie_enabled);
+
     let preferred_dark = use_preferred_dark_with_options(UsePreferredDarkOptions {
-        ssr_color_header_getter,
+        ssr_color_header_getter: Arc::new(move || {
+            ssr_color_header_getter()
+                .or_else(|| system_cookie.get_untracked().map(|mode| mode.to_string()))
+        }),
     });
 
     let system = Signal::derive(move || {
@@ -200,6 +294,11 @@ where
         }
     }
 
+    let initial_mode = initial_value.clone().into_signal().0.get_untracked();
+
+    #[cfg(not(feature = "ssr"))]
+    let storage_type = storage.clone();
+
     let (store, set_store) = get_store_signal(
         initial_value_from_url.clone().unwrap_or(initial_value),
         storage_signal,
@@ -211,6 +310,55 @@ where
 
     let (cookie, set_cookie) = get_cookie_signal(&cookie_name, cookie_enabled);
 
+    // The storage can't be read on the server. Otherwise the store holds the initial value if the storage is empty.
+    let has_stored_value = storage_signal.is_some()
+        || (storage_enabled && {
+            #[cfg(not(feature = "ssr"))]
+            {
+                storage_type
+                    .into_storage()
+                    .ok()
+                    .flatten()
+                    .and_then(|storage| storage.get_item(&storage_key).ok().flatten())
+                    .is_some()
+            }
+
+            #[cfg(feature = "ssr")]
+            false
+        });
+
+    let fallback_chain = fallback_chain.unwrap_or_else(|| {
+        if cookie_enabled {
+            vec![
+                ColorModeSource::Cookie,
+                ColorModeSource::Storage,
+                ColorModeSource::System,
+            ]
+        } else {
+            vec![ColorModeSource::Storage, ColorModeSource::System]
+        }
+    });
+
+    let resolved = fallback_chain.iter().find_map(|source| match source {
+        ColorModeSource::Storage => {
+            Some(store.get_untracked()).filter(|mode| has_stored_value && *mode != ColorMode::Auto)
+        }
+        ColorModeSource::Cookie => cookie
+            .get_untracked()
+            .filter(|mode| cookie_enabled && *mode != ColorMode::Auto),
+        ColorModeSource::System => Some(initial_mode.clone()),
+    });
+
+    if let Some(resolved) = resolved {
+        if store.get_untracked() != resolved {
+            set_store.set(resolved.clone());
+        }
+
+        if cookie_enabled && cookie.get_untracked().as_ref() != Some(&resolved) {
+            set_cookie.set(Some(resolved));
+        }
+    }
+
     if cookie_enabled {
         let _ = sync_signal_with_options(
             (cookie, set_cookie),
@@ -237,6 +385,17 @@ where
         }
     }
 
+    if cookie_enabled {
+        // remember the system preference for the server in case it doesn't receive the header
+        Effect::new(move |_| {
+            let system = system.get();
+
+            if system_cookie.get_untracked().as_ref() != Some(&system) {
+                set_system_cookie.set(Some(system));
+            }
+        });
+    }
+
     let state = Signal::derive(move || {
         let value = store.get();
         if value == ColorMode::Auto {
@@ -294,8 +453,23 @@ where
         }
     };
 
+    // the initial color mode is applied without a view transition
+    let is_initial = StoredValue::new(true);
+
     let default_on_changed = move |mode: ColorMode| {
-        update_html_attrs(target.clone(), attribute.clone(), mode);
+        let update = {
+            let target = target.clone();
+            let attribute = attribute.clone();
+            let update_html_attrs = update_html_attrs.clone();
+
+            move || update_html_attrs(target, attribute, mode)
+        };
+
+        if view_transition && !is_initial.try_get_value().unwrap_or(true) {
+            start_view_transition(update);
+        } else {
+            update();
+        }
     };
 
     let on_changed = move |mode: ColorMode| {
@@ -307,6 +481,7 @@ where
 
         move |_| {
             on_changed.clone()(state.get());
+            is_initial.set_value(false);
         }
     });
 
@@ -316,6 +491,8 @@ where
 
     let mode = Signal::derive(move || if emit_auto { store.get() } else { state.get() });
 
+    let is_auto = Signal::derive(move || store.get() == ColorMode::Auto);
+
     UseColorModeReturn {
         mode,
         set_mode: set_store,
@@ -323,7 +500,29 @@ where
         set_store,
         system,
         state,
+        is_auto,
+    }
+}
+
+/// Runs `update` inside `document.startViewTransition()` if supported. Otherwise `update` is called directly.
+fn start_view_transition(update: impl FnOnce() + 'static) {
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::js;
+        use wasm_bindgen::closure::Closure;
+
+        let document = document();
+
+        if let Some(start) = js!(document["startViewTransition"])
+            .ok()
+            .and_then(|start| start.dyn_into::<js_sys::Function>().ok())
+        {
+            let _ = start.call1(&document, &Closure::once_into_js(update));
+            return;
+        }
     }
+
+    update();
 }
 
 /// Color modes
@@ -336,6 +535,18 @@ pub enum ColorMode {
     Custom(String),
 }
 
+/// Sources of the color mode used by [`UseColorModeOptions::fallback_chain`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ColorModeSource {
+    /// The value from storage or [`UseColorModeOptions::storage_signal`]. Skipped if the storage is empty
+    /// and on the server because the storage can't be read there.
+    Storage,
+    /// The value of the cookie if `cookie_enabled` is `true`.
+    Cookie,
+    /// [`UseColorModeOptions::initial_value`] which defaults to `ColorMode::Auto`, i.e. the system preference.
+    System,
+}
+
 fn get_cookie_signal(
     cookie_name: &str,
     cookie_enabled: bool,
@@ -476,6 +687,13 @@ where
     /// Defaults to `false`.
     cookie_enabled: bool,
 
+    /// Name of the cookie that stores the system preference detected in the browser.
+    /// It is used on the server in auto mode if the `Sec-CH-Prefers-Color-Scheme` header is missing.
+    /// This only has an effect if `cookie_enabled` is `true`.
+    /// Defaults to `"leptos-use-color-scheme-system"`.
+    #[builder(into)]
+    system_cookie_name: String,
+
     /// Emit `auto` mode from state
     ///
     /// When set to `true`, preferred mode won't be translated into `light` or `dark`.
@@ -487,6 +705,20 @@ where
     /// If transitions on color mode change are enabled. Defaults to `false`.
     transition_enabled: bool,
 
+    /// If the color mode change should be wrapped in a
+    /// [View Transition](https://developer.mozilla.org/en-US/docs/Web/API/Document/startViewTransition).
+    /// Falls back to an immediate change if not supported by the browser.
+    /// Defaults to `false`.
+    view_transition: bool,
+
+    /// Order of the sources that are checked for an explicit color mode on initialization.
+    /// The first source that holds a mode other than `ColorMode::Auto` wins.
+    /// If no source holds an explicit mode, `initial_value` is used.
+    /// Defaults to `vec![ColorModeSource::Cookie, ColorModeSource::Storage, ColorModeSource::System]` if
+    /// `cookie_enabled` is `true` and to `vec![ColorModeSource::Storage, ColorModeSource::System]` otherwise.
+    #[builder(into)]
+    fallback_chain: Option<Vec<ColorModeSource>>,
+
     /// Listen to changes to this storage key from somewhere else.
     /// Defaults to true.
     listen_to_storage_changes: bool,
@@ -521,8 +753,11 @@ impl Default for UseColorModeOptions<&'static str, str> {
             storage_enabled: true,
             cookie_name: "leptos-use-color-scheme".into(),
             cookie_enabled: false,
+            system_cookie_name: "leptos-use-color-scheme-system".into(),
             emit_auto: false,
             transition_enabled: false,
+            view_transition: false,
+            fallback_chain: None,
             listen_to_storage_changes: true,
             ssr_color_header_getter: Arc::new(move || {
                 get_header!(
@@ -553,4 +788,7 @@ pub struct UseColorModeReturn {
 
     /// When [`UseColorModeOptions::emit_auto`] is `false` this is the same as `mode`. This will never report `ColorMode::Auto` but always on of the other modes.
     pub state: Signal<ColorMode>,
+
+    /// `true` as long as the system preference is followed, i.e. the user hasn't chosen an explicit mode.
+    pub is_auto: Signal<bool>,
 }
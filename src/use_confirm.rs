@@ -0,0 +1,189 @@
+use crate::utils::LocalBoxFuture;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::RefCell;
+use std::future::poll_fn;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+
+/// Promise-style confirmation flow that decouples the place that asks for a confirmation from the
+/// component that renders the confirmation dialog.
+///
+/// Calling `confirm` with a payload sets `is_revealed` to `true` and returns a future. This future
+/// resolves as soon as `accept` or `cancel` is called (usually from the dialog component) with
+/// the result [`UseConfirmResult::Confirmed`] or [`UseConfirmResult::Canceled`] respectively.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_confirm)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_confirm, UseConfirmResult, UseConfirmReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseConfirmReturn {
+///     is_revealed,
+///     payload,
+///     confirm,
+///     accept,
+///     cancel,
+/// } = use_confirm::<String>();
+///
+/// let delete = move |_| {
+///     let confirm = confirm.clone();
+///
+///     spawn_local(async move {
+///         if confirm("Delete this item?".to_string()).await == UseConfirmResult::Confirmed {
+///             log!("Deleted");
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=delete>"Delete"</button>
+///
+///     <Show when=move || is_revealed.get()>
+///         <div>
+///             <p>{payload}</p>
+///             <button on:click={
+///                 let accept = accept.clone();
+///                 move |_| accept()
+///             }>"Yes"</button>
+///             <button on:click={
+///                 let cancel = cancel.clone();
+///                 move |_| cancel()
+///             }>"No"</button>
+///         </div>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// Only one confirmation can be pending at a time. Calling `confirm` again while a confirmation is
+/// pending cancels the pending one. A pending confirmation is canceled as well when the component is
+/// cleaned up.
+///
+/// ## Server-Side Rendering
+///
+/// This works on the server as well, although there usually is nobody to confirm anything there.
+pub fn use_confirm<T>() -> UseConfirmReturn<
+    T,
+    impl Fn(T) -> ConfirmFuture + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let (is_revealed, set_revealed) = signal(false);
+    let (payload, set_payload) = signal(None::<T>);
+
+    let pending = StoredValue::new_local(None::<Rc<RefCell<ConfirmState>>>);
+
+    let resolve = move |result: UseConfirmResult| {
+        if let Some(state) = pending.try_update_value(Option::take).flatten() {
+            state.borrow_mut().resolve(result);
+        }
+    };
+
+    on_cleanup(move || resolve(UseConfirmResult::Canceled));
+
+    let confirm = move |value: T| -> ConfirmFuture {
+        resolve(UseConfirmResult::Canceled);
+
+        let state = Rc::new(RefCell::new(ConfirmState::default()));
+        pending.set_value(Some(Rc::clone(&state)));
+
+        set_payload.set(Some(value));
+        set_revealed.set(true);
+
+        Box::pin(poll_fn(move |cx| {
+            let mut state = state.borrow_mut();
+
+            match state.result {
+                Some(result) => Poll::Ready(result),
+                None => {
+                    state.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        }))
+    };
+
+    let close = move |result: UseConfirmResult| {
+        set_revealed.set(false);
+        resolve(result);
+    };
+
+    let accept = move || close(UseConfirmResult::Confirmed);
+
+    let cancel = move || close(UseConfirmResult::Canceled);
+
+    UseConfirmReturn {
+        is_revealed: is_revealed.into(),
+        payload: payload.into(),
+        confirm,
+        accept,
+        cancel,
+    }
+}
+
+/// Result of a confirmation requested by [`use_confirm`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum UseConfirmResult {
+    /// `accept` has been called
+    Confirmed,
+    /// `cancel` has been called, the confirmation was replaced by a new one or the component has
+    /// been cleaned up
+    Canceled,
+}
+
+#[derive(Default)]
+struct ConfirmState {
+    result: Option<UseConfirmResult>,
+    waker: Option<Waker>,
+}
+
+impl ConfirmState {
+    fn resolve(&mut self, result: UseConfirmResult) {
+        self.result = Some(result);
+
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Future returned by `confirm` of [`use_confirm`]. Resolves as soon as the confirmation is
+/// either accepted or canceled.
+pub type ConfirmFuture = LocalBoxFuture<UseConfirmResult>;
+
+/// Return type of [`use_confirm`].
+pub struct UseConfirmReturn<T, ConfirmFn, AcceptFn, CancelFn>
+where
+    T: Clone + Send + Sync + 'static,
+    ConfirmFn: Fn(T) -> ConfirmFuture + Clone + Send + Sync,
+    AcceptFn: Fn() + Clone + Send + Sync,
+    CancelFn: Fn() + Clone + Send + Sync,
+{
+    /// `true` while a confirmation is pending. Use this to show the dialog.
+    pub is_revealed: Signal<bool>,
+
+    /// The payload of the last call to `confirm`
+    pub payload: Signal<Option<T>>,
+
+    /// Request a confirmation with the given payload. Returns a future that resolves to the result.
+    pub confirm: ConfirmFn,
+
+    /// Accept the pending confirmation
+    pub accept: AcceptFn,
+
+    /// Cancel the pending confirmation
+    pub cancel: CancelFn,
+}
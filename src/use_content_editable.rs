@@ -0,0 +1,436 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::{IntoElementMaybeSignal, MaybeRwSignal};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Two-way binding of the content of a
+/// [`contenteditable`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/contenteditable) element.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_content_editable)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_content_editable, UseContentEditableReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseContentEditableReturn { content, set_content } = use_content_editable(el);
+///
+/// view! {
+///     <div node_ref=el contenteditable="true"></div>
+///     <p>{move || content.get().len()} " characters"</p>
+///     <button on:click=move |_| set_content.set(String::new())>"Clear"</button>
+/// }
+/// # }
+/// ```
+///
+/// You can also bind your own signal. Setting it updates the element. If the element has the focus, the caret stays
+/// at the same position.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_content_editable_with_options, UseContentEditableOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+/// let text = RwSignal::new("Edit me".to_string());
+///
+/// use_content_editable_with_options(el, UseContentEditableOptions::default().content(text));
+///
+/// view! { <div node_ref=el contenteditable="true"></div> }
+/// # }
+/// ```
+///
+/// ## HTML and Text
+///
+/// By default the text of the element is bound and pasted content is inserted as plain text.
+/// With the option `mode` set to [`ContentEditableMode::Html`] the inner HTML of the element is bound instead
+/// and pasted content keeps its formatting.
+///
+/// ## Debouncing
+///
+/// With the option `debounce` the signal is only updated after the user has stopped typing for the given number of
+/// milliseconds.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_content_editable_with_options, ContentEditableMode, UseContentEditableOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let el = NodeRef::<Div>::new();
+/// let editor = use_content_editable_with_options(
+///     el,
+///     UseContentEditableOptions::default()
+///         .mode(ContentEditableMode::Html)
+///         .debounce(300.0),
+/// );
+/// #
+/// # view! { <div node_ref=el contenteditable="true"></div> }
+/// # }
+/// ```
+///
+/// ## Sanitizing pasted content
+///
+/// `sanitize_paste` is called with the pasted text or HTML (depending on `mode`) and returns what will be inserted.
+/// Use it to strip unwanted markup.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_content_editable_with_options, ContentEditableMode, UseContentEditableOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let el = NodeRef::<Div>::new();
+/// let editor = use_content_editable_with_options(
+///     el,
+///     UseContentEditableOptions::default()
+///         .mode(ContentEditableMode::Html)
+///         .sanitize_paste(|html| html.replace("<script", "&lt;script")),
+/// );
+/// #
+/// # view! { <div node_ref=el contenteditable="true"></div> }
+/// # }
+/// ```
+///
+/// > Make sure to sanitize HTML properly with a library built for that before you render it anywhere else.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the signal is not bound to any element and keeps its value.
+pub fn use_content_editable<El, M>(target: El) -> UseContentEditableReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_content_editable_with_options(target, UseContentEditableOptions::default())
+}
+
+/// Version of [`use_content_editable`] that takes a `UseContentEditableOptions`. See [`use_content_editable`] for how to use.
+pub fn use_content_editable_with_options<El, M>(
+    target: El,
+    options: UseContentEditableOptions,
+) -> UseContentEditableReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseContentEditableOptions {
+        content,
+        mode,
+        debounce,
+        sanitize_paste,
+    } = options;
+
+    let (content, set_content) = content.into_signal();
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{use_debounce_fn, use_event_listener};
+        use leptos::ev::{input, paste};
+        use wasm_bindgen::JsCast;
+
+        let target = target.into_element_maybe_signal();
+        let element = Signal::derive_local(move || {
+            target
+                .get()
+                .map(|el| el.unchecked_into::<web_sys::HtmlElement>())
+        });
+
+        let read_element = move || element.get_untracked().map(|el| mode.read(&el));
+
+        let sync_to_signal = move || {
+            if let Some(value) = read_element() {
+                if content.with_untracked(|content| *content != value) {
+                    set_content.set(value);
+                }
+            }
+        };
+
+        let debounced_sync = use_debounce_fn(sync_to_signal, debounce);
+
+        let on_input = move || {
+            if debounce > 0.0 {
+                debounced_sync();
+            } else {
+                sync_to_signal();
+            }
+        };
+
+        let _ = use_event_listener(element, input, {
+            let on_input = on_input.clone();
+            move |_| on_input()
+        });
+
+        let _ = use_event_listener(element, paste, move |event| {
+            // pasting formatted content is fine unless it has to be sanitized
+            if mode == ContentEditableMode::Html && sanitize_paste.is_none() {
+                return;
+            }
+
+            let data = event
+                .unchecked_ref::<web_sys::ClipboardEvent>()
+                .clipboard_data();
+
+            let (Some(el), Some(data)) = (element.get_untracked(), data) else {
+                return;
+            };
+
+            let mut pasted = match mode {
+                ContentEditableMode::Text => data.get_data("text/plain").unwrap_or_default(),
+                ContentEditableMode::Html => data
+                    .get_data("text/html")
+                    .ok()
+                    .filter(|html| !html.is_empty())
+                    .unwrap_or_else(|| {
+                        escape_html(&data.get_data("text/plain").unwrap_or_default())
+                    }),
+            };
+
+            event.prevent_default();
+
+            if let Some(sanitize_paste) = &sanitize_paste {
+                pasted = sanitize_paste(pasted);
+            }
+
+            insert_at_caret(&el, &pasted, mode);
+            on_input();
+        });
+
+        Effect::new(move || {
+            let Some(el) = element.get() else {
+                return;
+            };
+
+            content.with(|content| {
+                if mode.read(&el) == *content {
+                    return;
+                }
+
+                let caret = caret_offset(&el);
+
+                mode.write(&el, content);
+
+                if let Some(caret) = caret {
+                    set_caret_offset(&el, caret);
+                }
+            });
+        });
+    }
+
+    UseContentEditableReturn {
+        content,
+        set_content,
+    }
+}
+
+/// What is bound by [`use_content_editable`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ContentEditableMode {
+    /// The text of the element (`innerText`). Pasted content is inserted as plain text.
+    #[default]
+    Text,
+
+    /// The HTML of the element (`innerHTML`). Pasted content keeps its formatting.
+    Html,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl ContentEditableMode {
+    fn read(self, el: &web_sys::HtmlElement) -> String {
+        match self {
+            Self::Text => el.inner_text(),
+            Self::Html => el.inner_html(),
+        }
+    }
+
+    fn write(self, el: &web_sys::HtmlElement, value: &str) {
+        match self {
+            Self::Text => el.set_inner_text(value),
+            Self::Html => el.set_inner_html(value),
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\n', "<br>")
+}
+
+/// Replaces the selection inside of `el` by `content`.
+#[cfg(not(feature = "ssr"))]
+fn insert_at_caret(el: &web_sys::HtmlElement, content: &str, mode: ContentEditableMode) {
+    let Some(selection) = window().get_selection().ok().flatten() else {
+        return;
+    };
+
+    if selection.range_count() == 0 {
+        return;
+    }
+
+    let Ok(range) = selection.get_range_at(0) else {
+        return;
+    };
+
+    if !range
+        .common_ancestor_container()
+        .is_ok_and(|ancestor| el.contains(Some(&ancestor)))
+    {
+        return;
+    }
+
+    let node: web_sys::Node = match mode {
+        ContentEditableMode::Text => document().create_text_node(content).into(),
+        ContentEditableMode::Html => match range.create_contextual_fragment(content) {
+            Ok(fragment) => fragment.into(),
+            Err(_) => return,
+        },
+    };
+
+    let _ = range.delete_contents();
+    let _ = range.insert_node(&node);
+    range.collapse_with_to_start(false);
+
+    let _ = selection.remove_all_ranges();
+    let _ = selection.add_range(&range);
+}
+
+/// Position of the caret in UTF-16 code units from the start of the text of `el`.
+/// `None` if the selection is not inside of `el`.
+#[cfg(not(feature = "ssr"))]
+fn caret_offset(el: &web_sys::HtmlElement) -> Option<u32> {
+    let selection = window().get_selection().ok().flatten()?;
+
+    if selection.range_count() == 0 {
+        return None;
+    }
+
+    let range = selection.get_range_at(0).ok()?;
+    let container = range.end_container().ok()?;
+
+    if !el.contains(Some(&container)) {
+        return None;
+    }
+
+    let before_caret = range.clone_range();
+    before_caret.select_node_contents(el).ok()?;
+    before_caret
+        .set_end(&container, range.end_offset().ok()?)
+        .ok()?;
+
+    Some(before_caret.to_string().length())
+}
+
+/// Places the caret `offset` UTF-16 code units from the start of the text of `el`.
+#[cfg(not(feature = "ssr"))]
+fn set_caret_offset(el: &web_sys::HtmlElement, offset: u32) {
+    use wasm_bindgen::JsCast;
+
+    // NodeFilter.SHOW_TEXT
+    const SHOW_TEXT: u32 = 0x4;
+
+    let (Some(selection), Ok(range)) = (
+        window().get_selection().ok().flatten(),
+        document().create_range(),
+    ) else {
+        return;
+    };
+
+    let mut remaining = offset;
+    let mut placed = false;
+
+    if let Ok(walker) = document().create_tree_walker_with_what_to_show(el, SHOW_TEXT) {
+        while let Ok(Some(node)) = walker.next_node() {
+            let length = node.unchecked_ref::<web_sys::CharacterData>().length();
+
+            if remaining <= length {
+                placed = range.set_start(&node, remaining).is_ok();
+                break;
+            }
+
+            remaining -= length;
+        }
+    }
+
+    if placed {
+        range.collapse_with_to_start(true);
+    } else {
+        // the content has become shorter than the old caret position
+        if range.select_node_contents(el).is_err() {
+            return;
+        }
+        range.collapse_with_to_start(false);
+    }
+
+    let _ = selection.remove_all_ranges();
+    let _ = selection.add_range(&range);
+}
+
+/// Options for [`use_content_editable_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseContentEditableOptions {
+    /// The content of the element. Can be a signal that is updated when the user edits the element.
+    /// Defaults to an empty string.
+    #[builder(into)]
+    content: MaybeRwSignal<String>,
+
+    /// Whether the text or the HTML of the element is bound. Defaults to [`ContentEditableMode::Text`].
+    mode: ContentEditableMode,
+
+    /// Milliseconds to wait after the last input before the signal is updated. Defaults to `0.0` which updates it
+    /// immediately.
+    debounce: f64,
+
+    /// Called with the pasted text or HTML. Returns the content that is inserted instead. Defaults to `None`.
+    #[builder(skip)]
+    sanitize_paste: Option<Arc<dyn Fn(String) -> String + Send + Sync>>,
+}
+
+impl Default for UseContentEditableOptions {
+    fn default() -> Self {
+        Self {
+            content: MaybeRwSignal::default(),
+            mode: ContentEditableMode::default(),
+            debounce: 0.0,
+            sanitize_paste: None,
+        }
+    }
+}
+
+impl UseContentEditableOptions {
+    /// Called with the pasted text or HTML. Returns the content that is inserted instead.
+    pub fn sanitize_paste(
+        self,
+        sanitize_paste: impl Fn(String) -> String + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            sanitize_paste: Some(Arc::new(sanitize_paste)),
+            ..self
+        }
+    }
+}
+
+/// Return type of [`use_content_editable`].
+pub struct UseContentEditableReturn {
+    /// The content of the element
+    pub content: Signal<String>,
+
+    /// Sets the content of the element
+    pub set_content: WriteSignal<String>,
+}
@@ -759,7 +759,7 @@ fn cookie_jar_to_string(jar: &CookieJar, name: &str) -> String {
     }
 }
 
-fn build_cookie_from_options(
+pub(crate) fn build_cookie_from_options(
     name: &str,
     max_age: Option<i64>,
     expires: Option<i64>,
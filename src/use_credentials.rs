@@ -0,0 +1,461 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive [Credential Management API](https://developer.mozilla.org/en-US/docs/Web/API/Credential_Management_API)
+/// for password and federated credentials.
+///
+/// Lets the browser remember the credentials of your login form and hand them back later, even without
+/// user interaction. For passkeys see [`fn@crate::use_webauthn`].
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_credentials)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_credentials, UseCredentialsReturn, UserCredential};
+/// #
+/// # async fn sign_in(id: &str, password: &str) -> bool { true }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseCredentialsReturn { credential, client, .. } = use_credentials();
+///
+/// // sign in without user interaction if the user has done so before
+/// leptos::task::spawn_local(async move {
+///     if let Ok(Some(UserCredential::Password { id, password, .. })) = client.silent_sign_in().await {
+///         sign_in(&id, &password).await;
+///     }
+/// });
+///
+/// let (username, set_username) = signal(String::new());
+/// let (password, set_password) = signal(String::new());
+///
+/// let submit = move |_| {
+///     leptos::task::spawn_local(async move {
+///         let (id, password) = (username.get_untracked(), password.get_untracked());
+///
+///         if sign_in(&id, &password).await {
+///             // the browser asks the user if the credential should be saved
+///             let _ = client.store(UserCredential::password(id, password)).await;
+///         }
+///     });
+/// };
+///
+/// let sign_out = move |_| {
+///     leptos::task::spawn_local(async move {
+///         // don't sign in silently until the user has picked a credential again
+///         let _ = client.prevent_silent_access().await;
+///     });
+/// };
+///
+/// view! {
+///     <input type="text" autocomplete="username" on:input=move |e| set_username.set(event_target_value(&e))/>
+///     <input type="password" autocomplete="current-password" on:input=move |e| set_password.set(event_target_value(&e))/>
+///     <button on:click=submit>"Sign in"</button>
+///     <button on:click=sign_out>"Sign out"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## Account Chooser
+///
+/// With `get` you can show the browser's account chooser, optionally including federated accounts
+/// of the given identity providers.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_credentials, CredentialMediation, GetCredentialOptions, UseCredentialsReturn, UserCredential};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseCredentialsReturn { client, .. } = use_credentials();
+///
+/// let choose = move |_| {
+///     leptos::task::spawn_local(async move {
+///         let options = GetCredentialOptions::default()
+///             .providers(vec!["https://accounts.google.com".to_string()])
+///             .mediation(CredentialMediation::Required);
+///
+///         match client.get(options).await {
+///             Ok(Some(UserCredential::Federated { provider, .. })) => { /* sign in with the provider */ }
+///             Ok(Some(UserCredential::Password { .. })) => { /* sign in with the password */ }
+///             _ => {}
+///         }
+///     });
+/// };
+///
+/// view! { <button on:click=choose>"Choose account"</button> }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_pending` are `false`, `credential` is `None` and the methods of
+/// [`CredentialsClient`] return `Err(UseCredentialsError::Server)`.
+pub fn use_credentials() -> UseCredentialsReturn {
+    let is_supported = use_supported(|| {
+        js!("PasswordCredential" in &window()) || js!("FederatedCredential" in &window())
+    });
+
+    let (credential, set_credential) = signal(None::<UserCredential>);
+    let (is_pending, set_is_pending) = signal(false);
+
+    UseCredentialsReturn {
+        is_supported,
+        credential: credential.into(),
+        is_pending: is_pending.into(),
+        client: CredentialsClient {
+            is_supported,
+            set_credential,
+            set_is_pending,
+        },
+    }
+}
+
+/// Gets and stores credentials. Returned by [`use_credentials`].
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct CredentialsClient {
+    is_supported: Signal<bool>,
+    set_credential: WriteSignal<Option<UserCredential>>,
+    set_is_pending: WriteSignal<bool>,
+}
+
+impl CredentialsClient {
+    /// Gets a stored credential. Returns `None` if there is none or the user dismissed the account chooser.
+    pub async fn get(
+        &self,
+        options: GetCredentialOptions,
+    ) -> Result<Option<UserCredential>, UseCredentialsError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseCredentialsError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            if !self.is_supported.get_untracked() {
+                return Err(UseCredentialsError::NotSupported);
+            }
+
+            let request_options = options.to_js();
+
+            let promise = window()
+                .navigator()
+                .credentials()
+                .get_with_options(request_options.unchecked_ref())
+                .map_err(UseCredentialsError::Failed)?;
+
+            self.set_is_pending.try_set(true);
+            let result = crate::js_fut!(promise).await;
+            self.set_is_pending.try_set(false);
+
+            let credential = parse_credential(&result.map_err(UseCredentialsError::Failed)?);
+
+            if credential.is_some() {
+                self.set_credential.try_set(credential.clone());
+            }
+
+            Ok(credential)
+        }
+    }
+
+    /// Gets the stored password credential without showing any UI. Only succeeds if the user has
+    /// exactly one stored credential for this site and silent access hasn't been prevented.
+    pub async fn silent_sign_in(&self) -> Result<Option<UserCredential>, UseCredentialsError> {
+        self.get(GetCredentialOptions::default().mediation(CredentialMediation::Silent))
+            .await
+    }
+
+    /// Asks the browser to save the credential, usually after a successful sign in.
+    pub async fn store(&self, credential: UserCredential) -> Result<(), UseCredentialsError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseCredentialsError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            if !self.is_supported.get_untracked() {
+                return Err(UseCredentialsError::NotSupported);
+            }
+
+            let promise = window()
+                .navigator()
+                .credentials()
+                .store(credential.to_js()?.unchecked_ref())
+                .map_err(UseCredentialsError::Failed)?;
+
+            crate::js_fut!(promise)
+                .await
+                .map_err(UseCredentialsError::Failed)?;
+
+            self.set_credential.try_set(Some(credential));
+
+            Ok(())
+        }
+    }
+
+    /// Prevents silent sign in until the user picks a credential again, usually when signing out.
+    pub async fn prevent_silent_access(&self) -> Result<(), UseCredentialsError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseCredentialsError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !self.is_supported.get_untracked() {
+                return Err(UseCredentialsError::NotSupported);
+            }
+
+            let promise = window()
+                .navigator()
+                .credentials()
+                .prevent_silent_access()
+                .map_err(UseCredentialsError::Failed)?;
+
+            crate::js_fut!(promise)
+                .await
+                .map_err(UseCredentialsError::Failed)?;
+
+            self.set_credential.try_set(None);
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn parse_credential(credential: &JsValue) -> Option<UserCredential> {
+    if !credential.is_object() {
+        return None;
+    }
+
+    let string = |key: &str| {
+        js_sys::Reflect::get(credential, &key.into())
+            .ok()
+            .and_then(|value| value.as_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    match string("type")?.as_str() {
+        "password" => Some(UserCredential::Password {
+            id: string("id")?,
+            password: string("password").unwrap_or_default(),
+            name: string("name"),
+            icon_url: string("iconURL"),
+        }),
+        "federated" => Some(UserCredential::Federated {
+            id: string("id")?,
+            provider: string("provider").unwrap_or_default(),
+            name: string("name"),
+            icon_url: string("iconURL"),
+        }),
+        _ => None,
+    }
+}
+
+/// A password or federated credential.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UserCredential {
+    /// A username and password
+    Password {
+        /// The username
+        id: String,
+        password: String,
+        /// Name shown in the account chooser
+        name: Option<String>,
+        /// Avatar shown in the account chooser
+        icon_url: Option<String>,
+    },
+
+    /// An account of an identity provider
+    Federated {
+        /// The account identifier, e.g. the email address
+        id: String,
+        /// Origin of the identity provider like `"https://accounts.google.com"`
+        provider: String,
+        /// Name shown in the account chooser
+        name: Option<String>,
+        /// Avatar shown in the account chooser
+        icon_url: Option<String>,
+    },
+}
+
+impl UserCredential {
+    /// Creates a password credential without name and icon.
+    pub fn password(id: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::Password {
+            id: id.into(),
+            password: password.into(),
+            name: None,
+            icon_url: None,
+        }
+    }
+
+    /// Creates a federated credential without name and icon.
+    pub fn federated(id: impl Into<String>, provider: impl Into<String>) -> Self {
+        Self::Federated {
+            id: id.into(),
+            provider: provider.into(),
+            name: None,
+            icon_url: None,
+        }
+    }
+
+    /// The username or account identifier
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Password { id, .. } | Self::Federated { id, .. } => id,
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn to_js(&self) -> Result<JsValue, UseCredentialsError> {
+        use wasm_bindgen::JsCast;
+
+        let data = js_sys::Object::new();
+
+        let (constructor, name, icon_url) = match self {
+            Self::Password {
+                id,
+                password,
+                name,
+                icon_url,
+            } => {
+                js!(data["id"] = id);
+                js!(data["password"] = password);
+                ("PasswordCredential", name, icon_url)
+            }
+            Self::Federated {
+                id,
+                provider,
+                name,
+                icon_url,
+            } => {
+                js!(data["id"] = id);
+                js!(data["provider"] = provider);
+                ("FederatedCredential", name, icon_url)
+            }
+        };
+
+        if let Some(name) = name {
+            js!(data["name"] = name);
+        }
+        if let Some(icon_url) = icon_url {
+            js!(data["iconURL"] = icon_url);
+        }
+
+        let constructor = js_sys::Reflect::get(&window(), &constructor.into())
+            .ok()
+            .and_then(|constructor| constructor.dyn_into::<js_sys::Function>().ok())
+            .ok_or(UseCredentialsError::NotSupported)?;
+
+        js_sys::Reflect::construct(&constructor, &js_sys::Array::of1(&data))
+            .map_err(UseCredentialsError::Failed)
+    }
+}
+
+/// How much the user is involved when getting a credential.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CredentialMediation {
+    /// Never show any UI. Returns `None` if user interaction would be required.
+    Silent,
+    /// Show the account chooser only if required. This is the default.
+    #[default]
+    Optional,
+    /// Always show the account chooser, e.g. after the user signed out.
+    Required,
+}
+
+impl CredentialMediation {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Silent => "silent",
+            Self::Optional => "optional",
+            Self::Required => "required",
+        }
+    }
+}
+
+/// Options for [`CredentialsClient::get`].
+#[derive(DefaultBuilder, Clone, Debug)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct GetCredentialOptions {
+    /// Include password credentials. Defaults to `true`.
+    password: bool,
+
+    /// Include federated credentials of these identity providers. Defaults to none.
+    providers: Vec<String>,
+
+    /// How much the user is involved. Defaults to [`CredentialMediation::Optional`].
+    mediation: CredentialMediation,
+}
+
+impl Default for GetCredentialOptions {
+    fn default() -> Self {
+        Self {
+            password: true,
+            providers: vec![],
+            mediation: CredentialMediation::default(),
+        }
+    }
+}
+
+impl GetCredentialOptions {
+    #[cfg(not(feature = "ssr"))]
+    fn to_js(&self) -> js_sys::Object {
+        let options = js_sys::Object::new();
+
+        js!(options["password"] = self.password);
+        js!(options["mediation"] = self.mediation.as_str());
+
+        if !self.providers.is_empty() {
+            let federated = js_sys::Object::new();
+            js!(federated["providers"] = self
+                .providers
+                .iter()
+                .map(|provider| JsValue::from_str(provider))
+                .collect::<js_sys::Array>());
+            js!(options["federated"] = federated);
+        }
+
+        options
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum UseCredentialsError {
+    #[error("the Credential Management API is not available on the server")]
+    Server,
+
+    #[error("password and federated credentials are not supported by this browser")]
+    NotSupported,
+
+    #[error("credential operation failed")]
+    Failed(JsValue),
+}
+
+/// Return type of [`use_credentials`].
+pub struct UseCredentialsReturn {
+    /// `true` if the browser supports password or federated credentials
+    pub is_supported: Signal<bool>,
+
+    /// The last credential that has been retrieved or stored
+    pub credential: Signal<Option<UserCredential>>,
+
+    /// `true` while waiting for the browser to return a credential
+    pub is_pending: Signal<bool>,
+
+    /// Gets and stores credentials
+    pub client: CredentialsClient,
+}
@@ -2,8 +2,8 @@
 
 use crate::core::IntoElementMaybeSignal;
 use crate::{
-    use_mutation_observer_with_options, watch_with_options, UseMutationObserverOptions,
-    WatchOptions,
+    use_event_listener, use_interval_fn, use_mutation_observer_with_options, watch_with_options,
+    UseMutationObserverOptions, WatchOptions,
 };
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
@@ -74,6 +74,39 @@ use wasm_bindgen::JsCast;
 /// # }
 /// ```
 ///
+/// ## External Changes
+///
+/// The option `observe` only detects changes of the `style` attribute of the target. If the variable is
+/// changed by a stylesheet (e.g. inside of a media query or by toggling a class) the signal can be kept
+/// in sync in two ways:
+///
+/// * `detect_transitions`: Adds a tiny discrete transition for the variable to the inline `transition`
+///   of the target and updates the signal on every `transitionend` event. This is instant and cheap but
+///   requires a browser that supports `transition-behavior: allow-discrete`. The transitions of the
+///   target that are active at that time are kept, but since inline styles take precedence, later
+///   changes of the `transition` by stylesheets don't apply to the target until it is cleaned up.
+/// * `poll`: Reads the computed value of the variable every given number of milliseconds.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_css_var_with_options, UseCssVarOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (color, set_color) = use_css_var_with_options(
+///     "--color",
+///     UseCssVarOptions::default()
+///         .detect_transitions(true)
+///         .poll(Some(1000)),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Values that have been read from the element are not written back as inline styles. This way
+/// stylesheet driven values keep working after they have been read.
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this simply returns `signal(options.initial_value)`.
@@ -95,6 +128,8 @@ where
         target,
         initial_value,
         observe,
+        detect_transitions,
+        poll,
         ..
     } = options;
 
@@ -102,23 +137,36 @@ where
 
     #[cfg(not(feature = "ssr"))]
     {
+        use crate::sendwrap_fn;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
         let el_signal = target.into_element_maybe_signal();
         let prop = prop.into();
 
+        // the last value read from or written to the element. Values read from the element aren't
+        // written back to the inline style.
+        let dom_value = Rc::new(RefCell::new(None::<String>));
+
         let update_css_var = {
             let el_signal = el_signal.clone();
+            let dom_value = Rc::clone(&dom_value);
 
             move || {
                 if let Some(el) = el_signal.get_untracked() {
-                    if let Ok(Some(style)) = window().get_computed_style(&el) {
-                        if let Ok(value) = style.get_property_value(&prop.read_untracked()) {
-                            set_variable.update(|var| *var = value.trim().to_string());
-                            return;
-                        }
-                    }
+                    let value = window()
+                        .get_computed_style(&el)
+                        .ok()
+                        .flatten()
+                        .and_then(|style| style.get_property_value(&prop.read_untracked()).ok())
+                        .map(|value| value.trim().to_string())
+                        .unwrap_or_else(|| initial_value.clone());
 
-                    let initial_value = initial_value.clone();
-                    set_variable.update(|var| *var = initial_value);
+                    dom_value.replace(Some(value.clone()));
+
+                    if variable.with_untracked(|var| var != &value) {
+                        set_variable.set(value);
+                    }
                 }
             }
         };
@@ -134,6 +182,89 @@ where
             );
         }
 
+        if detect_transitions {
+            // the element with the added transition and its original inline `transition`
+            let original_transition = Rc::new(RefCell::new(None::<(web_sys::HtmlElement, String)>));
+
+            let restore_transition = {
+                let original_transition = Rc::clone(&original_transition);
+
+                move || {
+                    if let Some((el, original)) = original_transition.take() {
+                        let style = el.style();
+
+                        let _ = if original.is_empty() {
+                            style.remove_property("transition").map(|_| ())
+                        } else {
+                            style.set_property("transition", &original)
+                        };
+                    }
+                }
+            };
+
+            let _ = watch_with_options(
+                {
+                    let el_signal = el_signal.clone();
+                    move || (el_signal.get(), prop.get())
+                },
+                {
+                    let restore_transition = restore_transition.clone();
+
+                    move |(el, prop), _, _| {
+                        restore_transition();
+
+                        if let Some(el) = el {
+                            let el = el.clone().unchecked_into::<web_sys::HtmlElement>();
+                            let style = el.style();
+                            let original =
+                                style.get_property_value("transition").unwrap_or_default();
+
+                            // the inline value replaces the transitions from stylesheets so they are kept
+                            let existing = window()
+                                .get_computed_style(&el)
+                                .ok()
+                                .flatten()
+                                .and_then(|style| style.get_property_value("transition").ok())
+                                .filter(|value| !value.is_empty())
+                                .unwrap_or_else(|| original.clone());
+
+                            let transition = format!("{prop} 1ms step-start allow-discrete");
+                            let value = if existing.is_empty() {
+                                transition
+                            } else {
+                                format!("{existing}, {transition}")
+                            };
+
+                            let _ = style.set_property("transition", &value);
+
+                            original_transition.replace(Some((el, original)));
+                        }
+                    }
+                },
+                WatchOptions::default().immediate(true),
+            );
+
+            on_cleanup(sendwrap_fn!(move || restore_transition()));
+
+            let update_css_var = update_css_var.clone();
+
+            let _ = use_event_listener(
+                el_signal.clone(),
+                leptos::ev::Custom::<web_sys::TransitionEvent>::new("transitionend"),
+                move |event| {
+                    if event.property_name() == prop.read_untracked().as_str() {
+                        update_css_var();
+                    }
+                },
+            );
+        }
+
+        if let Some(interval) = poll {
+            let update_css_var = update_css_var.clone();
+
+            let _ = use_interval_fn(update_css_var, interval);
+        }
+
         // To get around style attributes on node_refs that are not applied after the first render
         set_timeout(update_css_var.clone(), Duration::ZERO);
 
@@ -150,10 +281,16 @@ where
         Effect::watch(
             move || variable.get(),
             move |val, _, _| {
+                if dom_value.borrow().as_ref() == Some(val) {
+                    return;
+                }
+
                 if let Some(el) = el_signal.get() {
                     let el = el.unchecked_into::<web_sys::HtmlElement>();
                     let style = el.style();
                     let _ = style.set_property(&prop.get_untracked(), val);
+
+                    dom_value.replace(Some(val.clone()));
                 }
             },
             false,
@@ -181,6 +318,14 @@ where
     /// If `true` use a `MutationObserver` to monitor variable changes. Defaults to `false`.
     observe: bool,
 
+    /// If `true` detect changes of the variable from stylesheets with a transition on the target.
+    /// This sets the inline `transition` of the target to its computed `transition` with the one for
+    /// the variable appended. The original inline value is restored on cleanup. Defaults to `false`.
+    detect_transitions: bool,
+
+    /// Interval in milliseconds to read the variable from the target. Defaults to `None` (no polling).
+    poll: Option<u64>,
+
     #[builder(skip)]
     _marker: PhantomData<M>,
 }
@@ -195,6 +340,8 @@ where
             target: None,
             initial_value: "".into(),
             observe: false,
+            detect_transitions: false,
+            poll: None,
             _marker: PhantomData,
         }
     }
@@ -210,6 +357,8 @@ where
             target: document().document_element().expect("No document element"),
             initial_value: "".into(),
             observe: false,
+            detect_transitions: false,
+            poll: None,
             _marker: PhantomData,
         }
     }
@@ -0,0 +1,50 @@
+use cfg_if::cfg_if;
+use leptos::prelude::*;
+
+/// Reactive [`navigator.deviceMemory`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/deviceMemory).
+///
+/// The approximate amount of device memory in gigabytes. Browsers round the value down to the
+/// nearest power of two and cap it, e.g. to `8.0` in Chrome, to prevent fingerprinting.
+/// `None` if the API is not supported which is the case in Firefox and Safari at the time of writing.
+///
+/// See [`fn@crate::use_device_tier`] for a heuristic that combines this with [`fn@crate::use_hardware_concurrency`].
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_device_memory)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_device_memory;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let device_memory = use_device_memory();
+///
+/// let load_hd_textures = move || device_memory.get().is_some_and(|memory| memory >= 4.0);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this function returns a Signal that is always `None`.
+pub fn use_device_memory() -> Signal<Option<f64>> {
+    cfg_if! { if #[cfg(feature = "ssr")] {
+        Signal::derive(|| None)
+    } else {
+        use crate::{js, use_window};
+
+        // not using `Navigator::device_memory` as it's behind `web_sys_unstable_apis`
+        let device_memory = use_window().navigator().and_then(|navigator| {
+            js!(navigator["deviceMemory"])
+                .ok()
+                .and_then(|device_memory| device_memory.as_f64())
+        });
+
+        Signal::derive(move || device_memory)
+    }}
+}
@@ -0,0 +1,133 @@
+use crate::{use_device_memory, use_hardware_concurrency};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Heuristic that classifies the user's device as low, medium or high end.
+///
+/// It's based on [`fn@crate::use_device_memory`] and [`fn@crate::use_hardware_concurrency`].
+/// Use it to disable expensive effects like blurs, particles or high resolution textures on low end devices.
+///
+/// A device is `Low` if any of the known values is at or below the low threshold and `High` if
+/// all of the known values are at or above the high threshold. Otherwise, or if none of the values
+/// is known, it's `Medium`.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_device_tier)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_device_tier, DeviceTier};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let device_tier = use_device_tier();
+///
+/// view! {
+///     <div class:blurred={move || device_tier.get() > DeviceTier::Low}>
+///         "Content"
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// The thresholds can be customized.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_device_tier_with_options, DeviceTier, UseDeviceTierOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let device_tier = use_device_tier_with_options(
+///     UseDeviceTierOptions::default()
+///         .low_memory(4.0)
+///         .low_concurrency(4),
+/// );
+///
+/// let show_particles = move || device_tier.get() == DeviceTier::High;
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this function returns a Signal that is always `DeviceTier::Medium` as
+/// neither value is known there.
+pub fn use_device_tier() -> Signal<DeviceTier> {
+    use_device_tier_with_options(UseDeviceTierOptions::default())
+}
+
+/// Version of [`fn@crate::use_device_tier`] that takes a `UseDeviceTierOptions`. See [`fn@crate::use_device_tier`] for how to use.
+pub fn use_device_tier_with_options(options: UseDeviceTierOptions) -> Signal<DeviceTier> {
+    let UseDeviceTierOptions {
+        low_memory,
+        high_memory,
+        low_concurrency,
+        high_concurrency,
+    } = options;
+
+    let device_memory = use_device_memory();
+    let hardware_concurrency = use_hardware_concurrency();
+
+    Signal::derive(move || {
+        let device_memory = device_memory.get();
+        let hardware_concurrency = hardware_concurrency.get();
+
+        if device_memory.is_none() && hardware_concurrency.is_none() {
+            return DeviceTier::Medium;
+        }
+
+        if device_memory.is_some_and(|memory| memory <= low_memory)
+            || hardware_concurrency.is_some_and(|concurrency| concurrency <= low_concurrency)
+        {
+            DeviceTier::Low
+        } else if device_memory.is_none_or(|memory| memory >= high_memory)
+            && hardware_concurrency.is_none_or(|concurrency| concurrency >= high_concurrency)
+        {
+            DeviceTier::High
+        } else {
+            DeviceTier::Medium
+        }
+    })
+}
+
+/// Device classification returned by [`fn@crate::use_device_tier`]. Ordered from `Low` to `High`.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum DeviceTier {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+/// Options for [`fn@crate::use_device_tier_with_options`].
+#[derive(DefaultBuilder, Copy, Clone, Debug)]
+pub struct UseDeviceTierOptions {
+    /// Devices with this amount of memory in gigabytes or less are `Low`. Defaults to `2.0`.
+    low_memory: f64,
+
+    /// Devices with this amount of memory in gigabytes or more can be `High`. Defaults to `8.0`,
+    /// which is the highest value that Chrome reports.
+    high_memory: f64,
+
+    /// Devices with this number of logical processors or less are `Low`. Defaults to `2`.
+    low_concurrency: usize,
+
+    /// Devices with this number of logical processors or more can be `High`. Defaults to `8`.
+    high_concurrency: usize,
+}
+
+impl Default for UseDeviceTierOptions {
+    fn default() -> Self {
+        Self {
+            low_memory: 2.0,
+            high_memory: 8.0,
+            low_concurrency: 2,
+            high_concurrency: 8,
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Reactive controller for a native [`<dialog>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dialog) element.
+///
+/// Opens the dialog either as a modal (`open_modal`) or as a non-modal (`open`) and closes it
+/// with an optional return value. The returned signals are kept in sync with the dialog, also when it is
+/// closed by the browser (e.g. by pressing `Escape` or submitting a `<form method="dialog">`).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_dialog)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Dialog;
+/// # use leptos_use::{use_dialog, UseDialogReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Dialog>::new();
+///
+/// let UseDialogReturn {
+///     is_open,
+///     return_value,
+///     open_modal,
+///     close,
+///     ..
+/// } = use_dialog(el);
+///
+/// view! {
+///     <button on:click=move |_| open_modal()>"Open"</button>
+///     <dialog node_ref=el>
+///         <button on:click={
+///             let close = close.clone();
+///             move |_| close(Some("yes".to_string()))
+///         }>"Yes"</button>
+///         <button on:click=move |_| close(Some("no".to_string()))>"No"</button>
+///     </dialog>
+///     <p>"Answer: " {return_value}</p>
+/// }
+/// # }
+/// ```
+///
+/// ## Callbacks
+///
+/// `on_close` is called with the return value whenever the dialog is closed. `on_cancel` is called
+/// with the `cancel` event when the user dismisses a modal dialog (e.g. by pressing `Escape`).
+/// Call `prevent_default()` on the event to keep the dialog open.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Dialog;
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_dialog_with_options, UseDialogOptions, UseDialogReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Dialog>::new();
+///
+/// let UseDialogReturn { open_modal, .. } = use_dialog_with_options(
+///     el,
+///     UseDialogOptions::default()
+///         .close_on_backdrop_click(true)
+///         .on_close(|return_value| log!("closed with {return_value:?}"))
+///         .on_cancel(|event: web_sys::Event| event.prevent_default()),
+/// );
+/// #
+/// # view! { <dialog node_ref=el></dialog> }
+/// # }
+/// ```
+///
+/// ## Backdrop and Scrolling
+///
+/// With the option `close_on_backdrop_click` a modal dialog is closed when the user clicks on its backdrop
+/// (i.e. outside of its bounding box). While a modal dialog is open the page behind it can't be scrolled
+/// because `overflow: hidden` is set on the `<body>`. Set the option `lock_scroll` to `false` to disable this.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `open`, `open_modal` and `close` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_dialog`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned signals always contain `false` and `""` respectively and the returned closures are no-ops.
+pub fn use_dialog<El, M>(
+    target: El,
+) -> UseDialogReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn(Option<String>) + Clone + Send + Sync,
+>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    use_dialog_with_options(target, UseDialogOptions::default())
+}
+
+/// Version of [`use_dialog`] that takes a `UseDialogOptions`. See [`use_dialog`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_dialog_with_options<El, M>(
+    target: El,
+    options: UseDialogOptions,
+) -> UseDialogReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn(Option<String>) + Clone + Send + Sync,
+>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    let UseDialogOptions {
+        close_on_backdrop_click,
+        lock_scroll,
+        on_close,
+        on_cancel,
+    } = options;
+
+    let (is_open, set_open) = signal(false);
+    let (is_modal, set_modal) = signal(false);
+    let (return_value, set_return_value) = signal(String::new());
+
+    let open;
+    let open_modal;
+    let close;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_open;
+        let _ = set_modal;
+        let _ = set_return_value;
+
+        open = || {};
+        open_modal = || {};
+        close = |_| {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{sendwrap_fn, use_event_listener};
+        use leptos::ev::{click, Custom};
+        use send_wrapper::SendWrapper;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use wasm_bindgen::JsCast;
+
+        let target = target.into_element_maybe_signal();
+
+        let dialog = {
+            let target = target.clone();
+
+            move || {
+                target
+                    .get_untracked()
+                    .and_then(|el| el.dyn_into::<web_sys::HtmlDialogElement>().ok())
+            }
+        };
+
+        // previous value of the `overflow` style of the body while the scroll is locked
+        let previous_overflow = Rc::new(RefCell::new(None::<String>));
+
+        let unlock_scroll = {
+            let previous_overflow = Rc::clone(&previous_overflow);
+
+            move || {
+                if let (Some(overflow), Some(body)) = (previous_overflow.take(), document().body())
+                {
+                    let _ = body.style().set_property("overflow", &overflow);
+                }
+            }
+        };
+
+        let lock_scroll = {
+            let previous_overflow = Rc::clone(&previous_overflow);
+
+            move || {
+                if !lock_scroll || previous_overflow.borrow().is_some() {
+                    return;
+                }
+
+                if let Some(body) = document().body() {
+                    let style = body.style();
+
+                    previous_overflow.replace(style.get_property_value("overflow").ok());
+                    let _ = style.set_property("overflow", "hidden");
+                }
+            }
+        };
+
+        open = {
+            let dialog = dialog.clone();
+            let unlock_scroll = unlock_scroll.clone();
+
+            sendwrap_fn!(move || {
+                if let Some(dialog) = dialog() {
+                    if !dialog.open() {
+                        // don't report the value of the previous close when it's closed without one
+                        dialog.set_return_value("");
+                        dialog.show();
+                        unlock_scroll();
+                        set_modal.set(false);
+                        set_open.set(true);
+                    }
+                }
+            })
+        };
+
+        open_modal = {
+            let dialog = dialog.clone();
+
+            sendwrap_fn!(move || {
+                if let Some(dialog) = dialog() {
+                    if !dialog.open() {
+                        dialog.set_return_value("");
+
+                        if dialog.show_modal().is_ok() {
+                            lock_scroll();
+                            set_modal.set(true);
+                            set_open.set(true);
+                        }
+                    }
+                }
+            })
+        };
+
+        close = {
+            let dialog = dialog.clone();
+
+            sendwrap_fn!(move |value: Option<String>| {
+                if let Some(dialog) = dialog() {
+                    match value {
+                        Some(value) => dialog.close_with_return_value(&value),
+                        None => dialog.close_with_return_value(""),
+                    }
+                }
+            })
+        };
+
+        let _ = Effect::watch(
+            {
+                let target = target.clone();
+                move || target.get()
+            },
+            {
+                let dialog = dialog.clone();
+
+                move |_, _, _| {
+                    if let Some(dialog) = dialog() {
+                        set_open.set(dialog.open());
+                    }
+                }
+            },
+            true,
+        );
+
+        // fired for every way the dialog is closed
+        let _ = use_event_listener(target.clone(), Custom::<web_sys::Event>::new("close"), {
+            let dialog = dialog.clone();
+            let unlock_scroll = unlock_scroll.clone();
+
+            move |_| {
+                let value = dialog()
+                    .map(|dialog| dialog.return_value())
+                    .unwrap_or_default();
+
+                unlock_scroll();
+                set_modal.set(false);
+                set_open.set(false);
+                set_return_value.set(value.clone());
+
+                #[cfg(debug_assertions)]
+                let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                on_close(value);
+            }
+        });
+
+        let _ = use_event_listener(
+            target.clone(),
+            Custom::<web_sys::Event>::new("cancel"),
+            move |event| {
+                #[cfg(debug_assertions)]
+                let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                on_cancel(event);
+            },
+        );
+
+        if close_on_backdrop_click {
+            let _ = use_event_listener(target, click, move |event: web_sys::MouseEvent| {
+                let Some(dialog) = dialog() else {
+                    return;
+                };
+
+                // clicks on the backdrop have the dialog itself as target
+                if !is_modal.get_untracked()
+                    || event.target().as_ref() != Some(dialog.unchecked_ref())
+                {
+                    return;
+                }
+
+                let rect = dialog.get_bounding_client_rect();
+                let (x, y) = (event.client_x() as f64, event.client_y() as f64);
+
+                if x < rect.left() || x > rect.right() || y < rect.top() || y > rect.bottom() {
+                    dialog.close();
+                }
+            });
+        }
+
+        on_cleanup({
+            let cleanup = SendWrapper::new(move || {
+                unlock_scroll();
+            });
+
+            move || cleanup()
+        });
+    }
+
+    UseDialogReturn {
+        is_open: is_open.into(),
+        is_modal: is_modal.into(),
+        return_value: return_value.into(),
+        open,
+        open_modal,
+        close,
+    }
+}
+
+/// Options for [`use_dialog_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseDialogOptions {
+    /// Close a modal dialog when its backdrop is clicked. Defaults to `false`.
+    close_on_backdrop_click: bool,
+
+    /// Prevent the page from scrolling while a modal dialog is open. Defaults to `true`.
+    lock_scroll: bool,
+
+    /// Called with the return value when the dialog is closed.
+    on_close: Arc<dyn Fn(String) + Send + Sync>,
+
+    /// Called with the `cancel` event when the user dismisses a modal dialog.
+    on_cancel: Arc<dyn Fn(web_sys::Event) + Send + Sync>,
+}
+
+impl Default for UseDialogOptions {
+    fn default() -> Self {
+        Self {
+            close_on_backdrop_click: false,
+            lock_scroll: true,
+            on_close: Arc::new(|_| {}),
+            on_cancel: Arc::new(|_| {}),
+        }
+    }
+}
+
+/// Return type of [`use_dialog`].
+pub struct UseDialogReturn<OpenFn, OpenModalFn, CloseFn>
+where
+    OpenFn: Fn() + Clone + Send + Sync,
+    OpenModalFn: Fn() + Clone + Send + Sync,
+    CloseFn: Fn(Option<String>) + Clone + Send + Sync,
+{
+    /// `true` while the dialog is open
+    pub is_open: Signal<bool>,
+
+    /// `true` while the dialog is open as a modal
+    pub is_modal: Signal<bool>,
+
+    /// The return value the dialog was last closed with. Empty if it has been closed without one, e.g. by
+    /// pressing `Escape`.
+    pub return_value: Signal<String>,
+
+    /// Open the dialog as a non-modal
+    pub open: OpenFn,
+
+    /// Open the dialog as a modal with a backdrop
+    pub open_modal: OpenModalFn,
+
+    /// Close the dialog. The given value becomes the new `return_value` which is empty for `None`.
+    pub close: CloseFn,
+}
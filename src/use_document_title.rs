@@ -0,0 +1,247 @@
+use crate::core::MaybeRwSignal;
+use crate::utils::Pausable;
+use crate::{use_interval_fn_with_options, UseIntervalFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive document title.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_document_title)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_document_title, UseDocumentTitleReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseDocumentTitleReturn { set_title, .. } = use_document_title();
+///
+/// set_title.set(Some("Inbox".to_string())); // change the title
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// As long as the title is `None` the document keeps the title it had when this function was called.
+///
+/// ## Templates and Counters
+///
+/// With the option `template` every occurrence of `{}` in the template is replaced by the title.
+/// The option `count` prefixes the title with a counter like `(3) ` as long as it's greater than `0`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_document_title_with_options, UseDocumentTitleOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (unread, set_unread) = signal(0);
+///
+/// use_document_title_with_options(
+///     UseDocumentTitleOptions::default()
+///         .title(Some("Inbox".to_string()))
+///         .template("{} – MyApp")
+///         .count(unread),
+/// ); // "(2) Inbox – MyApp" once `unread` is 2
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Blinking
+///
+/// To get the attention of the user you can let the title alternate with a message by calling `blink`.
+/// The message is shown every other `blink_interval` milliseconds until `stop_blink` is called.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_document_title, UseDocumentTitleReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseDocumentTitleReturn { blink, stop_blink, is_blinking, .. } = use_document_title();
+///
+/// view! {
+///     <button on:click=move |_| blink("New message!".to_string())>"Notify"</button>
+///     <button on:click=move |_| stop_blink() disabled=move || !is_blinking.get()>"Stop"</button>
+/// }
+/// # }
+/// ```
+///
+/// When the component is cleaned up the original title of the document is restored.
+/// Set the option `restore_on_cleanup` to `false` to keep the last title instead.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `blink` and `stop_blink` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_document_title`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server only the signals work but the document title isn't changed. To render the title on the server
+/// use the `<Title>` component of `leptos_meta` instead.
+pub fn use_document_title(
+) -> UseDocumentTitleReturn<impl Fn(String) + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+{
+    use_document_title_with_options(UseDocumentTitleOptions::default())
+}
+
+/// Version of [`use_document_title`] that takes a `UseDocumentTitleOptions`. See [`use_document_title`] for how to use.
+pub fn use_document_title_with_options(
+    options: UseDocumentTitleOptions,
+) -> UseDocumentTitleReturn<impl Fn(String) + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+{
+    let UseDocumentTitleOptions {
+        title,
+        template,
+        count,
+        blink_interval,
+        restore_on_cleanup,
+    } = options;
+
+    let (title, set_title) = title.into_signal();
+
+    let (blink_message, set_blink_message) = signal(None::<String>);
+    let (show_blink_message, set_show_blink_message) = signal(false);
+
+    let Pausable { pause, resume, .. } = use_interval_fn_with_options(
+        move || set_show_blink_message.update(|show| *show = !*show),
+        blink_interval,
+        UseIntervalFnOptions::default().immediate(false),
+    );
+
+    let blink = move |message: String| {
+        set_blink_message.set(Some(message));
+        set_show_blink_message.set(true);
+        resume();
+    };
+
+    let stop_blink = move || {
+        pause();
+
+        // this is also called on cleanup when the signals might already be disposed
+        let _ = set_blink_message.try_set(None);
+        let _ = set_show_blink_message.try_set(false);
+    };
+
+    #[cfg(feature = "ssr")]
+    let original_title = String::new();
+
+    #[cfg(not(feature = "ssr"))]
+    let original_title = document().title();
+
+    let document_title = {
+        let original_title = original_title.clone();
+
+        Signal::derive(move || {
+            if show_blink_message.get() {
+                if let Some(message) = blink_message.get() {
+                    return message;
+                }
+            }
+
+            let title = match title.get() {
+                Some(title) => match &template {
+                    Some(template) => template.replace("{}", &title),
+                    None => title,
+                },
+                None => original_title.clone(),
+            };
+
+            match count.get() {
+                0 => title,
+                count => format!("({count}) {title}"),
+            }
+        })
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        Effect::new(move || document().set_title(&document_title.get()));
+
+        if restore_on_cleanup {
+            let stop_blink = stop_blink.clone();
+
+            on_cleanup(move || {
+                stop_blink();
+                document().set_title(&original_title);
+            });
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    let _ = (original_title, restore_on_cleanup);
+
+    UseDocumentTitleReturn {
+        title,
+        set_title,
+        document_title,
+        is_blinking: Signal::derive(move || blink_message.with(Option::is_some)),
+        blink,
+        stop_blink,
+    }
+}
+
+/// Options for [`use_document_title_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseDocumentTitleOptions {
+    /// The title. Can be a `RwSignal` in which case updates will change the document title. Defaults to `None`.
+    #[builder(into)]
+    title: MaybeRwSignal<Option<String>>,
+
+    /// Template in which every `{}` is replaced by the title like `"{} – MyApp"`. Defaults to `None`.
+    #[builder(into)]
+    template: Option<String>,
+
+    /// Counter that is shown in front of the title like `(3) ` if greater than `0`. Defaults to `0`.
+    #[builder(into)]
+    count: Signal<usize>,
+
+    /// Milliseconds between switching the title and the message while blinking. Defaults to `1000`.
+    blink_interval: u64,
+
+    /// Restore the original title of the document when the component is cleaned up. Defaults to `true`.
+    restore_on_cleanup: bool,
+}
+
+impl Default for UseDocumentTitleOptions {
+    fn default() -> Self {
+        Self {
+            title: Default::default(),
+            template: None,
+            count: Signal::default(),
+            blink_interval: 1000,
+            restore_on_cleanup: true,
+        }
+    }
+}
+
+/// Return type of [`use_document_title`].
+pub struct UseDocumentTitleReturn<BlinkFn, StopBlinkFn>
+where
+    BlinkFn: Fn(String) + Clone + Send + Sync,
+    StopBlinkFn: Fn() + Clone + Send + Sync,
+{
+    /// The title without template and counter
+    pub title: Signal<Option<String>>,
+
+    /// Set the title
+    pub set_title: WriteSignal<Option<String>>,
+
+    /// The resulting document title with template, counter and blink message applied
+    pub document_title: Signal<String>,
+
+    /// `true` while the title is blinking
+    pub is_blinking: Signal<bool>,
+
+    /// Let the title alternate with the given message until `stop_blink` is called
+    pub blink: BlinkFn,
+
+    /// Stop blinking and show the title again
+    pub stop_blink: StopBlinkFn,
+}
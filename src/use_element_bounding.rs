@@ -27,6 +27,45 @@ use leptos::reactive::wrappers::read::Signal;
 /// # }
 /// ```
 ///
+/// ## Update Timing
+///
+/// By default the signals are updated synchronously whenever a resize or scroll event is received. If you
+/// set `update_timing` to `UpdateTiming::NextFrame` the updates are batched and happen at most once per
+/// animation frame.
+///
+/// ## Transforms and Animations
+///
+/// CSS transforms and animations change the bounding box without triggering any events. If you need to
+/// track those, set `poll` to `true`. Then the bounding box is measured on every animation frame and the
+/// signals are only updated when it actually changed.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_element_bounding_with_options, UpdateTiming, UseElementBoundingOptions, UseElementBoundingReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+/// let UseElementBoundingReturn { x, y, ready, .. } = use_element_bounding_with_options(
+///     el,
+///     UseElementBoundingOptions::default()
+///         .update_timing(UpdateTiming::NextFrame)
+///         .poll(true),
+/// );
+///
+/// view! {
+///     <div node_ref=el class="animate-bounce"></div>
+///     <Show when=move || ready.get()>
+///         <p>{x} ", " {y}</p>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// The `ready` signal is `false` until the element has been measured for the first time. This allows you to
+/// distinguish a bounding box that is actually zero from one that hasn't been measured yet.
+///
 /// ## SendWrapped Return
 ///
 /// The returned closure `update` is a sendwrapped function. It can
@@ -60,6 +99,7 @@ where
     let (bottom, set_bottom) = signal(0.0);
     let (x, set_x) = signal(0.0);
     let (y, set_y) = signal(0.0);
+    let (ready, set_ready) = signal(false);
 
     let update;
 
@@ -76,6 +116,7 @@ where
         let _ = set_bottom;
         let _ = set_x;
         let _ = set_y;
+        let _ = set_ready;
 
         update = move || ();
     }
@@ -83,29 +124,43 @@ where
     #[cfg(not(feature = "ssr"))]
     {
         use crate::{
-            sendwrap_fn, use_event_listener_with_options, use_resize_observer, use_window,
-            UseEventListenerOptions,
+            sendwrap_fn, use_event_listener_with_options, use_raf_fn, use_resize_observer,
+            use_window, UseEventListenerOptions,
         };
         use leptos::ev::{resize, scroll};
+        use std::cell::Cell;
+        use std::rc::Rc;
 
         let UseElementBoundingOptions {
             reset,
             window_resize,
             window_scroll,
             immediate,
+            update_timing,
+            poll,
         } = options;
 
         let target = target.into_element_maybe_signal();
 
-        update = {
+        let measure = {
             let target = target.clone();
 
-            sendwrap_fn!(move || {
+            move || {
                 let el = target.get_untracked();
 
                 if let Some(el) = el {
                     let rect = el.get_bounding_client_rect();
 
+                    // don't notify subscribers if nothing changed. This is important when polling.
+                    if ready.get_untracked()
+                        && height.get_untracked() == rect.height()
+                        && width.get_untracked() == rect.width()
+                        && x.get_untracked() == rect.x()
+                        && y.get_untracked() == rect.y()
+                    {
+                        return;
+                    }
+
                     set_height.set(rect.height());
                     set_width.set(rect.width());
                     set_left.set(rect.x());
@@ -114,6 +169,7 @@ where
                     set_bottom.set(rect.y() + rect.height());
                     set_x.set(rect.x());
                     set_y.set(rect.y());
+                    set_ready.set(true);
                 } else if reset {
                     set_height.set(0.0);
                     set_width.set(0.0);
@@ -123,10 +179,39 @@ where
                     set_bottom.set(0.0);
                     set_x.set(0.0);
                     set_y.set(0.0);
+                    set_ready.set(false);
+                }
+            }
+        };
+
+        update = {
+            let measure = measure.clone();
+            let frame_requested = Rc::new(Cell::new(false));
+
+            sendwrap_fn!(move || match update_timing {
+                UpdateTiming::Sync => measure(),
+                UpdateTiming::NextFrame => {
+                    if frame_requested.replace(true) {
+                        return;
+                    }
+
+                    request_animation_frame({
+                        let measure = measure.clone();
+                        let frame_requested = Rc::clone(&frame_requested);
+
+                        move || {
+                            frame_requested.set(false);
+                            measure();
+                        }
+                    });
                 }
             })
         };
 
+        if poll {
+            let _ = use_raf_fn(move |_| measure());
+        }
+
         use_resize_observer(target.clone(), {
             let update = update.clone();
 
@@ -186,6 +271,7 @@ where
         bottom: bottom.into(),
         x: x.into(),
         y: y.into(),
+        ready: ready.into(),
         update,
     }
 }
@@ -212,6 +298,28 @@ pub struct UseElementBoundingOptions {
     ///
     /// Default: `true`
     pub immediate: bool,
+
+    /// Timing to recalculate the bounding box when an event is received
+    ///
+    /// Default: `UpdateTiming::Sync`
+    pub update_timing: UpdateTiming,
+
+    /// Measure the bounding box on every animation frame to also catch changes caused by
+    /// CSS transforms or animations. The signals are only updated if the bounding box changed.
+    ///
+    /// Default: `false`
+    pub poll: bool,
+}
+
+/// Timing of the updates of [`use_element_bounding_with_options`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum UpdateTiming {
+    /// Update the signals synchronously
+    #[default]
+    Sync,
+
+    /// Update the signals at most once on the next animation frame
+    NextFrame,
 }
 
 impl Default for UseElementBoundingOptions {
@@ -221,6 +329,8 @@ impl Default for UseElementBoundingOptions {
             window_resize: true,
             window_scroll: true,
             immediate: true,
+            update_timing: UpdateTiming::Sync,
+            poll: false,
         }
     }
 }
@@ -246,6 +356,8 @@ where
     pub x: Signal<f64>,
     /// Reactive version of [`BoudingClientRect.y`](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly/y)
     pub y: Signal<f64>,
+    /// `true` as soon as the element has been measured. Before that all the values above are `0.0`.
+    pub ready: Signal<bool>,
     /// Function to re-evaluate `get_bounding_client_rect()` and update the signals.
     pub update: F,
 }
@@ -0,0 +1,303 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::utils::Pausable;
+use crate::{
+    js, use_interval_fn_with_options, use_raf_fn_with_options, use_supported, UseIntervalFnOptions,
+    UseRafFnOptions,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+use wasm_bindgen::JsCast;
+
+/// Reactive element by point.
+///
+/// Wraps [`document.elementFromPoint`](https://developer.mozilla.org/en-US/docs/Web/API/Document/elementFromPoint)
+/// and [`document.elementsFromPoint`](https://developer.mozilla.org/en-US/docs/Web/API/Document/elementsFromPoint)
+/// and returns the topmost element (or all elements) at the given viewport coordinates.
+/// This is useful for example for hit testing in custom drag-and-drop implementations.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_element_by_point)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_element_by_point, use_mouse, UseElementByPointReturn, UseMouseReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseMouseReturn { x, y, .. } = use_mouse();
+///
+/// let UseElementByPointReturn { element, .. } = use_element_by_point(x, y);
+///
+/// view! {
+///     <p>"Element under the mouse: " {move || element.get().map(|el| el.tag_name())}</p>
+/// }
+/// # }
+/// ```
+///
+/// ## All elements
+///
+/// If you set the option `multiple` to `true`, the signal `elements` contains all elements at the point,
+/// ordered from the topmost to the bottommost.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_element_by_point_with_options, UseElementByPointOptions, UseElementByPointReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseElementByPointReturn { elements, .. } = use_element_by_point_with_options(
+///     100.0,
+///     200.0,
+///     UseElementByPointOptions::default().multiple(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Update mode
+///
+/// By default the element is looked up on every animation frame because the element at a point can
+/// change without the coordinates changing (e.g. by scrolling or DOM changes). With the option `interval`
+/// you can instead poll every `n` milliseconds or only update when the coordinates change with
+/// [`ElementByPointInterval::OnChange`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_element_by_point_with_options, use_mouse, ElementByPointInterval, UseElementByPointOptions, UseMouseReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let UseMouseReturn { x, y, .. } = use_mouse();
+/// let element_polled = use_element_by_point_with_options(
+///     x,
+///     y,
+///     UseElementByPointOptions::default().interval(100),
+/// );
+///
+/// let element_on_change = use_element_by_point_with_options(
+///     x,
+///     y,
+///     UseElementByPointOptions::default().interval(ElementByPointInterval::OnChange),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned signals always contain `None` and an empty `Vec` respectively.
+pub fn use_element_by_point<X, Y>(x: X, y: Y) -> UseElementByPointReturn
+where
+    X: Into<Signal<f64>>,
+    Y: Into<Signal<f64>>,
+{
+    use_element_by_point_with_options(x, y, UseElementByPointOptions::default())
+}
+
+/// Version of [`use_element_by_point`] that takes a `UseElementByPointOptions`. See [`use_element_by_point`] for how to use.
+pub fn use_element_by_point_with_options<X, Y>(
+    x: X,
+    y: Y,
+    options: UseElementByPointOptions,
+) -> UseElementByPointReturn
+where
+    X: Into<Signal<f64>>,
+    Y: Into<Signal<f64>>,
+{
+    let UseElementByPointOptions {
+        multiple,
+        immediate,
+        interval,
+    } = options;
+
+    let x = x.into();
+    let y = y.into();
+
+    let is_supported = use_supported(move || {
+        if multiple {
+            js!("elementsFromPoint" in &document())
+        } else {
+            js!("elementFromPoint" in &document())
+        }
+    });
+
+    let (element, set_element) = signal_local(None::<web_sys::Element>);
+    let (elements, set_elements) = signal_local(Vec::<web_sys::Element>::new());
+
+    let update = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() {
+                return;
+            }
+
+            let x = x.get_untracked() as f32;
+            let y = y.get_untracked() as f32;
+
+            let (new_element, new_elements) = if multiple {
+                let new_elements = document()
+                    .elements_from_point(x, y)
+                    .iter()
+                    .map(|el| el.unchecked_into::<web_sys::Element>())
+                    .collect::<Vec<_>>();
+
+                (new_elements.first().cloned(), Some(new_elements))
+            } else {
+                (document().element_from_point(x, y), None)
+            };
+
+            // only notify subscribers if something actually changed as this runs very often
+            if element.with_untracked(|element| element != &new_element) {
+                set_element.set(new_element);
+            }
+
+            if let Some(new_elements) = new_elements {
+                if elements.with_untracked(|elements| elements != &new_elements) {
+                    set_elements.set(new_elements);
+                }
+            }
+        }
+    };
+
+    let element = element.into();
+    let elements = elements.into();
+
+    match interval {
+        ElementByPointInterval::RequestAnimationFrame => {
+            let Pausable {
+                is_active,
+                pause,
+                resume,
+            } = use_raf_fn_with_options(
+                move |_| update(),
+                UseRafFnOptions::default().immediate(immediate),
+            );
+
+            UseElementByPointReturn {
+                is_supported,
+                element,
+                elements,
+                is_active,
+                pause: Arc::new(pause),
+                resume: Arc::new(resume),
+            }
+        }
+
+        ElementByPointInterval::Interval(interval) => {
+            let Pausable {
+                is_active,
+                pause,
+                resume,
+            } = use_interval_fn_with_options(
+                update,
+                interval,
+                UseIntervalFnOptions::default().immediate(immediate),
+            );
+
+            UseElementByPointReturn {
+                is_supported,
+                element,
+                elements,
+                is_active,
+                pause: Arc::new(pause),
+                resume: Arc::new(resume),
+            }
+        }
+
+        ElementByPointInterval::OnChange => {
+            let is_active = RwSignal::new(immediate);
+
+            Effect::new(move || {
+                x.track();
+                y.track();
+
+                if is_active.get() {
+                    update();
+                }
+            });
+
+            UseElementByPointReturn {
+                is_supported,
+                element,
+                elements,
+                is_active: is_active.into(),
+                pause: Arc::new(move || is_active.set(false)),
+                resume: Arc::new(move || is_active.set(true)),
+            }
+        }
+    }
+}
+
+/// Options for [`use_element_by_point_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseElementByPointOptions {
+    /// If `true` all elements at the point are looked up with `elementsFromPoint` and returned in
+    /// `elements`. Otherwise only `element` is updated. Defaults to `false`.
+    multiple: bool,
+
+    /// Whether to start looking up the element immediately. Defaults to `true`.
+    immediate: bool,
+
+    /// Update interval in milliseconds, `RequestAnimationFrame` or `OnChange`.
+    /// Defaults to `RequestAnimationFrame`.
+    #[builder(into)]
+    interval: ElementByPointInterval,
+}
+
+impl Default for UseElementByPointOptions {
+    fn default() -> Self {
+        Self {
+            multiple: false,
+            immediate: true,
+            interval: ElementByPointInterval::RequestAnimationFrame,
+        }
+    }
+}
+
+/// Interval type for [`UseElementByPointOptions`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ElementByPointInterval {
+    /// use [`fn@crate::use_raf_fn`] for updating the element
+    RequestAnimationFrame,
+
+    /// use [`fn@crate::use_interval_fn`] for updating the element
+    Interval(u64),
+
+    /// only update the element when the coordinates change
+    OnChange,
+}
+
+impl From<u64> for ElementByPointInterval {
+    fn from(value: u64) -> Self {
+        Self::Interval(value)
+    }
+}
+
+/// Return type of [`use_element_by_point`].
+pub struct UseElementByPointReturn {
+    /// Whether `elementFromPoint` (or `elementsFromPoint` if `multiple` is `true`) is supported
+    pub is_supported: Signal<bool>,
+
+    /// The topmost element at the point
+    pub element: Signal<Option<web_sys::Element>, LocalStorage>,
+
+    /// All elements at the point from the topmost to the bottommost. Only updated if `multiple` is `true`.
+    pub elements: Signal<Vec<web_sys::Element>, LocalStorage>,
+
+    /// A Signal that indicates whether the element lookup is active. `false` when paused.
+    pub is_active: Signal<bool>,
+
+    /// Temporarily pause the element lookup
+    pub pause: Arc<dyn Fn() + Send + Sync>,
+
+    /// Resume the element lookup
+    pub resume: Arc<dyn Fn() + Send + Sync>,
+}
@@ -3,11 +3,15 @@ use crate::{use_event_listener_with_options, UseEventListenerOptions};
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::ev::{mouseenter, mouseleave};
-use leptos::leptos_dom::helpers::TimeoutHandle;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
+    use leptos::ev::mousemove;
+    use leptos::leptos_dom::helpers::{IntervalHandle, TimeoutHandle};
+    use send_wrapper::SendWrapper;
+    use std::cell::Cell;
+    use std::rc::Rc;
     use std::time::Duration;
 }}
 
@@ -35,6 +39,54 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ## Delays
+///
+/// You can delay the change of the hover state when entering and leaving the element.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Button;
+/// # use leptos_use::{use_element_hover_with_options, UseElementHoverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let el = NodeRef::<Button>::new();
+/// let is_hovered = use_element_hover_with_options(
+///     el,
+///     UseElementHoverOptions::default()
+///         .delay_enter(200)
+///         .delay_leave(600),
+/// );
+/// #
+/// # view! { <button node_ref=el></button> }
+/// # }
+/// ```
+///
+/// ## Hover Intent
+///
+/// To prevent menus from flickering open when the mouse just passes over them, you can
+/// enable the "intent" mode. Then the element is only considered hovered once the mouse has
+/// slowed down over it.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_element_hover_with_options, UseElementHoverIntent, UseElementHoverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let el = NodeRef::<Div>::new();
+/// let is_hovered = use_element_hover_with_options(
+///     el,
+///     UseElementHoverOptions::default()
+///         .intent(Some(UseElementHoverIntent::default().sensitivity(5.0)))
+///         .delay_leave(300),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns a `Signal` that always contains the value `false`.
@@ -47,7 +99,7 @@ where
 
 /// Version of [`use_element_hover`] that takes a `UseElementHoverOptions`. See [`use_element_hover`] for how to use.
 
-#[cfg_attr(feature = "ssr", allow(unused_variables, unused_mut))]
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
 pub fn use_element_hover_with_options<El, M>(
     el: El,
     options: UseElementHoverOptions,
@@ -58,45 +110,142 @@ where
     let UseElementHoverOptions {
         delay_enter,
         delay_leave,
+        intent,
     } = options;
 
     let (is_hovered, set_hovered) = signal(false);
 
-    let mut timer: Option<TimeoutHandle> = None;
+    cfg_if! { if #[cfg(not(feature = "ssr"))] {
+        let timer = Rc::new(Cell::new(None::<TimeoutHandle>));
+        let intent_timer = Rc::new(Cell::new(None::<IntervalHandle>));
+
+        // last position of the pointer and the position at the previous intent check
+        let position = Rc::new(Cell::new((0.0, 0.0)));
+        let previous_position = Rc::new(Cell::new((0.0, 0.0)));
+
+        let clear_intent = {
+            let intent_timer = Rc::clone(&intent_timer);
 
-    let mut toggle = move |entering: bool| {
-        cfg_if! { if #[cfg(not(feature = "ssr"))] {
-            let delay = if entering { delay_enter } else { delay_leave };
+            move || {
+                if let Some(handle) = intent_timer.take() {
+                    handle.clear();
+                }
+            }
+        };
+
+        let toggle = {
+            let timer = Rc::clone(&timer);
+
+            move |entering: bool| {
+                let delay = if entering { delay_enter } else { delay_leave };
+
+                if let Some(handle) = timer.take() {
+                    handle.clear();
+                }
 
-            if let Some(handle) = timer.take() {
-                handle.clear();
+                if delay > 0 {
+                    timer.set(
+                        set_timeout_with_handle(
+                            move || set_hovered.set(entering),
+                            Duration::from_millis(delay),
+                        )
+                        .ok(),
+                    );
+                } else {
+                    set_hovered.set(entering);
+                }
             }
+        };
+
+        let on_enter = {
+            let toggle = toggle.clone();
+            let clear_intent = clear_intent.clone();
+            let intent_timer = Rc::clone(&intent_timer);
+            let position = Rc::clone(&position);
+
+            move |event: web_sys::MouseEvent| {
+                let Some(UseElementHoverIntent {
+                    sensitivity,
+                    interval,
+                }) = intent
+                else {
+                    toggle(true);
+                    return;
+                };
+
+                clear_intent();
 
-            if delay > 0 {
-                timer = set_timeout_with_handle(
-                    move || set_hovered.set(entering),
-                    Duration::from_millis(delay),
-                )
-                .ok();
-            } else {
-                set_hovered.set(entering);
+                let current = (event.client_x() as f64, event.client_y() as f64);
+                position.set(current);
+                previous_position.set(current);
+
+                let check = {
+                    let toggle = toggle.clone();
+                    let clear_intent = clear_intent.clone();
+                    let position = Rc::clone(&position);
+                    let previous_position = Rc::clone(&previous_position);
+
+                    move || {
+                        let (x, y) = position.get();
+                        let (prev_x, prev_y) = previous_position.get();
+
+                        if (x - prev_x).hypot(y - prev_y) < sensitivity {
+                            clear_intent();
+                            toggle(true);
+                        } else {
+                            previous_position.set((x, y));
+                        }
+                    }
+                };
+
+                intent_timer.set(
+                    set_interval_with_handle(check, Duration::from_millis(interval)).ok(),
+                );
             }
-        }}
-    };
+        };
+
+        let on_leave = {
+            let clear_intent = clear_intent.clone();
+
+            move |_| {
+                clear_intent();
+                toggle(false);
+            }
+        };
+
+        on_cleanup({
+            let cleanup = SendWrapper::new(move || {
+                clear_intent();
+
+                if let Some(handle) = timer.take() {
+                    handle.clear();
+                }
+            });
+
+            move || cleanup()
+        });
+    } else {
+        let on_enter = |_| {};
+        let on_leave = |_| {};
+    }}
 
     let listener_options = UseEventListenerOptions::default().passive(true);
 
     let el = el.into_element_maybe_signal();
 
-    let _ = use_event_listener_with_options(
-        el.clone(),
-        mouseenter,
-        move |_| toggle(true),
-        listener_options,
-    );
+    let _ = use_event_listener_with_options(el.clone(), mouseenter, on_enter, listener_options);
+
+    #[cfg(not(feature = "ssr"))]
+    if intent.is_some() {
+        let _ = use_event_listener_with_options(
+            el.clone(),
+            mousemove,
+            move |event| position.set((event.client_x() as f64, event.client_y() as f64)),
+            listener_options,
+        );
+    }
 
-    let _ =
-        use_event_listener_with_options(el, mouseleave, move |_| toggle(false), listener_options);
+    let _ = use_event_listener_with_options(el, mouseleave, on_leave, listener_options);
 
     is_hovered.into()
 }
@@ -109,4 +258,32 @@ pub struct UseElementHoverOptions {
 
     /// The time in ms after the mouse has left the element before the signal is changed to `false`. Defaults to `0`.
     delay_leave: u64,
+
+    /// If set, the mouse has to slow down over the element before it is considered hovered.
+    /// See [`UseElementHoverIntent`]. Defaults to `None`.
+    intent: Option<UseElementHoverIntent>,
+}
+
+/// Configures the "intent" mode of [`use_element_hover_with_options`].
+///
+/// While the mouse is over the element its position is sampled every `interval` ms.
+/// As soon as it moved less than `sensitivity` px between two samples the element is considered hovered
+/// (after `delay_enter`, if set).
+#[derive(DefaultBuilder, Clone, Copy, Debug, PartialEq)]
+pub struct UseElementHoverIntent {
+    /// Maximum distance in px the mouse may move between two samples to be considered slowed down.
+    /// Defaults to `7.0`.
+    sensitivity: f64,
+
+    /// The time in ms between two samples of the mouse position. Defaults to `100`.
+    interval: u64,
+}
+
+impl Default for UseElementHoverIntent {
+    fn default() -> Self {
+        Self {
+            sensitivity: 7.0,
+            interval: 100,
+        }
+    }
 }
@@ -0,0 +1,150 @@
+use crate::core::{IntoElementMaybeSignal, Rect};
+use crate::{
+    use_element_bounding_with_options, UseElementBoundingOptions, UseElementBoundingReturn,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive overlap of two elements.
+///
+/// Reports whether two elements overlap, the intersection rectangle and how much of the `target` is
+/// covered by the `other` element. The values are updated when the page is scrolled or resized and when
+/// one of the elements changes its size. This is useful for collision-aware tooltips and toolbars.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_element_overlap)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_element_overlap, UseElementOverlapReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let tooltip = NodeRef::<Div>::new();
+/// let toolbar = NodeRef::<Div>::new();
+///
+/// let UseElementOverlapReturn {
+///     is_overlapping,
+///     ratio,
+///     ..
+/// } = use_element_overlap(tooltip, toolbar);
+///
+/// view! {
+///     <div node_ref=toolbar>"Toolbar"</div>
+///     <div node_ref=tooltip class:flipped=is_overlapping>
+///         {move || format!("{:.0}% covered", ratio.get() * 100.0)}
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// ## Transforms and Animations
+///
+/// If the elements are moved by CSS transforms or animations, set the option `poll` to `true`.
+/// Then the elements are measured on every animation frame. See [`fn@crate::use_element_bounding`].
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned signals always contain `false`, `None` and `0.0` respectively.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_element_bounding`]
+pub fn use_element_overlap<El, M, OtherEl, OtherM>(
+    target: El,
+    other: OtherEl,
+) -> UseElementOverlapReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    OtherEl: IntoElementMaybeSignal<web_sys::Element, OtherM>,
+{
+    use_element_overlap_with_options(target, other, UseElementOverlapOptions::default())
+}
+
+/// Version of [`use_element_overlap`] that takes a `UseElementOverlapOptions`. See [`use_element_overlap`] for how to use.
+pub fn use_element_overlap_with_options<El, M, OtherEl, OtherM>(
+    target: El,
+    other: OtherEl,
+    options: UseElementOverlapOptions,
+) -> UseElementOverlapReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    OtherEl: IntoElementMaybeSignal<web_sys::Element, OtherM>,
+{
+    let UseElementOverlapOptions { poll } = options;
+
+    let target_rect = bounding_rect(target, poll);
+    let other_rect = bounding_rect(other, poll);
+
+    let intersection = Signal::derive(move || {
+        let target_rect = target_rect.get()?;
+        let other_rect = other_rect.get()?;
+
+        target_rect.intersection(&other_rect)
+    });
+
+    UseElementOverlapReturn {
+        is_overlapping: Signal::derive(move || intersection.with(Option::is_some)),
+        intersection,
+        ratio: Signal::derive(move || {
+            let area = target_rect
+                .get()
+                .map(|rect| rect.area())
+                .unwrap_or_default();
+
+            match intersection.get() {
+                Some(intersection) if area > 0.0 => intersection.area() / area,
+                _ => 0.0,
+            }
+        }),
+    }
+}
+
+/// Reactive bounding rect of the element or `None` if it hasn't been measured (yet).
+fn bounding_rect<El, M>(target: El, poll: bool) -> Signal<Option<Rect>>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseElementBoundingReturn {
+        x,
+        y,
+        width,
+        height,
+        ready,
+        ..
+    } = use_element_bounding_with_options(target, UseElementBoundingOptions::default().poll(poll));
+
+    Signal::derive(move || {
+        ready.get().then(|| Rect {
+            x: x.get(),
+            y: y.get(),
+            width: width.get(),
+            height: height.get(),
+        })
+    })
+}
+
+/// Options for [`use_element_overlap_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseElementOverlapOptions {
+    /// Measure the elements on every animation frame to also catch movements caused by CSS transforms
+    /// or animations. Defaults to `false`.
+    poll: bool,
+}
+
+/// Return type of [`use_element_overlap`].
+pub struct UseElementOverlapReturn {
+    /// `true` if the two elements overlap
+    pub is_overlapping: Signal<bool>,
+
+    /// The intersection rectangle of the two elements in viewport coordinates. `None` if they don't overlap.
+    pub intersection: Signal<Option<Rect>>,
+
+    /// The fraction (`0.0` to `1.0`) of the area of `target` that is covered by `other`
+    pub ratio: Signal<f64>,
+}
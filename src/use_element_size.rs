@@ -1,5 +1,7 @@
 use crate::core::IntoElementMaybeSignal;
 use crate::core::Size;
+use crate::filter_builder_methods;
+use crate::utils::{DebounceOptions, FilterOptions, ThrottleOptions};
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
@@ -41,6 +43,58 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ## Box Model
+///
+/// With the option `box_` you can choose which box of the target is measured. To size a canvas
+/// crisply on high density screens use `DevicePixelContentBox` which reports the size in device pixels.
+/// If the browser doesn't support this, the content box size multiplied by the device pixel ratio is reported.
+///
+/// ```
+/// # use leptos::{html::Canvas, prelude::*};
+/// # use leptos_use::{use_element_size_with_options, UseElementSizeOptions, UseElementSizeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Canvas>::new();
+///
+/// let UseElementSizeReturn { width, height } = use_element_size_with_options(
+///     el,
+///     UseElementSizeOptions::default()
+///         .box_(web_sys::ResizeObserverBoxOptions::DevicePixelContentBox),
+/// );
+///
+/// view! {
+///     <canvas
+///         node_ref=el
+///         style="width: 100%; height: 300px"
+///         width=move || width.get().round()
+///         height=move || height.get().round()
+///     ></canvas>
+/// }
+/// # }
+/// ```
+///
+/// ## Debounce and Throttle
+///
+/// To not be notified on every frame while the element is being resized, you can debounce or
+/// throttle the updates of the returned signals.
+///
+/// ```
+/// # use leptos::{html::Div, prelude::*};
+/// # use leptos_use::{use_element_size_with_options, UseElementSizeOptions, UseElementSizeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let el = NodeRef::<Div>::new();
+/// let UseElementSizeReturn { width, height } = use_element_size_with_options(
+///     el,
+///     UseElementSizeOptions::default().throttle(100.0),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server the returned signals always contain the value of the `initial_size` option.
@@ -64,7 +118,11 @@ pub fn use_element_size_with_options<El, M>(
 where
     El: IntoElementMaybeSignal<web_sys::Element, M>,
 {
-    let UseElementSizeOptions { box_, initial_size } = options;
+    let UseElementSizeOptions {
+        box_,
+        initial_size,
+        filter,
+    } = options;
 
     let (width, set_width) = signal(initial_size.width);
     let (height, set_height) = signal(initial_size.height);
@@ -150,12 +208,21 @@ where
                         }))
                     } else {
                         // fallback
-                        set_width.set(entry.content_rect().width());
-                        set_height.set(entry.content_rect().height())
+                        let scale =
+                            if box_ == web_sys::ResizeObserverBoxOptions::DevicePixelContentBox {
+                                window().device_pixel_ratio()
+                            } else {
+                                1.0
+                            };
+
+                        set_width.set(entry.content_rect().width() * scale);
+                        set_height.set(entry.content_rect().height() * scale)
                     }
                 }
             },
-            UseResizeObserverOptions::default().box_(box_),
+            UseResizeObserverOptions::default()
+                .box_(box_)
+                .filter(filter),
         );
 
         let _ = watch_with_options(
@@ -189,6 +256,16 @@ pub struct UseElementSizeOptions {
     /// The box that is used to determine the dimensions of the target. Defaults to `ContentBox`.
     #[builder(into)]
     pub box_: Option<web_sys::ResizeObserverBoxOptions>,
+
+    /// Allows to debounce or throttle the updates of the size. Defaults to no filter.
+    filter: FilterOptions,
+}
+
+impl UseElementSizeOptions {
+    filter_builder_methods!(
+        /// the size updates
+        filter
+    );
 }
 
 /// The return value of [`use_element_size`].
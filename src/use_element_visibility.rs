@@ -1,11 +1,16 @@
-use crate::core::IntoElementMaybeSignal;
+use crate::core::{Direction, IntoElementMaybeSignal};
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
 use std::marker::PhantomData;
 
-#[cfg(not(feature = "ssr"))]
-use crate::{use_intersection_observer_with_options, UseIntersectionObserverOptions};
+cfg_if! { if #[cfg(not(feature = "ssr"))] {
+    use crate::{
+        use_event_listener_with_options, use_intersection_observer_with_options, use_window,
+        UseEventListenerOptions, UseIntersectionObserverOptions,
+    };
+    use leptos::ev::{resize, scroll};
+}}
 use leptos::reactive::wrappers::read::Signal;
 
 /// Tracks the visibility of an element within the viewport.
@@ -35,6 +40,36 @@ use leptos::reactive::wrappers::read::Signal;
 /// # }
 /// ```
 ///
+/// ## Off-screen Direction
+///
+/// If you also want to know in which direction and how far the element is off-screen, for example to
+/// show a "scroll to see more" hint that points the right way, use [`use_element_visibility_with_details`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::core::Direction;
+/// # use leptos_use::{use_element_visibility_with_details, UseElementVisibilityReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseElementVisibilityReturn {
+///     offscreen_direction,
+///     offscreen_distance,
+///     ..
+/// } = use_element_visibility_with_details(el);
+///
+/// view! {
+///     <Show when=move || offscreen_direction.get() == Some(Direction::Bottom)>
+///         <p>"Scroll down " {offscreen_distance} "px to see more"</p>
+///     </Show>
+///     <div node_ref=el></div>
+/// }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns a `Signal` that always contains the value `false`.
@@ -53,35 +88,179 @@ where
 }
 
 /// Version of [`use_element_visibility`] with that takes a `UseElementVisibilityOptions`. See [`use_element_visibility`] for how to use.
-#[cfg_attr(feature = "ssr", allow(unused_variables))]
 pub fn use_element_visibility_with_options<El, M, ContainerEl, ContainerM>(
     target: El,
     options: UseElementVisibilityOptions<ContainerEl, ContainerM>,
 ) -> Signal<bool>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    ContainerEl: IntoElementMaybeSignal<web_sys::Element, ContainerM>,
+{
+    use_element_visibility_impl(target, options, false).is_visible
+}
+
+/// Version of [`use_element_visibility`] that also reports in which direction and how far the element
+/// is off-screen. See [`use_element_visibility`] for how to use.
+pub fn use_element_visibility_with_details<El, M>(target: El) -> UseElementVisibilityReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_element_visibility_with_details_and_options::<El, M, web_sys::Element, _>(
+        target,
+        UseElementVisibilityOptions::default(),
+    )
+}
+
+/// Version of [`use_element_visibility_with_details`] that takes a `UseElementVisibilityOptions`. See [`use_element_visibility`] for how to use.
+pub fn use_element_visibility_with_details_and_options<El, M, ContainerEl, ContainerM>(
+    target: El,
+    options: UseElementVisibilityOptions<ContainerEl, ContainerM>,
+) -> UseElementVisibilityReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    ContainerEl: IntoElementMaybeSignal<web_sys::Element, ContainerM>,
+{
+    use_element_visibility_impl(target, options, true)
+}
+
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+fn use_element_visibility_impl<El, M, ContainerEl, ContainerM>(
+    target: El,
+    options: UseElementVisibilityOptions<ContainerEl, ContainerM>,
+    track_offscreen: bool,
+) -> UseElementVisibilityReturn
 where
     El: IntoElementMaybeSignal<web_sys::Element, M>,
     ContainerEl: IntoElementMaybeSignal<web_sys::Element, ContainerM>,
 {
     let (is_visible, set_visible) = signal(false);
+    let (offscreen, set_offscreen) = signal(None::<(Direction, f64)>);
 
     cfg_if! { if #[cfg(not(feature = "ssr"))] {
+        let target = target.into_element_maybe_signal();
+        let viewport = options
+            .viewport
+            .map(|viewport| viewport.into_element_maybe_signal());
+
+        let update_offscreen = {
+            let target = target.clone();
+            let viewport = viewport.clone();
+
+            move || {
+                if !track_offscreen {
+                    return;
+                }
+
+                if is_visible.get_untracked() {
+                    set_offscreen.set(None);
+                    return;
+                }
+
+                if let Some(el) = target.get_untracked() {
+                    set_offscreen.set(offscreen_position(
+                        &el,
+                        viewport.as_ref().and_then(|viewport| viewport.get_untracked()),
+                    ));
+                }
+            }
+        };
+
         use_intersection_observer_with_options(
-            target.into_element_maybe_signal(),
-            move |entries, _| {
-                // In some circumstances Chrome passes a first (or only) entry which has a zero bounding client rect
-                // and returns `is_intersecting` erroneously as `false`.
-                if let Some(entry) = entries.into_iter().find(|entry| {
-                    let rect = entry.bounding_client_rect();
-                    rect.width() > 0.0 || rect.height() > 0.0
-                }) {
-                    set_visible.set(entry.is_intersecting());
+            target,
+            {
+                let update_offscreen = update_offscreen.clone();
+
+                move |entries, _| {
+                    // In some circumstances Chrome passes a first (or only) entry which has a zero bounding client rect
+                    // and returns `is_intersecting` erroneously as `false`.
+                    if let Some(entry) = entries.into_iter().find(|entry| {
+                        let rect = entry.bounding_client_rect();
+                        rect.width() > 0.0 || rect.height() > 0.0
+                    }) {
+                        set_visible.set(entry.is_intersecting());
+                        update_offscreen();
+                    }
                 }
             },
-            UseIntersectionObserverOptions::default().root(options.viewport),
+            UseIntersectionObserverOptions::default().root(viewport),
         );
+
+        if track_offscreen {
+            // the intersection observer only reports threshold crossings so the distance has to be
+            // updated manually while the element is off-screen
+            let _ = use_event_listener_with_options(
+                use_window(),
+                scroll,
+                {
+                    let update_offscreen = update_offscreen.clone();
+                    move |_| update_offscreen()
+                },
+                UseEventListenerOptions::default().capture(true).passive(true),
+            );
+
+            let _ = use_event_listener_with_options(
+                use_window(),
+                resize,
+                move |_| update_offscreen(),
+                UseEventListenerOptions::default().passive(true),
+            );
+        }
     }}
 
-    is_visible.into()
+    UseElementVisibilityReturn {
+        is_visible: is_visible.into(),
+        offscreen_direction: Signal::derive(move || {
+            offscreen.get().map(|(direction, _)| direction)
+        }),
+        offscreen_distance: Signal::derive(move || {
+            offscreen
+                .get()
+                .map(|(_, distance)| distance)
+                .unwrap_or_default()
+        }),
+    }
+}
+
+/// Returns the direction in which `el` lies outside of `viewport` (or the window if `None`)
+/// together with the distance in px between the element and the viewport.
+#[cfg(not(feature = "ssr"))]
+fn offscreen_position(
+    el: &web_sys::Element,
+    viewport: Option<web_sys::Element>,
+) -> Option<(Direction, f64)> {
+    let rect = el.get_bounding_client_rect();
+
+    let (root_top, root_right, root_bottom, root_left) = match viewport {
+        Some(viewport) => {
+            let root = viewport.get_bounding_client_rect();
+            (root.top(), root.right(), root.bottom(), root.left())
+        }
+        None => {
+            let window = window();
+            let size = |v: Result<wasm_bindgen::JsValue, _>| {
+                v.ok().and_then(|v| v.as_f64()).unwrap_or_default()
+            };
+
+            (
+                0.0,
+                size(window.inner_width()),
+                size(window.inner_height()),
+                0.0,
+            )
+        }
+    };
+
+    if rect.bottom() <= root_top {
+        Some((Direction::Top, root_top - rect.bottom()))
+    } else if rect.top() >= root_bottom {
+        Some((Direction::Bottom, rect.top() - root_bottom))
+    } else if rect.right() <= root_left {
+        Some((Direction::Left, root_left - rect.right()))
+    } else if rect.left() >= root_right {
+        Some((Direction::Right, rect.left() - root_right))
+    } else {
+        None
+    }
 }
 
 /// Options for [`use_element_visibility_with_options`].
@@ -114,3 +293,17 @@ where
         }
     }
 }
+
+/// Return type of [`use_element_visibility_with_details`].
+pub struct UseElementVisibilityReturn {
+    /// `true` if the element is (partially) visible within the viewport
+    pub is_visible: Signal<bool>,
+
+    /// The direction in which the element lies outside of the viewport. `None` if it is visible.
+    /// For example `Some(Direction::Bottom)` means that you have to scroll down to see it.
+    pub offscreen_direction: Signal<Option<Direction>>,
+
+    /// The distance in px between the element and the edge of the viewport.
+    /// `0.0` if the element is visible.
+    pub offscreen_distance: Signal<f64>,
+}
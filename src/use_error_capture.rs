@@ -0,0 +1,337 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Reactive log of uncaught errors and unhandled promise rejections.
+///
+/// Listens to the `error` and `unhandledrejection` events of the window and normalizes them into
+/// [`CapturedError`]s with message, source location and stack trace. The errors can be filtered, handed to
+/// a reporting callback and forwarded to a server endpoint.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_error_capture)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_error_capture, UseErrorCaptureReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseErrorCaptureReturn { errors, clear, .. } = use_error_capture();
+///
+/// view! {
+///     <ul>
+///         <For each=move || errors.get() key=|error| error.timestamp.to_bits() let:error>
+///             <li>{error.message}</li>
+///         </For>
+///     </ul>
+///     <button on:click=move |_| clear()>"Clear"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## Filtering and Reporting
+///
+/// Errors for which `filter` returns `false` are ignored. `on_error` is called for every captured error
+/// and with `report_url` the errors are sent as JSON with `navigator.sendBeacon()` so they arrive even if
+/// the page is being closed.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_error_capture_with_options, UseErrorCaptureOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// use_error_capture_with_options(
+///     UseErrorCaptureOptions::default()
+///         // ignore errors of browser extensions
+///         .filter(|error| {
+///             !error
+///                 .source
+///                 .as_ref()
+///                 .is_some_and(|source| source.starts_with("chrome-extension://"))
+///         })
+///         .on_error(|error| leptos::logging::error!("{error:?}"))
+///         .report_url(Some("/api/errors".to_string())),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `clear` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_error_capture`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no errors are captured and `errors` is always empty.
+pub fn use_error_capture() -> UseErrorCaptureReturn<impl Fn() + Clone + Send + Sync> {
+    use_error_capture_with_options(UseErrorCaptureOptions::default())
+}
+
+/// Version of [`use_error_capture`] that takes a `UseErrorCaptureOptions`. See [`use_error_capture`] for how to use.
+pub fn use_error_capture_with_options(
+    options: UseErrorCaptureOptions,
+) -> UseErrorCaptureReturn<impl Fn() + Clone + Send + Sync> {
+    let UseErrorCaptureOptions {
+        max_errors,
+        prevent_default,
+        report_url,
+        filter,
+        on_error,
+    } = options;
+
+    let (errors, set_errors) = signal(Vec::<CapturedError>::new());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::{error as error_ev, unhandledrejection};
+
+        let capture = move |captured: CapturedError| {
+            if let Some(filter) = &filter {
+                if !filter(&captured) {
+                    return false;
+                }
+            }
+
+            if let Some(on_error) = &on_error {
+                on_error(&captured);
+            }
+
+            if let Some(report_url) = &report_url {
+                let _ = window()
+                    .navigator()
+                    .send_beacon_with_opt_str(report_url, Some(&captured.to_json()));
+            }
+
+            set_errors.update(|errors| {
+                errors.push(captured);
+
+                if errors.len() > max_errors {
+                    errors.drain(..errors.len() - max_errors);
+                }
+            });
+
+            true
+        };
+        let capture = Arc::new(capture);
+
+        let _ = use_event_listener(window(), error_ev, {
+            let capture = Arc::clone(&capture);
+
+            move |event| {
+                let js_error = event.error();
+
+                let captured = CapturedError {
+                    kind: CapturedErrorKind::Error,
+                    message: event.message(),
+                    source: Some(event.filename()).filter(|source| !source.is_empty()),
+                    line: Some(event.lineno()).filter(|line| *line > 0),
+                    column: Some(event.colno()).filter(|column| *column > 0),
+                    stack: read_stack(&js_error),
+                    timestamp: js_sys::Date::now(),
+                };
+
+                if capture(captured) && prevent_default {
+                    event.prevent_default();
+                }
+            }
+        });
+
+        let _ = use_event_listener(window(), unhandledrejection, move |event| {
+            let reason = event.reason();
+
+            let captured = CapturedError {
+                kind: CapturedErrorKind::UnhandledRejection,
+                message: read_message(&reason),
+                source: None,
+                line: None,
+                column: None,
+                stack: read_stack(&reason),
+                timestamp: js_sys::Date::now(),
+            };
+
+            if capture(captured) && prevent_default {
+                event.prevent_default();
+            }
+        });
+    }
+
+    let clear = sendwrap_fn!(move || set_errors.set(vec![]));
+
+    UseErrorCaptureReturn {
+        errors: errors.into(),
+        last_error: Signal::derive(move || errors.with(|errors| errors.last().cloned())),
+        clear,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn read_stack(error: &wasm_bindgen::JsValue) -> Option<String> {
+    let stack = js_sys::Reflect::get(error, &"stack".into()).ok()?;
+    stack.as_string()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn read_message(reason: &wasm_bindgen::JsValue) -> String {
+    if let Some(message) = reason.as_string() {
+        return message;
+    }
+
+    if let Some(message) = js_sys::Reflect::get(reason, &"message".into())
+        .ok()
+        .and_then(|message| message.as_string())
+    {
+        return message;
+    }
+
+    js_sys::JSON::stringify(reason)
+        .ok()
+        .and_then(|json| json.as_string())
+        .unwrap_or_else(|| format!("{reason:?}"))
+}
+
+/// Where a [`CapturedError`] came from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CapturedErrorKind {
+    /// An uncaught exception reported by the `error` event
+    Error,
+    /// A rejected promise without a rejection handler reported by the `unhandledrejection` event
+    UnhandledRejection,
+}
+
+impl CapturedErrorKind {
+    /// The name of the event the error was captured from.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::UnhandledRejection => "unhandledrejection",
+        }
+    }
+}
+
+/// An error captured by [`use_error_capture`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapturedError {
+    /// Where the error came from
+    pub kind: CapturedErrorKind,
+
+    /// The error message or the rejection reason
+    pub message: String,
+
+    /// URL of the script in which the error occurred
+    pub source: Option<String>,
+
+    /// Line number in `source`
+    pub line: Option<u32>,
+
+    /// Column number in `source`
+    pub column: Option<u32>,
+
+    /// The stack trace if the error or rejection reason is an `Error` object
+    pub stack: Option<String>,
+
+    /// When the error was captured in milliseconds since the UNIX epoch
+    pub timestamp: f64,
+}
+
+impl CapturedError {
+    #[cfg(not(feature = "ssr"))]
+    fn to_json(&self) -> String {
+        use crate::js;
+
+        let object = js_sys::Object::new();
+        js!(object["kind"] = self.kind.as_str());
+        js!(object["message"] = self.message.as_str());
+        js!(object["source"] = self.source.clone());
+        js!(object["line"] = self.line);
+        js!(object["column"] = self.column);
+        js!(object["stack"] = self.stack.clone());
+        js!(object["timestamp"] = self.timestamp);
+
+        js_sys::JSON::stringify(&object)
+            .ok()
+            .and_then(|json| json.as_string())
+            .unwrap_or_default()
+    }
+}
+
+/// Options for [`use_error_capture_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseErrorCaptureOptions {
+    /// Maximum number of errors kept in `errors`. Older errors are dropped. Defaults to `100`.
+    max_errors: usize,
+
+    /// Prevent the default handling of captured errors which logs them to the console. Defaults to `false`.
+    prevent_default: bool,
+
+    /// If set the captured errors are sent as JSON to this URL with `navigator.sendBeacon()`. Defaults to `None`.
+    report_url: Option<String>,
+
+    /// Only errors for which this returns `true` are captured.
+    #[builder(skip)]
+    filter: Option<ErrorFilterFn>,
+
+    /// Called for every captured error.
+    #[builder(skip)]
+    on_error: Option<ErrorHandlerFn>,
+}
+
+type ErrorFilterFn = Arc<dyn Fn(&CapturedError) -> bool + Send + Sync>;
+type ErrorHandlerFn = Arc<dyn Fn(&CapturedError) + Send + Sync>;
+
+impl UseErrorCaptureOptions {
+    /// Only errors for which this returns `true` are captured.
+    pub fn filter(self, filter: impl Fn(&CapturedError) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            filter: Some(Arc::new(filter)),
+            ..self
+        }
+    }
+
+    /// Called for every captured error. Use this to send the errors to your reporting service.
+    pub fn on_error(self, on_error: impl Fn(&CapturedError) + Send + Sync + 'static) -> Self {
+        Self {
+            on_error: Some(Arc::new(on_error)),
+            ..self
+        }
+    }
+}
+
+impl Default for UseErrorCaptureOptions {
+    fn default() -> Self {
+        Self {
+            max_errors: 100,
+            prevent_default: false,
+            report_url: None,
+            filter: None,
+            on_error: None,
+        }
+    }
+}
+
+/// Return type of [`use_error_capture`].
+pub struct UseErrorCaptureReturn<ClearFn>
+where
+    ClearFn: Fn() + Clone + Send + Sync,
+{
+    /// The captured errors, oldest first
+    pub errors: Signal<Vec<CapturedError>>,
+
+    /// The most recently captured error
+    pub last_error: Signal<Option<CapturedError>>,
+
+    /// Clears `errors`
+    pub clear: ClearFn,
+}
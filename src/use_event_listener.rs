@@ -1,11 +1,12 @@
 use crate::core::IntoElementMaybeSignal;
+use crate::utils::Pausable;
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::ev::EventDescriptor;
+use leptos::prelude::*;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
     use crate::{watch_with_options, WatchOptions, sendwrap_fn};
-    use leptos::prelude::*;
     use std::cell::RefCell;
     use std::rc::Rc;
     use wasm_bindgen::closure::Closure;
@@ -85,6 +86,117 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ## Options
+///
+/// With [`use_event_listener_with_options`] you can configure the listener with the options of `addEventListener`
+/// like `capture`, `once` and `passive`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::ev::{scroll, click};
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_event_listener_with_options, UseEventListenerOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// use_event_listener_with_options(
+///     window(),
+///     scroll,
+///     |_| log!("scrolled"),
+///     UseEventListenerOptions::default().passive(true),
+/// );
+///
+/// use_event_listener_with_options(
+///     document(),
+///     click,
+///     |_| log!("first click"),
+///     UseEventListenerOptions::default().once(true).capture(true),
+/// );
+/// #
+/// #    view! { }
+/// # }
+/// ```
+///
+/// Pass an [`AbortSignal`](https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal) with the option `abort_signal`
+/// to remove a whole group of listeners at once by aborting its controller.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::ev::{keydown, keyup};
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_event_listener_with_options, UseEventListenerOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let controller = web_sys::AbortController::new().unwrap();
+///
+/// let options = UseEventListenerOptions::default().abort_signal(controller.signal());
+///
+/// use_event_listener_with_options(document(), keydown, |_| log!("down"), options);
+/// use_event_listener_with_options(document(), keyup, |_| log!("up"), options);
+///
+/// // removes both listeners
+/// controller.abort();
+/// #
+/// #    view! { }
+/// # }
+/// ```
+///
+/// ## Pause and resume
+///
+/// [`use_event_listener_pausable`] returns a [`Pausable`] to temporarily remove the listener and add it again later.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::ev::mousemove;
+/// # use leptos::logging::log;
+/// # use leptos_use::use_event_listener_pausable;
+/// # use leptos_use::utils::Pausable;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let Pausable { pause, resume, is_active } = use_event_listener_pausable(
+///     document(),
+///     mousemove,
+///     |evt| log!("{}, {}", evt.x(), evt.y()),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| if is_active.get() { pause() } else { resume() }>
+///         "Toggle tracking"
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// ## Event delegation
+///
+/// If you need the same listener on many elements, like the rows of a large list, you can use
+/// [`use_event_listener_delegated`] instead. It adds a single listener to a container and calls the
+/// handler only for events inside an element that matches a CSS selector. The matched element is passed to the handler.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::ev::click;
+/// # use leptos::logging::log;
+/// # use leptos_use::use_event_listener_delegated;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let list = NodeRef::new();
+///
+/// use_event_listener_delegated(list, click, "li[data-id]", |_, item| {
+///     log!("clicked item {:?}", item.get_attribute("data-id"));
+/// });
+///
+/// view! {
+///     <ul node_ref=list>
+///         {(0..1000).map(|i| view! { <li data-id=i>"Item " {i}</li> }).collect_view()}
+///     </ul>
+/// }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closure is a sendwrapped function. It can
@@ -205,6 +317,167 @@ where
     }
 }
 
+/// Version of [`use_event_listener`] that returns a [`Pausable`] instead of a cleanup closure.
+///
+/// While paused the listener is removed from the target. See [`use_event_listener`] for an example.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `pause` and `resume` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_event_listener_pausable`.
+pub fn use_event_listener_pausable<Ev, El, M, F>(
+    target: El,
+    event: Ev,
+    handler: F,
+) -> Pausable<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    Ev: EventDescriptor + 'static,
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+    F: FnMut(<Ev as EventDescriptor>::EventType) + 'static,
+{
+    use_event_listener_pausable_with_options(
+        target,
+        event,
+        handler,
+        UseEventListenerOptions::default(),
+    )
+}
+
+/// Version of [`use_event_listener_pausable`] that takes `UseEventListenerOptions`.
+pub fn use_event_listener_pausable_with_options<Ev, El, M, F>(
+    target: El,
+    event: Ev,
+    handler: F,
+    options: UseEventListenerOptions,
+) -> Pausable<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    Ev: EventDescriptor + 'static,
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+    F: FnMut(<Ev as EventDescriptor>::EventType) + 'static,
+{
+    let (is_active, set_active) = signal(true);
+
+    let target = target.into_element_maybe_signal();
+
+    let _ = use_event_listener_with_options(
+        Signal::derive_local(move || if is_active.get() { target.get() } else { None }),
+        event,
+        handler,
+        options,
+    );
+
+    Pausable {
+        is_active: is_active.into(),
+        pause: move || set_active.set(false),
+        resume: move || set_active.set(true),
+    }
+}
+
+/// Listens for events on `container` that happen inside elements matching `selector`.
+///
+/// Only one listener is added to the container no matter how many elements match. The handler receives the event
+/// and the closest element to the event target that matches `selector`. Events on elements outside the container, or on
+/// the container itself, are ignored. If the container is `window` or `document` every element is inside.
+///
+/// Events that don't bubble, like `focus` or `blur`, can't be delegated. Use their bubbling counterparts
+/// `focusin` and `focusout` instead.
+///
+/// See [`use_event_listener`] for an example.
+pub fn use_event_listener_delegated<Ev, El, M, F>(
+    container: El,
+    event: Ev,
+    selector: &str,
+    handler: F,
+) -> impl Fn() + Clone + Send + Sync
+where
+    Ev: EventDescriptor + 'static,
+    <Ev as EventDescriptor>::EventType: AsRef<web_sys::Event>,
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+    F: FnMut(<Ev as EventDescriptor>::EventType, web_sys::Element) + 'static,
+{
+    use_event_listener_delegated_with_options(
+        container,
+        event,
+        selector,
+        handler,
+        UseEventListenerOptions::default(),
+    )
+}
+
+/// Version of [`use_event_listener_delegated`] that takes `UseEventListenerOptions`.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+#[allow(unused_mut)]
+pub fn use_event_listener_delegated_with_options<Ev, El, M, F>(
+    container: El,
+    event: Ev,
+    selector: &str,
+    mut handler: F,
+    options: UseEventListenerOptions,
+) -> impl Fn() + Clone + Send + Sync
+where
+    Ev: EventDescriptor + 'static,
+    <Ev as EventDescriptor>::EventType: AsRef<web_sys::Event>,
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+    F: FnMut(<Ev as EventDescriptor>::EventType, web_sys::Element) + 'static,
+{
+    #[cfg(feature = "ssr")]
+    {
+        || {}
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let container = container.into_element_maybe_signal();
+        let selector = selector.to_string();
+
+        use_event_listener_with_options(
+            container.clone(),
+            event,
+            move |evt| {
+                let Some(target) = evt.as_ref().target() else {
+                    return;
+                };
+
+                // text nodes can be event targets but can't be matched against a selector
+                let element = match target.dyn_into::<web_sys::Element>() {
+                    Ok(element) => Some(element),
+                    Err(target) => target
+                        .dyn_into::<web_sys::Node>()
+                        .ok()
+                        .and_then(|node| node.parent_element()),
+                };
+
+                let Some(matched) =
+                    element.and_then(|element| element.closest(&selector).ok().flatten())
+                else {
+                    return;
+                };
+
+                // the container itself only counts if it's an ancestor of the matched element
+                let is_inside = container.get_untracked().is_some_and(|container| {
+                    if container.is_instance_of::<web_sys::Window>()
+                        || container.is_instance_of::<web_sys::Document>()
+                    {
+                        return true;
+                    }
+
+                    container
+                        .dyn_ref::<web_sys::Node>()
+                        .is_some_and(|container| {
+                            !container.is_same_node(Some(&matched))
+                                && container.contains(Some(&matched))
+                        })
+                });
+
+                if is_inside {
+                    handler(evt, matched);
+                }
+            },
+            options,
+        )
+    }
+}
+
 /// Options for [`use_event_listener_with_options`].
 #[derive(DefaultBuilder, Default, Copy, Clone)]
 #[cfg_attr(feature = "ssr", allow(dead_code))]
@@ -234,15 +507,30 @@ pub struct UseEventListenerOptions {
     /// to learn more.
     #[builder(into)]
     passive: Option<bool>,
+
+    /// An [`AbortSignal`](https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal).
+    /// The listener is removed when the signal is aborted. This way you can remove
+    /// many listeners at once by aborting their shared `AbortController`.
+    #[builder(skip)]
+    abort_signal: Option<StoredValue<web_sys::AbortSignal, LocalStorage>>,
 }
 
 impl UseEventListenerOptions {
+    /// Remove the listener when `abort_signal` is aborted.
+    pub fn abort_signal(self, abort_signal: web_sys::AbortSignal) -> Self {
+        Self {
+            abort_signal: Some(StoredValue::new_local(abort_signal)),
+            ..self
+        }
+    }
+
     #[cfg_attr(feature = "ssr", allow(dead_code))]
     fn as_add_event_listener_options(&self) -> web_sys::AddEventListenerOptions {
         let UseEventListenerOptions {
             capture,
             once,
             passive,
+            abort_signal,
         } = self;
 
         let options = web_sys::AddEventListenerOptions::new();
@@ -251,6 +539,9 @@ impl UseEventListenerOptions {
         if let Some(passive) = passive {
             options.set_passive(*passive);
         }
+        if let Some(Some(abort_signal)) = abort_signal.map(|signal| signal.try_get_value()) {
+            options.set_signal(&abort_signal);
+        }
 
         options
     }
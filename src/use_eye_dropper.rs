@@ -0,0 +1,202 @@
+#![cfg_attr(feature = "ssr", allow(unused_imports))]
+
+use crate::use_supported;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive [EyeDropper API](https://developer.mozilla.org/en-US/docs/Web/API/EyeDropper_API).
+///
+/// Lets the user pick a color from anywhere on the screen.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_eye_dropper)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_eye_dropper, UseEyeDropperReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseEyeDropperReturn { is_supported, color, open, .. } = use_eye_dropper();
+///
+/// view! {
+///     <button on:click=move |_| open() disabled=move || !is_supported.get()>"Pick a color"</button>
+///     <div style:background-color=move || color.get().unwrap_or_default()>{color}</div>
+/// }
+/// # }
+/// ```
+///
+/// The picked color is an sRGB hex string like `"#ff8800"`. Opening the eye dropper has to be triggered
+/// by a user interaction like a click. If the user presses Escape, `color` is left unchanged.
+///
+/// While the eye dropper is open (`is_open`) it can be closed again with `abort`. This also happens
+/// when the component is cleaned up.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `open` and `abort` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_eye_dropper`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false` and the returned closures are no-ops.
+pub fn use_eye_dropper(
+) -> UseEyeDropperReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_eye_dropper_with_options(UseEyeDropperOptions::default())
+}
+
+/// Version of [`use_eye_dropper`] that takes a `UseEyeDropperOptions`. See [`use_eye_dropper`] for how to use.
+pub fn use_eye_dropper_with_options(
+    options: UseEyeDropperOptions,
+) -> UseEyeDropperReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseEyeDropperOptions { initial_value } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("EyeDropper" in &window())
+        }
+    });
+
+    let (color, set_color) = signal(initial_value);
+    let (is_open, set_open) = signal(false);
+
+    let open;
+    let abort;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_color;
+        let _ = set_open;
+
+        open = || {};
+        abort = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js, js_fut, sendwrap_fn};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use wasm_bindgen::JsCast;
+
+        let abort_controller = Rc::new(RefCell::new(None::<web_sys::AbortController>));
+
+        abort = {
+            let abort_controller = Rc::clone(&abort_controller);
+
+            sendwrap_fn!(move || {
+                if let Some(controller) = abort_controller.take() {
+                    controller.abort();
+                }
+            })
+        };
+
+        open = {
+            let abort = abort.clone();
+
+            sendwrap_fn!(move || {
+                if !is_supported.get_untracked() {
+                    return;
+                }
+
+                abort();
+
+                let window = window();
+
+                let Some(eye_dropper) = js!(window["EyeDropper"])
+                    .and_then(|constructor| {
+                        js_sys::Reflect::construct(
+                            &constructor.unchecked_into::<js_sys::Function>(),
+                            &js_sys::Array::new(),
+                        )
+                    })
+                    .ok()
+                else {
+                    return;
+                };
+
+                let options = js_sys::Object::new();
+
+                if let Ok(controller) = web_sys::AbortController::new() {
+                    js!(options["signal"] = controller.signal());
+                    abort_controller.replace(Some(controller));
+                }
+
+                let Ok(promise) = js!(eye_dropper["open"])
+                    .and_then(|open| {
+                        open.unchecked_into::<js_sys::Function>()
+                            .call1(&eye_dropper, &options)
+                    })
+                    .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+                else {
+                    return;
+                };
+
+                set_open.set(true);
+
+                leptos::task::spawn_local(async move {
+                    // rejects if the user presses Escape or the eye dropper is aborted
+                    if let Ok(result) = js_fut!(promise).await {
+                        if let Some(hex) =
+                            js!(result["sRGBHex"]).ok().and_then(|hex| hex.as_string())
+                        {
+                            let _ = set_color.try_set(Some(hex));
+                        }
+                    }
+
+                    let _ = set_open.try_set(false);
+                });
+            })
+        };
+
+        on_cleanup(abort.clone());
+    }
+
+    UseEyeDropperReturn {
+        is_supported,
+        color: color.into(),
+        is_open: is_open.into(),
+        open,
+        abort,
+    }
+}
+
+/// Options for [`use_eye_dropper_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseEyeDropperOptions {
+    /// The initial value of `color`. Defaults to `None`.
+    #[builder(into)]
+    initial_value: Option<String>,
+}
+
+/// Return type of [`use_eye_dropper`].
+pub struct UseEyeDropperReturn<OpenFn, AbortFn>
+where
+    OpenFn: Fn() + Clone + Send + Sync,
+    AbortFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the EyeDropper API is supported
+    pub is_supported: Signal<bool>,
+
+    /// The last picked color as an sRGB hex string
+    pub color: Signal<Option<String>>,
+
+    /// `true` while the eye dropper is open
+    pub is_open: Signal<bool>,
+
+    /// Open the eye dropper
+    pub open: OpenFn,
+
+    /// Close the eye dropper without picking a color
+    pub abort: AbortFn,
+}
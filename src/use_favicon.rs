@@ -56,6 +56,67 @@ use wasm_bindgen::JsCast;
 /// # }
 /// ```
 ///
+/// ## Badges
+///
+/// With the option `badge` a counter or a dot is drawn onto the current favicon. This is done on an
+/// offscreen canvas so it works with any image format the browser can decode.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_favicon_with_options, FaviconBadge, UseFaviconOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// #
+/// let (unread, set_unread) = signal(3);
+///
+/// use_favicon_with_options(
+///     UseFaviconOptions::default()
+///         .badge(Signal::derive(move || Some(FaviconBadge::Count(unread.get()))))
+///         .badge_color("#2563eb"),
+/// );
+/// #
+/// #    view! { }
+/// # }
+/// ```
+///
+/// A `FaviconBadge::Count(0)` doesn't draw anything and counts above 99 are shown as "99+".
+/// Please note that icons from other origins can only be drawn if they are served with CORS headers.
+/// Otherwise the icon is shown without the badge.
+///
+/// ## Animation
+///
+/// Pass a list of icons as `animation` to cycle through them every `animation_interval` milliseconds.
+/// While the list is not empty it takes precedence over the icon set with the returned setter.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_favicon_with_options, UseFaviconOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// #
+/// let (is_recording, set_recording) = signal(true);
+///
+/// use_favicon_with_options(
+///     UseFaviconOptions::default()
+///         .animation(Signal::derive(move || {
+///             if is_recording.get() {
+///                 vec!["record-on.png".to_string(), "record-off.png".to_string()]
+///             } else {
+///                 vec![]
+///             }
+///         }))
+///         .animation_interval(800),
+/// );
+/// #
+/// #    view! { }
+/// # }
+/// ```
+///
+/// By default the last icon is kept when the component is cleaned up. Set the option
+/// `restore_on_cleanup` to `true` to restore the original favicon of the page instead.
+///
 /// ## Server-Side Rendering
 ///
 /// On the server only the signals work but no favicon will be changed obviously.
@@ -71,45 +132,249 @@ pub fn use_favicon_with_options(
         new_icon,
         base_url,
         rel,
+        badge,
+        badge_color,
+        badge_text_color,
+        animation,
+        animation_interval,
+        restore_on_cleanup,
     } = options;
 
     let (favicon, set_favicon) = new_icon.into_signal();
 
     cfg_if! { if #[cfg(not(feature = "ssr"))] {
+        use crate::utils::Pausable;
+        use crate::{use_interval_fn_with_options, UseIntervalFnOptions};
+        use crate::sendwrap_fn;
+        use std::cell::Cell;
+        use std::rc::Rc;
+
         let link_selector = format!("link[rel*=\"{rel}\"]");
 
-        let apply_icon = move |icon: &String| {
-            if let Some(head) = document().head() {
-                if let Ok(links) = head.query_selector_all(&link_selector) {
-                    let href = format!("{base_url}{icon}");
+        let links = move || {
+            let mut links = vec![];
 
-                    for i in 0..links.length() {
-                        let node = links.get(i).expect("checked length");
-                        let link: web_sys::HtmlLinkElement = node.unchecked_into();
-                        link.set_href(&href);
+            if let Some(head) = document().head() {
+                if let Ok(nodes) = head.query_selector_all(&link_selector) {
+                    for i in 0..nodes.length() {
+                        let node = nodes.get(i).expect("checked length");
+                        links.push(node.unchecked_into::<web_sys::HtmlLinkElement>());
                     }
                 }
             }
+
+            links
         };
 
+        let original_hrefs = links()
+            .into_iter()
+            .map(|link| {
+                let href = link.get_attribute("href");
+                (link, href)
+            })
+            .collect::<Vec<_>>();
+
+        let original_icon = original_hrefs.iter().find_map(|(_, href)| href.clone());
+
+        let apply_href = move |href: &str| {
+            for link in links() {
+                link.set_href(href);
+            }
+        };
+
+        let (frame, set_frame) = signal(0_usize);
+
+        let Pausable { pause, resume, .. } = use_interval_fn_with_options(
+            move || set_frame.update(|frame| *frame += 1),
+            animation_interval,
+            UseIntervalFnOptions::default().immediate(false),
+        );
+
+        Effect::watch(
+            move || animation.with(|frames| frames.len()),
+            move |len, _, _| {
+                set_frame.set(0);
+
+                if *len > 1 {
+                    resume();
+                } else {
+                    pause();
+                }
+            },
+            true,
+        );
+
+        let icon = Signal::derive(move || {
+            animation.with(|frames| {
+                if frames.is_empty() {
+                    favicon.get()
+                } else {
+                    Some(frames[frame.get() % frames.len()].clone())
+                }
+            })
+        });
+
+        // makes sure that a badge that finishes rendering late doesn't overwrite a newer icon
+        let generation = Rc::new(Cell::new(0_usize));
+
         Effect::watch(
-                        move || favicon.get(),
-            move |new_icon, prev_icon, _| {
-                if Some(new_icon) != prev_icon {
-                    if let Some(new_icon) = new_icon {
-                        apply_icon(new_icon);
+            move || (icon.get(), badge.get()),
+            move |(icon, badge), prev, _| {
+                // leave the icon of the page alone until there is something to show
+                if prev.is_none() && icon.is_none() && badge.is_none() {
+                    return;
+                }
+
+                let href = match icon {
+                    Some(icon) => format!("{base_url}{icon}"),
+                    None => match &original_icon {
+                        Some(href) => href.clone(),
+                        None => return,
+                    },
+                };
+
+                let current_generation = generation.get() + 1;
+                generation.set(current_generation);
+
+                match badge {
+                    None | Some(FaviconBadge::Count(0)) => apply_href(&href),
+                    Some(badge) => {
+                        let generation = Rc::clone(&generation);
+                        let apply_href = apply_href.clone();
+
+                        render_badge(
+                            href,
+                            *badge,
+                            badge_color.clone(),
+                            badge_text_color.clone(),
+                            move |href| {
+                                if generation.get() == current_generation {
+                                    apply_href(&href);
+                                }
+                            },
+                        );
                     }
                 }
             },
-            false,
+            true,
         );
+
+        if restore_on_cleanup {
+            on_cleanup(sendwrap_fn!(move || {
+                for (link, href) in &original_hrefs {
+                    let _ = match href {
+                        Some(href) => link.set_attribute("href", href),
+                        None => link.remove_attribute("href"),
+                    };
+                }
+            }));
+        }
     }}
 
     (favicon, set_favicon)
 }
 
+/// Draws the badge onto the icon at `href` and calls `on_done` with the resulting data URL.
+/// If the icon can't be drawn (e.g. because it's from another origin without CORS headers), `on_done` is
+/// called with the original `href`.
+#[cfg(not(feature = "ssr"))]
+fn render_badge(
+    href: String,
+    badge: FaviconBadge,
+    color: String,
+    text_color: String,
+    on_done: impl FnOnce(String) + 'static,
+) {
+    use wasm_bindgen_futures::JsFuture;
+
+    let Ok(img) = web_sys::HtmlImageElement::new() else {
+        on_done(href);
+        return;
+    };
+
+    img.set_cross_origin(Some("anonymous"));
+    img.set_src(&href);
+
+    leptos::task::spawn_local(async move {
+        let data_url = match JsFuture::from(img.decode()).await {
+            Ok(_) => draw_badge(&img, badge, &color, &text_color),
+            Err(_) => None,
+        };
+
+        on_done(data_url.unwrap_or(href));
+    });
+}
+
+#[cfg(not(feature = "ssr"))]
+fn draw_badge(
+    img: &web_sys::HtmlImageElement,
+    badge: FaviconBadge,
+    color: &str,
+    text_color: &str,
+) -> Option<String> {
+    use std::f64::consts::TAU;
+
+    const SIZE: f64 = 64.0;
+
+    let canvas = document()
+        .create_element("canvas")
+        .ok()?
+        .unchecked_into::<web_sys::HtmlCanvasElement>();
+    canvas.set_width(SIZE as u32);
+    canvas.set_height(SIZE as u32);
+
+    let ctx = canvas
+        .get_context("2d")
+        .ok()??
+        .unchecked_into::<web_sys::CanvasRenderingContext2d>();
+
+    ctx.draw_image_with_html_image_element_and_dw_and_dh(img, 0.0, 0.0, SIZE, SIZE)
+        .ok()?;
+
+    ctx.set_fill_style_str(color);
+    ctx.begin_path();
+
+    match badge {
+        FaviconBadge::Dot => {
+            ctx.arc(SIZE - 16.0, 16.0, 14.0, 0.0, TAU).ok()?;
+            ctx.fill();
+        }
+        FaviconBadge::Count(count) => {
+            let radius = 22.0;
+            ctx.arc(SIZE - radius, radius, radius, 0.0, TAU).ok()?;
+            ctx.fill();
+
+            let text = if count > 99 {
+                "99+".to_string()
+            } else {
+                count.to_string()
+            };
+            let font_size = if text.len() > 2 { 20 } else { 30 };
+
+            ctx.set_fill_style_str(text_color);
+            ctx.set_font(&format!("bold {font_size}px sans-serif"));
+            ctx.set_text_align("center");
+            ctx.set_text_baseline("middle");
+            ctx.fill_text(&text, SIZE - radius, radius + 1.0).ok()?;
+        }
+    }
+
+    // fails if the canvas is tainted by an icon from another origin
+    canvas.to_data_url().ok()
+}
+
+/// Badge that is drawn onto the favicon by [`use_favicon_with_options`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FaviconBadge {
+    /// A small dot in the top right corner
+    Dot,
+    /// A counter in the top right corner. `0` draws nothing and counts above 99 are shown as "99+".
+    Count(usize),
+}
+
 /// Options for [`use_favicon_with_options`].
 #[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
 pub struct UseFaviconOptions {
     /// New input favicon. Can be a `RwSignal` in which case updates will change the favicon. Defaults to None.
     #[builder(into)]
@@ -121,6 +386,28 @@ pub struct UseFaviconOptions {
     /// Rel attribute of the <link> tag. Defaults to "icon".
     #[builder(into)]
     rel: String,
+
+    /// Badge that is drawn onto the favicon. Defaults to `None`.
+    #[builder(into)]
+    badge: Signal<Option<FaviconBadge>>,
+
+    /// CSS color of the badge. Defaults to "#ef4444".
+    #[builder(into)]
+    badge_color: String,
+
+    /// CSS color of the counter text of the badge. Defaults to "#ffffff".
+    #[builder(into)]
+    badge_text_color: String,
+
+    /// Icons to cycle through. While not empty this takes precedence over the icon. Defaults to `[]`.
+    #[builder(into)]
+    animation: Signal<Vec<String>>,
+
+    /// Milliseconds between two icons of the `animation`. Defaults to `500`.
+    animation_interval: u64,
+
+    /// Restore the original favicon of the page when the component is cleaned up. Defaults to `false`.
+    restore_on_cleanup: bool,
 }
 
 impl Default for UseFaviconOptions {
@@ -129,6 +416,12 @@ impl Default for UseFaviconOptions {
             new_icon: Default::default(),
             base_url: "".to_string(),
             rel: "icon".to_string(),
+            badge: Signal::default(),
+            badge_color: "#ef4444".to_string(),
+            badge_text_color: "#ffffff".to_string(),
+            animation: Signal::default(),
+            animation_interval: 500,
+            restore_on_cleanup: false,
         }
     }
 }
@@ -0,0 +1,638 @@
+use crate::core::{Direction, IntoElementMaybeSignal};
+use cfg_if::cfg_if;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+cfg_if! { if #[cfg(not(feature = "ssr"))] {
+    use crate::{
+        sendwrap_fn, use_event_listener_with_options, use_resize_observer, use_window,
+        UseEventListenerOptions,
+    };
+    use leptos::ev::{resize, scroll};
+    use wasm_bindgen::JsCast;
+}}
+
+/// Positions a floating element (tooltip, popover, dropdown, ...) next to an anchor element.
+///
+/// Inspired by [Floating UI](https://floating-ui.com/). The coordinates of the floating element are
+/// computed from the bounding boxes of both elements and the chosen `placement` and are recomputed
+/// whenever the page is scrolled or resized or one of the elements changes its size.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_floating)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::{Button, Div};
+/// # use leptos_use::{use_floating, UseFloatingReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let anchor = NodeRef::<Button>::new();
+/// let floating = NodeRef::<Div>::new();
+///
+/// let UseFloatingReturn { floating_styles, .. } = use_floating(anchor, floating);
+///
+/// view! {
+///     <button node_ref=anchor>"Hover me"</button>
+///     <div node_ref=floating style=floating_styles>"Tooltip"</div>
+/// }
+/// # }
+/// ```
+///
+/// The returned `floating_styles` contain `position`, `left` and `top`. If you want to apply the
+/// position yourself (for example with a transform), use the returned `x` and `y` signals instead.
+///
+/// ## Placement and Middleware
+///
+/// With the option `placement` you choose on which side of the anchor the floating element is placed
+/// and how it is aligned. The final placement can differ from the requested one if `flip` is enabled and
+/// is returned as `placement`.
+///
+/// * `offset`: Distance in px between the anchor and the floating element.
+/// * `flip`: If the floating element doesn't fit on the chosen side it is placed on the opposite side.
+/// * `shift`: The floating element is shifted along the side of the anchor to keep it inside the viewport.
+/// * `padding`: Minimum distance in px to the edges of the viewport used by `flip` and `shift`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::{Button, Div};
+/// # use leptos_use::{use_floating_with_options, Placement, UseFloatingOptions, UseFloatingReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let anchor = NodeRef::<Button>::new();
+/// let floating = NodeRef::<Div>::new();
+///
+/// let UseFloatingReturn { x, y, placement, .. } = use_floating_with_options(
+///     anchor,
+///     floating,
+///     UseFloatingOptions::default()
+///         .placement(Placement::TopStart)
+///         .offset(8.0)
+///         .flip(true)
+///         .shift(true)
+///         .padding(4.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Arrow
+///
+/// If you provide an `arrow` element, the returned `arrow_x` (for placements on the top or bottom) or
+/// `arrow_y` (for placements on the left or right) contain the offset of the arrow inside of the floating
+/// element such that it points to the center of the anchor.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::{Button, Div};
+/// # use leptos_use::{use_floating_with_options, UseFloatingOptions, UseFloatingReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let anchor = NodeRef::<Button>::new();
+/// let floating = NodeRef::<Div>::new();
+/// let arrow = NodeRef::<Div>::new();
+///
+/// let UseFloatingReturn {
+///     floating_styles,
+///     arrow_x,
+///     ..
+/// } = use_floating_with_options(
+///     anchor,
+///     floating,
+///     UseFloatingOptions::default().arrow(Some(arrow)).offset(10.0),
+/// );
+///
+/// view! {
+///     <button node_ref=anchor>"Anchor"</button>
+///     <div node_ref=floating style=floating_styles>
+///         "Tooltip"
+///         <div
+///             node_ref=arrow
+///             style:position="absolute"
+///             style:top="-4px"
+///             style:left=move || format!("{}px", arrow_x.get().unwrap_or_default())
+///         ></div>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `update` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_floating`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned coordinates are always `0.0` and `update` is a no-op.
+pub fn use_floating<AnchorEl, AnchorM, FloatingEl, FloatingM>(
+    anchor: AnchorEl,
+    floating: FloatingEl,
+) -> UseFloatingReturn<impl Fn() + Clone + Send + Sync>
+where
+    AnchorEl: IntoElementMaybeSignal<web_sys::Element, AnchorM>,
+    FloatingEl: IntoElementMaybeSignal<web_sys::Element, FloatingM>,
+{
+    use_floating_with_options::<AnchorEl, AnchorM, FloatingEl, FloatingM, web_sys::Element, _>(
+        anchor,
+        floating,
+        UseFloatingOptions::default(),
+    )
+}
+
+/// Version of [`use_floating`] that takes a `UseFloatingOptions`. See [`use_floating`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_floating_with_options<AnchorEl, AnchorM, FloatingEl, FloatingM, ArrowEl, ArrowM>(
+    anchor: AnchorEl,
+    floating: FloatingEl,
+    options: UseFloatingOptions<ArrowEl, ArrowM>,
+) -> UseFloatingReturn<impl Fn() + Clone + Send + Sync>
+where
+    AnchorEl: IntoElementMaybeSignal<web_sys::Element, AnchorM>,
+    FloatingEl: IntoElementMaybeSignal<web_sys::Element, FloatingM>,
+    ArrowEl: IntoElementMaybeSignal<web_sys::Element, ArrowM>,
+{
+    let UseFloatingOptions {
+        placement,
+        strategy,
+        offset,
+        flip,
+        shift,
+        padding,
+        arrow,
+        ..
+    } = options;
+
+    let (position, set_position) = signal(FloatingPosition {
+        x: 0.0,
+        y: 0.0,
+        placement: placement.get_untracked(),
+        arrow_x: None,
+        arrow_y: None,
+    });
+    let (is_positioned, set_positioned) = signal(false);
+
+    let update;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_position;
+        let _ = set_positioned;
+
+        update = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let anchor = anchor.into_element_maybe_signal();
+        let floating = floating.into_element_maybe_signal();
+        let arrow = arrow.map(|arrow| arrow.into_element_maybe_signal());
+
+        update = {
+            let anchor = anchor.clone();
+            let floating = floating.clone();
+
+            sendwrap_fn!(move || {
+                let (Some(anchor), Some(floating)) =
+                    (anchor.get_untracked(), floating.get_untracked())
+                else {
+                    set_positioned.set(false);
+                    return;
+                };
+
+                let arrow = arrow.as_ref().and_then(|arrow| arrow.get_untracked());
+
+                let new_position = compute_position(
+                    &anchor,
+                    &floating,
+                    arrow.as_ref(),
+                    ComputeOptions {
+                        placement: placement.get_untracked(),
+                        strategy,
+                        offset,
+                        flip,
+                        shift,
+                        padding,
+                    },
+                );
+
+                if position.with_untracked(|position| position != &new_position) {
+                    set_position.set(new_position);
+                }
+                set_positioned.set(true);
+            })
+        };
+
+        let _ = use_resize_observer(anchor.clone(), {
+            let update = update.clone();
+            move |_, _| update()
+        });
+
+        let _ = use_resize_observer(floating.clone(), {
+            let update = update.clone();
+            move |_, _| update()
+        });
+
+        let _ = use_event_listener_with_options(
+            use_window(),
+            scroll,
+            {
+                let update = update.clone();
+                move |_| update()
+            },
+            UseEventListenerOptions::default()
+                .capture(true)
+                .passive(true),
+        );
+
+        let _ = use_event_listener_with_options(
+            use_window(),
+            resize,
+            {
+                let update = update.clone();
+                move |_| update()
+            },
+            UseEventListenerOptions::default().passive(true),
+        );
+
+        Effect::watch(
+            move || (anchor.get(), floating.get(), placement.get()),
+            {
+                let update = update.clone();
+                move |_, _, _| update()
+            },
+            true,
+        );
+    }
+
+    let strategy_name = strategy.to_string();
+
+    UseFloatingReturn {
+        x: Signal::derive(move || position.get().x),
+        y: Signal::derive(move || position.get().y),
+        placement: Signal::derive(move || position.get().placement),
+        arrow_x: Signal::derive(move || position.get().arrow_x),
+        arrow_y: Signal::derive(move || position.get().arrow_y),
+        floating_styles: Signal::derive(move || {
+            position.with(|position| {
+                format!(
+                    "position: {}; left: {}px; top: {}px;",
+                    strategy_name, position.x, position.y
+                )
+            })
+        }),
+        is_positioned: is_positioned.into(),
+        update,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct FloatingPosition {
+    x: f64,
+    y: f64,
+    placement: Placement,
+    arrow_x: Option<f64>,
+    arrow_y: Option<f64>,
+}
+
+#[cfg(not(feature = "ssr"))]
+struct ComputeOptions {
+    placement: Placement,
+    strategy: FloatingStrategy,
+    offset: f64,
+    flip: bool,
+    shift: bool,
+    padding: f64,
+}
+
+#[cfg(not(feature = "ssr"))]
+fn compute_position(
+    anchor: &web_sys::Element,
+    floating: &web_sys::Element,
+    arrow: Option<&web_sys::Element>,
+    options: ComputeOptions,
+) -> FloatingPosition {
+    let ComputeOptions {
+        mut placement,
+        strategy,
+        offset,
+        flip,
+        shift,
+        padding,
+    } = options;
+
+    let anchor_rect = anchor.get_bounding_client_rect();
+    let floating_rect = floating.get_bounding_client_rect();
+    let (floating_width, floating_height) = (floating_rect.width(), floating_rect.height());
+
+    let document_element = document().document_element();
+    let viewport_width = document_element
+        .as_ref()
+        .map(|el| el.client_width() as f64)
+        .unwrap_or_default();
+    let viewport_height = document_element
+        .as_ref()
+        .map(|el| el.client_height() as f64)
+        .unwrap_or_default();
+
+    // coordinates of the floating element in the viewport for a given placement
+    let coords = |placement: Placement| {
+        let cross_x = match placement.alignment() {
+            Alignment::Start => anchor_rect.left(),
+            Alignment::Center => anchor_rect.left() + (anchor_rect.width() - floating_width) / 2.0,
+            Alignment::End => anchor_rect.right() - floating_width,
+        };
+        let cross_y = match placement.alignment() {
+            Alignment::Start => anchor_rect.top(),
+            Alignment::Center => anchor_rect.top() + (anchor_rect.height() - floating_height) / 2.0,
+            Alignment::End => anchor_rect.bottom() - floating_height,
+        };
+
+        match placement.side() {
+            Direction::Top => (cross_x, anchor_rect.top() - floating_height - offset),
+            Direction::Bottom => (cross_x, anchor_rect.bottom() + offset),
+            Direction::Left => (anchor_rect.left() - floating_width - offset, cross_y),
+            Direction::Right => (anchor_rect.right() + offset, cross_y),
+        }
+    };
+
+    // how far the floating element overflows the viewport on its main side
+    let main_overflow = |placement: Placement, (x, y): (f64, f64)| match placement.side() {
+        Direction::Top => padding - y,
+        Direction::Bottom => y + floating_height - (viewport_height - padding),
+        Direction::Left => padding - x,
+        Direction::Right => x + floating_width - (viewport_width - padding),
+    };
+
+    let (mut x, mut y) = coords(placement);
+
+    if flip {
+        let overflow = main_overflow(placement, (x, y));
+
+        if overflow > 0.0 {
+            let flipped = placement.flipped();
+            let flipped_coords = coords(flipped);
+
+            if main_overflow(flipped, flipped_coords) < overflow {
+                placement = flipped;
+                (x, y) = flipped_coords;
+            }
+        }
+    }
+
+    if shift {
+        let clamp = |value: f64, size: f64, viewport_size: f64| {
+            value.min(viewport_size - padding - size).max(padding)
+        };
+
+        match placement.side() {
+            Direction::Top | Direction::Bottom => x = clamp(x, floating_width, viewport_width),
+            Direction::Left | Direction::Right => y = clamp(y, floating_height, viewport_height),
+        }
+    }
+
+    let (mut arrow_x, mut arrow_y) = (None, None);
+
+    if let Some(arrow) = arrow {
+        let arrow_rect = arrow.get_bounding_client_rect();
+
+        match placement.side() {
+            Direction::Top | Direction::Bottom => {
+                let center = anchor_rect.left() + anchor_rect.width() / 2.0 - x;
+                arrow_x = Some(
+                    (center - arrow_rect.width() / 2.0)
+                        .min(floating_width - arrow_rect.width())
+                        .max(0.0),
+                );
+            }
+            Direction::Left | Direction::Right => {
+                let center = anchor_rect.top() + anchor_rect.height() / 2.0 - y;
+                arrow_y = Some(
+                    (center - arrow_rect.height() / 2.0)
+                        .min(floating_height - arrow_rect.height())
+                        .max(0.0),
+                );
+            }
+        }
+    }
+
+    if strategy == FloatingStrategy::Absolute {
+        let (offset_x, offset_y) = containing_block_offset(floating);
+        x -= offset_x;
+        y -= offset_y;
+    }
+
+    FloatingPosition {
+        x,
+        y,
+        placement,
+        arrow_x,
+        arrow_y,
+    }
+}
+
+/// Viewport coordinates of the origin of the containing block of an absolutely positioned element.
+#[cfg(not(feature = "ssr"))]
+fn containing_block_offset(floating: &web_sys::Element) -> (f64, f64) {
+    let window = window();
+    let scroll_x = window.scroll_x().unwrap_or_default();
+    let scroll_y = window.scroll_y().unwrap_or_default();
+
+    let offset_parent = floating
+        .dyn_ref::<web_sys::HtmlElement>()
+        .and_then(|el| el.offset_parent());
+
+    let Some(offset_parent) = offset_parent else {
+        return (-scroll_x, -scroll_y);
+    };
+
+    // a statically positioned body is not a containing block. Then the initial containing block is used.
+    let is_static = window
+        .get_computed_style(&offset_parent)
+        .ok()
+        .flatten()
+        .and_then(|style| style.get_property_value("position").ok())
+        .map(|position| position == "static")
+        .unwrap_or(true);
+
+    if is_static {
+        return (-scroll_x, -scroll_y);
+    }
+
+    let rect = offset_parent.get_bounding_client_rect();
+
+    (
+        rect.left() + offset_parent.client_left() as f64 - offset_parent.scroll_left() as f64,
+        rect.top() + offset_parent.client_top() as f64 - offset_parent.scroll_top() as f64,
+    )
+}
+
+/// Options for [`use_floating_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseFloatingOptions<El, M>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    /// Where to place the floating element relative to the anchor. Defaults to `Placement::Bottom`.
+    #[builder(into)]
+    placement: Signal<Placement>,
+
+    /// The CSS `position` that is used for the floating element. Defaults to `FloatingStrategy::Absolute`.
+    strategy: FloatingStrategy,
+
+    /// Distance in px between the anchor and the floating element. Defaults to `0.0`.
+    offset: f64,
+
+    /// Place the floating element on the opposite side if it doesn't fit on the chosen side. Defaults to `false`.
+    flip: bool,
+
+    /// Shift the floating element along the anchor to keep it inside of the viewport. Defaults to `false`.
+    shift: bool,
+
+    /// Minimum distance in px to the edges of the viewport used by `flip` and `shift`. Defaults to `0.0`.
+    padding: f64,
+
+    /// Arrow element inside of the floating element. If provided, `arrow_x` or `arrow_y` is computed.
+    /// Defaults to `None`.
+    arrow: Option<El>,
+
+    #[builder(skip)]
+    _marker: PhantomData<M>,
+}
+
+impl<M> Default for UseFloatingOptions<web_sys::Element, M>
+where
+    web_sys::Element: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    fn default() -> Self {
+        Self {
+            placement: Placement::Bottom.into(),
+            strategy: FloatingStrategy::Absolute,
+            offset: 0.0,
+            flip: false,
+            shift: false,
+            padding: 0.0,
+            arrow: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Placement of the floating element relative to the anchor in [`use_floating`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub enum Placement {
+    Top,
+    TopStart,
+    TopEnd,
+    Right,
+    RightStart,
+    RightEnd,
+    #[default]
+    Bottom,
+    BottomStart,
+    BottomEnd,
+    Left,
+    LeftStart,
+    LeftEnd,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub(crate) enum Alignment {
+    Start,
+    Center,
+    End,
+}
+
+impl Placement {
+    /// The side of the anchor the floating element is placed on
+    pub fn side(&self) -> Direction {
+        match self {
+            Self::Top | Self::TopStart | Self::TopEnd => Direction::Top,
+            Self::Right | Self::RightStart | Self::RightEnd => Direction::Right,
+            Self::Bottom | Self::BottomStart | Self::BottomEnd => Direction::Bottom,
+            Self::Left | Self::LeftStart | Self::LeftEnd => Direction::Left,
+        }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    pub(crate) fn alignment(&self) -> Alignment {
+        match self {
+            Self::Top | Self::Right | Self::Bottom | Self::Left => Alignment::Center,
+            Self::TopStart | Self::RightStart | Self::BottomStart | Self::LeftStart => {
+                Alignment::Start
+            }
+            Self::TopEnd | Self::RightEnd | Self::BottomEnd | Self::LeftEnd => Alignment::End,
+        }
+    }
+
+    /// The placement on the opposite side with the same alignment
+    pub fn flipped(&self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::TopStart => Self::BottomStart,
+            Self::TopEnd => Self::BottomEnd,
+            Self::Right => Self::Left,
+            Self::RightStart => Self::LeftStart,
+            Self::RightEnd => Self::LeftEnd,
+            Self::Bottom => Self::Top,
+            Self::BottomStart => Self::TopStart,
+            Self::BottomEnd => Self::TopEnd,
+            Self::Left => Self::Right,
+            Self::LeftStart => Self::RightStart,
+            Self::LeftEnd => Self::RightEnd,
+        }
+    }
+}
+
+/// CSS `position` used for the floating element in [`use_floating`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum FloatingStrategy {
+    /// `position: absolute`. Coordinates are relative to the containing block of the floating element.
+    #[default]
+    Absolute,
+    /// `position: fixed`. Coordinates are relative to the viewport.
+    Fixed,
+}
+
+impl Display for FloatingStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Absolute => write!(f, "absolute"),
+            Self::Fixed => write!(f, "fixed"),
+        }
+    }
+}
+
+/// Return type of [`use_floating`].
+pub struct UseFloatingReturn<F>
+where
+    F: Fn() + Clone + Send + Sync,
+{
+    /// The x coordinate of the floating element (CSS `left`)
+    pub x: Signal<f64>,
+    /// The y coordinate of the floating element (CSS `top`)
+    pub y: Signal<f64>,
+    /// The final placement after `flip` has been applied
+    pub placement: Signal<Placement>,
+    /// Offset of the arrow from the left of the floating element. `Some` for top and bottom placements if an `arrow` is provided.
+    pub arrow_x: Signal<Option<f64>>,
+    /// Offset of the arrow from the top of the floating element. `Some` for left and right placements if an `arrow` is provided.
+    pub arrow_y: Signal<Option<f64>>,
+    /// CSS styles to apply to the floating element (`position`, `left` and `top`)
+    pub floating_styles: Signal<String>,
+    /// `true` as soon as both elements are mounted and the position has been computed.
+    pub is_positioned: Signal<bool>,
+    /// Recompute the position manually
+    pub update: F,
+}
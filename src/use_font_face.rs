@@ -0,0 +1,311 @@
+use crate::core::LoadStatus;
+use crate::utils::LocalBoxFuture;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Load web fonts with the [CSS Font Loading API](https://developer.mozilla.org/en-US/docs/Web/API/CSS_Font_Loading_API)
+/// and track their loading status.
+///
+/// Fonts can either be loaded from a URL with [`FontFaceSource::url`] or, if they are already declared with
+/// `@font-face` in a stylesheet, by their family name with [`FontFaceSource::named`].
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_font_face)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_font_face, FontFaceSource, UseFontFaceReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFontFaceReturn { is_loaded, fonts, .. } = use_font_face(vec![
+///     FontFaceSource::url("Inter", "/fonts/inter.woff2"),
+///     FontFaceSource::url("Inter", "/fonts/inter-bold.woff2").weight("700"),
+///     FontFaceSource::named("Roboto"),
+/// ]);
+///
+/// view! {
+///     <p style:font-family=move || if is_loaded.get() { "Inter" } else { "sans-serif" }>
+///         "Hello World"
+///     </p>
+/// }
+/// # }
+/// ```
+///
+/// The returned `status` is the overall status of all fonts. It's `LoadStatus::Error` as soon as one of the fonts
+/// failed to load and `LoadStatus::Loaded` once all fonts have loaded. `fonts` contains the family and status
+/// of every font in the same order as they were passed in.
+///
+/// ## Waiting for the Fonts
+///
+/// For layout-sensitive rendering (e.g. measuring text) you can wait for the fonts with the `ready` future.
+/// It resolves with the overall status once all fonts have finished loading (successfully or not).
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_font_face, FontFaceSource, UseFontFaceReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFontFaceReturn { ready, .. } = use_font_face(vec![FontFaceSource::named("Roboto")]);
+///
+/// spawn_local(async move {
+///     let status = ready().await;
+///     log!("Fonts finished loading: {status:?}");
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `ready` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_font_face`.
+///
+/// Fonts that have been loaded from a URL are removed from `document.fonts` when the component is cleaned up.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no fonts are loaded, the statuses are always `LoadStatus::Idle` and `ready` resolves immediately.
+pub fn use_font_face(
+    sources: Vec<FontFaceSource>,
+) -> UseFontFaceReturn<impl Fn() -> FontFaceReadyFuture + Clone + Send + Sync> {
+    let (fonts, set_fonts) = signal(
+        sources
+            .iter()
+            .map(|source| (source.family.clone(), LoadStatus::Idle))
+            .collect::<Vec<_>>(),
+    );
+
+    let status = Signal::derive(move || {
+        fonts.with(|fonts| overall_status(fonts.iter().map(|(_, status)| *status)))
+    });
+
+    #[cfg(feature = "ssr")]
+    let ready = {
+        let _ = set_fonts;
+        let _ = sources;
+
+        move || -> FontFaceReadyFuture { Box::pin(std::future::ready(status.get_untracked())) }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    let ready = {
+        use crate::sendwrap_fn;
+        use wasm_bindgen_futures::JsFuture;
+
+        let font_set = document().fonts();
+
+        // `None` if loading the font couldn't be started
+        let mut promises = Vec::<Option<js_sys::Promise>>::new();
+        // faces that have been added to `document.fonts` by this function
+        let mut added_faces = Vec::<web_sys::FontFace>::new();
+
+        for (index, source) in sources.into_iter().enumerate() {
+            let Some((promise, face)) = source.load(&font_set) else {
+                set_fonts.update(|fonts| fonts[index].1 = LoadStatus::Error);
+                promises.push(None);
+                continue;
+            };
+
+            promises.push(Some(promise.clone()));
+            added_faces.extend(face);
+            set_fonts.update(|fonts| fonts[index].1 = LoadStatus::Loading);
+
+            leptos::task::spawn_local(async move {
+                let status = font_status(JsFuture::from(promise).await);
+                set_fonts.try_update(|fonts| fonts[index].1 = status);
+            });
+        }
+
+        on_cleanup({
+            let font_set = font_set.clone();
+
+            sendwrap_fn!(move || {
+                for face in &added_faces {
+                    font_set.delete(face);
+                }
+            })
+        });
+
+        sendwrap_fn!(move || -> FontFaceReadyFuture {
+            let promises = promises.clone();
+
+            Box::pin(async move {
+                let mut statuses = Vec::with_capacity(promises.len());
+
+                for promise in promises {
+                    statuses.push(match promise {
+                        Some(promise) => font_status(JsFuture::from(promise).await),
+                        None => LoadStatus::Error,
+                    });
+                }
+
+                overall_status(statuses.into_iter())
+            })
+        })
+    };
+
+    UseFontFaceReturn {
+        status,
+        is_loading: Signal::derive(move || status.get() == LoadStatus::Loading),
+        is_loaded: Signal::derive(move || status.get() == LoadStatus::Loaded),
+        is_error: Signal::derive(move || status.get() == LoadStatus::Error),
+        fonts: fonts.into(),
+        ready,
+    }
+}
+
+/// A font to load with [`use_font_face`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FontFaceSource {
+    family: String,
+    url: Option<String>,
+    weight: Option<String>,
+    style: Option<String>,
+}
+
+impl FontFaceSource {
+    /// A font that is loaded from the given URL and added to `document.fonts` with the given family name.
+    pub fn url(family: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            family: family.into(),
+            url: Some(url.into()),
+            weight: None,
+            style: None,
+        }
+    }
+
+    /// A font that is already declared with `@font-face` in a stylesheet.
+    pub fn named(family: impl Into<String>) -> Self {
+        Self {
+            family: family.into(),
+            url: None,
+            weight: None,
+            style: None,
+        }
+    }
+
+    /// The `font-weight` of the font like `"700"` or `"bold"`.
+    pub fn weight(self, weight: impl Into<String>) -> Self {
+        Self {
+            weight: Some(weight.into()),
+            ..self
+        }
+    }
+
+    /// The `font-style` of the font like `"italic"`.
+    pub fn style(self, style: impl Into<String>) -> Self {
+        Self {
+            style: Some(style.into()),
+            ..self
+        }
+    }
+
+    /// Starts loading the font. Returns the promise of the loading and the face if it has been added to `font_set`.
+    #[cfg(not(feature = "ssr"))]
+    fn load(
+        &self,
+        font_set: &web_sys::FontFaceSet,
+    ) -> Option<(js_sys::Promise, Option<web_sys::FontFace>)> {
+        match &self.url {
+            Some(url) => {
+                let descriptors = web_sys::FontFaceDescriptors::new();
+                if let Some(weight) = &self.weight {
+                    descriptors.set_weight(weight);
+                }
+                if let Some(style) = &self.style {
+                    descriptors.set_style(style);
+                }
+
+                let face = web_sys::FontFace::new_with_str_and_descriptors(
+                    &self.family,
+                    &format!("url({url})"),
+                    &descriptors,
+                )
+                .ok()?;
+
+                font_set.add(&face).ok()?;
+                let promise = face.load().ok()?;
+
+                Some((promise, Some(face)))
+            }
+            None => {
+                let font = format!(
+                    "{} {} 1em \"{}\"",
+                    self.style.as_deref().unwrap_or("normal"),
+                    self.weight.as_deref().unwrap_or("normal"),
+                    self.family
+                );
+
+                Some((font_set.load(&font), None))
+            }
+        }
+    }
+}
+
+/// Status of a single font from the result of its loading promise.
+#[cfg(not(feature = "ssr"))]
+fn font_status(result: Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue>) -> LoadStatus {
+    match result {
+        // loading by name resolves with an empty list if no matching font exists
+        Ok(faces) if js_sys::Array::is_array(&faces) => {
+            if js_sys::Array::from(&faces).length() > 0 {
+                LoadStatus::Loaded
+            } else {
+                LoadStatus::Error
+            }
+        }
+        Ok(_) => LoadStatus::Loaded,
+        Err(_) => LoadStatus::Error,
+    }
+}
+
+/// Overall status of all fonts.
+fn overall_status(statuses: impl Iterator<Item = LoadStatus> + Clone) -> LoadStatus {
+    if statuses.clone().any(|status| status == LoadStatus::Error) {
+        LoadStatus::Error
+    } else if statuses.clone().any(|status| status == LoadStatus::Loading) {
+        LoadStatus::Loading
+    } else if statuses.clone().next().is_some()
+        && statuses.clone().all(|status| status == LoadStatus::Loaded)
+    {
+        LoadStatus::Loaded
+    } else {
+        LoadStatus::Idle
+    }
+}
+
+/// Future returned by `ready` of [`use_font_face`]. Resolves with the overall status once all fonts
+/// have finished loading.
+pub type FontFaceReadyFuture = LocalBoxFuture<LoadStatus>;
+
+/// Return type of [`use_font_face`].
+pub struct UseFontFaceReturn<ReadyFn>
+where
+    ReadyFn: Fn() -> FontFaceReadyFuture + Clone + Send + Sync,
+{
+    /// The overall loading status of all fonts
+    pub status: Signal<LoadStatus>,
+
+    /// `true` while any of the fonts are still loading
+    pub is_loading: Signal<bool>,
+
+    /// `true` once all fonts have loaded
+    pub is_loaded: Signal<bool>,
+
+    /// `true` if any of the fonts failed to load
+    pub is_error: Signal<bool>,
+
+    /// Family and loading status of every font in the order they were passed in
+    pub fonts: Signal<Vec<(String, LoadStatus)>>,
+
+    /// Returns a future that resolves with the overall status once all fonts have finished loading
+    pub ready: ReadyFn,
+}
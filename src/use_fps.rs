@@ -0,0 +1,175 @@
+use crate::utils::Pausable;
+use crate::{use_raf_fn_with_options, UseRafFnCallbackArgs, UseRafFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// Reactive frames per second.
+///
+/// Measures the frame rate with `requestAnimationFrame` over a sliding window of the most recent frames.
+/// Useful for performance overlays or to reduce the quality of animations when the page can't keep up.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_fps)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_fps, UseFpsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFpsReturn { fps, is_janky, .. } = use_fps();
+///
+/// view! {
+///     <div class:text-red-500=is_janky>"FPS: " {move || fps.get().round()}</div>
+/// }
+/// # }
+/// ```
+///
+/// ## Options
+///
+/// To not rerender on every frame `fps` is only updated every `every` frames (defaults to `10`).
+/// The average is taken over the last `window_size` frames (defaults to `60`).
+/// `is_janky` is `true` while `fps` is below `janky_threshold` (defaults to `30.0`).
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_fps_with_options, UseFpsOptions, UseFpsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFpsReturn { fps, is_janky, .. } = use_fps_with_options(
+///     UseFpsOptions::default().every(30).window_size(120).janky_threshold(50.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `pause` and `resume` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_fps`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `fps` is always `0.0` and `is_janky` is always `false`.
+pub fn use_fps() -> UseFpsReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_fps_with_options(UseFpsOptions::default())
+}
+
+/// Version of [`use_fps`] that takes a `UseFpsOptions`. See [`use_fps`] for how to use.
+pub fn use_fps_with_options(
+    options: UseFpsOptions,
+) -> UseFpsReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseFpsOptions {
+        every,
+        window_size,
+        janky_threshold,
+        immediate,
+    } = options;
+
+    let (fps, set_fps) = signal(0.0);
+
+    let deltas = Rc::new(RefCell::new(VecDeque::<f64>::with_capacity(window_size)));
+    let frame_count = Rc::new(Cell::new(0_usize));
+
+    let Pausable {
+        pause,
+        resume,
+        is_active,
+    } = use_raf_fn_with_options(
+        move |UseRafFnCallbackArgs { delta, .. }| {
+            if delta <= 0.0 {
+                return;
+            }
+
+            let mut deltas = deltas.borrow_mut();
+            if deltas.len() >= window_size.max(1) {
+                deltas.pop_front();
+            }
+            deltas.push_back(delta);
+
+            let count = frame_count.get() + 1;
+
+            if count < every.max(1) {
+                frame_count.set(count);
+            } else {
+                frame_count.set(0);
+
+                let average_delta = deltas.iter().sum::<f64>() / deltas.len() as f64;
+                set_fps.set(1000.0 / average_delta);
+            }
+        },
+        UseRafFnOptions::default().immediate(immediate),
+    );
+
+    let is_janky = Signal::derive(move || {
+        let fps = fps.get();
+        fps > 0.0 && fps < janky_threshold
+    });
+
+    UseFpsReturn {
+        fps: fps.into(),
+        is_janky,
+        is_active,
+        pause,
+        resume,
+    }
+}
+
+/// Options for [`use_fps_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseFpsOptions {
+    /// Update `fps` every this many frames. Defaults to `10`.
+    every: usize,
+
+    /// Number of most recent frames that are averaged. Defaults to `60`.
+    window_size: usize,
+
+    /// `is_janky` is `true` while `fps` is below this value. Defaults to `30.0`.
+    janky_threshold: f64,
+
+    /// Start measuring immediately. Defaults to `true`.
+    /// If `false` measuring only starts when you call `resume()`.
+    immediate: bool,
+}
+
+impl Default for UseFpsOptions {
+    fn default() -> Self {
+        Self {
+            every: 10,
+            window_size: 60,
+            janky_threshold: 30.0,
+            immediate: true,
+        }
+    }
+}
+
+/// Return type of [`use_fps`].
+pub struct UseFpsReturn<PauseFn, ResumeFn>
+where
+    PauseFn: Fn() + Clone + Send + Sync,
+    ResumeFn: Fn() + Clone + Send + Sync,
+{
+    /// Average frames per second over the last `window_size` frames
+    pub fps: Signal<f64>,
+
+    /// `true` while `fps` is below `janky_threshold`
+    pub is_janky: Signal<bool>,
+
+    /// Whether the measurement is running
+    pub is_active: Signal<bool>,
+
+    /// Pause measuring
+    pub pause: PauseFn,
+
+    /// Resume measuring
+    pub resume: ResumeFn,
+}
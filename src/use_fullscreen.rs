@@ -0,0 +1,476 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::use_supported;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use std::fmt::Display;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive [Fullscreen API](https://developer.mozilla.org/en-US/docs/Web/API/Fullscreen_API).
+///
+/// Shows an element fullscreen. Vendor-prefixed versions of the API are used in browsers
+/// that don't support the standard one, like Safari on older iPads.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_fullscreen)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_fullscreen, UseFullscreenReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseFullscreenReturn { is_fullscreen, toggle, .. } = use_fullscreen(el);
+///
+/// view! {
+///     <div node_ref=el>
+///         <button on:click=move |_| toggle()>
+///             {move || if is_fullscreen.get() { "Exit fullscreen" } else { "Go fullscreen" }}
+///         </button>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// Use `"html"` as the target to show the whole page fullscreen. Entering fullscreen has to be triggered by
+/// a user interaction like a click. If the browser denies the request, `error` is set.
+///
+/// `is_fullscreen` is only `true` if the target is the fullscreen element. `fullscreen_element` contains
+/// whichever element of the document is currently fullscreen, if any.
+///
+/// ## Orientation lock
+///
+/// On mobile devices the screen orientation can be locked while the target is fullscreen, e.g. to
+/// play a landscape game or video. The orientation is unlocked when fullscreen is exited.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Canvas;
+/// # use leptos_use::{use_fullscreen_with_options, OrientationLock, UseFullscreenOptions, UseFullscreenReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let game = NodeRef::<Canvas>::new();
+///
+/// let UseFullscreenReturn { enter, error, .. } = use_fullscreen_with_options(
+///     game,
+///     UseFullscreenOptions::default().orientation_lock(OrientationLock::Landscape),
+/// );
+///
+/// view! {
+///     <canvas node_ref=game></canvas>
+///     <button on:click=move |_| enter()>"Play"</button>
+///     <p>{move || error.get().map(|err| err.to_string())}</p>
+/// }
+/// # }
+/// ```
+///
+/// Locking the orientation isn't supported by every browser, in particular not on desktops.
+/// In this case `error` is set to `UseFullscreenError::OrientationLockFailed` while the target stays fullscreen.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `enter`, `exit` and `toggle` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_fullscreen`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_fullscreen` are always `false`, `fullscreen_element` is `None` and
+/// the returned closures are no-ops.
+pub fn use_fullscreen<El, M>(
+    target: El,
+) -> UseFullscreenReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_fullscreen_with_options(target, UseFullscreenOptions::default())
+}
+
+/// Version of [`use_fullscreen`] that takes a `UseFullscreenOptions`. See [`use_fullscreen`] for how to use.
+pub fn use_fullscreen_with_options<El, M>(
+    target: El,
+    options: UseFullscreenOptions,
+) -> UseFullscreenReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseFullscreenOptions { orientation_lock } = options;
+
+    let target = target.into_element_maybe_signal();
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let document = document();
+
+            [
+                "fullscreenEnabled",
+                "webkitFullscreenEnabled",
+                "mozFullScreenEnabled",
+                "msFullscreenEnabled",
+            ]
+            .into_iter()
+            .any(|key| {
+                js_sys::Reflect::get(&document, &key.into())
+                    .map(|enabled| enabled.is_truthy())
+                    .unwrap_or_default()
+            })
+        }
+    });
+
+    let (fullscreen_element, set_fullscreen_element) = signal_local(None::<web_sys::Element>);
+    let (is_fullscreen, set_fullscreen) = signal(false);
+    let (error, set_error) = signal(None::<UseFullscreenError>);
+
+    let enter;
+    let exit;
+    let toggle;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = target;
+        let _ = set_fullscreen_element;
+        let _ = set_fullscreen;
+        let _ = set_error;
+
+        enter = || {};
+        exit = || {};
+        toggle = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js, js_fut, sendwrap_fn, use_event_listener};
+        use leptos::ev::Custom;
+        use wasm_bindgen::JsCast;
+
+        let on_change = {
+            let target = target.clone();
+
+            move || {
+                let element = current_fullscreen_element();
+                let was_fullscreen = is_fullscreen.get_untracked();
+                let is_target = element.is_some() && element == target.get_untracked();
+
+                set_fullscreen_element.set(element);
+                set_fullscreen.set(is_target);
+
+                let Some(orientation_lock) = orientation_lock else {
+                    return;
+                };
+
+                if is_target && !was_fullscreen {
+                    let window = window();
+
+                    let Ok(promise) = js!(window["screen"])
+                        .and_then(|screen| js!(screen["orientation"]))
+                        .and_then(|orientation| {
+                            js!(orientation["lock"]).and_then(|lock| {
+                                lock.unchecked_into::<js_sys::Function>()
+                                    .call1(&orientation, &orientation_lock.into())
+                            })
+                        })
+                        .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+                    else {
+                        set_error.set(Some(UseFullscreenError::OrientationLockFailed(
+                            SendWrapper::new(JsValue::UNDEFINED),
+                        )));
+                        return;
+                    };
+
+                    leptos::task::spawn_local(async move {
+                        if let Err(err) = js_fut!(promise).await {
+                            let _ = set_error.try_set(Some(
+                                UseFullscreenError::OrientationLockFailed(SendWrapper::new(err)),
+                            ));
+                        }
+                    });
+                } else if !is_target && was_fullscreen {
+                    let window = window();
+
+                    if let Ok(orientation) =
+                        js!(window["screen"]).and_then(|screen| js!(screen["orientation"]))
+                    {
+                        let _ = js!(orientation["unlock"]).and_then(|unlock| {
+                            unlock
+                                .unchecked_into::<js_sys::Function>()
+                                .call0(&orientation)
+                        });
+                    }
+                }
+            }
+        };
+
+        for event in [
+            "fullscreenchange",
+            "webkitfullscreenchange",
+            "mozfullscreenchange",
+            "MSFullscreenChange",
+        ] {
+            let on_change = on_change.clone();
+
+            let _ = use_event_listener(
+                document(),
+                Custom::<web_sys::Event>::new(event),
+                move |_| on_change(),
+            );
+        }
+
+        for event in [
+            "fullscreenerror",
+            "webkitfullscreenerror",
+            "mozfullscreenerror",
+            "MSFullscreenError",
+        ] {
+            let _ = use_event_listener(
+                document(),
+                Custom::<web_sys::Event>::new(event),
+                move |event| {
+                    set_error.set(Some(UseFullscreenError::Denied(SendWrapper::new(
+                        event.into(),
+                    ))))
+                },
+            );
+        }
+
+        on_change();
+
+        enter = sendwrap_fn!(move || {
+            if is_fullscreen.get_untracked() {
+                return;
+            }
+
+            let Some(el) = target.get_untracked() else {
+                return;
+            };
+
+            let Some(request) = find_method(
+                &el,
+                &[
+                    "requestFullscreen",
+                    "webkitRequestFullscreen",
+                    "webkitRequestFullScreen",
+                    "mozRequestFullScreen",
+                    "msRequestFullscreen",
+                ],
+            ) else {
+                set_error.set(Some(UseFullscreenError::NotSupported));
+                return;
+            };
+
+            set_error.set(None);
+
+            match request.call0(&el) {
+                // older, prefixed versions don't return a promise
+                Ok(result) => {
+                    if let Ok(promise) = result.dyn_into::<js_sys::Promise>() {
+                        leptos::task::spawn_local(async move {
+                            // the state is updated by the `fullscreenchange` event
+                            if let Err(err) = js_fut!(promise).await {
+                                let _ = set_error.try_set(Some(UseFullscreenError::Denied(
+                                    SendWrapper::new(err),
+                                )));
+                            }
+                        });
+                    }
+                }
+                Err(err) => {
+                    set_error.set(Some(UseFullscreenError::Denied(SendWrapper::new(err))));
+                }
+            }
+        });
+
+        exit = sendwrap_fn!(move || {
+            if !is_fullscreen.try_get_untracked().unwrap_or_default() {
+                return;
+            }
+
+            let document = document();
+
+            if let Some(exit) = find_method(
+                &document,
+                &[
+                    "exitFullscreen",
+                    "webkitExitFullscreen",
+                    "webkitCancelFullScreen",
+                    "mozCancelFullScreen",
+                    "msExitFullscreen",
+                ],
+            ) {
+                if let Ok(promise) = exit
+                    .call0(&document)
+                    .and_then(|result| result.dyn_into::<js_sys::Promise>())
+                {
+                    leptos::task::spawn_local(async move {
+                        let _ = js_fut!(promise).await;
+                    });
+                }
+            }
+        });
+
+        toggle = {
+            let enter = enter.clone();
+            let exit = exit.clone();
+
+            sendwrap_fn!(move || {
+                if is_fullscreen.get_untracked() {
+                    exit();
+                } else {
+                    enter();
+                }
+            })
+        };
+    }
+
+    UseFullscreenReturn {
+        is_supported,
+        is_fullscreen: is_fullscreen.into(),
+        fullscreen_element: fullscreen_element.into(),
+        error: error.into(),
+        enter,
+        exit,
+        toggle,
+    }
+}
+
+/// Returns the first of the given methods that exists on `target`.
+#[cfg(not(feature = "ssr"))]
+fn find_method(target: &JsValue, names: &[&str]) -> Option<js_sys::Function> {
+    use wasm_bindgen::JsCast;
+
+    names.iter().find_map(|name| {
+        js_sys::Reflect::get(target, &(*name).into())
+            .ok()
+            .and_then(|method| method.dyn_into::<js_sys::Function>().ok())
+    })
+}
+
+#[cfg(not(feature = "ssr"))]
+fn current_fullscreen_element() -> Option<web_sys::Element> {
+    use wasm_bindgen::JsCast;
+
+    let document = document();
+
+    [
+        "fullscreenElement",
+        "webkitFullscreenElement",
+        "webkitCurrentFullScreenElement",
+        "mozFullScreenElement",
+        "msFullscreenElement",
+    ]
+    .into_iter()
+    .find_map(|key| {
+        js_sys::Reflect::get(&document, &key.into())
+            .ok()
+            .and_then(|element| element.dyn_into::<web_sys::Element>().ok())
+    })
+}
+
+/// Screen orientation that can be locked while an element is fullscreen.
+/// See [`ScreenOrientation.lock()`](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/lock#parameters).
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum OrientationLock {
+    Any,
+    Natural,
+    Landscape,
+    Portrait,
+    PortraitPrimary,
+    PortraitSecondary,
+    LandscapePrimary,
+    LandscapeSecondary,
+}
+
+impl Display for OrientationLock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => write!(f, "any"),
+            Self::Natural => write!(f, "natural"),
+            Self::Landscape => write!(f, "landscape"),
+            Self::Portrait => write!(f, "portrait"),
+            Self::PortraitPrimary => write!(f, "portrait-primary"),
+            Self::PortraitSecondary => write!(f, "portrait-secondary"),
+            Self::LandscapePrimary => write!(f, "landscape-primary"),
+            Self::LandscapeSecondary => write!(f, "landscape-secondary"),
+        }
+    }
+}
+
+impl From<OrientationLock> for JsValue {
+    fn from(value: OrientationLock) -> Self {
+        JsValue::from_str(&value.to_string())
+    }
+}
+
+/// Options for [`use_fullscreen_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseFullscreenOptions {
+    /// Lock the screen orientation while the target is fullscreen. Defaults to `None`.
+    #[builder(into)]
+    orientation_lock: Option<OrientationLock>,
+}
+
+/// Error of [`use_fullscreen`].
+#[derive(Error, Debug, Clone)]
+pub enum UseFullscreenError {
+    #[error("the Fullscreen API is not supported by this browser")]
+    NotSupported,
+
+    #[error("the request to enter fullscreen has been denied")]
+    Denied(SendWrapper<JsValue>),
+
+    #[error("the screen orientation couldn't be locked")]
+    OrientationLockFailed(SendWrapper<JsValue>),
+}
+
+/// Return type of [`use_fullscreen`].
+pub struct UseFullscreenReturn<EnterFn, ExitFn, ToggleFn>
+where
+    EnterFn: Fn() + Clone + Send + Sync,
+    ExitFn: Fn() + Clone + Send + Sync,
+    ToggleFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the Fullscreen API is supported
+    pub is_supported: Signal<bool>,
+
+    /// `true` while the target is fullscreen
+    pub is_fullscreen: Signal<bool>,
+
+    /// The element of the document that is currently fullscreen, which might not be the target
+    pub fullscreen_element: Signal<Option<web_sys::Element>, LocalStorage>,
+
+    /// The last error that occurred when entering fullscreen or locking the orientation
+    pub error: Signal<Option<UseFullscreenError>>,
+
+    /// Show the target fullscreen
+    pub enter: EnterFn,
+
+    /// Exit fullscreen
+    pub exit: ExitFn,
+
+    /// Enter or exit fullscreen depending on `is_fullscreen`
+    pub toggle: ToggleFn,
+}
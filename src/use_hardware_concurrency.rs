@@ -0,0 +1,47 @@
+use cfg_if::cfg_if;
+use leptos::prelude::*;
+
+/// Reactive [`navigator.hardwareConcurrency`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/hardwareConcurrency).
+///
+/// The number of logical processors available to run threads on the user's device.
+/// Browsers may report a lower number than the device actually has.
+///
+/// See [`fn@crate::use_device_tier`] for a heuristic that combines this with [`fn@crate::use_device_memory`].
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_hardware_concurrency)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_hardware_concurrency;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let hardware_concurrency = use_hardware_concurrency();
+///
+/// // e.g. the size of a web worker pool
+/// let workers = move || hardware_concurrency.get().unwrap_or(2).saturating_sub(1).max(1);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this function returns a Signal that is always `None`.
+pub fn use_hardware_concurrency() -> Signal<Option<usize>> {
+    cfg_if! { if #[cfg(feature = "ssr")] {
+        Signal::derive(|| None)
+    } else {
+        use crate::use_window;
+        let hardware_concurrency = use_window()
+            .navigator()
+            .map(|navigator| navigator.hardware_concurrency() as usize)
+            .filter(|hardware_concurrency| *hardware_concurrency > 0);
+
+        Signal::derive(move || hardware_concurrency)
+    }}
+}
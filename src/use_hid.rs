@@ -0,0 +1,452 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+pub use web_sys::HidDeviceFilter;
+
+/// Reactive [WebHID API](https://developer.mozilla.org/en-US/docs/Web/API/WebHID_API).
+///
+/// Pairs and opens human interface devices like macro pads, game controllers or custom peripherals.
+/// Input reports of the opened device are available as a signal. Reports are sent with the methods
+/// of the returned [`HidDeviceHandle`].
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_hid)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_hid, UseHidReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseHidReturn {
+///     device,
+///     input_report,
+///     hid,
+///     ..
+/// } = use_hid();
+///
+/// let connect = move |_| {
+///     leptos::task::spawn_local(async move {
+///         // shows the device picker, has to be called in reaction to a user gesture
+///         if let Ok(Some(device)) = hid.request_device().await {
+///             let _ = hid.open(&device).await;
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=connect>"Connect"</button>
+///     <p>{move || device.get().map(|device| device.product_name())}</p>
+///     <p>{move || input_report.get().map(|report| format!("{:?}", report.data))}</p>
+/// }
+/// # }
+/// ```
+///
+/// Previously paired devices are available in `devices` and can be opened without showing the picker.
+/// Only one device is opened at a time. Opening another device closes the previous one.
+///
+/// ## Report Filtering
+///
+/// Devices often send different kinds of reports that are distinguished by their report id.
+/// With the option `report_id` only reports with this id update `input_report`. With `filters`
+/// the picker only shows matching devices.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_hid_with_options, HidDeviceFilter, UseHidOptions, UseHidReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let filter = HidDeviceFilter::new();
+/// filter.set_vendor_id(0x1234);
+///
+/// let UseHidReturn { device, input_report, .. } = use_hid_with_options(
+///     UseHidOptions::default()
+///         .filters(vec![filter])
+///         .report_id(Some(1)),
+/// );
+///
+/// let light_up = move |_| {
+///     if let Some(device) = device.get_untracked() {
+///         leptos::task::spawn_local(async move {
+///             let _ = device.send_report(2, &[0xff, 0x00, 0x00]).await;
+///         });
+///     }
+/// };
+///
+/// view! { <button on:click=light_up>"Light up"</button> }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `devices` is empty, `device` and `input_report` are `None` and the methods of
+/// the [`HidClient`] return `Err(UseHidError::Server)`.
+pub fn use_hid() -> UseHidReturn {
+    use_hid_with_options(UseHidOptions::default())
+}
+
+/// Version of [`use_hid`] that takes a `UseHidOptions`. See [`use_hid`] for how to use.
+pub fn use_hid_with_options(options: UseHidOptions) -> UseHidReturn {
+    let UseHidOptions { filters, report_id } = options;
+
+    let is_supported = use_supported(|| js!("hid" in &window().navigator()));
+
+    let (devices, set_devices) = signal(Vec::<HidDeviceHandle>::new());
+    let (device, set_device) = signal(None::<HidDeviceHandle>);
+    let (input_report, set_input_report) = signal(None::<HidInputReport>);
+
+    let hid = HidClient {
+        is_supported,
+        set_devices,
+        device: device.into(),
+        set_device,
+        filters: StoredValue::new_local(filters),
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        use crate::use_event_listener;
+        use leptos::ev::Custom;
+        use wasm_bindgen::JsCast;
+
+        let refresh = move || {
+            leptos::task::spawn_local(async move {
+                let _ = hid.refresh().await;
+            });
+        };
+
+        refresh();
+
+        let navigator_hid = window()
+            .navigator()
+            .hid()
+            .unchecked_into::<web_sys::EventTarget>();
+
+        let _ = use_event_listener(
+            navigator_hid.clone(),
+            Custom::<web_sys::Event>::new("connect"),
+            move |_| refresh(),
+        );
+
+        let _ = use_event_listener(
+            navigator_hid,
+            Custom::<web_sys::HidConnectionEvent>::new("disconnect"),
+            move |event| {
+                let disconnected = HidDeviceHandle::new(event.device());
+
+                if device.get_untracked().as_ref() == Some(&disconnected) {
+                    set_device.set(None);
+                }
+
+                refresh();
+            },
+        );
+
+        let _ = use_event_listener(
+            Signal::derive_local(move || {
+                device
+                    .get()
+                    .map(|device| web_sys::HidDevice::clone(&device.device))
+            }),
+            Custom::<web_sys::HidInputReportEvent>::new("inputreport"),
+            move |event| {
+                if report_id.is_some_and(|report_id| report_id != event.report_id()) {
+                    return;
+                }
+
+                set_input_report.set(Some(HidInputReport {
+                    report_id: event.report_id(),
+                    data: data_view_to_vec(&event.data()),
+                }));
+            },
+        );
+    }
+
+    UseHidReturn {
+        is_supported,
+        devices: devices.into(),
+        device: device.into(),
+        input_report: input_report.into(),
+        hid,
+    }
+}
+
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+fn data_view_to_vec(view: &js_sys::DataView) -> Vec<u8> {
+    js_sys::Uint8Array::new_with_byte_offset_and_length(
+        &view.buffer(),
+        view.byte_offset() as u32,
+        view.byte_length() as u32,
+    )
+    .to_vec()
+}
+
+/// Pairs, opens and closes devices. Returned by [`use_hid`].
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct HidClient {
+    is_supported: Signal<bool>,
+    set_devices: WriteSignal<Vec<HidDeviceHandle>>,
+    device: Signal<Option<HidDeviceHandle>>,
+    set_device: WriteSignal<Option<HidDeviceHandle>>,
+    filters: StoredValue<Vec<HidDeviceFilter>, LocalStorage>,
+}
+
+impl HidClient {
+    /// Shows the device picker and returns the selected device or `None` if the user didn't select one.
+    /// The device is also added to `devices`. Has to be called in reaction to a user gesture.
+    pub async fn request_device(&self) -> Result<Option<HidDeviceHandle>, UseHidError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseHidError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            if !self.is_supported.get_untracked() {
+                return Err(UseHidError::NotSupported);
+            }
+
+            let options = self
+                .filters
+                .with_value(|filters| web_sys::HidDeviceRequestOptions::new(filters));
+
+            let selected: js_sys::Array =
+                crate::js_fut!(window().navigator().hid().request_device(&options))
+                    .await
+                    .map_err(UseHidError::Failed)?
+                    .unchecked_into();
+
+            self.refresh().await?;
+
+            Ok(selected
+                .iter()
+                .next()
+                .map(|device| HidDeviceHandle::new(device.unchecked_into())))
+        }
+    }
+
+    /// Opens the device so it can send and receive reports. Its input reports update `input_report`.
+    /// A previously opened device is closed.
+    pub async fn open(&self, device: &HidDeviceHandle) -> Result<(), UseHidError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseHidError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            if self.device.get_untracked().as_ref() == Some(device) && device.is_opened() {
+                return Ok(());
+            }
+
+            self.close().await?;
+
+            if !device.is_opened() {
+                crate::js_fut!(device.device.open())
+                    .await
+                    .map_err(UseHidError::Failed)?;
+            }
+
+            self.set_device.try_set(Some(device.clone()));
+
+            Ok(())
+        }
+    }
+
+    /// Closes the opened device.
+    pub async fn close(&self) -> Result<(), UseHidError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseHidError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(device) = self.device.get_untracked() else {
+                return Ok(());
+            };
+
+            self.set_device.try_set(None);
+
+            crate::js_fut!(device.device.close())
+                .await
+                .map(|_| ())
+                .map_err(UseHidError::Failed)
+        }
+    }
+
+    /// Reads the list of paired devices again. This happens automatically when a device is
+    /// connected or disconnected.
+    pub async fn refresh(&self) -> Result<(), UseHidError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseHidError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            if !self.is_supported.get_untracked() {
+                return Err(UseHidError::NotSupported);
+            }
+
+            let devices: js_sys::Array = crate::js_fut!(window().navigator().hid().get_devices())
+                .await
+                .map_err(UseHidError::Failed)?
+                .unchecked_into();
+
+            self.set_devices.try_set(
+                devices
+                    .iter()
+                    .map(|device| HidDeviceHandle::new(device.unchecked_into()))
+                    .collect(),
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// A paired HID device.
+#[derive(Clone, Debug)]
+pub struct HidDeviceHandle {
+    device: SendWrapper<web_sys::HidDevice>,
+}
+
+impl PartialEq for HidDeviceHandle {
+    fn eq(&self, other: &Self) -> bool {
+        js_sys::Object::is(&self.device, &other.device)
+    }
+}
+
+impl HidDeviceHandle {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn new(device: web_sys::HidDevice) -> Self {
+        Self {
+            device: SendWrapper::new(device),
+        }
+    }
+
+    /// The USB vendor id
+    pub fn vendor_id(&self) -> u16 {
+        self.device.vendor_id()
+    }
+
+    /// The USB product id
+    pub fn product_id(&self) -> u16 {
+        self.device.product_id()
+    }
+
+    /// The product name reported by the device
+    pub fn product_name(&self) -> String {
+        self.device.product_name()
+    }
+
+    /// `true` if the device has been opened
+    pub fn is_opened(&self) -> bool {
+        self.device.opened()
+    }
+
+    /// The underlying [`web_sys::HidDevice`]
+    pub fn hid_device(&self) -> &web_sys::HidDevice {
+        &self.device
+    }
+
+    /// Sends an output report. Use `0` as `report_id` if the device doesn't use report ids.
+    pub async fn send_report(&self, report_id: u8, data: &[u8]) -> Result<(), UseHidError> {
+        let promise = self
+            .device
+            .send_report_with_u8_array(report_id, &js_sys::Uint8Array::from(data))
+            .map_err(UseHidError::Failed)?;
+
+        crate::js_fut!(promise)
+            .await
+            .map(|_| ())
+            .map_err(UseHidError::Failed)
+    }
+
+    /// Sends a feature report. Use `0` as `report_id` if the device doesn't use report ids.
+    pub async fn send_feature_report(&self, report_id: u8, data: &[u8]) -> Result<(), UseHidError> {
+        let promise = self
+            .device
+            .send_feature_report_with_u8_array(report_id, &js_sys::Uint8Array::from(data))
+            .map_err(UseHidError::Failed)?;
+
+        crate::js_fut!(promise)
+            .await
+            .map(|_| ())
+            .map_err(UseHidError::Failed)
+    }
+
+    /// Reads the feature report with the given id.
+    pub async fn receive_feature_report(&self, report_id: u8) -> Result<Vec<u8>, UseHidError> {
+        use wasm_bindgen::JsCast;
+
+        let view: js_sys::DataView = crate::js_fut!(self.device.receive_feature_report(report_id))
+            .await
+            .map_err(UseHidError::Failed)?
+            .unchecked_into();
+
+        Ok(data_view_to_vec(&view))
+    }
+}
+
+/// An input report received from the opened device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HidInputReport {
+    /// The id of the report or `0` if the device doesn't use report ids
+    pub report_id: u8,
+
+    /// The data of the report without the report id
+    pub data: Vec<u8>,
+}
+
+#[derive(Error, Debug)]
+pub enum UseHidError {
+    #[error("WebHID is not available on the server")]
+    Server,
+
+    #[error("WebHID is not supported by this browser")]
+    NotSupported,
+
+    #[error("HID operation failed")]
+    Failed(JsValue),
+}
+
+/// Options for [`use_hid_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseHidOptions {
+    /// Only devices matching one of these filters are shown in the device picker. Defaults to no filters.
+    filters: Vec<HidDeviceFilter>,
+
+    /// If set only input reports with this id update `input_report`. Defaults to `None`.
+    report_id: Option<u8>,
+}
+
+/// Return type of [`use_hid`].
+pub struct UseHidReturn {
+    /// `true` if the browser supports the WebHID API
+    pub is_supported: Signal<bool>,
+
+    /// The devices the user has granted access to and that are currently connected
+    pub devices: Signal<Vec<HidDeviceHandle>>,
+
+    /// The opened device
+    pub device: Signal<Option<HidDeviceHandle>>,
+
+    /// The last input report of the opened device
+    pub input_report: Signal<Option<HidInputReport>>,
+
+    /// Pairs, opens and closes devices
+    pub hid: HidClient,
+}
@@ -0,0 +1,387 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{sendwrap_fn, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Time budget of an idle period in milliseconds when `requestIdleCallback` is not supported.
+/// This is the maximum that browsers hand out for a single idle period.
+const FALLBACK_BUDGET: f64 = 50.0;
+
+/// Schedule low-priority work with [`requestIdleCallback`](https://developer.mozilla.org/en-US/docs/Web/API/Window/requestIdleCallback).
+///
+/// The `callback` runs when the browser is idle and receives an [`IdleDeadline`] that tells how much time is
+/// left in the current idle period. If `requestIdleCallback` isn't supported (e.g. Safari) the callback is
+/// run by a `setTimeout` instead with a budget of 50ms.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_idle_callback)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_idle_callback, UseIdleCallbackReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseIdleCallbackReturn {
+///     schedule,
+///     cancel,
+///     is_pending,
+///     ..
+/// } = use_idle_callback(move |deadline| {
+///     // send analytics, warm up caches, ...
+///     leptos::logging::log!("{}ms left", deadline.time_remaining());
+/// });
+///
+/// schedule();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Calling `schedule` while the callback is already pending does nothing. Use `cancel` to remove it from the
+/// queue.
+///
+/// ## Timeout
+///
+/// If the browser is busy an idle period can be a long time coming. With the option `timeout` the callback is
+/// run after at most that many milliseconds. In this case [`IdleDeadline::did_timeout`] is `true`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_idle_callback_with_options, UseIdleCallbackOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let idle = use_idle_callback_with_options(
+///     move |deadline| { /* ... */ },
+///     UseIdleCallbackOptions::default().timeout(Some(2000)).immediate(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Idle Until Urgent
+///
+/// Work that is postponed until the browser is idle is sometimes needed earlier, e.g. because the user
+/// interacts with something that depends on it. Call the returned `flush` to run a pending callback right
+/// away. The callback then receives a deadline for which [`IdleDeadline::is_urgent`] is `true` and
+/// [`IdleDeadline::time_remaining`] is infinite so the work is finished in one go.
+///
+/// Set the option `flush_on_hidden` to `true` to flush when the page is hidden. This makes sure the work
+/// isn't lost when the user closes the tab.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `schedule`, `cancel` and `flush` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_idle_callback`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this does basically nothing. The provided closure will never be called.
+pub fn use_idle_callback<F>(
+    callback: F,
+) -> UseIdleCallbackReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    F: Fn(IdleDeadline) + 'static,
+{
+    use_idle_callback_with_options(callback, UseIdleCallbackOptions::default())
+}
+
+/// Version of [`use_idle_callback`] that takes a `UseIdleCallbackOptions`. See [`use_idle_callback`] for how to use.
+pub fn use_idle_callback_with_options<F>(
+    callback: F,
+    options: UseIdleCallbackOptions,
+) -> UseIdleCallbackReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    F: Fn(IdleDeadline) + 'static,
+{
+    let UseIdleCallbackOptions {
+        timeout,
+        immediate,
+        flush_on_hidden,
+    } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("requestIdleCallback" in &window())
+        }
+    });
+
+    let (is_pending, set_pending) = signal(false);
+
+    let schedule;
+    let cancel;
+    let flush;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = callback;
+        let _ = set_pending;
+
+        schedule = || {};
+        cancel = || {};
+        flush = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::visibilitychange;
+        use std::cell::Cell;
+        use std::rc::Rc;
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::{JsCast, JsValue};
+
+        #[derive(Copy, Clone)]
+        enum Handle {
+            Idle(u32),
+            Timeout(i32),
+        }
+
+        let handle = Rc::new(Cell::new(None::<Handle>));
+
+        let run = Rc::new({
+            let handle = Rc::clone(&handle);
+
+            move |deadline: IdleDeadline| {
+                handle.set(None);
+                set_pending.try_set(false);
+
+                #[cfg(debug_assertions)]
+                let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                callback(deadline);
+
+                #[cfg(debug_assertions)]
+                drop(zone);
+            }
+        });
+
+        // Called by `requestIdleCallback` with the native deadline or by `setTimeout` without an argument.
+        let on_idle = Rc::new(Closure::<dyn Fn(JsValue)>::new({
+            let run = Rc::clone(&run);
+
+            move |deadline: JsValue| {
+                run(if deadline.is_undefined() {
+                    IdleDeadline::fallback()
+                } else {
+                    IdleDeadline::native(deadline.unchecked_into())
+                });
+            }
+        }));
+
+        cancel = {
+            let handle = Rc::clone(&handle);
+
+            sendwrap_fn!(move || {
+                match handle.take() {
+                    Some(Handle::Idle(id)) => window().cancel_idle_callback(id),
+                    Some(Handle::Timeout(id)) => window().clear_timeout_with_handle(id),
+                    None => {}
+                }
+
+                set_pending.try_set(false);
+            })
+        };
+
+        schedule = {
+            let handle = Rc::clone(&handle);
+            let on_idle = Rc::clone(&on_idle);
+
+            sendwrap_fn!(move || {
+                if handle.get().is_some() {
+                    return;
+                }
+
+                let function = (*on_idle).as_ref().unchecked_ref();
+
+                let new_handle = if is_supported.get_untracked() {
+                    let result = if let Some(timeout) = timeout {
+                        let options = web_sys::IdleRequestOptions::new();
+                        options.set_timeout(timeout);
+                        window().request_idle_callback_with_options(function, &options)
+                    } else {
+                        window().request_idle_callback(function)
+                    };
+
+                    result.ok().map(Handle::Idle)
+                } else {
+                    window()
+                        .set_timeout_with_callback_and_timeout_and_arguments_0(function, 1)
+                        .ok()
+                        .map(Handle::Timeout)
+                };
+
+                if new_handle.is_some() {
+                    handle.set(new_handle);
+                    set_pending.set(true);
+                }
+            })
+        };
+
+        flush = {
+            let cancel = cancel.clone();
+
+            sendwrap_fn!(move || {
+                if handle.get().is_some() {
+                    cancel();
+                    run(IdleDeadline::urgent());
+                }
+            })
+        };
+
+        if flush_on_hidden {
+            let flush = flush.clone();
+
+            let _ = use_event_listener(document(), visibilitychange, move |_| {
+                if document().hidden() {
+                    flush();
+                }
+            });
+        }
+
+        if immediate {
+            schedule();
+        }
+
+        on_cleanup({
+            let cancel = cancel.clone();
+            #[allow(clippy::redundant_closure)]
+            move || cancel()
+        });
+    }
+
+    UseIdleCallbackReturn {
+        is_supported,
+        is_pending: is_pending.into(),
+        schedule,
+        cancel,
+        flush,
+    }
+}
+
+/// The argument of the callback of [`use_idle_callback`].
+///
+/// Wraps the browser's [IdleDeadline](https://developer.mozilla.org/en-US/docs/Web/API/IdleDeadline) and
+/// emulates it when `requestIdleCallback` isn't available or the callback is flushed.
+pub struct IdleDeadline {
+    kind: IdleDeadlineKind,
+}
+
+enum IdleDeadlineKind {
+    #[cfg(not(feature = "ssr"))]
+    Native(web_sys::IdleDeadline),
+    Fallback {
+        start: f64,
+    },
+    Urgent,
+}
+
+impl IdleDeadline {
+    #[cfg(not(feature = "ssr"))]
+    fn native(deadline: web_sys::IdleDeadline) -> Self {
+        Self {
+            kind: IdleDeadlineKind::Native(deadline),
+        }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn fallback() -> Self {
+        Self {
+            kind: IdleDeadlineKind::Fallback {
+                start: js_sys::Date::now(),
+            },
+        }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn urgent() -> Self {
+        Self {
+            kind: IdleDeadlineKind::Urgent,
+        }
+    }
+
+    /// Milliseconds left in the current idle period. Do chunks of work until this is `0.0` and then
+    /// schedule the rest again. Infinite if the callback has been flushed.
+    pub fn time_remaining(&self) -> f64 {
+        match &self.kind {
+            #[cfg(not(feature = "ssr"))]
+            IdleDeadlineKind::Native(deadline) => deadline.time_remaining(),
+            IdleDeadlineKind::Fallback { start } => {
+                (FALLBACK_BUDGET - (js_sys::Date::now() - start)).max(0.0)
+            }
+            IdleDeadlineKind::Urgent => f64::INFINITY,
+        }
+    }
+
+    /// `true` if the callback is run because the `timeout` has elapsed.
+    pub fn did_timeout(&self) -> bool {
+        match &self.kind {
+            #[cfg(not(feature = "ssr"))]
+            IdleDeadlineKind::Native(deadline) => deadline.did_timeout(),
+            _ => false,
+        }
+    }
+
+    /// `true` if the callback is run because it has been flushed.
+    pub fn is_urgent(&self) -> bool {
+        matches!(self.kind, IdleDeadlineKind::Urgent)
+    }
+}
+
+/// Options for [`use_idle_callback_with_options`].
+#[derive(DefaultBuilder, Default, Clone, Copy, Debug)]
+pub struct UseIdleCallbackOptions {
+    /// Maximum number of milliseconds to wait for an idle period before the callback is run anyway.
+    /// Defaults to `None` which waits as long as it takes.
+    timeout: Option<u32>,
+
+    /// Schedule the callback immediately on creation. Defaults to `false`.
+    immediate: bool,
+
+    /// Flush a pending callback when the page is hidden. Defaults to `false`.
+    flush_on_hidden: bool,
+}
+
+/// Return type of [`use_idle_callback`].
+pub struct UseIdleCallbackReturn<ScheduleFn, CancelFn, FlushFn>
+where
+    ScheduleFn: Fn() + Clone + Send + Sync,
+    CancelFn: Fn() + Clone + Send + Sync,
+    FlushFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether `requestIdleCallback` is supported. If not, a timeout is used instead.
+    pub is_supported: Signal<bool>,
+
+    /// `true` while the callback is scheduled but hasn't been run yet
+    pub is_pending: Signal<bool>,
+
+    /// Schedules the callback for the next idle period unless it is already pending
+    pub schedule: ScheduleFn,
+
+    /// Removes a pending callback from the queue
+    pub cancel: CancelFn,
+
+    /// Runs a pending callback right away
+    pub flush: FlushFn,
+}
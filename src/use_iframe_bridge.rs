@@ -0,0 +1,370 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{
+    use_window_message_with_options, UseWindowMessageError, UseWindowMessageOptions,
+    UseWindowMessageReturn, WindowMessenger,
+};
+use codee::{Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::html::Iframe;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::fmt::Debug;
+use thiserror::Error;
+
+/// Message type of the handshake requests that [`use_iframe_bridge`] sends to the iframe.
+/// The iframe has to answer them to establish the connection.
+pub const IFRAME_BRIDGE_HANDSHAKE: &str = "__bridge_handshake";
+
+/// Remote procedure calls between a page and an iframe over [`use_window_message`](crate::use_window_message).
+///
+/// Exposes Rust functions to the iframe and calls the functions exposed by the iframe. The connection is established
+/// by a handshake every time the iframe (re)loads.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_iframe_bridge)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Iframe;
+/// # use leptos_use::{use_iframe_bridge, UseIframeBridgeReturn};
+/// # use codee::string::JsonSerdeCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let iframe = NodeRef::<Iframe>::new();
+///
+/// let UseIframeBridgeReturn {
+///     bridge,
+///     is_connected,
+///     ..
+/// } = use_iframe_bridge::<JsonSerdeCodec>(iframe);
+///
+/// // the iframe can call this
+/// bridge.expose("get_user_name", |_: ()| "Leptos".to_string());
+///
+/// let on_click = move |_| {
+///     leptos::task::spawn_local(async move {
+///         // call a function exposed by the iframe
+///         let sum: Result<i32, _> = bridge.call("add", &(1, 2)).await;
+///     });
+/// };
+///
+/// view! {
+///     <iframe node_ref=iframe src="/embed"></iframe>
+///     <button on:click=on_click disabled=move || !is_connected.get()>"Add"</button>
+/// }
+/// # }
+/// ```
+///
+/// Calls made while the bridge isn't connected fail with [`UseIframeBridgeError::NotConnected`].
+/// Calls that aren't answered within `timeout` milliseconds fail with a timeout error.
+///
+/// ## Connection state
+///
+/// After the iframe has loaded, handshake requests of the type [`IFRAME_BRIDGE_HANDSHAKE`] are sent every
+/// `handshake_interval` milliseconds until the iframe answers one. Then `state` becomes
+/// [`IframeBridgeState::Connected`]. If the iframe doesn't answer within `handshake_timeout` milliseconds `state`
+/// becomes [`IframeBridgeState::Failed`].
+///
+/// ## Inside the iframe
+///
+/// The iframe talks to the parent with the [wire format of `use_window_message`](crate::use_window_message#wire-format).
+/// If it's a Leptos app as well it can use [`use_window_message`](crate::use_window_message) to answer the handshake and
+/// expose its functions.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{
+/// #     use_window_message, UseWindowMessageReturn, WindowMessageTarget, IFRAME_BRIDGE_HANDSHAKE,
+/// # };
+/// # use codee::string::JsonSerdeCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowMessageReturn { messenger, .. } =
+///     use_window_message::<JsonSerdeCodec>(WindowMessageTarget::Parent);
+///
+/// messenger.on_request(IFRAME_BRIDGE_HANDSHAKE, |_: ()| {});
+/// messenger.on_request("add", |(a, b): (i32, i32)| a + b);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `state` is always [`IframeBridgeState::Disconnected`] and calls return an error.
+pub fn use_iframe_bridge<C>(iframe: NodeRef<Iframe>) -> UseIframeBridgeReturn<C>
+where
+    C: Encoder<(), Encoded = String> + Decoder<(), Encoded = str> + 'static,
+    <C as Encoder<()>>::Error: Debug,
+    <C as Decoder<()>>::Error: Debug,
+{
+    use_iframe_bridge_with_options(iframe, UseIframeBridgeOptions::default())
+}
+
+/// Version of [`use_iframe_bridge`] that takes a `UseIframeBridgeOptions`. See [`use_iframe_bridge`] for how to use.
+pub fn use_iframe_bridge_with_options<C>(
+    iframe: NodeRef<Iframe>,
+    options: UseIframeBridgeOptions,
+) -> UseIframeBridgeReturn<C>
+where
+    C: Encoder<(), Encoded = String> + Decoder<(), Encoded = str> + 'static,
+    <C as Encoder<()>>::Error: Debug,
+    <C as Decoder<()>>::Error: Debug,
+{
+    let UseIframeBridgeOptions {
+        allowed_origins,
+        target_origin,
+        channel,
+        timeout,
+        handshake_interval,
+        handshake_timeout,
+    } = options;
+
+    let UseWindowMessageReturn { messenger, error } = use_window_message_with_options::<C>(
+        iframe,
+        UseWindowMessageOptions::default()
+            .allowed_origins(allowed_origins)
+            .target_origin(target_origin)
+            .channel(channel)
+            .timeout(timeout),
+    );
+
+    let (state, set_state) = signal(IframeBridgeState::Disconnected);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js_fut, use_event_listener};
+
+        // incremented for every handshake so that outdated handshakes stop
+        let generation = StoredValue::new(0_u32);
+
+        let connect = move || {
+            let current = generation
+                .try_update_value(|generation| {
+                    *generation = generation.wrapping_add(1);
+                    *generation
+                })
+                .unwrap_or_default();
+
+            set_state.set(IframeBridgeState::Connecting);
+
+            leptos::task::spawn_local(async move {
+                let mut waited = 0;
+
+                loop {
+                    if generation.try_get_value() != Some(current) {
+                        return;
+                    }
+
+                    let result = messenger
+                        .request_with_timeout::<(), ()>(
+                            IFRAME_BRIDGE_HANDSHAKE,
+                            &(),
+                            handshake_interval,
+                        )
+                        .await;
+
+                    if generation.try_get_value() != Some(current) {
+                        return;
+                    }
+
+                    match result {
+                        Ok(()) => {
+                            set_state.set(IframeBridgeState::Connected);
+                            return;
+                        }
+                        Err(UseWindowMessageError::NoTarget) => {
+                            // the iframe isn't ready yet
+                            let _ = js_fut!(sleep(handshake_interval)).await;
+                        }
+                        Err(UseWindowMessageError::Timeout) => {}
+                        Err(_) => {
+                            set_state.set(IframeBridgeState::Failed);
+                            return;
+                        }
+                    }
+
+                    waited += handshake_interval;
+
+                    if waited >= handshake_timeout {
+                        let _ = set_state.try_set(IframeBridgeState::Failed);
+                        return;
+                    }
+                }
+            });
+        };
+
+        let _ = use_event_listener(iframe, leptos::ev::load, move |_| connect());
+
+        Effect::watch(
+            move || iframe.get().is_some(),
+            move |is_mounted, _, _| {
+                if *is_mounted {
+                    connect();
+                } else {
+                    generation.update_value(|generation| *generation = generation.wrapping_add(1));
+                    set_state.set(IframeBridgeState::Disconnected);
+                }
+            },
+            true,
+        );
+    }
+
+    UseIframeBridgeReturn {
+        state: state.into(),
+        is_connected: Signal::derive(move || state.get() == IframeBridgeState::Connected),
+        error,
+        bridge: IframeBridge { messenger, state },
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn sleep(millis: u64) -> js_sys::Promise {
+    js_sys::Promise::new(&mut |resolve, _| {
+        let _ = window().set_timeout_with_callback_and_timeout_and_arguments_0(
+            &resolve,
+            millis.min(i32::MAX as u64) as i32,
+        );
+    })
+}
+
+/// Options for [`use_iframe_bridge_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseIframeBridgeOptions {
+    /// Origins the iframe is allowed to have. `"*"` accepts all origins.
+    /// Defaults to an empty list which only accepts iframes of the same origin.
+    allowed_origins: Vec<String>,
+
+    /// The origin the iframe must have for messages to be delivered. `"*"` delivers to any origin.
+    /// Defaults to `"/"` which only delivers to the same origin.
+    #[builder(into)]
+    target_origin: String,
+
+    /// Channel name of the underlying [`use_window_message`](crate::use_window_message). Defaults to `"leptos-use"`.
+    #[builder(into)]
+    channel: String,
+
+    /// Milliseconds to wait for the result of a call. Defaults to `5000`.
+    timeout: u64,
+
+    /// Milliseconds between handshake requests while connecting. Defaults to `250`.
+    handshake_interval: u64,
+
+    /// Milliseconds after which connecting fails if the iframe doesn't answer the handshake. Defaults to `10000`.
+    handshake_timeout: u64,
+}
+
+impl Default for UseIframeBridgeOptions {
+    fn default() -> Self {
+        Self {
+            allowed_origins: vec![],
+            target_origin: "/".to_string(),
+            channel: "leptos-use".to_string(),
+            timeout: 5000,
+            handshake_interval: 250,
+            handshake_timeout: 10000,
+        }
+    }
+}
+
+/// Connection state of [`use_iframe_bridge`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IframeBridgeState {
+    /// The iframe isn't mounted
+    Disconnected,
+
+    /// Waiting for the iframe to answer the handshake
+    Connecting,
+
+    /// The iframe has answered the handshake. Functions can be called.
+    Connected,
+
+    /// The iframe hasn't answered the handshake in time
+    Failed,
+}
+
+/// Return type of [`use_iframe_bridge`].
+pub struct UseIframeBridgeReturn<C>
+where
+    C: 'static,
+{
+    /// Expose functions to the iframe and call its functions
+    pub bridge: IframeBridge<C>,
+
+    /// Connection state of the bridge
+    pub state: Signal<IframeBridgeState>,
+
+    /// `true` if `state` is [`IframeBridgeState::Connected`]
+    pub is_connected: Signal<bool>,
+
+    /// Latest error that happened while answering a call from the iframe
+    pub error: Signal<Option<UseWindowMessageError>>,
+}
+
+/// Calls functions of the iframe and exposes functions to it. Returned by [`use_iframe_bridge`].
+pub struct IframeBridge<C>
+where
+    C: 'static,
+{
+    messenger: WindowMessenger<C>,
+    state: ReadSignal<IframeBridgeState>,
+}
+
+impl<C> Clone for IframeBridge<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for IframeBridge<C> {}
+
+impl<C> IframeBridge<C>
+where
+    C: 'static,
+{
+    /// Calls the function `name` exposed by the iframe with `args` and returns its result.
+    pub async fn call<T, R>(&self, name: &str, args: &T) -> Result<R, UseIframeBridgeError>
+    where
+        C: Encoder<T, Encoded = String> + Decoder<R, Encoded = str>,
+        <C as Encoder<T>>::Error: Debug,
+        <C as Decoder<R>>::Error: Debug,
+    {
+        if self.state.get_untracked() != IframeBridgeState::Connected {
+            return Err(UseIframeBridgeError::NotConnected);
+        }
+
+        Ok(self.messenger.request(name, args).await?)
+    }
+
+    /// Lets the iframe call `function` by `name`. Exposing another function with the same name replaces it.
+    pub fn expose<T, R>(&self, name: &str, function: impl Fn(T) -> R + 'static)
+    where
+        C: Decoder<T, Encoded = str> + Encoder<R, Encoded = String>,
+        <C as Decoder<T>>::Error: Debug,
+        <C as Encoder<R>>::Error: Debug,
+    {
+        self.messenger.on_request(name, function);
+    }
+
+    /// The underlying messenger to send plain messages to the iframe
+    pub fn messenger(&self) -> WindowMessenger<C> {
+        self.messenger
+    }
+}
+
+/// Error returned by [`IframeBridge::call`].
+#[derive(Error, Debug, Clone)]
+pub enum UseIframeBridgeError {
+    #[error("the iframe bridge isn't connected")]
+    NotConnected,
+
+    #[error(transparent)]
+    Message(#[from] UseWindowMessageError),
+}
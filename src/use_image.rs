@@ -0,0 +1,263 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::LoadStatus;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive image loading.
+///
+/// Preloads the image and reports if it's still loading, has loaded or failed to load. Once it's loaded
+/// the natural dimensions of the image are available. This is useful to show placeholders until an image can be displayed.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_image)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_image, UseImageReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseImageReturn { is_loading, is_error, retry, .. } = use_image("https://place.dog/300/200");
+///
+/// view! {
+///     <Show when=move || !is_loading.get() fallback=|| view! { <div class="placeholder"></div> }>
+///         <Show
+///             when=move || !is_error.get()
+///             fallback={
+///                 let retry = retry.clone();
+///                 move || {
+///                     let retry = retry.clone();
+///                     view! { <button on:click=move |_| retry()>"Retry"</button> }
+///                 }
+///             }
+///         >
+///             <img src="https://place.dog/300/200" />
+///         </Show>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// The source can be a signal. Every time it changes the new image is loaded. `natural_width` and `natural_height`
+/// contain the intrinsic size of the loaded image and `image` the preloaded image element itself.
+///
+/// ## Responsive Images
+///
+/// Provide `srcset` and `sizes` to let the browser pick the best image just like it would for an `<img>`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_image_with_options, UseImageOptions, UseImageReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseImageReturn { image, .. } = use_image_with_options(
+///     "photo-800.jpg",
+///     UseImageOptions::default()
+///         .srcset(Some("photo-400.jpg 400w, photo-800.jpg 800w".to_string()))
+///         .sizes(Some("(max-width: 600px) 400px, 800px".to_string())),
+/// );
+///
+/// // the URL the browser has picked
+/// let current_src = move || image.get().map(|image| image.current_src());
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `retry` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_image`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no image is loaded, the status is always `LoadStatus::Idle` and `retry` is a no-op.
+pub fn use_image(
+    src: impl Into<Signal<String>>,
+) -> UseImageReturn<impl Fn() + Clone + Send + Sync> {
+    use_image_with_options(src, UseImageOptions::default())
+}
+
+/// Version of [`use_image`] that takes a `UseImageOptions`. See [`use_image`] for how to use.
+pub fn use_image_with_options(
+    src: impl Into<Signal<String>>,
+    options: UseImageOptions,
+) -> UseImageReturn<impl Fn() + Clone + Send + Sync> {
+    let UseImageOptions {
+        srcset,
+        sizes,
+        cross_origin,
+        referrer_policy,
+    } = options;
+
+    let src = src.into();
+
+    let (status, set_status) = signal(LoadStatus::Idle);
+    let (image, set_image) = signal_local(None::<web_sys::HtmlImageElement>);
+    let (natural_width, set_natural_width) = signal(0_u32);
+    let (natural_height, set_natural_height) = signal(0_u32);
+
+    // incremented by `retry` to load the same image again
+    let (attempt, set_attempt) = signal(0_usize);
+
+    let retry;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_status;
+        let _ = set_image;
+        let _ = set_natural_width;
+        let _ = set_natural_height;
+        let _ = attempt;
+        let _ = set_attempt;
+
+        retry = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js_fut, sendwrap_fn};
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        // ignores images that finish loading after the source has changed
+        let generation = Rc::new(Cell::new(0_usize));
+
+        Effect::new(move || {
+            attempt.track();
+
+            let src = src.get();
+            let srcset = srcset.get();
+            let sizes = sizes.get();
+
+            let current = generation.get() + 1;
+            generation.set(current);
+
+            set_image.set(None);
+            set_natural_width.set(0);
+            set_natural_height.set(0);
+
+            if src.is_empty() && srcset.is_none() {
+                set_status.set(LoadStatus::Idle);
+                return;
+            }
+
+            let Ok(img) = web_sys::HtmlImageElement::new() else {
+                set_status.set(LoadStatus::Error);
+                return;
+            };
+
+            set_status.set(LoadStatus::Loading);
+
+            if let Some(cross_origin) = &cross_origin {
+                img.set_cross_origin(Some(cross_origin));
+            }
+            if let Some(referrer_policy) = &referrer_policy {
+                img.set_referrer_policy(referrer_policy);
+            }
+            // `srcset` and `sizes` have to be set before `src` to avoid loading the fallback image
+            if let Some(sizes) = &sizes {
+                img.set_sizes(sizes);
+            }
+            if let Some(srcset) = &srcset {
+                img.set_srcset(srcset);
+            }
+            img.set_src(&src);
+
+            let generation = Rc::clone(&generation);
+            let promise = img.decode();
+
+            leptos::task::spawn_local(async move {
+                let result = js_fut!(promise).await;
+
+                if generation.get() != current {
+                    return;
+                }
+
+                match result {
+                    Ok(_) => {
+                        let _ = set_natural_width.try_set(img.natural_width());
+                        let _ = set_natural_height.try_set(img.natural_height());
+                        let _ = set_image.try_set(Some(img));
+                        let _ = set_status.try_set(LoadStatus::Loaded);
+                    }
+                    Err(_) => {
+                        let _ = set_status.try_set(LoadStatus::Error);
+                    }
+                }
+            });
+        });
+
+        retry = sendwrap_fn!(move || {
+            set_attempt.update(|attempt| *attempt += 1);
+        });
+    }
+
+    UseImageReturn {
+        status: status.into(),
+        is_loading: Signal::derive(move || status.get() == LoadStatus::Loading),
+        is_loaded: Signal::derive(move || status.get() == LoadStatus::Loaded),
+        is_error: Signal::derive(move || status.get() == LoadStatus::Error),
+        image: image.into(),
+        natural_width: natural_width.into(),
+        natural_height: natural_height.into(),
+        retry,
+    }
+}
+
+/// Options for [`use_image_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseImageOptions {
+    /// Candidate images for different resolutions like the `srcset` attribute of `<img>`. Defaults to `None`.
+    #[builder(into)]
+    srcset: Signal<Option<String>>,
+
+    /// Sizes that select an image from `srcset` like the `sizes` attribute of `<img>`. Defaults to `None`.
+    #[builder(into)]
+    sizes: Signal<Option<String>>,
+
+    /// The `crossorigin` attribute of the image like `"anonymous"`. Defaults to `None`.
+    #[builder(into)]
+    cross_origin: Option<String>,
+
+    /// The `referrerpolicy` attribute of the image like `"no-referrer"`. Defaults to `None`.
+    #[builder(into)]
+    referrer_policy: Option<String>,
+}
+
+/// Return type of [`use_image`].
+pub struct UseImageReturn<RetryFn>
+where
+    RetryFn: Fn() + Clone + Send + Sync,
+{
+    /// Loading status of the image
+    pub status: Signal<LoadStatus>,
+
+    /// `true` while the image is loading
+    pub is_loading: Signal<bool>,
+
+    /// `true` if the image has loaded successfully
+    pub is_loaded: Signal<bool>,
+
+    /// `true` if the image failed to load
+    pub is_error: Signal<bool>,
+
+    /// The preloaded image element once it has loaded
+    pub image: Signal<Option<web_sys::HtmlImageElement>, LocalStorage>,
+
+    /// Intrinsic width of the loaded image in pixels
+    pub natural_width: Signal<u32>,
+
+    /// Intrinsic height of the loaded image in pixels
+    pub natural_height: Signal<u32>,
+
+    /// Load the image again, for example after it failed to load
+    pub retry: RetryFn,
+}
@@ -0,0 +1,358 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::use_supported;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use wasm_bindgen::JsValue;
+
+/// Take photos and grab frames from a camera stream with the
+/// [ImageCapture API](https://developer.mozilla.org/en-US/docs/Web/API/ImageCapture).
+///
+/// The stream is usually the one returned by [`fn@crate::use_user_media`]. The first video track of the
+/// stream is used for capturing.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_image_capture)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_image_capture, use_user_media, UseImageCaptureReturn, UseUserMediaReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUserMediaReturn { stream, .. } = use_user_media();
+///
+/// let UseImageCaptureReturn { photo, take_photo, .. } = use_image_capture(stream);
+///
+/// Effect::new(move || {
+///     if let Some(blob) = photo.get() {
+///         // upload or display the photo
+///     }
+/// });
+///
+/// view! { <button on:click=move |_| take_photo()>"Take photo"</button> }
+/// # }
+/// ```
+///
+/// `take_photo` uses the full resolution of the camera which can take a moment. For low latency previews
+/// use `grab_frame` which returns the current video frame as an `ImageBitmap` in `frame`.
+///
+/// ## Zoom and Torch
+///
+/// If the camera supports it, `capabilities` contains the zoom range and whether it has a torch (flash light).
+/// Use `set_zoom` and `set_torch` to control them.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_image_capture, use_user_media, UseImageCaptureReturn, UseUserMediaReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let UseUserMediaReturn { stream, .. } = use_user_media();
+/// let UseImageCaptureReturn { capabilities, torch, set_torch, .. } = use_image_capture(stream);
+///
+/// view! {
+///     <Show when=move || capabilities.get().torch>
+///         <button on:click={
+///             let set_torch = set_torch.clone();
+///             move |_| set_torch(!torch.get())
+///         }>"Toggle torch"</button>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `take_photo`, `grab_frame`, `set_zoom` and `set_torch` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_image_capture`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false` and the returned closures are no-ops.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_user_media`]
+pub fn use_image_capture<S>(
+    stream: S,
+) -> UseImageCaptureReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn(f64) + Clone + Send + Sync,
+    impl Fn(bool) + Clone + Send + Sync,
+>
+where
+    S: Into<Signal<Option<Result<web_sys::MediaStream, JsValue>>, LocalStorage>>,
+{
+    let stream = stream.into();
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("ImageCapture" in &window())
+        }
+    });
+
+    let (capture, set_capture) = signal_local(None::<web_sys::ImageCapture>);
+    let (capabilities, set_capabilities) = signal(ImageCaptureCapabilities::default());
+    let (photo, set_photo) = signal_local(None::<web_sys::Blob>);
+    let (frame, set_frame) = signal_local(None::<web_sys::ImageBitmap>);
+    let (zoom, set_zoom_value) = signal(None::<f64>);
+    let (torch, set_torch_value) = signal(false);
+
+    let take_photo;
+    let grab_frame;
+    let set_zoom;
+    let set_torch;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_capture;
+        let _ = set_capabilities;
+        let _ = set_photo;
+        let _ = set_frame;
+        let _ = set_zoom_value;
+        let _ = set_torch_value;
+
+        take_photo = || {};
+        grab_frame = || {};
+        set_zoom = |_: f64| {};
+        set_torch = |_: bool| {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js, js_fut, sendwrap_fn};
+        use wasm_bindgen::JsCast;
+
+        Effect::new(move || {
+            let track = stream
+                .get()
+                .and_then(Result::ok)
+                .and_then(|stream| stream.get_video_tracks().get(0).dyn_into().ok());
+
+            let Some(track) = track.filter(|_| is_supported.get()) else {
+                set_capture.set(None);
+                set_capabilities.set(ImageCaptureCapabilities::default());
+                set_zoom_value.set(None);
+                set_torch_value.set(false);
+                return;
+            };
+
+            let track_capabilities = call_track_method(&track, "getCapabilities");
+            let settings = call_track_method(&track, "getSettings");
+
+            set_capabilities.set(ImageCaptureCapabilities {
+                zoom: track_capabilities
+                    .as_ref()
+                    .and_then(|caps| js!(caps["zoom"]).ok())
+                    .filter(|zoom| zoom.is_object())
+                    .map(|zoom| ZoomRange {
+                        min: number(&zoom, "min"),
+                        max: number(&zoom, "max"),
+                        step: number(&zoom, "step"),
+                    }),
+                torch: track_capabilities
+                    .as_ref()
+                    .and_then(|caps| js!(caps["torch"]).ok())
+                    .map(|torch| torch.is_truthy())
+                    .unwrap_or_default(),
+            });
+
+            let settings = settings.as_ref();
+            set_zoom_value.set(
+                settings
+                    .and_then(|settings| js!(settings["zoom"]).ok())
+                    .and_then(|zoom| zoom.as_f64()),
+            );
+            set_torch_value.set(
+                settings
+                    .and_then(|settings| js!(settings["torch"]).ok())
+                    .and_then(|torch| torch.as_bool())
+                    .unwrap_or_default(),
+            );
+
+            set_capture.set(web_sys::ImageCapture::new(&track).ok());
+        });
+
+        take_photo = sendwrap_fn!(move || {
+            if let Some(capture) = capture.get_untracked() {
+                let promise = capture.take_photo();
+
+                leptos::task::spawn_local(async move {
+                    if let Ok(blob) = js_fut!(promise).await {
+                        let _ = set_photo.try_set(Some(blob.unchecked_into()));
+                    }
+                });
+            }
+        });
+
+        grab_frame = sendwrap_fn!(move || {
+            if let Some(capture) = capture.get_untracked() {
+                let promise = capture.grab_frame();
+
+                leptos::task::spawn_local(async move {
+                    if let Ok(bitmap) = js_fut!(promise).await {
+                        let _ = set_frame.try_set(Some(bitmap.unchecked_into()));
+                    }
+                });
+            }
+        });
+
+        set_zoom = sendwrap_fn!(move |value: f64| {
+            if capabilities.get_untracked().zoom.is_none() {
+                return;
+            }
+
+            if let Some(capture) = capture.get_untracked() {
+                apply_advanced_constraint(&capture.track(), "zoom", value.into(), move || {
+                    let _ = set_zoom_value.try_set(Some(value));
+                });
+            }
+        });
+
+        set_torch = sendwrap_fn!(move |enabled: bool| {
+            if !capabilities.get_untracked().torch {
+                return;
+            }
+
+            if let Some(capture) = capture.get_untracked() {
+                apply_advanced_constraint(&capture.track(), "torch", enabled.into(), move || {
+                    let _ = set_torch_value.try_set(enabled);
+                });
+            }
+        });
+    }
+
+    UseImageCaptureReturn {
+        is_supported,
+        photo: photo.into(),
+        frame: frame.into(),
+        capabilities: capabilities.into(),
+        zoom: zoom.into(),
+        torch: torch.into(),
+        take_photo,
+        grab_frame,
+        set_zoom,
+        set_torch,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn call_track_method(track: &web_sys::MediaStreamTrack, method: &str) -> Option<JsValue> {
+    use wasm_bindgen::JsCast;
+
+    js_sys::Reflect::get(track, &method.into())
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?
+        .call0(track)
+        .ok()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn number(obj: &JsValue, key: &str) -> f64 {
+    js_sys::Reflect::get(obj, &key.into())
+        .ok()
+        .and_then(|value| value.as_f64())
+        .unwrap_or_default()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn apply_advanced_constraint(
+    track: &web_sys::MediaStreamTrack,
+    name: &str,
+    value: JsValue,
+    on_applied: impl FnOnce() + 'static,
+) {
+    use wasm_bindgen::JsCast;
+
+    let constraint = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&constraint, &name.into(), &value);
+
+    let constraints = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(
+        &constraints,
+        &"advanced".into(),
+        &js_sys::Array::of1(&constraint),
+    );
+
+    let Ok(promise) = track.apply_constraints_with_constraints(constraints.unchecked_ref()) else {
+        return;
+    };
+
+    leptos::task::spawn_local(async move {
+        if crate::js_fut!(promise).await.is_ok() {
+            on_applied();
+        }
+    });
+}
+
+/// Zoom and torch capabilities of the camera used by [`use_image_capture`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ImageCaptureCapabilities {
+    /// The supported zoom range or `None` if the camera can't zoom
+    pub zoom: Option<ZoomRange>,
+
+    /// Whether the camera has a torch (flash light)
+    pub torch: bool,
+}
+
+/// Range of zoom values supported by the camera.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ZoomRange {
+    /// Minimum zoom factor
+    pub min: f64,
+    /// Maximum zoom factor
+    pub max: f64,
+    /// Step between two zoom factors
+    pub step: f64,
+}
+
+/// Return type of [`use_image_capture`].
+pub struct UseImageCaptureReturn<TakePhotoFn, GrabFrameFn, SetZoomFn, SetTorchFn>
+where
+    TakePhotoFn: Fn() + Clone + Send + Sync,
+    GrabFrameFn: Fn() + Clone + Send + Sync,
+    SetZoomFn: Fn(f64) + Clone + Send + Sync,
+    SetTorchFn: Fn(bool) + Clone + Send + Sync,
+{
+    /// Whether the ImageCapture API is supported
+    pub is_supported: Signal<bool>,
+
+    /// The last photo taken with `take_photo`
+    pub photo: Signal<Option<web_sys::Blob>, LocalStorage>,
+
+    /// The last frame grabbed with `grab_frame`
+    pub frame: Signal<Option<web_sys::ImageBitmap>, LocalStorage>,
+
+    /// Zoom and torch capabilities of the camera
+    pub capabilities: Signal<ImageCaptureCapabilities>,
+
+    /// The current zoom factor if the camera supports zooming
+    pub zoom: Signal<Option<f64>>,
+
+    /// Whether the torch is on
+    pub torch: Signal<bool>,
+
+    /// Take a photo with the full resolution of the camera
+    pub take_photo: TakePhotoFn,
+
+    /// Grab the current frame of the video
+    pub grab_frame: GrabFrameFn,
+
+    /// Set the zoom factor. Does nothing if the camera can't zoom.
+    pub set_zoom: SetZoomFn,
+
+    /// Turn the torch on or off. Does nothing if the camera has no torch.
+    pub set_torch: SetTorchFn,
+}
@@ -0,0 +1,218 @@
+use crate::use_media_query;
+use crate::utils::LocalBoxFuture;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Show the install prompt of a [Progressive Web App](https://developer.mozilla.org/en-US/docs/Web/Progressive_web_apps)
+/// whenever you want.
+///
+/// The [`beforeinstallprompt`](https://developer.mozilla.org/en-US/docs/Web/API/Window/beforeinstallprompt_event)
+/// event is captured so the prompt can later be shown with `prompt`, e.g. from a custom install button.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_install_prompt)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_install_prompt, InstallPromptOutcome, UseInstallPromptReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseInstallPromptReturn { can_install, is_installed, prompt } = use_install_prompt();
+///
+/// let install = move |_| {
+///     let choice = prompt();
+///
+///     spawn_local(async move {
+///         if choice.await == InstallPromptOutcome::Accepted {
+///             log!("Thanks for installing!");
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <Show when=move || can_install.get()>
+///         <button on:click=install.clone()>"Install app"</button>
+///     </Show>
+///     <Show when=move || is_installed.get()>"Running as installed app"</Show>
+/// }
+/// # }
+/// ```
+///
+/// `is_installed` is `true` if the app runs in standalone display mode or as soon as the
+/// [`appinstalled`](https://developer.mozilla.org/en-US/docs/Web/API/Window/appinstalled_event) event is fired.
+///
+/// The captured event can only be used once. After `prompt` has been called `can_install` is `false` until the
+/// browser fires another `beforeinstallprompt` event. If there is no captured event, the future resolves with
+/// `InstallPromptOutcome::Unavailable` immediately.
+///
+/// Please note that only some browsers support the `beforeinstallprompt` event.
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `prompt` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_install_prompt`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `can_install` and `is_installed` are always `false` and `prompt` resolves with
+/// `InstallPromptOutcome::Unavailable`.
+pub fn use_install_prompt(
+) -> UseInstallPromptReturn<impl Fn() -> InstallPromptFuture + Clone + Send + Sync> {
+    use_install_prompt_with_options(UseInstallPromptOptions::default())
+}
+
+/// Version of [`use_install_prompt`] that takes a `UseInstallPromptOptions`. See [`use_install_prompt`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_install_prompt_with_options(
+    options: UseInstallPromptOptions,
+) -> UseInstallPromptReturn<impl Fn() -> InstallPromptFuture + Clone + Send + Sync> {
+    let UseInstallPromptOptions { prevent_default } = options;
+
+    let (install_event, set_install_event) = signal_local(None::<web_sys::Event>);
+    let (app_installed, set_app_installed) = signal(false);
+
+    let is_standalone = use_media_query("(display-mode: standalone)");
+
+    let prompt;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_install_event;
+        let _ = set_app_installed;
+
+        prompt = || -> InstallPromptFuture {
+            Box::pin(std::future::ready(InstallPromptOutcome::Unavailable))
+        };
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js, js_fut, sendwrap_fn, use_event_listener};
+        use wasm_bindgen::JsCast;
+
+        let _ = use_event_listener(
+            window(),
+            leptos::ev::Custom::<web_sys::Event>::new("beforeinstallprompt"),
+            move |event| {
+                if prevent_default {
+                    event.prevent_default();
+                }
+
+                set_install_event.set(Some(event));
+            },
+        );
+
+        let _ = use_event_listener(
+            window(),
+            leptos::ev::Custom::<web_sys::Event>::new("appinstalled"),
+            move |_| {
+                set_install_event.set(None);
+                set_app_installed.set(true);
+            },
+        );
+
+        // iOS Safari doesn't support the display-mode media query
+        let navigator = window().navigator();
+        if js!(navigator["standalone"])
+            .ok()
+            .and_then(|standalone| standalone.as_bool())
+            .unwrap_or_default()
+        {
+            set_app_installed.set(true);
+        }
+
+        prompt = sendwrap_fn!(move || -> InstallPromptFuture {
+            let Some(event) = install_event.get_untracked() else {
+                return Box::pin(std::future::ready(InstallPromptOutcome::Unavailable));
+            };
+
+            // the event can only be used once
+            set_install_event.set(None);
+
+            let show_prompt = js!(event["prompt"])
+                .and_then(|show| show.unchecked_into::<js_sys::Function>().call0(&event));
+
+            Box::pin(async move {
+                if show_prompt.is_err() {
+                    return InstallPromptOutcome::Unavailable;
+                }
+
+                let Ok(user_choice) = js!(event["userChoice"]) else {
+                    return InstallPromptOutcome::Unavailable;
+                };
+
+                let Ok(choice) = js_fut!(js_sys::Promise::resolve(&user_choice)).await else {
+                    return InstallPromptOutcome::Unavailable;
+                };
+
+                match js!(choice["outcome"])
+                    .ok()
+                    .and_then(|outcome| outcome.as_string())
+                {
+                    Some(outcome) if outcome == "accepted" => InstallPromptOutcome::Accepted,
+                    Some(outcome) if outcome == "dismissed" => InstallPromptOutcome::Dismissed,
+                    _ => InstallPromptOutcome::Unavailable,
+                }
+            })
+        });
+    }
+
+    UseInstallPromptReturn {
+        can_install: Signal::derive(move || install_event.with(Option::is_some)),
+        is_installed: Signal::derive(move || app_installed.get() || is_standalone.get()),
+        prompt,
+    }
+}
+
+/// Choice of the user in the install prompt of [`use_install_prompt`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InstallPromptOutcome {
+    /// The user installed the app
+    Accepted,
+    /// The user dismissed the prompt
+    Dismissed,
+    /// There was no install prompt available
+    Unavailable,
+}
+
+/// Future returned by `prompt` of [`use_install_prompt`]. Resolves with the choice of the user.
+pub type InstallPromptFuture = LocalBoxFuture<InstallPromptOutcome>;
+
+/// Options for [`use_install_prompt_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseInstallPromptOptions {
+    /// Prevent the browser from showing its own install UI (like the mini-infobar on mobile) when
+    /// the `beforeinstallprompt` event is captured. Defaults to `true`.
+    prevent_default: bool,
+}
+
+impl Default for UseInstallPromptOptions {
+    fn default() -> Self {
+        Self {
+            prevent_default: true,
+        }
+    }
+}
+
+/// Return type of [`use_install_prompt`].
+pub struct UseInstallPromptReturn<PromptFn>
+where
+    PromptFn: Fn() -> InstallPromptFuture + Clone + Send + Sync,
+{
+    /// `true` while there is a captured install prompt that can be shown with `prompt`
+    pub can_install: Signal<bool>,
+
+    /// `true` if the app is installed and running in standalone mode or has just been installed
+    pub is_installed: Signal<bool>,
+
+    /// Show the install prompt. Returns a future that resolves with the choice of the user.
+    pub prompt: PromptFn,
+}
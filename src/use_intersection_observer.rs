@@ -49,6 +49,64 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ## Signals
+///
+/// Apart from the callback, the latest observed intersection is also available as signals.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_intersection_observer_with_options, UseIntersectionObserverOptions, UseIntersectionObserverReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseIntersectionObserverReturn {
+///     ratio,
+///     intersection_rect,
+///     ..
+/// } = use_intersection_observer_with_options(
+///     el,
+///     |_, _| {},
+///     UseIntersectionObserverOptions::default().thresholds(vec![0.0, 0.25, 0.5, 0.75, 1.0]),
+/// );
+///
+/// view! {
+///     <div node_ref=el>{move || format!("{:.0}% visible", ratio.get() * 100.0)}</div>
+/// }
+/// # }
+/// ```
+///
+/// If you observe multiple targets, the signals reflect the last entry reported by the observer.
+///
+/// ## Reactive Options
+///
+/// `root_margin` and `thresholds` can be signals. Whenever they change the observer is rebuilt.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_intersection_observer_with_options, UseIntersectionObserverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+/// let (margin, set_margin) = signal(100);
+///
+/// use_intersection_observer_with_options(
+///     el,
+///     |entries, _| {
+///         // ...
+///     },
+///     UseIntersectionObserverOptions::default()
+///         .root_margin(Signal::derive(move || format!("{}px", margin.get()))),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `pause`, `resume` and `stop` are sendwrapped functions. They can
@@ -107,6 +165,12 @@ where
 
     let (is_active, set_active) = signal(immediate);
 
+    let (ratio, set_ratio) = signal(0.0);
+    let (is_intersecting, set_intersecting) = signal(false);
+    let (bounding_client_rect, set_bounding_client_rect) = signal_local(None);
+    let (intersection_rect, set_intersection_rect) = signal_local(None);
+    let (root_bounds, set_root_bounds) = signal_local(None);
+
     cfg_if! { if #[cfg(feature = "ssr")] {
         let pause = || {};
         let cleanup = || {};
@@ -116,17 +180,24 @@ where
 
         let closure_js = Closure::<dyn FnMut(js_sys::Array, web_sys::IntersectionObserver)>::new(
             move |entries: js_sys::Array, observer| {
+                let entries = entries
+                    .to_vec()
+                    .into_iter()
+                    .map(|v| v.unchecked_into::<web_sys::IntersectionObserverEntry>())
+                    .collect::<Vec<_>>();
+
+                if let Some(entry) = entries.last() {
+                    set_ratio.set(entry.intersection_ratio());
+                    set_intersecting.set(entry.is_intersecting());
+                    set_bounding_client_rect.set(Some(entry.bounding_client_rect()));
+                    set_intersection_rect.set(Some(entry.intersection_rect()));
+                    set_root_bounds.set(entry.root_bounds());
+                }
+
                 #[cfg(debug_assertions)]
                 let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
-                callback(
-                    entries
-                        .to_vec()
-                        .into_iter()
-                        .map(|v| v.unchecked_into::<web_sys::IntersectionObserverEntry>())
-                        .collect(),
-                    observer,
-                );
+                callback(entries, observer);
             },
         )
         .into_js_value();
@@ -156,10 +227,12 @@ where
                         targets.get(),
                         root.as_ref().map(|root| root.get()),
                         is_active.get(),
+                        root_margin.get(),
+                        thresholds.get(),
                     )
                 },
                 move |values, _, _| {
-                    let (targets, root, is_active) = values;
+                    let (targets, root, is_active, root_margin, thresholds) = values;
 
                     cleanup();
 
@@ -168,7 +241,7 @@ where
                     }
 
                     let options = web_sys::IntersectionObserverInit::new();
-                    options.set_root_margin(&root_margin);
+                    options.set_root_margin(root_margin);
                     options.set_threshold(
                         &thresholds
                             .iter()
@@ -222,6 +295,11 @@ where
 
     UseIntersectionObserverReturn {
         is_active: is_active.into(),
+        ratio: ratio.into(),
+        is_intersecting: is_intersecting.into(),
+        bounding_client_rect: bounding_client_rect.into(),
+        intersection_rect: intersection_rect.into(),
+        root_bounds: root_bounds.into(),
         pause,
         resume: sendwrap_fn!(move || {
             cleanup();
@@ -255,8 +333,9 @@ where
     /// [`margin`](https://developer.mozilla.org/en-US/docs/Web/CSS/margin) property; see
     /// [The intersection root and root margin](https://developer.mozilla.org/en-US/docs/Web/API/Intersection_Observer_API#the_intersection_root_and_root_margin)
     /// for more information on how the margin works and the syntax. The default is `"0px"`.
+    /// If this is a signal the observer is rebuilt whenever it changes.
     #[builder(into)]
-    root_margin: String,
+    root_margin: Signal<String>,
 
     // TODO : validate that each number is between 0 and 1 ?
     /// A `Vec` of numbers between 0.0 and 1.0, specifying a ratio of intersection area to total
@@ -265,7 +344,9 @@ where
     /// element is visible. See [Thresholds](https://developer.mozilla.org/en-US/docs/Web/API/Intersection_Observer_API#thresholds)
     /// for a more in-depth description of how thresholds are used.
     /// The default is a single threshold of `[0.0]`.
-    thresholds: Vec<f64>,
+    /// If this is a signal the observer is rebuilt whenever it changes.
+    #[builder(into)]
+    thresholds: Signal<Vec<f64>>,
 
     #[builder(skip)]
     _marker: PhantomData<M>,
@@ -280,7 +361,7 @@ where
             immediate: true,
             root: None,
             root_margin: "0px".into(),
-            thresholds: vec![0.0],
+            thresholds: vec![0.0].into(),
             _marker: PhantomData,
         }
     }
@@ -301,4 +382,14 @@ where
     pub stop: StopFn,
     /// A signal which is `true` when the `IntersectionObserver` is active, and `false` when paused or stopped.
     pub is_active: Signal<bool>,
+    /// The latest [`intersectionRatio`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/intersectionRatio)
+    pub ratio: Signal<f64>,
+    /// The latest [`isIntersecting`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/isIntersecting)
+    pub is_intersecting: Signal<bool>,
+    /// The latest [`boundingClientRect`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/boundingClientRect)
+    pub bounding_client_rect: Signal<Option<web_sys::DomRectReadOnly>, LocalStorage>,
+    /// The latest [`intersectionRect`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/intersectionRect)
+    pub intersection_rect: Signal<Option<web_sys::DomRectReadOnly>, LocalStorage>,
+    /// The latest [`rootBounds`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/rootBounds)
+    pub root_bounds: Signal<Option<web_sys::DomRectReadOnly>, LocalStorage>,
 }
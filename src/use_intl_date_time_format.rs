@@ -0,0 +1,600 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::js;
+use crate::utils::js_value_from_to_string;
+use cfg_if::cfg_if;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use std::fmt::Display;
+use wasm_bindgen::JsValue;
+
+/// Reactive [`Intl.DateTimeFormat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat).
+///
+/// Dates are passed as milliseconds since the Unix epoch, the same representation that
+/// [`fn@crate::use_timestamp`] and `js_sys::Date::now()` use. Time zones are resolved by the browser,
+/// so no time zone database has to be shipped with your wasm binary.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_intl_date_time_format)
+///
+/// ## Usage
+///
+/// In basic use without specifying a locale, a formatted string in the default locale and with default options is returned.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_intl_date_time_format, UseIntlDateTimeFormatOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// // 2024-03-15T12:30:00Z
+/// let (date, set_date) = signal(1_710_505_800_000.0);
+///
+/// let date_time_format = use_intl_date_time_format(UseIntlDateTimeFormatOptions::default());
+///
+/// let formatted = date_time_format.format(date); // "3/15/2024" if in US English locale
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Using locales and options
+///
+/// Use the `locales` argument to get the format of the language used in the user interface of your
+/// application. The output can be customized with styles or with individual date and time components.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{
+/// #     use_intl_date_time_format, DateTimeMonthWidth, DateTimeNumericWidth, DateTimeStyle,
+/// #     DateTimeTextWidth, UseIntlDateTimeFormatOptions,
+/// # };
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let date = 1_710_505_800_000.0;
+///
+/// let date_time_format = use_intl_date_time_format(
+///     UseIntlDateTimeFormatOptions::default()
+///         .locale("de-DE")
+///         .date_style(DateTimeStyle::Full),
+/// );
+/// let formatted = date_time_format.format(date); // "Freitag, 15. März 2024"
+///
+/// let date_time_format = use_intl_date_time_format(
+///     UseIntlDateTimeFormatOptions::default()
+///         .locale("en-GB")
+///         .weekday(DateTimeTextWidth::Short)
+///         .day(DateTimeNumericWidth::Numeric)
+///         .month(DateTimeMonthWidth::Long)
+///         .hour(DateTimeNumericWidth::TwoDigit)
+///         .minute(DateTimeNumericWidth::TwoDigit)
+///         .time_zone("UTC"),
+/// );
+/// let formatted = date_time_format.format(date); // "Fri 15 March, 12:30"
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// For an exhaustive list of options see [`UseIntlDateTimeFormatOptions`](https://docs.rs/leptos_use/latest/leptos_use/struct.UseIntlDateTimeFormatOptions.html).
+///
+/// ## Time zones
+///
+/// By default dates are rendered in the time zone of the user's system. Set
+/// [`UseIntlDateTimeFormatOptions::time_zone`] to an IANA time zone name to render them in a specific
+/// time zone instead. The time zone that is actually used can be read with `resolved_time_zone`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_intl_date_time_format, DateTimeStyle, UseIntlDateTimeFormatOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (time_zone, set_time_zone) = signal("America/New_York".to_string());
+///
+/// let date_time_format = use_intl_date_time_format(Signal::derive(move || {
+///     UseIntlDateTimeFormatOptions::default()
+///         .locale("en-US")
+///         .time_style(DateTimeStyle::Short)
+///         .time_zone(time_zone.get())
+/// }));
+///
+/// let formatted = date_time_format.format(1_710_505_800_000.0); // "8:30 AM", then "9:30 PM" after set_time_zone("Asia/Tokyo")
+///
+/// let user_time_zone = use_intl_date_time_format(UseIntlDateTimeFormatOptions::default())
+///     .resolved_time_zone(); // e.g. "Europe/Berlin"
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Formatting ranges
+///
+/// The `format_range` method formats a date range in the most concise way, omitting the parts
+/// that both dates have in common.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_intl_date_time_format, DateTimeStyle, UseIntlDateTimeFormatOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let date_time_format = use_intl_date_time_format(
+///     UseIntlDateTimeFormatOptions::default()
+///         .locale("en-US")
+///         .date_style(DateTimeStyle::Medium)
+///         .time_zone("UTC"),
+/// );
+///
+/// let formatted = date_time_format.format_range(1_710_505_800_000.0, 1_710_851_400_000.0); // "Mar 15 – 19, 2024"
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// Since `Intl.DateTimeFormat` is a JavaScript API it is not available on the server. That's why
+/// it falls back to an ISO 8601 string in UTC on the server.
+pub fn use_intl_date_time_format(
+    options: impl Into<Signal<UseIntlDateTimeFormatOptions>>,
+) -> UseIntlDateTimeFormatReturn {
+    cfg_if! { if #[cfg(feature = "ssr")] {
+        UseIntlDateTimeFormatReturn
+    } else {
+        let options = options.into();
+
+        let date_time_format = Memo::new_with_compare(
+            move |_| {
+                options.with(|options| {
+                    SendWrapper::new(js_sys::Intl::DateTimeFormat::new(
+                        &js_sys::Array::from_iter(options.locales.iter().map(JsValue::from)),
+                        &js_sys::Object::from(options.clone()),
+                    ))
+                })
+            },
+            // a new instance is only created when the options have changed
+            |_, _| true,
+        );
+
+        UseIntlDateTimeFormatReturn {
+            js_intl_date_time_format: date_time_format.into(),
+        }
+    }}
+}
+
+/// The overall style of the date or time part, see [`UseIntlDateTimeFormatOptions::date_style`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum DateTimeStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl Display for DateTimeStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "full"),
+            Self::Long => write!(f, "long"),
+            Self::Medium => write!(f, "medium"),
+            Self::Short => write!(f, "short"),
+        }
+    }
+}
+
+js_value_from_to_string!(DateTimeStyle);
+
+/// The representation of textual date components like the weekday or the era.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum DateTimeTextWidth {
+    /// E.g., `Thursday`
+    Long,
+    /// E.g., `Thu`
+    Short,
+    /// E.g., `T`. Two weekdays may have the same narrow style for some locales (e.g. `Tuesday`'s narrow style is also `T`).
+    Narrow,
+}
+
+impl Display for DateTimeTextWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Long => write!(f, "long"),
+            Self::Short => write!(f, "short"),
+            Self::Narrow => write!(f, "narrow"),
+        }
+    }
+}
+
+js_value_from_to_string!(DateTimeTextWidth);
+
+/// The representation of numeric date and time components.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum DateTimeNumericWidth {
+    /// E.g., `1`
+    Numeric,
+    /// E.g., `01`
+    TwoDigit,
+}
+
+impl Display for DateTimeNumericWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Numeric => write!(f, "numeric"),
+            Self::TwoDigit => write!(f, "2-digit"),
+        }
+    }
+}
+
+js_value_from_to_string!(DateTimeNumericWidth);
+
+/// The representation of the month.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum DateTimeMonthWidth {
+    /// E.g., `3`
+    Numeric,
+    /// E.g., `03`
+    TwoDigit,
+    /// E.g., `March`
+    Long,
+    /// E.g., `Mar`
+    Short,
+    /// E.g., `M`. Two months may have the same narrow style for some locales (e.g. `May`'s narrow style is also `M`).
+    Narrow,
+}
+
+impl Display for DateTimeMonthWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Numeric => write!(f, "numeric"),
+            Self::TwoDigit => write!(f, "2-digit"),
+            Self::Long => write!(f, "long"),
+            Self::Short => write!(f, "short"),
+            Self::Narrow => write!(f, "narrow"),
+        }
+    }
+}
+
+js_value_from_to_string!(DateTimeMonthWidth);
+
+/// The hour cycle to use, see [`UseIntlDateTimeFormatOptions::hour_cycle`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum HourCycle {
+    /// Midnight is `0:00 AM`, the last hour is `11 PM`
+    H11,
+    /// Midnight is `12:00 AM`, the last hour is `11 PM`
+    H12,
+    /// Midnight is `0:00`, the last hour is `23`
+    H23,
+    /// Midnight is `24:00`, the last hour is `23`
+    H24,
+}
+
+impl Display for HourCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::H11 => write!(f, "h11"),
+            Self::H12 => write!(f, "h12"),
+            Self::H23 => write!(f, "h23"),
+            Self::H24 => write!(f, "h24"),
+        }
+    }
+}
+
+js_value_from_to_string!(HourCycle);
+
+/// The localized representation of the time zone name.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TimeZoneName {
+    /// E.g., `Pacific Standard Time`, `Nordamerikanische Westküsten-Normalzeit`
+    Long,
+    /// E.g., `PST`, `GMT-8`
+    Short,
+    /// E.g., `GMT-0800`
+    ShortOffset,
+    /// E.g., `GMT-08:00`
+    LongOffset,
+    /// E.g., `PT`, `Los Angeles Zeit`
+    ShortGeneric,
+    /// E.g., `Pacific Time`, `Nordamerikanische Westküstenzeit`
+    LongGeneric,
+}
+
+impl Display for TimeZoneName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Long => write!(f, "long"),
+            Self::Short => write!(f, "short"),
+            Self::ShortOffset => write!(f, "shortOffset"),
+            Self::LongOffset => write!(f, "longOffset"),
+            Self::ShortGeneric => write!(f, "shortGeneric"),
+            Self::LongGeneric => write!(f, "longGeneric"),
+        }
+    }
+}
+
+js_value_from_to_string!(TimeZoneName);
+
+/// Options for [`use_intl_date_time_format`].
+///
+/// All component options default to `None` which leaves the choice to the browser. If neither
+/// a style nor any component is given the date is formatted with `year`, `month` and `day` as `Numeric`.
+/// Please note that `date_style` and `time_style` can't be combined with the individual component options.
+#[derive(DefaultBuilder, Default, Clone, PartialEq, Debug)]
+pub struct UseIntlDateTimeFormatOptions {
+    /// A vec of strings, each with a BCP 47 language tag. Please refer to the
+    /// [MDN Docs](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat#parameters)
+    /// for more info.
+    locales: Vec<String>,
+
+    /// The date formatting style to use.
+    #[builder(into)]
+    date_style: Option<DateTimeStyle>,
+
+    /// The time formatting style to use.
+    #[builder(into)]
+    time_style: Option<DateTimeStyle>,
+
+    /// The IANA time zone name to use, e.g. `"UTC"`, `"America/New_York"` or `"Asia/Kolkata"`.
+    /// Defaults to the time zone of the user's system.
+    #[builder(into)]
+    time_zone: Option<String>,
+
+    /// Whether to use 12-hour time (as opposed to 24-hour time). Overrides [`UseIntlDateTimeFormatOptions::hour_cycle`].
+    /// The default is locale dependent.
+    #[builder(into)]
+    hour12: Option<bool>,
+
+    /// The hour cycle to use. The default is locale dependent.
+    #[builder(into)]
+    hour_cycle: Option<HourCycle>,
+
+    /// The calendar to use, such as `"chinese"`, `"gregory"`, `"persian"`, and so on.
+    /// For a list of supported calendar types, see [`Intl.Locale.prototype.getCalendars()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCalendars#supported_calendar_types).
+    #[builder(into)]
+    calendar: Option<String>,
+
+    /// The numbering system to use for number formatting, such as `"arab"`, `"hans"`, `"mathsans"`, and so on.
+    /// For a list of supported numbering system types, see [`Intl.Locale.prototype.getNumberingSystems()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getNumberingSystems#supported_numbering_system_types).
+    #[builder(into)]
+    numbering_system: Option<String>,
+
+    /// The representation of the weekday.
+    #[builder(into)]
+    weekday: Option<DateTimeTextWidth>,
+
+    /// The representation of the era, e.g. `AD` / `Anno Domini`.
+    #[builder(into)]
+    era: Option<DateTimeTextWidth>,
+
+    /// The representation of the year.
+    #[builder(into)]
+    year: Option<DateTimeNumericWidth>,
+
+    /// The representation of the month.
+    #[builder(into)]
+    month: Option<DateTimeMonthWidth>,
+
+    /// The representation of the day.
+    #[builder(into)]
+    day: Option<DateTimeNumericWidth>,
+
+    /// The formatting style used for day periods like "in the morning", "am", "noon", "n" etc.
+    /// Only has an effect if a 12-hour clock is used.
+    #[builder(into)]
+    day_period: Option<DateTimeTextWidth>,
+
+    /// The representation of the hour.
+    #[builder(into)]
+    hour: Option<DateTimeNumericWidth>,
+
+    /// The representation of the minute.
+    #[builder(into)]
+    minute: Option<DateTimeNumericWidth>,
+
+    /// The representation of the second.
+    #[builder(into)]
+    second: Option<DateTimeNumericWidth>,
+
+    /// The number of digits used to represent fractions of a second. Possible values are from 1 to 3.
+    #[builder(into)]
+    fractional_second_digits: Option<u8>,
+
+    /// The localized representation of the time zone name.
+    #[builder(into)]
+    time_zone_name: Option<TimeZoneName>,
+}
+
+impl UseIntlDateTimeFormatOptions {
+    pub fn locale(self, locale: &str) -> Self {
+        Self {
+            locales: vec![locale.to_string()],
+            ..self
+        }
+    }
+}
+
+impl From<UseIntlDateTimeFormatOptions> for js_sys::Object {
+    fn from(options: UseIntlDateTimeFormatOptions) -> Self {
+        let obj = Self::new();
+
+        macro_rules! set_optional {
+            ($($field:ident => $key:literal),* $(,)?) => {
+                $(
+                    if let Some(value) = options.$field {
+                        js!(obj[$key] = value);
+                    }
+                )*
+            };
+        }
+
+        set_optional!(
+            date_style => "dateStyle",
+            time_style => "timeStyle",
+            time_zone => "timeZone",
+            hour12 => "hour12",
+            hour_cycle => "hourCycle",
+            calendar => "calendar",
+            numbering_system => "numberingSystem",
+            weekday => "weekday",
+            era => "era",
+            year => "year",
+            month => "month",
+            day => "day",
+            day_period => "dayPeriod",
+            hour => "hour",
+            minute => "minute",
+            second => "second",
+            fractional_second_digits => "fractionalSecondDigits",
+            time_zone_name => "timeZoneName",
+        );
+
+        obj
+    }
+}
+
+cfg_if! { if #[cfg(feature = "ssr")] {
+    #[derive(Copy, Clone)]
+    pub struct UseIntlDateTimeFormatReturn;
+} else {
+    #[derive(Copy, Clone)]
+    pub struct UseIntlDateTimeFormatReturn {
+        /// The instance of [`Intl.DateTimeFormat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat).
+        /// It's recreated when the options change.
+        pub js_intl_date_time_format: Signal<SendWrapper<js_sys::Intl::DateTimeFormat>>,
+    }
+}}
+
+impl UseIntlDateTimeFormatReturn {
+    /// Formats a date given in milliseconds since the Unix epoch. Updates when the date or the options change.
+    pub fn format(&self, date: impl Into<Signal<f64>>) -> Signal<String> {
+        let date = date.into();
+        let this = *self;
+
+        Signal::derive(move || this.format_value(date.get()))
+    }
+
+    /// Formats a date given in milliseconds since the Unix epoch and returns a plain `String`.
+    pub fn format_value(&self, date: f64) -> String {
+        cfg_if! { if #[cfg(feature = "ssr")] {
+            iso_string(date)
+        } else {
+            self.js_intl_date_time_format.with(|date_time_format| {
+                date_time_format
+                    .format()
+                    .call1(date_time_format, &js_sys::Date::new(&JsValue::from(date)))
+                    .ok()
+                    .and_then(|result| result.as_string())
+                    .unwrap_or_default()
+            })
+        }}
+    }
+
+    /// Formats a date range in the most concise way based on the locales and options.
+    /// Both dates are given in milliseconds since the Unix epoch.
+    ///
+    /// ```
+    /// # use leptos::prelude::*;
+    /// # use leptos_use::{use_intl_date_time_format, DateTimeMonthWidth, DateTimeNumericWidth, UseIntlDateTimeFormatOptions};
+    /// #
+    /// # #[component]
+    /// # fn Demo() -> impl IntoView {
+    /// let date_time_format = use_intl_date_time_format(
+    ///     UseIntlDateTimeFormatOptions::default()
+    ///         .locale("en-US")
+    ///         .year(DateTimeNumericWidth::Numeric)
+    ///         .month(DateTimeMonthWidth::Short)
+    ///         .day(DateTimeNumericWidth::Numeric)
+    ///         .time_zone("UTC"),
+    /// );
+    ///
+    /// // 2024-01-10 to 2024-03-20
+    /// let formatted = date_time_format.format_range(1_704_844_800_000.0, 1_710_892_800_000.0); // "Jan 10 – Mar 20, 2024"
+    /// #
+    /// # view! { }
+    /// # }
+    /// ```
+    pub fn format_range(
+        &self,
+        start: impl Into<Signal<f64>>,
+        end: impl Into<Signal<f64>>,
+    ) -> Signal<String> {
+        let start = start.into();
+        let end = end.into();
+        let this = *self;
+
+        Signal::derive(move || this.format_range_value(start.get(), end.get()))
+    }
+
+    /// Same as [`UseIntlDateTimeFormatReturn::format_range`] but returns a plain `String`.
+    pub fn format_range_value(&self, start: f64, end: f64) -> String {
+        cfg_if! { if #[cfg(feature = "ssr")] {
+            format!("{} - {}", iso_string(start), iso_string(end))
+        } else {
+            self.js_intl_date_time_format.with(|date_time_format| {
+                date_time_format
+                    .format_range(
+                        &js_sys::Date::new(&JsValue::from(start)),
+                        &js_sys::Date::new(&JsValue::from(end)),
+                    )
+                    .map(String::from)
+                    .unwrap_or_default()
+            })
+        }}
+    }
+
+    /// The IANA name of the time zone that is used for formatting. This is either
+    /// [`UseIntlDateTimeFormatOptions::time_zone`] or, if that is not set, the time zone of the user's system.
+    ///
+    /// On the server this is always `"UTC"`.
+    pub fn resolved_time_zone(&self) -> Signal<String> {
+        cfg_if! { if #[cfg(feature = "ssr")] {
+            Signal::derive(|| "UTC".to_string())
+        } else {
+            let date_time_format = self.js_intl_date_time_format;
+
+            Signal::derive(move || {
+                date_time_format.with(|date_time_format| {
+                    let options = date_time_format.resolved_options();
+
+                    js!(options["timeZone"])
+                        .ok()
+                        .and_then(|time_zone| time_zone.as_string())
+                        .unwrap_or_default()
+                })
+            })
+        }}
+    }
+}
+
+/// Formats milliseconds since the Unix epoch as an ISO 8601 string in UTC, like `Date.prototype.toISOString()`.
+#[cfg(feature = "ssr")]
+fn iso_string(ms: f64) -> String {
+    let ms = ms as i64;
+    let secs = ms.div_euclid(1000);
+    let days = secs.div_euclid(86_400);
+    let secs_of_day = secs.rem_euclid(86_400);
+
+    // civil date from days since epoch, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60,
+        ms.rem_euclid(1000),
+    )
+}
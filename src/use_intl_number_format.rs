@@ -1,11 +1,12 @@
 #![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
 
+use crate::js;
 use crate::utils::js_value_from_to_string;
-use crate::{js, sendwrap_fn};
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
 use std::fmt::Display;
 use wasm_bindgen::{JsCast, JsValue};
 
@@ -91,7 +92,7 @@ use wasm_bindgen::{JsCast, JsValue};
 ///
 /// ```
 /// # use leptos::prelude::*;
-/// # use leptos_use::{NumberStyle, UnitDisplay, use_intl_number_format, UseIntlNumberFormatOptions};
+/// # use leptos_use::{CompactDisplay, Notation, NumberStyle, UnitDisplay, use_intl_number_format, UseIntlNumberFormatOptions};
 /// #
 /// # #[component]
 /// # fn Demo() -> impl IntoView {
@@ -140,11 +141,52 @@ use wasm_bindgen::{JsCast, JsValue};
 ///         .unit_display(UnitDisplay::Long),
 /// );
 /// let formatted = number_format.format::<i32>(16); // 16 litres
+///
+/// // compact notation
+/// let number_format = use_intl_number_format(
+///     UseIntlNumberFormatOptions::default()
+///         .locale("en-US")
+///         .notation(Notation::Compact)
+///         .compact_display(CompactDisplay::Short),
+/// );
+/// let formatted = number_format.format::<i32>(1_234_567); // 1.2M
 /// #
 /// # view! { }
 /// # }
 /// ```
 ///
+/// ## Reactive options
+///
+/// The options can also be a signal. The `Intl.NumberFormat` is recreated whenever they change, for example
+/// when the user picks a different locale or currency. All signals returned by `format` and
+/// `format_range` update accordingly.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{NumberStyle, use_intl_number_format, UseIntlNumberFormatOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (currency, set_currency) = signal("EUR".to_string());
+///
+/// let number_format = use_intl_number_format(Signal::derive(move || {
+///     UseIntlNumberFormatOptions::default()
+///         .locale("en-US")
+///         .style(NumberStyle::Currency)
+///         .currency(currency.get())
+/// }));
+///
+/// let price = number_format.format::<f64>(9.99); // "€9.99", then "$9.99" after set_currency("USD")
+///
+/// view! {
+///     // `format_value` formats a plain value and returns a `String`
+///     <For each=move || vec![1.5, 20.0, 300.25] key=|amount| amount.to_string() let:amount>
+///         <li>{move || number_format.format_value(amount)}</li>
+///     </For>
+/// }
+/// # }
+/// ```
+///
 /// For an exhaustive list of options see [`UseIntlNumberFormatOptions`](https://docs.rs/leptos_use/latest/leptos_use/struct.UseIntlNumberFormatOptions.html).
 ///
 /// ## Formatting ranges
@@ -157,17 +199,29 @@ use wasm_bindgen::{JsCast, JsValue};
 ///
 /// Since `Intl.NumberFormat` is a JavaScript API it is not available on the server. That's why
 /// it falls back to a simple call to `format!()` on the server.
-pub fn use_intl_number_format(options: UseIntlNumberFormatOptions) -> UseIntlNumberFormatReturn {
+pub fn use_intl_number_format(
+    options: impl Into<Signal<UseIntlNumberFormatOptions>>,
+) -> UseIntlNumberFormatReturn {
     cfg_if! { if #[cfg(feature = "ssr")] {
         UseIntlNumberFormatReturn
     } else {
-        let number_format = js_sys::Intl::NumberFormat::new(
-            &js_sys::Array::from_iter(options.locales.iter().map(JsValue::from)),
-            &js_sys::Object::from(options),
+        let options = options.into();
+
+        let number_format = Memo::new_with_compare(
+            move |_| {
+                options.with(|options| {
+                    SendWrapper::new(js_sys::Intl::NumberFormat::new(
+                        &js_sys::Array::from_iter(options.locales.iter().map(JsValue::from)),
+                        &js_sys::Object::from(options.clone()),
+                    ))
+                })
+            },
+            // a new instance is only created when the options have changed
+            |_, _| true,
         );
 
         UseIntlNumberFormatReturn {
-            js_intl_number_format: number_format,
+            js_intl_number_format: number_format.into(),
         }
     }}
 }
@@ -473,7 +527,7 @@ impl Display for TrailingZeroDisplay {
 js_value_from_to_string!(TrailingZeroDisplay);
 
 /// Options for [`use_intl_number_format`].
-#[derive(DefaultBuilder)]
+#[derive(DefaultBuilder, Clone, PartialEq, Debug)]
 pub struct UseIntlNumberFormatOptions {
     /// A vec of strings, each with a BCP 47 language tag. Please refer to the
     /// [MDN Docs](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat#parameters)
@@ -763,12 +817,15 @@ impl From<UseIntlNumberFormatOptions> for js_sys::Object {
 
 cfg_if! { if #[cfg(feature = "ssr")] {
     /// Return type of [`use_intl_number_format`].
+    #[derive(Copy, Clone)]
     pub struct UseIntlNumberFormatReturn;
 } else {
     /// Return type of [`use_intl_number_format`].
+    #[derive(Copy, Clone)]
     pub struct UseIntlNumberFormatReturn {
         /// The instance of [`Intl.NumberFormat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat).
-        pub js_intl_number_format: js_sys::Intl::NumberFormat,
+        /// It's recreated when the options change.
+        pub js_intl_number_format: Signal<SendWrapper<js_sys::Intl::NumberFormat>>,
     }
 }}
 
@@ -789,18 +846,30 @@ impl UseIntlNumberFormatReturn {
                 format!("{}", number.get())
             })
         } else {
-            let number_format = self.js_intl_number_format.clone();
+            let this = *self;
 
-            Signal::derive(sendwrap_fn!(move || {
-                if let Ok(result) = number_format
+            Signal::derive(move || this.format_value(number.get()))
+        }}
+    }
+
+    /// Formats a single number and returns the result directly instead of a signal.
+    /// When called in a reactive context it tracks the options, so it updates when they change.
+    pub fn format_value<N>(&self, number: N) -> String
+    where
+        N: Display,
+        js_sys::Number: From<N>,
+    {
+        cfg_if! { if #[cfg(feature = "ssr")] {
+            format!("{}", number)
+        } else {
+            self.js_intl_number_format.with(|number_format| {
+                number_format
                     .format()
-                    .call1(&number_format, &js_sys::Number::from(number.get()).into())
-                {
-                    result.as_string().unwrap_or_default()
-                } else {
-                    "".to_string()
-                }
-            }))
+                    .call1(number_format, &js_sys::Number::from(number).into())
+                    .ok()
+                    .and_then(|result| result.as_string())
+                    .unwrap_or_default()
+            })
         }}
     }
 
@@ -869,9 +938,11 @@ impl UseIntlNumberFormatReturn {
                 format!("{} - {}", start.get(), end.get())
             })
         } else {
-            let number_format = self.js_intl_number_format.clone();
+            let number_format = self.js_intl_number_format;
 
             Signal::derive_local(move || {
+                let number_format = number_format.get();
+
                 if let Ok(function) = js!(number_format["formatRange"]) {
                     let function = function.unchecked_into::<js_sys::Function>();
 
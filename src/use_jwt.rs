@@ -0,0 +1,424 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::utils::base64url_decode;
+use codee::Decoder;
+use default_struct_builder::DefaultBuilder;
+use leptos::logging::error;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use serde::Deserialize;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Reactive decoding of a [JSON Web Token](https://datatracker.ietf.org/doc/html/rfc7519).
+///
+/// Takes a signal of the token and decodes its header and claims. The claims are decoded into your own
+/// type with the given codec. Additionally the registered claims like `exp` are always available.
+///
+/// > This does **not** verify the signature of the token. Never trust the claims for authorization
+/// > decisions on the server. They are meant to be displayed or to schedule a token refresh on the client.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_jwt)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_jwt, UseJwtReturn};
+/// # use codee::string::JsonSerdeCodec;
+/// # use serde::Deserialize;
+/// #
+/// #[derive(Deserialize, Clone, Debug)]
+/// struct Claims {
+///     name: String,
+///     admin: bool,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (token, set_token) = signal(None::<String>);
+///
+/// let UseJwtReturn {
+///     claims, is_expired, ..
+/// } = use_jwt::<Claims, JsonSerdeCodec>(token);
+///
+/// view! {
+///     <p>{move || claims.get().map(|claims| format!("Logged in as {}", claims.name))}</p>
+///     <Show when=move || is_expired.get()>"Your session has expired"</Show>
+/// }
+/// # }
+/// ```
+///
+/// `is_expired` becomes `true` automatically when the time of the claim `exp` is reached.
+/// Tokens without an `exp` claim never expire.
+///
+/// ## Refresh Ahead
+///
+/// The callback `on_refresh` is called `refresh_ahead` milliseconds before the token expires. If that
+/// time has already passed when the token is set, it's called right away. It's called at most once per token.
+///
+/// Tokens that live shorter than twice `refresh_ahead` are refreshed halfway through their lifetime instead.
+/// The lifetime starts at the `iat` claim or when the token is set if there is none. Tokens that are already
+/// expired when they're set are never refreshed.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_jwt_with_options, UseJwtOptions, UseJwtReturn};
+/// # use codee::string::JsonSerdeCodec;
+/// # use serde::Deserialize;
+/// #
+/// # #[derive(Deserialize, Clone, Debug)]
+/// # struct Claims {
+/// #     name: String,
+/// # }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (token, set_token) = signal(None::<String>);
+///
+/// let UseJwtReturn { claims, .. } = use_jwt_with_options::<Claims, JsonSerdeCodec>(
+///     token,
+///     UseJwtOptions::default()
+///         .refresh_ahead(30_000)
+///         .on_refresh(move || {
+///             // fetch a new token from your server and call set_token
+///         }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the token is decoded the same way and `is_expired` is computed from the system
+/// time when it's read. `on_refresh` is never called on the server.
+pub fn use_jwt<T, C>(token: impl Into<Signal<Option<String>>>) -> UseJwtReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+    C: Decoder<T, Encoded = str>
+        + Decoder<JwtHeader, Encoded = str>
+        + Decoder<JwtRegisteredClaims, Encoded = str>,
+{
+    use_jwt_with_options::<T, C>(token, UseJwtOptions::default())
+}
+
+/// Version of [`use_jwt`] that takes a `UseJwtOptions`. See [`use_jwt`] for how to use.
+pub fn use_jwt_with_options<T, C>(
+    token: impl Into<Signal<Option<String>>>,
+    options: UseJwtOptions<<C as Decoder<T>>::Error>,
+) -> UseJwtReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+    C: Decoder<T, Encoded = str>
+        + Decoder<JwtHeader, Encoded = str>
+        + Decoder<JwtRegisteredClaims, Encoded = str>,
+{
+    let UseJwtOptions {
+        refresh_ahead,
+        on_refresh,
+        on_error,
+    } = options;
+
+    let token = token.into();
+
+    let decoded = Memo::new(move |_| {
+        token.with(|token| {
+            token
+                .as_deref()
+                .and_then(|token| decode_jwt::<T, C>(token).map_err(|err| on_error(err)).ok())
+        })
+    });
+
+    let expires_at = Memo::new(move |_| {
+        decoded.with(|decoded| {
+            decoded
+                .as_ref()
+                .and_then(|decoded| decoded.registered_claims.exp)
+                .map(|exp| exp * 1000.0)
+        })
+    });
+
+    let expire_trigger = Trigger::new();
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use leptos::leptos_dom::helpers::TimeoutHandle;
+        use std::time::Duration;
+
+        let timers = StoredValue::new_local(Vec::<TimeoutHandle>::new());
+
+        let clear_timers = move || {
+            for timer in timers.try_update_value(std::mem::take).unwrap_or_default() {
+                timer.clear();
+            }
+        };
+
+        let schedule = move |delay: f64, callback: Box<dyn FnOnce()>| {
+            // browsers run timeouts that are longer than this immediately
+            if delay > MAX_TIMEOUT_DELAY {
+                return;
+            }
+
+            if let Ok(handle) =
+                set_timeout_with_handle(callback, Duration::from_millis(delay.max(0.0) as u64))
+            {
+                timers.update_value(|timers| timers.push(handle));
+            }
+        };
+
+        Effect::new(move |_| {
+            clear_timers();
+
+            let Some(expires_at) = expires_at.get() else {
+                return;
+            };
+
+            let now = now();
+            let remaining = expires_at - now;
+
+            // an expired token is reported by `is_expired` and isn't refreshed
+            if remaining <= 0.0 {
+                return;
+            }
+
+            schedule(remaining, Box::new(move || expire_trigger.notify()));
+
+            let issued_at = decoded
+                .with_untracked(|decoded| {
+                    decoded
+                        .as_ref()
+                        .and_then(|decoded| decoded.registered_claims.iat)
+                })
+                .map(|iat| iat * 1000.0)
+                .filter(|issued_at| *issued_at <= now)
+                .unwrap_or(now);
+
+            // Short-lived tokens are refreshed halfway through their lifetime. Otherwise every
+            // refreshed token would be refreshed again right away.
+            let refresh_ahead = (refresh_ahead as f64).min((expires_at - issued_at) / 2.0);
+
+            let on_refresh = Arc::clone(&on_refresh);
+            schedule(
+                remaining - refresh_ahead,
+                Box::new(move || {
+                    #[cfg(debug_assertions)]
+                    let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                    on_refresh();
+                }),
+            );
+        });
+
+        on_cleanup(clear_timers);
+    }
+
+    UseJwtReturn {
+        header: Signal::derive(move || {
+            decoded.with(|decoded| decoded.as_ref().map(|decoded| decoded.header.clone()))
+        }),
+        claims: Signal::derive(move || {
+            decoded.with(|decoded| decoded.as_ref().map(|decoded| decoded.claims.clone()))
+        }),
+        registered_claims: Signal::derive(move || {
+            decoded.with(|decoded| {
+                decoded
+                    .as_ref()
+                    .map(|decoded| decoded.registered_claims.clone())
+            })
+        }),
+        expires_at: expires_at.into(),
+        is_expired: Signal::derive(move || {
+            expire_trigger.track();
+            expires_at
+                .get()
+                .is_some_and(|expires_at| expires_at <= now())
+        }),
+    }
+}
+
+/// Maximum delay of `setTimeout` in milliseconds.
+#[cfg(not(feature = "ssr"))]
+const MAX_TIMEOUT_DELAY: f64 = i32::MAX as f64;
+
+/// Milliseconds since the UNIX epoch.
+fn now() -> f64 {
+    #[cfg(feature = "ssr")]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs_f64() * 1000.0)
+            .unwrap_or_default()
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    js_sys::Date::now()
+}
+
+fn decode_jwt<T, C>(token: &str) -> Result<DecodedJwt<T>, UseJwtError<<C as Decoder<T>>::Error>>
+where
+    C: Decoder<T, Encoded = str>
+        + Decoder<JwtHeader, Encoded = str>
+        + Decoder<JwtRegisteredClaims, Encoded = str>,
+{
+    let mut parts = token.trim().split('.');
+
+    let (Some(header), Some(payload), Some(_signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(UseJwtError::Malformed);
+    };
+
+    let decode_part =
+        |part: &str| -> Option<String> { String::from_utf8(base64url_decode(part)?).ok() };
+
+    let header = decode_part(header).ok_or(UseJwtError::Malformed)?;
+    let payload = decode_part(payload).ok_or(UseJwtError::Malformed)?;
+
+    Ok(DecodedJwt {
+        header: <C as Decoder<JwtHeader>>::decode(&header).map_err(|_| UseJwtError::Malformed)?,
+        registered_claims: <C as Decoder<JwtRegisteredClaims>>::decode(&payload)
+            .map_err(|_| UseJwtError::Malformed)?,
+        claims: <C as Decoder<T>>::decode(&payload).map_err(UseJwtError::Claims)?,
+        token: token.to_string(),
+    })
+}
+
+struct DecodedJwt<T> {
+    token: String,
+    header: JwtHeader,
+    registered_claims: JwtRegisteredClaims,
+    claims: T,
+}
+
+impl<T> PartialEq for DecodedJwt<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.token == other.token
+    }
+}
+
+/// The header of a JWT. Further fields are ignored.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct JwtHeader {
+    /// The algorithm the token is signed with like `"HS256"`
+    pub alg: String,
+
+    /// The media type of the token, usually `"JWT"`
+    pub typ: Option<String>,
+
+    /// The content type of the claims
+    pub cty: Option<String>,
+
+    /// The id of the key the token is signed with
+    pub kid: Option<String>,
+}
+
+/// The registered claims of [RFC 7519](https://datatracker.ietf.org/doc/html/rfc7519#section-4.1)
+/// that are present in the token. Times are in seconds since the UNIX epoch.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct JwtRegisteredClaims {
+    /// Issuer
+    pub iss: Option<String>,
+
+    /// Subject
+    pub sub: Option<String>,
+
+    /// Expiration time
+    pub exp: Option<f64>,
+
+    /// Not before
+    pub nbf: Option<f64>,
+
+    /// Issued at
+    pub iat: Option<f64>,
+
+    /// JWT ID
+    pub jti: Option<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum UseJwtError<E> {
+    #[error("The token isn't a valid JWT")]
+    Malformed,
+
+    #[error("Failed to decode the claims: {0}")]
+    Claims(E),
+}
+
+/// Options for [`use_jwt_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseJwtOptions<E> {
+    /// How many milliseconds before the expiration `on_refresh` is called. At most half of the lifetime
+    /// of the token. Defaults to `60_000`.
+    refresh_ahead: u64,
+
+    /// Called `refresh_ahead` milliseconds before the token expires. Defaults to doing nothing.
+    #[builder(skip)]
+    on_refresh: Arc<dyn Fn() + Send + Sync>,
+
+    /// Called when the token can't be decoded. Defaults to logging the error to the console.
+    #[builder(skip)]
+    on_error: Arc<dyn Fn(UseJwtError<E>) + Send + Sync>,
+}
+
+impl<E> UseJwtOptions<E> {
+    /// Called `refresh_ahead` milliseconds before the token expires. Defaults to doing nothing.
+    pub fn on_refresh<F>(self, handler: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        Self {
+            on_refresh: Arc::new(handler),
+            ..self
+        }
+    }
+
+    /// Called when the token can't be decoded. Defaults to logging the error to the console.
+    pub fn on_error<F>(self, handler: F) -> Self
+    where
+        F: Fn(UseJwtError<E>) + Send + Sync + 'static,
+    {
+        Self {
+            on_error: Arc::new(handler),
+            ..self
+        }
+    }
+}
+
+impl<E> Default for UseJwtOptions<E> {
+    fn default() -> Self {
+        Self {
+            refresh_ahead: 60_000,
+            on_refresh: Arc::new(|| {}),
+            on_error: Arc::new(|_| {
+                error!("JWT decoding error");
+            }),
+        }
+    }
+}
+
+/// Return type of [`use_jwt`].
+pub struct UseJwtReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// The decoded header. `None` if there is no token or it can't be decoded.
+    pub header: Signal<Option<JwtHeader>>,
+
+    /// The claims decoded into your type. `None` if there is no token or it can't be decoded.
+    pub claims: Signal<Option<T>>,
+
+    /// The registered claims like `exp` and `sub`
+    pub registered_claims: Signal<Option<JwtRegisteredClaims>>,
+
+    /// When the token expires in milliseconds since the UNIX epoch
+    pub expires_at: Signal<Option<f64>>,
+
+    /// `true` if the token has an `exp` claim that lies in the past
+    pub is_expired: Signal<bool>,
+}
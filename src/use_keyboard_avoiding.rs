@@ -0,0 +1,302 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::use_supported;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Keeps an element clear of the on-screen keyboard of mobile devices.
+///
+/// When the keyboard appears the height of the part of the layout viewport that it covers is
+/// measured and the bound element is padded or moved up by that amount. This is useful for chat
+/// inputs, bottom sheets or toolbars that are fixed to the bottom of the screen.
+///
+/// The height is taken from the [VirtualKeyboard API](https://developer.mozilla.org/en-US/docs/Web/API/VirtualKeyboard_API)
+/// if the option `overlays_content` is `true` and the browser supports it. Otherwise it is computed
+/// from the [visual viewport](https://developer.mozilla.org/en-US/docs/Web/API/VisualViewport).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_keyboard_avoiding)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_keyboard_avoiding, UseKeyboardAvoidingReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseKeyboardAvoidingReturn {
+///     occluded_height,
+///     is_keyboard_open,
+///     ..
+/// } = use_keyboard_avoiding(el);
+///
+/// view! {
+///     <div node_ref=el style="position: fixed; bottom: 0; left: 0; right: 0;">
+///         <input placeholder="Message" />
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// ## Modes
+///
+/// By default the keyboard height is added to the `padding-bottom` of the element. With
+/// [`KeyboardAvoidingMode::Translate`] the element is moved up with a `transform` instead, which
+/// doesn't cause a relayout. With [`KeyboardAvoidingMode::None`] the element isn't touched and you
+/// can use `occluded_height` to lay out your UI yourself.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_keyboard_avoiding_with_options, KeyboardAvoidingMode, UseKeyboardAvoidingOptions, UseKeyboardAvoidingReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseKeyboardAvoidingReturn { occluded_height, .. } = use_keyboard_avoiding_with_options(
+///     el,
+///     UseKeyboardAvoidingOptions::default()
+///         .mode(KeyboardAvoidingMode::Translate)
+///         .offset(8.0),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
+/// The inline style that was present before is restored when the keyboard is closed.
+///
+/// ## Overlays Content
+///
+/// Set the option `overlays_content` to `true` to opt into the VirtualKeyboard API where it is
+/// available. The browser then stops resizing the viewport when the keyboard is shown and this
+/// function is in charge of keeping content visible. While pinch zoomed the visual viewport doesn't
+/// tell anything about the keyboard so `occluded_height` is `0.0` in this case.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `occluded_height` is always `0.0` and the element is not touched.
+pub fn use_keyboard_avoiding<El, M>(target: El) -> UseKeyboardAvoidingReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_keyboard_avoiding_with_options(target, UseKeyboardAvoidingOptions::default())
+}
+
+/// Version of [`use_keyboard_avoiding`] that takes a `UseKeyboardAvoidingOptions`. See [`use_keyboard_avoiding`] for how to use.
+pub fn use_keyboard_avoiding_with_options<El, M>(
+    target: El,
+    options: UseKeyboardAvoidingOptions,
+) -> UseKeyboardAvoidingReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseKeyboardAvoidingOptions {
+        mode,
+        offset,
+        overlays_content,
+    } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let navigator = window().navigator();
+            crate::js!("visualViewport" in &window()) || crate::js!("virtualKeyboard" in &navigator)
+        }
+    });
+
+    let (occluded_height, set_occluded_height) = signal(0.0_f64);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::{resize, scroll, Custom};
+        use wasm_bindgen::JsCast;
+
+        let navigator = window().navigator();
+        let virtual_keyboard = crate::js!(navigator["virtualKeyboard"])
+            .ok()
+            .filter(|vk| !vk.is_undefined() && !vk.is_null());
+
+        match (
+            overlays_content,
+            virtual_keyboard,
+            window().visual_viewport(),
+        ) {
+            (true, Some(virtual_keyboard), _) => {
+                crate::js!(virtual_keyboard["overlaysContent"] = true);
+
+                let virtual_keyboard: web_sys::EventTarget = virtual_keyboard.unchecked_into();
+
+                let _ = use_event_listener(
+                    virtual_keyboard.clone(),
+                    Custom::<web_sys::Event>::new("geometrychange"),
+                    move |_| {
+                        let height = crate::js!(virtual_keyboard["boundingRect"])
+                            .ok()
+                            .and_then(|rect| crate::js!(rect["height"]).ok())
+                            .and_then(|height| height.as_f64())
+                            .unwrap_or_default();
+
+                        set_occluded_height.set(height);
+                    },
+                );
+            }
+            (_, _, Some(visual_viewport)) => {
+                let update = {
+                    let visual_viewport = visual_viewport.clone();
+
+                    move || {
+                        set_occluded_height.set(occluded_by_keyboard(&visual_viewport));
+                    }
+                };
+
+                update();
+
+                let _ = use_event_listener(visual_viewport.clone(), resize, {
+                    let update = update.clone();
+                    move |_| update()
+                });
+                let _ = use_event_listener(visual_viewport, scroll, move |_| update());
+            }
+            _ => {}
+        }
+
+        if mode != KeyboardAvoidingMode::None {
+            let target = target.into_element_maybe_signal();
+
+            let property = match mode {
+                KeyboardAvoidingMode::Translate => "transform",
+                _ => "padding-bottom",
+            };
+
+            // inline style of the element from before it was touched by us
+            let original_style = StoredValue::new_local(None::<(web_sys::HtmlElement, String)>);
+
+            let restore = move || {
+                if let Some((el, value)) = original_style.try_update_value(|v| v.take()).flatten() {
+                    let _ = el.style().set_property(property, &value);
+                }
+            };
+
+            Effect::new(move || {
+                let height = occluded_height.get();
+
+                let Some(el) = target.get() else {
+                    restore();
+                    return;
+                };
+                let el: web_sys::HtmlElement = el.unchecked_into();
+
+                if height <= 0.0 {
+                    restore();
+                    return;
+                }
+
+                let is_new_element = original_style
+                    .with_value(|v| v.as_ref().map(|(prev, _)| prev != &el).unwrap_or(true));
+
+                if is_new_element {
+                    restore();
+
+                    let value = el.style().get_property_value(property).unwrap_or_default();
+                    original_style.set_value(Some((el.clone(), value)));
+                }
+
+                let value = match mode {
+                    KeyboardAvoidingMode::Translate => {
+                        format!("translateY({}px)", -(height + offset))
+                    }
+                    _ => format!("{}px", height + offset),
+                };
+
+                let _ = el.style().set_property(property, &value);
+            });
+
+            on_cleanup(restore);
+        }
+    }
+
+    let is_keyboard_open = Signal::derive(move || occluded_height.get() > 0.0);
+
+    UseKeyboardAvoidingReturn {
+        is_supported,
+        occluded_height: occluded_height.into(),
+        is_keyboard_open,
+    }
+}
+
+/// Height of the layout viewport that isn't covered by the visual viewport.
+#[cfg(not(feature = "ssr"))]
+fn occluded_by_keyboard(visual_viewport: &web_sys::VisualViewport) -> f64 {
+    // pinch zoom shrinks the visual viewport as well
+    if visual_viewport.scale() > 1.0 {
+        return 0.0;
+    }
+
+    let layout_height = window()
+        .inner_height()
+        .ok()
+        .and_then(|h| h.as_f64())
+        .unwrap_or_default();
+
+    // rounding gets rid of the sub pixel differences some browsers report without a keyboard
+    (layout_height - visual_viewport.height() - visual_viewport.offset_top())
+        .round()
+        .max(0.0)
+}
+
+/// How the bound element is kept clear of the keyboard.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub enum KeyboardAvoidingMode {
+    /// Adds the keyboard height to the `padding-bottom` of the element
+    #[default]
+    Padding,
+
+    /// Moves the element up with `transform: translateY(...)`
+    Translate,
+
+    /// Doesn't touch the element. Only `occluded_height` is measured.
+    None,
+}
+
+/// Options for [`use_keyboard_avoiding_with_options`].
+#[derive(DefaultBuilder, Default, Clone, Copy, Debug)]
+pub struct UseKeyboardAvoidingOptions {
+    /// How the element is kept clear of the keyboard. Defaults to [`KeyboardAvoidingMode::Padding`].
+    mode: KeyboardAvoidingMode,
+
+    /// Additional space in pixels between the keyboard and the element while the keyboard is open.
+    /// Defaults to `0.0`.
+    offset: f64,
+
+    /// Use the VirtualKeyboard API and tell the browser not to resize the viewport when the
+    /// keyboard is shown. Falls back to the visual viewport if unsupported. Defaults to `false`.
+    overlays_content: bool,
+}
+
+/// Return type of [`use_keyboard_avoiding`].
+#[derive(Clone, Copy)]
+pub struct UseKeyboardAvoidingReturn {
+    /// Whether the browser supports the VisualViewport or the VirtualKeyboard API
+    pub is_supported: Signal<bool>,
+
+    /// Height in pixels of the part of the viewport that is covered by the keyboard
+    pub occluded_height: Signal<f64>,
+
+    /// `true` while the keyboard covers part of the viewport
+    pub is_keyboard_open: Signal<bool>,
+}
@@ -0,0 +1,781 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive controls and state of an `<audio>` or `<video>` element.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_media_controls)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_media_controls, UseMediaControlsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video = NodeRef::<Video>::new();
+///
+/// let UseMediaControlsReturn { playing, current_time, duration, volume, .. } = use_media_controls(video);
+///
+/// view! {
+///     <video node_ref=video src="video.mp4"></video>
+///     <button on:click=move |_| playing.update(|playing| *playing = !*playing)>
+///         {move || if playing.get() { "Pause" } else { "Play" }}
+///     </button>
+///     <input
+///         type="range"
+///         max=duration
+///         prop:value=current_time
+///         on:input=move |ev| current_time.set(event_target_value(&ev).parse().unwrap_or_default())
+///     />
+///     <input
+///         type="range"
+///         max="1"
+///         step="0.01"
+///         prop:value=volume
+///         on:input=move |ev| volume.set(event_target_value(&ev).parse().unwrap_or_default())
+///     />
+/// }
+/// # }
+/// ```
+///
+/// `playing`, `current_time`, `volume`, `muted` and `rate` are read-write signals. They are updated when the
+/// state of the media element changes and setting them controls the media element. When the element is
+/// mounted they are initialized with the state of the element so attributes like `muted` or `autoplay` keep working.
+///
+/// `duration`, `buffered`, `seeking`, `ended`, `waiting` and `stalled` are read-only state of the element.
+/// `buffered` contains the time ranges `(start, end)` in seconds that have been loaded.
+///
+/// ## Sources
+///
+/// The `<source>` elements of the media element can be managed with the option `src`. Every time the sources
+/// change the media element is reloaded.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_media_controls_with_options, MediaSource, UseMediaControlsOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video = NodeRef::<Video>::new();
+///
+/// use_media_controls_with_options(
+///     video,
+///     UseMediaControlsOptions::default().src(vec![
+///         MediaSource::new("video.webm").mime_type("video/webm".to_string()),
+///         MediaSource::new("video.mp4").mime_type("video/mp4".to_string()),
+///     ]),
+/// );
+///
+/// view! { <video node_ref=video></video> }
+/// # }
+/// ```
+///
+/// ## Text Tracks
+///
+/// Subtitles, captions and other text tracks are provided by the option `tracks`. All text tracks of the element,
+/// including the ones added in the view, are available in the returned `tracks`. Use `enable_track` to show
+/// one of them and `disable_tracks` to hide all of them.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_media_controls_with_options, MediaTextTrackSource, UseMediaControlsOptions, UseMediaControlsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video = NodeRef::<Video>::new();
+///
+/// let UseMediaControlsReturn { tracks, enable_track, .. } = use_media_controls_with_options(
+///     video,
+///     UseMediaControlsOptions::default().tracks(vec![
+///         MediaTextTrackSource::new("subtitles.en.vtt").label("English").srclang("en"),
+///         MediaTextTrackSource::new("subtitles.de.vtt").label("Deutsch").srclang("de"),
+///     ]),
+/// );
+///
+/// view! {
+///     <video node_ref=video src="video.mp4"></video>
+///     <For each=move || tracks.get() key=|track| track.index let:track>
+///         <button on:click={
+///             let enable_track = enable_track.clone();
+///             move |_| enable_track(track.index)
+///         }>{track.label}</button>
+///     </For>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `enable_track` and `disable_tracks` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_media_controls`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the signals keep their default values and the returned closures are no-ops.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_picture_in_picture`]
+/// * [`fn@crate::use_media_session`]
+pub fn use_media_controls<El, M>(
+    target: El,
+) -> UseMediaControlsReturn<impl Fn(usize) + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::HtmlMediaElement, M>,
+{
+    use_media_controls_with_options(target, UseMediaControlsOptions::default())
+}
+
+/// Version of [`use_media_controls`] that takes a `UseMediaControlsOptions`. See [`use_media_controls`] for how to use.
+pub fn use_media_controls_with_options<El, M>(
+    target: El,
+    options: UseMediaControlsOptions,
+) -> UseMediaControlsReturn<impl Fn(usize) + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::HtmlMediaElement, M>,
+{
+    let UseMediaControlsOptions {
+        src,
+        tracks: track_sources,
+    } = options;
+
+    let target = target.into_element_maybe_signal();
+
+    let playing = RwSignal::new(false);
+    let current_time = RwSignal::new(0.0);
+    let volume = RwSignal::new(1.0);
+    let muted = RwSignal::new(false);
+    let rate = RwSignal::new(1.0);
+
+    let (duration, set_duration) = signal(0.0);
+    let (buffered, set_buffered) = signal(Vec::<(f64, f64)>::new());
+    let (seeking, set_seeking) = signal(false);
+    let (ended, set_ended) = signal(false);
+    let (waiting, set_waiting) = signal(false);
+    let (stalled, set_stalled) = signal(false);
+    let (tracks, set_tracks) = signal(Vec::<MediaTextTrack>::new());
+
+    let enable_track;
+    let disable_tracks;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = target;
+        let _ = set_duration;
+        let _ = set_buffered;
+        let _ = set_seeking;
+        let _ = set_ended;
+        let _ = set_waiting;
+        let _ = set_stalled;
+        let _ = set_tracks;
+
+        enable_track = |_: usize| {};
+        disable_tracks = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{sendwrap_fn, use_event_listener};
+        use leptos::ev::{
+            durationchange, emptied, ended as ended_ev, loadeddata, pause, play,
+            playing as playing_ev, progress, ratechange, seeked, seeking as seeking_ev,
+            stalled as stalled_ev, timeupdate, volumechange, waiting as waiting_ev, Custom,
+        };
+
+        let with_el = {
+            let target = target.clone();
+            move |f: &dyn Fn(&web_sys::HtmlMediaElement)| {
+                if let Some(el) = target.get_untracked() {
+                    f(&el);
+                }
+            }
+        };
+
+        let update_buffered = move |el: &web_sys::HtmlMediaElement| {
+            let ranges = el.buffered();
+            set_buffered.set(
+                (0..ranges.length())
+                    .filter_map(|i| Some((ranges.start(i).ok()?, ranges.end(i).ok()?)))
+                    .collect(),
+            );
+        };
+
+        let update_tracks = {
+            let target = target.clone();
+            move || {
+                let Some(list) = target.get_untracked().and_then(|el| el.text_tracks()) else {
+                    return;
+                };
+
+                set_tracks.set(
+                    (0..list.length())
+                        .filter_map(|index| list.get(index))
+                        .enumerate()
+                        .map(|(index, track)| MediaTextTrack::from_web_sys(index, &track))
+                        .collect(),
+                );
+            }
+        };
+
+        // initialize everything with the state of the element
+        Effect::new({
+            let update_tracks = update_tracks.clone();
+            let target = target.clone();
+
+            move || {
+                let Some(el) = target.get() else {
+                    return;
+                };
+
+                untrack(|| {
+                    playing.set(!el.paused());
+                    current_time.set(el.current_time());
+                    volume.set(el.volume());
+                    muted.set(el.muted());
+                    rate.set(el.playback_rate());
+
+                    set_duration.set(el.duration().max(0.0));
+                    set_seeking.set(el.seeking());
+                    set_ended.set(el.ended());
+                    update_buffered(&el);
+                    update_tracks();
+                });
+            }
+        });
+
+        // the values are compared with the element so that updates coming from the element
+        // itself aren't applied again
+        let _ = Effect::watch(
+            move || playing.get(),
+            {
+                let with_el = with_el.clone();
+                move |playing_value, _, _| {
+                    with_el(&|el| {
+                        if *playing_value && el.paused() {
+                            if let Ok(promise) = el.play() {
+                                leptos::task::spawn_local(async move {
+                                    // for example if autoplay is blocked
+                                    if crate::js_fut!(promise).await.is_err() {
+                                        let _ = playing.try_set(false);
+                                    }
+                                });
+                            }
+                        } else if !*playing_value && !el.paused() {
+                            let _ = el.pause();
+                        }
+                    })
+                }
+            },
+            false,
+        );
+
+        let _ = Effect::watch(
+            move || current_time.get(),
+            {
+                let with_el = with_el.clone();
+                move |time, _, _| {
+                    with_el(&|el| {
+                        if el.current_time() != *time {
+                            el.set_current_time(*time);
+                        }
+                    })
+                }
+            },
+            false,
+        );
+
+        let _ = Effect::watch(
+            move || volume.get(),
+            {
+                let with_el = with_el.clone();
+                move |volume: &f64, _, _| {
+                    with_el(&|el| {
+                        let volume = volume.clamp(0.0, 1.0);
+                        if el.volume() != volume {
+                            el.set_volume(volume);
+                        }
+                    })
+                }
+            },
+            false,
+        );
+
+        let _ = Effect::watch(
+            move || muted.get(),
+            {
+                let with_el = with_el.clone();
+                move |muted, _, _| {
+                    with_el(&|el| {
+                        if el.muted() != *muted {
+                            el.set_muted(*muted);
+                        }
+                    })
+                }
+            },
+            false,
+        );
+
+        let _ = Effect::watch(
+            move || rate.get(),
+            {
+                let with_el = with_el.clone();
+                move |rate, _, _| {
+                    with_el(&|el| {
+                        if el.playback_rate() != *rate {
+                            el.set_playback_rate(*rate);
+                        }
+                    })
+                }
+            },
+            false,
+        );
+
+        // sources and text tracks
+        Effect::new({
+            let target = target.clone();
+
+            move || {
+                let sources = src.get();
+
+                let Some(el) = target.get() else {
+                    return;
+                };
+
+                if sources.is_empty() {
+                    return;
+                }
+
+                replace_children(
+                    &el,
+                    "source",
+                    sources.iter().map(MediaSource::to_attributes),
+                );
+                el.load();
+            }
+        });
+
+        Effect::new({
+            let target = target.clone();
+
+            move || {
+                let sources = track_sources.get();
+
+                let Some(el) = target.get() else {
+                    return;
+                };
+
+                if sources.is_empty() {
+                    return;
+                }
+
+                replace_children(
+                    &el,
+                    "track",
+                    sources.iter().map(MediaTextTrackSource::to_attributes),
+                );
+            }
+        });
+
+        // element events
+        macro_rules! listen {
+            ($event:expr, $handler:expr) => {
+                let _ = use_event_listener(target.clone(), $event, {
+                    let with_el = with_el.clone();
+                    move |_| with_el(&$handler)
+                });
+            };
+        }
+
+        listen!(timeupdate, |el| current_time.set(el.current_time()));
+        listen!(durationchange, |el| set_duration
+            .set(el.duration().max(0.0)));
+        listen!(progress, update_buffered);
+        listen!(loadeddata, |el| {
+            set_waiting.set(false);
+            update_buffered(el);
+        });
+        listen!(play, |_| {
+            playing.set(true);
+            set_ended.set(false);
+        });
+        listen!(pause, |_| playing.set(false));
+        listen!(playing_ev, |_| {
+            set_waiting.set(false);
+            set_stalled.set(false);
+        });
+        listen!(waiting_ev, |_| set_waiting.set(true));
+        listen!(stalled_ev, |_| set_stalled.set(true));
+        listen!(seeking_ev, |_| set_seeking.set(true));
+        listen!(seeked, |_| set_seeking.set(false));
+        listen!(ended_ev, |_| set_ended.set(true));
+        listen!(volumechange, |el| {
+            volume.set(el.volume());
+            muted.set(el.muted());
+        });
+        listen!(ratechange, |el| rate.set(el.playback_rate()));
+        listen!(emptied, |el| {
+            set_duration.set(0.0);
+            set_buffered.set(vec![]);
+            current_time.set(el.current_time());
+        });
+
+        let track_list = Signal::derive_local({
+            let target = target.clone();
+            move || target.get().and_then(|el| el.text_tracks())
+        });
+
+        for event in ["addtrack", "removetrack", "change"] {
+            let _ = use_event_listener(track_list, Custom::<web_sys::Event>::new(event), {
+                let update_tracks = update_tracks.clone();
+                move |_| update_tracks()
+            });
+        }
+
+        enable_track = {
+            let target = target.clone();
+
+            sendwrap_fn!(move |index: usize| {
+                let Some(list) = target.get_untracked().and_then(|el| el.text_tracks()) else {
+                    return;
+                };
+
+                for (i, track) in (0..list.length()).filter_map(|i| list.get(i)).enumerate() {
+                    track.set_mode(if i == index {
+                        web_sys::TextTrackMode::Showing
+                    } else {
+                        web_sys::TextTrackMode::Disabled
+                    });
+                }
+            })
+        };
+
+        disable_tracks = sendwrap_fn!(move || {
+            let Some(list) = target.get_untracked().and_then(|el| el.text_tracks()) else {
+                return;
+            };
+
+            for track in (0..list.length()).filter_map(|i| list.get(i)) {
+                track.set_mode(web_sys::TextTrackMode::Disabled);
+            }
+        });
+    }
+
+    let selected_track = Signal::derive(move || {
+        tracks.with(|tracks| {
+            tracks
+                .iter()
+                .find(|track| track.mode == MediaTextTrackMode::Showing)
+                .map(|track| track.index)
+        })
+    });
+
+    UseMediaControlsReturn {
+        playing,
+        current_time,
+        duration: duration.into(),
+        volume,
+        muted,
+        rate,
+        buffered: buffered.into(),
+        seeking: seeking.into(),
+        ended: ended.into(),
+        waiting: waiting.into(),
+        stalled: stalled.into(),
+        tracks: tracks.into(),
+        selected_track,
+        enable_track,
+        disable_tracks,
+    }
+}
+
+/// Removes the child elements with the given tag name and appends new ones with the given attributes.
+#[cfg(not(feature = "ssr"))]
+fn replace_children<'a>(
+    el: &web_sys::HtmlMediaElement,
+    tag_name: &str,
+    children: impl Iterator<Item = Vec<(&'static str, &'a str)>>,
+) {
+    if let Ok(existing) = el.query_selector_all(&format!(":scope > {tag_name}")) {
+        for node in (0..existing.length()).filter_map(|i| existing.item(i)) {
+            let _ = el.remove_child(&node);
+        }
+    }
+
+    let document = document();
+
+    for attributes in children {
+        let Ok(child) = document.create_element(tag_name) else {
+            continue;
+        };
+
+        for (name, value) in attributes {
+            let _ = child.set_attribute(name, value);
+        }
+
+        let _ = el.append_child(&child);
+    }
+}
+
+/// A `<source>` of the media element managed by [`use_media_controls`].
+#[derive(DefaultBuilder, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MediaSource {
+    /// URL of the media
+    #[builder(into)]
+    pub src: String,
+
+    /// MIME type of the media like `"video/webm"`
+    #[builder(into)]
+    pub mime_type: Option<String>,
+
+    /// Media query for which this source should be used like `"(min-width: 800px)"`
+    #[builder(into)]
+    pub media: Option<String>,
+}
+
+impl MediaSource {
+    /// Creates a source with the given URL.
+    pub fn new(src: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            ..Default::default()
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn to_attributes(&self) -> Vec<(&'static str, &str)> {
+        [
+            Some(("src", self.src.as_str())),
+            self.mime_type
+                .as_deref()
+                .map(|mime_type| ("type", mime_type)),
+            self.media.as_deref().map(|media| ("media", media)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// A `<track>` of the media element managed by [`use_media_controls`].
+#[derive(DefaultBuilder, Clone, Debug, PartialEq, Eq)]
+pub struct MediaTextTrackSource {
+    /// URL of the track file, usually WebVTT
+    #[builder(into)]
+    pub src: String,
+
+    /// What kind of track this is. Defaults to `MediaTextTrackKind::Subtitles`.
+    pub kind: MediaTextTrackKind,
+
+    /// Title of the track shown to the user
+    #[builder(into)]
+    pub label: String,
+
+    /// Language of the track like `"en"`
+    #[builder(into)]
+    pub srclang: String,
+
+    /// Whether this track is enabled by default. Defaults to `false`.
+    pub default: bool,
+}
+
+impl MediaTextTrackSource {
+    /// Creates a subtitles track with the given URL.
+    pub fn new(src: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            kind: MediaTextTrackKind::Subtitles,
+            label: String::new(),
+            srclang: String::new(),
+            default: false,
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn to_attributes(&self) -> Vec<(&'static str, &str)> {
+        let mut attributes = vec![
+            ("src", self.src.as_str()),
+            ("kind", self.kind.as_str()),
+            ("label", self.label.as_str()),
+            ("srclang", self.srclang.as_str()),
+        ];
+
+        if self.default {
+            attributes.push(("default", ""));
+        }
+
+        attributes
+    }
+}
+
+/// A text track of the media element. Returned by [`use_media_controls`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MediaTextTrack {
+    /// Index of the track in the track list of the element. Pass this to `enable_track`.
+    pub index: usize,
+    /// The `id` of the track
+    pub id: String,
+    /// What kind of track this is
+    pub kind: MediaTextTrackKind,
+    /// Title of the track
+    pub label: String,
+    /// Language of the track
+    pub language: String,
+    /// Whether the track is shown, hidden or disabled
+    pub mode: MediaTextTrackMode,
+}
+
+impl MediaTextTrack {
+    #[cfg(not(feature = "ssr"))]
+    fn from_web_sys(index: usize, track: &web_sys::TextTrack) -> Self {
+        Self {
+            index,
+            id: track.id(),
+            kind: track.kind().into(),
+            label: track.label(),
+            language: track.language(),
+            mode: track.mode().into(),
+        }
+    }
+}
+
+/// Kind of a [`MediaTextTrack`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum MediaTextTrackKind {
+    /// Transcription or translation of the dialog
+    #[default]
+    Subtitles,
+    /// Transcription of the dialog and sound effects, for example for deaf users
+    Captions,
+    /// Textual description of the video content, for example for blind users
+    Descriptions,
+    /// Chapter titles for navigating the media
+    Chapters,
+    /// Data for scripts that isn't shown to the user
+    Metadata,
+}
+
+impl MediaTextTrackKind {
+    #[cfg(not(feature = "ssr"))]
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Subtitles => "subtitles",
+            Self::Captions => "captions",
+            Self::Descriptions => "descriptions",
+            Self::Chapters => "chapters",
+            Self::Metadata => "metadata",
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+impl From<web_sys::TextTrackKind> for MediaTextTrackKind {
+    fn from(kind: web_sys::TextTrackKind) -> Self {
+        match kind {
+            web_sys::TextTrackKind::Captions => Self::Captions,
+            web_sys::TextTrackKind::Descriptions => Self::Descriptions,
+            web_sys::TextTrackKind::Chapters => Self::Chapters,
+            web_sys::TextTrackKind::Metadata => Self::Metadata,
+            _ => Self::Subtitles,
+        }
+    }
+}
+
+/// Mode of a [`MediaTextTrack`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum MediaTextTrackMode {
+    /// The track isn't loaded
+    #[default]
+    Disabled,
+    /// The track is loaded but not shown
+    Hidden,
+    /// The track is shown
+    Showing,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl From<web_sys::TextTrackMode> for MediaTextTrackMode {
+    fn from(mode: web_sys::TextTrackMode) -> Self {
+        match mode {
+            web_sys::TextTrackMode::Hidden => Self::Hidden,
+            web_sys::TextTrackMode::Showing => Self::Showing,
+            _ => Self::Disabled,
+        }
+    }
+}
+
+/// Options for [`use_media_controls_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseMediaControlsOptions {
+    /// The `<source>` elements of the media element. If empty, the sources of the element are left
+    /// untouched. Defaults to an empty list.
+    #[builder(into)]
+    src: Signal<Vec<MediaSource>>,
+
+    /// The `<track>` elements of the media element. If empty, the tracks of the element are left
+    /// untouched. Defaults to an empty list.
+    #[builder(into)]
+    tracks: Signal<Vec<MediaTextTrackSource>>,
+}
+
+/// Return type of [`use_media_controls`].
+pub struct UseMediaControlsReturn<EnableTrackFn, DisableTracksFn>
+where
+    EnableTrackFn: Fn(usize) + Clone + Send + Sync,
+    DisableTracksFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the media is playing. Set it to play or pause.
+    pub playing: RwSignal<bool>,
+
+    /// The current playback position in seconds. Set it to seek.
+    pub current_time: RwSignal<f64>,
+
+    /// Duration of the media in seconds
+    pub duration: Signal<f64>,
+
+    /// Volume between `0.0` and `1.0`
+    pub volume: RwSignal<f64>,
+
+    /// Whether the media is muted
+    pub muted: RwSignal<bool>,
+
+    /// Playback rate where `1.0` is the normal speed
+    pub rate: RwSignal<f64>,
+
+    /// Time ranges `(start, end)` in seconds that have been loaded
+    pub buffered: Signal<Vec<(f64, f64)>>,
+
+    /// `true` while seeking
+    pub seeking: Signal<bool>,
+
+    /// `true` after the media has played to the end
+    pub ended: Signal<bool>,
+
+    /// `true` while playback is stopped because more data has to be loaded
+    pub waiting: Signal<bool>,
+
+    /// `true` if the browser tries to load data but it's not coming in
+    pub stalled: Signal<bool>,
+
+    /// All text tracks of the media element
+    pub tracks: Signal<Vec<MediaTextTrack>>,
+
+    /// Index of the text track that is currently shown
+    pub selected_track: Signal<Option<usize>>,
+
+    /// Show the text track with the given index and disable all others
+    pub enable_track: EnableTrackFn,
+
+    /// Disable all text tracks
+    pub disable_tracks: DisableTracksFn,
+}
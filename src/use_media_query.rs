@@ -2,6 +2,7 @@
 
 use crate::use_event_listener;
 use cfg_if::cfg_if;
+use default_struct_builder::DefaultBuilder;
 use leptos::ev::change;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
@@ -33,7 +34,29 @@ use std::rc::Rc;
 ///
 /// ## Server-Side Rendering
 ///
-/// On the server this functions returns a Signal that is always `false`.
+/// On the server this functions returns a Signal that is always `false` by default.
+///
+/// To avoid layout jumps on hydration you can provide a hint for the server with the option `initial_value`,
+/// for example derived from a [Client Hint](https://developer.mozilla.org/en-US/docs/Web/HTTP/Client_hints)
+/// header or a cookie. This value is also used on the client until the media query has been evaluated
+/// after hydration. So make sure that the client uses the same value as the server, e.g. by reading it
+/// from a cookie with [`fn@crate::use_cookie`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_media_query_with_options, UseMediaQueryOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let is_mobile_hint = false;
+/// let is_large_screen = use_media_query_with_options(
+///     "(min-width: 1024px)",
+///     UseMediaQueryOptions::default().initial_value(!is_mobile_hint),
+/// );
+/// #
+/// #    view! { }
+/// # }
+/// ```
 ///
 /// ## See also
 ///
@@ -41,9 +64,19 @@ use std::rc::Rc;
 /// * [`fn@crate::use_preferred_contrast`]
 /// * [`fn@crate::use_prefers_reduced_motion`]
 pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
+    use_media_query_with_options(query, UseMediaQueryOptions::default())
+}
+
+/// Version of [`use_media_query`] that takes a `UseMediaQueryOptions`. See [`use_media_query`] for how to use.
+pub fn use_media_query_with_options(
+    query: impl Into<Signal<String>>,
+    options: UseMediaQueryOptions,
+) -> Signal<bool> {
+    let UseMediaQueryOptions { initial_value } = options;
+
     let query = query.into();
 
-    let (matches, set_matches) = signal(false);
+    let (matches, set_matches) = signal(initial_value);
 
     cfg_if! { if #[cfg(not(feature = "ssr"))] {
         let media_query: Rc<RefCell<Option<web_sys::MediaQueryList>>> = Rc::new(RefCell::new(None));
@@ -104,4 +137,12 @@ pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
     matches.into()
 }
 
+/// Options for [`use_media_query_with_options`].
+#[derive(DefaultBuilder, Default, Clone, Copy, Debug)]
+pub struct UseMediaQueryOptions {
+    /// The value before the media query is evaluated, i.e. on the server and on the client
+    /// before hydration has finished. Defaults to `false`.
+    initial_value: bool,
+}
+
 type RemoveListener = Rc<RefCell<Option<Box<dyn Fn()>>>>;
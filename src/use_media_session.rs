@@ -0,0 +1,496 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::use_supported;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Reactive [Media Session API](https://developer.mozilla.org/en-US/docs/Web/API/Media_Session_API).
+///
+/// Shows information about the currently playing media in the media controls of the operating system
+/// (lock screen, notification area, hardware media keys, ...) and reacts to the actions triggered there.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_media_session)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{
+/// #     use_media_session_with_options, MediaSessionAction, MediaSessionArtwork, MediaSessionMetadata,
+/// #     MediaSessionPlaybackState, UseMediaSessionOptions,
+/// # };
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (playing, set_playing) = signal(false);
+///
+/// let is_supported = use_media_session_with_options(
+///     UseMediaSessionOptions::default()
+///         .metadata(Some(
+///             MediaSessionMetadata::default()
+///                 .title("Never Gonna Give You Up")
+///                 .artist("Rick Astley")
+///                 .artwork(vec![MediaSessionArtwork::new("cover.png").sizes("512x512")]),
+///         ))
+///         .playback_state(Signal::derive(move || {
+///             if playing.get() {
+///                 MediaSessionPlaybackState::Playing
+///             } else {
+///                 MediaSessionPlaybackState::Paused
+///             }
+///         }))
+///         .on_action(MediaSessionAction::Play, move |_| set_playing.set(true))
+///         .on_action(MediaSessionAction::Pause, move |_| set_playing.set(false)),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The metadata, playback state and position state can be signals. The media session is updated
+/// whenever they change.
+///
+/// ## Seeking
+///
+/// The handlers receive a [`MediaSessionActionDetails`] that contains `seek_time` for `SeekTo` and
+/// optionally `seek_offset` for `SeekBackward` and `SeekForward`. To show a progress bar in the media controls
+/// provide the `position_state`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_media_session_with_options, MediaSessionAction, MediaSessionPosition, UseMediaSessionOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (current_time, set_current_time) = signal(0.0);
+///
+/// use_media_session_with_options(
+///     UseMediaSessionOptions::default()
+///         .position_state(Signal::derive(move || {
+///             Some(MediaSessionPosition {
+///                 duration: 212.0,
+///                 position: current_time.get(),
+///                 playback_rate: 1.0,
+///             })
+///         }))
+///         .on_action(MediaSessionAction::SeekTo, move |details| {
+///             if let Some(time) = details.seek_time {
+///                 set_current_time.set(time);
+///             }
+///         })
+///         .on_action(MediaSessionAction::SeekForward, move |details| {
+///             set_current_time.update(|time| *time += details.seek_offset.unwrap_or(10.0));
+///         }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// When the component is cleaned up the registered action handlers are removed and the metadata is cleared.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that is always `false` and the handlers are never called.
+pub fn use_media_session() -> Signal<bool> {
+    use_media_session_with_options(UseMediaSessionOptions::default())
+}
+
+/// Version of [`use_media_session`] that takes a `UseMediaSessionOptions`. See [`use_media_session`] for how to use.
+pub fn use_media_session_with_options(options: UseMediaSessionOptions) -> Signal<bool> {
+    let UseMediaSessionOptions {
+        metadata,
+        playback_state,
+        position_state,
+        handlers,
+    } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("mediaSession" in &window().navigator())
+        }
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js, sendwrap_fn};
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::JsValue;
+
+        let media_session = move || {
+            let navigator = window().navigator();
+            js!(navigator["mediaSession"])
+                .ok()
+                .filter(|_| is_supported.get_untracked())
+        };
+
+        Effect::new(move || {
+            let metadata = metadata.get();
+
+            if let Some(media_session) = media_session() {
+                let value = metadata
+                    .as_ref()
+                    .and_then(MediaSessionMetadata::to_js)
+                    .unwrap_or(JsValue::NULL);
+                js!(media_session["metadata"] = value);
+            }
+        });
+
+        Effect::new(move || {
+            let state = playback_state.get();
+
+            if let Some(media_session) = media_session() {
+                js!(media_session["playbackState"] = state.as_str());
+            }
+        });
+
+        Effect::new(move || {
+            let position = position_state.get();
+
+            if let Some(media_session) = media_session() {
+                let state = js_sys::Object::new();
+                if let Some(position) = position {
+                    js!(state["duration"] = position.duration);
+                    js!(state["position"] = position.position.clamp(0.0, position.duration));
+                    js!(state["playbackRate"] = position.playback_rate);
+                }
+
+                // an empty state clears the position
+                let _ = call_method(&media_session, "setPositionState", &[state.into()]);
+            }
+        });
+
+        let actions = handlers
+            .iter()
+            .map(|(action, _)| *action)
+            .collect::<Vec<_>>();
+
+        Effect::new(move || {
+            if !is_supported.get() {
+                return;
+            }
+
+            let Some(media_session) = media_session() else {
+                return;
+            };
+
+            for (action, handler) in &handlers {
+                let handler = Arc::clone(handler);
+
+                let callback = Closure::<dyn Fn(JsValue)>::new(move |details: JsValue| {
+                    handler(MediaSessionActionDetails::from_js(&details));
+                })
+                .into_js_value();
+
+                // throws for actions that the browser doesn't know
+                let _ = call_method(
+                    &media_session,
+                    "setActionHandler",
+                    &[action.as_str().into(), callback],
+                );
+            }
+        });
+
+        on_cleanup(sendwrap_fn!(move || {
+            if !is_supported.try_get_untracked().unwrap_or_default() {
+                return;
+            }
+
+            let navigator = window().navigator();
+            let Ok(media_session) = js!(navigator["mediaSession"]) else {
+                return;
+            };
+
+            for action in &actions {
+                let _ = call_method(
+                    &media_session,
+                    "setActionHandler",
+                    &[action.as_str().into(), JsValue::NULL],
+                );
+            }
+
+            js!(media_session["metadata"] = JsValue::NULL);
+            js!(media_session["playbackState"] = MediaSessionPlaybackState::None.as_str());
+        }));
+    }
+
+    is_supported
+}
+
+#[cfg(not(feature = "ssr"))]
+fn call_method(
+    obj: &wasm_bindgen::JsValue,
+    method: &str,
+    args: &[wasm_bindgen::JsValue],
+) -> Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {
+    use wasm_bindgen::JsCast;
+
+    js_sys::Reflect::get(obj, &method.into())?
+        .unchecked_into::<js_sys::Function>()
+        .apply(obj, &args.iter().collect::<js_sys::Array>())
+}
+
+/// Information about the playing media shown by [`use_media_session`].
+#[derive(DefaultBuilder, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MediaSessionMetadata {
+    /// Title of the media
+    #[builder(into)]
+    pub title: String,
+
+    /// Name of the artist, group, creator, etc.
+    #[builder(into)]
+    pub artist: String,
+
+    /// Name of the album or collection
+    #[builder(into)]
+    pub album: String,
+
+    /// Images of the media like an album cover, in different sizes
+    pub artwork: Vec<MediaSessionArtwork>,
+}
+
+impl MediaSessionMetadata {
+    #[cfg(not(feature = "ssr"))]
+    fn to_js(&self) -> Option<wasm_bindgen::JsValue> {
+        use crate::js;
+        use wasm_bindgen::JsCast;
+
+        let init = js_sys::Object::new();
+        js!(init["title"] = self.title.as_str());
+        js!(init["artist"] = self.artist.as_str());
+        js!(init["album"] = self.album.as_str());
+
+        let artwork = self
+            .artwork
+            .iter()
+            .map(|artwork| {
+                let image = js_sys::Object::new();
+                js!(image["src"] = artwork.src.as_str());
+                if let Some(sizes) = &artwork.sizes {
+                    js!(image["sizes"] = sizes.as_str());
+                }
+                if let Some(mime_type) = &artwork.mime_type {
+                    js!(image["type"] = mime_type.as_str());
+                }
+                image
+            })
+            .collect::<js_sys::Array>();
+        js!(init["artwork"] = artwork);
+
+        let window = window();
+        let constructor = js!(window["MediaMetadata"]).ok()?;
+
+        js_sys::Reflect::construct(
+            &constructor.unchecked_into::<js_sys::Function>(),
+            &js_sys::Array::of1(&init),
+        )
+        .ok()
+    }
+}
+
+/// An image of the media shown by [`use_media_session`].
+#[derive(DefaultBuilder, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MediaSessionArtwork {
+    /// URL of the image
+    #[builder(into)]
+    pub src: String,
+
+    /// Sizes of the image like `"96x96"` or `"128x128 256x256"`
+    #[builder(into)]
+    pub sizes: Option<String>,
+
+    /// MIME type of the image like `"image/png"`
+    #[builder(into)]
+    pub mime_type: Option<String>,
+}
+
+impl MediaSessionArtwork {
+    /// Creates an artwork with the given image URL.
+    pub fn new(src: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Playback state of the media session of [`use_media_session`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum MediaSessionPlaybackState {
+    /// The browser decides the playback state from the playing media elements
+    #[default]
+    None,
+    /// The media is paused
+    Paused,
+    /// The media is playing
+    Playing,
+}
+
+impl MediaSessionPlaybackState {
+    #[cfg(not(feature = "ssr"))]
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Paused => "paused",
+            Self::Playing => "playing",
+        }
+    }
+}
+
+/// Duration, position and playback rate of the media shown by [`use_media_session`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MediaSessionPosition {
+    /// Duration of the media in seconds
+    pub duration: f64,
+    /// Current playback position in seconds
+    pub position: f64,
+    /// Playback rate where `1.0` is the normal speed
+    pub playback_rate: f64,
+}
+
+impl Default for MediaSessionPosition {
+    fn default() -> Self {
+        Self {
+            duration: 0.0,
+            position: 0.0,
+            playback_rate: 1.0,
+        }
+    }
+}
+
+/// Action triggered by the media controls of the operating system.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MediaSessionAction {
+    /// Start or resume playback
+    Play,
+    /// Pause playback
+    Pause,
+    /// Stop playback
+    Stop,
+    /// Seek back by a few seconds
+    SeekBackward,
+    /// Seek forward by a few seconds
+    SeekForward,
+    /// Seek to a specific time
+    SeekTo,
+    /// Play the previous track
+    PreviousTrack,
+    /// Play the next track
+    NextTrack,
+    /// Skip the currently playing advertisement
+    SkipAd,
+}
+
+impl MediaSessionAction {
+    #[cfg(not(feature = "ssr"))]
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Play => "play",
+            Self::Pause => "pause",
+            Self::Stop => "stop",
+            Self::SeekBackward => "seekbackward",
+            Self::SeekForward => "seekforward",
+            Self::SeekTo => "seekto",
+            Self::PreviousTrack => "previoustrack",
+            Self::NextTrack => "nexttrack",
+            Self::SkipAd => "skipad",
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn from_str(action: &str) -> Option<Self> {
+        Some(match action {
+            "play" => Self::Play,
+            "pause" => Self::Pause,
+            "stop" => Self::Stop,
+            "seekbackward" => Self::SeekBackward,
+            "seekforward" => Self::SeekForward,
+            "seekto" => Self::SeekTo,
+            "previoustrack" => Self::PreviousTrack,
+            "nexttrack" => Self::NextTrack,
+            "skipad" => Self::SkipAd,
+            _ => return None,
+        })
+    }
+}
+
+/// Details passed to the action handlers of [`use_media_session`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MediaSessionActionDetails {
+    /// The triggered action
+    pub action: MediaSessionAction,
+
+    /// Number of seconds to seek for `SeekBackward` and `SeekForward`. If `None` choose a suitable default.
+    pub seek_offset: Option<f64>,
+
+    /// The time in seconds to seek to for `SeekTo`
+    pub seek_time: Option<f64>,
+
+    /// `true` for `SeekTo` if the seek is part of an ongoing seek operation and should be fast
+    /// rather than precise
+    pub fast_seek: Option<bool>,
+}
+
+impl MediaSessionActionDetails {
+    #[cfg(not(feature = "ssr"))]
+    fn from_js(details: &wasm_bindgen::JsValue) -> Self {
+        let get = |key: &str| js_sys::Reflect::get(details, &key.into()).ok();
+
+        Self {
+            action: get("action")
+                .and_then(|action| action.as_string())
+                .and_then(|action| MediaSessionAction::from_str(&action))
+                .unwrap_or(MediaSessionAction::Play),
+            seek_offset: get("seekOffset").and_then(|offset| offset.as_f64()),
+            seek_time: get("seekTime").and_then(|time| time.as_f64()),
+            fast_seek: get("fastSeek").and_then(|fast_seek| fast_seek.as_bool()),
+        }
+    }
+}
+
+type MediaSessionActionHandler = Arc<dyn Fn(MediaSessionActionDetails) + Send + Sync>;
+
+/// Options for [`use_media_session_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseMediaSessionOptions {
+    /// Information about the playing media. `None` clears the metadata. Defaults to `None`.
+    #[builder(into)]
+    metadata: Signal<Option<MediaSessionMetadata>>,
+
+    /// Whether the media is playing or paused. Defaults to `MediaSessionPlaybackState::None`.
+    #[builder(into)]
+    playback_state: Signal<MediaSessionPlaybackState>,
+
+    /// Duration, position and playback rate of the media. `None` clears the position state.
+    /// Defaults to `None`.
+    #[builder(into)]
+    position_state: Signal<Option<MediaSessionPosition>>,
+
+    #[builder(skip)]
+    handlers: Vec<(MediaSessionAction, MediaSessionActionHandler)>,
+}
+
+impl UseMediaSessionOptions {
+    /// Call `handler` when the given action is triggered by the media controls.
+    /// Only one handler per action is used, a later one replaces an earlier one.
+    pub fn on_action(
+        mut self,
+        action: MediaSessionAction,
+        handler: impl Fn(MediaSessionActionDetails) + Send + Sync + 'static,
+    ) -> Self {
+        self.handlers.retain(|(existing, _)| *existing != action);
+        self.handlers.push((action, Arc::new(handler)));
+        self
+    }
+}
@@ -0,0 +1,351 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_interval_fn_with_options, use_supported, UseIntervalFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Reactive memory usage and system pressure.
+///
+/// Periodically reads the JavaScript heap statistics of
+/// [`performance.memory`](https://developer.mozilla.org/en-US/docs/Web/API/Performance/memory).
+/// Optionally the memory of the whole page is measured with
+/// [`performance.measureUserAgentSpecificMemory()`](https://developer.mozilla.org/en-US/docs/Web/API/Performance/measureUserAgentSpecificMemory)
+/// and the [Compute Pressure API](https://developer.mozilla.org/en-US/docs/Web/API/Compute_Pressure_API)
+/// is observed so that apps can shed caches when the system is under load.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_memory)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_memory, UseMemoryReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseMemoryReturn { is_supported, memory, .. } = use_memory();
+///
+/// view! {
+///     <Show when=move || is_supported.get()>
+///         "Used heap: " {move || memory.get().map(|memory| memory.used_js_heap_size)}
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## Pressure
+///
+/// `pressure` reports the state of the observed pressure source, `cpu` by default. Use it to free
+/// memory or reduce work when the system becomes busy.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_memory, PressureState, UseMemoryReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseMemoryReturn { pressure, .. } = use_memory();
+///
+/// Effect::new(move || {
+///     if pressure.get() >= Some(PressureState::Serious) {
+///         // clear caches
+///     }
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Page Memory
+///
+/// Set the option `measure_user_agent_specific` to `true` to also measure the memory of the whole page
+/// including workers and iframes. This is only available on cross-origin isolated pages.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_memory_with_options, UseMemoryOptions, UseMemoryReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseMemoryReturn { measured_bytes, .. } = use_memory_with_options(
+///     UseMemoryOptions::default()
+///         .measure_user_agent_specific(true)
+///         .interval(10_000),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server all signals are `None` and the support signals are `false`.
+pub fn use_memory() -> UseMemoryReturn {
+    use_memory_with_options(UseMemoryOptions::default())
+}
+
+/// Version of [`use_memory`] that takes a `UseMemoryOptions`. See [`use_memory`] for how to use.
+pub fn use_memory_with_options(options: UseMemoryOptions) -> UseMemoryReturn {
+    let UseMemoryOptions {
+        interval,
+        measure_user_agent_specific,
+        pressure_source,
+        pressure_sample_interval,
+    } = options;
+
+    let is_supported = use_supported(|| {
+        window()
+            .performance()
+            .is_some_and(|performance| js!("memory" in &performance))
+    });
+
+    let is_measure_supported = use_supported(|| {
+        let window = window();
+        let cross_origin_isolated = js!(window["crossOriginIsolated"])
+            .ok()
+            .and_then(|value| value.as_bool())
+            .unwrap_or_default();
+
+        cross_origin_isolated
+            && window
+                .performance()
+                .is_some_and(|performance| js!("measureUserAgentSpecificMemory" in &performance))
+    });
+
+    let is_pressure_supported = use_supported(|| js!("PressureObserver" in &window()));
+
+    let (memory, set_memory) = signal(None::<MemoryInfo>);
+    let (measured_bytes, set_measured_bytes) = signal(None::<f64>);
+    let (pressure, set_pressure) = signal(None::<PressureState>);
+
+    let is_measuring = Rc::new(Cell::new(false));
+
+    let _ = use_interval_fn_with_options(
+        move || {
+            if is_supported.get_untracked() {
+                set_memory.set(read_memory_info());
+            }
+
+            #[cfg(not(feature = "ssr"))]
+            if measure_user_agent_specific
+                && is_measure_supported.get_untracked()
+                && !is_measuring.get()
+            {
+                is_measuring.set(true);
+
+                let is_measuring = Rc::clone(&is_measuring);
+
+                leptos::task::spawn_local(async move {
+                    if let Some(bytes) = measure_user_agent_specific_memory().await {
+                        set_measured_bytes.try_set(Some(bytes));
+                    }
+                    is_measuring.set(false);
+                });
+            }
+        },
+        interval,
+        UseIntervalFnOptions::default().immediate_callback(true),
+    );
+
+    #[cfg(not(feature = "ssr"))]
+    if is_pressure_supported.get_untracked() {
+        use crate::sendwrap_fn;
+        use wasm_bindgen::prelude::*;
+        use wasm_bindgen::JsCast;
+        use wasm_bindgen::JsValue;
+
+        let callback = Closure::<dyn Fn(js_sys::Array)>::new(move |records: js_sys::Array| {
+            if let Some(record) = records.iter().last() {
+                let state = js!(record["state"])
+                    .ok()
+                    .and_then(|state| state.as_string())
+                    .and_then(|state| PressureState::from_str(&state));
+
+                set_pressure.set(state);
+            }
+        })
+        .into_js_value();
+
+        let observer = js_sys::Reflect::get(&window(), &"PressureObserver".into())
+            .ok()
+            .and_then(|class| {
+                js_sys::Reflect::construct(
+                    &class.unchecked_into::<js_sys::Function>(),
+                    &js_sys::Array::of1(&callback),
+                )
+                .ok()
+            });
+
+        if let Some(observer) = observer {
+            let observe_options = js_sys::Object::new();
+            js!(observe_options["sampleInterval"] = pressure_sample_interval as f64);
+
+            if let Ok(observe) = js!(observer["observe"]) {
+                let promise = observe.unchecked_into::<js_sys::Function>().call2(
+                    &observer,
+                    &JsValue::from_str(pressure_source.as_str()),
+                    &observe_options,
+                );
+
+                if let Ok(promise) =
+                    promise.and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+                {
+                    // rejected if the source isn't supported on this device
+                    leptos::task::spawn_local(async move {
+                        let _ = crate::js_fut!(promise).await;
+                    });
+                }
+            }
+
+            on_cleanup(sendwrap_fn!(move || {
+                if let Ok(disconnect) = js!(observer["disconnect"]) {
+                    let _ = disconnect
+                        .unchecked_into::<js_sys::Function>()
+                        .call0(&observer);
+                }
+            }));
+        }
+    }
+
+    UseMemoryReturn {
+        is_supported,
+        memory: memory.into(),
+        is_measure_supported,
+        measured_bytes: measured_bytes.into(),
+        is_pressure_supported,
+        pressure: pressure.into(),
+    }
+}
+
+fn read_memory_info() -> Option<MemoryInfo> {
+    let performance = window().performance()?;
+    let memory = js!(performance["memory"]).ok()?;
+
+    let read = |key: &str| {
+        js_sys::Reflect::get(&memory, &key.into())
+            .ok()
+            .and_then(|value| value.as_f64())
+            .unwrap_or_default()
+    };
+
+    Some(MemoryInfo {
+        js_heap_size_limit: read("jsHeapSizeLimit"),
+        total_js_heap_size: read("totalJSHeapSize"),
+        used_js_heap_size: read("usedJSHeapSize"),
+    })
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn measure_user_agent_specific_memory() -> Option<f64> {
+    use wasm_bindgen::JsCast;
+
+    let performance = window().performance()?;
+    let measure = js!(performance["measureUserAgentSpecificMemory"]).ok()?;
+
+    let promise = measure
+        .unchecked_into::<js_sys::Function>()
+        .call0(&performance)
+        .ok()?
+        .dyn_into::<js_sys::Promise>()
+        .ok()?;
+
+    let result = crate::js_fut!(promise).await.ok()?;
+
+    js!(result["bytes"]).ok()?.as_f64()
+}
+
+/// JavaScript heap statistics returned by [`use_memory`]. All values are in bytes.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct MemoryInfo {
+    /// The maximum size of the heap that is available to the context
+    pub js_heap_size_limit: f64,
+
+    /// The total allocated heap size
+    pub total_js_heap_size: f64,
+
+    /// The currently active segment of the heap
+    pub used_js_heap_size: f64,
+}
+
+/// State reported by the [Compute Pressure API](https://developer.mozilla.org/en-US/docs/Web/API/Compute_Pressure_API).
+/// The variants are ordered from the least to the most pressure.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PressureState {
+    /// The system is idle or only lightly used
+    Nominal,
+    /// The system is working fine but under increased load
+    Fair,
+    /// The system is under heavy load. Non-essential work should be reduced.
+    Serious,
+    /// The system is about to be throttled. Work and memory usage should be reduced as much as possible.
+    Critical,
+}
+
+impl PressureState {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn from_str(state: &str) -> Option<Self> {
+        match state {
+            "nominal" => Some(Self::Nominal),
+            "fair" => Some(Self::Fair),
+            "serious" => Some(Self::Serious),
+            "critical" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+}
+
+/// Options for [`use_memory_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseMemoryOptions {
+    /// How often the memory is read in milliseconds. Defaults to `1000`.
+    interval: u64,
+
+    /// Also measure the memory of the whole page with `performance.measureUserAgentSpecificMemory()`.
+    /// The measurement can take a while and is only available on cross-origin isolated pages. Defaults to `false`.
+    measure_user_agent_specific: bool,
+
+    /// The pressure source that is observed. Defaults to `"cpu"` which is the only source browsers support so far.
+    #[builder(into)]
+    pressure_source: String,
+
+    /// How often the pressure state is sampled in milliseconds. Defaults to `1000`.
+    pressure_sample_interval: u32,
+}
+
+impl Default for UseMemoryOptions {
+    fn default() -> Self {
+        Self {
+            interval: 1000,
+            measure_user_agent_specific: false,
+            pressure_source: "cpu".to_string(),
+            pressure_sample_interval: 1000,
+        }
+    }
+}
+
+/// Return type of [`use_memory`].
+pub struct UseMemoryReturn {
+    /// `true` if the browser supports `performance.memory`
+    pub is_supported: Signal<bool>,
+
+    /// The JavaScript heap statistics. `None` if not supported.
+    pub memory: Signal<Option<MemoryInfo>>,
+
+    /// `true` if `performance.measureUserAgentSpecificMemory()` is available
+    pub is_measure_supported: Signal<bool>,
+
+    /// Memory used by the whole page in bytes. Requires the option `measure_user_agent_specific`.
+    pub measured_bytes: Signal<Option<f64>>,
+
+    /// `true` if the browser supports the Compute Pressure API
+    pub is_pressure_supported: Signal<bool>,
+
+    /// Latest state of the observed pressure source. `None` until the first sample arrives.
+    pub pressure: Signal<Option<PressureState>>,
+}
@@ -1,12 +1,14 @@
 #![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
 
-use crate::core::{IntoElementMaybeSignal, Position};
+use crate::core::{ElementMaybeSignal, IntoElementMaybeSignal, Position};
 use crate::{use_event_listener_with_options, use_window, UseEventListenerOptions, UseWindow};
 use default_struct_builder::DefaultBuilder;
-use leptos::ev::{dragover, mousemove, touchend, touchmove, touchstart};
+use leptos::ev::{dragover, mousemove, pointermove, touchend, touchmove, touchstart};
 use leptos::prelude::*;
+use std::cell::Cell;
 use std::convert::Infallible;
 use std::marker::PhantomData;
+use std::rc::Rc;
 use wasm_bindgen::{JsCast, JsValue};
 
 /// Reactive mouse position
@@ -48,6 +50,45 @@ use wasm_bindgen::{JsCast, JsValue};
 /// # }
 /// ```
 ///
+/// ## Element Relative Coordinates
+///
+/// Instead of page, client, screen or movement coordinates you can also get the coordinates relative to
+/// an element. `UseMouseCoordType::element` reports the position in px relative to the top left corner
+/// of the element, `UseMouseCoordType::element_percentage` reports the position in percent (`0.0` - `100.0`)
+/// of the element's size and `UseMouseCoordType::svg_view_box` transforms the position into the user
+/// coordinate system (the `viewBox`) of an SVG element.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos::svg::Svg;
+/// # use leptos_use::{use_mouse_with_options, UseMouseOptions, UseMouseReturn, UseMouseCoordType};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+/// let svg = NodeRef::<Svg>::new();
+///
+/// let UseMouseReturn { x, y, .. } = use_mouse_with_options(
+///     UseMouseOptions::default().coord_type(UseMouseCoordType::element_percentage(el))
+/// );
+///
+/// let UseMouseReturn { x: svg_x, y: svg_y, .. } = use_mouse_with_options(
+///     UseMouseOptions::default().coord_type(UseMouseCoordType::svg_view_box(svg))
+/// );
+///
+/// view! {
+///     <div node_ref=el>{move || format!("{:.0}% / {:.0}%", x.get(), y.get())}</div>
+///     <svg node_ref=svg viewBox="0 0 10 10" width="200" height="200"></svg>
+/// }
+/// # }
+/// ```
+///
+/// ## Source Type
+///
+/// The returned `source_type` tells you if the last reported coordinates came from
+/// a mouse, a pen or a touch. Pens are detected by additionally listening to `pointermove` events.
+///
 /// ## Custom Extractor
 ///
 /// It's also possible to provide a custom extractor to get the position from the events.
@@ -101,8 +142,13 @@ where
     let (y, set_y) = signal(options.initial_value.y);
     let (source_type, set_source_type) = signal(UseMouseSourceType::Unset);
 
+    // Pens emit compatibility mouse events after the pointer events. We remember the pointer type
+    // of the last `pointermove` to be able to tell them apart from real mice.
+    let is_pen = Rc::new(Cell::new(false));
+
     let mouse_handler = {
         let coord_type = options.coord_type.clone();
+        let is_pen = Rc::clone(&is_pen);
 
         move |event: web_sys::MouseEvent| {
             let result = coord_type.extract_mouse_coords(&event);
@@ -110,11 +156,19 @@ where
             if let Some((x, y)) = result {
                 set_x.set(x);
                 set_y.set(y);
-                set_source_type.set(UseMouseSourceType::Mouse);
+                set_source_type.set(if is_pen.get() {
+                    UseMouseSourceType::Pen
+                } else {
+                    UseMouseSourceType::Mouse
+                });
             }
         }
     };
 
+    let pointer_handler = move |event: web_sys::PointerEvent| {
+        is_pen.set(event.pointer_type() == "pen");
+    };
+
     let drag_handler = {
         let mouse_handler = mouse_handler.clone();
 
@@ -158,6 +212,12 @@ where
         let target = options.target.into_element_maybe_signal();
         let event_listener_options = UseEventListenerOptions::default().passive(true);
 
+        let _ = use_event_listener_with_options(
+            target.clone(),
+            pointermove,
+            pointer_handler,
+            event_listener_options,
+        );
         let _ = use_event_listener_with_options(
             target.clone(),
             mousemove,
@@ -253,9 +313,41 @@ pub enum UseMouseCoordType<E: UseMouseEventExtractor + Clone> {
     Client,
     Screen,
     Movement,
+    /// Coordinates in px relative to the top left corner of the element.
+    Element(ElementMaybeSignal<web_sys::Element>),
+    /// Coordinates in percent (`0.0` - `100.0`) of the element's width and height.
+    ElementPercentage(ElementMaybeSignal<web_sys::Element>),
+    /// Coordinates in the user coordinate system (`viewBox`) of an SVG graphics element.
+    SvgViewBox(ElementMaybeSignal<web_sys::Element>),
     Custom(E),
 }
 
+impl UseMouseCoordType<Infallible> {
+    /// Creates a [`UseMouseCoordType::Element`] for the given element.
+    pub fn element<El, M>(el: El) -> Self
+    where
+        El: IntoElementMaybeSignal<web_sys::Element, M>,
+    {
+        Self::Element(el.into_element_maybe_signal())
+    }
+
+    /// Creates a [`UseMouseCoordType::ElementPercentage`] for the given element.
+    pub fn element_percentage<El, M>(el: El) -> Self
+    where
+        El: IntoElementMaybeSignal<web_sys::Element, M>,
+    {
+        Self::ElementPercentage(el.into_element_maybe_signal())
+    }
+
+    /// Creates a [`UseMouseCoordType::SvgViewBox`] for the given SVG element.
+    pub fn svg_view_box<El, M>(el: El) -> Self
+    where
+        El: IntoElementMaybeSignal<web_sys::Element, M>,
+    {
+        Self::SvgViewBox(el.into_element_maybe_signal())
+    }
+}
+
 impl Default for UseMouseCoordType<Infallible> {
     fn default() -> Self {
         Self::Page
@@ -285,6 +377,11 @@ impl<E: UseMouseEventExtractor + Clone> UseMouseEventExtractor for UseMouseCoord
             UseMouseCoordType::Movement => {
                 Some((event.movement_x() as f64, event.movement_y() as f64))
             }
+            UseMouseCoordType::Element(_)
+            | UseMouseCoordType::ElementPercentage(_)
+            | UseMouseCoordType::SvgViewBox(_) => {
+                self.extract_element_coords(event.client_x() as f64, event.client_y() as f64)
+            }
             UseMouseCoordType::Custom(ref extractor) => extractor.extract_mouse_coords(event),
         }
     }
@@ -295,11 +392,56 @@ impl<E: UseMouseEventExtractor + Clone> UseMouseEventExtractor for UseMouseCoord
             UseMouseCoordType::Client => Some((touch.client_x() as f64, touch.client_y() as f64)),
             UseMouseCoordType::Screen => Some((touch.screen_x() as f64, touch.client_y() as f64)),
             UseMouseCoordType::Movement => None,
+            UseMouseCoordType::Element(_)
+            | UseMouseCoordType::ElementPercentage(_)
+            | UseMouseCoordType::SvgViewBox(_) => {
+                self.extract_element_coords(touch.client_x() as f64, touch.client_y() as f64)
+            }
             UseMouseCoordType::Custom(ref extractor) => extractor.extract_touch_coords(touch),
         }
     }
 }
 
+impl<E: UseMouseEventExtractor + Clone> UseMouseCoordType<E> {
+    /// Transforms client coordinates into the coordinate space of the element variants.
+    fn extract_element_coords(&self, client_x: f64, client_y: f64) -> Option<(f64, f64)> {
+        match self {
+            UseMouseCoordType::Element(el) => {
+                let rect = el.get_untracked()?.get_bounding_client_rect();
+
+                Some((client_x - rect.left(), client_y - rect.top()))
+            }
+            UseMouseCoordType::ElementPercentage(el) => {
+                let rect = el.get_untracked()?.get_bounding_client_rect();
+
+                if rect.width() == 0.0 || rect.height() == 0.0 {
+                    return None;
+                }
+
+                Some((
+                    (client_x - rect.left()) / rect.width() * 100.0,
+                    (client_y - rect.top()) / rect.height() * 100.0,
+                ))
+            }
+            UseMouseCoordType::SvgViewBox(el) => {
+                let matrix = el
+                    .get_untracked()?
+                    .dyn_into::<web_sys::SvgGraphicsElement>()
+                    .ok()?
+                    .get_screen_ctm()?
+                    .inverse()
+                    .ok()?;
+
+                Some((
+                    matrix.a() as f64 * client_x + matrix.c() as f64 * client_y + matrix.e() as f64,
+                    matrix.b() as f64 * client_x + matrix.d() as f64 * client_y + matrix.f() as f64,
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
 impl UseMouseEventExtractor for Infallible {
     fn extract_mouse_coords(&self, _: &web_sys::MouseEvent) -> Option<(f64, f64)> {
         unreachable!()
@@ -331,6 +473,8 @@ pub enum UseMouseSourceType {
     Mouse,
     /// coordinates come from touch
     Touch,
+    /// coordinates come from a pen
+    Pen,
     /// Initially before any event has been recorded the source type is unset
     Unset,
 }
@@ -2,6 +2,8 @@ use crate::core::IntoElementsMaybeSignal;
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::reactive::wrappers::read::Signal;
+use std::fmt::Display;
+use std::str::FromStr;
 use wasm_bindgen::prelude::*;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
@@ -47,6 +49,79 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ## Filtering Records
+///
+/// If you observe several kinds of mutations but are only interested in some of them in the callback
+/// you can use the option `record_types`. Records of other types are discarded before the callback
+/// is called. If no record is left the callback isn't called at all.
+///
+/// To filter attribute mutations by attribute name use the option `attribute_filter`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_mutation_observer_with_options, MutationRecordType, UseMutationObserverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// use_mutation_observer_with_options(
+///     el,
+///     move |mutations, _| {
+///         // only records of type `childList`
+///     },
+///     UseMutationObserverOptions::default()
+///         .subtree(true)
+///         .child_list(true)
+///         .attributes(true)
+///         .record_types(vec![MutationRecordType::ChildList]),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
+/// ## Typed Mutations
+///
+/// With [`use_mutation_observer_typed_with_options`] you receive the mutations as a list of [`Mutation`]s
+/// instead of raw `web_sys::MutationRecord`s. Records about several added or removed nodes are split up
+/// into one [`Mutation`] per node.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_mutation_observer_typed_with_options, Mutation, UseMutationObserverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// use_mutation_observer_typed_with_options(
+///     el,
+///     move |mutations| {
+///         for mutation in mutations {
+///             match mutation {
+///                 Mutation::AttributeChanged { name, old_value, .. } => {
+///                     // ...
+///                 }
+///                 Mutation::ChildAdded { node, .. } => {
+///                     // ...
+///                 }
+///                 _ => {}
+///             }
+///         }
+///     },
+///     UseMutationObserverOptions::default()
+///         .child_list(true)
+///         .attributes(true)
+///         .attribute_old_value(true),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closure `stop` is a sendwrapped function. It can
@@ -90,19 +165,29 @@ where
         use crate::js;
         use send_wrapper::SendWrapper;
 
+        let record_types = options.record_types.clone();
+
         let closure_js = Closure::<dyn FnMut(js_sys::Array, web_sys::MutationObserver)>::new(
             move |entries: js_sys::Array, observer| {
+                let records = entries
+                    .to_vec()
+                    .into_iter()
+                    .map(|v| v.unchecked_into::<web_sys::MutationRecord>())
+                    .filter(|record| match &record_types {
+                        Some(types) => MutationRecordType::from_str(&record.type_())
+                            .is_ok_and(|ty| types.contains(&ty)),
+                        None => true,
+                    })
+                    .collect::<Vec<_>>();
+
+                if records.is_empty() {
+                    return;
+                }
+
                 #[cfg(debug_assertions)]
                 let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
-                callback(
-                    entries
-                        .to_vec()
-                        .into_iter()
-                        .map(|v| v.unchecked_into::<web_sys::MutationRecord>())
-                        .collect(),
-                    observer,
-                );
+                callback(records, observer);
             },
         )
         .into_js_value();
@@ -167,6 +252,33 @@ where
     }
 }
 
+/// Version of [`use_mutation_observer_with_options`] that calls the callback with [`Mutation`]s instead
+/// of raw `web_sys::MutationRecord`s. See [`use_mutation_observer`] for how to use.
+pub fn use_mutation_observer_typed_with_options<El, M, F>(
+    target: El,
+    mut callback: F,
+    options: UseMutationObserverOptions,
+) -> UseMutationObserverReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementsMaybeSignal<web_sys::Element, M>,
+    F: FnMut(Vec<Mutation>) + 'static,
+{
+    use_mutation_observer_with_options(
+        target,
+        move |records, _| {
+            let mutations = records
+                .iter()
+                .flat_map(Mutation::from_record)
+                .collect::<Vec<_>>();
+
+            if !mutations.is_empty() {
+                callback(mutations);
+            }
+        },
+        options,
+    )
+}
+
 /// Options for [`use_mutation_observer_with_options`].
 #[derive(DefaultBuilder, Clone, Default)]
 pub struct UseMutationObserverOptions {
@@ -208,6 +320,12 @@ pub struct UseMutationObserverOptions {
     /// Set to `true` to record the previous value of a node's text whenever the text changes on
     /// nodes being monitored. The default value is `false`.
     character_data_old_value: bool,
+
+    /// Only records of these types are passed to the callback. If this property isn't included,
+    /// all records are passed.
+    #[builder(into)]
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    record_types: Option<Vec<MutationRecordType>>,
 }
 
 impl From<UseMutationObserverOptions> for web_sys::MutationObserverInit {
@@ -220,6 +338,7 @@ impl From<UseMutationObserverOptions> for web_sys::MutationObserverInit {
             attribute_old_value,
             character_data,
             character_data_old_value,
+            ..
         } = val;
 
         let init = Self::new();
@@ -249,3 +368,150 @@ pub struct UseMutationObserverReturn<F: Fn() + Clone + Send + Sync> {
     /// A function to stop and detach the MutationObserver
     pub stop: F,
 }
+
+/// Type of a [`web_sys::MutationRecord`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MutationRecordType {
+    /// An attribute of the target changed
+    Attributes,
+    /// The character data (text) of the target changed
+    CharacterData,
+    /// Children of the target were added or removed
+    ChildList,
+}
+
+impl Display for MutationRecordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Attributes => write!(f, "attributes"),
+            Self::CharacterData => write!(f, "characterData"),
+            Self::ChildList => write!(f, "childList"),
+        }
+    }
+}
+
+impl FromStr for MutationRecordType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "attributes" => Ok(Self::Attributes),
+            "characterData" => Ok(Self::CharacterData),
+            "childList" => Ok(Self::ChildList),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A single mutation as returned by [`use_mutation_observer_typed_with_options`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Mutation {
+    /// An attribute of `target` changed
+    AttributeChanged {
+        /// The element whose attribute changed
+        target: web_sys::Element,
+        /// The local name of the changed attribute
+        name: String,
+        /// The namespace of the changed attribute
+        namespace: Option<String>,
+        /// The value before the change. Only available if `attribute_old_value` is `true`.
+        old_value: Option<String>,
+    },
+
+    /// `node` was added to the children of `target`
+    ChildAdded {
+        /// The parent node
+        target: web_sys::Node,
+        /// The added node
+        node: web_sys::Node,
+        /// The previous sibling of the added node
+        previous_sibling: Option<web_sys::Node>,
+        /// The next sibling of the added node
+        next_sibling: Option<web_sys::Node>,
+    },
+
+    /// `node` was removed from the children of `target`
+    ChildRemoved {
+        /// The former parent node
+        target: web_sys::Node,
+        /// The removed node
+        node: web_sys::Node,
+        /// The former previous sibling of the removed node
+        previous_sibling: Option<web_sys::Node>,
+        /// The former next sibling of the removed node
+        next_sibling: Option<web_sys::Node>,
+    },
+
+    /// The text of `target` changed
+    TextChanged {
+        /// The text node (or other character data node) that changed
+        target: web_sys::Node,
+        /// The current text
+        value: Option<String>,
+        /// The text before the change. Only available if `character_data_old_value` is `true`.
+        old_value: Option<String>,
+    },
+}
+
+impl Mutation {
+    /// Converts a `web_sys::MutationRecord` into a list of mutations. A `childList` record
+    /// results in one mutation per added or removed node.
+    pub fn from_record(record: &web_sys::MutationRecord) -> Vec<Self> {
+        let Some(target) = record.target() else {
+            return vec![];
+        };
+
+        match MutationRecordType::from_str(&record.type_()) {
+            Ok(MutationRecordType::Attributes) => {
+                vec![Self::AttributeChanged {
+                    target: target.unchecked_into(),
+                    name: record.attribute_name().unwrap_or_default(),
+                    namespace: record.attribute_namespace(),
+                    old_value: record.old_value(),
+                }]
+            }
+
+            Ok(MutationRecordType::CharacterData) => {
+                vec![Self::TextChanged {
+                    value: target.node_value(),
+                    target,
+                    old_value: record.old_value(),
+                }]
+            }
+
+            Ok(MutationRecordType::ChildList) => {
+                let previous_sibling = record.previous_sibling();
+                let next_sibling = record.next_sibling();
+
+                let nodes = |list: web_sys::NodeList| {
+                    (0..list.length())
+                        .filter_map(move |i| list.item(i))
+                        .collect::<Vec<_>>()
+                };
+
+                let removed =
+                    nodes(record.removed_nodes())
+                        .into_iter()
+                        .map(|node| Self::ChildRemoved {
+                            target: target.clone(),
+                            node,
+                            previous_sibling: previous_sibling.clone(),
+                            next_sibling: next_sibling.clone(),
+                        });
+
+                let added = nodes(record.added_nodes())
+                    .into_iter()
+                    .map(|node| Self::ChildAdded {
+                        target: target.clone(),
+                        node,
+                        previous_sibling: previous_sibling.clone(),
+                        next_sibling: next_sibling.clone(),
+                    });
+
+                removed.chain(added).collect()
+            }
+
+            Err(_) => vec![],
+        }
+    }
+}
@@ -0,0 +1,232 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_supported};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive network status and connection quality.
+///
+/// Tracks whether the browser is online with the `online` and `offline` events and exposes the fields of
+/// the [Network Information API](https://developer.mozilla.org/en-US/docs/Web/API/Network_Information_API).
+/// They are updated whenever the connection changes so apps can for example load lower quality media
+/// on slow connections.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_network)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_network, NetworkEffectiveType, UseNetworkReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseNetworkReturn {
+///     is_online,
+///     effective_type,
+///     save_data,
+///     ..
+/// } = use_network();
+///
+/// let high_quality = Signal::derive(move || {
+///     !save_data.get() && effective_type.get() >= Some(NetworkEffectiveType::FourG)
+/// });
+///
+/// view! {
+///     <Show when=move || is_online.get() fallback=|| "You are offline">
+///         <img src=move || if high_quality.get() { "photo-large.jpg" } else { "photo-small.jpg" }/>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_online` is always `true`, `save_data` is `false` and all the other signals are `None`.
+pub fn use_network() -> UseNetworkReturn {
+    let is_supported = use_supported(|| js!("connection" in &window().navigator()));
+
+    let (is_online, set_is_online) = signal(true);
+    let (online_at, set_online_at) = signal(None::<f64>);
+    let (offline_at, set_offline_at) = signal(None::<f64>);
+    let (downlink, set_downlink) = signal(None::<f64>);
+    let (downlink_max, set_downlink_max) = signal(None::<f64>);
+    let (rtt, set_rtt) = signal(None::<f64>);
+    let (save_data, set_save_data) = signal(false);
+    let (effective_type, set_effective_type) = signal(None::<NetworkEffectiveType>);
+    let (connection_type, set_connection_type) = signal(None::<NetworkType>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::{offline, online, Custom};
+        use wasm_bindgen::JsCast;
+
+        let navigator = window().navigator();
+
+        set_is_online.set(navigator.on_line());
+
+        let _ = use_event_listener(window(), online, move |_| {
+            set_is_online.set(true);
+            set_online_at.set(Some(js_sys::Date::now()));
+        });
+
+        let _ = use_event_listener(window(), offline, move |_| {
+            set_is_online.set(false);
+            set_offline_at.set(Some(js_sys::Date::now()));
+        });
+
+        if is_supported.get_untracked() {
+            if let Ok(connection) = js!(navigator["connection"]) {
+                let update = {
+                    let connection = connection.clone();
+
+                    move || {
+                        let read_f64 = |key: &str| {
+                            js_sys::Reflect::get(&connection, &key.into())
+                                .ok()
+                                .and_then(|value| value.as_f64())
+                        };
+                        let read_string = |key: &str| {
+                            js_sys::Reflect::get(&connection, &key.into())
+                                .ok()
+                                .and_then(|value| value.as_string())
+                        };
+
+                        set_downlink.set(read_f64("downlink"));
+                        set_downlink_max.set(read_f64("downlinkMax"));
+                        set_rtt.set(read_f64("rtt"));
+                        set_save_data.set(
+                            js_sys::Reflect::get(&connection, &"saveData".into())
+                                .ok()
+                                .and_then(|value| value.as_bool())
+                                .unwrap_or_default(),
+                        );
+                        set_effective_type.set(
+                            read_string("effectiveType")
+                                .and_then(|value| NetworkEffectiveType::from_str(&value)),
+                        );
+                        set_connection_type.set(
+                            read_string("type").and_then(|value| NetworkType::from_str(&value)),
+                        );
+                    }
+                };
+
+                update();
+
+                let _ = use_event_listener(
+                    connection.unchecked_into::<web_sys::EventTarget>(),
+                    Custom::<web_sys::Event>::new("change"),
+                    move |_| update(),
+                );
+            }
+        }
+    }
+
+    UseNetworkReturn {
+        is_supported,
+        is_online: is_online.into(),
+        online_at: online_at.into(),
+        offline_at: offline_at.into(),
+        downlink: downlink.into(),
+        downlink_max: downlink_max.into(),
+        rtt: rtt.into(),
+        save_data: save_data.into(),
+        effective_type: effective_type.into(),
+        connection_type: connection_type.into(),
+    }
+}
+
+/// The effective connection type as reported by the browser. It's determined from the recently observed
+/// round-trip time and downlink bandwidth and is ordered from the slowest to the fastest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NetworkEffectiveType {
+    /// Suitable for small transfers like text only pages
+    SlowTwoG,
+    /// Suitable for small images
+    TwoG,
+    /// Suitable for high resolution images, audio and SD video
+    ThreeG,
+    /// Suitable for HD video and real-time apps
+    FourG,
+}
+
+impl NetworkEffectiveType {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn from_str(effective_type: &str) -> Option<Self> {
+        match effective_type {
+            "slow-2g" => Some(Self::SlowTwoG),
+            "2g" => Some(Self::TwoG),
+            "3g" => Some(Self::ThreeG),
+            "4g" => Some(Self::FourG),
+            _ => None,
+        }
+    }
+}
+
+/// The type of connection the device uses to communicate with the network.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NetworkType {
+    Bluetooth,
+    Cellular,
+    Ethernet,
+    Mixed,
+    None,
+    Other,
+    Unknown,
+    Wifi,
+    Wimax,
+}
+
+impl NetworkType {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn from_str(connection_type: &str) -> Option<Self> {
+        match connection_type {
+            "bluetooth" => Some(Self::Bluetooth),
+            "cellular" => Some(Self::Cellular),
+            "ethernet" => Some(Self::Ethernet),
+            "mixed" => Some(Self::Mixed),
+            "none" => Some(Self::None),
+            "other" => Some(Self::Other),
+            "unknown" => Some(Self::Unknown),
+            "wifi" => Some(Self::Wifi),
+            "wimax" => Some(Self::Wimax),
+            _ => None,
+        }
+    }
+}
+
+/// Return type of [`use_network`].
+pub struct UseNetworkReturn {
+    /// `true` if the browser supports the Network Information API
+    pub is_supported: Signal<bool>,
+
+    /// `true` if the browser is online
+    pub is_online: Signal<bool>,
+
+    /// When the browser last went online in milliseconds since the UNIX epoch
+    pub online_at: Signal<Option<f64>>,
+
+    /// When the browser last went offline in milliseconds since the UNIX epoch
+    pub offline_at: Signal<Option<f64>>,
+
+    /// Estimated effective bandwidth in megabits per second
+    pub downlink: Signal<Option<f64>>,
+
+    /// Maximum downlink speed of the underlying connection technology in megabits per second
+    pub downlink_max: Signal<Option<f64>>,
+
+    /// Estimated effective round-trip time in milliseconds
+    pub rtt: Signal<Option<f64>>,
+
+    /// `true` if the user has requested a reduced data usage
+    pub save_data: Signal<bool>,
+
+    /// The effective type of the connection
+    pub effective_type: Signal<Option<NetworkEffectiveType>>,
+
+    /// The type of connection the device uses
+    pub connection_type: Signal<Option<NetworkType>>,
+}
@@ -0,0 +1,502 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_permission, use_supported, PermissionState};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive [Web NFC API](https://developer.mozilla.org/en-US/docs/Web/API/Web_NFC_API).
+///
+/// Reads and writes NDEF messages of NFC tags. Scanned messages are decoded into [`NfcRecord`]s
+/// and available as a signal.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_nfc)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_nfc, NfcRecord, UseNfcReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseNfcReturn { message, nfc, .. } = use_nfc();
+///
+/// let scan = move |_| {
+///     leptos::task::spawn_local(async move {
+///         // asks for permission, has to be called in reaction to a user gesture
+///         let _ = nfc.scan().await;
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=scan>"Scan"</button>
+///     <ul>
+///         {move || {
+///             message
+///                 .get()
+///                 .map(|message| {
+///                     message
+///                         .records
+///                         .into_iter()
+///                         .map(|record| match record {
+///                             NfcRecord::Text { text, .. } => view! { <li>{text}</li> }.into_any(),
+///                             NfcRecord::Url(url) => view! { <li>{url}</li> }.into_any(),
+///                             _ => view! { <li>"Other record"</li> }.into_any(),
+///                         })
+///                         .collect_view()
+///                 })
+///         }}
+///     </ul>
+/// }
+/// # }
+/// ```
+///
+/// ## Writing
+///
+/// The records passed to `write` are written to the next tag that is tapped. Existing records on
+/// the tag are overwritten.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_nfc, NfcRecord, UseNfcReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseNfcReturn { nfc, .. } = use_nfc();
+///
+/// let write = move |_| {
+///     leptos::task::spawn_local(async move {
+///         let _ = nfc
+///             .write(vec![
+///                 NfcRecord::text("Hello from Leptos"),
+///                 NfcRecord::Url("https://leptos.dev".to_string()),
+///             ])
+///             .await;
+///     });
+/// };
+///
+/// view! { <button on:click=write>"Write tag"</button> }
+/// # }
+/// ```
+///
+/// ## Aborting
+///
+/// `abort` stops scanning and cancels a pending write, which then returns `Err(UseNfcError::Aborted)`.
+/// This also happens automatically when the component is unmounted.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false`, `permission` is `PermissionState::Unknown`, `message`
+/// is `None` and the methods of [`NfcClient`] return `Err(UseNfcError::Server)`.
+pub fn use_nfc() -> UseNfcReturn {
+    let is_supported = use_supported(|| js!("NDEFReader" in &window()));
+    let permission = use_permission("nfc");
+
+    let (message, set_message) = signal(None::<NfcMessage>);
+    let (is_scanning, set_scanning) = signal(false);
+    let (error, set_error) = signal(None::<UseNfcError>);
+
+    let nfc = NfcClient {
+        reader: StoredValue::new_local(None),
+        scan_controller: StoredValue::new_local(None),
+        write_controller: StoredValue::new_local(None),
+        is_scanning: is_scanning.into(),
+        set_scanning,
+        set_error,
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        use crate::use_event_listener;
+        use leptos::ev::Custom;
+        use wasm_bindgen::JsCast;
+
+        let window = window();
+
+        let reader = js!(window["NDEFReader"])
+            .and_then(|constructor| {
+                js_sys::Reflect::construct(
+                    &constructor.unchecked_into::<js_sys::Function>(),
+                    &js_sys::Array::new(),
+                )
+            })
+            .unwrap_or_default();
+
+        let _ = use_event_listener(
+            reader.clone().unchecked_into::<web_sys::EventTarget>(),
+            Custom::<web_sys::Event>::new("reading"),
+            move |event| {
+                set_error.set(None);
+                set_message.set(Some(parse_message(&event)));
+            },
+        );
+
+        let _ = use_event_listener(
+            reader.clone().unchecked_into::<web_sys::EventTarget>(),
+            Custom::<web_sys::Event>::new("readingerror"),
+            move |_| set_error.set(Some(UseNfcError::ReadingFailed)),
+        );
+
+        nfc.reader.set_value(Some(reader));
+    }
+
+    on_cleanup(move || nfc.abort());
+
+    UseNfcReturn {
+        is_supported,
+        permission,
+        is_scanning: is_scanning.into(),
+        message: message.into(),
+        error: error.into(),
+        nfc,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn call_reader(
+    reader: &JsValue,
+    method: &str,
+    args: &[&JsValue],
+) -> Result<js_sys::Promise, JsValue> {
+    use wasm_bindgen::JsCast;
+
+    let method: js_sys::Function = js_sys::Reflect::get(reader, &method.into())?.dyn_into()?;
+    let args = args.iter().collect::<js_sys::Array>();
+
+    Ok(method.apply(reader, &args)?.unchecked_into())
+}
+
+#[cfg(not(feature = "ssr"))]
+fn parse_message(event: &web_sys::Event) -> NfcMessage {
+    let serial_number = js!(event["serialNumber"])
+        .ok()
+        .and_then(|serial_number| serial_number.as_string())
+        .unwrap_or_default();
+
+    let records = js!(event["message"])
+        .and_then(|message| js!(message["records"]))
+        .map(|records| {
+            js_sys::Array::from(&records)
+                .iter()
+                .map(parse_record)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    NfcMessage {
+        serial_number,
+        records,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn parse_record(record: JsValue) -> NfcRecord {
+    use wasm_bindgen::JsCast;
+
+    let string = |key: &str| {
+        js_sys::Reflect::get(&record, &key.into())
+            .ok()
+            .and_then(|value| value.as_string())
+    };
+
+    let data = js!(record["data"])
+        .ok()
+        .and_then(|data| data.dyn_into::<js_sys::DataView>().ok())
+        .map(|view| {
+            js_sys::Uint8Array::new_with_byte_offset_and_length(
+                &view.buffer(),
+                view.byte_offset() as u32,
+                view.byte_length() as u32,
+            )
+            .to_vec()
+        })
+        .unwrap_or_default();
+
+    let decode = |encoding: &str| {
+        web_sys::TextDecoder::new_with_label(encoding)
+            .and_then(|decoder| decoder.decode_with_u8_array(&data))
+            .unwrap_or_else(|_| String::from_utf8_lossy(&data).into_owned())
+    };
+
+    let record_type = string("recordType").unwrap_or_default();
+
+    match record_type.as_str() {
+        "empty" => NfcRecord::Empty,
+        "text" => NfcRecord::Text {
+            text: decode(&string("encoding").unwrap_or_else(|| "utf-8".to_string())),
+            lang: string("lang"),
+        },
+        "url" | "absolute-url" => NfcRecord::Url(decode("utf-8")),
+        "mime" => NfcRecord::Mime {
+            media_type: string("mediaType").unwrap_or_default(),
+            data,
+        },
+        _ => NfcRecord::Unknown {
+            record_type,
+            media_type: string("mediaType"),
+            data,
+        },
+    }
+}
+
+/// Scans and writes NFC tags. Returned by [`use_nfc`].
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct NfcClient {
+    reader: StoredValue<Option<JsValue>, LocalStorage>,
+    scan_controller: StoredValue<Option<web_sys::AbortController>, LocalStorage>,
+    write_controller: StoredValue<Option<web_sys::AbortController>, LocalStorage>,
+    is_scanning: Signal<bool>,
+    set_scanning: WriteSignal<bool>,
+    set_error: WriteSignal<Option<UseNfcError>>,
+}
+
+impl NfcClient {
+    /// Starts scanning for NFC tags. Every tag that is read updates `message`.
+    /// Asks for permission the first time, so it has to be called in reaction to a user gesture.
+    pub async fn scan(&self) -> Result<(), UseNfcError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseNfcError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let reader = self.reader()?;
+
+            if self.is_scanning.get_untracked() {
+                return Ok(());
+            }
+
+            let controller = web_sys::AbortController::new().map_err(UseNfcError::from)?;
+
+            let options = js_sys::Object::new();
+            js!(options["signal"] = controller.signal());
+
+            self.scan_controller.set_value(Some(controller));
+
+            let result = match call_reader(&reader, "scan", &[&options]) {
+                Ok(promise) => crate::js_fut!(promise)
+                    .await
+                    .map(|_| ())
+                    .map_err(UseNfcError::from),
+                Err(err) => Err(UseNfcError::from(err)),
+            };
+
+            match &result {
+                Ok(()) => {
+                    self.set_scanning.try_set(true);
+                    self.set_error.try_set(None);
+                }
+                Err(err) => {
+                    self.scan_controller.try_set_value(None);
+                    self.set_error.try_set(Some(err.clone()));
+                }
+            }
+
+            result
+        }
+    }
+
+    /// Writes the records to the next NFC tag that is tapped and overwrites its existing records.
+    /// A previous write that is still pending is aborted.
+    pub async fn write(&self, records: Vec<NfcRecord>) -> Result<(), UseNfcError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseNfcError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let reader = self.reader()?;
+
+            let controller = web_sys::AbortController::new().map_err(UseNfcError::from)?;
+
+            let message = js_sys::Object::new();
+            js!(message["records"] = records
+                .iter()
+                .map(NfcRecord::to_js)
+                .collect::<js_sys::Array>());
+
+            let options = js_sys::Object::new();
+            js!(options["overwrite"] = true);
+            js!(options["signal"] = controller.signal());
+
+            if let Some(Some(previous)) = self
+                .write_controller
+                .try_update_value(|previous| previous.replace(controller))
+            {
+                previous.abort();
+            }
+
+            let promise = call_reader(&reader, "write", &[&message, &options])?;
+
+            crate::js_fut!(promise)
+                .await
+                .map(|_| ())
+                .map_err(UseNfcError::from)
+        }
+    }
+
+    /// Stops scanning and aborts a pending write.
+    pub fn abort(&self) {
+        for controller in [self.scan_controller, self.write_controller] {
+            if let Some(Some(controller)) = controller.try_update_value(Option::take) {
+                controller.abort();
+            }
+        }
+
+        self.set_scanning.try_set(false);
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn reader(&self) -> Result<JsValue, UseNfcError> {
+        self.reader
+            .try_get_value()
+            .flatten()
+            .ok_or(UseNfcError::NotSupported)
+    }
+}
+
+/// An NDEF message read from an NFC tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NfcMessage {
+    /// The serial number of the tag, e.g. `"04:3c:2a:7a:9f:5c:80"`. Empty if not available.
+    pub serial_number: String,
+
+    /// The records of the message
+    pub records: Vec<NfcRecord>,
+}
+
+/// A decoded record of an [`NfcMessage`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NfcRecord {
+    /// A record without content
+    Empty,
+
+    /// A text record
+    Text {
+        /// The decoded text
+        text: String,
+        /// The language tag of the text, e.g. `"en"`
+        lang: Option<String>,
+    },
+
+    /// A URL record. Also used for absolute URL records when reading.
+    Url(String),
+
+    /// Binary data of the given MIME type, e.g. `"application/json"`
+    Mime { media_type: String, data: Vec<u8> },
+
+    /// Any other record type like `"smart-poster"` or external types
+    Unknown {
+        record_type: String,
+        media_type: Option<String>,
+        data: Vec<u8>,
+    },
+}
+
+impl NfcRecord {
+    /// Creates a text record without a language tag.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text {
+            text: text.into(),
+            lang: None,
+        }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn to_js(&self) -> js_sys::Object {
+        let record = js_sys::Object::new();
+
+        match self {
+            NfcRecord::Empty => {
+                js!(record["recordType"] = "empty");
+            }
+            NfcRecord::Text { text, lang } => {
+                js!(record["recordType"] = "text");
+                js!(record["data"] = text);
+                if let Some(lang) = lang {
+                    js!(record["lang"] = lang);
+                }
+            }
+            NfcRecord::Url(url) => {
+                js!(record["recordType"] = "url");
+                js!(record["data"] = url);
+            }
+            NfcRecord::Mime { media_type, data } => {
+                js!(record["recordType"] = "mime");
+                js!(record["mediaType"] = media_type);
+                js!(record["data"] = js_sys::Uint8Array::from(data.as_slice()));
+            }
+            NfcRecord::Unknown {
+                record_type,
+                media_type,
+                data,
+            } => {
+                js!(record["recordType"] = record_type);
+                if let Some(media_type) = media_type {
+                    js!(record["mediaType"] = media_type);
+                }
+                js!(record["data"] = js_sys::Uint8Array::from(data.as_slice()));
+            }
+        }
+
+        record
+    }
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum UseNfcError {
+    #[error("Web NFC is not available on the server")]
+    Server,
+
+    #[error("Web NFC is not supported by this browser")]
+    NotSupported,
+
+    #[error("the NFC operation has been aborted")]
+    Aborted,
+
+    #[error("the NFC tag couldn't be read")]
+    ReadingFailed,
+
+    #[error("NFC operation failed")]
+    Failed(SendWrapper<JsValue>),
+}
+
+impl From<JsValue> for UseNfcError {
+    fn from(err: JsValue) -> Self {
+        let is_abort = js!(err["name"])
+            .ok()
+            .and_then(|name| name.as_string())
+            .is_some_and(|name| name == "AbortError");
+
+        if is_abort {
+            Self::Aborted
+        } else {
+            Self::Failed(SendWrapper::new(err))
+        }
+    }
+}
+
+/// Return type of [`use_nfc`].
+pub struct UseNfcReturn {
+    /// `true` if the browser supports the Web NFC API
+    pub is_supported: Signal<bool>,
+
+    /// State of the `"nfc"` permission
+    pub permission: Signal<PermissionState>,
+
+    /// `true` while scanning for tags
+    pub is_scanning: Signal<bool>,
+
+    /// The last message that has been read
+    pub message: Signal<Option<NfcMessage>>,
+
+    /// The last error that occurred while scanning
+    pub error: Signal<Option<UseNfcError>>,
+
+    /// Scans and writes tags
+    pub nfc: NfcClient,
+}
@@ -0,0 +1,312 @@
+use crate::core::IntoElementMaybeSignal;
+use crate::{js, sendwrap_fn, use_event_listener, use_supported};
+use codee::{CodecError, Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{error as error_ev, message as message_ev, messageerror};
+use leptos::prelude::*;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Render into a canvas from a web worker with an [OffscreenCanvas](https://developer.mozilla.org/en-US/docs/Web/API/OffscreenCanvas).
+///
+/// Once the canvas is mounted, its control is transferred to an `OffscreenCanvas` which is sent to a newly created
+/// worker. Heavy rendering then happens off the main thread and doesn't block the UI. Draw commands are posted
+/// to the worker and messages from the worker are received through a typed channel.
+///
+/// The worker is terminated automatically when the component is cleaned up.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_offscreen_canvas)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::{html::Canvas, prelude::*};
+/// # use leptos_use::{use_offscreen_canvas, UseOffscreenCanvasReturn};
+/// # use codee::string::JsonSerdeCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Clone, PartialEq)]
+/// enum DrawCommand {
+///     Clear,
+///     Circle { x: f64, y: f64, radius: f64 },
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let canvas = NodeRef::<Canvas>::new();
+///
+/// let UseOffscreenCanvasReturn { post, .. } =
+///     use_offscreen_canvas::<DrawCommand, JsonSerdeCodec, _, _>(canvas, "render-worker.js");
+///
+/// view! {
+///     <canvas node_ref=canvas width="400" height="300"></canvas>
+///     <button on:click=move |_| post(&DrawCommand::Circle { x: 200.0, y: 150.0, radius: 50.0 })>
+///         "Draw circle"
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// Values are (en)decoded via the given codec. You can use any of the string codecs or a
+/// binary codec wrapped in `Base64`.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ## Worker
+///
+/// The first message the worker receives is an object `{ canvas }` that contains the transferred `OffscreenCanvas`.
+/// All following messages are the encoded commands. Strings that the worker posts back are decoded into `message`.
+///
+/// ```js
+/// // render-worker.js
+/// let ctx;
+///
+/// onmessage = (event) => {
+///     if (event.data.canvas) {
+///         ctx = event.data.canvas.getContext("2d");
+///         return;
+///     }
+///
+///     const command = JSON.parse(event.data);
+///     // draw with ctx ...
+///     postMessage(JSON.stringify("Clear"));
+/// };
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `post` and `terminate` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_offscreen_canvas`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no worker is created, `is_supported` is `false` and the returned functions are no-ops.
+pub fn use_offscreen_canvas<T, C, El, M>(
+    target: El,
+    worker_url: &str,
+) -> UseOffscreenCanvasReturn<
+    T,
+    impl Fn(&T) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    C,
+>
+where
+    T: Send + Sync,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str> + Send + Sync,
+    <C as Encoder<T>>::Error: Send + Sync,
+    <C as Decoder<T>>::Error: Send + Sync,
+    El: IntoElementMaybeSignal<web_sys::HtmlCanvasElement, M>,
+{
+    use_offscreen_canvas_with_options(target, worker_url, UseOffscreenCanvasOptions::default())
+}
+
+/// Version of [`use_offscreen_canvas`] that takes a `UseOffscreenCanvasOptions`. See [`use_offscreen_canvas`] for how to use.
+pub fn use_offscreen_canvas_with_options<T, C, El, M>(
+    target: El,
+    worker_url: &str,
+    options: UseOffscreenCanvasOptions,
+) -> UseOffscreenCanvasReturn<
+    T,
+    impl Fn(&T) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    C,
+>
+where
+    T: Send + Sync,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str> + Send + Sync,
+    <C as Encoder<T>>::Error: Send + Sync,
+    <C as Decoder<T>>::Error: Send + Sync,
+    El: IntoElementMaybeSignal<web_sys::HtmlCanvasElement, M>,
+{
+    let UseOffscreenCanvasOptions { worker_type } = options;
+
+    let is_supported =
+        use_supported(|| js!("OffscreenCanvas" in &window()) && js!("Worker" in &window()));
+
+    let (worker, set_worker) = signal_local(None::<web_sys::Worker>);
+    let (message, set_message) = signal(None::<T>);
+    let (error, set_error) = signal_local(
+        None::<UseOffscreenCanvasError<<C as Encoder<T>>::Error, <C as Decoder<T>>::Error>>,
+    );
+
+    let post = sendwrap_fn!(move |data: &T| {
+        if let Some(worker) = worker.get_untracked() {
+            match C::encode(data) {
+                Ok(msg) => {
+                    worker
+                        .post_message(&msg.into())
+                        .map_err(|err| {
+                            set_error.set(Some(UseOffscreenCanvasError::PostMessage(err)))
+                        })
+                        .ok();
+                }
+                Err(err) => {
+                    set_error.set(Some(UseOffscreenCanvasError::Codec(CodecError::Encode(
+                        err,
+                    ))));
+                }
+            }
+        }
+    });
+
+    let terminate = sendwrap_fn!(move || {
+        if let Some(worker) = worker.get_untracked() {
+            worker.terminate();
+            set_worker.set(None);
+        }
+    });
+
+    if is_supported.get_untracked() {
+        let target = target.into_element_maybe_signal();
+        let worker_url = worker_url.to_string();
+
+        Effect::new({
+            let terminate = terminate.clone();
+
+            move || {
+                terminate();
+
+                let Some(canvas) = target.get() else {
+                    return;
+                };
+
+                let offscreen = match canvas.transfer_control_to_offscreen() {
+                    Ok(offscreen) => offscreen,
+                    Err(err) => {
+                        set_error.set(Some(UseOffscreenCanvasError::Transfer(err)));
+                        return;
+                    }
+                };
+
+                let worker_options = web_sys::WorkerOptions::new();
+                worker_options.set_type(worker_type);
+
+                let new_worker =
+                    match web_sys::Worker::new_with_options(&worker_url, &worker_options) {
+                        Ok(worker) => worker,
+                        Err(err) => {
+                            set_error.set(Some(UseOffscreenCanvasError::CreateWorker(err)));
+                            return;
+                        }
+                    };
+
+                let init = js_sys::Object::new();
+                js!(init["canvas"] = &offscreen);
+
+                if let Err(err) =
+                    new_worker.post_message_with_transfer(&init, &js_sys::Array::of1(&offscreen))
+                {
+                    set_error.set(Some(UseOffscreenCanvasError::PostMessage(err)));
+                }
+
+                set_worker.set(Some(new_worker));
+            }
+        });
+
+        let _ = use_event_listener(worker, message_ev, move |event| {
+            if let Some(data) = event.data().as_string() {
+                match C::decode(&data) {
+                    Ok(msg) => {
+                        set_message.set(Some(msg));
+                    }
+                    Err(err) => set_error.set(Some(UseOffscreenCanvasError::Codec(
+                        CodecError::Decode(err),
+                    ))),
+                }
+            } else {
+                set_error.set(Some(UseOffscreenCanvasError::ValueNotString));
+            }
+        });
+
+        let _ = use_event_listener(worker, messageerror, move |event| {
+            set_error.set(Some(UseOffscreenCanvasError::MessageEvent(event)));
+        });
+
+        let _ = use_event_listener(worker, error_ev, move |event| {
+            set_error.set(Some(UseOffscreenCanvasError::Worker(event)));
+        });
+    }
+
+    on_cleanup({
+        let terminate = terminate.clone();
+
+        move || {
+            terminate();
+        }
+    });
+
+    UseOffscreenCanvasReturn {
+        is_supported,
+        worker: worker.into(),
+        message: message.into(),
+        post,
+        terminate,
+        error: error.into(),
+    }
+}
+
+/// Options for [`use_offscreen_canvas_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseOffscreenCanvasOptions {
+    /// The type of worker to create. Use `WorkerType::Module` if the worker script uses `import` statements.
+    /// Defaults to `WorkerType::Classic`.
+    worker_type: web_sys::WorkerType,
+}
+
+impl Default for UseOffscreenCanvasOptions {
+    fn default() -> Self {
+        Self {
+            worker_type: web_sys::WorkerType::Classic,
+        }
+    }
+}
+
+/// Return type of [`use_offscreen_canvas`].
+pub struct UseOffscreenCanvasReturn<T, PFn, TFn, C>
+where
+    T: Send + Sync + 'static,
+    PFn: Fn(&T) + Clone,
+    TFn: Fn() + Clone,
+    C: Encoder<T> + Decoder<T> + Send + Sync,
+{
+    /// `true` if this browser supports `OffscreenCanvas` and web workers.
+    pub is_supported: Signal<bool>,
+
+    /// The worker that renders into the canvas
+    pub worker: Signal<Option<web_sys::Worker>, LocalStorage>,
+
+    /// Latest message received from the worker
+    pub message: Signal<Option<T>>,
+
+    /// Sends a draw command to the worker
+    pub post: PFn,
+
+    /// Terminates the worker. The canvas can't be transferred again afterwards.
+    pub terminate: TFn,
+
+    /// Latest error that occurred
+    pub error: Signal<Option<ErrorType<T, C>>, LocalStorage>,
+}
+
+type ErrorType<T, C> = UseOffscreenCanvasError<<C as Encoder<T>>::Error, <C as Decoder<T>>::Error>;
+
+#[derive(Debug, Error)]
+pub enum UseOffscreenCanvasError<E, D> {
+    #[error("failed to transfer the control of the canvas to an OffscreenCanvas")]
+    Transfer(JsValue),
+    #[error("failed to create the worker")]
+    CreateWorker(JsValue),
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("worker message error")]
+    MessageEvent(web_sys::MessageEvent),
+    #[error("error in the worker")]
+    Worker(web_sys::ErrorEvent),
+    #[error("failed to (de)encode value")]
+    Codec(CodecError<E, D>),
+    #[error("received value is not a string")]
+    ValueNotString,
+}
@@ -0,0 +1,241 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{
+    sendwrap_fn, use_interval_fn_with_options, use_network, UseIntervalFnOptions, UseNetworkReturn,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Reactive online state.
+///
+/// By default this trusts [`navigator.onLine`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/onLine)
+/// which is only `false` if the device isn't connected to any network at all. In verified mode the
+/// connectivity is confirmed by periodically sending a lightweight request to a URL of your choice.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_online)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_online, UseOnlineReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseOnlineReturn { is_online, .. } = use_online();
+///
+/// view! { <Show when=move || !is_online.get()>"You are offline"</Show> }
+/// # }
+/// ```
+///
+/// ## Verified Connectivity
+///
+/// Set the option `verify_url` to check every `verify_interval` milliseconds that the URL can actually be
+/// reached. Any response counts as online, a network error or a timeout as offline. The requests are sent
+/// with the method `HEAD`, bypass the cache and don't require CORS.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_online_with_options, UseOnlineOptions, UseOnlineReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseOnlineReturn {
+///     is_online,
+///     last_verified_at,
+///     latency,
+///     verify,
+/// } = use_online_with_options(
+///     UseOnlineOptions::default()
+///         .verify_url(Some("/api/ping".to_string()))
+///         .verify_interval(10_000),
+/// );
+///
+/// view! {
+///     <p>"Latency: " {move || latency.get().map(|latency| format!("{latency:.0} ms"))}</p>
+///     <button on:click=move |_| verify()>"Check now"</button>
+/// }
+/// # }
+/// ```
+///
+/// A verification is also triggered whenever the browser comes back online. Going offline is
+/// reported immediately without a request.
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `verify` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_online`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_online` is always `true` and `last_verified_at` and `latency` are `None`.
+pub fn use_online() -> UseOnlineReturn<impl Fn() + Clone + Send + Sync> {
+    use_online_with_options(UseOnlineOptions::default())
+}
+
+/// Version of [`use_online`] that takes a `UseOnlineOptions`. See [`use_online`] for how to use.
+pub fn use_online_with_options(
+    options: UseOnlineOptions,
+) -> UseOnlineReturn<impl Fn() + Clone + Send + Sync> {
+    let UseOnlineOptions {
+        verify_url,
+        verify_interval,
+        verify_timeout,
+    } = options;
+
+    let UseNetworkReturn {
+        is_online: is_network_online,
+        ..
+    } = use_network();
+
+    let (is_verified_online, set_is_verified_online) = signal(true);
+    let (last_verified_at, set_last_verified_at) = signal(None::<f64>);
+    let (latency, set_latency) = signal(None::<f64>);
+
+    let is_verifying = Rc::new(Cell::new(false));
+
+    let verify = {
+        let verify_url = verify_url.clone();
+
+        Rc::new(move || {
+            #[cfg(not(feature = "ssr"))]
+            {
+                let Some(url) = verify_url.clone() else {
+                    return;
+                };
+
+                if !is_network_online.get_untracked() || is_verifying.get() {
+                    return;
+                }
+
+                is_verifying.set(true);
+
+                let is_verifying = Rc::clone(&is_verifying);
+
+                leptos::task::spawn_local(async move {
+                    match ping(&url, verify_timeout).await {
+                        Some(measured) => {
+                            set_is_verified_online.try_set(true);
+                            set_latency.try_set(Some(measured));
+                            set_last_verified_at.try_set(Some(js_sys::Date::now()));
+                        }
+                        None => {
+                            set_is_verified_online.try_set(false);
+                        }
+                    }
+
+                    is_verifying.set(false);
+                });
+            }
+        })
+    };
+
+    if verify_url.is_some() {
+        Effect::new({
+            let verify = Rc::clone(&verify);
+
+            move |_| {
+                if is_network_online.get() {
+                    verify();
+                } else {
+                    set_is_verified_online.set(false);
+                }
+            }
+        });
+
+        let _ = use_interval_fn_with_options(
+            {
+                let verify = Rc::clone(&verify);
+                move || verify()
+            },
+            verify_interval,
+            UseIntervalFnOptions::default(),
+        );
+    }
+
+    let is_online = if verify_url.is_some() {
+        is_verified_online.into()
+    } else {
+        is_network_online
+    };
+
+    UseOnlineReturn {
+        is_online,
+        last_verified_at: last_verified_at.into(),
+        latency: latency.into(),
+        verify: sendwrap_fn!(move || verify()),
+    }
+}
+
+/// Sends a `HEAD` request to `url` and returns the round-trip time in milliseconds if any response arrived.
+#[cfg(not(feature = "ssr"))]
+async fn ping(url: &str, timeout: u64) -> Option<f64> {
+    use std::time::Duration;
+
+    let performance = window().performance()?;
+    let controller = web_sys::AbortController::new().ok()?;
+
+    let init = web_sys::RequestInit::new();
+    init.set_method("HEAD");
+    init.set_cache(web_sys::RequestCache::NoStore);
+    init.set_mode(web_sys::RequestMode::NoCors);
+    init.set_signal(Some(&controller.signal()));
+
+    let start = performance.now();
+
+    set_timeout(move || controller.abort(), Duration::from_millis(timeout));
+
+    crate::js_fut!(window().fetch_with_str_and_init(url, &init))
+        .await
+        .ok()?;
+
+    Some(performance.now() - start)
+}
+
+/// Options for [`use_online_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseOnlineOptions {
+    /// If set the connectivity is verified by sending requests to this URL instead of trusting
+    /// `navigator.onLine`. Defaults to `None`.
+    verify_url: Option<String>,
+
+    /// How often the connectivity is verified in milliseconds. Defaults to `30_000`.
+    verify_interval: u64,
+
+    /// After how many milliseconds without a response a verification fails. Defaults to `5000`.
+    verify_timeout: u64,
+}
+
+impl Default for UseOnlineOptions {
+    fn default() -> Self {
+        Self {
+            verify_url: None,
+            verify_interval: 30_000,
+            verify_timeout: 5000,
+        }
+    }
+}
+
+/// Return type of [`use_online`].
+pub struct UseOnlineReturn<VerifyFn>
+where
+    VerifyFn: Fn() + Clone + Send + Sync,
+{
+    /// `true` if the browser is online. In verified mode only `true` if the last verification succeeded.
+    pub is_online: Signal<bool>,
+
+    /// When the connectivity was last verified successfully in milliseconds since the UNIX epoch
+    pub last_verified_at: Signal<Option<f64>>,
+
+    /// Round-trip time of the last successful verification in milliseconds
+    pub latency: Signal<Option<f64>>,
+
+    /// Verifies the connectivity right away. Does nothing if `verify_url` isn't set.
+    pub verify: VerifyFn,
+}
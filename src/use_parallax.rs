@@ -0,0 +1,279 @@
+use crate::core::IntoElementMaybeSignal;
+use crate::{
+    use_device_orientation, use_mouse_in_element_with_options, use_raf_fn_with_options,
+    UseDeviceOrientationReturn, UseMouseInElementOptions, UseMouseInElementReturn,
+    UseRafFnCallbackArgs, UseRafFnOptions,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Create parallax effects easily. It uses [`fn@crate::use_device_orientation`] and falls back to
+/// [`fn@crate::use_mouse_in_element`] if orientation is not supported.
+///
+/// The returned `tilt` and `roll` are normalized values that can be used to rotate or
+/// translate elements. For the mouse they're in the range `-0.5` to `0.5` relative to the
+/// center of the target element. For the device orientation they're in the range `-1.0` to `1.0`.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_parallax)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_parallax, UseParallaxReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseParallaxReturn { tilt, roll, source } = use_parallax(el);
+///
+/// let style = move || {
+///     format!(
+///         "transform: perspective(300px) rotateX({}deg) rotateY({}deg)",
+///         roll.get() * 20.0,
+///         tilt.get() * 20.0,
+///     )
+/// };
+///
+/// view! {
+///     <div node_ref=el>
+///         <div style=style>"Card"</div>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// ## Easing
+///
+/// To make the movement smoother you can provide an `easing` factor greater than `0.0` and up to `1.0`. On every
+/// animation frame the values move this fraction of the way towards the measured values.
+/// `1.0` (the default) means no easing. Values outside of this range are clamped.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_parallax_with_options, UseParallaxOptions, UseParallaxReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let el = NodeRef::<Div>::new();
+/// let UseParallaxReturn { tilt, roll, .. } = use_parallax_with_options(
+///     el,
+///     UseParallaxOptions::default()
+///         .easing(0.1)
+///         .mouse_tilt_adjust(|tilt| tilt * 2.0),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned signals always contain `0.0` and the source is `Mouse`.
+pub fn use_parallax<El, M>(target: El) -> UseParallaxReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_parallax_with_options(target, UseParallaxOptions::default())
+}
+
+/// Version of [`use_parallax`] that takes a `UseParallaxOptions`. See [`use_parallax`] for how to use.
+pub fn use_parallax_with_options<El, M>(
+    target: El,
+    options: UseParallaxOptions,
+) -> UseParallaxReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseParallaxOptions {
+        device_orientation_tilt_adjust,
+        device_orientation_roll_adjust,
+        mouse_tilt_adjust,
+        mouse_roll_adjust,
+        easing,
+    } = options;
+
+    let UseDeviceOrientationReturn {
+        is_supported,
+        alpha,
+        beta,
+        gamma,
+        ..
+    } = use_device_orientation();
+
+    let UseMouseInElementReturn {
+        element_x,
+        element_y,
+        element_width,
+        element_height,
+        ..
+    } = use_mouse_in_element_with_options(
+        target,
+        UseMouseInElementOptions::default().handle_outside(false),
+    );
+
+    let source = Signal::derive(move || {
+        let has_orientation = |v: Option<f64>| v.map(|v| v != 0.0).unwrap_or(false);
+
+        if is_supported.get() && (has_orientation(alpha.get()) || has_orientation(gamma.get())) {
+            UseParallaxSource::DeviceOrientation
+        } else {
+            UseParallaxSource::Mouse
+        }
+    });
+
+    let target_roll = Signal::derive(move || match source.get() {
+        UseParallaxSource::DeviceOrientation => {
+            device_orientation_roll_adjust(-beta.get().unwrap_or_default() / 90.0)
+        }
+        UseParallaxSource::Mouse => {
+            let height = element_height.get();
+
+            if height == 0.0 {
+                return 0.0;
+            }
+
+            mouse_roll_adjust(-(element_y.get() - height / 2.0) / height)
+        }
+    });
+
+    let target_tilt = Signal::derive(move || match source.get() {
+        UseParallaxSource::DeviceOrientation => {
+            device_orientation_tilt_adjust(gamma.get().unwrap_or_default() / 90.0)
+        }
+        UseParallaxSource::Mouse => {
+            let width = element_width.get();
+
+            if width == 0.0 {
+                return 0.0;
+            }
+
+            mouse_tilt_adjust((element_x.get() - width / 2.0) / width)
+        }
+    });
+
+    if easing >= 1.0 {
+        return UseParallaxReturn {
+            tilt: target_tilt,
+            roll: target_roll,
+            source,
+        };
+    }
+
+    let (tilt, set_tilt) = signal(target_tilt.get_untracked());
+    let (roll, set_roll) = signal(target_roll.get_untracked());
+    let (is_settled, set_settled) = signal(true);
+
+    // `0.0` would never reach the measured values and keep the animation frames running forever
+    let easing = easing.clamp(f64::EPSILON, 1.0);
+
+    let raf = use_raf_fn_with_options(
+        move |UseRafFnCallbackArgs { delta, .. }| {
+            // make the easing independent of the frame rate (relative to 60 fps). After a long
+            // frame the values simply get closer to the target.
+            let frames = delta / (1000.0 / 60.0);
+            let factor = 1.0 - (1.0 - easing).powf(frames);
+
+            let ease = |current: f64, target: f64| {
+                let next = current + (target - current) * factor;
+
+                if (target - next).abs() < 0.0001 {
+                    target
+                } else {
+                    next
+                }
+            };
+
+            let next_tilt = ease(tilt.get_untracked(), target_tilt.get_untracked());
+            let next_roll = ease(roll.get_untracked(), target_roll.get_untracked());
+
+            set_tilt.set(next_tilt);
+            set_roll.set(next_roll);
+
+            if next_tilt == target_tilt.get_untracked() && next_roll == target_roll.get_untracked()
+            {
+                set_settled.set(true);
+            }
+        },
+        UseRafFnOptions::default().immediate(false),
+    );
+
+    Effect::new(move || {
+        target_tilt.track();
+        target_roll.track();
+
+        set_settled.set(false);
+    });
+
+    Effect::new(move || {
+        if is_settled.get() {
+            (raf.pause)();
+        } else {
+            (raf.resume)();
+        }
+    });
+
+    UseParallaxReturn {
+        tilt: tilt.into(),
+        roll: roll.into(),
+        source,
+    }
+}
+
+/// Options for [`use_parallax_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseParallaxOptions {
+    /// Adjust the tilt value from the device orientation. Defaults to the identity function.
+    device_orientation_tilt_adjust: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+
+    /// Adjust the roll value from the device orientation. Defaults to the identity function.
+    device_orientation_roll_adjust: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+
+    /// Adjust the tilt value from the mouse. Defaults to the identity function.
+    mouse_tilt_adjust: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+
+    /// Adjust the roll value from the mouse. Defaults to the identity function.
+    mouse_roll_adjust: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+
+    /// Fraction (greater than `0.0` and up to `1.0`) of the remaining distance to the measured values that
+    /// the returned values move every animation frame. Defaults to `1.0` which means no easing.
+    easing: f64,
+}
+
+impl Default for UseParallaxOptions {
+    fn default() -> Self {
+        Self {
+            device_orientation_tilt_adjust: Arc::new(|v| v),
+            device_orientation_roll_adjust: Arc::new(|v| v),
+            mouse_tilt_adjust: Arc::new(|v| v),
+            mouse_roll_adjust: Arc::new(|v| v),
+            easing: 1.0,
+        }
+    }
+}
+
+/// Return type of [`use_parallax`].
+pub struct UseParallaxReturn {
+    /// Normalized rotation around the y-axis
+    pub tilt: Signal<f64>,
+    /// Normalized rotation around the x-axis
+    pub roll: Signal<f64>,
+    /// The source the values are computed from
+    pub source: Signal<UseParallaxSource>,
+}
+
+/// Source of the values of [`use_parallax`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UseParallaxSource {
+    /// Values come from the position of the mouse relative to the target element
+    Mouse,
+    /// Values come from the orientation of the device
+    DeviceOrientation,
+}
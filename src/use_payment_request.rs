@@ -0,0 +1,693 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+pub use web_sys::PaymentComplete;
+
+/// Reactive [Payment Request API](https://developer.mozilla.org/en-US/docs/Web/API/Payment_Request_API).
+///
+/// Shows the browser's payment sheet for the given payment methods. The payment details are read from
+/// a signal, so they can be derived from the signals of your cart.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_payment_request)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_payment_request, PaymentAmount, PaymentComplete, PaymentDetails, PaymentItem, PaymentMethod, UsePaymentRequestReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (quantity, set_quantity) = signal(1);
+///
+/// let details = Signal::derive(move || {
+///     let total = 9.99 * quantity.get() as f64;
+///
+///     PaymentDetails::new(PaymentItem::new("Total", PaymentAmount::new("USD", format!("{total:.2}"))))
+///         .display_items(vec![PaymentItem::new(
+///             format!("{} × T-shirt", quantity.get()),
+///             PaymentAmount::new("USD", format!("{total:.2}")),
+///         )])
+/// });
+///
+/// let UsePaymentRequestReturn {
+///     can_make_payment,
+///     payment_request,
+///     ..
+/// } = use_payment_request(vec![PaymentMethod::new("https://example.com/pay")], details);
+///
+/// let pay = move |_| {
+///     leptos::task::spawn_local(async move {
+///         if let Ok(response) = payment_request.show().await {
+///             // send `response.details()` to your server to process the payment
+///             let _ = response.complete(PaymentComplete::Success).await;
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=pay disabled=move || !can_make_payment.get()>"Buy"</button>
+/// }
+/// # }
+/// ```
+///
+/// `show` has to be called in reaction to a user gesture. `can_make_payment` is `true` if the user
+/// can pay with one of the given methods.
+///
+/// ## Shipping
+///
+/// With the option `request_shipping` the payment sheet asks for a shipping address and lets the user
+/// pick one of the `shipping_options` of the details. Whenever the user changes one of them,
+/// `shipping_address` and `shipping_option` are updated and the payment sheet is updated with the
+/// details recomputed from them.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_payment_request_with_options, PaymentAmount, PaymentDetails, PaymentItem, PaymentMethod, PaymentShippingOption, UsePaymentRequestOptions, UsePaymentRequestReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (shipping_id, set_shipping_id) = signal("standard".to_string());
+///
+/// let details = Signal::derive(move || {
+///     let express = shipping_id.get() == "express";
+///     let total = if express { "29.99" } else { "19.99" };
+///
+///     PaymentDetails::new(PaymentItem::new("Total", PaymentAmount::new("EUR", total)))
+///         .shipping_options(vec![
+///             PaymentShippingOption::new("standard", "Standard", PaymentAmount::new("EUR", "0.00"))
+///                 .selected(!express),
+///             PaymentShippingOption::new("express", "Express", PaymentAmount::new("EUR", "10.00"))
+///                 .selected(express),
+///         ])
+/// });
+///
+/// let UsePaymentRequestReturn { shipping_option, .. } = use_payment_request_with_options(
+///     vec![PaymentMethod::new("https://example.com/pay")],
+///     details,
+///     UsePaymentRequestOptions::default().request_shipping(true),
+/// );
+///
+/// Effect::new(move || {
+///     if let Some(id) = shipping_option.get() {
+///         set_shipping_id.set(id);
+///     }
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `can_make_payment` are `false` and `show` returns
+/// `Err(UsePaymentRequestError::Server)`.
+pub fn use_payment_request(
+    methods: Vec<PaymentMethod>,
+    details: impl Into<Signal<PaymentDetails>>,
+) -> UsePaymentRequestReturn {
+    use_payment_request_with_options(methods, details, UsePaymentRequestOptions::default())
+}
+
+/// Version of [`use_payment_request`] that takes a `UsePaymentRequestOptions`. See [`use_payment_request`] for how to use.
+pub fn use_payment_request_with_options(
+    methods: Vec<PaymentMethod>,
+    details: impl Into<Signal<PaymentDetails>>,
+    options: UsePaymentRequestOptions,
+) -> UsePaymentRequestReturn {
+    let details = details.into();
+
+    let is_supported = use_supported(|| js!("PaymentRequest" in &window()));
+
+    let (can_make_payment, set_can_make_payment) = signal(false);
+    let (is_showing, set_showing) = signal(false);
+    let (shipping_address, set_shipping_address) = signal(None::<PaymentShippingAddress>);
+    let (shipping_option, set_shipping_option) = signal(None::<String>);
+
+    let payment_request = PaymentRequestClient {
+        methods: StoredValue::new_local(methods),
+        details,
+        options: StoredValue::new(options),
+        request: StoredValue::new_local(None),
+        set_showing,
+        set_shipping_address,
+        set_shipping_option,
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        leptos::task::spawn_local(async move {
+            let Ok(promise) = payment_request.create_request().and_then(|request| {
+                call_method(&request, "canMakePayment").map_err(UsePaymentRequestError::from)
+            }) else {
+                return;
+            };
+
+            if let Ok(result) = crate::js_fut!(promise).await {
+                set_can_make_payment.try_set(result.as_bool().unwrap_or_default());
+            }
+        });
+    }
+
+    UsePaymentRequestReturn {
+        is_supported,
+        can_make_payment: can_make_payment.into(),
+        is_showing: is_showing.into(),
+        shipping_address: shipping_address.into(),
+        shipping_option: shipping_option.into(),
+        payment_request,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn call_method(target: &JsValue, method: &str) -> Result<js_sys::Promise, JsValue> {
+    use wasm_bindgen::JsCast;
+
+    let method: js_sys::Function = js_sys::Reflect::get(target, &method.into())?.dyn_into()?;
+
+    Ok(method.call0(target)?.unchecked_into())
+}
+
+/// Shows and aborts the payment sheet. Returned by [`use_payment_request`].
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct PaymentRequestClient {
+    methods: StoredValue<Vec<PaymentMethod>, LocalStorage>,
+    details: Signal<PaymentDetails>,
+    options: StoredValue<UsePaymentRequestOptions>,
+    request: StoredValue<Option<JsValue>, LocalStorage>,
+    set_showing: WriteSignal<bool>,
+    set_shipping_address: WriteSignal<Option<PaymentShippingAddress>>,
+    set_shipping_option: WriteSignal<Option<String>>,
+}
+
+impl PaymentRequestClient {
+    /// Shows the payment sheet with the current details and resolves when the user has accepted
+    /// the payment. Complete the payment with [`PaymentRequestResponse::complete`].
+    /// Has to be called in reaction to a user gesture.
+    pub async fn show(&self) -> Result<PaymentRequestResponse, UsePaymentRequestError> {
+        #[cfg(feature = "ssr")]
+        return Err(UsePaymentRequestError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::closure::Closure;
+            use wasm_bindgen::JsCast;
+
+            let request = self.create_request()?;
+            let target = request.unchecked_ref::<web_sys::EventTarget>();
+
+            let on_address_change = Closure::<dyn Fn(web_sys::PaymentRequestUpdateEvent)>::new({
+                let client = *self;
+                let request = request.clone();
+
+                move |event: web_sys::PaymentRequestUpdateEvent| {
+                    client.set_shipping_address.try_set(
+                        js!(request["shippingAddress"])
+                            .ok()
+                            .and_then(|address| address.dyn_into::<web_sys::PaymentAddress>().ok())
+                            .map(PaymentShippingAddress::from),
+                    );
+                    client.update_details(&event);
+                }
+            });
+
+            let on_option_change = Closure::<dyn Fn(web_sys::PaymentRequestUpdateEvent)>::new({
+                let client = *self;
+                let request = request.clone();
+
+                move |event: web_sys::PaymentRequestUpdateEvent| {
+                    client.set_shipping_option.try_set(
+                        js!(request["shippingOption"])
+                            .ok()
+                            .and_then(|option| option.as_string()),
+                    );
+                    client.update_details(&event);
+                }
+            });
+
+            let _ = target.add_event_listener_with_callback(
+                "shippingaddresschange",
+                on_address_change.as_ref().unchecked_ref(),
+            );
+            let _ = target.add_event_listener_with_callback(
+                "shippingoptionchange",
+                on_option_change.as_ref().unchecked_ref(),
+            );
+
+            self.request.set_value(Some(request.clone()));
+            self.set_showing.try_set(true);
+
+            let result = match call_method(&request, "show") {
+                Ok(promise) => crate::js_fut!(promise)
+                    .await
+                    .map(|response| PaymentRequestResponse {
+                        response: SendWrapper::new(response.unchecked_into()),
+                    })
+                    .map_err(UsePaymentRequestError::from),
+                Err(err) => Err(UsePaymentRequestError::from(err)),
+            };
+
+            self.request.try_set_value(None);
+            self.set_showing.try_set(false);
+
+            let _ = target.remove_event_listener_with_callback(
+                "shippingaddresschange",
+                on_address_change.as_ref().unchecked_ref(),
+            );
+            let _ = target.remove_event_listener_with_callback(
+                "shippingoptionchange",
+                on_option_change.as_ref().unchecked_ref(),
+            );
+
+            result
+        }
+    }
+
+    /// Closes the payment sheet. `show` then returns `Err(UsePaymentRequestError::Aborted)`.
+    pub fn abort(&self) {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(Some(request)) = self.request.try_get_value() {
+            let _ = call_method(&request, "abort");
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn create_request(&self) -> Result<JsValue, UsePaymentRequestError> {
+        use wasm_bindgen::JsCast;
+
+        let window = window();
+        let constructor = js!(window["PaymentRequest"])
+            .ok()
+            .and_then(|constructor| constructor.dyn_into::<js_sys::Function>().ok())
+            .ok_or(UsePaymentRequestError::NotSupported)?;
+
+        let methods = self.methods.with_value(|methods| {
+            methods
+                .iter()
+                .map(PaymentMethod::to_js)
+                .collect::<js_sys::Array>()
+        });
+        let details = self.details.with_untracked(PaymentDetails::to_js);
+        let options = self.options.with_value(UsePaymentRequestOptions::to_js);
+
+        js_sys::Reflect::construct(
+            &constructor,
+            &js_sys::Array::of3(&methods, &details, &options),
+        )
+        .map_err(UsePaymentRequestError::from)
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn update_details(&self, event: &web_sys::PaymentRequestUpdateEvent) {
+        let details = self.details;
+
+        let promise = wasm_bindgen_futures::future_to_promise(async move {
+            // let effects that react to the changed shipping signals run first
+            leptos::task::tick().await;
+
+            details
+                .try_with_untracked(|details| details.to_js().into())
+                .ok_or(JsValue::UNDEFINED)
+        });
+
+        let _ = event.update_with(&promise);
+    }
+}
+
+/// Response of [`PaymentRequestClient::show`].
+#[derive(Clone, Debug)]
+pub struct PaymentRequestResponse {
+    response: SendWrapper<web_sys::PaymentResponse>,
+}
+
+impl PaymentRequestResponse {
+    /// Identifier of the payment method the user has chosen
+    pub fn method_name(&self) -> String {
+        self.response.method_name()
+    }
+
+    /// Method specific data like a payment token that is sent to your server
+    pub fn details(&self) -> js_sys::Object {
+        self.response.details()
+    }
+
+    /// Name of the payer if `request_payer_name` was set
+    pub fn payer_name(&self) -> Option<String> {
+        self.response.payer_name()
+    }
+
+    /// Email of the payer if `request_payer_email` was set
+    pub fn payer_email(&self) -> Option<String> {
+        self.response.payer_email()
+    }
+
+    /// Phone number of the payer if `request_payer_phone` was set
+    pub fn payer_phone(&self) -> Option<String> {
+        self.response.payer_phone()
+    }
+
+    /// Shipping address if `request_shipping` was set
+    pub fn shipping_address(&self) -> Option<PaymentShippingAddress> {
+        self.response
+            .shipping_address()
+            .map(PaymentShippingAddress::from)
+    }
+
+    /// Id of the selected shipping option if `request_shipping` was set
+    pub fn shipping_option(&self) -> Option<String> {
+        self.response.shipping_option()
+    }
+
+    /// The underlying [`web_sys::PaymentResponse`]
+    pub fn payment_response(&self) -> &web_sys::PaymentResponse {
+        &self.response
+    }
+
+    /// Closes the payment sheet after the payment has been processed and tells the user the result.
+    pub async fn complete(&self, result: PaymentComplete) -> Result<(), UsePaymentRequestError> {
+        crate::js_fut!(self.response.complete_with_result(result))
+            .await
+            .map(|_| ())
+            .map_err(UsePaymentRequestError::from)
+    }
+}
+
+/// A payment method like `"https://google.com/pay"` and its method specific data.
+#[derive(Clone, Debug)]
+pub struct PaymentMethod {
+    /// Identifier of the payment method
+    pub supported_methods: String,
+
+    /// Method specific configuration
+    pub data: Option<js_sys::Object>,
+}
+
+impl PaymentMethod {
+    /// Creates a payment method without data.
+    pub fn new(supported_methods: impl Into<String>) -> Self {
+        Self {
+            supported_methods: supported_methods.into(),
+            data: None,
+        }
+    }
+
+    /// Sets the method specific data.
+    pub fn data(self, data: js_sys::Object) -> Self {
+        Self {
+            data: Some(data),
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn to_js(&self) -> js_sys::Object {
+        let method = js_sys::Object::new();
+        js!(method["supportedMethods"] = self.supported_methods.as_str());
+        if let Some(data) = &self.data {
+            js!(method["data"] = data);
+        }
+        method
+    }
+}
+
+/// A monetary amount.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PaymentAmount {
+    /// ISO 4217 currency code like `"USD"`
+    pub currency: String,
+
+    /// Decimal value like `"9.99"`
+    pub value: String,
+}
+
+impl PaymentAmount {
+    pub fn new(currency: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            currency: currency.into(),
+            value: value.into(),
+        }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn to_js(&self) -> js_sys::Object {
+        let amount = js_sys::Object::new();
+        js!(amount["currency"] = self.currency.as_str());
+        js!(amount["value"] = self.value.as_str());
+        amount
+    }
+}
+
+/// A line item of [`PaymentDetails`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PaymentItem {
+    /// Text shown to the user
+    pub label: String,
+
+    /// Amount of the item
+    pub amount: PaymentAmount,
+
+    /// `true` if the amount isn't final yet
+    pub pending: bool,
+}
+
+impl PaymentItem {
+    pub fn new(label: impl Into<String>, amount: PaymentAmount) -> Self {
+        Self {
+            label: label.into(),
+            amount,
+            pending: false,
+        }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn to_js(&self) -> js_sys::Object {
+        let item = js_sys::Object::new();
+        js!(item["label"] = self.label.as_str());
+        js!(item["amount"] = self.amount.to_js());
+        js!(item["pending"] = self.pending);
+        item
+    }
+}
+
+/// A shipping option of [`PaymentDetails`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PaymentShippingOption {
+    /// Identifier that is available as `shipping_option` when selected
+    pub id: String,
+
+    /// Text shown to the user
+    pub label: String,
+
+    /// Cost of the shipping option
+    pub amount: PaymentAmount,
+
+    /// `true` if this is the selected option
+    pub selected: bool,
+}
+
+impl PaymentShippingOption {
+    pub fn new(id: impl Into<String>, label: impl Into<String>, amount: PaymentAmount) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            amount,
+            selected: false,
+        }
+    }
+
+    /// Sets if this is the selected option.
+    pub fn selected(self, selected: bool) -> Self {
+        Self { selected, ..self }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn to_js(&self) -> js_sys::Object {
+        let option = js_sys::Object::new();
+        js!(option["id"] = self.id.as_str());
+        js!(option["label"] = self.label.as_str());
+        js!(option["amount"] = self.amount.to_js());
+        js!(option["selected"] = self.selected);
+        option
+    }
+}
+
+/// What the user pays. Passed as a signal to [`use_payment_request`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PaymentDetails {
+    /// The total amount
+    pub total: PaymentItem,
+
+    /// Line items shown in the payment sheet. They're not summed up by the browser.
+    pub display_items: Vec<PaymentItem>,
+
+    /// Shipping options the user can pick from if `request_shipping` is set
+    pub shipping_options: Vec<PaymentShippingOption>,
+}
+
+impl PaymentDetails {
+    pub fn new(total: PaymentItem) -> Self {
+        Self {
+            total,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the line items.
+    pub fn display_items(self, display_items: Vec<PaymentItem>) -> Self {
+        Self {
+            display_items,
+            ..self
+        }
+    }
+
+    /// Sets the shipping options.
+    pub fn shipping_options(self, shipping_options: Vec<PaymentShippingOption>) -> Self {
+        Self {
+            shipping_options,
+            ..self
+        }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn to_js(&self) -> js_sys::Object {
+        let details = js_sys::Object::new();
+        js!(details["total"] = self.total.to_js());
+        js!(details["displayItems"] = self
+            .display_items
+            .iter()
+            .map(PaymentItem::to_js)
+            .collect::<js_sys::Array>());
+        js!(details["shippingOptions"] = self
+            .shipping_options
+            .iter()
+            .map(PaymentShippingOption::to_js)
+            .collect::<js_sys::Array>());
+        details
+    }
+}
+
+/// Shipping address entered in the payment sheet.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PaymentShippingAddress {
+    /// ISO 3166-1 alpha-2 country code like `"US"`
+    pub country: String,
+    pub address_line: Vec<String>,
+    pub region: String,
+    pub city: String,
+    pub dependent_locality: String,
+    pub postal_code: String,
+    pub sorting_code: String,
+    pub organization: String,
+    pub recipient: String,
+    pub phone: String,
+}
+
+impl From<web_sys::PaymentAddress> for PaymentShippingAddress {
+    fn from(address: web_sys::PaymentAddress) -> Self {
+        Self {
+            country: address.country(),
+            address_line: address
+                .address_line()
+                .iter()
+                .filter_map(|line| line.as_string())
+                .collect(),
+            region: address.region(),
+            city: address.city(),
+            dependent_locality: address.dependent_locality(),
+            postal_code: address.postal_code(),
+            sorting_code: address.sorting_code(),
+            organization: address.organization(),
+            recipient: address.recipient(),
+            phone: address.phone(),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum UsePaymentRequestError {
+    #[error("the Payment Request API is not available on the server")]
+    Server,
+
+    #[error("the Payment Request API is not supported by this browser")]
+    NotSupported,
+
+    #[error("the payment has been aborted")]
+    Aborted,
+
+    #[error("payment request failed")]
+    Failed(JsValue),
+}
+
+impl From<JsValue> for UsePaymentRequestError {
+    fn from(err: JsValue) -> Self {
+        let is_abort = js!(err["name"])
+            .ok()
+            .and_then(|name| name.as_string())
+            .is_some_and(|name| name == "AbortError");
+
+        if is_abort {
+            Self::Aborted
+        } else {
+            Self::Failed(err)
+        }
+    }
+}
+
+/// Options for [`use_payment_request_with_options`].
+#[derive(DefaultBuilder, Default, Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UsePaymentRequestOptions {
+    /// Ask for the name of the payer. Defaults to `false`.
+    request_payer_name: bool,
+
+    /// Ask for the email of the payer. Defaults to `false`.
+    request_payer_email: bool,
+
+    /// Ask for the phone number of the payer. Defaults to `false`.
+    request_payer_phone: bool,
+
+    /// Ask for a shipping address and option. Defaults to `false`.
+    request_shipping: bool,
+}
+
+impl UsePaymentRequestOptions {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn to_js(&self) -> js_sys::Object {
+        let options = js_sys::Object::new();
+        js!(options["requestPayerName"] = self.request_payer_name);
+        js!(options["requestPayerEmail"] = self.request_payer_email);
+        js!(options["requestPayerPhone"] = self.request_payer_phone);
+        js!(options["requestShipping"] = self.request_shipping);
+        options
+    }
+}
+
+/// Return type of [`use_payment_request`].
+pub struct UsePaymentRequestReturn {
+    /// `true` if the browser supports the Payment Request API
+    pub is_supported: Signal<bool>,
+
+    /// `true` if the user can pay with one of the given payment methods
+    pub can_make_payment: Signal<bool>,
+
+    /// `true` while the payment sheet is shown
+    pub is_showing: Signal<bool>,
+
+    /// Shipping address last entered in the payment sheet
+    pub shipping_address: Signal<Option<PaymentShippingAddress>>,
+
+    /// Id of the shipping option last selected in the payment sheet
+    pub shipping_option: Signal<Option<String>>,
+
+    /// Shows and aborts the payment sheet
+    pub payment_request: PaymentRequestClient,
+}
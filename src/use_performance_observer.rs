@@ -0,0 +1,390 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, sendwrap_fn, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// Reactive [PerformanceObserver](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver).
+///
+/// Observes the given performance entry types and collects the reported entries in a signal. Entry types
+/// that aren't supported by the browser are ignored. From the observed entries the
+/// [Core Web Vitals](https://web.dev/articles/vitals) are calculated the same way the `web-vitals` library does.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_performance_observer)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_performance_observer, PerformanceEntryType, UsePerformanceObserverReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UsePerformanceObserverReturn { entries, .. } =
+///     use_performance_observer([PerformanceEntryType::LongTask, PerformanceEntryType::Paint]);
+///
+/// view! {
+///     <ul>
+///         <For each=move || entries.get() key=|entry| entry.start_time().to_bits() let:entry>
+///             <li>{entry.name()} ": " {entry.duration()} "ms"</li>
+///         </For>
+///     </ul>
+/// }
+/// # }
+/// ```
+///
+/// By default entries that have been recorded before the observer was created are delivered as well.
+/// This can be turned off with the option `buffered`. Only the most recent `max_entries` entries are kept.
+///
+/// ## Web Vitals
+///
+/// Observe `LargestContentfulPaint`, `LayoutShift` and `Event` to get the Largest Contentful Paint,
+/// Cumulative Layout Shift and Interaction to Next Paint metrics.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_performance_observer, PerformanceEntryType, UsePerformanceObserverReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UsePerformanceObserverReturn {
+///     largest_contentful_paint,
+///     cumulative_layout_shift,
+///     interaction_to_next_paint,
+///     ..
+/// } = use_performance_observer([
+///     PerformanceEntryType::LargestContentfulPaint,
+///     PerformanceEntryType::LayoutShift,
+///     PerformanceEntryType::Event,
+/// ]);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `stop` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_performance_observer`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no entries are observed, `is_supported` is `false` and `stop` is a no-op.
+pub fn use_performance_observer(
+    entry_types: impl IntoIterator<Item = PerformanceEntryType>,
+) -> UsePerformanceObserverReturn<impl Fn() + Clone + Send + Sync> {
+    use_performance_observer_with_options(entry_types, UsePerformanceObserverOptions::default())
+}
+
+/// Version of [`use_performance_observer`] that takes a `UsePerformanceObserverOptions`. See [`use_performance_observer`] for how to use.
+pub fn use_performance_observer_with_options(
+    entry_types: impl IntoIterator<Item = PerformanceEntryType>,
+    options: UsePerformanceObserverOptions,
+) -> UsePerformanceObserverReturn<impl Fn() + Clone + Send + Sync> {
+    let UsePerformanceObserverOptions {
+        buffered,
+        max_entries,
+        event_duration_threshold,
+    } = options;
+
+    let entry_types = entry_types.into_iter().collect::<Vec<_>>();
+
+    let is_supported = use_supported(|| js!("PerformanceObserver" in &window()));
+
+    let (entries, set_entries) = signal_local(Vec::<web_sys::PerformanceEntry>::new());
+    let (largest_contentful_paint, set_largest_contentful_paint) = signal(None::<f64>);
+    let (cumulative_layout_shift, set_cumulative_layout_shift) = signal(None::<f64>);
+    let (interaction_to_next_paint, set_interaction_to_next_paint) = signal(None::<f64>);
+
+    let (observer, set_observer) = signal_local(None::<web_sys::PerformanceObserver>);
+
+    let stop = sendwrap_fn!(move || {
+        if let Some(observer) = observer.get_untracked() {
+            observer.disconnect();
+            set_observer.set(None);
+        }
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let web_vitals = Rc::new(RefCell::new(WebVitals::default()));
+
+        let callback = Closure::<dyn FnMut(web_sys::PerformanceObserverEntryList)>::new(
+            move |entry_list: web_sys::PerformanceObserverEntryList| {
+                let new_entries = entry_list
+                    .get_entries()
+                    .into_iter()
+                    .map(|entry| entry.unchecked_into::<web_sys::PerformanceEntry>())
+                    .collect::<Vec<_>>();
+
+                let mut web_vitals = web_vitals.borrow_mut();
+                for entry in &new_entries {
+                    web_vitals.add_entry(entry);
+                }
+
+                set_largest_contentful_paint.set(web_vitals.largest_contentful_paint);
+                set_cumulative_layout_shift.set(web_vitals.cumulative_layout_shift());
+                set_interaction_to_next_paint.set(web_vitals.interaction_to_next_paint());
+
+                set_entries.update(|entries| {
+                    entries.extend(new_entries);
+
+                    if entries.len() > max_entries {
+                        entries.drain(..entries.len() - max_entries);
+                    }
+                });
+            },
+        )
+        .into_js_value();
+
+        if let Ok(new_observer) =
+            web_sys::PerformanceObserver::new(callback.unchecked_ref::<js_sys::Function>())
+        {
+            let supported_entry_types = supported_entry_types();
+
+            for entry_type in entry_types {
+                if !supported_entry_types
+                    .iter()
+                    .any(|t| t == entry_type.as_str())
+                {
+                    continue;
+                }
+
+                // `type` instead of `entryTypes` is needed for `buffered` to have an effect
+                let init = js_sys::Object::new();
+                js!(init["type"] = entry_type.as_str());
+                js!(init["buffered"] = buffered);
+                if entry_type == PerformanceEntryType::Event {
+                    js!(init["durationThreshold"] = event_duration_threshold);
+                }
+
+                new_observer.observe(init.unchecked_ref());
+            }
+
+            set_observer.set(Some(new_observer));
+        }
+    }
+
+    on_cleanup({
+        let stop = stop.clone();
+
+        move || {
+            stop();
+        }
+    });
+
+    UsePerformanceObserverReturn {
+        is_supported,
+        entries: entries.into(),
+        largest_contentful_paint: largest_contentful_paint.into(),
+        cumulative_layout_shift: cumulative_layout_shift.into(),
+        interaction_to_next_paint: interaction_to_next_paint.into(),
+        stop,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn supported_entry_types() -> Vec<String> {
+    let class = js_sys::Reflect::get(&window(), &"PerformanceObserver".into()).unwrap_or_default();
+
+    js!(class["supportedEntryTypes"])
+        .ok()
+        .and_then(|types| types.dyn_into::<js_sys::Array>().ok())
+        .map(|types| types.iter().filter_map(|t| t.as_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Running calculation of the Core Web Vitals from observed performance entries.
+#[derive(Default)]
+struct WebVitals {
+    largest_contentful_paint: Option<f64>,
+
+    /// Largest layout shift session window so far
+    max_session_value: Option<f64>,
+    session_value: f64,
+    session_start: f64,
+    session_last: f64,
+
+    /// Longest duration per interaction id
+    interactions: HashMap<u64, f64>,
+}
+
+impl WebVitals {
+    fn add_entry(&mut self, entry: &web_sys::PerformanceEntry) {
+        match entry.entry_type().as_str() {
+            "largest-contentful-paint" => {
+                self.largest_contentful_paint = Some(entry.start_time());
+            }
+            "layout-shift" => {
+                if js!(entry["hadRecentInput"])
+                    .ok()
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or_default()
+                {
+                    return;
+                }
+
+                let value = js!(entry["value"])
+                    .ok()
+                    .and_then(|v| v.as_f64())
+                    .unwrap_or_default();
+                let start_time = entry.start_time();
+
+                // a session window ends after a gap of 1s or when it's 5s long
+                if self.session_value > 0.0
+                    && start_time - self.session_last < 1000.0
+                    && start_time - self.session_start < 5000.0
+                {
+                    self.session_value += value;
+                } else {
+                    self.session_value = value;
+                    self.session_start = start_time;
+                }
+                self.session_last = start_time;
+
+                self.max_session_value = Some(
+                    self.max_session_value
+                        .unwrap_or_default()
+                        .max(self.session_value),
+                );
+            }
+            "event" | "first-input" => {
+                let interaction_id = js!(entry["interactionId"])
+                    .ok()
+                    .and_then(|v| v.as_f64())
+                    .unwrap_or_default() as u64;
+
+                if interaction_id == 0 {
+                    return;
+                }
+
+                let duration = self.interactions.entry(interaction_id).or_default();
+                *duration = duration.max(entry.duration());
+            }
+            _ => {}
+        }
+    }
+
+    fn cumulative_layout_shift(&self) -> Option<f64> {
+        self.max_session_value
+    }
+
+    /// Approximates the 98th percentile by ignoring the longest interaction for every 50 interactions.
+    fn interaction_to_next_paint(&self) -> Option<f64> {
+        let mut durations = self.interactions.values().copied().collect::<Vec<_>>();
+        durations.sort_by(|a, b| b.total_cmp(a));
+
+        let index = (durations.len() / 50).min(durations.len().saturating_sub(1));
+        durations.get(index).copied()
+    }
+}
+
+/// Type of performance entries that can be observed with [`use_performance_observer`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PerformanceEntryType {
+    /// `element`: when specific elements are rendered
+    Element,
+    /// `event`: slow user interactions
+    Event,
+    /// `first-input`: the first user interaction
+    FirstInput,
+    /// `largest-contentful-paint`: the largest element rendered so far
+    LargestContentfulPaint,
+    /// `layout-shift`: unexpected movement of elements
+    LayoutShift,
+    /// `long-animation-frame`: animation frames that took longer than 50ms
+    LongAnimationFrame,
+    /// `longtask`: tasks that blocked the main thread for more than 50ms
+    LongTask,
+    /// `mark`: marks created with `performance.mark()`
+    Mark,
+    /// `measure`: measures created with `performance.measure()`
+    Measure,
+    /// `navigation`: the navigation of the document
+    Navigation,
+    /// `paint`: first paint and first contentful paint
+    Paint,
+    /// `resource`: loading of resources like scripts, images and fetch requests
+    Resource,
+    /// `visibility-state`: changes of the visibility of the page
+    VisibilityState,
+}
+
+impl PerformanceEntryType {
+    /// The entry type name as used by the browser.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Element => "element",
+            Self::Event => "event",
+            Self::FirstInput => "first-input",
+            Self::LargestContentfulPaint => "largest-contentful-paint",
+            Self::LayoutShift => "layout-shift",
+            Self::LongAnimationFrame => "long-animation-frame",
+            Self::LongTask => "longtask",
+            Self::Mark => "mark",
+            Self::Measure => "measure",
+            Self::Navigation => "navigation",
+            Self::Paint => "paint",
+            Self::Resource => "resource",
+            Self::VisibilityState => "visibility-state",
+        }
+    }
+}
+
+/// Options for [`use_performance_observer_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UsePerformanceObserverOptions {
+    /// Also deliver entries that have been recorded before the observer was created. Defaults to `true`.
+    buffered: bool,
+
+    /// Maximum number of entries kept in `entries`. Older entries are dropped. Defaults to `1000`.
+    /// The web vitals are calculated from all entries regardless of this limit.
+    max_entries: usize,
+
+    /// Minimum duration in milliseconds of `event` entries to be reported. The browser doesn't accept
+    /// values below `16`. Defaults to `40` which is the same as in the `web-vitals` library.
+    event_duration_threshold: f64,
+}
+
+impl Default for UsePerformanceObserverOptions {
+    fn default() -> Self {
+        Self {
+            buffered: true,
+            max_entries: 1000,
+            event_duration_threshold: 40.0,
+        }
+    }
+}
+
+/// Return type of [`use_performance_observer`].
+pub struct UsePerformanceObserverReturn<StopFn>
+where
+    StopFn: Fn() + Clone + Send + Sync,
+{
+    /// `true` if the browser supports `PerformanceObserver`
+    pub is_supported: Signal<bool>,
+
+    /// The observed entries, oldest first
+    pub entries: Signal<Vec<web_sys::PerformanceEntry>, LocalStorage>,
+
+    /// Largest Contentful Paint in milliseconds. Requires observing `LargestContentfulPaint`.
+    pub largest_contentful_paint: Signal<Option<f64>>,
+
+    /// Cumulative Layout Shift score. Requires observing `LayoutShift`.
+    pub cumulative_layout_shift: Signal<Option<f64>>,
+
+    /// Interaction to Next Paint in milliseconds. Requires observing `Event`.
+    pub interaction_to_next_paint: Signal<Option<f64>>,
+
+    /// Stop observing
+    pub stop: StopFn,
+}
@@ -0,0 +1,888 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::filter_builder_methods;
+use crate::storage::StorageType;
+use crate::utils::{DebounceOptions, FilterOptions, ThrottleOptions};
+use crate::SameSite;
+use codee::{CodecError, Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::RefCell;
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::Arc;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Persist any `RwSignal` to a pluggable [`PersistenceBackend`].
+///
+/// The value is read from the backend once and then written back whenever the signal changes.
+/// Values are (en)decoded via the given string codec just like in [`fn@crate::storage::use_storage`].
+///
+/// Backends for local and session storage ([`StoragePersistence`]), cookies ([`CookiePersistence`]) and
+/// IndexedDB ([`IndexedDbPersistence`]) are included. Anything else, like a REST API, can be added by
+/// implementing [`PersistenceBackend`].
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_persisted_signal)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_persisted_signal, IndexedDbPersistence, StoragePersistence, UsePersistedSignalReturn};
+/// # use codee::string::{FromToStringCodec, JsonSerdeCodec};
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// # #[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+/// # pub struct Draft { title: String, body: String }
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let count = RwSignal::new(0);
+/// let UsePersistedSignalReturn { is_loaded, .. } = use_persisted_signal::<_, FromToStringCodec, _>(
+///     count,
+///     "count",
+///     StoragePersistence::local(),
+/// );
+///
+/// let draft = RwSignal::new(Draft::default());
+/// use_persisted_signal::<_, JsonSerdeCodec, _>(
+///     draft,
+///     "draft",
+///     IndexedDbPersistence::new("my-app", "drafts"),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Writing
+///
+/// Writes can be debounced or throttled with the option `filter`. Changes that are made before the
+/// stored value has been read win over the stored value and are written right after the read.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_persisted_signal_with_options, CookiePersistence, UsePersistedSignalOptions};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let text = RwSignal::new(String::new());
+///
+/// use_persisted_signal_with_options::<_, FromToStringCodec, _>(
+///     text,
+///     "text",
+///     CookiePersistence::default().max_age(3600_000), // one hour
+///     UsePersistedSignalOptions::default().debounce(500.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Custom Backends
+///
+/// A backend stores encoded strings under a key. All methods are async so backends that talk to
+/// the network or to IndexedDB don't block.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::PersistenceBackend;
+/// #
+/// # async fn http_get(url: &str) -> Result<Option<String>, String> { Ok(None) }
+/// # async fn http_put(url: &str, body: &str) -> Result<(), String> { Ok(()) }
+/// # async fn http_delete(url: &str) -> Result<(), String> { Ok(()) }
+/// #
+/// struct RestPersistence {
+///     base_url: String,
+/// }
+///
+/// impl PersistenceBackend for RestPersistence {
+///     type Error = String;
+///
+///     async fn read(&self, key: &str) -> Result<Option<String>, String> {
+///         http_get(&format!("{}/{key}", self.base_url)).await
+///     }
+///
+///     async fn write(&self, key: &str, value: &str) -> Result<(), String> {
+///         http_put(&format!("{}/{key}", self.base_url), value).await
+///     }
+///
+///     async fn remove(&self, key: &str) -> Result<(), String> {
+///         http_delete(&format!("{}/{key}", self.base_url)).await
+///     }
+/// }
+/// ```
+///
+/// Implement [`PersistenceBackend::subscribe`] as well if the backend can tell when the value was changed
+/// from somewhere else. [`StoragePersistence`] does that to sync local storage across tabs.
+///
+/// ## Relation to other Functions
+///
+/// [`fn@crate::storage::use_storage`], [`fn@crate::use_cookie`] and [`fn@crate::use_color_mode`] keep
+/// their own persistence and are not built on [`PersistenceBackend`]. They read the stored value
+/// synchronously, which an async backend can't do. [`StoragePersistence`] and [`CookiePersistence`] store
+/// values the same way though, so a value written by one can be read by the other when the same key and
+/// codec are used.
+///
+/// ## Hydration
+///
+/// The stored value is always read asynchronously after the component has been created. During
+/// hydration the signal therefore keeps the value it had on the server which prevents hydration errors.
+/// Use `is_loaded` to find out when the stored value has been applied.
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `remove` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_persisted_signal`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server nothing is read or written and `is_loaded` is always `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::storage::use_storage`]
+/// * [`fn@crate::use_cookie`]
+pub fn use_persisted_signal<T, C, B>(
+    source: RwSignal<T>,
+    key: impl Into<String>,
+    backend: B,
+) -> UsePersistedSignalReturn<impl Fn() + Clone + Send + Sync>
+where
+    T: Clone + Send + Sync + 'static,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+    B: PersistenceBackend,
+{
+    use_persisted_signal_with_options::<T, C, B>(
+        source,
+        key,
+        backend,
+        UsePersistedSignalOptions::default(),
+    )
+}
+
+/// Version of [`use_persisted_signal`] that takes a `UsePersistedSignalOptions`. See [`use_persisted_signal`] for how to use.
+pub fn use_persisted_signal_with_options<T, C, B>(
+    source: RwSignal<T>,
+    key: impl Into<String>,
+    backend: B,
+    options: UsePersistedSignalOptions<
+        B::Error,
+        <C as Encoder<T>>::Error,
+        <C as Decoder<T>>::Error,
+    >,
+) -> UsePersistedSignalReturn<impl Fn() + Clone + Send + Sync>
+where
+    T: Clone + Send + Sync + 'static,
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+    B: PersistenceBackend,
+{
+    let UsePersistedSignalOptions { on_error, filter } = options;
+
+    let key = key.into();
+
+    let (is_loaded, set_loaded) = signal(false);
+
+    let remove;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = source;
+        let _ = backend;
+        let _ = on_error;
+        let _ = filter;
+        let _ = set_loaded;
+
+        remove = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{sendwrap_fn, watch_with_options, WatchOptions};
+        use leptos::task::spawn_local;
+        use std::cell::{Cell, RefCell};
+
+        let backend = Rc::new(backend);
+        // encoded value that is known to be persisted. Used to avoid writing back what was just read.
+        let persisted = Rc::new(RefCell::new(None::<String>));
+        // incremented for every write or removal so only the latest one updates `persisted`
+        let write_count = Rc::new(Cell::new(0_usize));
+        let changed_before_load = Rc::new(Cell::new(false));
+
+        let write = {
+            let backend = Rc::clone(&backend);
+            let persisted = Rc::clone(&persisted);
+            let write_count = Rc::clone(&write_count);
+            let key = key.clone();
+            let on_error = Arc::clone(&on_error);
+
+            move |value: &T| {
+                let encoded = match C::encode(value) {
+                    Ok(encoded) => encoded,
+                    Err(err) => {
+                        on_error(UsePersistedSignalError::Codec(CodecError::Encode(err)));
+                        return;
+                    }
+                };
+
+                if persisted.borrow().as_ref() == Some(&encoded) {
+                    return;
+                }
+
+                write_count.set(write_count.get() + 1);
+                let count = write_count.get();
+
+                let backend = Rc::clone(&backend);
+                let persisted = Rc::clone(&persisted);
+                let write_count = Rc::clone(&write_count);
+                let key = key.clone();
+                let on_error = Arc::clone(&on_error);
+
+                spawn_local(async move {
+                    match backend.write(&key, &encoded).await {
+                        Ok(()) => {
+                            if write_count.get() == count {
+                                persisted.replace(Some(encoded));
+                            }
+                        }
+                        Err(err) => on_error(UsePersistedSignalError::Backend(err)),
+                    }
+                });
+            }
+        };
+
+        // applies a value that has been read from the backend
+        let apply = {
+            let persisted = Rc::clone(&persisted);
+            let on_error = Arc::clone(&on_error);
+
+            move |encoded: Option<String>| {
+                let Some(encoded) = encoded else {
+                    persisted.replace(None);
+                    return;
+                };
+
+                match C::decode(&encoded) {
+                    Ok(value) => {
+                        persisted.replace(Some(encoded));
+                        source.try_set(value);
+                    }
+                    Err(err) => on_error(UsePersistedSignalError::Codec(CodecError::Decode(err))),
+                }
+            }
+        };
+
+        let _ = watch_with_options(
+            move || source.get(),
+            {
+                let write = write.clone();
+                let changed_before_load = Rc::clone(&changed_before_load);
+
+                move |value, _, _| {
+                    if is_loaded.get_untracked() {
+                        write(value);
+                    } else {
+                        changed_before_load.set(true);
+                    }
+                }
+            },
+            WatchOptions::default().filter(filter),
+        );
+
+        {
+            let backend = Rc::clone(&backend);
+            let key = key.clone();
+            let on_error = Arc::clone(&on_error);
+            let apply = apply.clone();
+
+            spawn_local(async move {
+                match backend.read(&key).await {
+                    Ok(encoded) => {
+                        if changed_before_load.get() {
+                            if let Some(value) = source.try_get_untracked() {
+                                write(&value);
+                            }
+                        } else {
+                            apply(encoded);
+                        }
+                    }
+                    Err(err) => on_error(UsePersistedSignalError::Backend(err)),
+                }
+
+                set_loaded.try_set(true);
+            });
+        }
+
+        backend.subscribe(
+            &key,
+            Rc::new(move |encoded| {
+                if is_loaded.get_untracked() {
+                    apply(encoded);
+                }
+            }),
+        );
+
+        remove = {
+            let persisted = Rc::clone(&persisted);
+
+            sendwrap_fn!(move || {
+                persisted.replace(None);
+                write_count.set(write_count.get() + 1);
+
+                let backend = Rc::clone(&backend);
+                let key = key.clone();
+                let on_error = Arc::clone(&on_error);
+
+                spawn_local(async move {
+                    if let Err(err) = backend.remove(&key).await {
+                        on_error(UsePersistedSignalError::Backend(err));
+                    }
+                });
+            })
+        };
+    }
+
+    UsePersistedSignalReturn {
+        is_loaded: is_loaded.into(),
+        remove,
+    }
+}
+
+/// A place to persist encoded values under a key. Used by [`use_persisted_signal`].
+///
+/// See [`use_persisted_signal`] for an example of how to implement it.
+pub trait PersistenceBackend: 'static {
+    /// Error that the backend can fail with
+    type Error;
+
+    /// Reads the value stored under `key`. Returns `None` if there is none.
+    fn read(&self, key: &str) -> impl Future<Output = Result<Option<String>, Self::Error>>;
+
+    /// Stores `value` under `key`.
+    fn write(&self, key: &str, value: &str) -> impl Future<Output = Result<(), Self::Error>>;
+
+    /// Deletes the value stored under `key`.
+    fn remove(&self, key: &str) -> impl Future<Output = Result<(), Self::Error>>;
+
+    /// Called once by [`use_persisted_signal`] to listen to changes of the value under `key`
+    /// from the outside, e.g. from other tabs. Call `on_change` with the new encoded value.
+    /// The default implementation does nothing.
+    fn subscribe(&self, key: &str, on_change: Rc<dyn Fn(Option<String>)>) {
+        let _ = (key, on_change);
+    }
+}
+
+/// Persists to local or session storage. Local storage is synced across tabs.
+pub struct StoragePersistence {
+    storage_type: StorageType,
+}
+
+impl StoragePersistence {
+    /// Persists to the given kind of storage.
+    pub fn new(storage_type: StorageType) -> Self {
+        Self { storage_type }
+    }
+
+    /// Persists to local storage.
+    pub fn local() -> Self {
+        Self::new(StorageType::Local)
+    }
+
+    /// Persists to session storage.
+    pub fn session() -> Self {
+        Self::new(StorageType::Session)
+    }
+
+    fn storage(&self) -> Result<web_sys::Storage, JsValue> {
+        match &self.storage_type {
+            StorageType::Local => window().local_storage(),
+            StorageType::Session => window().session_storage(),
+            StorageType::Custom(storage) => Ok(Some(storage.clone())),
+        }?
+        .ok_or_else(|| JsValue::from_str("storage not available"))
+    }
+}
+
+impl From<StorageType> for StoragePersistence {
+    fn from(storage_type: StorageType) -> Self {
+        Self::new(storage_type)
+    }
+}
+
+impl PersistenceBackend for StoragePersistence {
+    type Error = JsValue;
+
+    async fn read(&self, key: &str) -> Result<Option<String>, JsValue> {
+        self.storage()?.get_item(key)
+    }
+
+    async fn write(&self, key: &str, value: &str) -> Result<(), JsValue> {
+        self.storage()?.set_item(key, value)
+    }
+
+    async fn remove(&self, key: &str) -> Result<(), JsValue> {
+        self.storage()?.remove_item(key)
+    }
+
+    fn subscribe(&self, key: &str, on_change: Rc<dyn Fn(Option<String>)>) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            use crate::use_event_listener;
+
+            let Ok(storage) = self.storage() else {
+                return;
+            };
+            let key = key.to_string();
+
+            // only fired for changes made in other documents
+            let _ = use_event_listener(window(), leptos::ev::storage, move |event| {
+                if event.storage_area().as_ref() != Some(&storage) {
+                    return;
+                }
+
+                match event.key() {
+                    Some(event_key) if event_key == key => on_change(event.new_value()),
+                    // all keys have been cleared
+                    None => on_change(None),
+                    _ => {}
+                }
+            });
+        }
+    }
+}
+
+/// Persists to a cookie that is named like the key.
+///
+/// Only the attributes relevant for cookies written by the browser are available.
+/// See [`fn@crate::use_cookie`] if you need the cookie on the server as well.
+#[derive(DefaultBuilder, Clone, Default, Debug)]
+pub struct CookiePersistence {
+    /// [`Max-Age` attribute](https://tools.ietf.org/html/rfc6265#section-5.2.2) in milliseconds.
+    /// Defaults to `None` which makes it a session cookie.
+    #[builder(into)]
+    max_age: Option<i64>,
+
+    /// [`Path` attribute](https://tools.ietf.org/html/rfc6265#section-5.2.4). Defaults to `None`
+    /// which uses the path of the current page.
+    #[builder(into)]
+    path: Option<String>,
+
+    /// [`Domain` attribute](https://tools.ietf.org/html/rfc6265#section-5.2.3). Defaults to `None`.
+    #[builder(into)]
+    domain: Option<String>,
+
+    /// [`SameSite` attribute](https://tools.ietf.org/html/draft-ietf-httpbis-rfc6265bis-03#section-4.1.2.7).
+    /// Defaults to `None`.
+    #[builder(into)]
+    same_site: Option<SameSite>,
+
+    /// [`Secure` attribute](https://tools.ietf.org/html/rfc6265#section-5.2.5). Defaults to `false`.
+    secure: bool,
+}
+
+impl CookiePersistence {
+    #[cfg(not(feature = "ssr"))]
+    fn document() -> web_sys::HtmlDocument {
+        use wasm_bindgen::JsCast;
+
+        document().unchecked_into()
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn set_cookie(&self, key: &str, value: &str, max_age: Option<i64>) -> Result<(), JsValue> {
+        // `Expires` is set as well because `Max-Age=0` alone isn't honored by all browsers
+        let expires = max_age.filter(|max_age| *max_age == 0);
+
+        let cookie = crate::use_cookie::build_cookie_from_options(
+            key,
+            max_age,
+            expires,
+            false,
+            self.secure,
+            &self.path,
+            self.same_site,
+            &self.domain,
+            value,
+        );
+
+        Self::document().set_cookie(&cookie.encoded().to_string())
+    }
+}
+
+impl PersistenceBackend for CookiePersistence {
+    type Error = JsValue;
+
+    async fn read(&self, key: &str) -> Result<Option<String>, JsValue> {
+        #[cfg(feature = "ssr")]
+        {
+            Ok(None)
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let cookies = Self::document().cookie()?;
+
+            Ok(cookie::Cookie::split_parse_encoded(cookies)
+                .flatten()
+                .find(|cookie| cookie.name() == key)
+                .map(|cookie| cookie.value().to_string()))
+        }
+    }
+
+    async fn write(&self, key: &str, value: &str) -> Result<(), JsValue> {
+        #[cfg(feature = "ssr")]
+        {
+            Ok(())
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            self.set_cookie(key, value, self.max_age)
+        }
+    }
+
+    async fn remove(&self, key: &str) -> Result<(), JsValue> {
+        #[cfg(feature = "ssr")]
+        {
+            Ok(())
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            self.set_cookie(key, "", Some(0))
+        }
+    }
+}
+
+/// Persists to an object store of an [IndexedDB](https://developer.mozilla.org/en-US/docs/Web/API/IndexedDB_API) database.
+///
+/// The database and the object store are created if they don't exist yet. Several backends can use
+/// different stores of the same database. A missing store is created by upgrading the database to a new version.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_persisted_signal, IndexedDbPersistence};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let draft = RwSignal::new(String::new());
+/// let note = RwSignal::new(String::new());
+///
+/// use_persisted_signal::<_, FromToStringCodec, _>(
+///     draft,
+///     "current",
+///     IndexedDbPersistence::new("my-app", "drafts"),
+/// );
+/// use_persisted_signal::<_, FromToStringCodec, _>(
+///     note,
+///     "current",
+///     IndexedDbPersistence::new("my-app", "notes"),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The database is opened once on first use and shared by all clones of the backend. It's closed when
+/// the last clone is dropped.
+#[derive(Clone, Debug)]
+pub struct IndexedDbPersistence {
+    database: String,
+    store: String,
+    connection: Rc<RefCell<Option<IdbConnection>>>,
+}
+
+/// Open database that is closed on drop.
+#[derive(Debug)]
+struct IdbConnection(web_sys::IdbDatabase);
+
+impl Drop for IdbConnection {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "ssr"))]
+        self.0.close();
+    }
+}
+
+impl IndexedDbPersistence {
+    /// Persists to the object store `store` of the database `database`.
+    pub fn new(database: impl Into<String>, store: impl Into<String>) -> Self {
+        Self {
+            database: database.into(),
+            store: store.into(),
+            connection: Default::default(),
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    async fn open(&self) -> Result<web_sys::IdbDatabase, JsValue> {
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::JsCast;
+
+        if let Some(IdbConnection(db)) = self.connection.borrow().as_ref() {
+            return Ok(db.clone());
+        }
+
+        let factory = window()
+            .indexed_db()?
+            .ok_or_else(|| JsValue::from_str("IndexedDB not available"))?;
+
+        let mut db = open_idb_database(&factory, &self.database, None, &self.store).await?;
+
+        // Stores can only be created while upgrading the database. This is the case if another backend
+        // has already created the database for a different store.
+        for _ in 0..3 {
+            if db.object_store_names().contains(&self.store) {
+                break;
+            }
+
+            let version = db.version() as u32 + 1;
+            db.close();
+
+            db = match open_idb_database(&factory, &self.database, Some(version), &self.store).await
+            {
+                Ok(db) => db,
+                // another tab or backend has upgraded the database in the meantime
+                Err(_) => open_idb_database(&factory, &self.database, None, &self.store).await?,
+            };
+        }
+
+        // another call might have opened the database in the meantime
+        if let Some(IdbConnection(cached)) = self.connection.borrow().as_ref() {
+            db.close();
+            return Ok(cached.clone());
+        }
+
+        // don't block other tabs from upgrading the database. It's opened again on the next use.
+        let on_version_change = Closure::<dyn Fn()>::new({
+            let connection = Rc::downgrade(&self.connection);
+
+            move || {
+                if let Some(connection) = connection.upgrade() {
+                    connection.take();
+                }
+            }
+        });
+        db.set_onversionchange(Some(on_version_change.into_js_value().unchecked_ref()));
+
+        self.connection.replace(Some(IdbConnection(db.clone())));
+
+        Ok(db)
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    async fn object_store(
+        &self,
+        mode: web_sys::IdbTransactionMode,
+    ) -> Result<web_sys::IdbObjectStore, JsValue> {
+        let transaction = match self
+            .open()
+            .await?
+            .transaction_with_str_and_mode(&self.store, mode)
+        {
+            Ok(transaction) => transaction,
+            Err(_) => {
+                // the browser might have closed the connection
+                self.connection.take();
+
+                self.open()
+                    .await?
+                    .transaction_with_str_and_mode(&self.store, mode)?
+            }
+        };
+
+        transaction.object_store(&self.store)
+    }
+}
+
+impl PersistenceBackend for IndexedDbPersistence {
+    type Error = JsValue;
+
+    async fn read(&self, key: &str) -> Result<Option<String>, JsValue> {
+        #[cfg(feature = "ssr")]
+        {
+            Ok(None)
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let store = self
+                .object_store(web_sys::IdbTransactionMode::Readonly)
+                .await?;
+
+            let value = idb_request_result(&store.get(&JsValue::from_str(key))?).await?;
+
+            Ok(value.as_string())
+        }
+    }
+
+    async fn write(&self, key: &str, value: &str) -> Result<(), JsValue> {
+        #[cfg(feature = "ssr")]
+        {
+            Ok(())
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let store = self
+                .object_store(web_sys::IdbTransactionMode::Readwrite)
+                .await?;
+
+            idb_request_result(
+                &store.put_with_key(&JsValue::from_str(value), &JsValue::from_str(key))?,
+            )
+            .await
+            .map(|_| ())
+        }
+    }
+
+    async fn remove(&self, key: &str) -> Result<(), JsValue> {
+        #[cfg(feature = "ssr")]
+        {
+            Ok(())
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let store = self
+                .object_store(web_sys::IdbTransactionMode::Readwrite)
+                .await?;
+
+            idb_request_result(&store.delete(&JsValue::from_str(key))?)
+                .await
+                .map(|_| ())
+        }
+    }
+}
+
+/// Opens the database `name` and creates the object store `store` if the database is upgraded.
+/// Without a `version` the current version is opened, or version `1` if the database doesn't exist yet.
+#[cfg(not(feature = "ssr"))]
+async fn open_idb_database(
+    factory: &web_sys::IdbFactory,
+    name: &str,
+    version: Option<u32>,
+    store: &str,
+) -> Result<web_sys::IdbDatabase, JsValue> {
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    let request = match version {
+        Some(version) => factory.open_with_u32(name, version)?,
+        None => factory.open(name)?,
+    };
+
+    let on_upgrade_needed = Closure::<dyn Fn(JsValue)>::new({
+        let request = request.clone();
+        let store = store.to_string();
+
+        move |_| {
+            if let Ok(db) = request.result() {
+                let db: web_sys::IdbDatabase = db.unchecked_into();
+
+                if !db.object_store_names().contains(&store) {
+                    let _ = db.create_object_store(&store);
+                }
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade_needed.as_ref().unchecked_ref()));
+
+    let db = idb_request_result(&request).await;
+    request.set_onupgradeneeded(None);
+
+    Ok(db?.unchecked_into())
+}
+
+/// Waits for an IndexedDB request to finish and returns its result.
+#[cfg(not(feature = "ssr"))]
+async fn idb_request_result(request: &web_sys::IdbRequest) -> Result<JsValue, JsValue> {
+    let done = js_sys::Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+
+    let result = crate::js_fut!(done).await;
+
+    request.set_onsuccess(None);
+    request.set_onerror(None);
+
+    match result {
+        Ok(_) => request.result(),
+        Err(_) => Err(request
+            .error()
+            .ok()
+            .flatten()
+            .map(JsValue::from)
+            .unwrap_or(JsValue::UNDEFINED)),
+    }
+}
+
+/// Error passed to `on_error` of [`use_persisted_signal_with_options`].
+#[derive(Error, Debug)]
+pub enum UsePersistedSignalError<B, E, D> {
+    #[error("persistence backend failed")]
+    Backend(B),
+    #[error("failed to encode / decode the value")]
+    Codec(CodecError<E, D>),
+}
+
+/// Options for [`use_persisted_signal_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePersistedSignalOptions<B, E, D> {
+    /// Callback for when reading, writing or (en)decoding fails. Defaults to doing nothing.
+    #[builder(skip)]
+    on_error: Arc<dyn Fn(UsePersistedSignalError<B, E, D>) + Send + Sync>,
+
+    /// Debounce or throttle the writes to the backend. Defaults to writing on every change.
+    #[builder(into)]
+    filter: FilterOptions,
+}
+
+impl<B, E, D> Default for UsePersistedSignalOptions<B, E, D> {
+    fn default() -> Self {
+        Self {
+            on_error: Arc::new(|_| ()),
+            filter: FilterOptions::default(),
+        }
+    }
+}
+
+impl<B, E, D> UsePersistedSignalOptions<B, E, D> {
+    filter_builder_methods!(
+        /// the writes to the backend
+        filter
+    );
+
+    /// Callback for when reading, writing or (en)decoding fails.
+    pub fn on_error(
+        self,
+        on_error: impl Fn(UsePersistedSignalError<B, E, D>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            on_error: Arc::new(on_error),
+            ..self
+        }
+    }
+}
+
+/// Return type of [`use_persisted_signal`].
+pub struct UsePersistedSignalReturn<RemoveFn>
+where
+    RemoveFn: Fn() + Clone + Send + Sync,
+{
+    /// `true` once the stored value has been read and applied to the signal
+    pub is_loaded: Signal<bool>,
+
+    /// Deletes the persisted value. The signal keeps its current value and is persisted again on
+    /// the next change.
+    pub remove: RemoveFn,
+}
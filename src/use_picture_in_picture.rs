@@ -0,0 +1,454 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::use_supported;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive [Picture-in-Picture API](https://developer.mozilla.org/en-US/docs/Web/API/Picture-in-Picture_API).
+///
+/// Plays a video in a small always-on-top window. Optionally the
+/// [Document Picture-in-Picture API](https://developer.mozilla.org/en-US/docs/Web/API/Document_Picture-in-Picture_API)
+/// can be used to move any element into such a window.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_picture_in_picture)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_picture_in_picture, UsePictureInPictureReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video = NodeRef::<Video>::new();
+///
+/// let UsePictureInPictureReturn { is_supported, is_active, toggle, .. } = use_picture_in_picture(video);
+///
+/// view! {
+///     <video node_ref=video src="video.mp4" controls></video>
+///     <button on:click=move |_| toggle() disabled=move || !is_supported.get()>
+///         {move || if is_active.get() { "Exit Picture-in-Picture" } else { "Enter Picture-in-Picture" }}
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// Entering Picture-in-Picture has to be triggered by a user interaction like a click. `width` and `height`
+/// contain the size of the Picture-in-Picture window while it's open and are updated when the user resizes it.
+///
+/// ## Document Picture-in-Picture
+///
+/// With the option `document_pip` set to `true` the target element is moved into a Picture-in-Picture window
+/// if the browser supports the Document Picture-in-Picture API. This works with any element, not only videos.
+/// The style sheets of the page are copied into the new window unless `copy_styles` is `false`.
+/// When the window is closed the element is moved back to where it was.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_picture_in_picture_with_options, UsePictureInPictureOptions, UsePictureInPictureReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let player = NodeRef::<Div>::new();
+///
+/// let UsePictureInPictureReturn { enter, .. } = use_picture_in_picture_with_options(
+///     player,
+///     UsePictureInPictureOptions::default().document_pip(true),
+/// );
+///
+/// view! {
+///     <div node_ref=player>
+///         <video src="video.mp4" controls></video>
+///         <p>"Custom controls"</p>
+///     </div>
+///     <button on:click=move |_| enter()>"Pop out"</button>
+/// }
+/// # }
+/// ```
+///
+/// If the Document Picture-in-Picture API isn't available and the target is a video, the normal
+/// Picture-in-Picture mode is used instead.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `enter`, `exit` and `toggle` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_picture_in_picture`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_document_pip_supported` are always `false` and the returned
+/// closures are no-ops.
+pub fn use_picture_in_picture<El, M>(
+    target: El,
+) -> UsePictureInPictureReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_picture_in_picture_with_options(target, UsePictureInPictureOptions::default())
+}
+
+/// Version of [`use_picture_in_picture`] that takes a `UsePictureInPictureOptions`. See [`use_picture_in_picture`] for how to use.
+pub fn use_picture_in_picture_with_options<El, M>(
+    target: El,
+    options: UsePictureInPictureOptions,
+) -> UsePictureInPictureReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UsePictureInPictureOptions {
+        document_pip,
+        copy_styles,
+    } = options;
+
+    let target = target.into_element_maybe_signal();
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let document = document();
+            crate::js!(document["pictureInPictureEnabled"])
+                .map(|enabled| enabled.is_truthy())
+                .unwrap_or_default()
+        }
+    });
+
+    let is_document_pip_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("documentPictureInPicture" in &window())
+        }
+    });
+
+    let (is_active, set_active) = signal(false);
+    let (width, set_width) = signal(0.0);
+    let (height, set_height) = signal(0.0);
+
+    let enter;
+    let exit;
+    let toggle;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = target;
+        let _ = set_active;
+        let _ = set_width;
+        let _ = set_height;
+
+        enter = || {};
+        exit = || {};
+        toggle = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js, js_fut, sendwrap_fn, use_event_listener};
+        use leptos::ev::Custom;
+        use wasm_bindgen::JsCast;
+
+        // either the classic `PictureInPictureWindow` or the `Window` of Document Picture-in-Picture
+        let (pip_window, set_pip_window) = signal_local(None::<web_sys::EventTarget>);
+        let (document_window, set_document_window) = signal_local(None::<web_sys::Window>);
+
+        let update_size = move |pip: &wasm_bindgen::JsValue| {
+            let (w, h) = window_size(pip);
+            let _ = set_width.try_set(w);
+            let _ = set_height.try_set(h);
+        };
+
+        let _ = use_event_listener(
+            target.clone(),
+            Custom::<web_sys::Event>::new("enterpictureinpicture"),
+            move |event| {
+                if let Some(pip) = js!(event["pictureInPictureWindow"])
+                    .ok()
+                    .filter(|pip| pip.is_object())
+                {
+                    update_size(&pip);
+                    set_pip_window.set(Some(pip.unchecked_into()));
+                }
+                set_active.set(true);
+            },
+        );
+
+        let _ = use_event_listener(
+            target.clone(),
+            Custom::<web_sys::Event>::new("leavepictureinpicture"),
+            move |_| {
+                set_active.set(false);
+                set_pip_window.set(None);
+            },
+        );
+
+        let _ = use_event_listener(
+            pip_window,
+            Custom::<web_sys::Event>::new("resize"),
+            move |event| {
+                if let Some(pip) = event.target() {
+                    update_size(&pip);
+                }
+            },
+        );
+
+        let enter_document_pip = move |el: web_sys::Element| {
+            let window = window();
+
+            let Ok(document_picture_in_picture) = js!(window["documentPictureInPicture"]) else {
+                return;
+            };
+
+            let pip_options = js_sys::Object::new();
+            js!(pip_options["width"] = el.client_width());
+            js!(pip_options["height"] = el.client_height());
+
+            let Ok(promise) = js!(document_picture_in_picture["requestWindow"])
+                .and_then(|request_window| {
+                    request_window
+                        .unchecked_into::<js_sys::Function>()
+                        .call1(&document_picture_in_picture, &pip_options)
+                })
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+            else {
+                return;
+            };
+
+            leptos::task::spawn_local(async move {
+                let Ok(pip) = js_fut!(promise).await else {
+                    return;
+                };
+                let pip = pip.unchecked_into::<web_sys::Window>();
+
+                let Some(pip_body) = pip.document().and_then(|pip_document| {
+                    if copy_styles {
+                        copy_style_sheets(&pip_document);
+                    }
+                    pip_document.body()
+                }) else {
+                    return;
+                };
+
+                // marks the position of the element in the page to move it back later
+                let placeholder = document().create_text_node("");
+                let Some(parent) = el.parent_node() else {
+                    return;
+                };
+                if parent.insert_before(&placeholder, Some(&el)).is_err()
+                    || pip_body.append_child(&el).is_err()
+                {
+                    return;
+                }
+
+                let restore = wasm_bindgen::closure::Closure::once_into_js(move || {
+                    if let Some(parent) = placeholder.parent_node() {
+                        let _ = parent.replace_child(&el, &placeholder);
+                    }
+
+                    let _ = set_active.try_set(false);
+                    let _ = set_pip_window.try_set(None);
+                    let _ = set_document_window.try_set(None);
+                });
+                let _ = pip.add_event_listener_with_callback("pagehide", restore.unchecked_ref());
+
+                update_size(&pip);
+                let _ = set_document_window.try_set(Some(pip.clone()));
+                let _ = set_pip_window.try_set(Some(pip.into()));
+                let _ = set_active.try_set(true);
+            });
+        };
+
+        enter = sendwrap_fn!(move || {
+            if is_active.get_untracked() {
+                return;
+            }
+
+            let Some(el) = target.get_untracked() else {
+                return;
+            };
+
+            if document_pip && is_document_pip_supported.get_untracked() {
+                enter_document_pip(el);
+                return;
+            }
+
+            if !is_supported.get_untracked() {
+                return;
+            }
+
+            let Ok(promise) = js!(el["requestPictureInPicture"])
+                .and_then(|request| request.unchecked_into::<js_sys::Function>().call0(&el))
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+            else {
+                return;
+            };
+
+            leptos::task::spawn_local(async move {
+                // the state is updated by the `enterpictureinpicture` event
+                let _ = js_fut!(promise).await;
+            });
+        });
+
+        exit = sendwrap_fn!(move || {
+            if !is_active.try_get_untracked().unwrap_or_default() {
+                return;
+            }
+
+            if let Some(pip) = document_window.try_get_untracked().flatten() {
+                // the element is moved back by the `pagehide` listener
+                let _ = pip.close();
+                return;
+            }
+
+            let document = document();
+
+            if let Ok(promise) = js!(document["exitPictureInPicture"])
+                .and_then(|exit| exit.unchecked_into::<js_sys::Function>().call0(&document))
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+            {
+                leptos::task::spawn_local(async move {
+                    let _ = js_fut!(promise).await;
+                });
+            }
+        });
+
+        toggle = {
+            let enter = enter.clone();
+            let exit = exit.clone();
+
+            sendwrap_fn!(move || {
+                if is_active.get_untracked() {
+                    exit();
+                } else {
+                    enter();
+                }
+            })
+        };
+
+        on_cleanup(exit.clone());
+    }
+
+    UsePictureInPictureReturn {
+        is_supported,
+        is_document_pip_supported,
+        is_active: is_active.into(),
+        width: width.into(),
+        height: height.into(),
+        enter,
+        exit,
+        toggle,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn window_size(pip: &wasm_bindgen::JsValue) -> (f64, f64) {
+    let number = |keys: [&str; 2]| {
+        keys.into_iter()
+            .find_map(|key| {
+                js_sys::Reflect::get(pip, &key.into())
+                    .ok()
+                    .and_then(|value| value.as_f64())
+            })
+            .unwrap_or_default()
+    };
+
+    // `Window` has `innerWidth`, `PictureInPictureWindow` has `width`
+    (
+        number(["innerWidth", "width"]),
+        number(["innerHeight", "height"]),
+    )
+}
+
+#[cfg(not(feature = "ssr"))]
+fn copy_style_sheets(pip_document: &web_sys::Document) {
+    let (Some(head), Some(pip_head)) = (document().head(), pip_document.head()) else {
+        return;
+    };
+
+    let Ok(nodes) = head.query_selector_all("style, link[rel=\"stylesheet\"]") else {
+        return;
+    };
+
+    for i in 0..nodes.length() {
+        if let Some(clone) = nodes
+            .item(i)
+            .and_then(|node| node.clone_node_with_deep(true).ok())
+        {
+            let _ = pip_head.append_child(&clone);
+        }
+    }
+}
+
+/// Options for [`use_picture_in_picture_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UsePictureInPictureOptions {
+    /// Use the Document Picture-in-Picture API if it's supported. This moves the target element into
+    /// the Picture-in-Picture window. Defaults to `false`.
+    document_pip: bool,
+
+    /// Copy the style sheets of the page into the Document Picture-in-Picture window. Defaults to `true`.
+    copy_styles: bool,
+}
+
+impl Default for UsePictureInPictureOptions {
+    fn default() -> Self {
+        Self {
+            document_pip: false,
+            copy_styles: true,
+        }
+    }
+}
+
+/// Return type of [`use_picture_in_picture`].
+pub struct UsePictureInPictureReturn<EnterFn, ExitFn, ToggleFn>
+where
+    EnterFn: Fn() + Clone + Send + Sync,
+    ExitFn: Fn() + Clone + Send + Sync,
+    ToggleFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the Picture-in-Picture API is supported
+    pub is_supported: Signal<bool>,
+
+    /// Whether the Document Picture-in-Picture API is supported
+    pub is_document_pip_supported: Signal<bool>,
+
+    /// `true` while the target is shown in a Picture-in-Picture window
+    pub is_active: Signal<bool>,
+
+    /// Width of the Picture-in-Picture window
+    pub width: Signal<f64>,
+
+    /// Height of the Picture-in-Picture window
+    pub height: Signal<f64>,
+
+    /// Show the target in a Picture-in-Picture window
+    pub enter: EnterFn,
+
+    /// Close the Picture-in-Picture window
+    pub exit: ExitFn,
+
+    /// Enter or exit Picture-in-Picture depending on `is_active`
+    pub toggle: ToggleFn,
+}
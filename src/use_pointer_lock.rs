@@ -0,0 +1,289 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::use_supported;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive [Pointer Lock API](https://developer.mozilla.org/en-US/docs/Web/API/Pointer_Lock_API).
+///
+/// Hides the cursor and delivers the relative mouse movement even when the pointer would hit the edge of
+/// the screen. This is what you want for first-person camera controls in games or 3D viewers.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_pointer_lock)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Canvas;
+/// # use leptos_use::{use_pointer_lock, UsePointerLockReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let canvas = NodeRef::<Canvas>::new();
+///
+/// let UsePointerLockReturn {
+///     is_locked,
+///     movement_x,
+///     movement_y,
+///     lock,
+///     ..
+/// } = use_pointer_lock(canvas);
+///
+/// let (yaw, set_yaw) = signal(0.0);
+///
+/// Effect::new(move |_| {
+///     // rotate the camera by the movement of the last frame
+///     set_yaw.update(|yaw| *yaw += movement_x.get() * 0.002);
+/// });
+///
+/// view! {
+///     <canvas node_ref=canvas on:click=move |_| lock()></canvas>
+/// }
+/// # }
+/// ```
+///
+/// Locking the pointer has to be triggered by a user interaction like a click. The user can always unlock
+/// it by pressing `Esc`.
+///
+/// ## Movement
+///
+/// All `mousemove` events of a frame are summed up and `movement_x` and `movement_y` are updated once per animation frame.
+/// If the mouse hasn't moved in a frame they are `0.0`. This way a render loop can read the movement since
+/// the last frame without losing events.
+///
+/// ## Unadjusted movement
+///
+/// By default the movement is affected by the acceleration settings of the operating system. With
+/// `unadjusted_movement` set to `true` the raw movement of the mouse is reported instead, if the browser
+/// supports it. Otherwise the pointer is locked with the adjusted movement.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Canvas;
+/// # use leptos_use::{use_pointer_lock_with_options, UsePointerLockOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let canvas = NodeRef::<Canvas>::new();
+/// let pointer_lock = use_pointer_lock_with_options(
+///     canvas,
+///     UsePointerLockOptions::default().unadjusted_movement(true),
+/// );
+/// #
+/// # view! { <canvas node_ref=canvas></canvas> }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `lock` and `unlock` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_pointer_lock`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_locked` are always `false`, the movement is always `0.0` and
+/// the returned closures are no-ops.
+pub fn use_pointer_lock<El, M>(
+    target: El,
+) -> UsePointerLockReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_pointer_lock_with_options(target, UsePointerLockOptions::default())
+}
+
+/// Version of [`use_pointer_lock`] that takes a `UsePointerLockOptions`. See [`use_pointer_lock`] for how to use.
+pub fn use_pointer_lock_with_options<El, M>(
+    target: El,
+    options: UsePointerLockOptions,
+) -> UsePointerLockReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UsePointerLockOptions {
+        unadjusted_movement,
+    } = options;
+
+    let target = target.into_element_maybe_signal();
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("pointerLockElement" in &document())
+        }
+    });
+
+    let (is_locked, set_locked) = signal(false);
+    let (movement_x, set_movement_x) = signal(0.0);
+    let (movement_y, set_movement_y) = signal(0.0);
+
+    let lock;
+    let unlock;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = target;
+        let _ = set_locked;
+        let _ = set_movement_x;
+        let _ = set_movement_y;
+
+        lock = || {};
+        unlock = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{
+            js, js_fut, sendwrap_fn, use_event_listener, use_raf_fn_with_options, UseRafFnOptions,
+        };
+        use leptos::ev::{mousemove, Custom};
+        use std::cell::Cell;
+        use std::rc::Rc;
+        use wasm_bindgen::JsCast;
+
+        let accumulated = Rc::new(Cell::new((0.0, 0.0)));
+
+        let raf = use_raf_fn_with_options(
+            {
+                let accumulated = Rc::clone(&accumulated);
+
+                move |_| {
+                    let (x, y) = accumulated.replace((0.0, 0.0));
+
+                    if movement_x.get_untracked() != x {
+                        set_movement_x.set(x);
+                    }
+                    if movement_y.get_untracked() != y {
+                        set_movement_y.set(y);
+                    }
+                }
+            },
+            UseRafFnOptions::default().immediate(false),
+        );
+
+        let _ = use_event_listener(
+            document(),
+            Custom::<web_sys::Event>::new("pointerlockchange"),
+            {
+                let target = target.clone();
+
+                move |_| {
+                    let locked = document().pointer_lock_element().is_some()
+                        && document().pointer_lock_element() == target.get_untracked();
+
+                    set_locked.set(locked);
+
+                    if locked {
+                        (raf.resume)();
+                    } else {
+                        (raf.pause)();
+                        set_movement_x.set(0.0);
+                        set_movement_y.set(0.0);
+                    }
+                }
+            },
+        );
+
+        let _ = use_event_listener(document(), mousemove, move |event| {
+            if is_locked.get_untracked() {
+                let (x, y) = accumulated.get();
+
+                accumulated.set((x + event.movement_x() as f64, y + event.movement_y() as f64));
+            }
+        });
+
+        lock = sendwrap_fn!(move || {
+            if is_locked.get_untracked() {
+                return;
+            }
+
+            let Some(el) = target.get_untracked() else {
+                return;
+            };
+
+            if !unadjusted_movement {
+                el.request_pointer_lock();
+                return;
+            }
+
+            let options = js_sys::Object::new();
+            js!(options["unadjustedMovement"] = true);
+
+            let result = js!(el["requestPointerLock"]).and_then(|request| {
+                request
+                    .unchecked_into::<js_sys::Function>()
+                    .call1(&el, &options)
+            });
+
+            if let Ok(promise) = result.and_then(|result| result.dyn_into::<js_sys::Promise>()) {
+                leptos::task::spawn_local(async move {
+                    // fall back to the adjusted movement if the raw movement isn't supported
+                    if js_fut!(promise).await.is_err() {
+                        el.request_pointer_lock();
+                    }
+                });
+            }
+        });
+
+        unlock = sendwrap_fn!(move || {
+            if is_locked.try_get_untracked().unwrap_or_default() {
+                document().exit_pointer_lock();
+            }
+        });
+
+        on_cleanup(unlock.clone());
+    }
+
+    UsePointerLockReturn {
+        is_supported,
+        is_locked: is_locked.into(),
+        movement_x: movement_x.into(),
+        movement_y: movement_y.into(),
+        lock,
+        unlock,
+    }
+}
+
+/// Options for [`use_pointer_lock_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UsePointerLockOptions {
+    /// Report the raw mouse movement that isn't affected by the operating system's mouse acceleration.
+    /// Falls back to the adjusted movement if not supported by the browser. Defaults to `false`.
+    unadjusted_movement: bool,
+}
+
+/// Return type of [`use_pointer_lock`].
+pub struct UsePointerLockReturn<LockFn, UnlockFn>
+where
+    LockFn: Fn() + Clone + Send + Sync,
+    UnlockFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the Pointer Lock API is supported
+    pub is_supported: Signal<bool>,
+
+    /// `true` while the pointer is locked to the target
+    pub is_locked: Signal<bool>,
+
+    /// Horizontal mouse movement in pixels during the last animation frame
+    pub movement_x: Signal<f64>,
+
+    /// Vertical mouse movement in pixels during the last animation frame
+    pub movement_y: Signal<f64>,
+
+    /// Lock the pointer to the target
+    pub lock: LockFn,
+
+    /// Release the pointer lock
+    pub unlock: UnlockFn,
+}
@@ -0,0 +1,328 @@
+use crate::core::{IntoElementMaybeSignal, PointerType};
+use crate::{
+    use_document, use_event_listener_with_options, use_window, UseEventListenerOptions, UseWindow,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{pointercancel, pointerdown, pointerleave, pointermove, pointerout, pointerup};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::Arc;
+use web_sys::PointerEvent;
+
+/// Reactive state of all active pointers (multi-touch).
+///
+/// Tracks every pointer that is currently pressed or in contact with the target as a map of
+/// pointer id to [`UsePointerState`] (position, pressure, tilt, ...). This is the building block for
+/// pinch-to-zoom, rotate and other multi-finger interactions.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_pointers)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_pointers_with_options, UsePointersOptions, UsePointersReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UsePointersReturn { pointers, count } = use_pointers_with_options(
+///     UsePointersOptions::default().target(el)
+/// );
+///
+/// let pinch_distance = move || {
+///     let pointers = pointers.get();
+///     let mut iter = pointers.values();
+///
+///     match (iter.next(), iter.next()) {
+///         (Some(a), Some(b)) => Some((a.x - b.x).hypot(a.y - b.y)),
+///         _ => None,
+///     }
+/// };
+///
+/// view! {
+///     <div node_ref=el style="touch-action: none">
+///         {count} " pointers, pinch distance: " {move || format!("{:?}", pinch_distance())}
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// Please note that touch pointers are cancelled by the browser as soon as it starts to pan or zoom.
+/// Set `touch-action: none` on the target to prevent that.
+///
+/// ## Callbacks
+///
+/// You can get notified when a pointer starts to be tracked or stops being tracked.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_pointers_with_options, UsePointersOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// use_pointers_with_options(
+///     UsePointersOptions::default()
+///         .on_enter(|pointer| log!("Pointer {} down", pointer.id))
+///         .on_leave(|pointer| log!("Pointer {} up", pointer.id))
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Hovering Pointers
+///
+/// By default only pointers with an active button state (touches, pressed-down pens and mouse buttons)
+/// are tracked. If you set `include_hover` to `true`, hovering mice and pens are tracked as well
+/// until they leave the target or the page.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that always contains an empty map.
+pub fn use_pointers() -> UsePointersReturn {
+    use_pointers_with_options(UsePointersOptions::default())
+}
+
+/// Version of [`use_pointers`] that takes a `UsePointersOptions`. See [`use_pointers`] for how to use.
+pub fn use_pointers_with_options<El, M>(options: UsePointersOptions<El, M>) -> UsePointersReturn
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    let UsePointersOptions {
+        target,
+        pointer_types,
+        include_hover,
+        on_enter,
+        on_leave,
+        ..
+    } = options;
+
+    let pointers = RwSignal::new(HashMap::<i32, UsePointerState>::new());
+
+    let filter_event = move |event: &PointerEvent| {
+        let ty = event.pointer_type();
+        pointer_types.iter().any(|p| p.to_string() == ty)
+    };
+
+    let track = {
+        let filter_event = filter_event.clone();
+
+        move |event: PointerEvent, insert: bool| {
+            if !filter_event(&event) {
+                return;
+            }
+
+            let state = UsePointerState::from(&event);
+
+            let is_new = !pointers.with_untracked(|pointers| pointers.contains_key(&state.id));
+            if is_new && !insert {
+                return;
+            }
+
+            pointers.update(|pointers| {
+                pointers.insert(state.id, state.clone());
+            });
+
+            if is_new {
+                #[cfg(debug_assertions)]
+                let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                on_enter(state);
+            }
+        }
+    };
+
+    let untrack = move |event: PointerEvent| {
+        if !filter_event(&event) {
+            return;
+        }
+
+        let mut removed = false;
+        pointers.update(|pointers| {
+            removed = pointers.remove(&event.pointer_id()).is_some();
+        });
+
+        if removed {
+            #[cfg(debug_assertions)]
+            let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+            on_leave(UsePointerState::from(&event));
+        }
+    };
+
+    let target = target.into_element_maybe_signal();
+    let listener_options = UseEventListenerOptions::default().passive(true);
+
+    let _ = use_event_listener_with_options(
+        target.clone(),
+        pointerdown,
+        {
+            let track = track.clone();
+            move |event| track(event, true)
+        },
+        listener_options,
+    );
+    let _ = use_event_listener_with_options(
+        target.clone(),
+        pointermove,
+        {
+            let track = track.clone();
+            move |event| track(event, include_hover)
+        },
+        listener_options,
+    );
+    // Pointers can be released outside of the target so these are listened to on the window
+    let _ = use_event_listener_with_options(
+        use_window(),
+        pointerup,
+        {
+            let untrack = untrack.clone();
+            move |event: PointerEvent| {
+                if include_hover && event.pointer_type() != "touch" {
+                    // keeps hovering if it hasn't left the target yet
+                    track(event, false);
+                } else {
+                    untrack(event);
+                }
+            }
+        },
+        listener_options,
+    );
+    let _ = use_event_listener_with_options(
+        use_window(),
+        pointercancel,
+        untrack.clone(),
+        listener_options,
+    );
+
+    if include_hover {
+        let _ = use_event_listener_with_options(
+            target,
+            pointerleave,
+            untrack.clone(),
+            listener_options,
+        );
+
+        // `pointerleave` isn't fired on the window. A pointer that leaves the page fires a
+        // `pointerout` without a `relatedTarget` instead.
+        let _ = use_event_listener_with_options(
+            use_document(),
+            pointerout,
+            move |event: PointerEvent| {
+                if event.related_target().is_none() {
+                    untrack(event);
+                }
+            },
+            listener_options,
+        );
+    }
+
+    UsePointersReturn {
+        pointers: pointers.into(),
+        count: Signal::derive(move || pointers.with(|pointers| pointers.len())),
+    }
+}
+
+/// Options for [`use_pointers_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePointersOptions<El, M>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    /// The element to listen to the pointer events on. Defaults to `window`.
+    /// Releasing and cancelling pointers is always detected on `window`.
+    target: El,
+
+    /// Pointer types that are tracked. Defaults to `[Mouse, Touch, Pen]`.
+    pointer_types: Vec<PointerType>,
+
+    /// Also track hovering mice and pens that don't have an active button state. Defaults to `false`.
+    include_hover: bool,
+
+    /// Called when a pointer starts to be tracked.
+    on_enter: Arc<dyn Fn(UsePointerState) + Send + Sync>,
+
+    /// Called when a pointer stops being tracked.
+    on_leave: Arc<dyn Fn(UsePointerState) + Send + Sync>,
+
+    #[builder(skip)]
+    _marker: PhantomData<M>,
+}
+
+impl<M> Default for UsePointersOptions<UseWindow, M>
+where
+    UseWindow: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    fn default() -> Self {
+        Self {
+            target: use_window(),
+            pointer_types: vec![PointerType::Mouse, PointerType::Touch, PointerType::Pen],
+            include_hover: false,
+            on_enter: Arc::new(|_| {}),
+            on_leave: Arc::new(|_| {}),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// State of a single pointer tracked by [`use_pointers`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UsePointerState {
+    /// The unique id of the pointer (`pointerId`)
+    pub id: i32,
+    /// The type of the pointer. `None` if the browser reports an unknown type.
+    pub pointer_type: Option<PointerType>,
+    /// If this is the primary pointer of its type
+    pub is_primary: bool,
+    /// X coordinate relative to the viewport (`clientX`)
+    pub x: f64,
+    /// Y coordinate relative to the viewport (`clientY`)
+    pub y: f64,
+    /// Normalized pressure between `0.0` and `1.0`
+    pub pressure: f64,
+    /// Plane angle in degrees between the Y-Z plane and the plane containing the pointer axis and the Y axis
+    pub tilt_x: f64,
+    /// Plane angle in degrees between the X-Z plane and the plane containing the pointer axis and the X axis
+    pub tilt_y: f64,
+    /// Clockwise rotation in degrees of the pointer around its own major axis
+    pub twist: f64,
+    /// Width of the contact geometry in px
+    pub width: f64,
+    /// Height of the contact geometry in px
+    pub height: f64,
+}
+
+impl From<&PointerEvent> for UsePointerState {
+    fn from(event: &PointerEvent) -> Self {
+        Self {
+            id: event.pointer_id(),
+            pointer_type: PointerType::from_str(&event.pointer_type()).ok(),
+            is_primary: event.is_primary(),
+            x: event.client_x() as f64,
+            y: event.client_y() as f64,
+            pressure: event.pressure() as f64,
+            tilt_x: event.tilt_x() as f64,
+            tilt_y: event.tilt_y() as f64,
+            twist: event.twist() as f64,
+            width: event.width() as f64,
+            height: event.height() as f64,
+        }
+    }
+}
+
+/// Return type of [`use_pointers`].
+pub struct UsePointersReturn {
+    /// Map of pointer id to the state of all currently tracked pointers
+    pub pointers: Signal<HashMap<i32, UsePointerState>>,
+    /// Number of currently tracked pointers
+    pub count: Signal<usize>,
+}
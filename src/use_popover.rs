@@ -0,0 +1,415 @@
+use crate::core::{ElementsMaybeSignal, IntoElementMaybeSignal, IntoElementsMaybeSignal};
+use crate::use_supported;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::fmt::Display;
+use std::sync::Arc;
+
+/// Reactive [Popover API](https://developer.mozilla.org/en-US/docs/Web/API/Popover_API).
+///
+/// Controls an element as a popover with `show`, `hide` and `toggle`. The returned `is_open` signal is kept
+/// in sync with the actual state of the popover, also when it is light-dismissed by the browser
+/// (click outside or `Escape` key).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_popover)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_popover, UsePopoverReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UsePopoverReturn { is_open, toggle, .. } = use_popover(el);
+///
+/// view! {
+///     <button on:click=move |_| toggle()>"Toggle Popover"</button>
+///     <div node_ref=el>"I'm a popover"</div>
+///     <p>"Open: " {is_open}</p>
+/// }
+/// # }
+/// ```
+///
+/// The `popover` attribute is added to the element automatically (as `auto` or `manual` depending
+/// on the option `mode`) if it's not present already.
+///
+/// ## Fallback
+///
+/// In browsers that don't support the Popover API the element is shown and hidden with the `hidden`
+/// attribute instead. In `auto` mode light-dismissing is emulated by hiding the popover on clicks outside of it
+/// and on the `Escape` key. When the popover is shown, an element inside of it with the `autofocus`
+/// attribute is focused. When it's hidden, the focus is restored to the element that was focused before.
+///
+/// Please note that in fallback mode, a click on the button that toggles the popover counts as a click
+/// outside. Add it to the option `ignore` so it doesn't immediately hide the popover again.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::{Button, Div};
+/// # use leptos_use::{use_popover_with_options, UsePopoverOptions, UsePopoverReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+/// let button = NodeRef::<Button>::new();
+///
+/// let UsePopoverReturn { toggle, .. } = use_popover_with_options(
+///     el,
+///     UsePopoverOptions::default()
+///         .ignore([button])
+///         .on_toggle(|open| leptos::logging::log!("open: {open}")),
+/// );
+///
+/// view! {
+///     <button node_ref=button on:click=move |_| toggle()>"Toggle Popover"</button>
+///     <div node_ref=el>"I'm a popover"</div>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `show`, `hide` and `toggle` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_popover`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_open` is always `false` and the returned closures are no-ops.
+pub fn use_popover<El, M>(
+    target: El,
+) -> UsePopoverReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    use_popover_with_options(target, UsePopoverOptions::default())
+}
+
+/// Version of [`use_popover`] that takes a `UsePopoverOptions`. See [`use_popover`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_popover_with_options<El, M>(
+    target: El,
+    options: UsePopoverOptions,
+) -> UsePopoverReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    let UsePopoverOptions {
+        mode,
+        ignore,
+        on_toggle,
+    } = options;
+
+    let is_supported = use_supported(is_popover_supported);
+
+    let (is_open, set_open) = signal(false);
+
+    let show;
+    let hide;
+    let toggle;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_open;
+
+        show = || {};
+        hide = || {};
+        toggle = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{
+            on_click_outside_with_options, sendwrap_fn, use_event_listener, OnClickOutsideOptions,
+        };
+        use leptos::ev::Custom;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use wasm_bindgen::JsCast;
+
+        let target = target.into_element_maybe_signal();
+
+        let html_element = {
+            let target = target.clone();
+
+            move || {
+                target
+                    .get_untracked()
+                    .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok())
+            }
+        };
+
+        let set_state = move |open: bool| {
+            if open != is_open.get_untracked() {
+                set_open.set(open);
+
+                #[cfg(debug_assertions)]
+                let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                on_toggle(open);
+            }
+        };
+
+        let sync = {
+            let set_state = set_state.clone();
+
+            move |el: &web_sys::HtmlElement| {
+                set_state(el.matches(":popover-open").unwrap_or_default());
+            }
+        };
+        let previous_focus = Rc::new(RefCell::new(None::<web_sys::HtmlElement>));
+
+        show = {
+            let html_element = html_element.clone();
+            let previous_focus = Rc::clone(&previous_focus);
+            let set_state = set_state.clone();
+            let sync = sync.clone();
+
+            sendwrap_fn!(move || {
+                let Some(el) = html_element() else {
+                    return;
+                };
+
+                if is_supported.get_untracked() {
+                    let _ = el.show_popover();
+                    sync(&el);
+                } else if !is_open.get_untracked() {
+                    previous_focus.replace(
+                        document()
+                            .active_element()
+                            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok()),
+                    );
+
+                    el.set_hidden(false);
+                    set_state(true);
+
+                    if let Ok(Some(autofocus)) = el.query_selector("[autofocus]") {
+                        if let Some(autofocus) = autofocus.dyn_ref::<web_sys::HtmlElement>() {
+                            let _ = autofocus.focus();
+                        }
+                    }
+                }
+            })
+        };
+
+        hide = {
+            let html_element = html_element.clone();
+            let sync = sync.clone();
+
+            sendwrap_fn!(move || {
+                let Some(el) = html_element() else {
+                    return;
+                };
+
+                if is_supported.get_untracked() {
+                    let _ = el.hide_popover();
+                    sync(&el);
+                } else if is_open.get_untracked() {
+                    let had_focus = document()
+                        .active_element()
+                        .map(|active| el.contains(Some(&active)))
+                        .unwrap_or_default();
+
+                    el.set_hidden(true);
+                    set_state(false);
+
+                    if let Some(previous_focus) = previous_focus.take() {
+                        if had_focus {
+                            let _ = previous_focus.focus();
+                        }
+                    }
+                }
+            })
+        };
+
+        toggle = {
+            let show = show.clone();
+            let hide = hide.clone();
+
+            move || {
+                if is_open.get_untracked() {
+                    hide();
+                } else {
+                    show();
+                }
+            }
+        };
+
+        let _ = Effect::watch(
+            {
+                let target = target.clone();
+                move || target.get()
+            },
+            {
+                let html_element = html_element.clone();
+                let sync = sync.clone();
+
+                move |_, _, _| {
+                    if let Some(el) = html_element() {
+                        if !el.has_attribute("popover") {
+                            let _ = el.set_attribute("popover", &mode.to_string());
+                        }
+
+                        if is_supported.get_untracked() {
+                            sync(&el);
+                        } else {
+                            el.set_hidden(!is_open.get_untracked());
+                        }
+                    }
+                }
+            },
+            true,
+        );
+
+        // fired by the browser when the popover is shown or (light-)dismissed
+        let _ = use_event_listener(
+            target.clone(),
+            Custom::<web_sys::Event>::new("toggle"),
+            move |_| {
+                if let Some(el) = html_element() {
+                    sync(&el);
+                }
+            },
+        );
+
+        if mode == PopoverMode::Auto {
+            let _ = on_click_outside_with_options(
+                target,
+                {
+                    let hide = hide.clone();
+
+                    move |_| {
+                        if !is_supported.get_untracked() {
+                            hide();
+                        }
+                    }
+                },
+                OnClickOutsideOptions::default()
+                    .ignore(ignore)
+                    .detect_escape(true),
+            );
+        }
+    }
+
+    UsePopoverReturn {
+        is_supported,
+        is_open: is_open.into(),
+        show,
+        hide,
+        toggle,
+    }
+}
+
+fn is_popover_supported() -> bool {
+    #[cfg(feature = "ssr")]
+    {
+        false
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::js;
+
+        let window = window();
+
+        js!(window["HTMLElement"])
+            .and_then(|element| js_sys::Reflect::get(&element, &"prototype".into()))
+            .map(|prototype| js!("showPopover" in &prototype))
+            .unwrap_or_default()
+    }
+}
+
+/// Options for [`use_popover_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UsePopoverOptions {
+    /// Value of the `popover` attribute that is set if the element doesn't have one already.
+    /// `Auto` popovers can be light-dismissed. Defaults to `PopoverMode::Auto`.
+    mode: PopoverMode,
+
+    /// Elements that don't hide the popover when clicked in fallback mode. Defaults to `[]`.
+    #[builder(skip)]
+    ignore: ElementsMaybeSignal<web_sys::EventTarget>,
+
+    /// Called with the new state whenever the popover is shown or hidden.
+    on_toggle: Arc<dyn Fn(bool) + Send + Sync>,
+}
+
+impl Default for UsePopoverOptions {
+    fn default() -> Self {
+        Self {
+            mode: PopoverMode::Auto,
+            ignore: Vec::<web_sys::EventTarget>::new().into_elements_maybe_signal(),
+            on_toggle: Arc::new(|_| {}),
+        }
+    }
+}
+
+impl UsePopoverOptions {
+    /// Elements that don't hide the popover when clicked in fallback mode. Defaults to `[]`.
+    pub fn ignore<M: ?Sized>(
+        self,
+        ignore: impl IntoElementsMaybeSignal<web_sys::EventTarget, M>,
+    ) -> Self {
+        Self {
+            ignore: ignore.into_elements_maybe_signal(),
+            ..self
+        }
+    }
+}
+
+/// Mode of the popover. See [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/popover).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum PopoverMode {
+    /// Can be light-dismissed and only one `auto` popover can be shown at a time
+    #[default]
+    Auto,
+    /// Has to be hidden explicitly
+    Manual,
+}
+
+impl Display for PopoverMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Manual => write!(f, "manual"),
+        }
+    }
+}
+
+/// Return type of [`use_popover`].
+pub struct UsePopoverReturn<ShowFn, HideFn, ToggleFn>
+where
+    ShowFn: Fn() + Clone + Send + Sync,
+    HideFn: Fn() + Clone + Send + Sync,
+    ToggleFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the Popover API is supported. If not, the fallback is used.
+    pub is_supported: Signal<bool>,
+
+    /// `true` while the popover is shown
+    pub is_open: Signal<bool>,
+
+    /// Show the popover
+    pub show: ShowFn,
+
+    /// Hide the popover
+    pub hide: HideFn,
+
+    /// Toggle the popover
+    pub toggle: ToggleFn,
+}
@@ -0,0 +1,269 @@
+#![cfg_attr(feature = "ssr", allow(unused_imports))]
+
+use crate::storage::{use_storage_with_options, StorageType, UseStorageOptions};
+use crate::utils::get_header;
+use crate::{use_locales_with_options, UseLocalesOptions};
+use codee::string::{FromToStringCodec, OptionCodec};
+use default_struct_builder::DefaultBuilder;
+use leptos::{logging::warn, prelude::*};
+use std::sync::Arc;
+use unic_langid::LanguageIdentifier;
+
+/// Reactive, parsed and ranked list of the user's preferred languages.
+///
+/// Builds on [`fn@crate::use_locales`] and parses every language tag into a [`PreferredLanguage`]
+/// with its language, script and region subtags. The list is updated when the browser fires the
+/// [`languagechange`](https://developer.mozilla.org/en-US/docs/Web/API/Window/languagechange_event) event.
+///
+/// The user can override the browser languages, e.g. with a language picker in your app.
+/// The override is persisted to storage and the overriding language is ranked first.
+/// The browser languages follow as fallbacks.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_preferred_languages)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_preferred_languages, UsePreferredLanguagesReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UsePreferredLanguagesReturn {
+///     languages,
+///     primary,
+///     override_language,
+///     set_override_language,
+/// } = use_preferred_languages();
+///
+/// // e.g. "de" for a browser with languages ["de-AT", "en-US"]
+/// let language = move || primary.get().map(|primary| primary.language).unwrap_or_default();
+///
+/// view! {
+///     <p>{language}</p>
+///     <button on:click=move |_| set_override_language.set(Some("fr-CA".to_string()))>
+///         "Français"
+///     </button>
+///     <button on:click=move |_| set_override_language.set(None)>"Browser default"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## Storage
+///
+/// By default the override is persisted in the local storage under the key
+/// `"leptos-use-preferred-language"`. See [`UsePreferredLanguagesOptions`] for how to change the
+/// key, the storage type or to disable persisting.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the languages are parsed from the `accept-language` header ranked by their quality values.
+///
+/// > If you're using `axum` you have to enable the `"axum"` feature in your Cargo.toml.
+/// > In case it's `actix-web` enable the feature `"actix"`, for `spin` enable `"spin"`.
+///
+/// The override stored in the browser's storage is not available on the server. So it's only applied
+/// after hydration.
+///
+/// ### Bring your own header
+///
+/// In case you're neither using Axum, Actix nor Spin, or the default implementation is not to your liking,
+/// you can provide your own way of reading the language header value using the option
+/// [`crate::UsePreferredLanguagesOptions::ssr_lang_header_getter`].
+pub fn use_preferred_languages() -> UsePreferredLanguagesReturn {
+    use_preferred_languages_with_options(UsePreferredLanguagesOptions::default())
+}
+
+/// Version of [`fn@crate::use_preferred_languages`] that takes a `UsePreferredLanguagesOptions`. See [`fn@crate::use_preferred_languages`] for how to use.
+pub fn use_preferred_languages_with_options(
+    options: UsePreferredLanguagesOptions,
+) -> UsePreferredLanguagesReturn {
+    let UsePreferredLanguagesOptions {
+        storage_key,
+        storage,
+        storage_enabled,
+        listen_to_storage_changes,
+        ssr_lang_header_getter,
+    } = options;
+
+    #[cfg(not(feature = "ssr"))]
+    let browser_languages = {
+        let _ = ssr_lang_header_getter;
+
+        use_locales_with_options(UseLocalesOptions::default())
+    };
+
+    #[cfg(feature = "ssr")]
+    let browser_languages = {
+        let accept_language = ssr_lang_header_getter().unwrap_or_default();
+
+        let mut ranked = accept_language
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';');
+                let tag = parts.next()?.trim().to_string();
+
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .and_then(|quality| quality.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+
+                (!tag.is_empty() && tag != "*").then_some((tag, quality))
+            })
+            .collect::<Vec<_>>();
+
+        // stable sort keeps the header order for equal quality values
+        ranked.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        let locales = ranked.into_iter().map(|(tag, _)| tag).collect::<Vec<_>>();
+
+        Signal::derive(move || locales.clone())
+    };
+
+    let (override_language, set_override_language) = if storage_enabled {
+        let (override_language, set_override_language, _) =
+            use_storage_with_options::<Option<String>, OptionCodec<FromToStringCodec>>(
+                storage,
+                storage_key,
+                UseStorageOptions::default().listen_to_storage_changes(listen_to_storage_changes),
+            );
+
+        (override_language, set_override_language)
+    } else {
+        let (override_language, set_override_language) = signal(None);
+
+        (override_language.into(), set_override_language)
+    };
+
+    let languages = Memo::new(move |_| {
+        let override_language = override_language.get();
+        let browser_languages = browser_languages.get();
+
+        let tags = override_language.iter().chain(
+            browser_languages
+                .iter()
+                .filter(|tag| Some(*tag) != override_language.as_ref()),
+        );
+
+        let mut languages = Vec::<PreferredLanguage>::new();
+
+        for tag in tags {
+            match PreferredLanguage::parse(tag, languages.len()) {
+                Some(language) => languages.push(language),
+                None => warn!("Received an invalid language tag \"{tag}\""),
+            }
+        }
+
+        languages
+    });
+
+    let primary = Signal::derive(move || languages.with(|languages| languages.first().cloned()));
+
+    UsePreferredLanguagesReturn {
+        languages: languages.into(),
+        primary,
+        override_language,
+        set_override_language,
+    }
+}
+
+/// A parsed language tag as returned by [`fn@crate::use_preferred_languages`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PreferredLanguage {
+    /// The language tag as provided by the browser or the override, e.g. `"en-US"`.
+    pub tag: String,
+
+    /// The language subtag, e.g. `"en"`.
+    pub language: String,
+
+    /// The script subtag, e.g. `"Latn"` for `"sr-Latn-RS"`.
+    pub script: Option<String>,
+
+    /// The region subtag, e.g. `"US"`.
+    pub region: Option<String>,
+
+    /// The position in the ranked list. `0` is the most preferred language.
+    pub rank: usize,
+}
+
+impl PreferredLanguage {
+    fn parse(tag: &str, rank: usize) -> Option<Self> {
+        let id = tag.parse::<LanguageIdentifier>().ok()?;
+
+        Some(Self {
+            tag: tag.to_string(),
+            language: id.language.to_string(),
+            script: id.script.map(|script| script.to_string()),
+            region: id.region.map(|region| region.to_string()),
+            rank,
+        })
+    }
+
+    /// Returns the parsed language identifier, e.g. to match it against the supported locales
+    /// of your app like [`fn@crate::use_locale`] does.
+    pub fn language_identifier(&self) -> LanguageIdentifier {
+        self.tag.parse().unwrap_or_default()
+    }
+}
+
+/// Options for [`fn@crate::use_preferred_languages_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePreferredLanguagesOptions {
+    /// Key to persist the override into localStorage/sessionStorage.
+    /// Defaults to `"leptos-use-preferred-language"`.
+    #[builder(into)]
+    storage_key: String,
+
+    /// Storage type, can be `Local` or `Session` or custom.
+    /// Defaults to `Local`.
+    storage: StorageType,
+
+    /// If the override should be persisted.
+    /// Defaults to `true`.
+    storage_enabled: bool,
+
+    /// Listen to changes to this storage key from somewhere else, e.g. other tabs.
+    /// Defaults to `true`.
+    listen_to_storage_changes: bool,
+
+    /// Getter function to return the string value of the accept language header.
+    /// When you use one of the features `"axum"`, `"actix"` or `"spin"` there's a valid default implementation provided.
+    #[allow(dead_code)]
+    ssr_lang_header_getter: Arc<dyn Fn() -> Option<String>>,
+}
+
+impl Default for UsePreferredLanguagesOptions {
+    fn default() -> Self {
+        Self {
+            storage_key: "leptos-use-preferred-language".into(),
+            storage: StorageType::default(),
+            storage_enabled: true,
+            listen_to_storage_changes: true,
+            ssr_lang_header_getter: Arc::new(move || {
+                get_header!(
+                    ACCEPT_LANGUAGE,
+                    use_preferred_languages,
+                    ssr_lang_header_getter
+                )
+            }),
+        }
+    }
+}
+
+/// Return type of [`fn@crate::use_preferred_languages`].
+pub struct UsePreferredLanguagesReturn {
+    /// The ranked list of preferred languages. The override, if set, comes first.
+    /// Invalid language tags are skipped.
+    pub languages: Signal<Vec<PreferredLanguage>>,
+
+    /// The most preferred language. Shortcut for the first element of `languages`.
+    pub primary: Signal<Option<PreferredLanguage>>,
+
+    /// The language tag that overrides the browser languages. `None` if the browser languages are used.
+    pub override_language: Signal<Option<String>>,
+
+    /// Set or clear (with `None`) the override. The value is persisted to storage if enabled.
+    pub set_override_language: WriteSignal<Option<String>>,
+}
@@ -0,0 +1,118 @@
+use crate::utils::get_header;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Reactive [reduced data preference](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-reduced-data).
+///
+/// Returns `true` if the user asked for sites to use less data, e.g. by enabling a data saver mode.
+/// As the `prefers-reduced-data` media query is not widely supported yet, this also checks
+/// [`navigator.connection.saveData`](https://developer.mozilla.org/en-US/docs/Web/API/NetworkInformation/saveData).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_preferred_reduced_data)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_preferred_reduced_data;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_reduced_data_preferred = use_preferred_reduced_data();
+///
+/// view! {
+///     <Show
+///         when=move || !is_reduced_data_preferred.get()
+///         fallback=|| view! { <img src="poster.jpg"/> }
+///     >
+///         <video src="background.mp4" autoplay muted loop/>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this will try to read the
+/// [`Save-Data` header](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Save-Data)
+/// which the browser sends when the user enabled a data saver mode.
+/// If the header is missing it returns `false`.
+///
+/// > If you're using `axum` you have to enable the `"axum"` feature in your Cargo.toml.
+/// > In case it's `actix-web` enable the feature `"actix"`, for `spin` enable `"spin"`.
+///
+/// ### Bring your own header
+///
+/// In case you're neither using Axum, Actix nor Spin, or the default implementation is not to your
+/// liking, you can provide your own way of reading the save data header value using the option
+/// [`crate::UsePreferredReducedDataOptions::ssr_save_data_header_getter`].
+///
+/// ## See also
+///
+/// * [`fn@crate::use_media_query`]
+/// * [`fn@crate::use_preferred_contrast`]
+/// * [`fn@crate::use_preferred_dark`]
+/// * [`fn@crate::use_prefers_reduced_motion`]
+pub fn use_preferred_reduced_data() -> Signal<bool> {
+    use_preferred_reduced_data_with_options(UsePreferredReducedDataOptions::default())
+}
+
+/// Version of [`fn@crate::use_preferred_reduced_data`] that takes a `UsePreferredReducedDataOptions`. See [`fn@crate::use_preferred_reduced_data`] for how to use.
+pub fn use_preferred_reduced_data_with_options(
+    options: UsePreferredReducedDataOptions,
+) -> Signal<bool> {
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::js;
+
+        let _ = options;
+
+        let is_reduced = crate::use_media_query("(prefers-reduced-data: reduce)");
+
+        let navigator = window().navigator();
+        let save_data = js!(navigator["connection"])
+            .ok()
+            .filter(|connection| connection.is_object())
+            .and_then(|connection| js!(connection["saveData"]).ok())
+            .and_then(|save_data| save_data.as_bool())
+            .unwrap_or_default();
+
+        Signal::derive(move || save_data || is_reduced.get())
+    }
+    #[cfg(feature = "ssr")]
+    {
+        Signal::derive(move || {
+            (options.ssr_save_data_header_getter)()
+                .is_some_and(|save_data| save_data.trim().eq_ignore_ascii_case("on"))
+        })
+    }
+}
+
+/// Options for [`fn@crate::use_preferred_reduced_data_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePreferredReducedDataOptions {
+    /// Getter function to return the string value of the
+    /// [`Save-Data`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Save-Data)
+    /// header.
+    /// When you use one of the features `"axum"`, `"actix"` or `"spin"` there's a valid default
+    /// implementation provided.
+    #[allow(dead_code)]
+    pub(crate) ssr_save_data_header_getter: Arc<dyn Fn() -> Option<String> + Send + Sync>,
+}
+
+impl Default for UsePreferredReducedDataOptions {
+    fn default() -> Self {
+        Self {
+            ssr_save_data_header_getter: Arc::new(move || {
+                get_header!(
+                    HeaderName::from_static("save-data"),
+                    use_preferred_reduced_data,
+                    ssr_save_data_header_getter
+                )
+            }),
+        }
+    }
+}
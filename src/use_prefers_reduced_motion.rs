@@ -57,6 +57,7 @@ use std::sync::Arc;
 /// * [`fn@crate::use_media_query`]
 /// * [`fn@crate::use_preferred_contrast`]
 /// * [`fn@crate::use_preferred_dark`]
+/// * [`fn@crate::use_preferred_reduced_data`]
 pub fn use_prefers_reduced_motion() -> Signal<bool> {
     use_prefers_reduced_motion_with_options(UsePrefersReducedMotionOptions::default())
 }
@@ -0,0 +1,530 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::future::Future;
+
+/// Prefetch resources as soon as the user shows the intent to use them.
+///
+/// The `loader` is called when the `target` element is hovered, focused or touched, or when it
+/// scrolls into view. This is a well known technique to make navigation feel instant: the time
+/// between hovering a link and clicking it is usually long enough to fetch most of the data.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_prefetch)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::A;
+/// # use leptos_use::{use_prefetch, UsePrefetchReturn};
+/// #
+/// # async fn fetch_user_cache() {}
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<A>::new();
+///
+/// let UsePrefetchReturn { is_prefetched, .. } = use_prefetch(el, || async {
+///     // warm up your cache here
+///     fetch_user_cache().await;
+/// });
+///
+/// view! {
+///     <a node_ref=el href="/users">"Users"</a>
+/// }
+/// # }
+/// ```
+///
+/// The loader is called at most once. You can also start it yourself with the returned `prefetch`.
+///
+/// ## Links
+///
+/// With [`use_prefetch_link`] no loader is needed. Instead a
+/// `<link rel="prefetch">` or `<link rel="preload">` is inserted into the `<head>` and the browser
+/// takes care of the rest. The same `href` is only ever inserted once per page.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::{A, Button};
+/// # use leptos_use::{use_prefetch_link, PrefetchLink};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let link = NodeRef::<A>::new();
+/// let button = NodeRef::<Button>::new();
+///
+/// use_prefetch_link(link, PrefetchLink::prefetch("/about"));
+/// use_prefetch_link(button, PrefetchLink::preload("/editor.wasm", "fetch"));
+///
+/// view! {
+///     <a node_ref=link href="/about">"About"</a>
+///     <button node_ref=button>"Open editor"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## Triggers
+///
+/// By default prefetching starts when the element is hovered for `hover_delay` milliseconds,
+/// focused or touched. If the option `on_visible` is `true` it also starts when the element enters
+/// the viewport (extended by `root_margin`). This is useful on touch devices without hover.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::A;
+/// # use leptos_use::{use_prefetch_with_options, UsePrefetchOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<A>::new();
+///
+/// use_prefetch_with_options(
+///     el,
+///     || async { /* ... */ },
+///     UsePrefetchOptions::default()
+///         .on_hover(false)
+///         .on_visible(true)
+///         .root_margin("200px"),
+/// );
+/// #
+/// # view! { <a node_ref=el href="/"></a> }
+/// # }
+/// ```
+///
+/// ## Concurrency
+///
+/// Prefetches of all instances on the page share one queue so a long list of visible links
+/// doesn't flood the network. At most `max_concurrent` prefetches run at the same time; the
+/// rest wait in order.
+///
+/// ## Save Data
+///
+/// If the user asked for reduced data usage (see [`fn@crate::use_preferred_reduced_data`]) nothing is
+/// prefetched. Set `respect_save_data` to `false` to prefetch anyway.
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `prefetch` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_prefetch`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this amounts to a no-op.
+pub fn use_prefetch<El, M, F, Fut>(
+    target: El,
+    loader: F,
+) -> UsePrefetchReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    use_prefetch_with_options(target, loader, UsePrefetchOptions::default())
+}
+
+/// Version of [`use_prefetch`] that takes a `UsePrefetchOptions`. See [`use_prefetch`] for how to use.
+pub fn use_prefetch_with_options<El, M, F, Fut>(
+    target: El,
+    loader: F,
+    options: UsePrefetchOptions,
+) -> UsePrefetchReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    let UsePrefetchOptions {
+        on_hover,
+        hover_delay,
+        on_focus,
+        on_visible,
+        root_margin,
+        max_concurrent,
+        respect_save_data,
+    } = options;
+
+    let (is_prefetching, set_prefetching) = signal(false);
+    let (is_prefetched, set_prefetched) = signal(false);
+
+    let prefetch;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = target;
+        let _ = loader;
+        let _ = set_prefetching;
+        let _ = set_prefetched;
+
+        prefetch = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{
+            use_event_listener, use_event_listener_with_options,
+            use_intersection_observer_with_options, use_preferred_reduced_data,
+            UseEventListenerOptions, UseIntersectionObserverOptions,
+        };
+        use leptos::ev::{focusin, mouseenter, mouseleave, touchstart};
+        use std::cell::Cell;
+        use std::rc::Rc;
+        use std::time::Duration;
+
+        let is_data_saving = use_preferred_reduced_data();
+
+        let loader = Rc::new(loader);
+        let is_requested = Rc::new(Cell::new(false));
+
+        prefetch = sendwrap_fn!(move || {
+            if is_requested.get() || (respect_save_data && is_data_saving.get_untracked()) {
+                return;
+            }
+            is_requested.set(true);
+
+            let loader = Rc::clone(&loader);
+
+            queue::enqueue(
+                max_concurrent,
+                Box::pin(async move {
+                    set_prefetching.try_set(true);
+
+                    #[cfg(debug_assertions)]
+                    let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                    let future = loader();
+
+                    #[cfg(debug_assertions)]
+                    drop(zone);
+
+                    future.await;
+
+                    set_prefetching.try_set(false);
+                    set_prefetched.try_set(true);
+                }),
+            );
+        });
+
+        let target = target.into_element_maybe_signal();
+
+        if on_hover {
+            let timer = Rc::new(Cell::new(None::<TimeoutHandle>));
+
+            let _ = use_event_listener(target.clone(), mouseenter, {
+                let prefetch = prefetch.clone();
+                let timer = Rc::clone(&timer);
+
+                move |_| {
+                    if hover_delay == 0 {
+                        prefetch();
+                        return;
+                    }
+
+                    if let Some(handle) = timer.take() {
+                        handle.clear();
+                    }
+
+                    timer.set(
+                        set_timeout_with_handle(
+                            {
+                                let prefetch = prefetch.clone();
+                                move || prefetch()
+                            },
+                            Duration::from_millis(hover_delay),
+                        )
+                        .ok(),
+                    );
+                }
+            });
+
+            let _ = use_event_listener(target.clone(), mouseleave, move |_| {
+                if let Some(handle) = timer.take() {
+                    handle.clear();
+                }
+            });
+
+            // on touch devices there is no hover so the touch itself is the best hint available
+            let _ = use_event_listener_with_options(
+                target.clone(),
+                touchstart,
+                {
+                    let prefetch = prefetch.clone();
+                    move |_| prefetch()
+                },
+                UseEventListenerOptions::default().passive(true),
+            );
+        }
+
+        if on_focus {
+            let _ = use_event_listener(target.clone(), focusin, {
+                let prefetch = prefetch.clone();
+                move |_| prefetch()
+            });
+        }
+
+        if on_visible {
+            let _ = use_intersection_observer_with_options(
+                target,
+                {
+                    let prefetch = prefetch.clone();
+
+                    move |entries, _| {
+                        if entries.iter().any(|entry| entry.is_intersecting()) {
+                            prefetch();
+                        }
+                    }
+                },
+                UseIntersectionObserverOptions::default().root_margin(root_margin),
+            );
+        }
+    }
+
+    UsePrefetchReturn {
+        is_prefetching: is_prefetching.into(),
+        is_prefetched: is_prefetched.into(),
+        prefetch,
+    }
+}
+
+/// Version of [`use_prefetch`] that inserts a `<link>` into the document head instead of calling a loader.
+/// See [`use_prefetch`] for how to use.
+pub fn use_prefetch_link<El, M>(
+    target: El,
+    link: PrefetchLink,
+) -> UsePrefetchReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_prefetch_link_with_options(target, link, UsePrefetchOptions::default())
+}
+
+/// Version of [`use_prefetch_link`] that takes a `UsePrefetchOptions`. See [`use_prefetch`] for how to use.
+pub fn use_prefetch_link_with_options<El, M>(
+    target: El,
+    link: PrefetchLink,
+    options: UsePrefetchOptions,
+) -> UsePrefetchReturn<impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_prefetch_with_options(
+        target,
+        move || {
+            let link = link.clone();
+
+            async move {
+                #[cfg(not(feature = "ssr"))]
+                link.insert().await;
+            }
+        },
+        options,
+    )
+}
+
+/// Describes the `<link>` inserted by [`use_prefetch_link`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrefetchLink {
+    /// URL of the resource
+    pub href: String,
+
+    /// `rel` attribute of the link
+    pub rel: PrefetchRel,
+
+    /// `as` attribute of the link. Required for preloads.
+    pub as_: Option<String>,
+}
+
+impl PrefetchLink {
+    /// Low priority fetch of a resource that is probably needed for a future navigation.
+    pub fn prefetch(href: impl Into<String>) -> Self {
+        Self {
+            href: href.into(),
+            rel: PrefetchRel::Prefetch,
+            as_: None,
+        }
+    }
+
+    /// High priority fetch of a resource that is needed on the current page.
+    /// `as_` is the kind of the resource like `"script"`, `"style"`, `"font"` or `"fetch"`.
+    pub fn preload(href: impl Into<String>, as_: impl Into<String>) -> Self {
+        Self {
+            href: href.into(),
+            rel: PrefetchRel::Preload,
+            as_: Some(as_.into()),
+        }
+    }
+
+    /// Inserts the link into the head and waits until it finished loading or failed.
+    #[cfg(not(feature = "ssr"))]
+    async fn insert(&self) {
+        use wasm_bindgen::JsCast;
+
+        let key = format!("{}|{}", self.rel, self.href);
+        if !queue::mark_inserted(key) {
+            return;
+        }
+
+        let Some(head) = document().head() else {
+            return;
+        };
+        let Ok(link) = document().create_element("link") else {
+            return;
+        };
+        let link: web_sys::HtmlLinkElement = link.unchecked_into();
+
+        link.set_rel(&self.rel.to_string());
+        link.set_href(&self.href);
+        if let Some(as_) = &self.as_ {
+            link.set_as(as_);
+        }
+
+        let loaded = js_sys::Promise::new(&mut |resolve, _| {
+            link.set_onload(Some(&resolve));
+            link.set_onerror(Some(&resolve));
+        });
+
+        if head.append_child(&link).is_ok() {
+            let _ = crate::js_fut!(loaded).await;
+        }
+
+        link.set_onload(None);
+        link.set_onerror(None);
+    }
+}
+
+/// Value of the `rel` attribute of a [`PrefetchLink`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PrefetchRel {
+    /// `rel="prefetch"`
+    #[default]
+    Prefetch,
+
+    /// `rel="preload"`
+    Preload,
+}
+
+impl std::fmt::Display for PrefetchRel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrefetchRel::Prefetch => write!(f, "prefetch"),
+            PrefetchRel::Preload => write!(f, "preload"),
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+mod queue {
+    use std::cell::RefCell;
+    use std::collections::{HashSet, VecDeque};
+    use std::future::Future;
+    use std::pin::Pin;
+
+    pub(super) type Task = Pin<Box<dyn Future<Output = ()>>>;
+
+    #[derive(Default)]
+    struct Queue {
+        running: usize,
+        pending: VecDeque<(usize, Task)>,
+        inserted_links: HashSet<String>,
+    }
+
+    thread_local! {
+        static QUEUE: RefCell<Queue> = RefCell::new(Queue::default());
+    }
+
+    /// Runs `task` as soon as less than `max_concurrent` tasks are running.
+    pub(super) fn enqueue(max_concurrent: usize, task: Task) {
+        QUEUE.with_borrow_mut(|queue| queue.pending.push_back((max_concurrent.max(1), task)));
+        run_pending();
+    }
+
+    /// Returns `false` if a link with this key has been inserted before.
+    pub(super) fn mark_inserted(key: String) -> bool {
+        QUEUE.with_borrow_mut(|queue| queue.inserted_links.insert(key))
+    }
+
+    fn run_pending() {
+        loop {
+            let task = QUEUE.with_borrow_mut(|queue| match queue.pending.front() {
+                Some((max_concurrent, _)) if queue.running < *max_concurrent => {
+                    queue.running += 1;
+                    queue.pending.pop_front().map(|(_, task)| task)
+                }
+                _ => None,
+            });
+
+            let Some(task) = task else {
+                break;
+            };
+
+            leptos::task::spawn_local(async move {
+                task.await;
+
+                QUEUE.with_borrow_mut(|queue| queue.running -= 1);
+                run_pending();
+            });
+        }
+    }
+}
+
+/// Options for [`use_prefetch_with_options`].
+#[derive(DefaultBuilder, Clone)]
+pub struct UsePrefetchOptions {
+    /// Prefetch when the element is hovered or touched. Defaults to `true`.
+    on_hover: bool,
+
+    /// Milliseconds the pointer has to stay on the element before prefetching starts.
+    /// This filters out the pointer just passing by. Defaults to `65`.
+    hover_delay: u64,
+
+    /// Prefetch when the element or one of its descendants receives the focus. Defaults to `true`.
+    on_focus: bool,
+
+    /// Prefetch when the element becomes visible in the viewport. Defaults to `false`.
+    on_visible: bool,
+
+    /// Margin around the viewport used for `on_visible`. Uses the syntax of the CSS `margin`
+    /// property. Defaults to `"0px"`.
+    #[builder(into)]
+    root_margin: Signal<String>,
+
+    /// Maximum number of prefetches that run at the same time across the page. Defaults to `2`.
+    max_concurrent: usize,
+
+    /// Don't prefetch if the user asked for reduced data usage. Defaults to `true`.
+    respect_save_data: bool,
+}
+
+impl Default for UsePrefetchOptions {
+    fn default() -> Self {
+        Self {
+            on_hover: true,
+            hover_delay: 65,
+            on_focus: true,
+            on_visible: false,
+            root_margin: "0px".to_string().into(),
+            max_concurrent: 2,
+            respect_save_data: true,
+        }
+    }
+}
+
+/// Return type of [`use_prefetch`].
+pub struct UsePrefetchReturn<PrefetchFn>
+where
+    PrefetchFn: Fn() + Clone + Send + Sync,
+{
+    /// `true` while the loader is running
+    pub is_prefetching: Signal<bool>,
+
+    /// `true` after the loader has finished
+    pub is_prefetched: Signal<bool>,
+
+    /// Starts prefetching right away unless it has been started before
+    pub prefetch: PrefetchFn,
+}
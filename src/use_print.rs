@@ -0,0 +1,273 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::{ElementMaybeSignal, IntoElementMaybeSignal};
+use crate::{sendwrap_fn, use_media_query};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive print lifecycle.
+///
+/// `is_printing` is `true` while the page is printed or shown in the print preview. It's driven by the
+/// [`beforeprint`](https://developer.mozilla.org/en-US/docs/Web/API/Window/beforeprint_event) and
+/// [`afterprint`](https://developer.mozilla.org/en-US/docs/Web/API/Window/afterprint_event) events as well as
+/// the `print` media query. The returned `print` opens the print dialog.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_print)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_print, UsePrintReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UsePrintReturn { is_printing, print } = use_print();
+///
+/// view! {
+///     <Show when=move || !is_printing.get()>
+///         <nav>"Hidden when printed"</nav>
+///     </Show>
+///     <button on:click=move |_| print()>"Print"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## Print a Single Element
+///
+/// With the option `target` only the given element is printed. Its content is cloned into a hidden
+/// `iframe` together with the stylesheets of the page. Current values of form fields and the content
+/// of canvases aren't part of the clone.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_print_with_options, UsePrintOptions, UsePrintReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let invoice = NodeRef::<Div>::new();
+///
+/// let UsePrintReturn { print, .. } =
+///     use_print_with_options(UsePrintOptions::default().target(invoice));
+///
+/// view! {
+///     <div node_ref=invoice>"Invoice #42"</div>
+///     <button on:click=move |_| print()>"Print invoice"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `print` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_print`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_printing` is always `false` and `print` does nothing.
+pub fn use_print() -> UsePrintReturn<impl Fn() + Clone + Send + Sync> {
+    use_print_with_options(UsePrintOptions::default())
+}
+
+/// Version of [`use_print`] that takes a `UsePrintOptions`. See [`use_print`] for how to use.
+pub fn use_print_with_options(
+    options: UsePrintOptions,
+) -> UsePrintReturn<impl Fn() + Clone + Send + Sync> {
+    let UsePrintOptions {
+        target,
+        copy_styles,
+    } = options;
+
+    let (is_print_event, set_is_print_event) = signal(false);
+    let is_print_media = use_media_query("print");
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::Custom;
+
+        let _ = use_event_listener(
+            window(),
+            Custom::<web_sys::Event>::new("beforeprint"),
+            move |_| set_is_print_event.set(true),
+        );
+
+        let _ = use_event_listener(
+            window(),
+            Custom::<web_sys::Event>::new("afterprint"),
+            move |_| set_is_print_event.set(false),
+        );
+    }
+
+    let print = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        match target.get_untracked() {
+            Some(element) => print_element(&element, copy_styles, set_is_print_event),
+            None => {
+                let _ = window().print();
+            }
+        }
+    });
+
+    UsePrintReturn {
+        is_printing: Signal::derive(move || is_print_event.get() || is_print_media.get()),
+        print,
+    }
+}
+
+/// Clones `element` into a hidden `iframe` and prints that. The frame removes itself after printing.
+#[cfg(not(feature = "ssr"))]
+fn print_element(
+    element: &web_sys::Element,
+    copy_styles: bool,
+    set_is_printing: WriteSignal<bool>,
+) {
+    use leptos::wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    let document = document();
+
+    let Some(body) = document.body() else {
+        return;
+    };
+
+    let Ok(frame) = document
+        .create_element("iframe")
+        .map(|frame| frame.unchecked_into::<web_sys::HtmlIFrameElement>())
+    else {
+        return;
+    };
+
+    let _ = frame.set_attribute("aria-hidden", "true");
+    let _ = frame.set_attribute(
+        "style",
+        "position: fixed; right: 0; bottom: 0; width: 0; height: 0; border: 0;",
+    );
+
+    if body.append_child(&frame).is_err() {
+        return;
+    }
+
+    let (Some(frame_window), Some(frame_document)) =
+        (frame.content_window(), frame.content_document())
+    else {
+        frame.remove();
+        return;
+    };
+
+    frame_document.set_title(&document.title());
+
+    let stylesheets_loaded = js_sys::Array::new();
+
+    if copy_styles {
+        if let (Some(frame_head), Ok(styles)) = (
+            frame_document.head(),
+            document.query_selector_all("style, link[rel='stylesheet']"),
+        ) {
+            for index in 0..styles.length() {
+                let Some(style) = styles.item(index) else {
+                    continue;
+                };
+                let Ok(style) = frame_document.import_node_with_deep(&style, true) else {
+                    continue;
+                };
+
+                if let Some(link) = style.dyn_ref::<web_sys::HtmlElement>() {
+                    if link.tag_name().eq_ignore_ascii_case("link") {
+                        // print only once the stylesheet is there, even if it failed to load
+                        stylesheets_loaded.push(&js_sys::Promise::new(&mut |resolve, _| {
+                            link.set_onload(Some(&resolve));
+                            link.set_onerror(Some(&resolve));
+                        }));
+                    }
+                }
+
+                let _ = frame_head.append_child(&style);
+            }
+        }
+    }
+
+    if let (Some(frame_body), Ok(content)) = (
+        frame_document.body(),
+        frame_document.import_node_with_deep(element, true),
+    ) {
+        let _ = frame_body.append_child(&content);
+    }
+
+    leptos::task::spawn_local(async move {
+        let _ = crate::js_fut!(js_sys::Promise::all(&stylesheets_loaded)).await;
+
+        let on_after_print = Closure::once_into_js({
+            let frame = frame.clone();
+
+            move || {
+                set_is_printing.try_set(false);
+                frame.remove();
+            }
+        });
+
+        let listener_options = web_sys::AddEventListenerOptions::new();
+        listener_options.set_once(true);
+
+        let _ = frame_window.add_event_listener_with_callback_and_add_event_listener_options(
+            "afterprint",
+            on_after_print.unchecked_ref(),
+            &listener_options,
+        );
+
+        set_is_printing.try_set(true);
+
+        if frame_window.print().is_err() {
+            set_is_printing.try_set(false);
+            frame.remove();
+        }
+    });
+}
+
+/// Options for [`use_print_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UsePrintOptions {
+    /// If set only this element is printed instead of the whole page. Defaults to `None`.
+    #[builder(skip)]
+    target: ElementMaybeSignal<web_sys::Element>,
+
+    /// Copy the `<style>` and `<link rel="stylesheet">` elements of the page into the print frame when
+    /// printing a `target`. Defaults to `true`.
+    copy_styles: bool,
+}
+
+impl UsePrintOptions {
+    /// If set only this element is printed instead of the whole page. Defaults to `None`.
+    pub fn target<M>(self, target: impl IntoElementMaybeSignal<web_sys::Element, M>) -> Self {
+        Self {
+            target: target.into_element_maybe_signal(),
+            ..self
+        }
+    }
+}
+
+impl Default for UsePrintOptions {
+    fn default() -> Self {
+        Self {
+            target: Default::default(),
+            copy_styles: true,
+        }
+    }
+}
+
+/// Return type of [`use_print`].
+pub struct UsePrintReturn<PrintFn>
+where
+    PrintFn: Fn() + Clone + Send + Sync,
+{
+    /// `true` while the page is being printed or shown in the print preview
+    pub is_printing: Signal<bool>,
+
+    /// Opens the print dialog for the page or the `target` element
+    pub print: PrintFn,
+}
@@ -6,6 +6,13 @@ use leptos::prelude::*;
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
+/// Frames arriving up to this many milliseconds early still count for the `fps_limit`
+/// as the timestamps of animation frames jitter a bit.
+const FPS_LIMIT_TOLERANCE: f64 = 1.0;
+
+/// Upper bound of steps per frame with `fixed_timestep` so a slow callback can't stall the page.
+const MAX_FIXED_STEPS_PER_FRAME: u32 = 10;
+
 /// Call function on every requestAnimationFrame.
 /// With controls of pausing and resuming.
 ///
@@ -35,10 +42,79 @@ use std::rc::Rc;
 /// You can use `use_raf_fn_with_options` and set `immediate` to `false`. In that case
 /// you have to call `resume()` before the `callback` is executed.
 ///
+/// ## Timing
+///
+/// The callback receives a [`UseRafFnCallbackArgs`] with the `delta` time since the last call, the
+/// `elapsed` time that the loop has been active for and the `timestamp` of the frame.
+/// All of them are in milliseconds. Time spent paused counts neither towards `delta` nor `elapsed`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_raf_fn, UseRafFnCallbackArgs};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (x, set_x) = signal(0.0);
+///
+/// // move by 100 pixels per second regardless of the frame rate
+/// use_raf_fn(move |UseRafFnCallbackArgs { delta, .. }| {
+///     set_x.update(|x| *x += delta / 10.0);
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## FPS Limit
+///
+/// With the option `fps_limit` frames are skipped so that the callback is called at most that many
+/// times per second.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_raf_fn_with_options, UseRafFnOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// use_raf_fn_with_options(
+///     move |_| { /* ... */ },
+///     UseRafFnOptions::default().fps_limit(30.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Fixed Timestep
+///
+/// Physics simulations and games often need to be advanced in steps of always the same length to be
+/// deterministic. Set the option `fixed_timestep` to the length of a step in milliseconds. The time
+/// of the frames is then accumulated and the callback is called as many times as whole steps fit in,
+/// each time with `delta` equal to the step. This can be zero times in one frame and several in the next.
+/// To prevent the loop from falling further and further behind when a step takes longer than
+/// the step itself, at most 10 steps are run per frame and the remaining time is dropped.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_raf_fn_with_options, UseRafFnCallbackArgs, UseRafFnOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// use_raf_fn_with_options(
+///     move |UseRafFnCallbackArgs { delta, .. }| {
+///         // `delta` is always 1000.0 / 120.0
+///     },
+///     UseRafFnOptions::default().fixed_timestep(1000.0 / 120.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `pause` and `resume` are sendwrapped functions. They can
-/// only be called from the same thread that called `use_interval_fn`.
+/// only be called from the same thread that called `use_raf_fn`.
 ///
 /// ## Server-Side Rendering
 ///
@@ -54,7 +130,11 @@ pub fn use_raf_fn_with_options(
     callback: impl Fn(UseRafFnCallbackArgs) + 'static,
     options: UseRafFnOptions,
 ) -> Pausable<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
-    let UseRafFnOptions { immediate } = options;
+    let UseRafFnOptions {
+        immediate,
+        fps_limit,
+        fixed_timestep,
+    } = options;
 
     let raf_handle = Rc::new(Cell::new(None::<i32>));
 
@@ -90,10 +170,16 @@ pub fn use_raf_fn_with_options(
         }}
     };
 
+    // reset on resume so that the time spent paused isn't part of the next `delta`
+    let previous_frame_timestamp = Rc::new(Cell::new(0.0_f64));
+    let accumulated_time = Rc::new(Cell::new(0.0_f64));
+
     let loop_fn = {
         #[allow(clippy::clone_on_copy)]
         let request_next_frame = request_next_frame.clone();
-        let previous_frame_timestamp = Cell::new(0.0_f64);
+        let previous_frame_timestamp = Rc::clone(&previous_frame_timestamp);
+        let accumulated_time = Rc::clone(&accumulated_time);
+        let elapsed = Cell::new(0.0_f64);
 
         move |timestamp: f64| {
             if !is_active.try_get_untracked().unwrap_or_default() {
@@ -107,16 +193,53 @@ pub fn use_raf_fn_with_options(
                 0.0
             };
 
+            if let Some(fps_limit) = fps_limit {
+                if prev_timestamp > 0.0 && delta < 1000.0 / fps_limit - FPS_LIMIT_TOLERANCE {
+                    request_next_frame();
+                    return;
+                }
+            }
+
+            previous_frame_timestamp.set(timestamp);
+
             #[cfg(debug_assertions)]
             let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
-            callback(UseRafFnCallbackArgs { delta, timestamp });
+            if let Some(step) = fixed_timestep.filter(|step| *step > 0.0) {
+                let mut accumulated = accumulated_time.get() + delta;
+                let mut steps = 0;
+
+                while accumulated >= step && steps < MAX_FIXED_STEPS_PER_FRAME {
+                    accumulated -= step;
+                    steps += 1;
+                    elapsed.set(elapsed.get() + step);
+
+                    callback(UseRafFnCallbackArgs {
+                        delta: step,
+                        elapsed: elapsed.get(),
+                        timestamp,
+                    });
+                }
+
+                // drop what couldn't be caught up with
+                if steps == MAX_FIXED_STEPS_PER_FRAME {
+                    accumulated %= step;
+                }
+
+                accumulated_time.set(accumulated);
+            } else {
+                elapsed.set(elapsed.get() + delta);
+
+                callback(UseRafFnCallbackArgs {
+                    delta,
+                    elapsed: elapsed.get(),
+                    timestamp,
+                });
+            }
 
             #[cfg(debug_assertions)]
             drop(zone);
 
-            previous_frame_timestamp.set(timestamp);
-
             request_next_frame();
         }
     };
@@ -125,6 +248,9 @@ pub fn use_raf_fn_with_options(
 
     let resume = sendwrap_fn!(move || {
         if !is_active.get_untracked() {
+            previous_frame_timestamp.set(0.0);
+            accumulated_time.set(0.0);
+
             set_active.set(true);
             request_next_frame();
         }
@@ -163,19 +289,39 @@ pub struct UseRafFnOptions {
     /// Start the requestAnimationFrame loop immediately on creation. Defaults to `true`.
     /// If false, the loop will only start when you call `resume()`.
     immediate: bool,
+
+    /// Maximum number of times per second the callback is called. Defaults to `None` which calls it
+    /// in every frame.
+    #[builder(into)]
+    fps_limit: Option<f64>,
+
+    /// Length of a step in milliseconds. If set, the callback is called once per elapsed step
+    /// instead of once per frame. Defaults to `None`.
+    #[builder(into)]
+    fixed_timestep: Option<f64>,
 }
 
 impl Default for UseRafFnOptions {
     fn default() -> Self {
-        Self { immediate: true }
+        Self {
+            immediate: true,
+            fps_limit: None,
+            fixed_timestep: None,
+        }
     }
 }
 
 /// Type of the argument for the callback of [`use_raf_fn`].
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct UseRafFnCallbackArgs {
-    /// Time elapsed between this and the last frame.
+    /// Time elapsed between this and the last call in milliseconds.
+    /// This is `0.0` for the first frame after the loop is started or resumed.
+    /// When `fixed_timestep` is set this is always the length of a step.
     pub delta: f64,
 
+    /// Total time in milliseconds the loop has been active for.
+    pub elapsed: f64,
+
     /// Time elapsed since the creation of the web page. See [MDN Docs](https://developer.mozilla.org/en-US/docs/Web/API/DOMHighResTimeStamp#the_time_origin) Time origin.
     pub timestamp: f64,
 }
@@ -0,0 +1,421 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, sendwrap_fn, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+/// Reactive [ReportingObserver](https://developer.mozilla.org/en-US/docs/Web/API/ReportingObserver)
+/// and [Content Security Policy violations](https://developer.mozilla.org/en-US/docs/Web/API/SecurityPolicyViolationEvent).
+///
+/// Collects deprecation and intervention reports as well as `securitypolicyviolation` events in reactive lists.
+/// Use the callbacks to ship them as telemetry from production apps.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_reporting_observer)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_reporting_observer, UseReportingObserverReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseReportingObserverReturn { reports, csp_violations, .. } = use_reporting_observer();
+///
+/// view! {
+///     <p>{move || reports.get().len()} " reports"</p>
+///     <p>{move || csp_violations.get().len()} " CSP violations"</p>
+/// }
+/// # }
+/// ```
+///
+/// ## Callbacks
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_reporting_observer_with_options, ReportType, UseReportingObserverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// use_reporting_observer_with_options(
+///     UseReportingObserverOptions::default()
+///         .types(vec![ReportType::Deprecation])
+///         .on_report(|report| leptos::logging::warn!("{:?}", report.message))
+///         .on_csp_violation(|violation| {
+///             leptos::logging::warn!("blocked {}", violation.blocked_uri)
+///         }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// By default reports that have been generated before the observer was created are delivered as well.
+/// This can be turned off with the option `buffered`.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `stop` and `clear` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_reporting_observer`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server nothing is observed and the lists are always empty.
+pub fn use_reporting_observer(
+) -> UseReportingObserverReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_reporting_observer_with_options(UseReportingObserverOptions::default())
+}
+
+/// Version of [`use_reporting_observer`] that takes a `UseReportingObserverOptions`. See [`use_reporting_observer`] for how to use.
+pub fn use_reporting_observer_with_options(
+    options: UseReportingObserverOptions,
+) -> UseReportingObserverReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseReportingObserverOptions {
+        types,
+        buffered,
+        csp_violations: observe_csp_violations,
+        max_entries,
+        on_report,
+        on_csp_violation,
+    } = options;
+
+    let is_supported = use_supported(|| js!("ReportingObserver" in &window()));
+
+    let (reports, set_reports) = signal(Vec::<Report>::new());
+    let (csp_violations, set_csp_violations) = signal(Vec::<CspViolation>::new());
+    let (observer, set_observer) = signal_local(None::<js_sys::Object>);
+
+    let stop = sendwrap_fn!(move || {
+        if let Some(observer) = observer.get_untracked() {
+            if let Ok(disconnect) = js!(observer["disconnect"]) {
+                let _ = js_sys::Function::from(disconnect).call0(&observer);
+            }
+            set_observer.set(None);
+        }
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::Custom;
+        use wasm_bindgen::prelude::*;
+        use wasm_bindgen::JsCast;
+
+        if is_supported.get_untracked() {
+            let callback = Closure::<dyn Fn(js_sys::Array)>::new(move |records: js_sys::Array| {
+                let new_reports = records
+                    .iter()
+                    .filter_map(Report::from_js)
+                    .collect::<Vec<_>>();
+
+                if let Some(on_report) = &on_report {
+                    for report in &new_reports {
+                        on_report(report);
+                    }
+                }
+
+                set_reports.update(|reports| {
+                    reports.extend(new_reports);
+
+                    if reports.len() > max_entries {
+                        reports.drain(..reports.len() - max_entries);
+                    }
+                });
+            })
+            .into_js_value();
+
+            let observer_options = js_sys::Object::new();
+            js!(observer_options["types"] = types
+                .iter()
+                .map(|t| JsValue::from_str(t.as_str()))
+                .collect::<js_sys::Array>());
+            js!(observer_options["buffered"] = buffered);
+
+            let new_observer = js_sys::Reflect::get(&window(), &"ReportingObserver".into())
+                .ok()
+                .and_then(|class| {
+                    js_sys::Reflect::construct(
+                        &class.unchecked_into::<js_sys::Function>(),
+                        &js_sys::Array::of2(&callback, &observer_options),
+                    )
+                    .ok()
+                })
+                .map(JsCast::unchecked_into::<js_sys::Object>);
+
+            if let Some(new_observer) = new_observer {
+                if let Ok(observe) = js!(new_observer["observe"]) {
+                    let _ = js_sys::Function::from(observe).call0(&new_observer);
+                }
+
+                set_observer.set(Some(new_observer));
+            }
+        }
+
+        if observe_csp_violations {
+            let _ = use_event_listener(
+                document(),
+                Custom::<web_sys::SecurityPolicyViolationEvent>::new("securitypolicyviolation"),
+                move |event| {
+                    let violation = CspViolation::from(&event);
+
+                    if let Some(on_csp_violation) = &on_csp_violation {
+                        on_csp_violation(&violation);
+                    }
+
+                    set_csp_violations.update(|violations| {
+                        violations.push(violation);
+
+                        if violations.len() > max_entries {
+                            violations.drain(..violations.len() - max_entries);
+                        }
+                    });
+                },
+            );
+        }
+    }
+
+    on_cleanup({
+        let stop = stop.clone();
+
+        move || {
+            stop();
+        }
+    });
+
+    let clear = sendwrap_fn!(move || {
+        set_reports.set(vec![]);
+        set_csp_violations.set(vec![]);
+    });
+
+    UseReportingObserverReturn {
+        is_supported,
+        reports: reports.into(),
+        csp_violations: csp_violations.into(),
+        stop,
+        clear,
+    }
+}
+
+/// Type of reports observed by [`use_reporting_observer`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReportType {
+    /// Usage of a deprecated API
+    Deprecation,
+    /// The browser declined to do something the page asked for, e.g. for security or performance reasons
+    Intervention,
+}
+
+impl ReportType {
+    /// The report type name as used by the browser.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Deprecation => "deprecation",
+            Self::Intervention => "intervention",
+        }
+    }
+}
+
+/// A deprecation or intervention report.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    /// The type of the report
+    pub report_type: ReportType,
+
+    /// URL of the document that generated the report
+    pub url: String,
+
+    /// Identifier of the deprecated feature or the intervention
+    pub id: Option<String>,
+
+    /// Human readable description
+    pub message: Option<String>,
+
+    /// URL of the script that caused the report
+    pub source_file: Option<String>,
+
+    /// Line number in `source_file`
+    pub line_number: Option<u32>,
+
+    /// Column number in `source_file`
+    pub column_number: Option<u32>,
+}
+
+impl Report {
+    #[cfg(not(feature = "ssr"))]
+    fn from_js(report: wasm_bindgen::JsValue) -> Option<Self> {
+        let report_type = match js!(report["type"]).ok()?.as_string()?.as_str() {
+            "deprecation" => ReportType::Deprecation,
+            "intervention" => ReportType::Intervention,
+            _ => return None,
+        };
+
+        let body = js!(report["body"]).ok()?;
+        let string = |key: &str| {
+            js_sys::Reflect::get(&body, &key.into())
+                .ok()
+                .and_then(|value| value.as_string())
+        };
+        let number = |key: &str| {
+            js_sys::Reflect::get(&body, &key.into())
+                .ok()
+                .and_then(|value| value.as_f64())
+                .map(|value| value as u32)
+        };
+
+        Some(Self {
+            report_type,
+            url: js!(report["url"])
+                .ok()
+                .and_then(|url| url.as_string())
+                .unwrap_or_default(),
+            id: string("id"),
+            message: string("message"),
+            source_file: string("sourceFile"),
+            line_number: number("lineNumber"),
+            column_number: number("columnNumber"),
+        })
+    }
+}
+
+/// A Content Security Policy violation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CspViolation {
+    /// URL of the document in which the violation occurred
+    pub document_uri: String,
+
+    /// URL of the resource that was blocked
+    pub blocked_uri: String,
+
+    /// The directive whose enforcement uncovered the violation
+    pub effective_directive: String,
+
+    /// The directive that was violated
+    pub violated_directive: String,
+
+    /// The complete policy that was violated
+    pub original_policy: String,
+
+    /// `true` if the policy is only reported (`Content-Security-Policy-Report-Only`) and not enforced
+    pub report_only: bool,
+
+    /// URL of the script that caused the violation
+    pub source_file: Option<String>,
+
+    /// Line number in `source_file`
+    pub line_number: Option<u32>,
+
+    /// Column number in `source_file`
+    pub column_number: Option<u32>,
+
+    /// The first characters of the inline script or style that caused the violation
+    pub sample: Option<String>,
+}
+
+impl From<&web_sys::SecurityPolicyViolationEvent> for CspViolation {
+    fn from(event: &web_sys::SecurityPolicyViolationEvent) -> Self {
+        let non_empty = |value: String| Some(value).filter(|value| !value.is_empty());
+        let positive = |value: i32| u32::try_from(value).ok().filter(|value| *value > 0);
+
+        Self {
+            document_uri: event.document_uri(),
+            blocked_uri: event.blocked_uri(),
+            effective_directive: event.effective_directive(),
+            violated_directive: event.violated_directive(),
+            original_policy: event.original_policy(),
+            report_only: event.disposition()
+                == web_sys::SecurityPolicyViolationEventDisposition::Report,
+            source_file: non_empty(event.source_file()),
+            line_number: positive(event.line_number()),
+            column_number: positive(event.column_number()),
+            sample: non_empty(event.sample()),
+        }
+    }
+}
+
+/// Options for [`use_reporting_observer_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseReportingObserverOptions {
+    /// The types of reports to observe. Defaults to deprecations and interventions.
+    types: Vec<ReportType>,
+
+    /// Also deliver reports that have been generated before the observer was created. Defaults to `true`.
+    buffered: bool,
+
+    /// Listen to `securitypolicyviolation` events. Defaults to `true`.
+    csp_violations: bool,
+
+    /// Maximum number of reports and CSP violations that are kept each. Older ones are dropped. Defaults to `100`.
+    max_entries: usize,
+
+    /// Called for every observed report.
+    #[builder(skip)]
+    on_report: Option<ReportHandlerFn>,
+
+    /// Called for every CSP violation.
+    #[builder(skip)]
+    on_csp_violation: Option<CspViolationHandlerFn>,
+}
+
+type ReportHandlerFn = Arc<dyn Fn(&Report) + Send + Sync>;
+type CspViolationHandlerFn = Arc<dyn Fn(&CspViolation) + Send + Sync>;
+
+impl UseReportingObserverOptions {
+    /// Called for every observed report.
+    pub fn on_report(self, on_report: impl Fn(&Report) + Send + Sync + 'static) -> Self {
+        Self {
+            on_report: Some(Arc::new(on_report)),
+            ..self
+        }
+    }
+
+    /// Called for every CSP violation.
+    pub fn on_csp_violation(
+        self,
+        on_csp_violation: impl Fn(&CspViolation) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            on_csp_violation: Some(Arc::new(on_csp_violation)),
+            ..self
+        }
+    }
+}
+
+impl Default for UseReportingObserverOptions {
+    fn default() -> Self {
+        Self {
+            types: vec![ReportType::Deprecation, ReportType::Intervention],
+            buffered: true,
+            csp_violations: true,
+            max_entries: 100,
+            on_report: None,
+            on_csp_violation: None,
+        }
+    }
+}
+
+/// Return type of [`use_reporting_observer`].
+pub struct UseReportingObserverReturn<StopFn, ClearFn>
+where
+    StopFn: Fn() + Clone + Send + Sync,
+    ClearFn: Fn() + Clone + Send + Sync,
+{
+    /// `true` if the browser supports `ReportingObserver`
+    pub is_supported: Signal<bool>,
+
+    /// The observed reports, oldest first
+    pub reports: Signal<Vec<Report>>,
+
+    /// The CSP violations, oldest first
+    pub csp_violations: Signal<Vec<CspViolation>>,
+
+    /// Stop observing reports
+    pub stop: StopFn,
+
+    /// Clears `reports` and `csp_violations`
+    pub clear: ClearFn,
+}
@@ -1,10 +1,13 @@
 use crate::core::IntoElementsMaybeSignal;
+use crate::filter_builder_methods;
+use crate::utils::{DebounceOptions, FilterOptions, ThrottleOptions};
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::reactive::wrappers::read::Signal;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
     use crate::{sendwrap_fn, use_supported};
+    use crate::utils::create_filter_wrapper_with_arg;
     use std::cell::RefCell;
     use std::rc::Rc;
     use wasm_bindgen::prelude::*;
@@ -45,6 +48,61 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ## Box Model
+///
+/// By default the content box of the target is observed. With the option `box_` you can choose to
+/// observe the border box or the device pixel content box instead.
+///
+/// If the browser doesn't support observing the device pixel content box (e.g. Safari), the content box is
+/// observed instead.
+///
+/// ```
+/// # use leptos::{html::Canvas, prelude::*};
+/// # use leptos_use::{use_resize_observer_with_options, UseResizeObserverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Canvas>::new();
+///
+/// use_resize_observer_with_options(
+///     el,
+///     move |entries, _| {
+///         // web_sys::ResizeObserverSize
+///         let size = entries[0].device_pixel_content_box_size();
+///     },
+///     UseResizeObserverOptions::default()
+///         .box_(web_sys::ResizeObserverBoxOptions::DevicePixelContentBox),
+/// );
+/// #
+/// # view! { <canvas node_ref=el></canvas> }
+/// # }
+/// ```
+///
+/// ## Debounce and Throttle
+///
+/// If the resize callback does expensive work (like re-layouting) you can debounce or throttle it.
+/// Please note that in this case the callback only receives the entries of the last resize notification.
+///
+/// ```
+/// # use leptos::{html::Div, prelude::*};
+/// # use leptos_use::{use_resize_observer_with_options, UseResizeObserverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// use_resize_observer_with_options(
+///     el,
+///     move |entries, _| {
+///         // only called 200ms after the last resize
+///     },
+///     UseResizeObserverOptions::default().debounce(200.0),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closure `stop` is a sendwrapped function. It can
@@ -72,7 +130,7 @@ where
 #[cfg_attr(feature = "ssr", allow(unused_variables, unused_mut))]
 pub fn use_resize_observer_with_options<Els, M, F>(
     target: Els,
-    mut callback: F,
+    callback: F,
     options: UseResizeObserverOptions,
 ) -> UseResizeObserverReturn<impl Fn() + Clone + Send + Sync>
 where
@@ -91,23 +149,47 @@ where
     {
         use crate::js;
 
-        let closure_js = Closure::<dyn FnMut(js_sys::Array, web_sys::ResizeObserver)>::new(
-            move |entries: js_sys::Array, observer| {
+        let callback = Rc::new(RefCell::new(callback));
+
+        let filtered_callback = create_filter_wrapper_with_arg(
+            options.filter.filter_fn(),
+            move |(entries, observer): (
+                Vec<web_sys::ResizeObserverEntry>,
+                web_sys::ResizeObserver,
+            )| {
                 #[cfg(debug_assertions)]
                 let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
-                callback(
+                (callback.borrow_mut())(entries, observer);
+            },
+        );
+
+        let closure_js = Closure::<dyn FnMut(js_sys::Array, web_sys::ResizeObserver)>::new(
+            move |entries: js_sys::Array, observer| {
+                filtered_callback((
                     entries
                         .to_vec()
                         .into_iter()
                         .map(|v| v.unchecked_into::<web_sys::ResizeObserverEntry>())
                         .collect(),
                     observer,
-                );
+                ));
             },
         )
         .into_js_value();
 
+        let observer_options = {
+            let mut options = options.clone();
+
+            if options.box_ == Some(web_sys::ResizeObserverBoxOptions::DevicePixelContentBox)
+                && !is_device_pixel_content_box_supported()
+            {
+                options.box_ = Some(web_sys::ResizeObserverBoxOptions::ContentBox);
+            }
+
+            web_sys::ResizeObserverOptions::from(options)
+        };
+
         let observer: Rc<RefCell<Option<web_sys::ResizeObserver>>> = Rc::new(RefCell::new(None));
 
         let is_supported = use_supported(|| js!("ResizeObserver" in &window()));
@@ -142,7 +224,7 @@ where
 
                         for target in targets.iter().flatten() {
                             let target = target.clone();
-                            obs.observe_with_options(&target, &options.clone().into());
+                            obs.observe_with_options(&target, &observer_options);
                         }
                         observer.replace(Some(obs));
                     }
@@ -168,12 +250,35 @@ where
     }
 }
 
+#[cfg(not(feature = "ssr"))]
+fn is_device_pixel_content_box_supported() -> bool {
+    use crate::js;
+
+    let window = window();
+
+    js!(window["ResizeObserverEntry"])
+        .and_then(|entry| js_sys::Reflect::get(&entry, &"prototype".into()))
+        .map(|prototype| js!("devicePixelContentBoxSize" in &prototype))
+        .unwrap_or(false)
+}
+
 /// Options for [`use_resize_observer_with_options`].
 #[derive(DefaultBuilder, Clone, Default)]
 pub struct UseResizeObserverOptions {
     /// The box that is used to determine the dimensions of the target. Defaults to `ContentBox`.
     #[builder(into)]
     pub box_: Option<web_sys::ResizeObserverBoxOptions>,
+
+    /// Allows to debounce or throttle the callback. Defaults to no filter.
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    filter: FilterOptions,
+}
+
+impl UseResizeObserverOptions {
+    filter_builder_methods!(
+        /// the resize callback
+        filter
+    );
 }
 
 impl From<UseResizeObserverOptions> for web_sys::ResizeObserverOptions {
@@ -0,0 +1,422 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_permission, use_supported, PermissionState};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive [Window Management API](https://developer.mozilla.org/en-US/docs/Web/API/Window_Management_API).
+///
+/// Lists all screens connected to the device and tells you which one the browser window is on.
+/// The signals are updated when a screen is connected or disconnected, when the window is moved to a
+/// different screen or when the properties of a screen change.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_screen_details)
+///
+/// ## Usage
+///
+/// The details are only available after the user has granted the `"window-management"` permission.
+/// Calling `request` asks for it and should be done in reaction to a user gesture.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_screen_details, UseScreenDetailsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseScreenDetailsReturn {
+///     screens,
+///     current_screen,
+///     screen_details,
+///     ..
+/// } = use_screen_details();
+///
+/// let request = move |_| {
+///     leptos::task::spawn_local(async move {
+///         let _ = screen_details.request().await;
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=request>"Detect screens"</button>
+///     <ul>
+///         {move || {
+///             screens
+///                 .get()
+///                 .into_iter()
+///                 .map(|screen| view! { <li>{screen.label} " " {screen.width} "x" {screen.height}</li> })
+///                 .collect_view()
+///         }}
+///     </ul>
+/// }
+/// # }
+/// ```
+///
+/// ## Placing windows
+///
+/// `open_window` opens a new window that covers the available area of the chosen screen and
+/// `request_fullscreen` shows an element fullscreen on the chosen screen, e.g. to present slides on a projector.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_screen_details, UseScreenDetailsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseScreenDetailsReturn { screens, screen_details, .. } = use_screen_details();
+///
+/// let slides = NodeRef::<Div>::new();
+///
+/// let present = move |_| {
+///     // present on the first screen that isn't the primary one
+///     if let Some(screen) = screens.get().into_iter().find(|screen| !screen.is_primary) {
+///         if let Some(slides) = slides.get() {
+///             let _ = screen_details.request_fullscreen(&slides, screen.index);
+///         }
+///     }
+/// };
+///
+/// view! {
+///     <div node_ref=slides>"Slides"</div>
+///     <button on:click=present>"Present"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false`, `permission` is `PermissionState::Unknown`, `screens`
+/// is empty, `current_screen` is `None` and the methods of [`ScreenDetailsClient`] return `Err(UseScreenDetailsError::Server)`.
+pub fn use_screen_details() -> UseScreenDetailsReturn {
+    let is_supported = use_supported(|| js!("getScreenDetails" in &window()));
+    let permission = use_permission("window-management");
+
+    let (screens, set_screens) = signal(Vec::<ScreenInfo>::new());
+    let (current_screen, set_current_screen) = signal(None::<ScreenInfo>);
+    let (error, set_error) = signal(None::<UseScreenDetailsError>);
+
+    let screen_details = ScreenDetailsClient {
+        details: StoredValue::new_local(None),
+        screens: StoredValue::new_local(vec![]),
+        set_screens,
+        set_current_screen,
+        set_error,
+    };
+
+    // the details can be read without asking if the permission has been granted before
+    #[cfg(not(feature = "ssr"))]
+    Effect::new(move |_| {
+        if is_supported.get() && permission.get() == PermissionState::Granted {
+            leptos::task::spawn_local(async move {
+                let _ = screen_details.request().await;
+            });
+        }
+    });
+
+    UseScreenDetailsReturn {
+        is_supported,
+        permission,
+        screens: screens.into(),
+        current_screen: current_screen.into(),
+        error: error.into(),
+        screen_details,
+    }
+}
+
+/// Properties of a screen as returned by [`use_screen_details`].
+/// All coordinates and sizes are in CSS pixels relative to the multi-screen origin.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScreenInfo {
+    /// Position in the list of screens. Used to refer to the screen in the methods of [`ScreenDetailsClient`].
+    pub index: usize,
+
+    /// A human-readable description of the screen, e.g. `"Built-in Retina Display"`
+    pub label: String,
+
+    /// The x coordinate of the left edge of the screen
+    pub left: i32,
+
+    /// The y coordinate of the top edge of the screen
+    pub top: i32,
+
+    /// The width of the screen
+    pub width: i32,
+
+    /// The height of the screen
+    pub height: i32,
+
+    /// The x coordinate of the left edge of the area that is available for windows, i.e. without task bars
+    pub avail_left: i32,
+
+    /// The y coordinate of the top edge of the area that is available for windows
+    pub avail_top: i32,
+
+    /// The width of the area that is available for windows
+    pub avail_width: i32,
+
+    /// The height of the area that is available for windows
+    pub avail_height: i32,
+
+    /// The device pixel ratio of the screen
+    pub device_pixel_ratio: f64,
+
+    /// If this is the primary screen of the operating system
+    pub is_primary: bool,
+
+    /// If this screen is built into the device, like a laptop display
+    pub is_internal: bool,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl ScreenInfo {
+    fn from_js(index: usize, screen: &JsValue) -> Self {
+        let get = |key: &str| js_sys::Reflect::get(screen, &key.into()).unwrap_or_default();
+        let int = |key: &str| get(key).as_f64().unwrap_or_default() as i32;
+
+        Self {
+            index,
+            label: get("label").as_string().unwrap_or_default(),
+            left: int("left"),
+            top: int("top"),
+            width: int("width"),
+            height: int("height"),
+            avail_left: int("availLeft"),
+            avail_top: int("availTop"),
+            avail_width: int("availWidth"),
+            avail_height: int("availHeight"),
+            device_pixel_ratio: get("devicePixelRatio").as_f64().unwrap_or(1.0),
+            is_primary: get("isPrimary").as_bool().unwrap_or_default(),
+            is_internal: get("isInternal").as_bool().unwrap_or_default(),
+        }
+    }
+}
+
+/// Gives access to the screen details and places windows on screens. See [`use_screen_details`] for how to use.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct ScreenDetailsClient {
+    details: StoredValue<Option<JsValue>, LocalStorage>,
+    screens: StoredValue<Vec<JsValue>, LocalStorage>,
+    set_screens: WriteSignal<Vec<ScreenInfo>>,
+    set_current_screen: WriteSignal<Option<ScreenInfo>>,
+    set_error: WriteSignal<Option<UseScreenDetailsError>>,
+}
+
+impl ScreenDetailsClient {
+    /// Asks for the `"window-management"` permission and starts tracking the screens.
+    /// If the screens are already tracked this does nothing.
+    pub async fn request(&self) -> Result<(), UseScreenDetailsError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseScreenDetailsError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use crate::use_event_listener;
+            use leptos::ev::Custom;
+            use wasm_bindgen::JsCast;
+
+            if self.details.try_with_value(Option::is_some) == Some(true) {
+                return Ok(());
+            }
+
+            let window = window();
+
+            let result = match js!(window["getScreenDetails"])
+                .and_then(|get| get.dyn_into::<js_sys::Function>())
+                .and_then(|get| get.call0(&window))
+            {
+                Ok(promise) => crate::js_fut!(promise.unchecked_into::<js_sys::Promise>())
+                    .await
+                    .map_err(|err| {
+                        let is_denied = js!(err["name"])
+                            .ok()
+                            .and_then(|name| name.as_string())
+                            .is_some_and(|name| name == "NotAllowedError");
+
+                        if is_denied {
+                            UseScreenDetailsError::PermissionDenied
+                        } else {
+                            UseScreenDetailsError::Failed(SendWrapper::new(err))
+                        }
+                    }),
+                Err(_) => Err(UseScreenDetailsError::NotSupported),
+            };
+
+            let details = match result {
+                Ok(details) => details,
+                Err(err) => {
+                    self.set_error.try_set(Some(err.clone()));
+                    return Err(err);
+                }
+            };
+
+            self.set_error.try_set(None);
+            self.details.try_set_value(Some(details.clone()));
+            self.update();
+
+            let this = *self;
+
+            for event in ["screenschange", "currentscreenchange"] {
+                let _ = use_event_listener(
+                    details.clone().unchecked_into::<web_sys::EventTarget>(),
+                    Custom::<web_sys::Event>::new(event),
+                    move |_| this.update(),
+                );
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Opens `url` in a new window that covers the available area of the screen with the given `index`.
+    pub fn open_window(
+        &self,
+        url: &str,
+        index: usize,
+    ) -> Result<Option<web_sys::Window>, UseScreenDetailsError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseScreenDetailsError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let screen = self.screen(index)?;
+            let screen = ScreenInfo::from_js(index, &screen);
+
+            window()
+                .open_with_url_and_target_and_features(
+                    url,
+                    "_blank",
+                    &format!(
+                        "left={},top={},width={},height={}",
+                        screen.avail_left,
+                        screen.avail_top,
+                        screen.avail_width,
+                        screen.avail_height
+                    ),
+                )
+                .map_err(|err| UseScreenDetailsError::Failed(SendWrapper::new(err)))
+        }
+    }
+
+    /// Shows `element` fullscreen on the screen with the given `index`.
+    /// Has to be called in reaction to a user gesture.
+    pub fn request_fullscreen(
+        &self,
+        element: &web_sys::Element,
+        index: usize,
+    ) -> Result<(), UseScreenDetailsError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseScreenDetailsError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            let options = js_sys::Object::new();
+            js!(options["screen"] = self.screen(index)?);
+
+            js!(element["requestFullscreen"])
+                .and_then(|request| request.dyn_into::<js_sys::Function>())
+                .and_then(|request| request.call1(element, &options))
+                .map(|_| ())
+                .map_err(|err| UseScreenDetailsError::Failed(SendWrapper::new(err)))
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn screen(&self, index: usize) -> Result<JsValue, UseScreenDetailsError> {
+        self.screens
+            .try_with_value(|screens| screens.get(index).cloned())
+            .flatten()
+            .ok_or(UseScreenDetailsError::ScreenNotFound(index))
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn update(&self) {
+        use crate::use_event_listener;
+        use leptos::ev::change;
+        use wasm_bindgen::JsCast;
+
+        let Some(Some(details)) = self.details.try_get_value() else {
+            return;
+        };
+
+        let screens = js!(details["screens"])
+            .map(|screens| js_sys::Array::from(&screens).to_vec())
+            .unwrap_or_default();
+
+        let current = js!(details["currentScreen"]).unwrap_or_default();
+
+        let infos = screens
+            .iter()
+            .enumerate()
+            .map(|(index, screen)| ScreenInfo::from_js(index, screen))
+            .collect::<Vec<_>>();
+
+        let current_screen = screens
+            .iter()
+            .position(|screen| *screen == current)
+            .and_then(|index| infos.get(index).cloned());
+
+        // listen to property changes of screens that have not been seen before
+        let known = self.screens.try_get_value().unwrap_or_default();
+        let this = *self;
+
+        for screen in screens.iter().filter(|screen| !known.contains(screen)) {
+            let _ = use_event_listener(
+                screen.clone().unchecked_into::<web_sys::EventTarget>(),
+                change,
+                move |_| this.update(),
+            );
+        }
+
+        self.screens.try_set_value(screens);
+        self.set_screens.try_set(infos);
+        self.set_current_screen.try_set(current_screen);
+    }
+}
+
+/// Error returned by the methods of [`ScreenDetailsClient`].
+#[derive(Error, Debug, Clone)]
+pub enum UseScreenDetailsError {
+    #[error("the Window Management API is not available on the server")]
+    Server,
+
+    #[error("the Window Management API is not supported by this browser")]
+    NotSupported,
+
+    #[error("the permission to manage windows has been denied")]
+    PermissionDenied,
+
+    #[error("there is no screen with index {0}")]
+    ScreenNotFound(usize),
+
+    #[error("the window management operation failed")]
+    Failed(SendWrapper<JsValue>),
+}
+
+/// Return type of [`use_screen_details`].
+pub struct UseScreenDetailsReturn {
+    /// `true` if the browser supports the Window Management API
+    pub is_supported: Signal<bool>,
+
+    /// State of the `"window-management"` permission
+    pub permission: Signal<PermissionState>,
+
+    /// All screens connected to the device. Empty until the details have been requested.
+    pub screens: Signal<Vec<ScreenInfo>>,
+
+    /// The screen that the browser window is currently on
+    pub current_screen: Signal<Option<ScreenInfo>>,
+
+    /// The last error of a request
+    pub error: Signal<Option<UseScreenDetailsError>>,
+
+    /// Requests the details and places windows on screens
+    pub screen_details: ScreenDetailsClient,
+}
@@ -0,0 +1,269 @@
+use crate::core::LoadStatus;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Load an external script by injecting a `<script>` element into the `<head>` of the document.
+///
+/// The returned `status` reports if the script is still loading, has loaded or failed to load.
+/// Multiple components that request the same `src` share a single script element which is only
+/// removed after the last of them has unloaded it.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_script_tag)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_script_tag, UseScriptTagReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseScriptTagReturn { is_loaded, is_error, .. } =
+///     use_script_tag("https://player.twitch.tv/js/embed/v1.js");
+///
+/// view! {
+///     <Show when=move || is_loaded.get()>"Script loaded"</Show>
+///     <Show when=move || is_error.get()>"Failed to load script"</Show>
+/// }
+/// # }
+/// ```
+///
+/// By default the script is loaded as soon as the component is mounted and unloaded when it's cleaned up.
+/// Set the option `immediate` to `false` to load it manually with `load` and `unload_on_cleanup` to `false`
+/// to keep it in the document.
+///
+/// ## Modules and Import Maps
+///
+/// Set `module` to `true` to load the script with `type="module"`. An `import_map` (the JSON content
+/// of a `<script type="importmap">`) is injected before the first module script is loaded.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_script_tag_with_options, UseScriptTagOptions, UseScriptTagReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseScriptTagReturn { status, .. } = use_script_tag_with_options(
+///     "/app.js",
+///     UseScriptTagOptions::default()
+///         .module(true)
+///         .import_map(r#"{ "imports": { "lodash": "https://esm.sh/lodash" } }"#.to_string())
+///         .attrs(vec![("crossorigin".to_string(), "anonymous".to_string())]),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Please note that the options of the first component that loads a script are used for the shared
+/// script element.
+///
+/// Please note that import maps can only be added before any module script has started loading and that
+/// identical import maps are only injected once.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `load` and `unload` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_script_tag`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no script is loaded, the status is always `LoadStatus::Idle` and the returned closures are no-ops.
+pub fn use_script_tag(
+    src: impl Into<String>,
+) -> UseScriptTagReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_script_tag_with_options(src, UseScriptTagOptions::default())
+}
+
+/// Version of [`use_script_tag`] that takes a `UseScriptTagOptions`. See [`use_script_tag`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_script_tag_with_options(
+    src: impl Into<String>,
+    options: UseScriptTagOptions,
+) -> UseScriptTagReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseScriptTagOptions {
+        immediate,
+        unload_on_cleanup,
+        module,
+        import_map,
+        attrs,
+    } = options;
+
+    let src = src.into();
+
+    // the status shared by all users of the same script element
+    let shared_status = RwSignal::new(None::<ArcRwSignal<LoadStatus>>);
+
+    let load;
+    let unload;
+
+    #[cfg(feature = "ssr")]
+    {
+        load = || {};
+        unload = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::sendwrap_fn;
+        use crate::utils::{acquire_head_element, release_head_element};
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        // whether this instance currently holds a reference to the script element
+        let acquired = Rc::new(Cell::new(false));
+
+        load = {
+            let src = src.clone();
+            let acquired = Rc::clone(&acquired);
+
+            sendwrap_fn!(move || {
+                if acquired.replace(true) {
+                    return;
+                }
+
+                if let Some(import_map) = &import_map {
+                    inject_import_map(import_map);
+                }
+
+                let status = acquire_head_element("script", &src, |element| {
+                    for (name, value) in &attrs {
+                        let _ = element.set_attribute(name, value);
+                    }
+                    if module {
+                        let _ = element.set_attribute("type", "module");
+                    }
+                    let _ = element.set_attribute("src", &src);
+                });
+                // the element couldn't be created so there is nothing to release
+                if status.is_none() {
+                    acquired.set(false);
+                }
+
+                shared_status.set(Some(
+                    status.unwrap_or_else(|| ArcRwSignal::new(LoadStatus::Error)),
+                ));
+            })
+        };
+
+        unload = {
+            let src = src.clone();
+
+            sendwrap_fn!(move || {
+                if !acquired.replace(false) {
+                    return;
+                }
+
+                release_head_element("script", &src);
+
+                // this is also called on cleanup when the signal might already be disposed
+                let _ = shared_status.try_set(None);
+            })
+        };
+
+        if immediate {
+            Effect::new(load.clone());
+        }
+
+        if unload_on_cleanup {
+            on_cleanup(unload.clone());
+        }
+    }
+
+    let status = Signal::derive(move || {
+        shared_status
+            .get()
+            .map(|status| status.get())
+            .unwrap_or_default()
+    });
+
+    UseScriptTagReturn {
+        status,
+        is_loaded: Signal::derive(move || status.get() == LoadStatus::Loaded),
+        is_error: Signal::derive(move || status.get() == LoadStatus::Error),
+        load,
+        unload,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+thread_local! {
+    static IMPORT_MAPS: std::cell::RefCell<std::collections::HashSet<String>> = Default::default();
+}
+
+#[cfg(not(feature = "ssr"))]
+fn inject_import_map(import_map: &str) {
+    let is_new =
+        IMPORT_MAPS.with_borrow_mut(|import_maps| import_maps.insert(import_map.to_string()));
+    if !is_new {
+        return;
+    }
+
+    if let Ok(element) = document().create_element("script") {
+        let _ = element.set_attribute("type", "importmap");
+        element.set_text_content(Some(import_map));
+
+        if let Some(head) = document().head() {
+            let _ = head.append_child(&element);
+        }
+    }
+}
+
+/// Options for [`use_script_tag_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseScriptTagOptions {
+    /// Load the script as soon as the component is mounted. Defaults to `true`.
+    immediate: bool,
+
+    /// Unload the script when the component is cleaned up. The script element is only removed when
+    /// no other component uses it anymore. Defaults to `true`.
+    unload_on_cleanup: bool,
+
+    /// Load the script as a module (`type="module"`). Defaults to `false`.
+    module: bool,
+
+    /// JSON content of an import map that is injected before the script. Defaults to `None`.
+    #[builder(into)]
+    import_map: Option<String>,
+
+    /// Additional attributes of the script element like `crossorigin` or `integrity`. Defaults to `[]`.
+    attrs: Vec<(String, String)>,
+}
+
+impl Default for UseScriptTagOptions {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            unload_on_cleanup: true,
+            module: false,
+            import_map: None,
+            attrs: vec![],
+        }
+    }
+}
+
+/// Return type of [`use_script_tag`].
+pub struct UseScriptTagReturn<LoadFn, UnloadFn>
+where
+    LoadFn: Fn() + Clone + Send + Sync,
+    UnloadFn: Fn() + Clone + Send + Sync,
+{
+    /// The loading status of the script
+    pub status: Signal<LoadStatus>,
+
+    /// `true` as soon as the script has loaded
+    pub is_loaded: Signal<bool>,
+
+    /// `true` if the script failed to load
+    pub is_error: Signal<bool>,
+
+    /// Load the script. If another component has loaded the same script already, it's not loaded again.
+    pub load: LoadFn,
+
+    /// Unload the script. The script element is removed when no other component uses it anymore.
+    pub unload: UnloadFn,
+}
@@ -0,0 +1,470 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::ConnectionReadyState;
+use crate::{js, sendwrap_fn, use_supported};
+use codee::Decoder;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use std::sync::Arc;
+use wasm_bindgen::JsValue;
+pub use web_sys::{FlowControlType, ParityType, SerialPortFilter};
+
+/// Reactive [Web Serial API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Serial_API).
+///
+/// Lets the user pick a serial port, for example a microcontroller connected via USB, opens it and
+/// exposes the received data as signals. Every received line is additionally decoded with the given
+/// codec which makes it easy to talk to devices with text based protocols.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_serial)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_serial, UseSerialReturn};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseSerialReturn {
+///     ready_state,
+///     message,
+///     open,
+///     write,
+///     ..
+/// } = use_serial::<String, FromToStringCodec>();
+///
+/// view! {
+///     // the port picker can only be shown in reaction to a user gesture
+///     <button on:click=move |_| open()>"Connect"</button>
+///     <button on:click=move |_| write(b"PING\n")>"Ping"</button>
+///     <p>{move || ready_state.get().to_string()} ": " {message}</p>
+/// }
+/// # }
+/// ```
+///
+/// Lines are separated by `\n`. A trailing `\r` is removed before decoding. If the device sends binary
+/// data use `data` instead which always contains the last received chunk of bytes.
+///
+/// ## Port Options
+///
+/// The port is opened with 9600 baud, 8 data bits, 1 stop bit, no parity and no flow control.
+/// All of these can be changed in the options. With `filters` the picker only shows matching devices.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_serial_with_options, SerialPortFilter, UseSerialOptions, UseSerialReturn};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let filter = SerialPortFilter::new();
+/// filter.set_usb_vendor_id(0x2341);
+///
+/// let UseSerialReturn { message, .. } = use_serial_with_options::<String, FromToStringCodec>(
+///     UseSerialOptions::default()
+///         .baud_rate(115_200)
+///         .filters(vec![filter])
+///         .on_disconnect(|| {
+///             leptos::logging::log!("Device unplugged");
+///         }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// When the device is unplugged `ready_state` becomes `ConnectionReadyState::Closed` and the callback
+/// `on_disconnect` is called.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `open`, `close` and `write` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_serial`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `ready_state` is always `ConnectionReadyState::Closed`, `data` and `message` are `None`
+/// and the returned closures do nothing.
+#[allow(clippy::type_complexity)]
+pub fn use_serial<T, C>() -> UseSerialReturn<
+    T,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn(&[u8]) + Clone + Send + Sync,
+>
+where
+    T: Clone + Send + Sync + 'static,
+    C: Decoder<T, Encoded = str>,
+{
+    use_serial_with_options::<T, C>(UseSerialOptions::default())
+}
+
+/// Version of [`use_serial`] that takes a `UseSerialOptions`. See [`use_serial`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_serial_with_options<T, C>(
+    options: UseSerialOptions,
+) -> UseSerialReturn<
+    T,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn(&[u8]) + Clone + Send + Sync,
+>
+where
+    T: Clone + Send + Sync + 'static,
+    C: Decoder<T, Encoded = str>,
+{
+    let UseSerialOptions {
+        baud_rate,
+        data_bits,
+        stop_bits,
+        parity,
+        flow_control,
+        buffer_size,
+        filters,
+        on_disconnect,
+    } = options;
+
+    let is_supported = use_supported(|| js!("serial" in &window().navigator()));
+
+    let (ready_state, set_ready_state) = signal(ConnectionReadyState::Closed);
+    let (data, set_data) = signal(None::<Vec<u8>>);
+    let (message, set_message) = signal(None::<T>);
+    let (error, set_error) = signal(None::<UseSerialError>);
+
+    let connection = StoredValue::new_local(None::<SerialConnection>);
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        use crate::use_event_listener;
+        use leptos::ev::Custom;
+        use wasm_bindgen::JsCast;
+
+        let _ = use_event_listener(
+            window()
+                .navigator()
+                .serial()
+                .unchecked_into::<web_sys::EventTarget>(),
+            Custom::<web_sys::Event>::new("disconnect"),
+            move |event| {
+                let is_own_port = connection.with_value(|connection| {
+                    connection.as_ref().is_some_and(|connection| {
+                        event
+                            .target()
+                            .is_some_and(|target| js_sys::Object::is(&target, &connection.port))
+                    })
+                });
+
+                if is_own_port {
+                    connection.set_value(None);
+                    set_ready_state.set(ConnectionReadyState::Closed);
+
+                    #[cfg(debug_assertions)]
+                    let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                    on_disconnect();
+                }
+            },
+        );
+    }
+
+    let open = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() {
+                set_error.set(Some(UseSerialError::NotSupported));
+                return;
+            }
+
+            if ready_state.get_untracked() != ConnectionReadyState::Closed {
+                return;
+            }
+
+            set_ready_state.set(ConnectionReadyState::Connecting);
+
+            let request_options = web_sys::SerialPortRequestOptions::new();
+            request_options.set_filters(&filters);
+
+            let port_options = web_sys::SerialOptions::new(baud_rate);
+            port_options.set_data_bits(data_bits);
+            port_options.set_stop_bits(stop_bits);
+            port_options.set_parity(parity);
+            port_options.set_flow_control(flow_control);
+            if let Some(buffer_size) = buffer_size {
+                port_options.set_buffer_size(buffer_size);
+            }
+
+            leptos::task::spawn_local(async move {
+                match open_port(&request_options, &port_options).await {
+                    Ok(opened) => {
+                        let reader = opened.reader.clone();
+                        connection.set_value(Some(opened));
+
+                        set_error.set(None);
+                        set_ready_state.set(ConnectionReadyState::Open);
+
+                        read_loop::<T, C>(&reader, set_data, set_message, set_error).await;
+
+                        // no `close` was called so the device stopped sending or disappeared
+                        if ready_state.get_untracked() == ConnectionReadyState::Open {
+                            if let Some(Some(opened)) = connection.try_update_value(Option::take) {
+                                let _ = opened.close().await;
+                            }
+                            set_ready_state.set(ConnectionReadyState::Closed);
+                        }
+                    }
+                    Err(err) => {
+                        set_error.set(Some(err));
+                        set_ready_state.set(ConnectionReadyState::Closed);
+                    }
+                }
+            });
+        }
+    });
+
+    let close = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(opened) = connection.try_update_value(Option::take).flatten() else {
+                return;
+            };
+
+            set_ready_state.set(ConnectionReadyState::Closing);
+
+            leptos::task::spawn_local(async move {
+                if let Err(err) = opened.close().await {
+                    set_error.set(Some(err));
+                }
+
+                set_ready_state.set(ConnectionReadyState::Closed);
+            });
+        }
+    });
+
+    let write = sendwrap_fn!(move |bytes: &[u8]| {
+        #[cfg(not(feature = "ssr"))]
+        connection.with_value(|connection| {
+            if let Some(connection) = connection {
+                let _ = connection
+                    .writer
+                    .write_with_chunk(&js_sys::Uint8Array::from(bytes));
+            }
+        });
+    });
+
+    on_cleanup({
+        let close = close.clone();
+        move || close()
+    });
+
+    UseSerialReturn {
+        is_supported,
+        ready_state: ready_state.into(),
+        data: data.into(),
+        message: message.into(),
+        error: error.into(),
+        open,
+        close,
+        write,
+    }
+}
+
+/// An opened port with the locked streams.
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+struct SerialConnection {
+    port: SendWrapper<web_sys::SerialPort>,
+    reader: SendWrapper<web_sys::ReadableStreamDefaultReader>,
+    writer: SendWrapper<web_sys::WritableStreamDefaultWriter>,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl SerialConnection {
+    /// Stops reading, releases the streams and closes the port.
+    async fn close(self) -> Result<(), UseSerialError> {
+        let js_error = |err: JsValue| UseSerialError::Js(SendWrapper::new(err));
+
+        let _ = crate::js_fut!(self.reader.cancel()).await;
+        self.reader.release_lock();
+        self.writer.release_lock();
+
+        crate::js_fut!(self.port.close())
+            .await
+            .map(|_| ())
+            .map_err(js_error)
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn open_port(
+    request_options: &web_sys::SerialPortRequestOptions,
+    port_options: &web_sys::SerialOptions,
+) -> Result<SerialConnection, UseSerialError> {
+    use wasm_bindgen::JsCast;
+
+    let js_error = |err: JsValue| UseSerialError::Js(SendWrapper::new(err));
+
+    let port: web_sys::SerialPort = crate::js_fut!(window()
+        .navigator()
+        .serial()
+        .request_port_with_options(request_options))
+    .await
+    .map_err(js_error)?
+    .unchecked_into();
+
+    crate::js_fut!(port.open(port_options))
+        .await
+        .map_err(js_error)?;
+
+    let reader = web_sys::ReadableStreamDefaultReader::new(&port.readable()).map_err(js_error)?;
+    let writer = web_sys::WritableStreamDefaultWriter::new(&port.writable()).map_err(js_error)?;
+
+    Ok(SerialConnection {
+        port: SendWrapper::new(port),
+        reader: SendWrapper::new(reader),
+        writer: SendWrapper::new(writer),
+    })
+}
+
+/// Reads from the port until the stream ends or fails.
+#[cfg(not(feature = "ssr"))]
+async fn read_loop<T, C>(
+    reader: &web_sys::ReadableStreamDefaultReader,
+    set_data: WriteSignal<Option<Vec<u8>>>,
+    set_message: WriteSignal<Option<T>>,
+    set_error: WriteSignal<Option<UseSerialError>>,
+) where
+    T: Send + Sync + 'static,
+    C: Decoder<T, Encoded = str>,
+{
+    let mut buffer = Vec::<u8>::new();
+
+    loop {
+        let chunk = match crate::js_fut!(reader.read()).await {
+            Ok(chunk) => chunk,
+            Err(err) => {
+                set_error.try_set(Some(UseSerialError::Js(SendWrapper::new(err))));
+                break;
+            }
+        };
+
+        if js!(chunk["done"]).ok().and_then(|done| done.as_bool()) == Some(true) {
+            break;
+        }
+
+        let Ok(value) = js!(chunk["value"]) else {
+            continue;
+        };
+
+        let bytes = js_sys::Uint8Array::new(&value).to_vec();
+        buffer.extend_from_slice(&bytes);
+        set_data.try_set(Some(bytes));
+
+        while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+            let line = buffer.drain(..=end).collect::<Vec<_>>();
+            let line = String::from_utf8_lossy(&line);
+
+            match C::decode(line.trim_end_matches(['\r', '\n'])) {
+                Ok(message) => {
+                    set_message.try_set(Some(message));
+                }
+                Err(_) => {
+                    set_error.try_set(Some(UseSerialError::Decode));
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum UseSerialError {
+    /// The browser doesn't support the Web Serial API
+    NotSupported,
+    /// A received line couldn't be decoded with the codec
+    Decode,
+    /// A call of the browser API failed, for example because the user closed the port picker
+    Js(SendWrapper<JsValue>),
+}
+
+/// Options for [`use_serial_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseSerialOptions {
+    /// The baud rate of the connection. Defaults to `9600`.
+    baud_rate: u32,
+
+    /// The number of data bits per frame, either `7` or `8`. Defaults to `8`.
+    data_bits: u8,
+
+    /// The number of stop bits at the end of a frame, either `1` or `2`. Defaults to `1`.
+    stop_bits: u8,
+
+    /// The parity mode. Defaults to `ParityType::None`.
+    parity: ParityType,
+
+    /// The flow control mode. Defaults to `FlowControlType::None`.
+    flow_control: FlowControlType,
+
+    /// The size of the read and write buffers in bytes. Defaults to `None` which uses the browser's default.
+    buffer_size: Option<u32>,
+
+    /// Only ports matching one of these filters are shown in the port picker. Defaults to no filters.
+    filters: Vec<SerialPortFilter>,
+
+    /// Called when the opened port is disconnected from the device.
+    on_disconnect: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl Default for UseSerialOptions {
+    fn default() -> Self {
+        Self {
+            baud_rate: 9600,
+            data_bits: 8,
+            stop_bits: 1,
+            parity: ParityType::None,
+            flow_control: FlowControlType::None,
+            buffer_size: None,
+            filters: vec![],
+            on_disconnect: Arc::new(|| {}),
+        }
+    }
+}
+
+/// Return type of [`use_serial`].
+pub struct UseSerialReturn<T, OpenFn, CloseFn, WriteFn>
+where
+    T: Send + Sync + 'static,
+    OpenFn: Fn() + Clone + Send + Sync,
+    CloseFn: Fn() + Clone + Send + Sync,
+    WriteFn: Fn(&[u8]) + Clone + Send + Sync,
+{
+    /// `true` if the browser supports the Web Serial API
+    pub is_supported: Signal<bool>,
+
+    /// The state of the connection to the port
+    pub ready_state: Signal<ConnectionReadyState>,
+
+    /// The last chunk of bytes received from the port
+    pub data: Signal<Option<Vec<u8>>>,
+
+    /// The last received line decoded with the codec
+    pub message: Signal<Option<T>>,
+
+    /// The error of the last failed operation
+    pub error: Signal<Option<UseSerialError>>,
+
+    /// Shows the port picker and opens the selected port. Has to be called in reaction to a user gesture.
+    pub open: OpenFn,
+
+    /// Closes the port
+    pub close: CloseFn,
+
+    /// Sends bytes to the port. Does nothing if the port isn't open.
+    pub write: WriteFn,
+}
@@ -0,0 +1,299 @@
+#![cfg_attr(feature = "ssr", allow(unused_imports))]
+
+use crate::use_supported;
+use crate::utils::LocalBoxFuture;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive [Web Share API](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/share).
+///
+/// Opens the native share dialog of the platform to share text, links or files.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_share)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::{use_share, UseShareData, UseShareReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseShareReturn { is_supported, share, .. } = use_share();
+///
+/// let on_click = move |_| {
+///     let result = share(
+///         UseShareData::default()
+///             .title("Leptos-Use")
+///             .text("Collection of essential Leptos utilities")
+///             .url("https://leptos-use.rs"),
+///     );
+///
+///     spawn_local(async move {
+///         let _ = result.await;
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=on_click disabled=move || !is_supported.get()>"Share"</button>
+/// }
+/// # }
+/// ```
+///
+/// Sharing has to be triggered by a user interaction like a click. The returned future resolves with a
+/// [`ShareOutcome`] which is `ShareOutcome::Canceled` if the user closed the share dialog.
+///
+/// ## Sharing Files
+///
+/// Not every platform can share every kind of file. Use `can_share` to check if some data can be shared
+/// before offering to share it.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_share, UseShareData, UseShareReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let file: web_sys::File = unimplemented!();
+/// let UseShareReturn { can_share, share, .. } = use_share();
+///
+/// let data = UseShareData::default().files(vec![file]);
+///
+/// if can_share(&data) {
+///     let _ = share(data);
+/// }
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Clipboard Fallback
+///
+/// Set the option `clipboard_fallback` to `true` to copy the text and URL to the clipboard if the data can't
+/// be shared. In that case the future resolves with `ShareOutcome::Copied`. If there is neither text
+/// nor a URL to copy, the clipboard is left untouched and the future resolves with `ShareOutcome::Unsupported`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_share_with_options, UseShareOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let share = use_share_with_options(UseShareOptions::default().clipboard_fallback(true));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `can_share` and `share` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_share`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false`, `can_share` returns `false` and `share` resolves
+/// with `ShareOutcome::Unsupported`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_clipboard`]
+pub fn use_share() -> UseShareReturn<
+    impl Fn(&UseShareData) -> bool + Clone + Send + Sync,
+    impl Fn(UseShareData) -> ShareFuture + Clone + Send + Sync,
+> {
+    use_share_with_options(UseShareOptions::default())
+}
+
+/// Version of [`use_share`] that takes a `UseShareOptions`. See [`use_share`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_share_with_options(
+    options: UseShareOptions,
+) -> UseShareReturn<
+    impl Fn(&UseShareData) -> bool + Clone + Send + Sync,
+    impl Fn(UseShareData) -> ShareFuture + Clone + Send + Sync,
+> {
+    let UseShareOptions { clipboard_fallback } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("share" in &window().navigator())
+        }
+    });
+
+    let can_share;
+    let share;
+
+    #[cfg(feature = "ssr")]
+    {
+        can_share = |_: &UseShareData| false;
+        share = |_: UseShareData| -> ShareFuture {
+            Box::pin(std::future::ready(ShareOutcome::Unsupported))
+        };
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js, js_fut, sendwrap_fn};
+        use wasm_bindgen::JsCast;
+
+        let check = move |data: &UseShareData| {
+            if !is_supported.get_untracked() {
+                return false;
+            }
+
+            let navigator = window().navigator();
+
+            if js!("canShare" in &navigator) {
+                navigator.can_share_with_data(&data.to_web_sys())
+            } else {
+                // browsers without `canShare` can't share files
+                data.files.is_empty()
+            }
+        };
+
+        can_share = sendwrap_fn!(move |data: &UseShareData| check(data));
+
+        share = sendwrap_fn!(move |data: UseShareData| -> ShareFuture {
+            let navigator = window().navigator();
+
+            if check(&data) {
+                let promise = navigator.share_with_data(&data.to_web_sys());
+
+                return Box::pin(async move {
+                    match js_fut!(promise).await {
+                        Ok(_) => ShareOutcome::Shared,
+                        Err(err) => match err.dyn_ref::<web_sys::DomException>() {
+                            Some(err) if err.name() == "AbortError" => ShareOutcome::Canceled,
+                            _ => ShareOutcome::Error,
+                        },
+                    }
+                });
+            }
+
+            if !clipboard_fallback || !js!("clipboard" in &navigator) {
+                return Box::pin(std::future::ready(ShareOutcome::Unsupported));
+            }
+
+            let text = [data.text, data.url]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            // don't replace the clipboard content with nothing
+            if text.is_empty() {
+                return Box::pin(std::future::ready(ShareOutcome::Unsupported));
+            }
+
+            let promise = navigator.clipboard().write_text(&text);
+
+            Box::pin(async move {
+                match js_fut!(promise).await {
+                    Ok(_) => ShareOutcome::Copied,
+                    Err(_) => ShareOutcome::Error,
+                }
+            })
+        });
+    }
+
+    UseShareReturn {
+        is_supported,
+        can_share,
+        share,
+    }
+}
+
+/// Data to share with [`use_share`].
+#[derive(DefaultBuilder, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UseShareData {
+    /// Title of the shared content
+    #[builder(into)]
+    pub title: Option<String>,
+
+    /// Text of the shared content
+    #[builder(into)]
+    pub text: Option<String>,
+
+    /// URL of the shared content
+    #[builder(into)]
+    pub url: Option<String>,
+
+    /// Files to share
+    pub files: Vec<web_sys::File>,
+}
+
+impl UseShareData {
+    #[cfg(not(feature = "ssr"))]
+    fn to_web_sys(&self) -> web_sys::ShareData {
+        let data = web_sys::ShareData::new();
+
+        if let Some(title) = &self.title {
+            data.set_title(title);
+        }
+        if let Some(text) = &self.text {
+            data.set_text(text);
+        }
+        if let Some(url) = &self.url {
+            data.set_url(url);
+        }
+        if !self.files.is_empty() {
+            data.set_files(&self.files.iter().collect::<js_sys::Array>());
+        }
+
+        data
+    }
+}
+
+/// Result of `share` of [`use_share`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShareOutcome {
+    /// The data has been shared
+    Shared,
+    /// The data couldn't be shared and has been copied to the clipboard instead
+    Copied,
+    /// The user closed the share dialog
+    Canceled,
+    /// The data can't be shared on this platform and the clipboard fallback is disabled, not available
+    /// or there is nothing to copy
+    Unsupported,
+    /// Sharing or copying failed for another reason
+    Error,
+}
+
+/// Future returned by `share` of [`use_share`]. Resolves with the [`ShareOutcome`].
+pub type ShareFuture = LocalBoxFuture<ShareOutcome>;
+
+/// Options for [`use_share_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseShareOptions {
+    /// Copy the text and URL to the clipboard if the data can't be shared. Defaults to `false`.
+    clipboard_fallback: bool,
+}
+
+/// Return type of [`use_share`].
+pub struct UseShareReturn<CanShareFn, ShareFn>
+where
+    CanShareFn: Fn(&UseShareData) -> bool + Clone + Send + Sync,
+    ShareFn: Fn(UseShareData) -> ShareFuture + Clone + Send + Sync,
+{
+    /// Whether the Web Share API is supported
+    pub is_supported: Signal<bool>,
+
+    /// Check if the given data can be shared on this platform
+    pub can_share: CanShareFn,
+
+    /// Open the share dialog with the given data. Returns a future that resolves with the [`ShareOutcome`].
+    pub share: ShareFn,
+}
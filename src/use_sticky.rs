@@ -0,0 +1,281 @@
+use crate::core::{Direction, IntoElementMaybeSignal};
+use cfg_if::cfg_if;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::marker::PhantomData;
+
+cfg_if! { if #[cfg(not(feature = "ssr"))] {
+    use crate::{
+        use_intersection_observer_with_options, watch_with_options, UseIntersectionObserverOptions,
+        WatchOptions,
+    };
+}}
+
+/// Reactive stuck state of a `position: sticky` element.
+///
+/// Reports if the element is currently "stuck" (pinned to the edge of its scroll container)
+/// and on which edge. This is detected by inserting invisible 1px sentinel elements right before and/or
+/// right after the target element and observing them with an
+/// [IntersectionObserver](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver).
+/// As soon as a sentinel scrolls out of the container on the edge the element is sticking to,
+/// the element is considered stuck.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_sticky)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Header;
+/// # use leptos_use::{use_sticky, UseStickyReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Header>::new();
+///
+/// let UseStickyReturn { is_stuck, .. } = use_sticky(el);
+///
+/// view! {
+///     <header
+///         node_ref=el
+///         style="position: sticky; top: 0"
+///         class:shadow=is_stuck
+///     >
+///         "Sticky Header"
+///     </header>
+/// }
+/// # }
+/// ```
+///
+/// ## Offsets
+///
+/// The sentinels have to know at which offset the element sticks. Set the options `top` and `bottom`
+/// to the same values (in px) as the CSS properties `top` and `bottom` of your element.
+/// An edge that is `None` is not observed. By default only `top` is observed with an offset of `0`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_sticky_with_options, UseStickyOptions, UseStickyReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseStickyReturn { stuck_edge, .. } = use_sticky_with_options(
+///     el,
+///     UseStickyOptions::default().top(None).bottom(Some(20.0)),
+/// );
+///
+/// view! {
+///     <div node_ref=el style="position: sticky; bottom: 20px">
+///         "Stuck at " {move || format!("{:?}", stuck_edge.get())}
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// Please note that the sentinels are inserted as siblings of the target element. If the parent of the
+/// element is a flex or grid container you might have to account for the additional items.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns signals that always contain `false` and `None` respectively.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_intersection_observer`]
+/// * [`fn@crate::use_element_visibility`]
+pub fn use_sticky<El, M>(target: El) -> UseStickyReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_sticky_with_options::<El, M, web_sys::Element, _>(target, UseStickyOptions::default())
+}
+
+/// Version of [`use_sticky`] that takes a `UseStickyOptions`. See [`use_sticky`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_sticky_with_options<El, M, ContainerEl, ContainerM>(
+    target: El,
+    options: UseStickyOptions<ContainerEl, ContainerM>,
+) -> UseStickyReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    ContainerEl: IntoElementMaybeSignal<web_sys::Element, ContainerM>,
+{
+    let UseStickyOptions {
+        viewport,
+        top,
+        bottom,
+        ..
+    } = options;
+
+    let (stuck_top, set_stuck_top) = signal(false);
+    let (stuck_bottom, set_stuck_bottom) = signal(false);
+
+    cfg_if! { if #[cfg(not(feature = "ssr"))] {
+        let target = target.into_element_maybe_signal();
+        let viewport = viewport.map(|viewport| viewport.into_element_maybe_signal());
+
+        let (top_sentinel, set_top_sentinel) = signal_local(None::<web_sys::Element>);
+        let (bottom_sentinel, set_bottom_sentinel) = signal_local(None::<web_sys::Element>);
+
+        let remove_sentinels = move || {
+            for sentinel in [top_sentinel, bottom_sentinel] {
+                if let Some(Some(sentinel)) = sentinel.try_get_untracked() {
+                    sentinel.remove();
+                }
+            }
+        };
+
+        let _ = watch_with_options(
+            move || target.get(),
+            move |el, _, _| {
+                remove_sentinels();
+                set_top_sentinel.set(None);
+                set_bottom_sentinel.set(None);
+                set_stuck_top.set(false);
+                set_stuck_bottom.set(false);
+
+                if let Some(el) = el {
+                    if top.is_some() {
+                        set_top_sentinel
+                            .set(insert_sentinel(el, "beforebegin", "margin-bottom: -1px"));
+                    }
+                    if bottom.is_some() {
+                        set_bottom_sentinel
+                            .set(insert_sentinel(el, "afterend", "margin-top: -1px"));
+                    }
+                }
+            },
+            WatchOptions::default().immediate(true),
+        );
+
+        on_cleanup(remove_sentinels);
+
+        if let Some(top) = top {
+            use_intersection_observer_with_options(
+                top_sentinel,
+                move |entries, _| {
+                    if let Some(entry) = entries.last() {
+                        let root_top = entry.root_bounds().map(|r| r.top()).unwrap_or(top);
+
+                        set_stuck_top.set(
+                            !entry.is_intersecting()
+                                && entry.bounding_client_rect().top() < root_top,
+                        );
+                    }
+                },
+                UseIntersectionObserverOptions::default()
+                    .root(viewport.clone())
+                    .root_margin(format!("{}px 0px 0px 0px", -top)),
+            );
+        }
+
+        if let Some(bottom) = bottom {
+            use_intersection_observer_with_options(
+                bottom_sentinel,
+                move |entries, _| {
+                    if let Some(entry) = entries.last() {
+                        let root_bottom = entry.root_bounds().map(|r| r.bottom()).unwrap_or_else(|| {
+                            window()
+                                .inner_height()
+                                .ok()
+                                .and_then(|h| h.as_f64())
+                                .unwrap_or_default()
+                                - bottom
+                        });
+
+                        set_stuck_bottom.set(
+                            !entry.is_intersecting()
+                                && entry.bounding_client_rect().bottom() > root_bottom,
+                        );
+                    }
+                },
+                UseIntersectionObserverOptions::default()
+                    .root(viewport)
+                    .root_margin(format!("0px 0px {}px 0px", -bottom)),
+            );
+        }
+    }}
+
+    UseStickyReturn {
+        is_stuck: Signal::derive(move || stuck_top.get() || stuck_bottom.get()),
+        stuck_edge: Signal::derive(move || {
+            if stuck_top.get() {
+                Some(Direction::Top)
+            } else if stuck_bottom.get() {
+                Some(Direction::Bottom)
+            } else {
+                None
+            }
+        }),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn insert_sentinel(
+    el: &web_sys::Element,
+    position: &str,
+    margin: &str,
+) -> Option<web_sys::Element> {
+    let sentinel = document().create_element("div").ok()?;
+
+    let _ = sentinel.set_attribute("aria-hidden", "true");
+    let _ = sentinel.set_attribute(
+        "style",
+        &format!("height: 1px; {margin}; visibility: hidden; pointer-events: none"),
+    );
+
+    el.insert_adjacent_element(position, &sentinel).ok()?;
+
+    Some(sentinel)
+}
+
+/// Options for [`use_sticky_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseStickyOptions<El, M>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    /// The scroll container the sticky element is pinned to.
+    /// Defaults to `None` (which means the viewport will be used).
+    viewport: Option<El>,
+
+    /// The CSS `top` value (in px) of the sticky element. If `None` the top edge is not observed.
+    /// Defaults to `Some(0.0)`.
+    top: Option<f64>,
+
+    /// The CSS `bottom` value (in px) of the sticky element. If `None` the bottom edge is not observed.
+    /// Defaults to `None`.
+    bottom: Option<f64>,
+
+    #[builder(skip)]
+    _marker: PhantomData<M>,
+}
+
+impl<M> Default for UseStickyOptions<web_sys::Element, M>
+where
+    web_sys::Element: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    fn default() -> Self {
+        Self {
+            viewport: None,
+            top: Some(0.0),
+            bottom: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Return type of [`use_sticky`].
+pub struct UseStickyReturn {
+    /// `true` if the element is currently stuck to any of the observed edges.
+    pub is_stuck: Signal<bool>,
+
+    /// The edge the element is currently stuck to or `None` if it isn't stuck.
+    pub stuck_edge: Signal<Option<Direction>>,
+}
@@ -0,0 +1,208 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, sendwrap_fn, use_interval_fn, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive storage quota and persistence of the [Storage API](https://developer.mozilla.org/en-US/docs/Web/API/Storage_API).
+///
+/// Reads how much storage the origin uses and may use with `navigator.storage.estimate()` and whether the
+/// storage is persistent, i.e. won't be cleared by the browser when the device runs low on disk space.
+/// Offline apps can use this to warn the user before the storage runs out.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_storage_quota)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_storage_quota, UseStorageQuotaReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseStorageQuotaReturn {
+///     usage,
+///     quota,
+///     is_running_low,
+///     is_persisted,
+///     request_persistence,
+///     ..
+/// } = use_storage_quota();
+///
+/// view! {
+///     <p>{move || format!("{:?} of {:?} bytes used", usage.get(), quota.get())}</p>
+///     <Show when=move || is_running_low.get()>"You are running out of storage!"</Show>
+///     <button on:click=move |_| request_persistence() disabled=is_persisted>
+///         "Keep my offline data"
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// The estimate is read once. Call `refresh` after storing data or set the option `interval` to
+/// read it periodically. `is_running_low` becomes `true` when the usage exceeds the option
+/// `low_ratio` of the quota.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_storage_quota_with_options, UseStorageQuotaOptions, UseStorageQuotaReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseStorageQuotaReturn { is_running_low, .. } = use_storage_quota_with_options(
+///     UseStorageQuotaOptions::default()
+///         .interval(Some(60_000))
+///         .low_ratio(0.8),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `request_persistence` and `refresh` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_storage_quota`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `usage` and `quota` are `None`, the boolean signals are `false` and the returned
+/// closures do nothing.
+pub fn use_storage_quota(
+) -> UseStorageQuotaReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_storage_quota_with_options(UseStorageQuotaOptions::default())
+}
+
+/// Version of [`use_storage_quota`] that takes a `UseStorageQuotaOptions`. See [`use_storage_quota`] for how to use.
+pub fn use_storage_quota_with_options(
+    options: UseStorageQuotaOptions,
+) -> UseStorageQuotaReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseStorageQuotaOptions {
+        interval,
+        low_ratio,
+    } = options;
+
+    let is_supported = use_supported(|| js!("storage" in &window().navigator()));
+
+    let (usage, set_usage) = signal(None::<f64>);
+    let (quota, set_quota) = signal(None::<f64>);
+    let (is_persisted, set_is_persisted) = signal(false);
+
+    let refresh = move || {
+        #[cfg(not(feature = "ssr"))]
+        if is_supported.get_untracked() {
+            leptos::task::spawn_local(async move {
+                let storage = window().navigator().storage();
+
+                if let Some(estimate) = storage_promise(storage.estimate()).await {
+                    let estimate = web_sys::StorageEstimate::from(estimate);
+                    set_usage.try_set(estimate.get_usage());
+                    set_quota.try_set(estimate.get_quota());
+                }
+
+                if let Some(persisted) = storage_promise(storage.persisted()).await {
+                    set_is_persisted.try_set(persisted.as_bool().unwrap_or_default());
+                }
+            });
+        }
+    };
+
+    refresh();
+
+    if let Some(interval) = interval {
+        let _ = use_interval_fn(refresh, interval);
+    }
+
+    let request_persistence = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        if is_supported.get_untracked() {
+            leptos::task::spawn_local(async move {
+                if let Some(persisted) =
+                    storage_promise(window().navigator().storage().persist()).await
+                {
+                    set_is_persisted.try_set(persisted.as_bool().unwrap_or_default());
+                }
+            });
+        }
+    });
+
+    let usage_ratio = Signal::derive(move || match (usage.get(), quota.get()) {
+        (Some(usage), Some(quota)) if quota > 0.0 => Some(usage / quota),
+        _ => None,
+    });
+
+    UseStorageQuotaReturn {
+        is_supported,
+        usage: usage.into(),
+        quota: quota.into(),
+        usage_ratio,
+        is_running_low: Signal::derive(move || {
+            usage_ratio.get().is_some_and(|ratio| ratio >= low_ratio)
+        }),
+        is_persisted: is_persisted.into(),
+        request_persistence,
+        refresh: sendwrap_fn!(move || refresh()),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn storage_promise(
+    promise: Result<js_sys::Promise, wasm_bindgen::JsValue>,
+) -> Option<wasm_bindgen::JsValue> {
+    crate::js_fut!(promise.ok()?).await.ok()
+}
+
+/// Options for [`use_storage_quota_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseStorageQuotaOptions {
+    /// If set the estimate is read again every `interval` milliseconds. Defaults to `None`.
+    interval: Option<u64>,
+
+    /// The ratio of usage to quota from which on `is_running_low` is `true`. Defaults to `0.9`.
+    low_ratio: f64,
+}
+
+impl Default for UseStorageQuotaOptions {
+    fn default() -> Self {
+        Self {
+            interval: None,
+            low_ratio: 0.9,
+        }
+    }
+}
+
+/// Return type of [`use_storage_quota`].
+pub struct UseStorageQuotaReturn<RequestPersistenceFn, RefreshFn>
+where
+    RequestPersistenceFn: Fn() + Clone + Send + Sync,
+    RefreshFn: Fn() + Clone + Send + Sync,
+{
+    /// `true` if the browser supports the Storage API
+    pub is_supported: Signal<bool>,
+
+    /// Estimated number of bytes used by the origin
+    pub usage: Signal<Option<f64>>,
+
+    /// Estimated number of bytes available to the origin
+    pub quota: Signal<Option<f64>>,
+
+    /// `usage` divided by `quota`
+    pub usage_ratio: Signal<Option<f64>>,
+
+    /// `true` if `usage_ratio` reached the option `low_ratio`
+    pub is_running_low: Signal<bool>,
+
+    /// `true` if the storage of the origin is persistent
+    pub is_persisted: Signal<bool>,
+
+    /// Asks the browser to make the storage persistent. Browsers might grant this without asking the user,
+    /// for example for installed apps, or deny it. Updates `is_persisted`.
+    pub request_persistence: RequestPersistenceFn,
+
+    /// Reads the estimate and the persistence again
+    pub refresh: RefreshFn,
+}
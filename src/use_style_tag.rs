@@ -0,0 +1,266 @@
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Inject a reactive `<style>` element into the `<head>` of the document.
+///
+/// The style element is added when the component is mounted (unless `immediate` is `false`) and
+/// removed again when the component is cleaned up. Its content is updated whenever the `css` signal changes.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_style_tag)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_style_tag, UseStyleTagReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (color, set_color) = signal("red".to_string());
+///
+/// let UseStyleTagReturn { is_loaded, .. } =
+///     use_style_tag(Signal::derive(move || format!(".warning {{ color: {}; }}", color.get())));
+///
+/// view! {
+///     <p class="warning">"Careful!"</p>
+/// }
+/// # }
+/// ```
+///
+/// ## Scoping
+///
+/// With the option `scoped` the CSS is nested inside of the attribute selector
+/// `[data-leptos-use-style="<scope_id>"]` with a generated `scope_id`. Put the attribute `data-leptos-use-style`
+/// with this value on an element to apply the styles only to it and its descendants.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_style_tag_with_options, UseStyleTagOptions, UseStyleTagReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseStyleTagReturn { scope_id, .. } = use_style_tag_with_options(
+///     "p { font-weight: bold; }",
+///     UseStyleTagOptions::default()
+///         .scoped(true)
+///         .media(Some("screen".to_string())),
+/// );
+///
+/// view! {
+///     <div data-leptos-use-style=scope_id>
+///         <p>"Only this paragraph is bold"</p>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// Please note that scoping relies on [CSS nesting](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_nesting).
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `load` and `unload` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_style_tag`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no style element is injected, `is_loaded` is always `false` and the returned closures are no-ops.
+pub fn use_style_tag<S>(
+    css: S,
+) -> UseStyleTagReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    S: Into<Signal<String>>,
+{
+    use_style_tag_with_options(css, UseStyleTagOptions::default())
+}
+
+/// Version of [`use_style_tag`] that takes a `UseStyleTagOptions`. See [`use_style_tag`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_style_tag_with_options<S>(
+    css: S,
+    options: UseStyleTagOptions,
+) -> UseStyleTagReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    S: Into<Signal<String>>,
+{
+    let UseStyleTagOptions {
+        id,
+        media,
+        immediate,
+        scoped,
+    } = options;
+
+    let css = css.into();
+
+    let index = next_index();
+    let id = id.unwrap_or_else(|| format!("leptos-use-style-{index}"));
+    let scope_id = scoped.then(|| index.to_string());
+
+    let (is_loaded, set_loaded) = signal(false);
+
+    let load;
+    let unload;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_loaded;
+
+        load = || {};
+        unload = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::sendwrap_fn;
+
+        let content = {
+            let scope_id = scope_id.clone();
+
+            move |css: String| match &scope_id {
+                Some(scope_id) => format!("[data-leptos-use-style=\"{scope_id}\"] {{\n{css}\n}}"),
+                None => css,
+            }
+        };
+
+        let update = {
+            let id = id.clone();
+            let content = content.clone();
+
+            move |css: String, media: Option<String>| {
+                if let Some(el) = document().get_element_by_id(&id) {
+                    el.set_text_content(Some(&content(css)));
+
+                    let _ = match media {
+                        Some(media) => el.set_attribute("media", &media),
+                        None => el.remove_attribute("media"),
+                    };
+                }
+            }
+        };
+
+        load = {
+            let id = id.clone();
+            let update = update.clone();
+
+            sendwrap_fn!(move || {
+                if is_loaded.get_untracked() {
+                    return;
+                }
+
+                if document().get_element_by_id(&id).is_none() {
+                    let Ok(el) = document().create_element("style") else {
+                        return;
+                    };
+
+                    el.set_id(&id);
+
+                    if let Some(head) = document().head() {
+                        let _ = head.append_child(&el);
+                    }
+                }
+
+                update(css.get_untracked(), media.get_untracked());
+                set_loaded.set(true);
+            })
+        };
+
+        unload = {
+            let id = id.clone();
+
+            sendwrap_fn!(move || {
+                if let Some(el) = document().get_element_by_id(&id) {
+                    el.remove();
+                }
+
+                // this is also called on cleanup when the signal might already be disposed
+                let _ = set_loaded.try_set(false);
+            })
+        };
+
+        let _ = Effect::watch(
+            move || (css.get(), media.get()),
+            move |(css, media), _, _| {
+                if is_loaded.get_untracked() {
+                    update(css.clone(), media.clone());
+                }
+            },
+            false,
+        );
+
+        if immediate {
+            Effect::new(load.clone());
+        }
+
+        on_cleanup(unload.clone());
+    }
+
+    UseStyleTagReturn {
+        id,
+        scope_id,
+        is_loaded: is_loaded.into(),
+        load,
+        unload,
+    }
+}
+
+fn next_index() -> usize {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    COUNTER.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Options for [`use_style_tag_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseStyleTagOptions {
+    /// The `id` of the style element. Defaults to a generated unique id.
+    #[builder(into)]
+    id: Option<String>,
+
+    /// The `media` attribute of the style element. Defaults to `None`.
+    #[builder(into)]
+    media: Signal<Option<String>>,
+
+    /// Inject the style element as soon as the component is mounted. Defaults to `true`.
+    immediate: bool,
+
+    /// Nest the CSS inside of a generated attribute selector. Defaults to `false`.
+    scoped: bool,
+}
+
+impl Default for UseStyleTagOptions {
+    fn default() -> Self {
+        Self {
+            id: None,
+            media: Signal::default(),
+            immediate: true,
+            scoped: false,
+        }
+    }
+}
+
+/// Return type of [`use_style_tag`].
+pub struct UseStyleTagReturn<LoadFn, UnloadFn>
+where
+    LoadFn: Fn() + Clone + Send + Sync,
+    UnloadFn: Fn() + Clone + Send + Sync,
+{
+    /// The `id` of the style element
+    pub id: String,
+
+    /// Value of the attribute `data-leptos-use-style` that scopes the styles if the option `scoped` is `true`
+    pub scope_id: Option<String>,
+
+    /// `true` while the style element is in the document
+    pub is_loaded: Signal<bool>,
+
+    /// Inject the style element
+    pub load: LoadFn,
+
+    /// Remove the style element
+    pub unload: UnloadFn,
+}
@@ -0,0 +1,235 @@
+use crate::core::LoadStatus;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Load an external stylesheet on demand by injecting a `<link rel="stylesheet">` element into
+/// the `<head>` of the document.
+///
+/// The returned `status` reports if the stylesheet is still loading, has loaded or failed to load.
+/// Multiple components that request the same `href` share a single link element which is only
+/// removed after the last of them has unloaded it.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_stylesheet)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_stylesheet, UseStylesheetReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseStylesheetReturn { is_loaded, .. } = use_stylesheet("/themes/dark.css");
+///
+/// view! {
+///     <Show when=move || is_loaded.get() fallback=|| "Loading theme...">
+///         "Theme loaded"
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// By default the stylesheet is loaded as soon as the component is mounted and removed when it's cleaned up.
+/// Set the option `immediate` to `false` to load it manually with `load` and `unload_on_cleanup` to `false`
+/// to keep it in the document.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_stylesheet_with_options, UseStylesheetOptions, UseStylesheetReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseStylesheetReturn { load, .. } = use_stylesheet_with_options(
+///     "/print.css",
+///     UseStylesheetOptions::default()
+///         .immediate(false)
+///         .media("print".to_string()),
+/// );
+///
+/// view! { <button on:click=move |_| load()>"Enable print styles"</button> }
+/// # }
+/// ```
+///
+/// Please note that the options of the first component that loads a stylesheet are used for the shared
+/// link element.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `load` and `unload` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_stylesheet`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no stylesheet is loaded, the status is always `LoadStatus::Idle` and the returned closures are no-ops.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_script_tag`]
+/// * [`fn@crate::use_style_tag`]
+pub fn use_stylesheet(
+    href: impl Into<String>,
+) -> UseStylesheetReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_stylesheet_with_options(href, UseStylesheetOptions::default())
+}
+
+/// Version of [`use_stylesheet`] that takes a `UseStylesheetOptions`. See [`use_stylesheet`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_stylesheet_with_options(
+    href: impl Into<String>,
+    options: UseStylesheetOptions,
+) -> UseStylesheetReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseStylesheetOptions {
+        immediate,
+        unload_on_cleanup,
+        media,
+        attrs,
+    } = options;
+
+    let href = href.into();
+
+    // the status shared by all users of the same link element
+    let shared_status = RwSignal::new(None::<ArcRwSignal<LoadStatus>>);
+
+    let load;
+    let unload;
+
+    #[cfg(feature = "ssr")]
+    {
+        load = || {};
+        unload = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::sendwrap_fn;
+        use crate::utils::{acquire_head_element, release_head_element};
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        // whether this instance currently holds a reference to the link element
+        let acquired = Rc::new(Cell::new(false));
+
+        load = {
+            let href = href.clone();
+            let acquired = Rc::clone(&acquired);
+
+            sendwrap_fn!(move || {
+                if acquired.replace(true) {
+                    return;
+                }
+
+                let status = acquire_head_element("link", &href, |element| {
+                    for (name, value) in &attrs {
+                        let _ = element.set_attribute(name, value);
+                    }
+                    if let Some(media) = &media {
+                        let _ = element.set_attribute("media", media);
+                    }
+                    let _ = element.set_attribute("rel", "stylesheet");
+                    let _ = element.set_attribute("href", &href);
+                });
+                // the element couldn't be created so there is nothing to release
+                if status.is_none() {
+                    acquired.set(false);
+                }
+
+                shared_status.set(Some(
+                    status.unwrap_or_else(|| ArcRwSignal::new(LoadStatus::Error)),
+                ));
+            })
+        };
+
+        unload = {
+            let href = href.clone();
+
+            sendwrap_fn!(move || {
+                if !acquired.replace(false) {
+                    return;
+                }
+
+                release_head_element("link", &href);
+
+                // this is also called on cleanup when the signal might already be disposed
+                let _ = shared_status.try_set(None);
+            })
+        };
+
+        if immediate {
+            Effect::new(load.clone());
+        }
+
+        if unload_on_cleanup {
+            on_cleanup(unload.clone());
+        }
+    }
+
+    let status = Signal::derive(move || {
+        shared_status
+            .get()
+            .map(|status| status.get())
+            .unwrap_or_default()
+    });
+
+    UseStylesheetReturn {
+        status,
+        is_loaded: Signal::derive(move || status.get() == LoadStatus::Loaded),
+        is_error: Signal::derive(move || status.get() == LoadStatus::Error),
+        load,
+        unload,
+    }
+}
+
+/// Options for [`use_stylesheet_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseStylesheetOptions {
+    /// Load the stylesheet as soon as the component is mounted. Defaults to `true`.
+    immediate: bool,
+
+    /// Unload the stylesheet when the component is cleaned up. The link element is only removed when
+    /// no other component uses it anymore. Defaults to `true`.
+    unload_on_cleanup: bool,
+
+    /// The `media` attribute of the link element. Defaults to `None`.
+    #[builder(into)]
+    media: Option<String>,
+
+    /// Additional attributes of the link element like `crossorigin` or `integrity`. Defaults to `[]`.
+    attrs: Vec<(String, String)>,
+}
+
+impl Default for UseStylesheetOptions {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            unload_on_cleanup: true,
+            media: None,
+            attrs: vec![],
+        }
+    }
+}
+
+/// Return type of [`use_stylesheet`].
+pub struct UseStylesheetReturn<LoadFn, UnloadFn>
+where
+    LoadFn: Fn() + Clone + Send + Sync,
+    UnloadFn: Fn() + Clone + Send + Sync,
+{
+    /// The loading status of the stylesheet
+    pub status: Signal<LoadStatus>,
+
+    /// `true` as soon as the stylesheet has loaded
+    pub is_loaded: Signal<bool>,
+
+    /// `true` if the stylesheet failed to load
+    pub is_error: Signal<bool>,
+
+    /// Load the stylesheet. If another component has loaded the same stylesheet already, it's not loaded again.
+    pub load: LoadFn,
+
+    /// Unload the stylesheet. The link element is removed when no other component uses it anymore.
+    pub unload: UnloadFn,
+}
@@ -0,0 +1,397 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::{ElementMaybeSignal, IntoElementMaybeSignal, MaybeRwSignal};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Reactive [dir](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/dir) of the element's text.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_text_direction)
+///
+/// ## Usage
+///
+/// By default the `dir` attribute of the `<html>` element is controlled.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_text_direction, TextDirection, UseTextDirectionReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseTextDirectionReturn { dir, set_dir, .. } = use_text_direction();
+///
+/// set_dir.set(TextDirection::Rtl);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Auto Detection
+///
+/// With [`TextDirection::Auto`] and a bound `value` signal the direction is inferred from the
+/// first strong (left-to-right or right-to-left) character of the value. The inferred
+/// `"ltr"` or `"rtl"` is applied to the element and reported by `resolved`. This is handy for
+/// inputs where users type in different scripts.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Input;
+/// # use leptos_use::{use_text_direction_with_options, TextDirection, UseTextDirectionOptions, UseTextDirectionReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Input>::new();
+/// let (text, set_text) = signal("שלום".to_string());
+///
+/// let UseTextDirectionReturn { resolved, .. } = use_text_direction_with_options(
+///     UseTextDirectionOptions::default()
+///         .target(el)
+///         .initial_value(TextDirection::Auto)
+///         .value(text),
+/// );
+///
+/// view! {
+///     <input node_ref=el bind:value=(text, set_text) />
+///     <p>"Direction: " {move || resolved.get().to_string()}</p>
+/// }
+/// # }
+/// ```
+///
+/// If no `value` is given or it contains no strong character, `dir="auto"` is applied and the
+/// browser decides. `resolved` then reports the computed direction of the element.
+///
+/// The function [`detect_text_direction`] exposes the detection on its own.
+///
+/// ## Document Direction
+///
+/// The returned `document_dir` follows the `dir` attribute of the `<html>` element, including
+/// changes made from outside of this function. This is useful for components that have to mirror
+/// their layout (e.g. icons or slide-in panels) according to the page direction.
+///
+/// ## Observe
+///
+/// Set the option `observe` to `true` to keep `dir` in sync when the `dir` attribute of the target
+/// is changed by someone else.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the attribute is not applied. `document_dir` is always [`TextDirection::Ltr`]
+/// and `resolved` falls back to it when it can't be inferred from the `value`.
+pub fn use_text_direction() -> UseTextDirectionReturn {
+    use_text_direction_with_options(UseTextDirectionOptions::default())
+}
+
+/// Version of [`use_text_direction`] that takes a `UseTextDirectionOptions`. See [`use_text_direction`] for how to use.
+pub fn use_text_direction_with_options<El, M>(
+    options: UseTextDirectionOptions<El, M>,
+) -> UseTextDirectionReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    M: ?Sized,
+{
+    let UseTextDirectionOptions {
+        target,
+        initial_value,
+        value,
+        observe,
+        ..
+    } = options;
+
+    let (dir, set_dir) = initial_value.into_signal();
+
+    let document_dir = use_document_dir();
+
+    // direction computed by the browser while `dir="auto"` is applied
+    let computed_dir = RwSignal::new(None::<TextDirection>);
+
+    let applied = Signal::derive(move || match dir.get() {
+        TextDirection::Auto => value
+            .and_then(|value| value.with(|v| detect_text_direction(v)))
+            .unwrap_or(TextDirection::Auto),
+        dir => dir,
+    });
+
+    let resolved = Signal::derive(move || match applied.get() {
+        TextDirection::Auto => computed_dir.get().unwrap_or_else(|| document_dir.get()),
+        dir => dir,
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{use_mutation_observer_with_options, UseMutationObserverOptions};
+
+        let target = target.into_element_maybe_signal();
+
+        // last attribute value set by us to tell our own mutations apart from foreign ones
+        let last_applied = StoredValue::new(None::<String>);
+
+        Effect::new({
+            let target = target.clone();
+
+            move || {
+                let applied = applied.get();
+
+                if let Some(el) = target.get() {
+                    let attr = applied.to_string();
+                    let _ = el.set_attribute("dir", &attr);
+                    last_applied.set_value(Some(attr));
+
+                    computed_dir.set(if applied == TextDirection::Auto {
+                        computed_direction(&el)
+                    } else {
+                        None
+                    });
+                }
+            }
+        });
+
+        if observe {
+            let _ = use_mutation_observer_with_options(
+                target.clone(),
+                move |_, _| {
+                    let Some(el) = target.get_untracked() else {
+                        return;
+                    };
+
+                    let attr = el.get_attribute("dir");
+                    if attr == last_applied.get_value() {
+                        return;
+                    }
+
+                    last_applied.set_value(attr.clone());
+                    set_dir.set(attr.as_deref().map(TextDirection::from).unwrap_or_default());
+                },
+                UseMutationObserverOptions::default()
+                    .attributes(true)
+                    .attribute_filter(vec!["dir".to_string()]),
+            );
+        }
+    }
+
+    UseTextDirectionReturn {
+        dir,
+        set_dir,
+        resolved,
+        document_dir,
+    }
+}
+
+/// Returns the direction of the first strong character in `text` or `None` if there isn't any.
+///
+/// Digits, punctuation and whitespace are weak or neutral and are skipped.
+///
+/// ```
+/// # use leptos_use::{detect_text_direction, TextDirection};
+/// #
+/// assert_eq!(detect_text_direction("123 مرحبا hello"), Some(TextDirection::Rtl));
+/// assert_eq!(detect_text_direction("  (Hello) שלום"), Some(TextDirection::Ltr));
+/// assert_eq!(detect_text_direction("42 - ?"), None);
+/// ```
+pub fn detect_text_direction(text: &str) -> Option<TextDirection> {
+    text.chars().find_map(|c| {
+        if is_strong_rtl(c) {
+            Some(TextDirection::Rtl)
+        } else if is_strong_ltr(c) {
+            Some(TextDirection::Ltr)
+        } else {
+            None
+        }
+    })
+}
+
+fn is_strong_rtl(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0591}'..='\u{08FF}'
+            | '\u{200F}'
+            | '\u{FB1D}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFC}'
+            | '\u{10800}'..='\u{10FFF}'
+            | '\u{1E800}'..='\u{1EFFF}'
+    ) && !matches!(c, '\u{0660}'..='\u{0669}' | '\u{06F0}'..='\u{06F9}')
+}
+
+fn is_strong_ltr(c: char) -> bool {
+    match c {
+        'A'..='Z' | 'a'..='z' => true,
+        '\u{00C0}'..='\u{00D6}'
+        | '\u{00D8}'..='\u{00F6}'
+        | '\u{00F8}'..='\u{02B8}'
+        | '\u{0370}'..='\u{058F}'
+        | '\u{0900}'..='\u{1FFF}'
+        | '\u{200E}'
+        | '\u{2C00}'..='\u{FB1C}'
+        | '\u{FE00}'..='\u{FE6F}'
+        | '\u{FF21}'..='\u{FF3A}'
+        | '\u{FF41}'..='\u{FF5A}'
+        | '\u{FF66}'..='\u{FFDC}' => c.is_alphabetic(),
+        c if c > '\u{FFFF}' => c.is_alphabetic(),
+        _ => false,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn computed_direction(el: &web_sys::Element) -> Option<TextDirection> {
+    let style = window().get_computed_style(el).ok()??;
+    let direction = style.get_property_value("direction").ok()?;
+
+    match TextDirection::from(direction.as_str()) {
+        TextDirection::Auto => None,
+        dir => Some(dir),
+    }
+}
+
+fn use_document_dir() -> Signal<TextDirection> {
+    cfg_if::cfg_if! { if #[cfg(feature = "ssr")] {
+        Signal::stored(TextDirection::Ltr)
+    } else {
+        use crate::{use_mutation_observer_with_options, UseMutationObserverOptions};
+
+        let read_dir = || {
+            document().document_element().and_then(|html| {
+                match html.get_attribute("dir").as_deref().map(TextDirection::from) {
+                    Some(TextDirection::Auto) | None => computed_direction(&html),
+                    dir => dir,
+                }
+            })
+            .unwrap_or_default()
+        };
+
+        let (document_dir, set_document_dir) = signal(read_dir());
+
+        let html: ElementMaybeSignal<web_sys::Element> = "html".into_element_maybe_signal();
+
+        let _ = use_mutation_observer_with_options(
+            html,
+            move |_, _| set_document_dir.set(read_dir()),
+            UseMutationObserverOptions::default()
+                .attributes(true)
+                .attribute_filter(vec!["dir".to_string()]),
+        );
+
+        document_dir.into()
+    }}
+}
+
+/// Direction of text as used by the HTML attribute `dir`
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug, Hash)]
+pub enum TextDirection {
+    /// Left to right
+    #[default]
+    Ltr,
+    /// Right to left
+    Rtl,
+    /// Inferred from the content
+    Auto,
+}
+
+impl Display for TextDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use TextDirection::*;
+
+        match self {
+            Ltr => write!(f, "ltr"),
+            Rtl => write!(f, "rtl"),
+            Auto => write!(f, "auto"),
+        }
+    }
+}
+
+impl From<&str> for TextDirection {
+    fn from(s: &str) -> Self {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "rtl" => TextDirection::Rtl,
+            "auto" => TextDirection::Auto,
+            _ => TextDirection::Ltr,
+        }
+    }
+}
+
+impl From<String> for TextDirection {
+    fn from(s: String) -> Self {
+        TextDirection::from(s.as_str())
+    }
+}
+
+impl FromStr for TextDirection {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(TextDirection::from(s))
+    }
+}
+
+/// Options for [`use_text_direction_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTextDirectionOptions<El, M>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    M: ?Sized,
+{
+    /// Element that the `dir` attribute will be applied to. Defaults to `"html"`.
+    target: El,
+
+    /// Initial direction. Can be a signal to control it from the outside.
+    /// Defaults to [`TextDirection::Ltr`].
+    #[builder(into)]
+    initial_value: MaybeRwSignal<TextDirection>,
+
+    /// Text that the direction is inferred from in [`TextDirection::Auto`] mode.
+    /// Defaults to `None` which leaves the detection to the browser.
+    #[builder(skip)]
+    value: Option<Signal<String>>,
+
+    /// Update `dir` when the `dir` attribute of the target is changed from the outside.
+    /// Defaults to `false`.
+    observe: bool,
+
+    #[builder(skip)]
+    _marker: PhantomData<M>,
+}
+
+impl<El, M> UseTextDirectionOptions<El, M>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    M: ?Sized,
+{
+    /// Text that the direction is inferred from in [`TextDirection::Auto`] mode.
+    pub fn value(self, value: impl Into<Signal<String>>) -> Self {
+        Self {
+            value: Some(value.into()),
+            ..self
+        }
+    }
+}
+
+impl Default for UseTextDirectionOptions<&'static str, str> {
+    fn default() -> Self {
+        Self {
+            target: "html",
+            initial_value: TextDirection::Ltr.into(),
+            value: None,
+            observe: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Return type of [`use_text_direction`].
+pub struct UseTextDirectionReturn {
+    /// The requested direction. Can be [`TextDirection::Auto`].
+    pub dir: Signal<TextDirection>,
+
+    /// Sets the requested direction
+    pub set_dir: WriteSignal<TextDirection>,
+
+    /// The effective direction. Is never [`TextDirection::Auto`].
+    pub resolved: Signal<TextDirection>,
+
+    /// Direction of the document (`<html>` element)
+    pub document_dir: Signal<TextDirection>,
+}
@@ -0,0 +1,582 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::now;
+use crate::js;
+use crate::utils::{js_value_from_to_string, Pausable};
+use crate::{use_interval_fn_with_options, UseIntervalFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use std::fmt::Display;
+use std::sync::Arc;
+use wasm_bindgen::JsValue;
+
+/// Reactive time ago formatting like "3 minutes ago" or "in 2 days".
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_timeago)
+///
+/// ## Usage
+///
+/// The time is given in milliseconds since the Unix epoch, the same representation that
+/// [`fn@crate::use_timestamp`] and `js_sys::Date::now()` use.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_timeago;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (time, set_time) = signal(js_sys::Date::now() - 3.0 * 60_000.0);
+///
+/// let time_ago = use_timeago(time); // "3 minutes ago"
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Without further options the messages are in English. Times in the past are formatted as
+/// "3 minutes ago" or "yesterday", times in the future as "in 3 minutes" or "tomorrow".
+///
+/// ## Localization
+///
+/// Provide [`UseTimeAgoOptions::intl`] to format the messages with
+/// [`Intl.RelativeTimeFormat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat)
+/// in the given locales. The Intl options can be a signal, e.g. to follow the locale picked by the user.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_timeago_with_options, RelativeTimeStyle, TimeAgoIntlOptions, UseTimeAgoOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let time = js_sys::Date::now() - 86_400_000.0;
+/// let (locale, set_locale) = signal("de-DE".to_string());
+///
+/// let time_ago = use_timeago_with_options(
+///     time,
+///     UseTimeAgoOptions::default().intl(Signal::derive(move || {
+///         TimeAgoIntlOptions::default()
+///             .locale(&locale.get())
+///             .style(RelativeTimeStyle::Short)
+///     })),
+/// ); // "gestern", "vor 3 Std." or "in 2 Wo."
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Rounding and update intervals
+///
+/// The difference is expressed in the largest fitting unit and rounded with [`UseTimeAgoOptions::rounding`].
+/// How often the message is updated can be chosen per unit with [`UseTimeAgoOptions::update_interval`].
+/// By default seconds are updated every second, minutes every 30 seconds, hours every minute and
+/// everything larger every hour.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_timeago_with_options, TimeAgoUnit, UseTimeAgoOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let time = js_sys::Date::now() - 90.0 * 60_000.0;
+/// let time_ago = use_timeago_with_options(
+///     time,
+///     UseTimeAgoOptions::default()
+///         .show_second(true)
+///         // "1.5 hours ago" instead of "2 hours ago"
+///         .rounding(|value, unit| match unit {
+///             TimeAgoUnit::Hour | TimeAgoUnit::Day => (value * 2.0).round() / 2.0,
+///             _ => value.floor(),
+///         })
+///         .update_interval(|unit| match unit {
+///             TimeAgoUnit::Second => 1_000,
+///             _ => 60_000,
+///         }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Full date cutoff
+///
+/// Relative times become hard to read for dates that are far away. With
+/// [`UseTimeAgoOptions::full_date_after_days`] the full date is displayed instead once the
+/// difference is larger than the given number of days. It's formatted with `Intl.DateTimeFormat`
+/// in the locales of [`UseTimeAgoOptions::intl`] unless you provide your own
+/// [`UseTimeAgoOptions::full_date_formatter`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_timeago_with_options, UseTimeAgoOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let time = js_sys::Date::now() - 30.0 * 86_400_000.0;
+/// let time_ago = use_timeago_with_options(
+///     time,
+///     UseTimeAgoOptions::default().full_date_after_days(7),
+/// ); // e.g. "9/14/2024" instead of "last month"
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## With controls
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_timeago_with_controls, UseTimeAgoReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let time = js_sys::Date::now();
+/// let UseTimeAgoReturn {
+///     time_ago,
+///     is_active,
+///     pause,
+///     resume,
+/// } = use_timeago_with_controls(time);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the message is computed once in English, the Intl options and the full date
+/// cutoff are ignored there. It is not updated as there are no intervals on the server.
+pub fn use_timeago(time: impl Into<Signal<f64>>) -> Signal<String> {
+    use_timeago_with_options(time, UseTimeAgoOptions::default())
+}
+
+/// Version of [`use_timeago`] that takes a `UseTimeAgoOptions`. See [`use_timeago`] for how to use.
+pub fn use_timeago_with_options(
+    time: impl Into<Signal<f64>>,
+    options: UseTimeAgoOptions,
+) -> Signal<String> {
+    use_timeago_with_controls_and_options(time, options).time_ago
+}
+
+/// Version of [`use_timeago`] that returns controls. See [`use_timeago`] for how to use.
+pub fn use_timeago_with_controls(
+    time: impl Into<Signal<f64>>,
+) -> UseTimeAgoReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_timeago_with_controls_and_options(time, UseTimeAgoOptions::default())
+}
+
+/// Version of [`use_timeago`] that takes a `UseTimeAgoOptions` and returns controls. See [`use_timeago`] for how to use.
+pub fn use_timeago_with_controls_and_options(
+    time: impl Into<Signal<f64>>,
+    options: UseTimeAgoOptions,
+) -> UseTimeAgoReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseTimeAgoOptions {
+        show_second,
+        intl,
+        rounding,
+        update_interval,
+        full_date_after_days,
+        full_date_formatter,
+    } = options;
+
+    let time = time.into();
+
+    let (now_ms, set_now_ms) = signal(now());
+
+    let diff = Memo::new(move |_| time.get() - now_ms.get());
+    let unit = Memo::new(move |_| TimeAgoUnit::for_diff(diff.get()));
+
+    let Pausable {
+        is_active,
+        pause,
+        resume,
+    } = use_interval_fn_with_options(
+        move || set_now_ms.set(now()),
+        Signal::derive(move || update_interval(unit.get())),
+        UseIntervalFnOptions::default(),
+    );
+
+    #[cfg(not(feature = "ssr"))]
+    let relative_time_format = intl.map(|intl| {
+        Memo::new_with_compare(
+            move |_| {
+                intl.with(|intl| {
+                    SendWrapper::new(js_sys::Intl::RelativeTimeFormat::new(
+                        &js_sys::Array::from_iter(intl.locales.iter().map(JsValue::from)),
+                        &js_sys::Object::from(intl.clone()),
+                    ))
+                })
+            },
+            // a new instance is only created when the options have changed
+            |_, _| true,
+        )
+    });
+
+    let time_ago = Signal::derive(move || {
+        let diff = diff.get();
+        let abs_diff = diff.abs();
+
+        if abs_diff.is_nan() {
+            return String::new();
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        if let Some(days) = full_date_after_days {
+            if abs_diff > days as f64 * TimeAgoUnit::Day.ms() {
+                return match &full_date_formatter {
+                    Some(formatter) => formatter(time.get()),
+                    None => format_full_date(
+                        time.get(),
+                        intl.map(|intl| intl.with(|intl| intl.locales.clone()))
+                            .unwrap_or_default(),
+                    ),
+                };
+            }
+        }
+
+        let unit = unit.get();
+        let value = if abs_diff < TimeAgoUnit::Minute.ms() && !show_second {
+            0.0
+        } else {
+            rounding(abs_diff / unit.ms(), unit)
+        };
+
+        #[cfg(not(feature = "ssr"))]
+        if let Some(relative_time_format) = relative_time_format {
+            return relative_time_format.with(|relative_time_format| {
+                let value = if value == 0.0 {
+                    0.0
+                } else {
+                    value.copysign(diff)
+                };
+
+                relative_time_format.format(value, &unit.to_string()).into()
+            });
+        }
+
+        english_message(value, unit, diff < 0.0)
+    });
+
+    UseTimeAgoReturn {
+        time_ago,
+        is_active,
+        pause,
+        resume,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn format_full_date(time: f64, locales: Vec<String>) -> String {
+    let date_time_format = js_sys::Intl::DateTimeFormat::new(
+        &js_sys::Array::from_iter(locales.iter().map(JsValue::from)),
+        &js_sys::Object::new(),
+    );
+
+    date_time_format
+        .format()
+        .call1(&date_time_format, &js_sys::Date::new(&JsValue::from(time)))
+        .ok()
+        .and_then(|result| result.as_string())
+        .unwrap_or_default()
+}
+
+fn english_message(value: f64, unit: TimeAgoUnit, past: bool) -> String {
+    if value == 0.0 {
+        return "just now".to_string();
+    }
+
+    let (last, next) = match unit {
+        TimeAgoUnit::Day => ("yesterday", "tomorrow"),
+        TimeAgoUnit::Week => ("last week", "next week"),
+        TimeAgoUnit::Month => ("last month", "next month"),
+        TimeAgoUnit::Year => ("last year", "next year"),
+        _ => ("", ""),
+    };
+
+    if value == 1.0 && !last.is_empty() {
+        return if past { last } else { next }.to_string();
+    }
+
+    let plural = if value == 1.0 { "" } else { "s" };
+
+    if past {
+        format!("{value} {unit}{plural} ago")
+    } else {
+        format!("in {value} {unit}{plural}")
+    }
+}
+
+/// Unit that a time difference is expressed in by [`use_timeago`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TimeAgoUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeAgoUnit {
+    const ALL: [Self; 7] = [
+        Self::Second,
+        Self::Minute,
+        Self::Hour,
+        Self::Day,
+        Self::Week,
+        Self::Month,
+        Self::Year,
+    ];
+
+    /// Duration of one unit in milliseconds. Months are 30 days and years are 365 days.
+    pub fn ms(&self) -> f64 {
+        match self {
+            Self::Second => 1_000.0,
+            Self::Minute => 60_000.0,
+            Self::Hour => 3_600_000.0,
+            Self::Day => 86_400_000.0,
+            Self::Week => 604_800_000.0,
+            Self::Month => 2_592_000_000.0,
+            Self::Year => 31_536_000_000.0,
+        }
+    }
+
+    /// Differences below this number of milliseconds are expressed in this unit.
+    fn max(&self) -> f64 {
+        match self {
+            Self::Second => 60_000.0,
+            // 46 minutes
+            Self::Minute => 2_760_000.0,
+            // 20 hours
+            Self::Hour => 72_000_000.0,
+            // 6 days
+            Self::Day => 518_400_000.0,
+            // 4 weeks
+            Self::Week => 2_419_200_000.0,
+            // 11 months
+            Self::Month => 28_512_000_000.0,
+            Self::Year => f64::INFINITY,
+        }
+    }
+
+    fn for_diff(diff: f64) -> Self {
+        let abs_diff = diff.abs();
+
+        Self::ALL
+            .into_iter()
+            .find(|unit| abs_diff < unit.max())
+            .unwrap_or(Self::Year)
+    }
+}
+
+impl Display for TimeAgoUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Second => write!(f, "second"),
+            Self::Minute => write!(f, "minute"),
+            Self::Hour => write!(f, "hour"),
+            Self::Day => write!(f, "day"),
+            Self::Week => write!(f, "week"),
+            Self::Month => write!(f, "month"),
+            Self::Year => write!(f, "year"),
+        }
+    }
+}
+
+/// Whether to always use numeric values in [`TimeAgoIntlOptions`].
+#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RelativeTimeNumeric {
+    /// E.g., `1 day ago`
+    Always,
+    /// E.g., `yesterday`
+    #[default]
+    Auto,
+}
+
+impl Display for RelativeTimeNumeric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Always => write!(f, "always"),
+            Self::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+js_value_from_to_string!(RelativeTimeNumeric);
+
+/// The length of the messages in [`TimeAgoIntlOptions`].
+#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RelativeTimeStyle {
+    /// E.g., `in 1 month`
+    #[default]
+    Long,
+    /// E.g., `in 1 mo.`
+    Short,
+    /// E.g., `in 1 mo.`. The narrow style could be similar to the short style for some locales.
+    Narrow,
+}
+
+impl Display for RelativeTimeStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Long => write!(f, "long"),
+            Self::Short => write!(f, "short"),
+            Self::Narrow => write!(f, "narrow"),
+        }
+    }
+}
+
+js_value_from_to_string!(RelativeTimeStyle);
+
+/// Options for localizing [`use_timeago`] with `Intl.RelativeTimeFormat`.
+#[derive(DefaultBuilder, Default, Clone, PartialEq, Debug)]
+pub struct TimeAgoIntlOptions {
+    /// A vec of strings, each with a BCP 47 language tag. Please refer to the
+    /// [MDN Docs](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat#parameters)
+    /// for more info.
+    locales: Vec<String>,
+
+    /// Whether to always use numeric values or to allow phrases like "yesterday" and "now".
+    /// Defaults to `Auto`. Please note that `Always` displays "just now" as "in 0 seconds".
+    numeric: RelativeTimeNumeric,
+
+    /// The length of the messages. Defaults to `Long`.
+    style: RelativeTimeStyle,
+}
+
+impl TimeAgoIntlOptions {
+    pub fn locale(self, locale: &str) -> Self {
+        Self {
+            locales: vec![locale.to_string()],
+            ..self
+        }
+    }
+}
+
+impl From<TimeAgoIntlOptions> for js_sys::Object {
+    fn from(options: TimeAgoIntlOptions) -> Self {
+        let obj = Self::new();
+
+        js!(obj["numeric"] = options.numeric);
+        js!(obj["style"] = options.style);
+
+        obj
+    }
+}
+
+/// Options for [`use_timeago_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTimeAgoOptions {
+    /// Whether to show seconds. If `false` differences below one minute are displayed as "just now".
+    /// Defaults to `false`.
+    show_second: bool,
+
+    /// Localize the messages with `Intl.RelativeTimeFormat`. Defaults to `None` which uses English messages.
+    #[builder(into)]
+    intl: Option<Signal<TimeAgoIntlOptions>>,
+
+    /// Rounds the difference expressed in the given unit. Defaults to `f64::round`.
+    #[builder(skip)]
+    rounding: Arc<dyn Fn(f64, TimeAgoUnit) -> f64 + Send + Sync>,
+
+    /// Returns the update interval in milliseconds while the difference is expressed in the given unit.
+    #[builder(skip)]
+    update_interval: Arc<dyn Fn(TimeAgoUnit) -> u64 + Send + Sync>,
+
+    /// Display the full date instead of a relative time when the difference is larger than this
+    /// number of days. Defaults to `None` which always displays a relative time.
+    #[builder(skip)]
+    full_date_after_days: Option<u32>,
+
+    /// Formats the full date. Defaults to `Intl.DateTimeFormat` with the locales of [`UseTimeAgoOptions::intl`].
+    #[builder(skip)]
+    full_date_formatter: Option<Arc<dyn Fn(f64) -> String + Send + Sync>>,
+}
+
+impl UseTimeAgoOptions {
+    /// Rounds the difference expressed in the given unit. Defaults to `f64::round`.
+    ///
+    /// A rounded value of `0` is displayed as "just now".
+    pub fn rounding(
+        self,
+        rounding: impl Fn(f64, TimeAgoUnit) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            rounding: Arc::new(rounding),
+            ..self
+        }
+    }
+
+    /// Returns the update interval in milliseconds while the difference is expressed in the given unit.
+    pub fn update_interval(
+        self,
+        update_interval: impl Fn(TimeAgoUnit) -> u64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            update_interval: Arc::new(update_interval),
+            ..self
+        }
+    }
+
+    /// Display the full date instead of a relative time when the difference is larger than this
+    /// number of days.
+    pub fn full_date_after_days(self, days: u32) -> Self {
+        Self {
+            full_date_after_days: Some(days),
+            ..self
+        }
+    }
+
+    /// Formats the full date that is displayed once [`UseTimeAgoOptions::full_date_after_days`] is exceeded.
+    /// The argument is the time in milliseconds since the Unix epoch.
+    pub fn full_date_formatter(
+        self,
+        full_date_formatter: impl Fn(f64) -> String + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            full_date_formatter: Some(Arc::new(full_date_formatter)),
+            ..self
+        }
+    }
+}
+
+impl Default for UseTimeAgoOptions {
+    fn default() -> Self {
+        Self {
+            show_second: false,
+            intl: None,
+            rounding: Arc::new(|value, _| value.round()),
+            update_interval: Arc::new(|unit| match unit {
+                TimeAgoUnit::Second => 1_000,
+                TimeAgoUnit::Minute => 30_000,
+                TimeAgoUnit::Hour => 60_000,
+                _ => 3_600_000,
+            }),
+            full_date_after_days: None,
+            full_date_formatter: None,
+        }
+    }
+}
+
+/// Return type of [`use_timeago_with_controls`].
+pub struct UseTimeAgoReturn<PauseFn, ResumeFn>
+where
+    PauseFn: Fn() + Clone + Send + Sync,
+    ResumeFn: Fn() + Clone + Send + Sync,
+{
+    /// The formatted time ago message
+    pub time_ago: Signal<String>,
+
+    /// A Signal that indicates whether the message is updated. `false` when paused.
+    pub is_active: Signal<bool>,
+
+    /// Temporarily pause updating the message
+    pub pause: PauseFn,
+
+    /// Resume updating the message
+    pub resume: ResumeFn,
+}
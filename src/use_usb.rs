@@ -0,0 +1,482 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+pub use web_sys::{
+    UsbControlTransferParameters, UsbDeviceFilter, UsbRecipient, UsbRequestType, UsbTransferStatus,
+};
+
+/// Reactive [WebUSB API](https://developer.mozilla.org/en-US/docs/Web/API/WebUSB_API).
+///
+/// Keeps a reactive list of the USB devices the user has granted access to which is updated when
+/// a device is plugged in or unplugged. New devices are paired with [`UsbClient::request_device`].
+/// The devices are returned as [`UsbDeviceHandle`]s whose async methods claim interfaces and transfer data.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_usb)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_usb, UseUsbReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUsbReturn { devices, usb, .. } = use_usb();
+///
+/// let pair = move |_| {
+///     leptos::task::spawn_local(async move {
+///         // shows the device picker, has to be called in reaction to a user gesture
+///         let _ = usb.request_device().await;
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=pair>"Pair device"</button>
+///     <ul>
+///         {move || {
+///             devices
+///                 .get()
+///                 .into_iter()
+///                 .map(|device| view! { <li>{device.product_name()}</li> })
+///                 .collect_view()
+///         }}
+///     </ul>
+/// }
+/// # }
+/// ```
+///
+/// With the option `filters` the picker only shows matching devices.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_usb_with_options, UsbDeviceFilter, UseUsbOptions, UseUsbReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let filter = UsbDeviceFilter::new();
+/// filter.set_vendor_id(0x2341);
+///
+/// let UseUsbReturn { devices, .. } = use_usb_with_options(UseUsbOptions::default().filters(vec![filter]));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Transfers
+///
+/// Before data can be transferred the device has to be opened and an interface has to be claimed.
+/// [`UsbDeviceHandle::connect`] does both.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{UsbDeviceHandle, UsbTransferStatus};
+/// #
+/// async fn blink(device: UsbDeviceHandle) -> Result<(), leptos_use::UseUsbError> {
+///     device.connect(1, 0).await?;
+///
+///     device.transfer_out(1, b"LED ON").await?;
+///
+///     let response = device.transfer_in(2, 64).await?;
+///     if response.status == UsbTransferStatus::Ok {
+///         leptos::logging::log!("{:?}", response.data);
+///     }
+///
+///     device.close().await
+/// }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `devices` is always empty and [`UsbClient::request_device`] returns
+/// `Err(UseUsbError::Server)`.
+pub fn use_usb() -> UseUsbReturn {
+    use_usb_with_options(UseUsbOptions::default())
+}
+
+/// Version of [`use_usb`] that takes a `UseUsbOptions`. See [`use_usb`] for how to use.
+pub fn use_usb_with_options(options: UseUsbOptions) -> UseUsbReturn {
+    let UseUsbOptions { filters } = options;
+
+    let is_supported = use_supported(|| js!("usb" in &window().navigator()));
+
+    let (devices, set_devices) = signal(Vec::<UsbDeviceHandle>::new());
+
+    let usb = UsbClient {
+        is_supported,
+        set_devices,
+        filters: StoredValue::new_local(filters),
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        use crate::use_event_listener;
+        use leptos::ev::Custom;
+        use wasm_bindgen::JsCast;
+
+        let refresh = move || {
+            leptos::task::spawn_local(async move {
+                let _ = usb.refresh().await;
+            });
+        };
+
+        refresh();
+
+        for event in ["connect", "disconnect"] {
+            let _ = use_event_listener(
+                window()
+                    .navigator()
+                    .usb()
+                    .unchecked_into::<web_sys::EventTarget>(),
+                Custom::<web_sys::Event>::new(event),
+                move |_| refresh(),
+            );
+        }
+    }
+
+    UseUsbReturn {
+        is_supported,
+        devices: devices.into(),
+        usb,
+    }
+}
+
+/// Pairs new devices. Returned by [`use_usb`].
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UsbClient {
+    is_supported: Signal<bool>,
+    set_devices: WriteSignal<Vec<UsbDeviceHandle>>,
+    filters: StoredValue<Vec<UsbDeviceFilter>, LocalStorage>,
+}
+
+impl UsbClient {
+    /// Shows the device picker and returns the selected device. The device is also added to `devices`.
+    /// Has to be called in reaction to a user gesture.
+    pub async fn request_device(&self) -> Result<UsbDeviceHandle, UseUsbError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseUsbError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            if !self.is_supported.get_untracked() {
+                return Err(UseUsbError::NotSupported);
+            }
+
+            let options = self
+                .filters
+                .with_value(|filters| web_sys::UsbDeviceRequestOptions::new(filters));
+
+            let device: web_sys::UsbDevice =
+                crate::js_fut!(window().navigator().usb().request_device(&options))
+                    .await
+                    .map_err(UseUsbError::Failed)?
+                    .unchecked_into();
+
+            self.refresh().await?;
+
+            Ok(UsbDeviceHandle::new(device))
+        }
+    }
+
+    /// Reads the list of paired devices again. This happens automatically when a device is
+    /// connected or disconnected.
+    pub async fn refresh(&self) -> Result<(), UseUsbError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseUsbError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            if !self.is_supported.get_untracked() {
+                return Err(UseUsbError::NotSupported);
+            }
+
+            let devices: js_sys::Array = crate::js_fut!(window().navigator().usb().get_devices())
+                .await
+                .map_err(UseUsbError::Failed)?
+                .unchecked_into();
+
+            self.set_devices.try_set(
+                devices
+                    .iter()
+                    .map(|device| UsbDeviceHandle::new(device.unchecked_into()))
+                    .collect(),
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// A paired USB device.
+#[derive(Clone, Debug)]
+pub struct UsbDeviceHandle {
+    device: SendWrapper<web_sys::UsbDevice>,
+}
+
+impl PartialEq for UsbDeviceHandle {
+    fn eq(&self, other: &Self) -> bool {
+        js_sys::Object::is(&self.device, &other.device)
+    }
+}
+
+impl UsbDeviceHandle {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn new(device: web_sys::UsbDevice) -> Self {
+        Self {
+            device: SendWrapper::new(device),
+        }
+    }
+
+    /// The USB vendor id
+    pub fn vendor_id(&self) -> u16 {
+        self.device.vendor_id()
+    }
+
+    /// The USB product id
+    pub fn product_id(&self) -> u16 {
+        self.device.product_id()
+    }
+
+    /// The product name reported by the device
+    pub fn product_name(&self) -> Option<String> {
+        self.device.product_name()
+    }
+
+    /// The manufacturer name reported by the device
+    pub fn manufacturer_name(&self) -> Option<String> {
+        self.device.manufacturer_name()
+    }
+
+    /// The serial number reported by the device
+    pub fn serial_number(&self) -> Option<String> {
+        self.device.serial_number()
+    }
+
+    /// `true` if the device has been opened
+    pub fn is_opened(&self) -> bool {
+        self.device.opened()
+    }
+
+    /// The underlying [`web_sys::UsbDevice`]
+    pub fn usb_device(&self) -> &web_sys::UsbDevice {
+        &self.device
+    }
+
+    /// Opens the device, selects the configuration if it isn't active yet and claims the interface.
+    pub async fn connect(
+        &self,
+        configuration_value: u8,
+        interface_number: u8,
+    ) -> Result<(), UseUsbError> {
+        if !self.is_opened() {
+            self.open().await?;
+        }
+
+        let is_selected = self.device.configuration().is_some_and(|configuration| {
+            configuration.configuration_value() == configuration_value
+        });
+
+        if !is_selected {
+            self.select_configuration(configuration_value).await?;
+        }
+
+        self.claim_interface(interface_number).await
+    }
+
+    /// Opens the device.
+    pub async fn open(&self) -> Result<(), UseUsbError> {
+        usb_promise(self.device.open()).await
+    }
+
+    /// Closes the device. Claimed interfaces are released.
+    pub async fn close(&self) -> Result<(), UseUsbError> {
+        usb_promise(self.device.close()).await
+    }
+
+    /// Revokes the permission to access the device. It's removed from `devices` afterwards.
+    pub async fn forget(&self) -> Result<(), UseUsbError> {
+        usb_promise(self.device.forget()).await
+    }
+
+    /// Selects the configuration with the given value. The device has to be opened.
+    pub async fn select_configuration(&self, configuration_value: u8) -> Result<(), UseUsbError> {
+        usb_promise(self.device.select_configuration(configuration_value)).await
+    }
+
+    /// Claims the interface for exclusive access. The device has to be opened.
+    pub async fn claim_interface(&self, interface_number: u8) -> Result<(), UseUsbError> {
+        usb_promise(self.device.claim_interface(interface_number)).await
+    }
+
+    /// Releases a claimed interface.
+    pub async fn release_interface(&self, interface_number: u8) -> Result<(), UseUsbError> {
+        usb_promise(self.device.release_interface(interface_number)).await
+    }
+
+    /// Receives up to `length` bytes from a bulk or interrupt endpoint.
+    pub async fn transfer_in(
+        &self,
+        endpoint_number: u8,
+        length: u32,
+    ) -> Result<UsbTransferIn, UseUsbError> {
+        in_transfer(self.device.transfer_in(endpoint_number, length)).await
+    }
+
+    /// Sends `data` to a bulk or interrupt endpoint.
+    pub async fn transfer_out(
+        &self,
+        endpoint_number: u8,
+        data: &[u8],
+    ) -> Result<UsbTransferOut, UseUsbError> {
+        out_transfer(
+            self.device
+                .transfer_out_with_u8_slice(endpoint_number, data)
+                .map_err(UseUsbError::Failed)?,
+        )
+        .await
+    }
+
+    /// Receives up to `length` bytes with a control transfer.
+    pub async fn control_transfer_in(
+        &self,
+        setup: &UsbControlTransferParameters,
+        length: u16,
+    ) -> Result<UsbTransferIn, UseUsbError> {
+        in_transfer(self.device.control_transfer_in(setup, length)).await
+    }
+
+    /// Sends `data` with a control transfer.
+    pub async fn control_transfer_out(
+        &self,
+        setup: &UsbControlTransferParameters,
+        data: &[u8],
+    ) -> Result<UsbTransferOut, UseUsbError> {
+        out_transfer(
+            self.device
+                .control_transfer_out_with_u8_slice(setup, data)
+                .map_err(UseUsbError::Failed)?,
+        )
+        .await
+    }
+}
+
+async fn usb_promise<T>(promise: js_sys::Promise<T>) -> Result<(), UseUsbError>
+where
+    T: wasm_bindgen::convert::FromWasmAbi + 'static,
+{
+    crate::js_fut!(promise)
+        .await
+        .map(|_| ())
+        .map_err(UseUsbError::Failed)
+}
+
+async fn in_transfer(
+    promise: js_sys::Promise<web_sys::UsbInTransferResult>,
+) -> Result<UsbTransferIn, UseUsbError> {
+    use wasm_bindgen::JsCast;
+
+    let result: web_sys::UsbInTransferResult = crate::js_fut!(promise)
+        .await
+        .map_err(UseUsbError::Failed)?
+        .unchecked_into();
+
+    let data = result
+        .data()
+        .map(|view| {
+            js_sys::Uint8Array::new_with_byte_offset_and_length(
+                &view.buffer(),
+                view.byte_offset() as u32,
+                view.byte_length() as u32,
+            )
+            .to_vec()
+        })
+        .unwrap_or_default();
+
+    Ok(UsbTransferIn {
+        status: result.status(),
+        data,
+    })
+}
+
+async fn out_transfer(
+    promise: js_sys::Promise<web_sys::UsbOutTransferResult>,
+) -> Result<UsbTransferOut, UseUsbError> {
+    use wasm_bindgen::JsCast;
+
+    let result: web_sys::UsbOutTransferResult = crate::js_fut!(promise)
+        .await
+        .map_err(UseUsbError::Failed)?
+        .unchecked_into();
+
+    Ok(UsbTransferOut {
+        status: result.status(),
+        bytes_written: result.bytes_written(),
+    })
+}
+
+/// Result of a transfer from the device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsbTransferIn {
+    /// `UsbTransferStatus::Ok` if the transfer succeeded
+    pub status: UsbTransferStatus,
+
+    /// The received bytes
+    pub data: Vec<u8>,
+}
+
+/// Result of a transfer to the device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsbTransferOut {
+    /// `UsbTransferStatus::Ok` if the transfer succeeded
+    pub status: UsbTransferStatus,
+
+    /// How many bytes have been sent
+    pub bytes_written: u32,
+}
+
+#[derive(Error, Debug)]
+pub enum UseUsbError {
+    #[error("WebUSB is not available on the server")]
+    Server,
+
+    #[error("WebUSB is not supported by this browser")]
+    NotSupported,
+
+    #[error("USB operation failed")]
+    Failed(JsValue),
+}
+
+/// Options for [`use_usb_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseUsbOptions {
+    /// Only devices matching one of these filters are shown in the device picker. Defaults to no filters.
+    filters: Vec<UsbDeviceFilter>,
+}
+
+/// Return type of [`use_usb`].
+pub struct UseUsbReturn {
+    /// `true` if the browser supports the WebUSB API
+    pub is_supported: Signal<bool>,
+
+    /// The devices the user has granted access to and that are currently connected
+    pub devices: Signal<Vec<UsbDeviceHandle>>,
+
+    /// Pairs new devices
+    pub usb: UsbClient,
+}
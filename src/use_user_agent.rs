@@ -0,0 +1,399 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::utils::get_header;
+use crate::{js, use_window};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [User-Agent Client Hints](https://developer.mozilla.org/en-US/docs/Web/API/User-Agent_Client_Hints_API).
+///
+/// Exposes the brands, platform and whether it's a mobile device from
+/// [`navigator.userAgentData`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/userAgentData).
+/// The high entropy values like the architecture or the device model are requested with
+/// [`getHighEntropyValues()`](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorUAData/getHighEntropyValues)
+/// and filled in as soon as the browser provides them.
+///
+/// Browsers that don't support client hints (Firefox and Safari at the time of writing) fall back to
+/// parsing the `navigator.userAgent` string. The fallback only knows common browsers and platforms.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_user_agent)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_user_agent, UseUserAgentReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUserAgentReturn {
+///     mobile,
+///     platform,
+///     ..
+/// } = use_user_agent();
+///
+/// view! {
+///     <Show when=move || mobile.get() fallback=|| view! { <p>"Desktop layout"</p> }>
+///         <p>"Mobile layout on " {platform}</p>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## High entropy values
+///
+/// By default the architecture, bitness, model, platform version and the full version list are requested.
+/// The browser may prompt the user or decline to reveal some of them. Then the signals stay `None`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_user_agent_with_options, UseUserAgentOptions, UseUserAgentReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUserAgentReturn { architecture, .. } = use_user_agent_with_options(
+///     UseUserAgentOptions::default().high_entropy_hints(vec!["architecture".to_string()]),
+/// );
+///
+/// let is_arm = move || architecture.get().as_deref() == Some("arm");
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the `user-agent` header is parsed with the same fallback that is used in browsers
+/// without client hints. The high entropy values are always `None` on the server.
+///
+/// > If you're using `axum` you have to enable the `"axum"` feature in your Cargo.toml.
+/// > In case it's `actix-web` enable the feature `"actix"`, for `spin` enable `"spin"`.
+///
+/// ### Bring your own header
+///
+/// In case you're neither using Axum, Actix nor Spin, or the default implementation is not to your liking,
+/// you can provide your own way of reading the header value using the option
+/// [`crate::UseUserAgentOptions::ssr_user_agent_header_getter`].
+pub fn use_user_agent() -> UseUserAgentReturn {
+    use_user_agent_with_options(UseUserAgentOptions::default())
+}
+
+/// Version of [`fn@crate::use_user_agent`] that takes a `UseUserAgentOptions`. See [`fn@crate::use_user_agent`] for how to use.
+pub fn use_user_agent_with_options(options: UseUserAgentOptions) -> UseUserAgentReturn {
+    let UseUserAgentOptions {
+        high_entropy_hints,
+        ssr_user_agent_header_getter,
+    } = options;
+
+    let (is_supported, set_supported) = signal(false);
+    let (data, set_data) = signal(UserAgentData::default());
+
+    #[cfg(feature = "ssr")]
+    {
+        let user_agent = ssr_user_agent_header_getter().unwrap_or_default();
+        set_data.set(parse_user_agent(&user_agent));
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = ssr_user_agent_header_getter;
+
+        if let Some(navigator) = use_window().navigator() {
+            let user_agent = navigator.user_agent().unwrap_or_default();
+
+            let ua_data = js!(navigator["userAgentData"])
+                .ok()
+                .filter(|ua_data| ua_data.is_object());
+
+            match ua_data {
+                Some(ua_data) => {
+                    set_supported.set(true);
+                    set_data.set(UserAgentData {
+                        user_agent,
+                        brands: brands_from_js(js!(ua_data["brands"]).ok()),
+                        platform: string_from_js(js!(ua_data["platform"]).ok()).unwrap_or_default(),
+                        mobile: js!(ua_data["mobile"])
+                            .ok()
+                            .and_then(|mobile| mobile.as_bool())
+                            .unwrap_or_default(),
+                        ..Default::default()
+                    });
+
+                    if !high_entropy_hints.is_empty() {
+                        leptos::task::spawn_local(async move {
+                            if let Some(values) =
+                                get_high_entropy_values(&ua_data, &high_entropy_hints).await
+                            {
+                                set_data.update(|data| {
+                                    data.architecture =
+                                        string_from_js(js!(values["architecture"]).ok());
+                                    data.bitness = string_from_js(js!(values["bitness"]).ok());
+                                    data.model = string_from_js(js!(values["model"]).ok())
+                                        .filter(|model| !model.is_empty());
+                                    data.platform_version =
+                                        string_from_js(js!(values["platformVersion"]).ok());
+                                    data.full_version_list =
+                                        brands_from_js(js!(values["fullVersionList"]).ok());
+                                });
+                            }
+                        });
+                    }
+                }
+                None => set_data.set(parse_user_agent(&user_agent)),
+            }
+        }
+    }
+
+    UseUserAgentReturn {
+        is_supported: is_supported.into(),
+        user_agent: Signal::derive(move || data.with(|data| data.user_agent.clone())),
+        brands: Signal::derive(move || data.with(|data| data.brands.clone())),
+        platform: Signal::derive(move || data.with(|data| data.platform.clone())),
+        mobile: Signal::derive(move || data.with(|data| data.mobile)),
+        architecture: Signal::derive(move || data.with(|data| data.architecture.clone())),
+        bitness: Signal::derive(move || data.with(|data| data.bitness.clone())),
+        model: Signal::derive(move || data.with(|data| data.model.clone())),
+        platform_version: Signal::derive(move || data.with(|data| data.platform_version.clone())),
+        full_version_list: Signal::derive(move || data.with(|data| data.full_version_list.clone())),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn get_high_entropy_values(ua_data: &JsValue, hints: &[String]) -> Option<JsValue> {
+    let function = js!(ua_data["getHighEntropyValues"])
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?;
+
+    let promise = function
+        .call1(
+            ua_data,
+            &js_sys::Array::from_iter(hints.iter().map(JsValue::from)),
+        )
+        .ok()?
+        .unchecked_into::<js_sys::Promise>();
+
+    crate::js_fut!(promise).await.ok()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn string_from_js(value: Option<JsValue>) -> Option<String> {
+    value.and_then(|value| value.as_string())
+}
+
+#[cfg(not(feature = "ssr"))]
+fn brands_from_js(value: Option<JsValue>) -> Vec<UserAgentBrand> {
+    let Some(brands) = value.and_then(|value| value.dyn_into::<js_sys::Array>().ok()) else {
+        return vec![];
+    };
+
+    brands
+        .iter()
+        .map(|brand| UserAgentBrand {
+            brand: string_from_js(js!(brand["brand"]).ok()).unwrap_or_default(),
+            version: string_from_js(js!(brand["version"]).ok()).unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// Fallback for browsers without client hints and for the server. Detects common browsers
+/// and platforms from the user agent string.
+fn parse_user_agent(user_agent: &str) -> UserAgentData {
+    fn version_after<'a>(user_agent: &'a str, token: &str) -> Option<&'a str> {
+        let start = user_agent.find(token)? + token.len();
+
+        user_agent[start..]
+            .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .next()
+            .filter(|version| !version.is_empty())
+    }
+
+    fn major(version: &str) -> String {
+        version.split('.').next().unwrap_or_default().to_string()
+    }
+
+    // order matters as e.g. Edge and Opera also contain "Chrome/" and Chrome contains "Safari/"
+    let detected = [
+        ("Microsoft Edge", "Edg/"),
+        ("Opera", "OPR/"),
+        ("Samsung Internet", "SamsungBrowser/"),
+        ("Firefox", "Firefox/"),
+        ("Firefox", "FxiOS/"),
+        ("Google Chrome", "CriOS/"),
+        ("Google Chrome", "Chrome/"),
+    ]
+    .into_iter()
+    .find_map(|(brand, token)| version_after(user_agent, token).map(|version| (brand, version)))
+    .or_else(|| {
+        user_agent
+            .contains("Safari/")
+            .then(|| version_after(user_agent, "Version/"))
+            .flatten()
+            .map(|version| ("Safari", version))
+    });
+
+    let (brands, full_version_list) = match detected {
+        Some((brand, version)) => (
+            vec![UserAgentBrand {
+                brand: brand.to_string(),
+                version: major(version),
+            }],
+            vec![UserAgentBrand {
+                brand: brand.to_string(),
+                version: version.to_string(),
+            }],
+        ),
+        None => (vec![], vec![]),
+    };
+
+    let platform = if user_agent.contains("Windows") {
+        "Windows"
+    } else if user_agent.contains("Android") {
+        "Android"
+    } else if ["iPhone", "iPad", "iPod"]
+        .iter()
+        .any(|device| user_agent.contains(device))
+    {
+        "iOS"
+    } else if user_agent.contains("Macintosh") || user_agent.contains("Mac OS X") {
+        "macOS"
+    } else if user_agent.contains("CrOS") {
+        "Chrome OS"
+    } else if user_agent.contains("Linux") {
+        "Linux"
+    } else {
+        "Unknown"
+    };
+
+    let lowercase = user_agent.to_lowercase();
+
+    let architecture = if ["x86_64", "x64", "win64", "wow64", "i686", "amd64"]
+        .iter()
+        .any(|token| lowercase.contains(token))
+    {
+        Some("x86")
+    } else if ["arm", "aarch64"]
+        .iter()
+        .any(|token| lowercase.contains(token))
+    {
+        Some("arm")
+    } else {
+        None
+    };
+
+    let bitness = [
+        "x86_64", "x64", "win64", "wow64", "amd64", "aarch64", "arm64",
+    ]
+    .iter()
+    .any(|token| lowercase.contains(token))
+    .then(|| "64".to_string());
+
+    UserAgentData {
+        user_agent: user_agent.to_string(),
+        brands,
+        platform: platform.to_string(),
+        mobile: user_agent.contains("Mobi"),
+        architecture: architecture.map(str::to_string),
+        bitness,
+        model: None,
+        platform_version: None,
+        full_version_list,
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Debug)]
+struct UserAgentData {
+    user_agent: String,
+    brands: Vec<UserAgentBrand>,
+    platform: String,
+    mobile: bool,
+    architecture: Option<String>,
+    bitness: Option<String>,
+    model: Option<String>,
+    platform_version: Option<String>,
+    full_version_list: Vec<UserAgentBrand>,
+}
+
+/// A browser brand and its version as reported by [`fn@crate::use_user_agent`].
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct UserAgentBrand {
+    /// The name of the brand, e.g. `"Google Chrome"` or `"Chromium"`.
+    ///
+    /// Browsers add made up brands like `"Not)A;Brand"` on purpose to the client hints.
+    /// Don't rely on the brand list having a fixed size or order.
+    pub brand: String,
+
+    /// The version of the brand. The major version for `brands`, the full version for `full_version_list`.
+    pub version: String,
+}
+
+/// Options for [`fn@crate::use_user_agent_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseUserAgentOptions {
+    /// The high entropy values to request with `getHighEntropyValues()`. Pass an empty vec to not
+    /// request any. Defaults to `["architecture", "bitness", "model", "platformVersion", "fullVersionList"]`.
+    high_entropy_hints: Vec<String>,
+
+    /// Getter function to return the string value of the `user-agent` header.
+    /// When you use one of the features `"axum"`, `"actix"` or `"spin"` there's a valid default
+    /// implementation provided.
+    #[allow(dead_code)]
+    ssr_user_agent_header_getter: Arc<dyn Fn() -> Option<String> + Send + Sync>,
+}
+
+impl Default for UseUserAgentOptions {
+    fn default() -> Self {
+        Self {
+            high_entropy_hints: [
+                "architecture",
+                "bitness",
+                "model",
+                "platformVersion",
+                "fullVersionList",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+            ssr_user_agent_header_getter: Arc::new(move || {
+                get_header!(USER_AGENT, use_user_agent, ssr_user_agent_header_getter)
+            }),
+        }
+    }
+}
+
+/// Return type of [`fn@crate::use_user_agent`].
+pub struct UseUserAgentReturn {
+    /// Whether User-Agent Client Hints are supported. If `false` the values are parsed from the user agent string.
+    pub is_supported: Signal<bool>,
+
+    /// The raw user agent string.
+    pub user_agent: Signal<String>,
+
+    /// The brands with their major versions, e.g. `[{"Chromium", "124"}, {"Google Chrome", "124"}, {"Not-A.Brand", "99"}]`.
+    pub brands: Signal<Vec<UserAgentBrand>>,
+
+    /// The platform, e.g. `"Windows"`, `"macOS"`, `"Linux"`, `"Android"`, `"iOS"` or `"Chrome OS"`.
+    pub platform: Signal<String>,
+
+    /// Whether the browser is on a mobile device.
+    pub mobile: Signal<bool>,
+
+    /// The CPU architecture, e.g. `"x86"` or `"arm"`. High entropy value.
+    pub architecture: Signal<Option<String>>,
+
+    /// The bitness of the CPU architecture, e.g. `"64"`. High entropy value.
+    pub bitness: Signal<Option<String>>,
+
+    /// The device model on mobile devices, e.g. `"Pixel 8"`. High entropy value.
+    pub model: Signal<Option<String>>,
+
+    /// The version of the platform, e.g. `"14.4.1"`. High entropy value.
+    pub platform_version: Signal<Option<String>>,
+
+    /// The brands with their full versions. High entropy value.
+    pub full_version_list: Signal<Vec<UserAgentBrand>>,
+}
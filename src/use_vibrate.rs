@@ -0,0 +1,369 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{use_interval_fn_with_options, use_supported, UseIntervalFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use thiserror::Error;
+
+/// Reactive [Vibration API](https://developer.mozilla.org/en-US/docs/Web/API/Vibration_API).
+///
+/// Most modern mobile devices include vibration hardware, which lets software code provide physical
+/// feedback to the user by causing the device to shake.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_vibrate)
+///
+/// ## Usage
+///
+/// The pattern is a list of durations in milliseconds that alternate between vibrating and pausing.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_vibrate_with_options, UseVibrateOptions, UseVibrateReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// // vibrate for 300 ms, pause for 100 ms, then vibrate for 300 ms again
+/// let UseVibrateReturn { vibrate, .. } =
+///     use_vibrate_with_options(UseVibrateOptions::default().pattern(vec![300, 100, 300]));
+///
+/// view! { <button on:click=move |_| vibrate()>"Vibrate"</button> }
+/// # }
+/// ```
+///
+/// The pattern can also be a signal. Changing it while vibrating restarts the vibration with the new pattern.
+///
+/// ## Presets
+///
+/// [`VibratePreset`] provides some common patterns.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_vibrate_with_options, UseVibrateOptions, VibratePreset};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let vibrate = use_vibrate_with_options(UseVibrateOptions::default().pattern(VibratePreset::Success));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Looping
+///
+/// With the option `interval` the pattern is repeated every `interval` milliseconds until `stop` is called.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_vibrate_with_options, UseVibrateOptions, UseVibrateReturn, VibratePreset};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseVibrateReturn {
+///     vibrate,
+///     stop,
+///     is_vibrating,
+///     ..
+/// } = use_vibrate_with_options(
+///     UseVibrateOptions::default()
+///         .pattern(VibratePreset::Heartbeat)
+///         .interval(1000),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| if is_vibrating.get() { stop() } else { vibrate() }>
+///         {move || if is_vibrating.get() { "Stop" } else { "Start" }}
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// ## User activation
+///
+/// Browsers ignore vibration requests until the user has interacted with the page. Instead of failing
+/// silently `vibrate` sets `error` to [`UseVibrateError::NoUserActivation`] in this case. So make sure to call it
+/// from an event handler like a click.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `vibrate` and `stop` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_vibrate`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_vibrating` are always `false` and the returned closures are no-ops.
+pub fn use_vibrate(
+) -> UseVibrateReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_vibrate_with_options(UseVibrateOptions::default())
+}
+
+/// Version of [`use_vibrate`] that takes a `UseVibrateOptions`. See [`use_vibrate`] for how to use.
+pub fn use_vibrate_with_options(
+    options: UseVibrateOptions,
+) -> UseVibrateReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseVibrateOptions { pattern, interval } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("vibrate" in &window().navigator())
+        }
+    });
+
+    let (is_vibrating, set_vibrating) = signal(false);
+    let (error, set_error) = signal(None::<UseVibrateError>);
+
+    let vibrate;
+    let stop;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = pattern;
+        let _ = interval;
+        let _ = set_vibrating;
+        let _ = set_error;
+
+        vibrate = || {};
+        stop = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js, sendwrap_fn};
+        use std::time::Duration;
+
+        let timeout = StoredValue::new(None::<TimeoutHandle>);
+
+        let clear_timeout = move || {
+            if let Some(Some(handle)) = timeout.try_get_value() {
+                handle.clear();
+            }
+            timeout.try_set_value(None);
+        };
+
+        // Returns `false` if the browser refused to vibrate
+        let start = move |pattern: &[u32]| {
+            let js_pattern = pattern
+                .iter()
+                .map(|duration| wasm_bindgen::JsValue::from(*duration))
+                .collect::<js_sys::Array>();
+
+            window().navigator().vibrate_with_pattern(&js_pattern)
+        };
+
+        let repeat = use_interval_fn_with_options(
+            move || {
+                pattern.with_untracked(|pattern| start(pattern));
+            },
+            interval,
+            UseIntervalFnOptions::default().immediate(false),
+        );
+
+        let pause_repeat = repeat.pause.clone();
+
+        vibrate = sendwrap_fn!(move || {
+            if !is_supported.get_untracked() {
+                set_error.set(Some(UseVibrateError::NotSupported));
+                return;
+            }
+
+            // the browser ignores vibrations before the user has interacted with the page
+            let navigator = window().navigator();
+            let has_been_active = js!(navigator["userActivation"])
+                .ok()
+                .filter(|activation| !activation.is_undefined())
+                .map(|activation| {
+                    js!(activation["hasBeenActive"])
+                        .ok()
+                        .and_then(|value| value.as_bool())
+                        .unwrap_or(true)
+                })
+                .unwrap_or(true);
+
+            if !has_been_active {
+                set_error.set(Some(UseVibrateError::NoUserActivation));
+                return;
+            }
+
+            clear_timeout();
+
+            if !pattern.with_untracked(|pattern| start(pattern)) {
+                set_error.set(Some(UseVibrateError::Rejected));
+                return;
+            }
+
+            set_error.set(None);
+            set_vibrating.set(true);
+
+            if interval > 0 {
+                (repeat.resume)();
+            } else {
+                let duration = pattern.with_untracked(|pattern| pattern.iter().sum::<u32>());
+
+                timeout.set_value(
+                    set_timeout_with_handle(
+                        move || {
+                            timeout.try_set_value(None);
+                            let _ = set_vibrating.try_set(false);
+                        },
+                        Duration::from_millis(duration as u64),
+                    )
+                    .ok(),
+                );
+            }
+        });
+
+        stop = sendwrap_fn!(move || {
+            pause_repeat();
+            clear_timeout();
+
+            if is_vibrating.try_get_untracked().unwrap_or_default() {
+                window().navigator().vibrate_with_duration(0);
+                set_vibrating.set(false);
+            }
+        });
+
+        let _ = Effect::watch(
+            move || pattern.track(),
+            {
+                let vibrate = vibrate.clone();
+
+                move |_, _, _| {
+                    if is_vibrating.get_untracked() {
+                        vibrate();
+                    }
+                }
+            },
+            false,
+        );
+
+        on_cleanup(stop.clone());
+    }
+
+    UseVibrateReturn {
+        is_supported,
+        is_vibrating: is_vibrating.into(),
+        error: error.into(),
+        vibrate,
+        stop,
+    }
+}
+
+/// Options for [`use_vibrate_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseVibrateOptions {
+    /// Vibration pattern in milliseconds. The values alternate between vibrating and pausing.
+    /// Accepts a [`VibratePreset`] as well. Defaults to a single vibration of 200 ms.
+    #[builder(into)]
+    pattern: Signal<Vec<u32>>,
+
+    /// If greater than `0`, the pattern is repeated with this interval in milliseconds until `stop` is called.
+    /// Defaults to `0`.
+    interval: u64,
+}
+
+impl Default for UseVibrateOptions {
+    fn default() -> Self {
+        Self {
+            pattern: Signal::stored(vec![200]),
+            interval: 0,
+        }
+    }
+}
+
+/// Common vibration patterns. Can be passed to [`UseVibrateOptions::pattern`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VibratePreset {
+    /// A single short vibration
+    Tap,
+
+    /// Two short vibrations
+    DoubleTap,
+
+    /// A short vibration followed by a longer one
+    Success,
+
+    /// Two medium vibrations
+    Warning,
+
+    /// Three long vibrations
+    Error,
+
+    /// Two quick vibrations like a heartbeat. Looks best when looped every second.
+    Heartbeat,
+
+    /// `... --- ...` in morse code
+    Sos,
+}
+
+impl VibratePreset {
+    /// The vibration pattern of this preset in milliseconds
+    pub fn pattern(&self) -> Vec<u32> {
+        match self {
+            Self::Tap => vec![50],
+            Self::DoubleTap => vec![50, 100, 50],
+            Self::Success => vec![50, 50, 150],
+            Self::Warning => vec![150, 100, 150],
+            Self::Error => vec![300, 100, 300, 100, 300],
+            Self::Heartbeat => vec![100, 120, 100],
+            Self::Sos => vec![
+                100, 100, 100, 100, 100, 300, 300, 100, 300, 100, 300, 300, 100, 100, 100, 100, 100,
+            ],
+        }
+    }
+}
+
+impl From<VibratePreset> for Vec<u32> {
+    fn from(preset: VibratePreset) -> Self {
+        preset.pattern()
+    }
+}
+
+impl From<VibratePreset> for Signal<Vec<u32>> {
+    fn from(preset: VibratePreset) -> Self {
+        Signal::stored(preset.pattern())
+    }
+}
+
+/// Error returned in `error` by [`use_vibrate`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum UseVibrateError {
+    #[error("the Vibration API is not supported by this browser")]
+    NotSupported,
+
+    #[error("vibration requires the user to interact with the page first")]
+    NoUserActivation,
+
+    #[error("the browser refused to vibrate")]
+    Rejected,
+}
+
+/// Return type of [`use_vibrate`].
+pub struct UseVibrateReturn<VibrateFn, StopFn>
+where
+    VibrateFn: Fn() + Clone + Send + Sync,
+    StopFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the Vibration API is supported
+    pub is_supported: Signal<bool>,
+
+    /// `true` while the pattern is playing or looping
+    pub is_vibrating: Signal<bool>,
+
+    /// Why the last call to `vibrate` didn't vibrate. `None` if it did.
+    pub error: Signal<Option<UseVibrateError>>,
+
+    /// Start vibrating with the pattern. Has to be called in response to a user interaction.
+    pub vibrate: VibrateFn,
+
+    /// Stop vibrating and cancel the loop
+    pub stop: StopFn,
+}
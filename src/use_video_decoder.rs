@@ -0,0 +1,420 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::use_supported;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+use wasm_bindgen::JsValue;
+
+/// Reactive [`VideoDecoder`](https://developer.mozilla.org/en-US/docs/Web/API/VideoDecoder) of the
+/// [WebCodecs API](https://developer.mozilla.org/en-US/docs/Web/API/WebCodecs_API).
+///
+/// Decodes encoded video chunks, for example received over a WebSocket or WebTransport, into video frames
+/// that can be drawn to a canvas.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_video_decoder)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_video_decoder_with_options, UseVideoDecoderOptions, UseVideoDecoderReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseVideoDecoderReturn { frame, decode, is_saturated, .. } = use_video_decoder_with_options(
+///     UseVideoDecoderOptions::default().config(web_sys::VideoDecoderConfig::new("vp8")),
+/// );
+///
+/// Effect::new(move || {
+///     if let Some(frame) = frame.get() {
+///         // draw the frame to a canvas
+///     }
+/// });
+///
+/// # let chunk: web_sys::EncodedVideoChunk = unimplemented!();
+/// // check `is_saturated` before feeding more chunks into the decoder
+/// if !is_saturated.get_untracked() {
+///     decode(&chunk);
+/// }
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The decoder can also be configured later with `configure`, for example once the decoder config has been
+/// received from the sender. If the decoder has been closed because of an error, `configure` creates a new one.
+///
+/// ## Frames
+///
+/// By default the last decoded frame is available in `frame`. When a new frame arrives the previous one is
+/// closed to release its memory. So don't keep a frame around after `frame` has changed.
+///
+/// To handle every frame yourself provide `on_frame`. In this case `frame` stays `None` and you're responsible for
+/// calling `close()` on every frame once you don't need it anymore.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_video_decoder_with_options, UseVideoDecoderOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let decoder = use_video_decoder_with_options(UseVideoDecoderOptions::default().on_frame(
+///     |frame: web_sys::VideoFrame| {
+///         // render the frame and release it
+///         frame.close();
+///     },
+/// ));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Backpressure
+///
+/// `decode_queue_size` is the number of chunks the decoder still has to process. `is_saturated` becomes `true` once
+/// it reaches `max_queue_size`. Stop feeding chunks until it's `false` again to avoid falling further and further behind.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `configure`, `decode`, `flush`, `reset` and `close` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_video_decoder`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false` and the returned closures are no-ops.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_video_encoder`]
+#[allow(clippy::type_complexity)]
+pub fn use_video_decoder() -> UseVideoDecoderReturn<
+    impl Fn(&web_sys::VideoDecoderConfig) + Clone + Send + Sync,
+    impl Fn(&web_sys::EncodedVideoChunk) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+> {
+    use_video_decoder_with_options(UseVideoDecoderOptions::default())
+}
+
+/// Version of [`use_video_decoder`] that takes a `UseVideoDecoderOptions`. See [`use_video_decoder`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_video_decoder_with_options(
+    options: UseVideoDecoderOptions,
+) -> UseVideoDecoderReturn<
+    impl Fn(&web_sys::VideoDecoderConfig) + Clone + Send + Sync,
+    impl Fn(&web_sys::EncodedVideoChunk) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+> {
+    let UseVideoDecoderOptions {
+        config,
+        max_queue_size,
+        on_frame,
+        on_error,
+    } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("VideoDecoder" in &window())
+        }
+    });
+
+    let (state, set_state) = signal(web_sys::CodecState::Unconfigured);
+    let (frame, set_frame) = signal_local(None::<web_sys::VideoFrame>);
+    let (decode_queue_size, set_decode_queue_size) = signal(0_u32);
+    let (error, set_error) = signal_local(None::<web_sys::DomException>);
+
+    let configure;
+    let decode;
+    let flush;
+    let reset;
+    let close;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_state;
+        let _ = set_frame;
+        let _ = set_decode_queue_size;
+        let _ = set_error;
+
+        configure = |_: &web_sys::VideoDecoderConfig| {};
+        decode = |_: &web_sys::EncodedVideoChunk| {};
+        flush = || {};
+        reset = || {};
+        close = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js_fut, sendwrap_fn};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::JsCast;
+
+        let decoder = Rc::new(RefCell::new(None::<web_sys::VideoDecoder>));
+
+        let handle_error = move |err: JsValue| {
+            let _ = set_error.try_set(err.dyn_ref::<web_sys::DomException>().cloned());
+            let _ = set_state.try_set(web_sys::CodecState::Closed);
+            let _ = set_decode_queue_size.try_set(0);
+
+            if let Some(on_error) = &on_error {
+                on_error(err);
+            }
+        };
+
+        let create_decoder = {
+            let handle_error = handle_error.clone();
+
+            move || {
+                let on_frame = on_frame.clone();
+                let output = Closure::<dyn Fn(web_sys::VideoFrame)>::new(move |frame| {
+                    if let Some(on_frame) = &on_frame {
+                        on_frame(frame);
+                    } else {
+                        // the previous frame has to be closed to release its memory
+                        let _ = set_frame.try_update(|current| {
+                            current.replace(frame).map(|previous| previous.close())
+                        });
+                    }
+                })
+                .into_js_value();
+
+                let handle_error = handle_error.clone();
+                let error = Closure::<dyn Fn(JsValue)>::new(handle_error).into_js_value();
+
+                let decoder = web_sys::VideoDecoder::new(&web_sys::VideoDecoderInit::new(
+                    error.unchecked_ref(),
+                    output.unchecked_ref(),
+                ))
+                .ok()?;
+
+                let on_dequeue =
+                    Closure::<dyn Fn(web_sys::Event)>::new(move |event: web_sys::Event| {
+                        if let Some(decoder) = event
+                            .target()
+                            .and_then(|target| target.dyn_into::<web_sys::VideoDecoder>().ok())
+                        {
+                            let _ = set_decode_queue_size.try_set(decoder.decode_queue_size());
+                        }
+                    })
+                    .into_js_value();
+                decoder.set_ondequeue(Some(on_dequeue.unchecked_ref()));
+
+                Some(decoder)
+            }
+        };
+
+        configure = {
+            let decoder = Rc::clone(&decoder);
+            let handle_error = handle_error.clone();
+
+            sendwrap_fn!(move |config: &web_sys::VideoDecoderConfig| {
+                if !is_supported.get_untracked() {
+                    return;
+                }
+
+                let mut decoder = decoder.borrow_mut();
+
+                let is_closed = match decoder.as_ref() {
+                    Some(decoder) => decoder.state() == web_sys::CodecState::Closed,
+                    None => true,
+                };
+                if is_closed {
+                    *decoder = create_decoder();
+                }
+
+                if let Some(decoder) = decoder.as_ref() {
+                    match decoder.configure(config) {
+                        Ok(_) => {
+                            set_error.set(None);
+                            set_state.set(decoder.state());
+                        }
+                        Err(err) => handle_error(err),
+                    }
+                }
+            })
+        };
+
+        decode = {
+            let decoder = Rc::clone(&decoder);
+            let handle_error = handle_error.clone();
+
+            sendwrap_fn!(move |chunk: &web_sys::EncodedVideoChunk| {
+                if let Some(decoder) = decoder.borrow().as_ref() {
+                    match decoder.decode(chunk) {
+                        Ok(_) => set_decode_queue_size.set(decoder.decode_queue_size()),
+                        Err(err) => handle_error(err),
+                    }
+                }
+            })
+        };
+
+        flush = {
+            let decoder = Rc::clone(&decoder);
+
+            sendwrap_fn!(move || {
+                if let Some(decoder) = decoder.borrow().as_ref() {
+                    if decoder.state() != web_sys::CodecState::Configured {
+                        return;
+                    }
+
+                    let promise = decoder.flush();
+                    leptos::task::spawn_local(async move {
+                        // errors are reported to the error callback of the decoder
+                        let _ = js_fut!(promise).await;
+                    });
+                }
+            })
+        };
+
+        reset = {
+            let decoder = Rc::clone(&decoder);
+
+            sendwrap_fn!(move || {
+                if let Some(decoder) = decoder.borrow().as_ref() {
+                    if decoder.reset().is_ok() {
+                        set_state.set(decoder.state());
+                        set_decode_queue_size.set(0);
+                    }
+                }
+            })
+        };
+
+        close = sendwrap_fn!(move || {
+            if let Some(decoder) = decoder.take() {
+                let _ = decoder.close();
+            }
+
+            let _ = set_state.try_set(web_sys::CodecState::Closed);
+            let _ = set_decode_queue_size.try_set(0);
+            let _ = set_frame.try_update(|frame| frame.take().map(|frame| frame.close()));
+        });
+
+        if let Some(config) = config {
+            configure(&config);
+        }
+
+        on_cleanup(close.clone());
+    }
+
+    UseVideoDecoderReturn {
+        is_supported,
+        state: state.into(),
+        frame: frame.into(),
+        decode_queue_size: decode_queue_size.into(),
+        is_saturated: Signal::derive(move || decode_queue_size.get() >= max_queue_size),
+        error: error.into(),
+        configure,
+        decode,
+        flush,
+        reset,
+        close,
+    }
+}
+
+type VideoFrameHandler = Arc<dyn Fn(web_sys::VideoFrame) + Send + Sync>;
+type CodecErrorHandler = Arc<dyn Fn(JsValue) + Send + Sync>;
+
+/// Options for [`use_video_decoder_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseVideoDecoderOptions {
+    /// If provided, the decoder is configured with it right away. Defaults to `None`.
+    #[builder(into)]
+    config: Option<web_sys::VideoDecoderConfig>,
+
+    /// Number of chunks in the decode queue from which on `is_saturated` is `true`. Defaults to `4`.
+    max_queue_size: u32,
+
+    #[builder(skip)]
+    on_frame: Option<VideoFrameHandler>,
+
+    #[builder(skip)]
+    on_error: Option<CodecErrorHandler>,
+}
+
+impl Default for UseVideoDecoderOptions {
+    fn default() -> Self {
+        Self {
+            config: None,
+            max_queue_size: 4,
+            on_frame: None,
+            on_error: None,
+        }
+    }
+}
+
+impl UseVideoDecoderOptions {
+    /// Called with every decoded frame instead of setting `frame`. The handler is responsible for closing the frames.
+    pub fn on_frame(self, on_frame: impl Fn(web_sys::VideoFrame) + Send + Sync + 'static) -> Self {
+        Self {
+            on_frame: Some(Arc::new(on_frame)),
+            ..self
+        }
+    }
+
+    /// Called when decoding fails. The decoder is closed afterwards and has to be configured again.
+    pub fn on_error(self, on_error: impl Fn(JsValue) + Send + Sync + 'static) -> Self {
+        Self {
+            on_error: Some(Arc::new(on_error)),
+            ..self
+        }
+    }
+}
+
+/// Return type of [`use_video_decoder`].
+pub struct UseVideoDecoderReturn<ConfigureFn, DecodeFn, FlushFn, ResetFn, CloseFn>
+where
+    ConfigureFn: Fn(&web_sys::VideoDecoderConfig) + Clone + Send + Sync,
+    DecodeFn: Fn(&web_sys::EncodedVideoChunk) + Clone + Send + Sync,
+    FlushFn: Fn() + Clone + Send + Sync,
+    ResetFn: Fn() + Clone + Send + Sync,
+    CloseFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the `VideoDecoder` API is supported
+    pub is_supported: Signal<bool>,
+
+    /// State of the decoder
+    pub state: Signal<web_sys::CodecState>,
+
+    /// The last decoded frame. Only set if no `on_frame` handler is provided.
+    pub frame: Signal<Option<web_sys::VideoFrame>, LocalStorage>,
+
+    /// Number of chunks that still have to be decoded
+    pub decode_queue_size: Signal<u32>,
+
+    /// `true` if `decode_queue_size` has reached `max_queue_size`
+    pub is_saturated: Signal<bool>,
+
+    /// The last error that occurred while configuring or decoding
+    pub error: Signal<Option<web_sys::DomException>, LocalStorage>,
+
+    /// Configure the decoder. Creates a new decoder if the previous one has been closed.
+    pub configure: ConfigureFn,
+
+    /// Add a chunk to the decode queue
+    pub decode: DecodeFn,
+
+    /// Decode all chunks in the queue right away
+    pub flush: FlushFn,
+
+    /// Discard all chunks in the queue. The decoder has to be configured again afterwards.
+    pub reset: ResetFn,
+
+    /// Close the decoder. This also happens when the component is cleaned up.
+    pub close: CloseFn,
+}
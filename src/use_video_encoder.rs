@@ -0,0 +1,411 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::use_supported;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+use wasm_bindgen::JsValue;
+
+/// Reactive [`VideoEncoder`](https://developer.mozilla.org/en-US/docs/Web/API/VideoEncoder) of the
+/// [WebCodecs API](https://developer.mozilla.org/en-US/docs/Web/API/WebCodecs_API).
+///
+/// Encodes video frames, for example from a camera or a canvas, into encoded video chunks that can be sent over
+/// the network and decoded again with [`fn@crate::use_video_decoder`].
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_video_encoder)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_video_encoder_with_options, UseVideoEncoderOptions, UseVideoEncoderReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseVideoEncoderReturn { encode, is_saturated, .. } = use_video_encoder_with_options(
+///     UseVideoEncoderOptions::default()
+///         .config(web_sys::VideoEncoderConfig::new("vp8", 480, 640))
+///         .on_chunk(|chunk: web_sys::EncodedVideoChunk, metadata| {
+///             // send the chunk to the other side
+///         }),
+/// );
+///
+/// # let frame: web_sys::VideoFrame = unimplemented!();
+/// if !is_saturated.get_untracked() {
+///     // request a key frame every now and then so that new receivers can start decoding
+///     encode(&frame, false);
+/// }
+/// frame.close();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The handler receives the encoded chunk and optionally its metadata. The first chunk after `configure` carries the
+/// metadata's `decoder_config` which the decoder on the other side needs to be configured with.
+///
+/// Without `on_chunk` the last encoded chunk is available in `chunk`.
+///
+/// The encoder doesn't take ownership of the frames. You still have to close them after calling `encode`.
+///
+/// ## Backpressure
+///
+/// `encode_queue_size` is the number of frames the encoder still has to process. `is_saturated` becomes `true` once
+/// it reaches `max_queue_size`. Drop frames until it's `false` again, otherwise the latency grows and the frames
+/// waiting in the queue use a lot of memory.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `configure`, `encode`, `flush`, `reset` and `close` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_video_encoder`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false` and the returned closures are no-ops.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_video_decoder`]
+#[allow(clippy::type_complexity)]
+pub fn use_video_encoder() -> UseVideoEncoderReturn<
+    impl Fn(&web_sys::VideoEncoderConfig) + Clone + Send + Sync,
+    impl Fn(&web_sys::VideoFrame, bool) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+> {
+    use_video_encoder_with_options(UseVideoEncoderOptions::default())
+}
+
+/// Version of [`use_video_encoder`] that takes a `UseVideoEncoderOptions`. See [`use_video_encoder`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_video_encoder_with_options(
+    options: UseVideoEncoderOptions,
+) -> UseVideoEncoderReturn<
+    impl Fn(&web_sys::VideoEncoderConfig) + Clone + Send + Sync,
+    impl Fn(&web_sys::VideoFrame, bool) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+> {
+    let UseVideoEncoderOptions {
+        config,
+        max_queue_size,
+        on_chunk,
+        on_error,
+    } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("VideoEncoder" in &window())
+        }
+    });
+
+    let (state, set_state) = signal(web_sys::CodecState::Unconfigured);
+    let (chunk, set_chunk) = signal_local(None::<web_sys::EncodedVideoChunk>);
+    let (encode_queue_size, set_encode_queue_size) = signal(0_u32);
+    let (error, set_error) = signal_local(None::<web_sys::DomException>);
+
+    let configure;
+    let encode;
+    let flush;
+    let reset;
+    let close;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_state;
+        let _ = set_chunk;
+        let _ = set_encode_queue_size;
+        let _ = set_error;
+
+        configure = |_: &web_sys::VideoEncoderConfig| {};
+        encode = |_: &web_sys::VideoFrame, _: bool| {};
+        flush = || {};
+        reset = || {};
+        close = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js_fut, sendwrap_fn};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::JsCast;
+
+        let encoder = Rc::new(RefCell::new(None::<web_sys::VideoEncoder>));
+
+        let handle_error = move |err: JsValue| {
+            let _ = set_error.try_set(err.dyn_ref::<web_sys::DomException>().cloned());
+            let _ = set_state.try_set(web_sys::CodecState::Closed);
+            let _ = set_encode_queue_size.try_set(0);
+
+            if let Some(on_error) = &on_error {
+                on_error(err);
+            }
+        };
+
+        let create_encoder = {
+            let handle_error = handle_error.clone();
+
+            move || {
+                let on_chunk = on_chunk.clone();
+                let output = Closure::<dyn Fn(web_sys::EncodedVideoChunk, JsValue)>::new(
+                    move |chunk, metadata: JsValue| {
+                        if let Some(on_chunk) = &on_chunk {
+                            let metadata = metadata.is_object().then(|| {
+                                metadata.unchecked_into::<web_sys::EncodedVideoChunkMetadata>()
+                            });
+                            on_chunk(chunk, metadata);
+                        } else {
+                            let _ = set_chunk.try_set(Some(chunk));
+                        }
+                    },
+                )
+                .into_js_value();
+
+                let handle_error = handle_error.clone();
+                let error = Closure::<dyn Fn(JsValue)>::new(handle_error).into_js_value();
+
+                let encoder = web_sys::VideoEncoder::new(&web_sys::VideoEncoderInit::new(
+                    error.unchecked_ref(),
+                    output.unchecked_ref(),
+                ))
+                .ok()?;
+
+                let on_dequeue =
+                    Closure::<dyn Fn(web_sys::Event)>::new(move |event: web_sys::Event| {
+                        if let Some(encoder) = event
+                            .target()
+                            .and_then(|target| target.dyn_into::<web_sys::VideoEncoder>().ok())
+                        {
+                            let _ = set_encode_queue_size.try_set(encoder.encode_queue_size());
+                        }
+                    })
+                    .into_js_value();
+                encoder.set_ondequeue(Some(on_dequeue.unchecked_ref()));
+
+                Some(encoder)
+            }
+        };
+
+        configure = {
+            let encoder = Rc::clone(&encoder);
+            let handle_error = handle_error.clone();
+
+            sendwrap_fn!(move |config: &web_sys::VideoEncoderConfig| {
+                if !is_supported.get_untracked() {
+                    return;
+                }
+
+                let mut encoder = encoder.borrow_mut();
+
+                let is_closed = match encoder.as_ref() {
+                    Some(encoder) => encoder.state() == web_sys::CodecState::Closed,
+                    None => true,
+                };
+                if is_closed {
+                    *encoder = create_encoder();
+                }
+
+                if let Some(encoder) = encoder.as_ref() {
+                    match encoder.configure(config) {
+                        Ok(_) => {
+                            set_error.set(None);
+                            set_state.set(encoder.state());
+                        }
+                        Err(err) => handle_error(err),
+                    }
+                }
+            })
+        };
+
+        encode = {
+            let encoder = Rc::clone(&encoder);
+            let handle_error = handle_error.clone();
+
+            sendwrap_fn!(move |frame: &web_sys::VideoFrame, key_frame: bool| {
+                if let Some(encoder) = encoder.borrow().as_ref() {
+                    let options = web_sys::VideoEncoderEncodeOptions::new();
+                    options.set_key_frame(key_frame);
+
+                    match encoder.encode_with_options(frame, &options) {
+                        Ok(_) => set_encode_queue_size.set(encoder.encode_queue_size()),
+                        Err(err) => handle_error(err),
+                    }
+                }
+            })
+        };
+
+        flush = {
+            let encoder = Rc::clone(&encoder);
+
+            sendwrap_fn!(move || {
+                if let Some(encoder) = encoder.borrow().as_ref() {
+                    if encoder.state() != web_sys::CodecState::Configured {
+                        return;
+                    }
+
+                    let promise = encoder.flush();
+                    leptos::task::spawn_local(async move {
+                        // errors are reported to the error callback of the encoder
+                        let _ = js_fut!(promise).await;
+                    });
+                }
+            })
+        };
+
+        reset = {
+            let encoder = Rc::clone(&encoder);
+
+            sendwrap_fn!(move || {
+                if let Some(encoder) = encoder.borrow().as_ref() {
+                    if encoder.reset().is_ok() {
+                        set_state.set(encoder.state());
+                        set_encode_queue_size.set(0);
+                    }
+                }
+            })
+        };
+
+        close = sendwrap_fn!(move || {
+            if let Some(encoder) = encoder.take() {
+                let _ = encoder.close();
+            }
+
+            let _ = set_state.try_set(web_sys::CodecState::Closed);
+            let _ = set_encode_queue_size.try_set(0);
+        });
+
+        if let Some(config) = config {
+            configure(&config);
+        }
+
+        on_cleanup(close.clone());
+    }
+
+    UseVideoEncoderReturn {
+        is_supported,
+        state: state.into(),
+        chunk: chunk.into(),
+        encode_queue_size: encode_queue_size.into(),
+        is_saturated: Signal::derive(move || encode_queue_size.get() >= max_queue_size),
+        error: error.into(),
+        configure,
+        encode,
+        flush,
+        reset,
+        close,
+    }
+}
+
+type EncodedChunkHandler = Arc<
+    dyn Fn(web_sys::EncodedVideoChunk, Option<web_sys::EncodedVideoChunkMetadata>) + Send + Sync,
+>;
+type CodecErrorHandler = Arc<dyn Fn(JsValue) + Send + Sync>;
+
+/// Options for [`use_video_encoder_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseVideoEncoderOptions {
+    /// If provided, the encoder is configured with it right away. Defaults to `None`.
+    #[builder(into)]
+    config: Option<web_sys::VideoEncoderConfig>,
+
+    /// Number of frames in the encode queue from which on `is_saturated` is `true`. Defaults to `2`.
+    max_queue_size: u32,
+
+    #[builder(skip)]
+    on_chunk: Option<EncodedChunkHandler>,
+
+    #[builder(skip)]
+    on_error: Option<CodecErrorHandler>,
+}
+
+impl Default for UseVideoEncoderOptions {
+    fn default() -> Self {
+        Self {
+            config: None,
+            max_queue_size: 2,
+            on_chunk: None,
+            on_error: None,
+        }
+    }
+}
+
+impl UseVideoEncoderOptions {
+    /// Called with every encoded chunk and its metadata instead of setting `chunk`.
+    pub fn on_chunk(
+        self,
+        on_chunk: impl Fn(web_sys::EncodedVideoChunk, Option<web_sys::EncodedVideoChunkMetadata>)
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self {
+            on_chunk: Some(Arc::new(on_chunk)),
+            ..self
+        }
+    }
+
+    /// Called when encoding fails. The encoder is closed afterwards and has to be configured again.
+    pub fn on_error(self, on_error: impl Fn(JsValue) + Send + Sync + 'static) -> Self {
+        Self {
+            on_error: Some(Arc::new(on_error)),
+            ..self
+        }
+    }
+}
+
+/// Return type of [`use_video_encoder`].
+pub struct UseVideoEncoderReturn<ConfigureFn, EncodeFn, FlushFn, ResetFn, CloseFn>
+where
+    ConfigureFn: Fn(&web_sys::VideoEncoderConfig) + Clone + Send + Sync,
+    EncodeFn: Fn(&web_sys::VideoFrame, bool) + Clone + Send + Sync,
+    FlushFn: Fn() + Clone + Send + Sync,
+    ResetFn: Fn() + Clone + Send + Sync,
+    CloseFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the `VideoEncoder` API is supported
+    pub is_supported: Signal<bool>,
+
+    /// State of the encoder
+    pub state: Signal<web_sys::CodecState>,
+
+    /// The last encoded chunk. Only set if no `on_chunk` handler is provided.
+    pub chunk: Signal<Option<web_sys::EncodedVideoChunk>, LocalStorage>,
+
+    /// Number of frames that still have to be encoded
+    pub encode_queue_size: Signal<u32>,
+
+    /// `true` if `encode_queue_size` has reached `max_queue_size`
+    pub is_saturated: Signal<bool>,
+
+    /// The last error that occurred while configuring or encoding
+    pub error: Signal<Option<web_sys::DomException>, LocalStorage>,
+
+    /// Configure the encoder. Creates a new encoder if the previous one has been closed.
+    pub configure: ConfigureFn,
+
+    /// Add a frame to the encode queue. If the second argument is `true` the frame is encoded as a key frame.
+    pub encode: EncodeFn,
+
+    /// Encode all frames in the queue right away
+    pub flush: FlushFn,
+
+    /// Discard all frames in the queue. The encoder has to be configured again afterwards.
+    pub reset: ResetFn,
+
+    /// Close the encoder. This also happens when the component is cleaned up.
+    pub close: CloseFn,
+}
@@ -0,0 +1,347 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::use_supported;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive [Screen Wake Lock API](https://developer.mozilla.org/en-US/docs/Web/API/Screen_Wake_Lock_API).
+///
+/// Prevents the screen from dimming or locking, e.g. while a recipe or a presentation is shown.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_wake_lock)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_wake_lock, UseWakeLockReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWakeLockReturn {
+///     is_supported,
+///     is_active,
+///     request,
+///     release,
+///     ..
+/// } = use_wake_lock();
+///
+/// view! {
+///     <button on:click=move |_| if is_active.get() { release() } else { request() }>
+///         {move || if is_active.get() { "Allow screen to sleep" } else { "Keep screen on" }}
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// ## Reacquiring the lock
+///
+/// The browser releases the lock when the page is hidden, e.g. when the user switches tabs. By default
+/// the lock is requested again automatically as soon as the page becomes visible. This can be disabled with
+/// the option `auto_reacquire`. The lock isn't reacquired after you have called `release`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_wake_lock_with_options, UseWakeLockOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let wake_lock = use_wake_lock_with_options(UseWakeLockOptions::default().auto_reacquire(false));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Release reason
+///
+/// `release_reason` tells you why the lock isn't active (anymore). This way you can explain to the user for
+/// example that the lock has been refused because the device is in battery saver mode.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_wake_lock, UseWakeLockReturn, WakeLockReleaseReason};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWakeLockReturn { release_reason, .. } = use_wake_lock();
+///
+/// let message = move || match release_reason.get() {
+///     Some(WakeLockReleaseReason::Refused { .. }) => {
+///         "The screen can't be kept on, maybe because battery saver is enabled"
+///     }
+///     Some(WakeLockReleaseReason::System) => "The system has released the lock",
+///     _ => "",
+/// };
+/// #
+/// # view! { <p>{message}</p> }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `request` and `release` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_wake_lock`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_active` are always `false`, `release_reason` is `None` and
+/// the returned closures are no-ops.
+pub fn use_wake_lock(
+) -> UseWakeLockReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    use_wake_lock_with_options(UseWakeLockOptions::default())
+}
+
+/// Version of [`use_wake_lock`] that takes a `UseWakeLockOptions`. See [`use_wake_lock`] for how to use.
+pub fn use_wake_lock_with_options(
+    options: UseWakeLockOptions,
+) -> UseWakeLockReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
+    let UseWakeLockOptions { auto_reacquire } = options;
+
+    let is_supported = use_supported(|| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            crate::js!("wakeLock" in &window().navigator())
+        }
+    });
+
+    let (is_active, set_active) = signal(false);
+    let (release_reason, set_release_reason) = signal(None::<WakeLockReleaseReason>);
+
+    let request;
+    let release;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = set_active;
+        let _ = set_release_reason;
+
+        request = || {};
+        release = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{js, js_fut, sendwrap_fn, use_event_listener};
+        use leptos::ev::visibilitychange;
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::{JsCast, JsValue};
+
+        let sentinel = StoredValue::new_local(None::<JsValue>);
+        // `true` between calling `request` and `release`
+        let is_wanted = StoredValue::new(false);
+        // `true` while a request for the lock hasn't been resolved yet
+        let is_pending = StoredValue::new(false);
+
+        let release_lock = |lock: &JsValue| {
+            let _ = js!(lock["release"])
+                .and_then(|release| release.unchecked_into::<js_sys::Function>().call0(lock));
+        };
+
+        let acquire = move || {
+            if is_pending.get_value() {
+                return;
+            }
+
+            let navigator = window().navigator();
+
+            let Ok(promise) = js!(navigator["wakeLock"])
+                .and_then(|wake_lock| {
+                    js!(wake_lock["request"]).and_then(|request| {
+                        request
+                            .unchecked_into::<js_sys::Function>()
+                            .call1(&wake_lock, &"screen".into())
+                    })
+                })
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+            else {
+                return;
+            };
+
+            is_pending.set_value(true);
+
+            leptos::task::spawn_local(async move {
+                let result = js_fut!(promise).await;
+                is_pending.try_set_value(false);
+
+                match result {
+                    Ok(lock) => {
+                        // `release` has been called or the component has been unmounted in the meantime
+                        if !is_wanted.try_get_value().unwrap_or_default() {
+                            release_lock(&lock);
+                            return;
+                        }
+
+                        let on_release = Closure::once_into_js({
+                            let lock = lock.clone();
+
+                            move || {
+                                // a newer lock has replaced this one
+                                let is_current = sentinel
+                                    .try_with_value(|current| {
+                                        current.as_ref().is_some_and(|current| {
+                                            js_sys::Object::is(current, &lock)
+                                        })
+                                    })
+                                    .unwrap_or_default();
+
+                                if !is_current {
+                                    return;
+                                }
+
+                                let reason = if !is_wanted.try_get_value().unwrap_or_default() {
+                                    WakeLockReleaseReason::Released
+                                } else if document().hidden() {
+                                    WakeLockReleaseReason::DocumentHidden
+                                } else {
+                                    WakeLockReleaseReason::System
+                                };
+
+                                sentinel.try_set_value(None);
+                                let _ = set_active.try_set(false);
+                                let _ = set_release_reason.try_set(Some(reason));
+                            }
+                        });
+
+                        let _ = lock
+                            .unchecked_ref::<web_sys::EventTarget>()
+                            .add_event_listener_with_callback(
+                                "release",
+                                on_release.unchecked_ref(),
+                            );
+
+                        if let Some(Some(previous)) =
+                            sentinel.try_update_value(|sentinel| sentinel.replace(lock))
+                        {
+                            release_lock(&previous);
+                        }
+                        let _ = set_active.try_set(true);
+                        let _ = set_release_reason.try_set(None);
+                    }
+                    Err(err) => {
+                        let string = |key: &str| {
+                            js_sys::Reflect::get(&err, &key.into())
+                                .ok()
+                                .and_then(|value| value.as_string())
+                                .unwrap_or_default()
+                        };
+
+                        let _ = set_release_reason.try_set(Some(WakeLockReleaseReason::Refused {
+                            name: string("name"),
+                            message: string("message"),
+                        }));
+                    }
+                }
+            });
+        };
+
+        let _ = use_event_listener(document(), visibilitychange, move |_| {
+            if auto_reacquire
+                && !document().hidden()
+                && is_wanted.get_value()
+                && !is_active.get_untracked()
+            {
+                acquire();
+            }
+        });
+
+        request = sendwrap_fn!(move || {
+            if !is_supported.get_untracked() || is_active.get_untracked() {
+                return;
+            }
+
+            is_wanted.set_value(true);
+
+            // the lock can't be acquired while the page is hidden
+            if !document().hidden() {
+                acquire();
+            }
+        });
+
+        release = sendwrap_fn!(move || {
+            is_wanted.try_set_value(false);
+
+            if let Some(Some(lock)) = sentinel.try_get_value() {
+                release_lock(&lock);
+            }
+        });
+
+        on_cleanup(release.clone());
+    }
+
+    UseWakeLockReturn {
+        is_supported,
+        is_active: is_active.into(),
+        release_reason: release_reason.into(),
+        request,
+        release,
+    }
+}
+
+/// Options for [`use_wake_lock_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseWakeLockOptions {
+    /// Request the lock again when the page becomes visible after it has been released because the page was hidden.
+    /// Defaults to `true`.
+    auto_reacquire: bool,
+}
+
+impl Default for UseWakeLockOptions {
+    fn default() -> Self {
+        Self {
+            auto_reacquire: true,
+        }
+    }
+}
+
+/// Why the wake lock isn't active. See [`use_wake_lock`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WakeLockReleaseReason {
+    /// The lock has been released by calling `release`.
+    Released,
+
+    /// The browser has released the lock because the page has been hidden.
+    DocumentHidden,
+
+    /// The system has released the lock while the page was visible, e.g. because the battery is low.
+    System,
+
+    /// The request for the lock has been refused, e.g. because of battery saver mode or a permissions policy.
+    Refused {
+        /// The name of the error, usually `"NotAllowedError"`
+        name: String,
+
+        /// The error message provided by the browser
+        message: String,
+    },
+}
+
+/// Return type of [`use_wake_lock`].
+pub struct UseWakeLockReturn<RequestFn, ReleaseFn>
+where
+    RequestFn: Fn() + Clone + Send + Sync,
+    ReleaseFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the Screen Wake Lock API is supported
+    pub is_supported: Signal<bool>,
+
+    /// `true` while the screen is kept on
+    pub is_active: Signal<bool>,
+
+    /// Why the lock isn't active. `None` while the lock is active or before it has been requested.
+    pub release_reason: Signal<Option<WakeLockReleaseReason>>,
+
+    /// Request the wake lock. If the page is hidden the lock is requested once it becomes visible.
+    pub request: RequestFn,
+
+    /// Release the wake lock
+    pub release: ReleaseFn,
+}
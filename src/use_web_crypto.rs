@@ -0,0 +1,583 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::cell::Cell;
+use std::rc::Rc;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive hashing and helpers for signing and encryption with the
+/// [Web Crypto API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Crypto_API).
+///
+/// Computes the digest of a reactive string whenever it changes. For message authentication and
+/// encryption the module provides [`HmacKey`] and [`AesGcmKey`] whose async methods wrap
+/// [`SubtleCrypto`](https://developer.mozilla.org/en-US/docs/Web/API/SubtleCrypto).
+///
+/// > The Web Crypto API is only available in [secure contexts](https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts),
+/// > i.e. on HTTPS pages or `localhost`.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_web_crypto)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_web_crypto, UseWebCryptoReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (text, set_text) = signal("Hello".to_string());
+///
+/// let UseWebCryptoReturn { hex, .. } = use_web_crypto(text);
+///
+/// view! { <p>"SHA-256: " {hex}</p> }
+/// # }
+/// ```
+///
+/// The hash algorithm defaults to SHA-256 and can be changed with the option `algorithm`.
+/// Results of outdated inputs that arrive late are discarded.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_web_crypto_with_options, HashAlgorithm, UseWebCryptoOptions, UseWebCryptoReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (text, set_text) = signal("Hello".to_string());
+/// let UseWebCryptoReturn { digest, .. } = use_web_crypto_with_options(
+///     text,
+///     UseWebCryptoOptions::default().algorithm(HashAlgorithm::Sha512),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## HMAC
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{HashAlgorithm, HmacKey};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// leptos::task::spawn_local(async move {
+///     let key = HmacKey::generate(HashAlgorithm::Sha256, true).await.unwrap();
+///
+///     let signature = key.sign(b"message").await.unwrap();
+///     assert!(key.verify(&signature, b"message").await.unwrap());
+///
+///     // store the raw key and import it later with `HmacKey::import`
+///     let raw_key = key.export().await.unwrap();
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## AES-GCM
+///
+/// Every encryption uses a new random initialization vector which is returned together with the
+/// encrypted data as [`AesGcmCiphertext`]. Use [`AesGcmCiphertext::to_bytes`] to store or send both.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{AesGcmCiphertext, AesGcmKey, AesKeyLength};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// leptos::task::spawn_local(async move {
+///     let key = AesGcmKey::generate(AesKeyLength::Aes256, false).await.unwrap();
+///
+///     let ciphertext = key.encrypt(b"secret").await.unwrap();
+///     let bytes = ciphertext.to_bytes();
+///
+///     let ciphertext = AesGcmCiphertext::from_bytes(&bytes).unwrap();
+///     assert_eq!(key.decrypt(&ciphertext).await.unwrap(), b"secret");
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `digest` and `hex` are always `None` and all key methods return
+/// `Err(UseWebCryptoError::Server)`.
+pub fn use_web_crypto(input: impl Into<Signal<String>>) -> UseWebCryptoReturn {
+    use_web_crypto_with_options(input, UseWebCryptoOptions::default())
+}
+
+/// Version of [`use_web_crypto`] that takes a `UseWebCryptoOptions`. See [`use_web_crypto`] for how to use.
+pub fn use_web_crypto_with_options(
+    input: impl Into<Signal<String>>,
+    options: UseWebCryptoOptions,
+) -> UseWebCryptoReturn {
+    let UseWebCryptoOptions { algorithm } = options;
+
+    let input = input.into();
+
+    let is_supported = use_supported(|| {
+        window()
+            .crypto()
+            .is_ok_and(|crypto| js!("subtle" in &crypto))
+    });
+
+    let (digest, set_digest) = signal(None::<Vec<u8>>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let generation = Rc::new(Cell::new(0_u64));
+
+        Effect::new(move |_| {
+            let input = input.get();
+
+            if !is_supported.get() {
+                return;
+            }
+
+            let current = generation.get() + 1;
+            generation.set(current);
+
+            let generation = Rc::clone(&generation);
+
+            leptos::task::spawn_local(async move {
+                let result = digest_bytes(algorithm, input.as_bytes()).await.ok();
+
+                if generation.get() == current {
+                    set_digest.try_set(result);
+                }
+            });
+        });
+    }
+
+    UseWebCryptoReturn {
+        is_supported,
+        digest: digest.into(),
+        hex: Signal::derive(move || digest.with(|digest| digest.as_deref().map(to_hex))),
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn subtle() -> Result<web_sys::SubtleCrypto, UseWebCryptoError> {
+    let crypto = window()
+        .crypto()
+        .map_err(|_| UseWebCryptoError::NotSupported)?;
+
+    if !js!("subtle" in &crypto) {
+        return Err(UseWebCryptoError::NotSupported);
+    }
+
+    Ok(crypto.subtle())
+}
+
+/// Creates an algorithm object like `{ name: "AES-GCM" }`.
+#[cfg(not(feature = "ssr"))]
+fn algorithm_object(name: &str) -> js_sys::Object {
+    let algorithm = js_sys::Object::new();
+    js!(algorithm["name"] = name);
+    algorithm
+}
+
+#[cfg(not(feature = "ssr"))]
+fn key_usages(usages: &[&str]) -> js_sys::Array {
+    usages
+        .iter()
+        .map(|usage| JsValue::from_str(usage))
+        .collect()
+}
+
+/// Awaits a promise that resolves to an `ArrayBuffer` and copies its content.
+#[cfg(not(feature = "ssr"))]
+async fn buffer_promise(
+    promise: Result<js_sys::Promise, JsValue>,
+) -> Result<Vec<u8>, UseWebCryptoError> {
+    let promise = promise.map_err(UseWebCryptoError::Failed)?;
+
+    let buffer = crate::js_fut!(promise)
+        .await
+        .map_err(UseWebCryptoError::Failed)?;
+
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+/// Awaits a promise that resolves to a `CryptoKey`.
+#[cfg(not(feature = "ssr"))]
+async fn key_promise(
+    promise: Result<js_sys::Promise, JsValue>,
+) -> Result<web_sys::CryptoKey, UseWebCryptoError> {
+    use wasm_bindgen::JsCast;
+
+    let promise = promise.map_err(UseWebCryptoError::Failed)?;
+
+    crate::js_fut!(promise)
+        .await
+        .map_err(UseWebCryptoError::Failed)?
+        .dyn_into()
+        .map_err(UseWebCryptoError::Failed)
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn digest_bytes(algorithm: HashAlgorithm, data: &[u8]) -> Result<Vec<u8>, UseWebCryptoError> {
+    buffer_promise(subtle()?.digest_with_object_and_buffer_source(
+        &algorithm_object(algorithm.as_str()),
+        &js_sys::Uint8Array::from(data),
+    ))
+    .await
+}
+
+/// Hash algorithms supported by the Web Crypto API.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// SHA-1. Not secure anymore and only meant for compatibility.
+    Sha1,
+    #[default]
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    /// The name the Web Crypto API uses for the algorithm, e.g. `"SHA-256"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sha1 => "SHA-1",
+            Self::Sha256 => "SHA-256",
+            Self::Sha384 => "SHA-384",
+            Self::Sha512 => "SHA-512",
+        }
+    }
+}
+
+/// A key to sign and verify messages with HMAC.
+#[derive(Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct HmacKey {
+    key: web_sys::CryptoKey,
+}
+
+impl HmacKey {
+    /// Generates a new random key for HMAC with the given hash algorithm. Only keys that are
+    /// `extractable` can be exported.
+    pub async fn generate(
+        hash: HashAlgorithm,
+        extractable: bool,
+    ) -> Result<Self, UseWebCryptoError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebCryptoError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let key = key_promise(subtle()?.generate_key_with_object(
+                &hmac_algorithm(hash),
+                extractable,
+                &key_usages(&["sign", "verify"]),
+            ))
+            .await?;
+
+            Ok(Self { key })
+        }
+    }
+
+    /// Imports a raw key for HMAC with the given hash algorithm.
+    pub async fn import(
+        raw: &[u8],
+        hash: HashAlgorithm,
+        extractable: bool,
+    ) -> Result<Self, UseWebCryptoError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebCryptoError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let key = key_promise(subtle()?.import_key_with_object(
+                "raw",
+                &js_sys::Uint8Array::from(raw),
+                &hmac_algorithm(hash),
+                extractable,
+                &key_usages(&["sign", "verify"]),
+            ))
+            .await?;
+
+            Ok(Self { key })
+        }
+    }
+
+    /// Exports the raw key. Fails if the key isn't extractable.
+    pub async fn export(&self) -> Result<Vec<u8>, UseWebCryptoError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebCryptoError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        buffer_promise(subtle()?.export_key("raw", &self.key)).await
+    }
+
+    /// Computes the signature of `data`.
+    pub async fn sign(&self, data: &[u8]) -> Result<Vec<u8>, UseWebCryptoError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebCryptoError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        buffer_promise(subtle()?.sign_with_object_and_buffer_source(
+            &algorithm_object("HMAC"),
+            &self.key,
+            &js_sys::Uint8Array::from(data),
+        ))
+        .await
+    }
+
+    /// Returns `true` if `signature` is a valid signature of `data`.
+    pub async fn verify(&self, signature: &[u8], data: &[u8]) -> Result<bool, UseWebCryptoError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebCryptoError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let promise = subtle()?
+                .verify_with_object_and_buffer_source_and_buffer_source(
+                    &algorithm_object("HMAC"),
+                    &self.key,
+                    &js_sys::Uint8Array::from(signature),
+                    &js_sys::Uint8Array::from(data),
+                )
+                .map_err(UseWebCryptoError::Failed)?;
+
+            Ok(crate::js_fut!(promise)
+                .await
+                .map_err(UseWebCryptoError::Failed)?
+                .as_bool()
+                .unwrap_or_default())
+        }
+    }
+
+    /// The underlying [`web_sys::CryptoKey`].
+    pub fn crypto_key(&self) -> &web_sys::CryptoKey {
+        &self.key
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn hmac_algorithm(hash: HashAlgorithm) -> js_sys::Object {
+    let algorithm = algorithm_object("HMAC");
+    js!(algorithm["hash"] = hash.as_str());
+    algorithm
+}
+
+/// Length of an AES key.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AesKeyLength {
+    Aes128,
+    #[default]
+    Aes256,
+}
+
+impl AesKeyLength {
+    /// The length of the key in bits.
+    pub fn bits(&self) -> u32 {
+        match self {
+            Self::Aes128 => 128,
+            Self::Aes256 => 256,
+        }
+    }
+}
+
+/// A key to encrypt and decrypt data with AES-GCM.
+#[derive(Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct AesGcmKey {
+    key: web_sys::CryptoKey,
+}
+
+impl AesGcmKey {
+    /// Generates a new random AES-GCM key. Only keys that are `extractable` can be exported.
+    pub async fn generate(
+        length: AesKeyLength,
+        extractable: bool,
+    ) -> Result<Self, UseWebCryptoError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebCryptoError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let algorithm = algorithm_object(AES_GCM);
+            js!(algorithm["length"] = length.bits());
+
+            let key = key_promise(subtle()?.generate_key_with_object(
+                &algorithm,
+                extractable,
+                &key_usages(&["encrypt", "decrypt"]),
+            ))
+            .await?;
+
+            Ok(Self { key })
+        }
+    }
+
+    /// Imports a raw AES key. It has to be 16 or 32 bytes long.
+    pub async fn import(raw: &[u8], extractable: bool) -> Result<Self, UseWebCryptoError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebCryptoError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let key = key_promise(subtle()?.import_key_with_object(
+                "raw",
+                &js_sys::Uint8Array::from(raw),
+                &algorithm_object(AES_GCM),
+                extractable,
+                &key_usages(&["encrypt", "decrypt"]),
+            ))
+            .await?;
+
+            Ok(Self { key })
+        }
+    }
+
+    /// Exports the raw key. Fails if the key isn't extractable.
+    pub async fn export(&self) -> Result<Vec<u8>, UseWebCryptoError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebCryptoError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        buffer_promise(subtle()?.export_key("raw", &self.key)).await
+    }
+
+    /// Encrypts `plaintext` with a new random initialization vector.
+    pub async fn encrypt(&self, plaintext: &[u8]) -> Result<AesGcmCiphertext, UseWebCryptoError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebCryptoError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let subtle = subtle()?;
+
+            let iv = js_sys::Uint8Array::new_with_length(AES_GCM_IV_LENGTH as u32);
+            window()
+                .crypto()
+                .map_err(UseWebCryptoError::Failed)?
+                .get_random_values_with_array_buffer_view(&iv)
+                .map_err(UseWebCryptoError::Failed)?;
+
+            let data = buffer_promise(subtle.encrypt_with_object_and_buffer_source(
+                &aes_gcm_params(&iv),
+                &self.key,
+                &js_sys::Uint8Array::from(plaintext),
+            ))
+            .await?;
+
+            Ok(AesGcmCiphertext {
+                iv: iv.to_vec(),
+                data,
+            })
+        }
+    }
+
+    /// Decrypts a ciphertext created by [`AesGcmKey::encrypt`]. Fails if the data has been
+    /// tampered with or was encrypted with another key.
+    pub async fn decrypt(
+        &self,
+        ciphertext: &AesGcmCiphertext,
+    ) -> Result<Vec<u8>, UseWebCryptoError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebCryptoError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        buffer_promise(subtle()?.decrypt_with_object_and_buffer_source(
+            &aes_gcm_params(&js_sys::Uint8Array::from(ciphertext.iv.as_slice())),
+            &self.key,
+            &js_sys::Uint8Array::from(ciphertext.data.as_slice()),
+        ))
+        .await
+    }
+
+    /// The underlying [`web_sys::CryptoKey`].
+    pub fn crypto_key(&self) -> &web_sys::CryptoKey {
+        &self.key
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+const AES_GCM: &str = "AES-GCM";
+
+/// Length of the initialization vector in bytes as recommended for AES-GCM.
+const AES_GCM_IV_LENGTH: usize = 12;
+
+#[cfg(not(feature = "ssr"))]
+fn aes_gcm_params(iv: &js_sys::Uint8Array) -> js_sys::Object {
+    let params = algorithm_object(AES_GCM);
+    js!(params["iv"] = iv);
+    params
+}
+
+/// Encrypted data together with the initialization vector it was encrypted with.
+/// The initialization vector doesn't have to be kept secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AesGcmCiphertext {
+    /// The 12 byte initialization vector
+    pub iv: Vec<u8>,
+
+    /// The encrypted data including the authentication tag
+    pub data: Vec<u8>,
+}
+
+impl AesGcmCiphertext {
+    /// Concatenates the initialization vector and the encrypted data.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.iv.as_slice(), self.data.as_slice()].concat()
+    }
+
+    /// Splits bytes created by [`AesGcmCiphertext::to_bytes`]. Returns `None` if they are too short.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < AES_GCM_IV_LENGTH {
+            return None;
+        }
+
+        let (iv, data) = bytes.split_at(AES_GCM_IV_LENGTH);
+
+        Some(Self {
+            iv: iv.to_vec(),
+            data: data.to_vec(),
+        })
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum UseWebCryptoError {
+    #[error("Web Crypto is not available on the server")]
+    Server,
+
+    #[error("Web Crypto is not supported by this browser or the page isn't served securely")]
+    NotSupported,
+
+    #[error("Crypto operation failed")]
+    Failed(JsValue),
+}
+
+/// Options for [`use_web_crypto_with_options`].
+#[derive(DefaultBuilder, Default)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseWebCryptoOptions {
+    /// The hash algorithm used to compute the digest. Defaults to [`HashAlgorithm::Sha256`].
+    algorithm: HashAlgorithm,
+}
+
+/// Return type of [`use_web_crypto`].
+pub struct UseWebCryptoReturn {
+    /// `true` if the browser supports the Web Crypto API in the current context
+    pub is_supported: Signal<bool>,
+
+    /// The digest of the latest input. `None` until it has been computed.
+    pub digest: Signal<Option<Vec<u8>>>,
+
+    /// `digest` as lowercase hex string
+    pub hex: Signal<Option<String>>,
+}
@@ -1,5 +1,9 @@
+use crate::utils::LocalBoxFuture;
+use crate::{sendwrap_fn, use_interval_fn};
 use default_struct_builder::DefaultBuilder;
-use std::future::Future;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::future::{Future, IntoFuture};
 use thiserror::Error;
 use wasm_bindgen::JsValue;
 pub use web_sys::LockMode;
@@ -40,64 +44,133 @@ pub use web_sys::LockMode;
 /// # }
 /// ```
 ///
+/// The request is made right away. Besides being awaitable, the returned [`UseWebLockReturn`] reports
+/// the state of this request with the signals `is_pending` (waiting until the lock is granted) and
+/// `is_held` (the callback is running).
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_web_lock;
+/// #
+/// # async fn my_process(_lock: web_sys::Lock) {}
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let lock = use_web_lock("my_lock", my_process);
+/// let (is_pending, is_held) = (lock.is_pending, lock.is_held);
+///
+/// leptos::task::spawn_local(async move {
+///     let _ = lock.await;
+/// });
+///
+/// view! {
+///     <Show when=move || is_pending.get()>"Waiting for another tab..."</Show>
+///     <Show when=move || is_held.get()>"Working..."</Show>
+/// }
+/// # }
+/// ```
+///
+/// ## Locks of Other Tabs
+///
+/// To see the locks that are held or requested by any tab or worker of the same origin use
+/// [`use_web_lock_snapshot`]. The Web Locks API doesn't notify about changes of these so the snapshot
+/// is only read when calling `refresh` unless polling is enabled with the option `interval`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_web_lock_snapshot_with_options, UseWebLockSnapshotOptions, UseWebLockSnapshotReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebLockSnapshotReturn { is_held, pending, .. } = use_web_lock_snapshot_with_options(
+///     UseWebLockSnapshotOptions::default()
+///         .name(Some("my_lock".to_string()))
+///         .interval(Some(1000)),
+/// );
+///
+/// view! {
+///     <Show when=move || is_held.get()>
+///         "Some tab is working. " {move || pending.get().len()} " requests are waiting."
+///     </Show>
+/// }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
-/// On the server this returns `Err(UseWebLockError::Server)` and the task is not executed.
+/// On the server this resolves to `Err(UseWebLockError::Server)`, the task is not executed and
+/// `is_pending` and `is_held` are always `false`.
 // #[doc(cfg(feature = "use_web_lock"))]
-pub async fn use_web_lock<C, F, R>(name: &str, callback: C) -> Result<R, UseWebLockError>
+pub fn use_web_lock<C, F, R>(name: &str, callback: C) -> UseWebLockReturn<R>
 where
     C: FnOnce(web_sys::Lock) -> F + 'static,
     F: Future<Output = R>,
     R: 'static,
 {
-    use_web_lock_with_options(name, callback, UseWebLockOptions::default()).await
+    use_web_lock_with_options(name, callback, UseWebLockOptions::default())
 }
 
 /// Version of [`fn@crate::use_web_lock`] that takes a `UseWebLockOptions`. See [`fn@crate::use_web_lock`] for how to use.
 // #[doc(cfg(feature = "use_web_lock"))]
-pub async fn use_web_lock_with_options<C, F, R>(
+pub fn use_web_lock_with_options<C, F, R>(
     name: &str,
     callback: C,
     options: UseWebLockOptions,
-) -> Result<R, UseWebLockError>
+) -> UseWebLockReturn<R>
 where
     C: FnOnce(web_sys::Lock) -> F + 'static,
     F: Future<Output = R>,
     R: 'static,
 {
+    let (is_pending, set_pending) = signal(false);
+    let (is_held, set_held) = signal(false);
+
+    let result: LocalBoxFuture<Result<R, UseWebLockError>>;
+
     #[cfg(feature = "ssr")]
     {
         let _ = name;
         let _ = callback;
         let _ = options;
+        let _ = set_pending;
+        let _ = set_held;
 
-        Err(UseWebLockError::Server)
+        result = Box::pin(std::future::ready(Err(UseWebLockError::Server)));
     }
 
     #[cfg(not(feature = "ssr"))]
     {
         use crate::js_fut;
         use leptos::prelude::window;
-        use std::sync::{Arc, Mutex};
+        use std::cell::RefCell;
+        use std::rc::Rc;
         use wasm_bindgen::closure::Closure;
         use wasm_bindgen::JsCast;
         use wasm_bindgen_futures::future_to_promise;
 
-        let ret_value = Arc::new(Mutex::new(None));
+        let ret_value = Rc::new(RefCell::new(None));
 
         let handler = Closure::once(Box::new({
-            let ret_value = Arc::clone(&ret_value);
+            let ret_value = Rc::clone(&ret_value);
+
+            move |lock: web_sys::Lock| {
+                set_pending.try_set(false);
+                // with `if_available` the callback is called with `null` if the lock isn't available
+                set_held.try_set(!lock.is_null());
 
-            move |lock| {
                 future_to_promise(async move {
                     let ret = callback(lock).await;
-                    ret_value.lock().expect("Lock failed").replace(ret);
+                    ret_value.replace(Some(ret));
+                    set_held.try_set(false);
+
                     Ok(JsValue::null())
                 })
             }
         }) as Box<dyn FnOnce(web_sys::Lock) -> _>)
         .into_js_value();
 
+        set_pending.set(true);
+
         let lock_promise = window()
             .navigator()
             .locks()
@@ -107,16 +180,45 @@ where
                 handler.unchecked_ref(),
             );
 
-        js_fut!(lock_promise)
-            .await
-            .map(move |_| {
-                Arc::into_inner(ret_value)
-                    .expect("Arc has more than one reference still")
-                    .into_inner()
-                    .expect("Lock failed")
-                    .expect("Return value was None")
+        result = Box::pin(async move {
+            let res = js_fut!(lock_promise).await;
+
+            set_pending.try_set(false);
+            set_held.try_set(false);
+
+            res.map_err(UseWebLockError::Failed).and_then(move |_| {
+                ret_value
+                    .take()
+                    .ok_or_else(|| UseWebLockError::Failed(JsValue::undefined()))
             })
-            .map_err(UseWebLockError::Failed)
+        });
+    }
+
+    UseWebLockReturn {
+        is_pending: is_pending.into(),
+        is_held: is_held.into(),
+        result,
+    }
+}
+
+/// Return type of [`fn@crate::use_web_lock`]. Await it to get the result of the callback.
+// #[doc(cfg(feature = "use_web_lock"))]
+pub struct UseWebLockReturn<R> {
+    /// `true` while this request waits for the lock to be granted
+    pub is_pending: Signal<bool>,
+
+    /// `true` while this request holds the lock, i.e. while the callback is running
+    pub is_held: Signal<bool>,
+
+    result: LocalBoxFuture<Result<R, UseWebLockError>>,
+}
+
+impl<R> IntoFuture for UseWebLockReturn<R> {
+    type Output = Result<R, UseWebLockError>;
+    type IntoFuture = LocalBoxFuture<Self::Output>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.result
     }
 }
 
@@ -171,3 +273,150 @@ impl Default for UseWebLockOptions {
         }
     }
 }
+
+/// Reactive snapshot of the locks of all tabs and workers of the origin as returned by
+/// [`navigator.locks.query()`](https://developer.mozilla.org/en-US/docs/Web/API/LockManager/query).
+///
+/// The Web Locks API doesn't notify about changes so the snapshot is only read initially and when
+/// calling `refresh`. Set the option `interval` to read it periodically.
+/// To track a lock requested by this component use the return value of [`fn@crate::use_web_lock`].
+/// See [`fn@crate::use_web_lock`] for how to use.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the lists are empty, `is_held` is `false` and `refresh` does nothing.
+// #[doc(cfg(feature = "use_web_lock"))]
+pub fn use_web_lock_snapshot() -> UseWebLockSnapshotReturn<impl Fn() + Clone + Send + Sync> {
+    use_web_lock_snapshot_with_options(UseWebLockSnapshotOptions::default())
+}
+
+/// Version of [`fn@crate::use_web_lock_snapshot`] that takes a `UseWebLockSnapshotOptions`. See [`fn@crate::use_web_lock`] for how to use.
+// #[doc(cfg(feature = "use_web_lock"))]
+pub fn use_web_lock_snapshot_with_options(
+    options: UseWebLockSnapshotOptions,
+) -> UseWebLockSnapshotReturn<impl Fn() + Clone + Send + Sync> {
+    let UseWebLockSnapshotOptions { name, interval } = options;
+
+    let (held, set_held) = signal(Vec::<WebLockInfo>::new());
+    let (pending, set_pending) = signal(Vec::<WebLockInfo>::new());
+
+    let refresh = {
+        let name = name.clone();
+
+        move || {
+            #[cfg(feature = "ssr")]
+            {
+                let _ = &name;
+                let _ = set_held;
+                let _ = set_pending;
+            }
+
+            #[cfg(not(feature = "ssr"))]
+            {
+                use crate::js_fut;
+
+                let name = name.clone();
+
+                leptos::task::spawn_local(async move {
+                    let Ok(snapshot) = js_fut!(window().navigator().locks().query()).await else {
+                        return;
+                    };
+
+                    let read = |key: &str| {
+                        let locks =
+                            js_sys::Reflect::get(&snapshot, &key.into()).unwrap_or_default();
+
+                        js_sys::Array::from(&locks)
+                            .iter()
+                            .map(WebLockInfo::from_js)
+                            .filter(|info| name.as_ref().is_none_or(|name| &info.name == name))
+                            .collect::<Vec<_>>()
+                    };
+
+                    set_held.try_set(read("held"));
+                    set_pending.try_set(read("pending"));
+                });
+            }
+        }
+    };
+
+    refresh();
+
+    if let Some(interval) = interval {
+        let _ = use_interval_fn(refresh.clone(), interval);
+    }
+
+    UseWebLockSnapshotReturn {
+        held: held.into(),
+        pending: pending.into(),
+        is_held: Signal::derive(move || held.with(|held| !held.is_empty())),
+        refresh: sendwrap_fn!(move || refresh()),
+    }
+}
+
+/// Information about a lock in the snapshot of [`fn@crate::use_web_lock_snapshot`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct WebLockInfo {
+    /// The name of the lock
+    pub name: String,
+
+    /// Whether the lock is exclusive or shared
+    pub mode: LockMode,
+
+    /// The id of the tab or worker that holds or requested the lock
+    pub client_id: String,
+}
+
+impl WebLockInfo {
+    #[cfg(not(feature = "ssr"))]
+    fn from_js(info: JsValue) -> Self {
+        let read = |key: &str| {
+            js_sys::Reflect::get(&info, &key.into())
+                .ok()
+                .and_then(|value| value.as_string())
+                .unwrap_or_default()
+        };
+
+        Self {
+            name: read("name"),
+            mode: if read("mode") == "shared" {
+                LockMode::Shared
+            } else {
+                LockMode::Exclusive
+            },
+            client_id: read("clientId"),
+        }
+    }
+}
+
+/// Options for [`fn@crate::use_web_lock_snapshot_with_options`].
+// #[doc(cfg(feature = "use_web_lock"))]
+#[derive(DefaultBuilder, Default)]
+pub struct UseWebLockSnapshotOptions {
+    /// If set only locks with this name are part of the snapshot. Defaults to `None`.
+    name: Option<String>,
+
+    /// If set the snapshot is read every `interval` milliseconds. Defaults to `None` (no polling).
+    interval: Option<u64>,
+}
+
+/// Return type of [`fn@crate::use_web_lock_snapshot`].
+// #[doc(cfg(feature = "use_web_lock"))]
+pub struct UseWebLockSnapshotReturn<RefreshFn>
+where
+    RefreshFn: Fn() + Clone + Send + Sync,
+{
+    /// Locks that are currently held
+    pub held: Signal<Vec<WebLockInfo>>,
+
+    /// Lock requests that are waiting to be granted
+    pub pending: Signal<Vec<WebLockInfo>>,
+
+    /// `true` if any lock of the snapshot is held by any tab or worker. With the option `name` this
+    /// tells if that lock is held by anyone. Use `is_held` of [`fn@crate::use_web_lock`] to know if
+    /// this component holds the lock.
+    pub is_held: Signal<bool>,
+
+    /// Reads the snapshot right away, for example after acquiring a lock
+    pub refresh: RefreshFn,
+}
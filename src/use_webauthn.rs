@@ -0,0 +1,469 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::utils::{base64url_decode, base64url_encode};
+use crate::{js, use_supported};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// High level [Web Authentication API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Authentication_API)
+/// to register and sign in with passkeys.
+///
+/// The methods of the returned [`WebauthnClient`] take the options that your server generated as JSON, in the
+/// format of `PublicKeyCredentialCreationOptionsJSON` and `PublicKeyCredentialRequestOptionsJSON` with all binary
+/// fields base64url encoded. The options may also be wrapped in a `publicKey` object like many server libraries do.
+/// The resulting credential is returned as JSON with base64url encoded binary fields, ready to be sent to the server.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_webauthn)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_webauthn, UseWebauthnReturn};
+/// #
+/// # async fn fetch_registration_options() -> String { String::new() }
+/// # async fn send_to_server(credential: String) {}
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebauthnReturn {
+///     is_supported,
+///     is_pending,
+///     client,
+///     ..
+/// } = use_webauthn();
+///
+/// let create_passkey = move |_| {
+///     leptos::task::spawn_local(async move {
+///         let options = fetch_registration_options().await;
+///
+///         if let Ok(credential) = client.register(&options).await {
+///             send_to_server(credential).await;
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=create_passkey disabled=move || !is_supported.get() || is_pending.get()>
+///         "Create passkey"
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// ## Conditional UI
+///
+/// If `is_conditional_mediation_available` is `true` the browser can offer the passkeys in the autofill of an
+/// input with `autocomplete="username webauthn"`. Start such a request with `authenticate_conditional` when the
+/// page loads. It's aborted automatically when another ceremony is started.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_webauthn, UseWebauthnReturn};
+/// #
+/// # async fn fetch_authentication_options() -> String { String::new() }
+/// # async fn send_to_server(credential: String) {}
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebauthnReturn {
+///     is_conditional_mediation_available,
+///     client,
+///     ..
+/// } = use_webauthn();
+///
+/// Effect::new(move || {
+///     if is_conditional_mediation_available.get() {
+///         leptos::task::spawn_local(async move {
+///             let options = fetch_authentication_options().await;
+///
+///             if let Ok(credential) = client.authenticate_conditional(&options).await {
+///                 send_to_server(credential).await;
+///             }
+///         });
+///     }
+/// });
+///
+/// view! { <input type="text" autocomplete="username webauthn"/> }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server all signals are `false` and the methods of the [`WebauthnClient`] return
+/// `Err(UseWebauthnError::Server)`.
+pub fn use_webauthn() -> UseWebauthnReturn {
+    let is_supported = use_supported(|| js!("PublicKeyCredential" in &window()));
+
+    let (is_conditional_mediation_available, set_is_conditional_mediation_available) =
+        signal(false);
+    let (is_platform_authenticator_available, set_is_platform_authenticator_available) =
+        signal(false);
+    let (is_pending, set_is_pending) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        leptos::task::spawn_local(async move {
+            set_is_conditional_mediation_available.try_set(
+                call_static_bool("isConditionalMediationAvailable")
+                    .await
+                    .unwrap_or_default(),
+            );
+            set_is_platform_authenticator_available.try_set(
+                call_static_bool("isUserVerifyingPlatformAuthenticatorAvailable")
+                    .await
+                    .unwrap_or_default(),
+            );
+        });
+    }
+
+    UseWebauthnReturn {
+        is_supported,
+        is_conditional_mediation_available: is_conditional_mediation_available.into(),
+        is_platform_authenticator_available: is_platform_authenticator_available.into(),
+        is_pending: is_pending.into(),
+        client: WebauthnClient {
+            is_supported,
+            set_is_pending,
+            abort_controller: StoredValue::new_local(None),
+        },
+    }
+}
+
+/// Runs the WebAuthn ceremonies. Returned by [`use_webauthn`].
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct WebauthnClient {
+    is_supported: Signal<bool>,
+    set_is_pending: WriteSignal<bool>,
+    abort_controller: StoredValue<Option<web_sys::AbortController>, LocalStorage>,
+}
+
+impl WebauthnClient {
+    /// Creates a new credential from the JSON registration options of the server and returns the
+    /// credential as JSON.
+    pub async fn register(&self, options_json: &str) -> Result<String, UseWebauthnError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebauthnError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let public_key = parse_options(options_json)?;
+
+            decode_field(&public_key, "challenge")?;
+            if let Ok(user) = js!(public_key["user"]) {
+                decode_field(&user, "id")?;
+            }
+            decode_credential_list(&public_key, "excludeCredentials")?;
+
+            let options = js_sys::Object::new();
+            js!(options["publicKey"] = public_key);
+
+            self.run(options, Ceremony::Create).await
+        }
+    }
+
+    /// Signs in with an existing credential using the JSON authentication options of the server and returns
+    /// the assertion as JSON.
+    pub async fn authenticate(&self, options_json: &str) -> Result<String, UseWebauthnError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebauthnError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        self.authenticate_with_mediation(options_json, None).await
+    }
+
+    /// Like [`WebauthnClient::authenticate`] but offers the passkeys in the autofill of inputs with
+    /// `autocomplete="username webauthn"` instead of showing a dialog.
+    pub async fn authenticate_conditional(
+        &self,
+        options_json: &str,
+    ) -> Result<String, UseWebauthnError> {
+        #[cfg(feature = "ssr")]
+        return Err(UseWebauthnError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        self.authenticate_with_mediation(options_json, Some("conditional"))
+            .await
+    }
+
+    /// Aborts the pending ceremony if there is one.
+    pub fn abort(&self) {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(controller) = self
+            .abort_controller
+            .try_update_value(Option::take)
+            .flatten()
+        {
+            controller.abort();
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    async fn authenticate_with_mediation(
+        &self,
+        options_json: &str,
+        mediation: Option<&str>,
+    ) -> Result<String, UseWebauthnError> {
+        let public_key = parse_options(options_json)?;
+
+        decode_field(&public_key, "challenge")?;
+        decode_credential_list(&public_key, "allowCredentials")?;
+
+        let options = js_sys::Object::new();
+        js!(options["publicKey"] = public_key);
+        if let Some(mediation) = mediation {
+            js!(options["mediation"] = mediation);
+        }
+
+        self.run(options, Ceremony::Get).await
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    async fn run(
+        &self,
+        options: js_sys::Object,
+        ceremony: Ceremony,
+    ) -> Result<String, UseWebauthnError> {
+        use wasm_bindgen::JsCast;
+
+        if !self.is_supported.get_untracked() {
+            return Err(UseWebauthnError::NotSupported);
+        }
+
+        // only one ceremony can be pending at a time
+        self.abort();
+
+        let controller = web_sys::AbortController::new().map_err(UseWebauthnError::from_js)?;
+        js!(options["signal"] = controller.signal());
+        self.abort_controller.set_value(Some(controller));
+
+        let credentials = window().navigator().credentials();
+
+        let promise = match ceremony {
+            Ceremony::Create => credentials.create_with_options(options.unchecked_ref()),
+            Ceremony::Get => credentials.get_with_options(options.unchecked_ref()),
+        }
+        .map_err(UseWebauthnError::from_js)?;
+
+        self.set_is_pending.set(true);
+        let result = crate::js_fut!(promise).await;
+        self.set_is_pending.try_set(false);
+
+        let credential = result.map_err(UseWebauthnError::from_js)?;
+
+        if credential.is_null() {
+            return Err(UseWebauthnError::NotAllowed);
+        }
+
+        js_sys::JSON::stringify(&credential_to_json(&credential))
+            .ok()
+            .and_then(|json| json.as_string())
+            .ok_or(UseWebauthnError::Serialize)
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+enum Ceremony {
+    /// `navigator.credentials.create()` to register a new credential
+    Create,
+    /// `navigator.credentials.get()` to authenticate with an existing credential
+    Get,
+}
+
+/// Parses the JSON options of the server and unwraps the `publicKey` object if there is one.
+#[cfg(not(feature = "ssr"))]
+fn parse_options(options_json: &str) -> Result<JsValue, UseWebauthnError> {
+    let options =
+        js_sys::JSON::parse(options_json).map_err(|_| UseWebauthnError::InvalidOptions)?;
+
+    if !options.is_object() {
+        return Err(UseWebauthnError::InvalidOptions);
+    }
+
+    match js!(options["publicKey"]) {
+        Ok(public_key) if public_key.is_object() => Ok(public_key),
+        _ => Ok(options),
+    }
+}
+
+/// Replaces the base64url encoded string at `key` with an `ArrayBuffer`.
+#[cfg(not(feature = "ssr"))]
+fn decode_field(object: &JsValue, key: &str) -> Result<(), UseWebauthnError> {
+    let Some(encoded) = js_sys::Reflect::get(object, &key.into())
+        .ok()
+        .and_then(|value| value.as_string())
+    else {
+        return Ok(());
+    };
+
+    let bytes = base64url_decode(&encoded).ok_or(UseWebauthnError::InvalidOptions)?;
+
+    let _ = js_sys::Reflect::set(
+        object,
+        &key.into(),
+        &js_sys::Uint8Array::from(bytes.as_slice()).buffer(),
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "ssr"))]
+fn decode_credential_list(object: &JsValue, key: &str) -> Result<(), UseWebauthnError> {
+    let Ok(list) = js_sys::Reflect::get(object, &key.into()) else {
+        return Ok(());
+    };
+
+    if !js_sys::Array::is_array(&list) {
+        return Ok(());
+    }
+
+    for descriptor in js_sys::Array::from(&list).iter() {
+        decode_field(&descriptor, "id")?;
+    }
+
+    Ok(())
+}
+
+/// Converts a `PublicKeyCredential` into an object that can be serialized as JSON.
+#[cfg(not(feature = "ssr"))]
+fn credential_to_json(credential: &JsValue) -> js_sys::Object {
+    let json = js_sys::Object::new();
+
+    copy_value(credential, &json, "id");
+    copy_value(credential, &json, "type");
+    copy_value(credential, &json, "authenticatorAttachment");
+    copy_buffer(credential, &json, "rawId");
+
+    let extension_results = call_method(credential, "getClientExtensionResults")
+        .unwrap_or_else(|| js_sys::Object::new().into());
+    js!(json["clientExtensionResults"] = extension_results);
+
+    if let Ok(response) = js!(credential["response"]) {
+        let response_json = js_sys::Object::new();
+
+        for key in [
+            "clientDataJSON",
+            "attestationObject",
+            "authenticatorData",
+            "signature",
+            "userHandle",
+        ] {
+            copy_buffer(&response, &response_json, key);
+        }
+
+        if let Some(transports) = call_method(&response, "getTransports") {
+            js!(response_json["transports"] = transports);
+        }
+
+        js!(json["response"] = response_json);
+    }
+
+    json
+}
+
+#[cfg(not(feature = "ssr"))]
+fn copy_value(from: &JsValue, to: &js_sys::Object, key: &str) {
+    if let Ok(value) = js_sys::Reflect::get(from, &key.into()) {
+        if !value.is_undefined() {
+            let _ = js_sys::Reflect::set(to, &key.into(), &value);
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn copy_buffer(from: &JsValue, to: &js_sys::Object, key: &str) {
+    use wasm_bindgen::JsCast;
+
+    if let Some(buffer) = js_sys::Reflect::get(from, &key.into())
+        .ok()
+        .and_then(|value| value.dyn_into::<js_sys::ArrayBuffer>().ok())
+    {
+        let bytes = js_sys::Uint8Array::new(&buffer).to_vec();
+        let _ = js_sys::Reflect::set(to, &key.into(), &base64url_encode(&bytes).into());
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn call_method(object: &JsValue, method: &str) -> Option<JsValue> {
+    use wasm_bindgen::JsCast;
+
+    js_sys::Reflect::get(object, &method.into())
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?
+        .call0(object)
+        .ok()
+}
+
+/// Calls a static method of `PublicKeyCredential` that returns a promise of a boolean.
+#[cfg(not(feature = "ssr"))]
+async fn call_static_bool(method: &str) -> Option<bool> {
+    use wasm_bindgen::JsCast;
+
+    let class = js_sys::Reflect::get(&window(), &"PublicKeyCredential".into()).ok()?;
+    let promise = call_method(&class, method)?
+        .dyn_into::<js_sys::Promise>()
+        .ok()?;
+
+    crate::js_fut!(promise).await.ok()?.as_bool()
+}
+
+#[derive(Error, Debug)]
+pub enum UseWebauthnError {
+    #[error("WebAuthn is not available on the server")]
+    Server,
+
+    #[error("WebAuthn is not supported by this browser")]
+    NotSupported,
+
+    #[error("The options of the server are not valid JSON or contain invalid base64url")]
+    InvalidOptions,
+
+    #[error("The ceremony was cancelled by the user, timed out or is not allowed")]
+    NotAllowed,
+
+    #[error("The ceremony was aborted")]
+    Aborted,
+
+    #[error("The credential could not be serialized")]
+    Serialize,
+
+    #[error("WebAuthn failed")]
+    Failed(JsValue),
+}
+
+impl UseWebauthnError {
+    #[cfg(not(feature = "ssr"))]
+    fn from_js(error: JsValue) -> Self {
+        use wasm_bindgen::JsCast;
+
+        match error.dyn_ref::<web_sys::DomException>().map(|e| e.name()) {
+            Some(name) if name == "NotAllowedError" => Self::NotAllowed,
+            Some(name) if name == "AbortError" => Self::Aborted,
+            _ => Self::Failed(error),
+        }
+    }
+}
+
+/// Return type of [`use_webauthn`].
+pub struct UseWebauthnReturn {
+    /// `true` if the browser supports the Web Authentication API
+    pub is_supported: Signal<bool>,
+
+    /// `true` if passkeys can be offered in the autofill of inputs (conditional UI)
+    pub is_conditional_mediation_available: Signal<bool>,
+
+    /// `true` if the device has a built-in authenticator like Touch ID or Windows Hello
+    pub is_platform_authenticator_available: Signal<bool>,
+
+    /// `true` while a ceremony is waiting for the user
+    pub is_pending: Signal<bool>,
+
+    /// Runs the registration and authentication ceremonies
+    pub client: WebauthnClient,
+}
@@ -0,0 +1,443 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+use wasm_bindgen::JsCast;
+
+/// Reactive WebGL context that survives context loss.
+///
+/// The GPU can reset at any time, for example when a driver crashes or too many contexts are alive.
+/// The browser then fires `webglcontextlost` on the canvas and all resources like buffers, textures and shaders
+/// are gone. This function requests the context with the given attributes, allows the browser to restore it
+/// and calls `on_init` again once it is restored so you can recreate your resources.
+///
+/// Please refer to [Handling lost context on MDN](https://developer.mozilla.org/en-US/docs/Web/API/WebGL_API/WebGL_best_practices#handle_context_loss)
+/// for more details.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_webgl_context)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::{html::Canvas, prelude::*};
+/// # use leptos_use::{use_webgl_context_with_options, UseWebGlContextOptions, UseWebGlContextReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let canvas = NodeRef::<Canvas>::new();
+///
+/// let UseWebGlContextReturn { is_context_lost, .. } = use_webgl_context_with_options(
+///     canvas,
+///     UseWebGlContextOptions::default()
+///         .antialias(false)
+///         .on_init(|context| {
+///             let gl = context.as_webgl2().unwrap();
+///             // create buffers, textures and shaders here
+///             gl.clear_color(0.0, 0.0, 0.0, 1.0);
+///             gl.clear(web_sys::WebGl2RenderingContext::COLOR_BUFFER_BIT);
+///         }),
+/// );
+///
+/// view! {
+///     <canvas node_ref=canvas></canvas>
+///     <Show when=move || is_context_lost.get()>"Restoring the GPU context..."</Show>
+/// }
+/// # }
+/// ```
+///
+/// The `context` signal is `None` while the context is lost, so effects that depend on it rerun
+/// as soon as it is restored.
+///
+/// ## Simulating Context Loss
+///
+/// `lose_context` and `restore_context` use the `WEBGL_lose_context` extension to test your recovery code.
+///
+/// ```
+/// # use leptos::{html::Canvas, prelude::*};
+/// # use leptos_use::{use_webgl_context, UseWebGlContextReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let canvas = NodeRef::<Canvas>::new();
+///
+/// let UseWebGlContextReturn { lose_context, restore_context, .. } = use_webgl_context(canvas);
+///
+/// view! {
+///     <canvas node_ref=canvas></canvas>
+///     <button on:click=move |_| lose_context()>"Lose context"</button>
+///     <button on:click=move |_| restore_context()>"Restore context"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `lose_context` and `restore_context` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_webgl_context`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no context is created, `context` is always `None` and the returned functions are no-ops.
+///
+/// ## See also
+///
+/// - [`fn@crate::use_canvas`]
+pub fn use_webgl_context<El, M>(
+    target: El,
+) -> UseWebGlContextReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::HtmlCanvasElement, M>,
+{
+    use_webgl_context_with_options(target, UseWebGlContextOptions::default())
+}
+
+/// Version of [`use_webgl_context`] that takes a `UseWebGlContextOptions`. See [`use_webgl_context`] for how to use.
+pub fn use_webgl_context_with_options<El, M>(
+    target: El,
+    options: UseWebGlContextOptions,
+) -> UseWebGlContextReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::HtmlCanvasElement, M>,
+{
+    let (context, set_context) = signal_local(None::<WebGlContext>);
+    let (is_context_lost, set_context_lost) = signal(false);
+    let (restore_count, set_restore_count) = signal(0_usize);
+
+    let lose_context;
+    let restore_context;
+
+    #[cfg(feature = "ssr")]
+    {
+        let _ = target;
+        let _ = set_context;
+        let _ = set_context_lost;
+        let _ = set_restore_count;
+        let _ = options;
+
+        lose_context = || {};
+        restore_context = || {};
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::Custom;
+
+        let UseWebGlContextOptions {
+            version,
+            alpha,
+            antialias,
+            depth,
+            stencil,
+            premultiplied_alpha,
+            preserve_drawing_buffer,
+            power_preference,
+            fail_if_major_performance_caveat,
+            on_init,
+            on_context_lost,
+        } = options;
+
+        let target = target.into_element_maybe_signal();
+
+        let acquire_context = Arc::new(move |canvas: &web_sys::HtmlCanvasElement| {
+            let attributes = web_sys::WebGlContextAttributes::new();
+            attributes.set_alpha(alpha);
+            attributes.set_antialias(antialias);
+            attributes.set_depth(depth);
+            attributes.set_stencil(stencil);
+            attributes.set_premultiplied_alpha(premultiplied_alpha);
+            attributes.set_preserve_drawing_buffer(preserve_drawing_buffer);
+            attributes.set_power_preference(power_preference);
+            attributes.set_fail_if_major_performance_caveat(fail_if_major_performance_caveat);
+
+            let context = canvas
+                .get_context_with_context_options(version.as_str(), &attributes)
+                .ok()
+                .flatten()
+                .and_then(|context| WebGlContext::new(version, context));
+
+            if let Some(context) = &context {
+                if let Some(on_init) = &on_init {
+                    on_init(context.clone());
+                }
+            }
+
+            set_context.set(context);
+        });
+
+        Effect::new({
+            let target = target.clone();
+            let acquire_context = Arc::clone(&acquire_context);
+
+            move || {
+                set_context_lost.set(false);
+
+                if let Some(canvas) = target.get() {
+                    acquire_context(&canvas);
+                } else {
+                    set_context.set(None);
+                }
+            }
+        });
+
+        let _ = use_event_listener(
+            target.clone(),
+            Custom::<web_sys::Event>::new("webglcontextlost"),
+            move |event| {
+                // without this the browser never restores the context
+                event.prevent_default();
+
+                set_context_lost.set(true);
+                set_context.set(None);
+
+                if let Some(on_context_lost) = &on_context_lost {
+                    on_context_lost();
+                }
+            },
+        );
+
+        let _ = use_event_listener(
+            target.clone(),
+            Custom::<web_sys::Event>::new("webglcontextrestored"),
+            {
+                let target = target.clone();
+
+                move |_| {
+                    set_context_lost.set(false);
+                    set_restore_count.update(|count| *count += 1);
+
+                    if let Some(canvas) = target.get_untracked() {
+                        acquire_context(&canvas);
+                    }
+                }
+            },
+        );
+
+        let lose_context_extension = {
+            let target = target.clone();
+
+            move || {
+                target.get_untracked().and_then(|canvas| {
+                    canvas
+                        .get_context(version.as_str())
+                        .ok()
+                        .flatten()
+                        .and_then(|context| {
+                            WebGlContext::new(version, context)?.get_extension("WEBGL_lose_context")
+                        })
+                })
+            }
+        };
+
+        lose_context = {
+            let lose_context_extension = lose_context_extension.clone();
+
+            sendwrap_fn!(move || {
+                if let Some(extension) = lose_context_extension() {
+                    call_extension_method(&extension, "loseContext");
+                }
+            })
+        };
+
+        restore_context = sendwrap_fn!(move || {
+            if let Some(extension) = lose_context_extension() {
+                call_extension_method(&extension, "restoreContext");
+            }
+        });
+    }
+
+    UseWebGlContextReturn {
+        context: context.into(),
+        is_context_lost: is_context_lost.into(),
+        restore_count: restore_count.into(),
+        lose_context,
+        restore_context,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn call_extension_method(extension: &js_sys::Object, method: &str) {
+    if let Ok(function) = js_sys::Reflect::get(extension, &method.into()) {
+        let _ = function
+            .unchecked_into::<js_sys::Function>()
+            .call0(extension);
+    }
+}
+
+/// WebGL version requested by [`use_webgl_context_with_options`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum WebGlVersion {
+    /// `WebGLRenderingContext`
+    WebGl,
+    /// `WebGL2RenderingContext`
+    #[default]
+    WebGl2,
+}
+
+impl WebGlVersion {
+    /// The context id that is passed to `canvas.getContext()`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::WebGl => "webgl",
+            Self::WebGl2 => "webgl2",
+        }
+    }
+}
+
+/// WebGL rendering context returned by [`use_webgl_context`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum WebGlContext {
+    /// Context of version `WebGlVersion::WebGl`
+    WebGl(web_sys::WebGlRenderingContext),
+    /// Context of version `WebGlVersion::WebGl2`
+    WebGl2(web_sys::WebGl2RenderingContext),
+}
+
+impl WebGlContext {
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn new(version: WebGlVersion, context: js_sys::Object) -> Option<Self> {
+        match version {
+            WebGlVersion::WebGl => context.dyn_into().ok().map(Self::WebGl),
+            WebGlVersion::WebGl2 => context.dyn_into().ok().map(Self::WebGl2),
+        }
+    }
+
+    #[cfg_attr(feature = "ssr", allow(dead_code))]
+    fn get_extension(&self, name: &str) -> Option<js_sys::Object> {
+        match self {
+            Self::WebGl(context) => context.get_extension(name).ok().flatten(),
+            Self::WebGl2(context) => context.get_extension(name).ok().flatten(),
+        }
+    }
+
+    /// Returns the WebGL context if this is one.
+    pub fn as_webgl(&self) -> Option<&web_sys::WebGlRenderingContext> {
+        match self {
+            Self::WebGl(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns the WebGL2 context if this is one.
+    pub fn as_webgl2(&self) -> Option<&web_sys::WebGl2RenderingContext> {
+        match self {
+            Self::WebGl2(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the context is lost.
+    pub fn is_context_lost(&self) -> bool {
+        match self {
+            Self::WebGl(context) => context.is_context_lost(),
+            Self::WebGl2(context) => context.is_context_lost(),
+        }
+    }
+}
+
+/// Options for [`use_webgl_context_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseWebGlContextOptions {
+    /// The WebGL version to request. Defaults to `WebGlVersion::WebGl2`.
+    version: WebGlVersion,
+
+    /// Whether the canvas contains an alpha buffer. Defaults to `true`.
+    alpha: bool,
+
+    /// Whether to perform anti-aliasing if possible. Defaults to `true`.
+    antialias: bool,
+
+    /// Whether the drawing buffer has a depth buffer of at least 16 bits. Defaults to `true`.
+    depth: bool,
+
+    /// Whether the drawing buffer has a stencil buffer of at least 8 bits. Defaults to `false`.
+    stencil: bool,
+
+    /// Whether the page compositor assumes the drawing buffer contains colors with pre-multiplied alpha.
+    /// Defaults to `true`.
+    premultiplied_alpha: bool,
+
+    /// If `true` the buffers are not cleared and keep their values until cleared or overwritten.
+    /// This is needed to read the canvas with `toDataURL()` after drawing. Defaults to `false`.
+    preserve_drawing_buffer: bool,
+
+    /// Hint to the browser which GPU configuration is suitable. Defaults to `WebGlPowerPreference::Default`.
+    power_preference: web_sys::WebGlPowerPreference,
+
+    /// If `true` no context is created if the system performance is low. Defaults to `false`.
+    fail_if_major_performance_caveat: bool,
+
+    /// Called when the context is created and again every time it is restored after being lost.
+    #[builder(skip)]
+    on_init: Option<Arc<dyn Fn(WebGlContext) + Send + Sync>>,
+
+    /// Called when the context is lost.
+    #[builder(skip)]
+    on_context_lost: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl UseWebGlContextOptions {
+    /// Called when the context is created and again every time it is restored after being lost.
+    /// This is where you (re-)create buffers, textures and shaders.
+    pub fn on_init(self, on_init: impl Fn(WebGlContext) + Send + Sync + 'static) -> Self {
+        Self {
+            on_init: Some(Arc::new(on_init)),
+            ..self
+        }
+    }
+
+    /// Called when the context is lost. All resources created with the context are invalid from now on.
+    pub fn on_context_lost(self, on_context_lost: impl Fn() + Send + Sync + 'static) -> Self {
+        Self {
+            on_context_lost: Some(Arc::new(on_context_lost)),
+            ..self
+        }
+    }
+}
+
+impl Default for UseWebGlContextOptions {
+    fn default() -> Self {
+        Self {
+            version: WebGlVersion::default(),
+            alpha: true,
+            antialias: true,
+            depth: true,
+            stencil: false,
+            premultiplied_alpha: true,
+            preserve_drawing_buffer: false,
+            power_preference: web_sys::WebGlPowerPreference::Default,
+            fail_if_major_performance_caveat: false,
+            on_init: None,
+            on_context_lost: None,
+        }
+    }
+}
+
+/// Return type of [`use_webgl_context`].
+pub struct UseWebGlContextReturn<LoseFn, RestoreFn>
+where
+    LoseFn: Fn() + Clone + Send + Sync,
+    RestoreFn: Fn() + Clone + Send + Sync,
+{
+    /// The WebGL context. `None` until the canvas is mounted, while the context is lost or if it couldn't be created.
+    pub context: Signal<Option<WebGlContext>, LocalStorage>,
+
+    /// `true` while the context is lost and not yet restored
+    pub is_context_lost: Signal<bool>,
+
+    /// How many times the context has been restored after being lost
+    pub restore_count: Signal<usize>,
+
+    /// Simulate losing the context with the `WEBGL_lose_context` extension
+    pub lose_context: LoseFn,
+
+    /// Restore a context lost with `lose_context`
+    pub restore_context: RestoreFn,
+}
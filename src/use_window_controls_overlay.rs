@@ -0,0 +1,144 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, use_supported};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive [Window Controls Overlay API](https://developer.mozilla.org/en-US/docs/Web/API/Window_Controls_Overlay_API).
+///
+/// Installed desktop PWAs with `"display_override": ["window-controls-overlay"]` in their manifest can
+/// draw into the title bar area. This function tells you if the overlay is visible and where the
+/// title bar area is, i.e. the space that isn't covered by the OS window buttons. The values are updated
+/// on the [`geometrychange`](https://developer.mozilla.org/en-US/docs/Web/API/WindowControlsOverlay/geometrychange_event) event.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_window_controls_overlay)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_window_controls_overlay, UseWindowControlsOverlayReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowControlsOverlayReturn {
+///     visible,
+///     x,
+///     width,
+///     height,
+///     ..
+/// } = use_window_controls_overlay();
+///
+/// view! {
+///     <Show when=move || visible.get()>
+///         <header style:margin-left=move || format!("{}px", x.get())
+///             style:width=move || format!("{}px", width.get())
+///             style:height=move || format!("{}px", height.get())
+///         >
+///             "My custom title bar"
+///         </header>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// For pure CSS layouts you can also use the environment variables `titlebar-area-x`, `titlebar-area-y`,
+/// `titlebar-area-width` and `titlebar-area-height`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `visible` are `false` and the geometry is always `0.0`.
+pub fn use_window_controls_overlay() -> UseWindowControlsOverlayReturn {
+    let is_supported = use_supported(|| js!("windowControlsOverlay" in &window().navigator()));
+
+    let (visible, set_visible) = signal(false);
+    let (x, set_x) = signal(0.0);
+    let (y, set_y) = signal(0.0);
+    let (width, set_width) = signal(0.0);
+    let (height, set_height) = signal(0.0);
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        use crate::use_event_listener;
+        use leptos::ev::Custom;
+        use wasm_bindgen::JsCast;
+
+        let navigator = window().navigator();
+        let overlay = js!(navigator["windowControlsOverlay"]).unwrap_or_default();
+
+        let update = {
+            let overlay = overlay.clone();
+
+            move || {
+                set_visible.set(
+                    js!(overlay["visible"])
+                        .ok()
+                        .and_then(|visible| visible.as_bool())
+                        .unwrap_or_default(),
+                );
+
+                let rect = js!(overlay["getTitlebarAreaRect"])
+                    .and_then(|get_rect| {
+                        get_rect
+                            .unchecked_into::<js_sys::Function>()
+                            .call0(&overlay)
+                    })
+                    .unwrap_or_default();
+
+                let get = |key: &str| {
+                    js_sys::Reflect::get(&rect, &key.into())
+                        .ok()
+                        .and_then(|value| value.as_f64())
+                        .unwrap_or_default()
+                };
+
+                set_x.set(get("x"));
+                set_y.set(get("y"));
+                set_width.set(get("width"));
+                set_height.set(get("height"));
+            }
+        };
+
+        update();
+
+        let _ = use_event_listener(
+            overlay.unchecked_into::<web_sys::EventTarget>(),
+            Custom::<web_sys::Event>::new("geometrychange"),
+            move |_| update(),
+        );
+    }
+
+    UseWindowControlsOverlayReturn {
+        is_supported,
+        visible: visible.into(),
+        x: x.into(),
+        y: y.into(),
+        width: width.into(),
+        height: height.into(),
+    }
+}
+
+/// Return type of [`use_window_controls_overlay`].
+#[derive(Clone, Copy)]
+pub struct UseWindowControlsOverlayReturn {
+    /// If the Window Controls Overlay API is supported by the browser
+    pub is_supported: Signal<bool>,
+
+    /// If the window controls overlay is visible, i.e. the app draws the title bar itself.
+    /// The user can toggle this in installed PWAs.
+    pub visible: Signal<bool>,
+
+    /// The x coordinate of the title bar area in CSS pixels
+    pub x: Signal<f64>,
+
+    /// The y coordinate of the title bar area in CSS pixels
+    pub y: Signal<f64>,
+
+    /// The width of the title bar area without the OS window buttons in CSS pixels
+    pub width: Signal<f64>,
+
+    /// The height of the title bar area in CSS pixels
+    pub height: Signal<f64>,
+}
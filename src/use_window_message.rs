@@ -0,0 +1,688 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use codee::{Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::html::Iframe;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use send_wrapper::SendWrapper;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Typed messaging between windows and iframes with
+/// [`postMessage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/postMessage).
+///
+/// Messages have a type and a payload that is (en)coded with the given codec. Incoming messages are only accepted from
+/// the target window and from allowed origins.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_window_message)
+///
+/// ## Usage
+///
+/// Inside an iframe you can talk to the parent page like this:
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_window_message, UseWindowMessageReturn, WindowMessageTarget};
+/// # use codee::string::JsonSerdeCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowMessageReturn { messenger, .. } =
+///     use_window_message::<JsonSerdeCodec>(WindowMessageTarget::Parent);
+///
+/// // latest message of type "theme"
+/// let theme = messenger.message::<String>("theme");
+///
+/// let _ = messenger.post("ready", &true);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// To talk to an iframe from the parent page pass its node ref as the target.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Iframe;
+/// # use leptos_use::{use_window_message, UseWindowMessageReturn};
+/// # use codee::string::JsonSerdeCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let iframe = NodeRef::<Iframe>::new();
+///
+/// let UseWindowMessageReturn { messenger, .. } = use_window_message::<JsonSerdeCodec>(iframe);
+///
+/// view! {
+///     <iframe node_ref=iframe src="/embed"></iframe>
+///     <button on:click=move |_| { let _ = messenger.post("theme", &"dark".to_string()); }>
+///         "Dark"
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ## Requests
+///
+/// `request` sends a message and waits for the reply of the other side. The other side answers requests of a type
+/// with a handler registered by `on_request`. If there is no reply within `timeout` milliseconds the request fails
+/// with [`UseWindowMessageError::Timeout`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_window_message, UseWindowMessageReturn, WindowMessageTarget};
+/// # use codee::string::JsonSerdeCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowMessageReturn { messenger, .. } =
+///     use_window_message::<JsonSerdeCodec>(WindowMessageTarget::Parent);
+///
+/// // answer requests from the parent
+/// messenger.on_request("add", |(a, b): (i32, i32)| a + b);
+///
+/// // ask the parent
+/// leptos::task::spawn_local(async move {
+///     let user: Result<String, _> = messenger.request("user-name", &()).await;
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Origins
+///
+/// By default only messages from the same origin are accepted and messages are only delivered to windows of the
+/// same origin. For cross-origin communication list the origins of the other side.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_window_message_with_options, UseWindowMessageOptions, WindowMessageTarget};
+/// # use codee::string::JsonSerdeCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let messages = use_window_message_with_options::<JsonSerdeCodec>(
+///     WindowMessageTarget::Parent,
+///     UseWindowMessageOptions::default()
+///         .allowed_origins(vec!["https://app.example.com".to_string()])
+///         .target_origin("https://app.example.com"),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Wire format
+///
+/// Every message is a plain object like
+/// `{ channel: "leptos-use", type: "theme", data: "\"dark\"", id: 1, reply_to: 1, error: "..." }`.
+/// `data` is the encoded payload, `id` is only set for requests, `reply_to` and `error` only for replies.
+/// Messages with a different `channel` are ignored, so a page that doesn't use Rust can interoperate easily.
+///
+/// ## Server-Side Rendering
+///
+/// On the server all message signals are always `None` and the methods of [`WindowMessenger`] return
+/// `Err(UseWindowMessageError::Server)`.
+pub fn use_window_message<C>(target: impl Into<WindowMessageTarget>) -> UseWindowMessageReturn<C>
+where
+    C: 'static,
+{
+    use_window_message_with_options(target, UseWindowMessageOptions::default())
+}
+
+/// Version of [`use_window_message`] that takes a `UseWindowMessageOptions`. See [`use_window_message`] for how to use.
+pub fn use_window_message_with_options<C>(
+    target: impl Into<WindowMessageTarget>,
+    options: UseWindowMessageOptions,
+) -> UseWindowMessageReturn<C>
+where
+    C: 'static,
+{
+    let UseWindowMessageOptions {
+        allowed_origins,
+        target_origin,
+        channel,
+        timeout,
+    } = options;
+
+    let (error, set_error) = signal(None::<UseWindowMessageError>);
+
+    let messenger = WindowMessenger {
+        target: StoredValue::new_local(target.into()),
+        state: StoredValue::new_local(MessengerState::default()),
+        channel: StoredValue::new(channel),
+        target_origin: StoredValue::new(target_origin),
+        timeout,
+        set_error,
+        _codec: PhantomData,
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+
+        let _ = use_event_listener(window(), leptos::ev::message, move |event| {
+            let origin = event.origin();
+
+            let is_allowed = if allowed_origins.is_empty() {
+                window().location().origin().ok() == Some(origin.clone())
+            } else {
+                allowed_origins
+                    .iter()
+                    .any(|allowed| allowed == "*" || *allowed == origin)
+            };
+
+            let is_from_target = messenger
+                .target_window()
+                .zip(event.source())
+                .is_some_and(|(target, source)| JsValue::from(target) == JsValue::from(source));
+
+            if !is_allowed || !is_from_target {
+                return;
+            }
+
+            if let Some(envelope) = Envelope::from_js(&event.data(), &messenger.channel.get_value())
+            {
+                messenger.receive(envelope, origin);
+            }
+        });
+    }
+
+    UseWindowMessageReturn {
+        messenger,
+        error: error.into(),
+    }
+}
+
+/// Options for [`use_window_message_with_options`].
+#[derive(DefaultBuilder)]
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct UseWindowMessageOptions {
+    /// Origins incoming messages are accepted from. `"*"` accepts all origins.
+    /// Defaults to an empty list which only accepts messages from the same origin.
+    allowed_origins: Vec<String>,
+
+    /// The origin the target window must have for a message to be delivered. `"*"` delivers to any origin.
+    /// Defaults to `"/"` which only delivers to the same origin.
+    #[builder(into)]
+    target_origin: String,
+
+    /// Name that identifies the messages of this function. Messages with another channel name are ignored.
+    /// Defaults to `"leptos-use"`.
+    #[builder(into)]
+    channel: String,
+
+    /// Milliseconds to wait for the reply to a request. Defaults to `5000`.
+    timeout: u64,
+}
+
+impl Default for UseWindowMessageOptions {
+    fn default() -> Self {
+        Self {
+            allowed_origins: vec![],
+            target_origin: "/".to_string(),
+            channel: "leptos-use".to_string(),
+            timeout: 5000,
+        }
+    }
+}
+
+/// The window that [`use_window_message`] talks to.
+#[derive(Clone)]
+pub enum WindowMessageTarget {
+    /// `window.parent`. Use this inside an iframe.
+    Parent,
+
+    /// `window.opener`. Use this inside a window opened by `window.open()`.
+    Opener,
+
+    /// The content window of an iframe
+    Iframe(NodeRef<Iframe>),
+
+    /// Any other window like one returned by `window.open()`
+    Window(SendWrapper<web_sys::Window>),
+}
+
+impl From<NodeRef<Iframe>> for WindowMessageTarget {
+    fn from(node_ref: NodeRef<Iframe>) -> Self {
+        Self::Iframe(node_ref)
+    }
+}
+
+impl From<web_sys::Window> for WindowMessageTarget {
+    fn from(window: web_sys::Window) -> Self {
+        Self::Window(SendWrapper::new(window))
+    }
+}
+
+impl WindowMessageTarget {
+    /// The current window of this target, if available.
+    pub fn window(&self) -> Option<web_sys::Window> {
+        #[cfg(feature = "ssr")]
+        {
+            None
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            match self {
+                Self::Parent => window().parent().ok().flatten(),
+                Self::Opener => window()
+                    .opener()
+                    .ok()
+                    .filter(|opener| !opener.is_null() && !opener.is_undefined())
+                    .map(|opener| opener.unchecked_into()),
+                Self::Iframe(node_ref) => node_ref
+                    .get_untracked()
+                    .and_then(|iframe| iframe.content_window()),
+                Self::Window(window) => Some((**window).clone()),
+            }
+        }
+    }
+}
+
+/// Return type of [`use_window_message`].
+pub struct UseWindowMessageReturn<C>
+where
+    C: 'static,
+{
+    /// Send messages and requests to the target window and receive its messages
+    pub messenger: WindowMessenger<C>,
+
+    /// Latest error that happened while receiving or answering a message
+    pub error: Signal<Option<UseWindowMessageError>>,
+}
+
+/// Sends and receives the messages of [`use_window_message`].
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub struct WindowMessenger<C>
+where
+    C: 'static,
+{
+    target: StoredValue<WindowMessageTarget, LocalStorage>,
+    state: StoredValue<MessengerState, LocalStorage>,
+    channel: StoredValue<String>,
+    target_origin: StoredValue<String>,
+    timeout: u64,
+    set_error: WriteSignal<Option<UseWindowMessageError>>,
+    _codec: PhantomData<fn() -> C>,
+}
+
+impl<C> Clone for WindowMessenger<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for WindowMessenger<C> {}
+
+type RequestHandler = Rc<dyn Fn(&str) -> Result<String, String>>;
+
+#[derive(Default)]
+struct MessengerState {
+    messages: HashMap<String, RwSignal<Option<String>>>,
+    handlers: HashMap<String, RequestHandler>,
+    pending: HashMap<u32, js_sys::Function>,
+    next_id: u32,
+}
+
+impl<C> WindowMessenger<C>
+where
+    C: 'static,
+{
+    /// Sends a message of the given type to the target window.
+    pub fn post<T>(&self, message_type: &str, data: &T) -> Result<(), UseWindowMessageError>
+    where
+        C: Encoder<T, Encoded = String>,
+        <C as Encoder<T>>::Error: Debug,
+    {
+        #[cfg(feature = "ssr")]
+        return Err(UseWindowMessageError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let data =
+                C::encode(data).map_err(|err| UseWindowMessageError::Encode(format!("{err:?}")))?;
+
+            self.send(
+                Envelope {
+                    message_type: message_type.to_string(),
+                    data: Some(data),
+                    ..Default::default()
+                },
+                None,
+            )
+        }
+    }
+
+    /// Sends a request of the given type to the target window and waits for the reply.
+    pub async fn request<T, R>(
+        &self,
+        message_type: &str,
+        data: &T,
+    ) -> Result<R, UseWindowMessageError>
+    where
+        C: Encoder<T, Encoded = String> + Decoder<R, Encoded = str>,
+        <C as Encoder<T>>::Error: Debug,
+        <C as Decoder<R>>::Error: Debug,
+    {
+        self.request_with_timeout(message_type, data, self.timeout)
+            .await
+    }
+
+    /// Version of [`WindowMessenger::request`] that waits `timeout` milliseconds for the reply instead of the
+    /// timeout given in the options.
+    pub async fn request_with_timeout<T, R>(
+        &self,
+        message_type: &str,
+        data: &T,
+        timeout: u64,
+    ) -> Result<R, UseWindowMessageError>
+    where
+        C: Encoder<T, Encoded = String> + Decoder<R, Encoded = str>,
+        <C as Encoder<T>>::Error: Debug,
+        <C as Decoder<R>>::Error: Debug,
+    {
+        #[cfg(feature = "ssr")]
+        return Err(UseWindowMessageError::Server);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            use crate::js_fut;
+            use std::time::Duration;
+
+            let data =
+                C::encode(data).map_err(|err| UseWindowMessageError::Encode(format!("{err:?}")))?;
+
+            let id = self
+                .state
+                .try_update_value(|state| {
+                    state.next_id = state.next_id.wrapping_add(1);
+                    state.next_id
+                })
+                .unwrap_or_default();
+
+            let state = self.state;
+            let promise = js_sys::Promise::new(&mut |resolve, _| {
+                state.update_value(|state| {
+                    state.pending.insert(id, resolve);
+                });
+            });
+
+            let sent = self.send(
+                Envelope {
+                    message_type: message_type.to_string(),
+                    data: Some(data),
+                    id: Some(id),
+                    ..Default::default()
+                },
+                None,
+            );
+
+            if let Err(err) = sent {
+                self.state.update_value(|state| {
+                    state.pending.remove(&id);
+                });
+                return Err(err);
+            }
+
+            // resolves with `null` if there is no reply in time
+            let _ = set_timeout_with_handle(
+                move || {
+                    if let Some(Some(resolve)) =
+                        state.try_update_value(|state| state.pending.remove(&id))
+                    {
+                        let _ = resolve.call1(&JsValue::NULL, &JsValue::NULL);
+                    }
+                },
+                Duration::from_millis(timeout),
+            );
+
+            let reply = js_fut!(promise).await.unwrap_or(JsValue::NULL);
+
+            let Some(reply) = Envelope::from_js(&reply, &self.channel.get_value()) else {
+                return Err(UseWindowMessageError::Timeout);
+            };
+
+            if let Some(error) = reply.error {
+                return Err(UseWindowMessageError::Remote(error));
+            }
+
+            C::decode(reply.data.as_deref().unwrap_or_default())
+                .map_err(|err| UseWindowMessageError::Decode(format!("{err:?}")))
+        }
+    }
+
+    /// Signal of the latest message of the given type. `None` until the first message of this type has been received
+    /// or if the message couldn't be decoded.
+    pub fn message<T>(&self, message_type: &str) -> Signal<Option<T>>
+    where
+        C: Decoder<T, Encoded = str>,
+        T: Send + Sync + 'static,
+    {
+        let raw = self.raw_message(message_type);
+
+        Signal::derive(move || raw.with(|raw| raw.as_deref().and_then(|raw| C::decode(raw).ok())))
+    }
+
+    /// Answers requests of the given type with the return value of `handler`.
+    /// Registering a handler for the same type again replaces the previous one.
+    pub fn on_request<T, R>(&self, message_type: &str, handler: impl Fn(T) -> R + 'static)
+    where
+        C: Decoder<T, Encoded = str> + Encoder<R, Encoded = String>,
+        <C as Decoder<T>>::Error: Debug,
+        <C as Encoder<R>>::Error: Debug,
+    {
+        let handler: RequestHandler = Rc::new(move |data: &str| {
+            let data =
+                C::decode(data).map_err(|err| format!("failed to decode request: {err:?}"))?;
+
+            C::encode(&handler(data)).map_err(|err| format!("failed to encode reply: {err:?}"))
+        });
+
+        self.state.update_value(|state| {
+            state.handlers.insert(message_type.to_string(), handler);
+        });
+    }
+
+    fn raw_message(&self, message_type: &str) -> RwSignal<Option<String>> {
+        if let Some(raw) = self
+            .state
+            .with_value(|state| state.messages.get(message_type).copied())
+        {
+            return raw;
+        }
+
+        let raw = RwSignal::new(None);
+
+        self.state.update_value(|state| {
+            state.messages.insert(message_type.to_string(), raw);
+        });
+
+        raw
+    }
+
+    fn target_window(&self) -> Option<web_sys::Window> {
+        self.target.with_value(|target| target.window())
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn send(
+        &self,
+        envelope: Envelope,
+        target_origin: Option<String>,
+    ) -> Result<(), UseWindowMessageError> {
+        let target = self
+            .target_window()
+            .ok_or(UseWindowMessageError::NoTarget)?;
+
+        let target_origin = target_origin.unwrap_or_else(|| self.target_origin.get_value());
+
+        target
+            .post_message(&envelope.to_js(&self.channel.get_value()), &target_origin)
+            .map_err(|err| UseWindowMessageError::PostMessage(SendWrapper::new(err)))
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn receive(&self, envelope: Envelope, origin: String) {
+        if let Some(reply_to) = envelope.reply_to {
+            let resolve = self
+                .state
+                .try_update_value(|state| state.pending.remove(&reply_to))
+                .flatten();
+
+            if let Some(resolve) = resolve {
+                let _ = resolve.call1(&JsValue::NULL, &envelope.to_js(&self.channel.get_value()));
+            }
+
+            return;
+        }
+
+        let raw = self
+            .state
+            .with_value(|state| state.messages.get(&envelope.message_type).copied());
+
+        if let Some(raw) = raw {
+            raw.set(envelope.data.clone());
+        }
+
+        let Some(id) = envelope.id else {
+            return;
+        };
+
+        let handler = self
+            .state
+            .with_value(|state| state.handlers.get(&envelope.message_type).cloned());
+
+        let result = match handler {
+            Some(handler) => handler(envelope.data.as_deref().unwrap_or_default()),
+            None => Err(format!(
+                "no handler for requests of type `{}`",
+                envelope.message_type
+            )),
+        };
+
+        let (data, error) = match result {
+            Ok(data) => (Some(data), None),
+            Err(error) => (None, Some(error)),
+        };
+
+        let sent = self.send(
+            Envelope {
+                message_type: envelope.message_type,
+                data,
+                reply_to: Some(id),
+                error,
+                ..Default::default()
+            },
+            // reply only to the origin the request came from
+            Some(origin),
+        );
+
+        if let Err(err) = sent {
+            self.set_error.set(Some(err));
+        }
+    }
+}
+
+#[derive(Default)]
+struct Envelope {
+    message_type: String,
+    data: Option<String>,
+    id: Option<u32>,
+    reply_to: Option<u32>,
+    error: Option<String>,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl Envelope {
+    fn from_js(value: &JsValue, channel: &str) -> Option<Self> {
+        use crate::js;
+
+        if !value.is_object() || js!(value["channel"]).ok()?.as_string()? != channel {
+            return None;
+        }
+
+        let string = |key: &str| {
+            js_sys::Reflect::get(value, &key.into())
+                .ok()
+                .and_then(|value| value.as_string())
+        };
+        let number = |key: &str| {
+            js_sys::Reflect::get(value, &key.into())
+                .ok()
+                .and_then(|value| value.as_f64())
+                .map(|value| value as u32)
+        };
+
+        Some(Self {
+            message_type: string("type")?,
+            data: string("data"),
+            id: number("id"),
+            reply_to: number("reply_to"),
+            error: string("error"),
+        })
+    }
+
+    fn to_js(&self, channel: &str) -> JsValue {
+        use crate::js;
+
+        let object = js_sys::Object::new();
+
+        js!(object["channel"] = channel);
+        js!(object["type"] = self.message_type.as_str());
+
+        if let Some(data) = &self.data {
+            js!(object["data"] = data.as_str());
+        }
+        if let Some(id) = self.id {
+            js!(object["id"] = id);
+        }
+        if let Some(reply_to) = self.reply_to {
+            js!(object["reply_to"] = reply_to);
+        }
+        if let Some(error) = &self.error {
+            js!(object["error"] = error.as_str());
+        }
+
+        object.into()
+    }
+}
+
+/// Error type of [`use_window_message`].
+#[derive(Error, Debug, Clone)]
+pub enum UseWindowMessageError {
+    #[error("postMessage is not available on the server")]
+    Server,
+
+    #[error("the target window is not available")]
+    NoTarget,
+
+    #[error("failed to encode the message: {0}")]
+    Encode(String),
+
+    #[error("failed to decode the message: {0}")]
+    Decode(String),
+
+    #[error("failed to post the message")]
+    PostMessage(SendWrapper<JsValue>),
+
+    #[error("no reply has been received in time")]
+    Timeout,
+
+    #[error("the request failed on the other side: {0}")]
+    Remote(String),
+}
@@ -41,7 +41,7 @@ where
 }
 
 /// Specify a debounce or throttle filter with their respective options or no filter
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub enum FilterOptions {
     #[default]
     None,
@@ -0,0 +1,5 @@
+use std::future::Future;
+use std::pin::Pin;
+
+/// A boxed future that doesn't have to be `Send`. Returned by functions that wrap browser promises.
+pub type LocalBoxFuture<T> = Pin<Box<dyn Future<Output = T>>>;
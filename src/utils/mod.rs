@@ -1,3 +1,5 @@
+#[cfg(any(feature = "use_jwt", feature = "use_webauthn"))]
+mod base64url;
 mod filters;
 #[cfg(all(
     feature = "ssr",
@@ -9,11 +11,21 @@ mod header_macro;
 mod is;
 mod js;
 mod js_value_from_to_string;
+mod local_box_future;
 mod pausable;
 mod sendwrap_fn;
+#[cfg(any(feature = "use_active_element", feature = "on_click_outside"))]
+mod shadow_dom;
+#[cfg(all(
+    not(feature = "ssr"),
+    any(feature = "use_stylesheet", feature = "use_script_tag")
+))]
+mod shared_head_element;
 mod signal_filtered;
 mod use_derive_signal;
 
+#[cfg(any(feature = "use_jwt", feature = "use_webauthn"))]
+pub(crate) use base64url::*;
 pub use filters::*;
 #[cfg(all(
     feature = "ssr",
@@ -26,6 +38,15 @@ pub(crate) use header_macro::*;
 pub use is::*;
 #[allow(unused_imports)]
 pub(crate) use js_value_from_to_string::*;
+pub use local_box_future::*;
 pub use pausable::*;
+#[cfg(any(feature = "use_active_element", feature = "on_click_outside"))]
+#[allow(unused_imports)]
+pub(crate) use shadow_dom::*;
+#[cfg(all(
+    not(feature = "ssr"),
+    any(feature = "use_stylesheet", feature = "use_script_tag")
+))]
+pub(crate) use shared_head_element::*;
 #[allow(unused_imports)]
 pub(crate) use signal_filtered::*;
@@ -0,0 +1,22 @@
+use leptos::prelude::*;
+
+/// Returns `document.activeElement`. If `include_shadow` is `true` it descends into open shadow roots
+/// and returns the focused element inside of them instead of their host.
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+pub(crate) fn active_element(include_shadow: bool) -> Option<web_sys::Element> {
+    let mut active_element = document().active_element();
+
+    if include_shadow {
+        while let Some(shadow_root) = active_element
+            .as_ref()
+            .and_then(|element| element.shadow_root())
+        {
+            match shadow_root.active_element() {
+                Some(inner) => active_element = Some(inner),
+                None => break,
+            }
+        }
+    }
+
+    active_element
+}
@@ -0,0 +1,100 @@
+use crate::core::LoadStatus;
+use leptos::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+struct SharedHeadElement {
+    element: web_sys::Element,
+    status: ArcRwSignal<LoadStatus>,
+    count: usize,
+    _on_load: Closure<dyn Fn()>,
+    _on_error: Closure<dyn Fn()>,
+}
+
+thread_local! {
+    // keyed by tag name and URL
+    static HEAD_ELEMENTS: RefCell<HashMap<(&'static str, String), SharedHeadElement>> =
+        Default::default();
+}
+
+/// Adds an element with the given `tag` that loads `url` to the head of the document or reuses the one
+/// that has been added for the same `tag` and `url` before. The element is only created once and
+/// `init` is called on it before it's added to set its attributes. When the element is reused `init`
+/// isn't called, so the attributes of later calls are ignored.
+///
+/// Returns the shared load status of the element or `None` if the element couldn't be created.
+/// Every call that returns `Some` has to be paired with a call to [`release_head_element`].
+pub(crate) fn acquire_head_element(
+    tag: &'static str,
+    url: &str,
+    init: impl FnOnce(&web_sys::Element),
+) -> Option<ArcRwSignal<LoadStatus>> {
+    HEAD_ELEMENTS.with_borrow_mut(|elements| {
+        let key = (tag, url.to_string());
+
+        if let Some(entry) = elements.get_mut(&key) {
+            entry.count += 1;
+            return Some(entry.status.clone());
+        }
+
+        let element = document().create_element(tag).ok()?;
+
+        let status = ArcRwSignal::new(LoadStatus::Loading);
+
+        let on_load = Closure::<dyn Fn()>::new({
+            let status = status.clone();
+            move || status.set(LoadStatus::Loaded)
+        });
+
+        let on_error = Closure::<dyn Fn()>::new({
+            let status = status.clone();
+            move || status.set(LoadStatus::Error)
+        });
+
+        init(&element);
+
+        let _ = element.add_event_listener_with_callback("load", on_load.as_ref().unchecked_ref());
+        let _ =
+            element.add_event_listener_with_callback("error", on_error.as_ref().unchecked_ref());
+
+        if let Some(head) = document().head() {
+            let _ = head.append_child(&element);
+        }
+
+        elements.insert(
+            key,
+            SharedHeadElement {
+                element,
+                status: status.clone(),
+                count: 1,
+                _on_load: on_load,
+                _on_error: on_error,
+            },
+        );
+
+        Some(status)
+    })
+}
+
+/// Releases an element acquired with [`acquire_head_element`]. It's removed from the document when
+/// it isn't used anymore.
+pub(crate) fn release_head_element(tag: &'static str, url: &str) {
+    let removed = HEAD_ELEMENTS.with_borrow_mut(|elements| {
+        let key = (tag, url.to_string());
+
+        let entry = elements.get_mut(&key)?;
+        entry.count -= 1;
+
+        if entry.count == 0 {
+            elements.remove(&key)
+        } else {
+            None
+        }
+    });
+
+    if let Some(entry) = removed {
+        entry.element.remove();
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{utils::DebounceOptions, watch_with_options, WatchOptions};
+use crate::{
+    utils::DebounceOptions, watch_filtered_with_options, WatchFilteredReturn, WatchOptions,
+};
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
 
@@ -30,7 +32,40 @@ use leptos::prelude::*;
 /// # }
 /// ```
 ///
-/// This really is only shorthand shorthand for `watch_with_options(deps, callback, WatchOptions::default().debounce(ms))`.
+/// This is basically shorthand for `watch_with_options(deps, callback, WatchOptions::default().debounce(ms))`
+/// except that the returned [`WatchFilteredReturn`] lets you control a pending callback.
+///
+/// ### Flush and Cancel
+///
+/// Call `flush` to invoke a pending callback right away, e.g. before the user navigates away or
+/// submits a form. `cancel` drops it instead. `is_pending` tells you if there is one.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{watch_debounced, WatchFilteredReturn};
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// #     let (source, set_source) = signal(String::new());
+/// #
+/// let WatchFilteredReturn {
+///     flush,
+///     cancel,
+///     is_pending,
+///     ..
+/// } = watch_debounced(
+///     move || source.get(),
+///     move |value, _, _| {
+///         log!("save {value}");
+///     },
+///     1000.0,
+/// );
+///
+/// view! {
+///     <button on:click=move |_| flush()>"Save now"</button>
+/// }
+/// # }
+/// ```
 ///
 /// Please note that if the current component is cleaned up before the debounced callback is called, the debounced callback will not be called.
 ///
@@ -76,14 +111,18 @@ pub fn watch_debounced<W, T, DFn, CFn>(
     deps: DFn,
     callback: CFn,
     ms: f64,
-) -> impl Fn() + Clone + Send + Sync
+) -> WatchFilteredReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
 where
     DFn: Fn() -> W + 'static,
     CFn: Fn(&W, Option<&W>, Option<T>) -> T + Clone + 'static,
     W: Clone + 'static,
     T: Clone + 'static,
 {
-    watch_with_options(deps, callback, WatchOptions::default().debounce(ms))
+    watch_filtered_with_options(deps, callback, WatchOptions::default().debounce(ms))
 }
 
 /// Version of `watch_debounced` that accepts `WatchDebouncedOptions`.
@@ -93,14 +132,18 @@ pub fn watch_debounced_with_options<W, T, DFn, CFn>(
     callback: CFn,
     ms: f64,
     options: WatchDebouncedOptions,
-) -> impl Fn() + Clone + Send + Sync
+) -> WatchFilteredReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
 where
     DFn: Fn() -> W + 'static,
     CFn: Fn(&W, Option<&W>, Option<T>) -> T + Clone + 'static,
     W: Clone + 'static,
     T: Clone + 'static,
 {
-    watch_with_options(
+    watch_filtered_with_options(
         deps,
         callback,
         WatchOptions::default()
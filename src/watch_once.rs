@@ -0,0 +1,81 @@
+use leptos::prelude::*;
+
+/// A version of `leptos::watch` that stops after the callback has been called once.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos::task::Executor;
+/// # use leptos_use::watch_once;
+/// #
+/// # let _ = Executor::init_futures_executor();
+/// # let owner = Owner::new();
+/// # owner.set();
+/// #
+/// let (source, set_source) = signal(0);
+///
+/// watch_once(
+///     move || source.get(),
+///     |value, prev_value| {
+///         log!("changed from {prev_value:?} to {value}");
+///     },
+/// );
+/// # Executor::poll_local();
+///
+/// set_source.set(1);
+/// # Executor::poll_local();
+/// // > "changed from Some(0) to 1"
+///
+/// set_source.set(2);
+/// # Executor::poll_local();
+/// // (nothing happens)
+/// ```
+///
+/// The callback is called on the first change of any signal that is accessed in `deps`. It is
+/// an `FnOnce` so it can consume whatever it captures.
+///
+/// Like all effects the callback is called asynchronously after the change. If `set_source.set(1)` and
+/// `set_source.set(2)` were called right after each other, the callback would only see the latest value
+/// and log `"changed from Some(0) to 2"`.
+///
+/// The returned function stops the watcher before the callback has been called.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the callback will never be called.
+///
+/// ## See also
+///
+/// * [`fn@crate::watch_until`]
+/// * [`fn@crate::whenever`]
+pub fn watch_once<W, DFn, CFn>(deps: DFn, callback: CFn) -> impl Fn() + Clone + Send + Sync
+where
+    DFn: Fn() -> W + 'static,
+    CFn: FnOnce(&W, Option<&W>) + 'static,
+    W: Clone + 'static,
+{
+    let callback = StoredValue::new_local(Some(callback));
+    let effect = StoredValue::new_local(None::<Effect<LocalStorage>>);
+
+    let stop = move || {
+        if let Some(effect) = effect.try_update_value(Option::take).flatten() {
+            effect.stop();
+        }
+    };
+
+    effect.set_value(Some(Effect::watch(
+        deps,
+        move |value, prev_value, _| {
+            if let Some(callback) = callback.try_update_value(Option::take).flatten() {
+                callback(value, prev_value);
+            }
+
+            stop();
+        },
+        false,
+    )));
+
+    stop
+}
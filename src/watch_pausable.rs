@@ -12,9 +12,13 @@ use leptos::prelude::*;
 /// ```
 /// # use leptos::prelude::*;
 /// # use leptos::logging::log;
+/// # use leptos::task::Executor;
 /// # use leptos_use::{watch_pausable, WatchPausableReturn};
 /// #
-/// # pub fn Demo() -> impl IntoView {
+/// # let _ = Executor::init_futures_executor();
+/// # let owner = Owner::new();
+/// # owner.set();
+/// #
 /// let (source, set_source) = signal("foo".to_string());
 ///
 /// let WatchPausableReturn {
@@ -28,19 +32,78 @@ use leptos::prelude::*;
 ///         log!("Changed to {}", v);
 ///     },
 /// );
+/// # Executor::poll_local();
 ///
-/// set_source.set("bar".to_string()); // > "Changed to bar"
+/// set_source.set("bar".to_string());
+/// # Executor::poll_local();
+/// // > "Changed to bar"
 ///
 /// pause();
 ///
-/// set_source.set("foobar".to_string()); // (nothing happens)
+/// set_source.set("foobar".to_string());
+/// # Executor::poll_local();
+/// // (nothing happens)
 ///
 /// resume();
 ///
-/// set_source.set("hello".to_string()); // > "Changed to hello"
-/// #    view! { }
-/// # }
+/// set_source.set("hello".to_string());
+/// # Executor::poll_local();
+/// // > "Changed to hello"
+/// ```
+///
+/// Like all effects the callback is called asynchronously after the source has changed. Pausing or
+/// resuming right after changing the source, before the effects had a chance to run, applies to that
+/// change as well. Changes between two runs are batched into one call.
+///
+/// ### Catching up on missed changes
+///
+/// Changes that happen while paused are skipped. `missed_updates` counts how many there have been
+/// since the watcher was last paused. Call `resume_and_run` instead of `resume` to invoke the callback
+/// right away with the latest value if there have been any.
+///
 /// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos::task::Executor;
+/// # use leptos_use::{watch_pausable, WatchPausableReturn};
+/// #
+/// # let _ = Executor::init_futures_executor();
+/// # let owner = Owner::new();
+/// # owner.set();
+/// #
+/// let (source, set_source) = signal("foo".to_string());
+///
+/// let WatchPausableReturn {
+///     pause,
+///     resume_and_run,
+///     missed_updates,
+///     ..
+/// } = watch_pausable(
+///     move || source.get(),
+///     |v, _, _| {
+///         log!("Changed to {}", v);
+///     },
+/// );
+/// # Executor::poll_local();
+///
+/// pause();
+///
+/// set_source.set("bar".to_string());
+/// # Executor::poll_local();
+/// set_source.set("foobar".to_string());
+/// # Executor::poll_local();
+/// // (nothing happens)
+///
+/// assert_eq!(missed_updates.get(), 2);
+///
+/// resume_and_run(); // > "Changed to foobar"
+/// ```
+///
+/// `missed_updates` counts the calls of the callback that have been skipped. Since changes are batched
+/// until the effects run, several changes in a row might count as one. `resume_and_run` calls the callback
+/// synchronously. It receives the value it has seen last as the previous value and the value it has
+/// returned last as the previous return value. The return value of this call is passed on to the next
+/// regular call as well.
 ///
 /// There's also [`watch_pausable_with_options`] which takes the same options as [`watch`].
 ///
@@ -60,6 +123,7 @@ pub fn watch_pausable<W, T, DFn, CFn>(
     impl Fn() + Clone + Send + Sync,
     impl Fn() + Clone + Send + Sync,
     impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
 >
 where
     DFn: Fn() -> W + 'static,
@@ -79,6 +143,7 @@ pub fn watch_pausable_with_options<W, T, DFn, CFn>(
     impl Fn() + Clone + Send + Sync,
     impl Fn() + Clone + Send + Sync,
     impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
 >
 where
     DFn: Fn() -> W + 'static,
@@ -87,11 +152,29 @@ where
     T: Clone + 'static,
 {
     let (is_active, set_active) = signal(true);
+    let (missed_updates, set_missed_updates) = signal(0_usize);
+
+    let callback = StoredValue::new_local(callback);
+    // value and return value of the last invocation of the callback
+    let last_seen = StoredValue::new_local(None::<W>);
+    let last_ret = StoredValue::new_local(None::<T>);
+    // latest value that has been skipped while paused
+    let pending = StoredValue::new_local(None::<W>);
 
-    let pausable_callback = move |val: &W, prev_val: Option<&W>, prev_ret: Option<Option<T>>| {
+    // `last_ret` is used instead of the previous return value of the watch because it also includes
+    // the calls of `resume_and_run` and isn't reset by skipped calls
+    let pausable_callback = move |val: &W, prev_val: Option<&W>, _: Option<Option<T>>| {
         if is_active.get_untracked() {
-            Some(callback(val, prev_val, prev_ret.unwrap_or(None)))
+            let ret = (callback.get_value())(val, prev_val, last_ret.get_value());
+
+            last_seen.set_value(Some(val.clone()));
+            last_ret.set_value(Some(ret.clone()));
+
+            Some(ret)
         } else {
+            pending.set_value(Some(val.clone()));
+            set_missed_updates.update(|missed| *missed += 1);
+
             None
         }
     };
@@ -99,27 +182,48 @@ where
     let stop = watch_with_options(deps, pausable_callback, options);
 
     let pause = move || {
+        if is_active.get_untracked() {
+            set_missed_updates.set(0);
+        }
         set_active.set(false);
     };
 
     let resume = move || {
+        pending.set_value(None);
         set_active.set(true);
     };
 
+    let resume_and_run = move || {
+        set_active.set(true);
+
+        let Some(val) = pending.try_update_value(Option::take).flatten() else {
+            return;
+        };
+
+        let prev_val = last_seen.get_value();
+        let ret = (callback.get_value())(&val, prev_val.as_ref(), last_ret.get_value());
+
+        last_seen.set_value(Some(val));
+        last_ret.set_value(Some(ret));
+    };
+
     WatchPausableReturn {
         stop,
         pause,
         resume,
+        resume_and_run,
         is_active: is_active.into(),
+        missed_updates: missed_updates.into(),
     }
 }
 
 /// Return type of [`watch_pausable`]
-pub struct WatchPausableReturn<StopFn, PauseFn, ResumeFn>
+pub struct WatchPausableReturn<StopFn, PauseFn, ResumeFn, ResumeAndRunFn>
 where
     StopFn: Fn() + Clone + Send + Sync,
     PauseFn: Fn() + Clone + Send + Sync,
     ResumeFn: Fn() + Clone + Send + Sync,
+    ResumeAndRunFn: Fn() + Clone + Send + Sync,
 {
     /// Stops the watcher
     pub stop: StopFn,
@@ -130,6 +234,13 @@ where
     /// Resumes the watcher
     pub resume: ResumeFn,
 
+    /// Resumes the watcher and synchronously calls the callback with the latest value if it has
+    /// changed while paused
+    pub resume_and_run: ResumeAndRunFn,
+
     /// Whether the watcher is active (not paused). This doesn't reflect if the watcher has been stopped
     pub is_active: Signal<bool>,
+
+    /// Number of changes that have been skipped since the watcher was last paused
+    pub missed_updates: Signal<usize>,
 }
@@ -1,4 +1,6 @@
-use crate::{utils::ThrottleOptions, watch_with_options, WatchOptions};
+use crate::{
+    utils::ThrottleOptions, watch_filtered_with_options, WatchFilteredReturn, WatchOptions,
+};
 use default_struct_builder::DefaultBuilder;
 
 /// A throttled version of `leptos::watch`.
@@ -29,7 +31,40 @@ use default_struct_builder::DefaultBuilder;
 /// # }
 /// ```
 ///
-/// This really is only shorthand shorthand for `watch_with_options(deps, callback, WatchOptions::default().throttle(ms))`.
+/// This is basically shorthand for `watch_with_options(deps, callback, WatchOptions::default().throttle(ms))`
+/// except that the returned [`WatchFilteredReturn`] lets you control a pending callback.
+///
+/// ### Flush and Cancel
+///
+/// Call `flush` to invoke a pending callback right away, e.g. before the user navigates away or
+/// submits a form. `cancel` drops it instead. `is_pending` tells you if there is one.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{watch_throttled, WatchFilteredReturn};
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// #     let (source, set_source) = signal(String::new());
+/// #
+/// let WatchFilteredReturn {
+///     flush,
+///     cancel,
+///     is_pending,
+///     ..
+/// } = watch_throttled(
+///     move || source.get(),
+///     move |value, _, _| {
+///         log!("save {value}");
+///     },
+///     1000.0,
+/// );
+///
+/// view! {
+///     <button on:click=move |_| flush()>"Save now"</button>
+/// }
+/// # }
+/// ```
 ///
 /// Please note that if the current component is cleaned up before the throttled callback is called, the throttled callback will not be called.
 ///
@@ -56,6 +91,9 @@ use default_struct_builder::DefaultBuilder;
 /// # }
 /// ```
 ///
+/// Please note that with `trailing` set to `false` a change that is dropped by the throttle stays
+/// pending until it is flushed, cancelled or the callback is called for a later change.
+///
 /// ## Recommended Reading
 ///
 /// - [**Debounce vs Throttle**: Definitive Visual Guide](https://redd.one/blog/debounce-vs-throttle)
@@ -75,14 +113,18 @@ pub fn watch_throttled<W, T, DFn, CFn>(
     deps: DFn,
     callback: CFn,
     ms: f64,
-) -> impl Fn() + Clone + Send + Sync
+) -> WatchFilteredReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
 where
     DFn: Fn() -> W + 'static,
     CFn: Fn(&W, Option<&W>, Option<T>) -> T + Clone + 'static,
     W: Clone + 'static,
     T: Clone + 'static,
 {
-    watch_with_options(deps, callback, WatchOptions::default().throttle(ms))
+    watch_filtered_with_options(deps, callback, WatchOptions::default().throttle(ms))
 }
 
 /// Version of [`fn@watch_throttled`] that accepts `WatchThrottledOptions`. See [`watch_throttled`] for how to use.
@@ -91,14 +133,18 @@ pub fn watch_throttled_with_options<W, T, DFn, CFn>(
     callback: CFn,
     ms: f64,
     options: WatchThrottledOptions,
-) -> impl Fn() + Clone + Send + Sync
+) -> WatchFilteredReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
 where
     DFn: Fn() -> W + 'static,
     CFn: Fn(&W, Option<&W>, Option<T>) -> T + Clone + 'static,
     W: Clone + 'static,
     T: Clone + 'static,
 {
-    watch_with_options(
+    watch_filtered_with_options(
         deps,
         callback,
         WatchOptions::default()
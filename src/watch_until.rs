@@ -0,0 +1,125 @@
+use leptos::prelude::*;
+use std::cell::RefCell;
+use std::future::{poll_fn, Future};
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+
+/// Wait for a watched value to meet a condition.
+///
+/// Returns a future that resolves with the value returned by `deps` as soon as `predicate`
+/// returns `true` for it. The watcher is stopped afterwards.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos::logging::log;
+/// # use leptos_use::watch_until;
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// let (count, set_count) = signal(0);
+///
+/// let until_ten = watch_until(move || count.get(), |count| *count >= 10);
+///
+/// spawn_local(async move {
+///     let count = until_ten.await;
+///     log!("reached {count}");
+/// });
+/// #
+/// #     view! { }
+/// # }
+/// ```
+///
+/// The predicate is checked for the current value as well so the future resolves right away
+/// if the condition is already met.
+///
+/// The watcher is created when `watch_until` is called, not when the future is polled first.
+/// Changes that happen in between are not missed.
+///
+/// ### Waiting for a signal to become `true`
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::watch_until;
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// # let (is_ready, set_ready) = signal(false);
+/// spawn_local(async move {
+///     watch_until(move || is_ready.get(), |is_ready| *is_ready).await;
+///     // continue once ready
+/// });
+/// #
+/// #     view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned future never resolves. The same is true if the reactive owner
+/// is disposed before the condition is met.
+///
+/// ## See also
+///
+/// * [`fn@crate::watch_once`]
+/// * [`fn@crate::whenever`]
+pub fn watch_until<W, DFn, PFn>(deps: DFn, predicate: PFn) -> impl Future<Output = W>
+where
+    DFn: Fn() -> W + 'static,
+    PFn: Fn(&W) -> bool + 'static,
+    W: Clone + 'static,
+{
+    let state = Rc::new(RefCell::new(UntilState::<W> {
+        value: None,
+        waker: None,
+    }));
+
+    let effect = StoredValue::new_local(None::<Effect<LocalStorage>>);
+
+    effect.set_value(Some(Effect::watch(
+        deps,
+        {
+            let state = Rc::clone(&state);
+
+            move |value, _, _| {
+                if !predicate(value) {
+                    return;
+                }
+
+                let mut state = state.borrow_mut();
+                if state.value.is_some() {
+                    return;
+                }
+                state.value = Some(value.clone());
+
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+                drop(state);
+
+                if let Some(effect) = effect.try_update_value(Option::take).flatten() {
+                    effect.stop();
+                }
+            }
+        },
+        true,
+    )));
+
+    poll_fn(move |cx| {
+        let mut state = state.borrow_mut();
+
+        match state.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    })
+}
+
+struct UntilState<W> {
+    value: Option<W>,
+    waker: Option<Waker>,
+}
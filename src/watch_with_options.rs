@@ -188,3 +188,113 @@ impl WatchOptions {
         filter
     );
 }
+
+/// Like [`watch_with_options`] but the filtered callback can be flushed and cancelled.
+/// Used by [`fn@crate::watch_debounced`] and [`fn@crate::watch_throttled`].
+#[cfg(any(feature = "watch_debounced", feature = "watch_throttled"))]
+pub(crate) fn watch_filtered_with_options<W, T, DFn, CFn>(
+    deps: DFn,
+    callback: CFn,
+    options: WatchOptions,
+) -> WatchFilteredReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
+where
+    DFn: Fn() -> W + 'static,
+    CFn: Fn(&W, Option<&W>, Option<T>) -> T + Clone + 'static,
+    W: Clone + 'static,
+    T: Clone + 'static,
+{
+    let (is_pending, set_pending) = signal(false);
+
+    let callback = StoredValue::new_local(callback);
+    let cur_deps_value = StoredValue::new_local(None::<W>);
+    let prev_deps_value = StoredValue::new_local(None::<W>);
+    let prev_callback_value = StoredValue::new_local(None::<T>);
+    // the first run of `deps` only schedules the callback if it runs immediately
+    let is_first_run = StoredValue::new_local(true);
+    let immediate = options.immediate;
+
+    let invoke = move |value: &W, prev_value: Option<&W>| {
+        set_pending.try_set(false);
+
+        let ret = (callback.get_value())(value, prev_value, prev_callback_value.get_value());
+        prev_callback_value.set_value(Some(ret.clone()));
+
+        ret
+    };
+
+    let stop = watch_with_options(
+        move || {
+            let value = deps();
+
+            prev_deps_value.set_value(cur_deps_value.get_value());
+            cur_deps_value.set_value(Some(value.clone()));
+
+            if !is_first_run.get_value() || immediate {
+                set_pending.try_set(true);
+            }
+            is_first_run.set_value(false);
+
+            value
+        },
+        move |value, prev_value, _| {
+            // the invocation has been flushed or cancelled
+            if !is_pending.get_untracked() {
+                return None;
+            }
+
+            Some(invoke(value, prev_value))
+        },
+        options,
+    );
+
+    let flush = move || {
+        if !is_pending.get_untracked() {
+            return;
+        }
+
+        if let Some(value) = cur_deps_value.get_value() {
+            #[cfg(debug_assertions)]
+            let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+            invoke(&value, prev_deps_value.get_value().as_ref());
+
+            #[cfg(debug_assertions)]
+            drop(zone);
+        }
+    };
+
+    let cancel = move || {
+        set_pending.try_set(false);
+    };
+
+    WatchFilteredReturn {
+        stop,
+        flush,
+        cancel,
+        is_pending: is_pending.into(),
+    }
+}
+
+/// Return type of [`fn@crate::watch_debounced`] and [`fn@crate::watch_throttled`]
+pub struct WatchFilteredReturn<StopFn, FlushFn, CancelFn>
+where
+    StopFn: Fn() + Clone + Send + Sync,
+    FlushFn: Fn() + Clone + Send + Sync,
+    CancelFn: Fn() + Clone + Send + Sync,
+{
+    /// Stops the watcher
+    pub stop: StopFn,
+
+    /// Calls a pending callback right away instead of waiting for the timeout
+    pub flush: FlushFn,
+
+    /// Drops a pending callback
+    pub cancel: CancelFn,
+
+    /// Whether a change is waiting for the callback to be called
+    pub is_pending: Signal<bool>,
+}